@@ -0,0 +1,58 @@
+//! Compares `Regex::is_match` with `Regex::find`, on short and long texts.
+//!
+//! Run with `cargo bench --bench is_match`.
+
+use regress::Regex;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// \return the mean time of one call of \p f, running it for about 100ms.
+fn time_per_call(mut f: impl FnMut() -> bool) -> Duration {
+    let mut iters = 1u32;
+    loop {
+        let begin = Instant::now();
+        for _ in 0..iters {
+            black_box(f());
+        }
+        let elapsed = begin.elapsed();
+        if elapsed >= Duration::from_millis(100) {
+            return elapsed / iters;
+        }
+        iters *= 2;
+    }
+}
+
+fn main() {
+    let long_text = "the quick brown fox jumps over the lazy dog; ".repeat(2000);
+    let cases = [
+        (r"\d{4}-\d{2}-\d{2}", "due 2024-05-01"),
+        (r"\d{4}-\d{2}-\d{2}", "no date here"),
+        (r"foo|bar|baz", "a bar"),
+        (r"foo|bar|baz", "none"),
+        (r"[a-z]+ing", "something"),
+        (r"(a|b)*c", "ababababx"),
+        (r"[a-z]+ing", "the quick brown fox jumps over the lazy dog"),
+        (r"\w+nn\b", "the quick brown fox jumps over the lazy dog"),
+        (r"\d{4}-\d{2}-\d{2}", long_text.as_str()),
+        (r"foo|bar|baz", long_text.as_str()),
+        (r"[a-z]+ing", long_text.as_str()),
+        (r"\w+nn\b", long_text.as_str()),
+    ];
+    println!(
+        "{:<22} {:>8} {:>12} {:>12}",
+        "pattern", "text", "is_match", "find"
+    );
+    for (pattern, text) in cases {
+        let re = Regex::new(pattern).unwrap();
+        assert_eq!(re.is_match(text), re.find(text).is_some());
+        let is_match = time_per_call(|| re.is_match(black_box(text)));
+        let find = time_per_call(|| re.find(black_box(text)).is_some());
+        println!(
+            "{:<22} {:>8} {:>12?} {:>12?}",
+            pattern,
+            text.len(),
+            is_match,
+            find
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    chars_to_code_point_ranges, pack_adjacent_chars, parse_line, push_enum_variants, MAX_CODE_POINT,
+};
+use std::fs::File;
+use std::io::{self, BufRead};
+
+use codegen::{Block, Enum, Function, Scope};
+
+pub(crate) fn generate(scope: &mut Scope) {
+    let mut property_enum = Enum::new("UnicodePropertyValueAge");
+    property_enum
+        .vis("pub")
+        .derive("Debug")
+        .derive("Clone")
+        .derive("Copy");
+
+    let mut is_property_fn = Function::new("is_property_value_age");
+    is_property_fn
+        .vis("pub(crate)")
+        .arg("c", "char")
+        .arg("value", "&UnicodePropertyValueAge")
+        .ret("bool")
+        .line("use UnicodePropertyValueAge::*;");
+    let mut is_property_fn_match_block = Block::new("match value");
+
+    let mut property_from_str_fn = Function::new("unicode_property_value_age_from_str");
+    property_from_str_fn
+        .arg("s", "&str")
+        .ret("Option<UnicodePropertyValueAge>")
+        .vis("pub")
+        .line("use UnicodePropertyValueAge::*;");
+    let mut property_from_str_fn_match_block = Block::new("match s");
+
+    let mut table_fn = Function::new("property_value_age_table");
+    table_fn
+        .vis("pub(crate)")
+        .arg("value", "&UnicodePropertyValueAge")
+        .ret("CodePointTable")
+        .line("use UnicodePropertyValueAge::*;");
+    let mut table_fn_match_block = Block::new("match value");
+
+    let mut variants = Vec::new();
+
+    for (version, name) in AGES {
+        let file = File::open("DerivedAge.txt").expect("could not open DerivedAge.txt");
+        let lines = io::BufReader::new(file).lines();
+        let mut chars = Vec::new();
+
+        for line in lines {
+            parse_line(&line.unwrap(), &mut chars, version);
+        }
+
+        pack_adjacent_chars(&mut chars);
+
+        // The private use planes cannot be packed into a CodePointRange.
+        if chars.iter().any(|&(_, end)| end > MAX_CODE_POINT) {
+            table_fn_match_block.line(format!(
+                "{} => CodePointTable::Unpacked(&AGE_{}),",
+                name,
+                name.to_uppercase()
+            ));
+            scope.raw(&format!(
+                "pub(crate) const AGE_{}: [CodePointRangeUnpacked; {}] = [\n    {}\n];",
+                name.to_uppercase(),
+                chars.len(),
+                chars
+                    .iter()
+                    .map(|cs| format!("CodePointRangeUnpacked::from({}, {}),", cs.0, cs.1))
+                    .collect::<Vec<String>>()
+                    .join("\n    ")
+            ));
+        } else {
+            table_fn_match_block.line(format!(
+                "{} => CodePointTable::Packed(&AGE_{}),",
+                name,
+                name.to_uppercase()
+            ));
+            let ranges = chars_to_code_point_ranges(&chars);
+            scope.raw(&format!(
+                "pub(crate) const AGE_{}: [CodePointRange; {}] = [\n    {}\n];",
+                name.to_uppercase(),
+                ranges.len(),
+                ranges.join("\n    ")
+            ));
+        }
+
+        scope
+            .new_fn(&format!("is_age_{}", name.to_lowercase()))
+            .vis("pub(crate)")
+            .arg("c", "char")
+            .ret("bool")
+            .line(format!(
+                "AGE_{}.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()",
+                name.to_uppercase()
+            ))
+            .doc(&format!(
+                "Return whether c was assigned in Unicode version {}.",
+                version
+            ));
+
+        property_enum.new_variant(name);
+        variants.push(*name);
+
+        is_property_fn_match_block.line(format!("{} => is_age_{}(c),", name, name.to_lowercase()));
+
+        property_from_str_fn_match_block
+            .line(format!("\"{}\" | \"{}\" => Some({}),", version, name, name));
+    }
+
+    is_property_fn.push_block(is_property_fn_match_block);
+    table_fn.push_block(table_fn_match_block);
+
+    property_from_str_fn_match_block.line("_ => None,");
+    property_from_str_fn.push_block(property_from_str_fn_match_block);
+
+    scope
+        .push_fn(is_property_fn)
+        .push_fn(table_fn)
+        .push_enum(property_enum)
+        .push_fn(property_from_str_fn);
+
+    push_enum_variants(
+        scope,
+        "UnicodePropertyValueAge",
+        "UNICODE_PROPERTY_VALUE_AGE_VARIANTS",
+        &variants,
+    );
+}
+
+// Structure: (Version, Name)
+// The name is also the long alias of the value in PropertyValueAliases.txt.
+const AGES: &[(&str, &str); 23] = &[
+    ("1.1", "V1_1"),
+    ("2.0", "V2_0"),
+    ("2.1", "V2_1"),
+    ("3.0", "V3_0"),
+    ("3.1", "V3_1"),
+    ("3.2", "V3_2"),
+    ("4.0", "V4_0"),
+    ("4.1", "V4_1"),
+    ("5.0", "V5_0"),
+    ("5.1", "V5_1"),
+    ("5.2", "V5_2"),
+    ("6.0", "V6_0"),
+    ("6.1", "V6_1"),
+    ("6.2", "V6_2"),
+    ("6.3", "V6_3"),
+    ("7.0", "V7_0"),
+    ("8.0", "V8_0"),
+    ("9.0", "V9_0"),
+    ("10.0", "V10_0"),
+    ("11.0", "V11_0"),
+    ("12.0", "V12_0"),
+    ("12.1", "V12_1"),
+    ("13.0", "V13_0"),
+];
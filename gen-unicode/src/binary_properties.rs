@@ -1,4 +1,4 @@
-use crate::{chars_to_code_point_ranges, pack_adjacent_chars, parse_line};
+use crate::{chars_to_code_point_ranges, pack_adjacent_chars, parse_line, push_enum_variants};
 use std::fs::File;
 use std::io::{self, BufRead};
 
@@ -29,6 +29,16 @@ pub(crate) fn generate(scope: &mut Scope) {
         .line("use UnicodePropertyBinary::*;");
     let mut property_from_str_fn_match_block = Block::new("match s");
 
+    let mut table_fn = Function::new("property_binary_table");
+    table_fn
+        .vis("pub(crate)")
+        .arg("value", "&UnicodePropertyBinary")
+        .ret("CodePointTable")
+        .line("use UnicodePropertyBinary::*;");
+    let mut table_fn_match_block = Block::new("match value");
+
+    let mut variants = Vec::new();
+
     for (alias, orig_name, name, ucd_file_name) in BINARY_PROPERTIES {
         let file = File::open(ucd_file_name).unwrap();
         let lines = io::BufReader::new(file).lines();
@@ -42,6 +52,11 @@ pub(crate) fn generate(scope: &mut Scope) {
 
         // Some properties cannot be packed into a CodePointRange.
         if ["Noncharacter_Code_Point"].contains(orig_name) {
+            table_fn_match_block.line(format!(
+                "{} => CodePointTable::Unpacked(&{}),",
+                name,
+                orig_name.to_uppercase()
+            ));
             scope.raw(&format!(
                 "pub(crate) const {}: [CodePointRangeUnpacked; {}] = [\n    {}\n];",
                 orig_name.to_uppercase(),
@@ -53,6 +68,11 @@ pub(crate) fn generate(scope: &mut Scope) {
                     .join("\n    ")
             ));
         } else {
+            table_fn_match_block.line(format!(
+                "{} => CodePointTable::Packed(&{}),",
+                name,
+                orig_name.to_uppercase()
+            ));
             let ranges = chars_to_code_point_ranges(&chars);
             scope.raw(&format!(
                 "pub(crate) const {}: [CodePointRange; {}] = [\n    {}\n];",
@@ -67,7 +87,7 @@ pub(crate) fn generate(scope: &mut Scope) {
             .vis("pub(crate)")
             .arg("c", "char")
             .ret("bool")
-            .line(&format!(
+            .line(format!(
                 "{}.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()",
                 orig_name.to_uppercase()
             ))
@@ -77,6 +97,7 @@ pub(crate) fn generate(scope: &mut Scope) {
             ));
 
         property_enum.new_variant(name);
+        variants.push(*name);
 
         is_property_fn_match_block.line(format!("{} => is_{}(c),", name, orig_name.to_lowercase()));
 
@@ -91,6 +112,7 @@ pub(crate) fn generate(scope: &mut Scope) {
     property_enum.new_variant("Ascii");
     property_enum.new_variant("Any");
     property_enum.new_variant("Assigned");
+    variants.extend(["Ascii", "Any", "Assigned"]);
 
     let ascii_ranges = chars_to_code_point_ranges(&[(0, 127)]);
 
@@ -129,19 +151,33 @@ pub(crate) fn generate(scope: &mut Scope) {
     is_property_fn_match_block.line("Any => is_any(c),");
     is_property_fn_match_block.line("Assigned => is_assigned(c),");
 
+    table_fn_match_block.line("Ascii => CodePointTable::Packed(&ASCII),");
+    table_fn_match_block.line("Any => CodePointTable::Unpacked(&ANY),");
+    table_fn_match_block
+        .line("Assigned => CodePointTable::Complement(&CodePointTable::Unpacked(&UNASSIGNED)),");
+
     property_from_str_fn_match_block.line("\"ASCII\" => Some(Ascii),");
     property_from_str_fn_match_block.line("\"Any\" => Some(Any),");
     property_from_str_fn_match_block.line("\"Assigned\" => Some(Assigned),");
 
     is_property_fn.push_block(is_property_fn_match_block);
+    table_fn.push_block(table_fn_match_block);
 
     property_from_str_fn_match_block.line("_ => None,");
     property_from_str_fn.push_block(property_from_str_fn_match_block);
 
     scope
         .push_fn(is_property_fn)
+        .push_fn(table_fn)
         .push_enum(property_enum)
         .push_fn(property_from_str_fn);
+
+    push_enum_variants(
+        scope,
+        "UnicodePropertyBinary",
+        "UNICODE_PROPERTY_BINARY_VARIANTS",
+        &variants,
+    );
 }
 
 pub(crate) fn generate_tests(scope: &mut Scope) {
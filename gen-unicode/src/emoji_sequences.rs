@@ -0,0 +1,130 @@
+use std::fs::File;
+use std::io::{self, BufRead};
+
+use codegen::{Block, Enum, Function, Scope};
+
+// The properties of strings are only available with the 'v' flag. Each is
+// emitted as a sorted table of the sequences of code points it matches,
+// including those of a single code point.
+pub(crate) fn generate(scope: &mut Scope) {
+    let mut property_enum = Enum::new("UnicodePropertyOfStrings");
+    property_enum
+        .vis("pub")
+        .derive("Debug")
+        .derive("Clone")
+        .derive("Copy");
+
+    let mut property_from_str_fn = Function::new("unicode_property_of_strings_from_str");
+    property_from_str_fn
+        .arg("s", "&str")
+        .ret("Option<UnicodePropertyOfStrings>")
+        .vis("pub")
+        .line("use UnicodePropertyOfStrings::*;");
+    let mut property_from_str_fn_match_block = Block::new("match s");
+
+    for (orig_name, name, ucd_file_name) in PROPERTIES_OF_STRINGS {
+        let file = File::open(ucd_file_name)
+            .unwrap_or_else(|_| panic!("could not open {}", ucd_file_name));
+        let lines = io::BufReader::new(file).lines();
+        let mut sequences = Vec::new();
+
+        for line in lines {
+            parse_sequence_line(&line.unwrap(), &mut sequences, orig_name);
+        }
+
+        sequences.sort();
+        sequences.dedup();
+
+        scope.raw(&format!(
+            "pub(crate) static {}: [&[u32]; {}] = [\n    {}\n];",
+            orig_name.to_uppercase(),
+            sequences.len(),
+            sequences
+                .iter()
+                .map(|seq| format!(
+                    "&[{}],",
+                    seq.iter()
+                        .map(|c| format!("0x{:X}", c))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ))
+                .collect::<Vec<String>>()
+                .join("\n    ")
+        ));
+
+        property_enum.new_variant(name);
+
+        property_from_str_fn_match_block.line(format!("\"{}\" => Some({}),", orig_name, name));
+    }
+
+    // RGI_Emoji is the union of the other properties, so it has no table of
+    // its own.
+    property_enum.new_variant("RgiEmoji");
+    property_from_str_fn_match_block.line("\"RGI_Emoji\" => Some(RgiEmoji),");
+
+    property_from_str_fn_match_block.line("_ => None,");
+    property_from_str_fn.push_block(property_from_str_fn_match_block);
+
+    scope.push_enum(property_enum).push_fn(property_from_str_fn);
+}
+
+// Parse a line from the emoji sequence files with the following syntax:
+// 231A..231B    ; Basic_Emoji     ; watch..hourglass done  # E0.6   [2] (⌚..⌛)
+// 0023 FE0F 20E3; Emoji_Keycap_Sequence ; keycap: \x{23}   # E0.6   [1] (#️⃣)
+// A range stands for each of its code points as a sequence of one.
+fn parse_sequence_line(line: &str, sequences: &mut Vec<Vec<u32>>, property: &str) {
+    if line.starts_with('#') {
+        return;
+    }
+
+    let mut fields = line.split(';').map(str::trim);
+    let (Some(codepoint_hexes), Some(type_field)) = (fields.next(), fields.next()) else {
+        return;
+    };
+    if type_field != property {
+        return;
+    }
+
+    if let Some((first_hex, last_hex)) = codepoint_hexes.split_once("..") {
+        let first = u32::from_str_radix(first_hex, 16).unwrap();
+        let last = u32::from_str_radix(last_hex, 16).unwrap();
+        sequences.extend((first..=last).map(|c| vec![c]));
+    } else {
+        sequences.push(
+            codepoint_hexes
+                .split_whitespace()
+                .map(|hex| u32::from_str_radix(hex, 16).unwrap())
+                .collect(),
+        );
+    }
+}
+
+// Structure: (Original Name, Name, UCD file name)
+const PROPERTIES_OF_STRINGS: &[(&str, &str, &str); 6] = &[
+    ("Basic_Emoji", "BasicEmoji", "emoji-sequences.txt"),
+    (
+        "Emoji_Keycap_Sequence",
+        "EmojiKeycapSequence",
+        "emoji-sequences.txt",
+    ),
+    (
+        "RGI_Emoji_Flag_Sequence",
+        "RgiEmojiFlagSequence",
+        "emoji-sequences.txt",
+    ),
+    (
+        "RGI_Emoji_Modifier_Sequence",
+        "RgiEmojiModifierSequence",
+        "emoji-sequences.txt",
+    ),
+    (
+        "RGI_Emoji_Tag_Sequence",
+        "RgiEmojiTagSequence",
+        "emoji-sequences.txt",
+    ),
+    (
+        "RGI_Emoji_ZWJ_Sequence",
+        "RgiEmojiZwjSequence",
+        "emoji-zwj-sequences.txt",
+    ),
+];
@@ -1,4 +1,4 @@
-use crate::{chars_to_code_point_ranges, pack_adjacent_chars, parse_line};
+use crate::{chars_to_code_point_ranges, pack_adjacent_chars, parse_line, push_enum_variants};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, BufRead};
@@ -31,6 +31,19 @@ pub(crate) fn generate(scope: &mut Scope) {
         .line("use UnicodePropertyValueGeneralCategory::*;");
     let mut property_from_str_fn_match_block = Block::new("match s");
 
+    let mut table_fn = Function::new("property_value_general_category_table");
+    table_fn
+        .vis("pub(crate)")
+        .arg("value", "&UnicodePropertyValueGeneralCategory")
+        .ret("CodePointTable")
+        .line("use UnicodePropertyValueGeneralCategory::*;");
+    let mut table_fn_match_block = Block::new("match value");
+
+    // The table of each value, by name, from which the tables of the derived values are made.
+    let mut tables = HashMap::new();
+
+    let mut variants = Vec::new();
+
     for (alias0, alias1, orig_name, name) in GENERAL_CATEGORY_VALUES {
         let file = File::open("DerivedGeneralCategory.txt")
             .expect("could not open DerivedGeneralCategory.txt");
@@ -45,6 +58,10 @@ pub(crate) fn generate(scope: &mut Scope) {
 
         // Some properties cannot be packed into a CodePointRange.
         if ["Unassigned", "Private_Use"].contains(orig_name) {
+            tables.insert(
+                *orig_name,
+                format!("CodePointTable::Unpacked(&{})", orig_name.to_uppercase()),
+            );
             scope.raw(&format!(
                 "pub(crate) const {}: [CodePointRangeUnpacked; {}] = [\n    {}\n];",
                 orig_name.to_uppercase(),
@@ -56,6 +73,10 @@ pub(crate) fn generate(scope: &mut Scope) {
                     .join("\n    ")
             ));
         } else {
+            tables.insert(
+                *orig_name,
+                format!("CodePointTable::Packed(&{})", orig_name.to_uppercase()),
+            );
             let ranges = chars_to_code_point_ranges(&chars);
             scope.raw(&format!(
                 "pub(crate) const {}: [CodePointRange; {}] = [\n    {}\n];",
@@ -70,7 +91,7 @@ pub(crate) fn generate(scope: &mut Scope) {
             .vis("pub(crate)")
             .arg("c", "char")
             .ret("bool")
-            .line(&format!(
+            .line(format!(
                 "{}.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()",
                 orig_name.to_uppercase()
             ))
@@ -80,8 +101,10 @@ pub(crate) fn generate(scope: &mut Scope) {
             ));
 
         property_enum.new_variant(name);
+        variants.push(*name);
 
         is_property_fn_match_block.line(format!("{} => is_{}(c),", name, orig_name.to_lowercase()));
+        table_fn_match_block.line(format!("{} => {},", name, tables[orig_name]));
 
         property_from_str_fn_match_block.line(if alias0.is_empty() {
             format!("\"{}\" | \"{}\" => Some({}),", alias1, orig_name, name)
@@ -111,8 +134,18 @@ pub(crate) fn generate(scope: &mut Scope) {
             ));
 
         property_enum.new_variant(name);
+        variants.push(*name);
 
         is_property_fn_match_block.line(format!("{} => is_{}(c),", name, orig_name.to_lowercase()));
+        let value_tables: Vec<&str> = value_names_str
+            .split(',')
+            .map(|name| tables[name].as_str())
+            .collect();
+        table_fn_match_block.line(format!(
+            "{} => CodePointTable::Union(&[{}]),",
+            name,
+            value_tables.join(", ")
+        ));
 
         property_from_str_fn_match_block.line(if alias0.is_empty() {
             format!("\"{}\" | \"{}\" => Some({}),", alias1, orig_name, name)
@@ -125,14 +158,23 @@ pub(crate) fn generate(scope: &mut Scope) {
     }
 
     is_property_fn.push_block(is_property_fn_match_block);
+    table_fn.push_block(table_fn_match_block);
 
     property_from_str_fn_match_block.line("_ => None,");
     property_from_str_fn.push_block(property_from_str_fn_match_block);
 
     scope
         .push_fn(is_property_fn)
+        .push_fn(table_fn)
         .push_enum(property_enum)
         .push_fn(property_from_str_fn);
+
+    push_enum_variants(
+        scope,
+        "UnicodePropertyValueGeneralCategory",
+        "UNICODE_PROPERTY_VALUE_GENERAL_CATEGORY_VARIANTS",
+        &variants,
+    );
 }
 
 pub(crate) fn generate_tests(scope: &mut Scope) {
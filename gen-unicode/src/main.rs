@@ -1,5 +1,7 @@
+mod ages;
 mod binary_properties;
 mod case_folding;
+mod emoji_sequences;
 mod general_category_values;
 mod scripts;
 use codegen::Scope;
@@ -43,12 +45,15 @@ fn main() {
     let mut scope = Scope::new();
     scope.import("crate::unicode", "CodePointRange");
     scope.import("crate::unicode", "CodePointRangeUnpacked");
+    scope.import("crate::unicode", "CodePointTable");
     scope.import("crate::unicode", "FoldRange");
 
     binary_properties::generate(&mut scope);
     case_folding::generate_folds(&mut scope);
     scripts::generate(&mut scope);
     general_category_values::generate(&mut scope);
+    ages::generate(&mut scope);
+    emoji_sequences::generate(&mut scope);
 
     let mut scope_tests = Scope::new();
     scope_tests.import("common", "*");
@@ -81,6 +86,28 @@ fn main() {
         .expect("Failed to run 'cargo fmt' on tests file");
 }
 
+// Emit a constant listing every variant of an enum in declaration order, so
+// that a variant may be recovered from its index.
+pub(crate) fn push_enum_variants(
+    scope: &mut Scope,
+    enum_name: &str,
+    const_name: &str,
+    variants: &[&str],
+) {
+    scope.raw(&format!(
+        "/// Every {}, in declaration order.\npub(crate) const {}: [{}; {}] = [\n    {}\n];",
+        enum_name,
+        const_name,
+        enum_name,
+        variants.len(),
+        variants
+            .iter()
+            .map(|v| format!("{}::{},", enum_name, v))
+            .collect::<Vec<String>>()
+            .join("\n    ")
+    ));
+}
+
 // Parse line from the UCD files with the following syntax:
 // 0380..0383    ; Cn #   [4] <reserved-0380>..<reserved-0383>
 // 038B          ; Cn #       <reserved-038B>
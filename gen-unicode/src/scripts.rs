@@ -1,4 +1,4 @@
-use crate::{chars_to_code_point_ranges, pack_adjacent_chars, parse_line};
+use crate::{chars_to_code_point_ranges, pack_adjacent_chars, parse_line, push_enum_variants};
 use std::fs::File;
 use std::io::{self, BufRead};
 
@@ -29,6 +29,34 @@ pub(crate) fn generate(scope: &mut Scope) {
         .line("use UnicodePropertyValueScript::*;");
     let mut property_from_str_fn_match_block = Block::new("match s");
 
+    let mut is_extensions_fn = Function::new("is_property_value_script_extensions");
+    is_extensions_fn
+        .vis("pub(crate)")
+        .arg("c", "char")
+        .arg("value", "&UnicodePropertyValueScript")
+        .ret("bool")
+        .line("use UnicodePropertyValueScript::*;");
+    let mut is_extensions_fn_match_block = Block::new("match value");
+
+    let mut table_fn = Function::new("property_value_script_table");
+    table_fn
+        .vis("pub(crate)")
+        .arg("value", "&UnicodePropertyValueScript")
+        .ret("CodePointTable")
+        .line("use UnicodePropertyValueScript::*;");
+    let mut table_fn_match_block = Block::new("match value");
+
+    let mut extensions_table_fn = Function::new("property_value_script_extensions_table");
+    extensions_table_fn
+        .vis("pub(crate)")
+        .arg("value", "&UnicodePropertyValueScript")
+        .ret("CodePointTable")
+        .line("use UnicodePropertyValueScript::*;");
+    let mut extensions_table_fn_match_block = Block::new("match value");
+
+    let mut variants = Vec::new();
+    let extensions = parse_script_extensions();
+
     for (alias0, alias1, orig_name, name) in SCRIPTS {
         let file = File::open("Scripts.txt").expect("could not open Scripts.txt");
         let lines = io::BufReader::new(file).lines();
@@ -40,6 +68,55 @@ pub(crate) fn generate(scope: &mut Scope) {
 
         pack_adjacent_chars(&mut chars);
 
+        let extension_chars =
+            script_extensions_chars(&chars, &extensions, alias0, alias1, orig_name);
+        if extension_chars == chars {
+            is_extensions_fn_match_block.line(format!(
+                "{} => is_{}(c),",
+                name,
+                orig_name.to_lowercase()
+            ));
+            extensions_table_fn_match_block.line(format!(
+                "{} => CodePointTable::Packed(&{}),",
+                name,
+                orig_name.to_uppercase()
+            ));
+        } else {
+            let ranges = chars_to_code_point_ranges(&extension_chars);
+
+            scope.raw(&format!(
+                "pub(crate) const {}_EXTENSIONS: [CodePointRange; {}] = [\n    {}\n];",
+                orig_name.to_uppercase(),
+                ranges.len(),
+                ranges.join("\n    ")
+            ));
+
+            scope
+                .new_fn(&format!("is_{}_extensions", orig_name.to_lowercase()))
+                .vis("pub(crate)")
+                .arg("c", "char")
+                .ret("bool")
+                .line(format!(
+                    "{}_EXTENSIONS.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()",
+                    orig_name.to_uppercase()
+                ))
+                .doc(&format!(
+                    "Return whether c has '{}' in its Script_Extensions Unicode property.",
+                    orig_name
+                ));
+
+            is_extensions_fn_match_block.line(format!(
+                "{} => is_{}_extensions(c),",
+                name,
+                orig_name.to_lowercase()
+            ));
+            extensions_table_fn_match_block.line(format!(
+                "{} => CodePointTable::Packed(&{}_EXTENSIONS),",
+                name,
+                orig_name.to_uppercase()
+            ));
+        }
+
         let ranges = chars_to_code_point_ranges(&chars);
 
         scope.raw(&format!(
@@ -54,7 +131,7 @@ pub(crate) fn generate(scope: &mut Scope) {
             .vis("pub(crate)")
             .arg("c", "char")
             .ret("bool")
-            .line(&format!(
+            .line(format!(
                 "{}.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()",
                 orig_name.to_uppercase()
             ))
@@ -64,8 +141,14 @@ pub(crate) fn generate(scope: &mut Scope) {
             ));
 
         property_enum.new_variant(name);
+        variants.push(*name);
 
         is_property_fn_match_block.line(format!("{} => is_{}(c),", name, orig_name.to_lowercase()));
+        table_fn_match_block.line(format!(
+            "{} => CodePointTable::Packed(&{}),",
+            name,
+            orig_name.to_uppercase()
+        ));
 
         property_from_str_fn_match_block.line(if alias0.is_empty() && alias1.is_empty() {
             format!("\"{}\" => Some({}),", orig_name, name)
@@ -80,14 +163,27 @@ pub(crate) fn generate(scope: &mut Scope) {
     }
 
     is_property_fn.push_block(is_property_fn_match_block);
+    is_extensions_fn.push_block(is_extensions_fn_match_block);
+    table_fn.push_block(table_fn_match_block);
+    extensions_table_fn.push_block(extensions_table_fn_match_block);
 
     property_from_str_fn_match_block.line("_ => None,");
     property_from_str_fn.push_block(property_from_str_fn_match_block);
 
     scope
         .push_fn(is_property_fn)
+        .push_fn(is_extensions_fn)
+        .push_fn(table_fn)
+        .push_fn(extensions_table_fn)
         .push_enum(property_enum)
         .push_fn(property_from_str_fn);
+
+    push_enum_variants(
+        scope,
+        "UnicodePropertyValueScript",
+        "UNICODE_PROPERTY_VALUE_SCRIPT_VARIANTS",
+        &variants,
+    );
 }
 
 pub(crate) fn generate_tests(scope: &mut Scope) {
@@ -160,6 +256,134 @@ pub(crate) fn generate_tests(scope: &mut Scope) {
 
         f.push_block(b);
     }
+
+    let extensions = parse_script_extensions();
+
+    for (alias0, alias1, orig_name, name) in SCRIPTS {
+        // Only test the code points whose Script_Extensions differ from their Script.
+        let short_name = script_short_name(alias0, alias1, orig_name);
+        let code_points: Vec<String> = extensions
+            .iter()
+            .filter(|(_, scripts)| scripts.iter().any(|s| s == short_name))
+            .map(|((first, _), _)| format!("\"\\u{{{:x}}}\"", first))
+            .collect();
+        if code_points.is_empty() {
+            continue;
+        }
+
+        scope
+            .new_fn(&format!(
+                "unicode_escape_property_script_extensions_{}",
+                name.to_lowercase()
+            ))
+            .attr("test")
+            .line(format!(
+                "test_with_configs(unicode_escape_property_script_extensions_{}_tc)",
+                name.to_lowercase()
+            ));
+
+        let f = scope.new_fn(&format!(
+            "unicode_escape_property_script_extensions_{}_tc",
+            name.to_lowercase()
+        ));
+
+        f.arg("tc", "TestConfig");
+
+        f.line(format!(
+            "const CODE_POINTS: [&str; {}] = [\n    {},\n];",
+            code_points.len(),
+            code_points.join(",\n    ")
+        ));
+
+        let mut regexes = vec![
+            format!(r#""^\\p{{Script_Extensions={}}}+$""#, orig_name),
+            format!(r#""^\\p{{scx={}}}+$""#, orig_name),
+        ];
+
+        for alias in [alias0, alias1] {
+            if !alias.is_empty() {
+                regexes.push(format!(r#""^\\p{{Script_Extensions={}}}+$""#, alias));
+                regexes.push(format!(r#""^\\p{{scx={}}}+$""#, alias));
+            }
+        }
+
+        f.line(format!(
+            "const REGEXES: [&str; {}] = [\n    {},\n];",
+            regexes.len(),
+            regexes.join(",\n    ")
+        ));
+
+        let mut b = Block::new("for regex in REGEXES");
+        b.line("let regex = tc.compile(regex);");
+
+        let mut bb = Block::new("for code_point in CODE_POINTS");
+        bb.line("regex.test_succeeds(code_point);");
+
+        b.push_block(bb);
+
+        f.push_block(b);
+    }
+}
+
+// The name of a script used in ScriptExtensions.txt, like "Hani" for Han.
+fn script_short_name<'a>(alias0: &'a str, alias1: &'a str, orig_name: &'a str) -> &'a str {
+    [alias0, alias1, orig_name]
+        .into_iter()
+        .find(|s| !s.is_empty())
+        .unwrap()
+}
+
+// Parse ScriptExtensions.txt, whose lines have the following syntax:
+// 3001..3002    ; Bopo Hang Hani Hira Kana Yiii # Po   [2] IDEOGRAPHIC COMMA..IDEOGRAPHIC FULL STOP
+// into the ranges of code points and the short names of their scripts.
+fn parse_script_extensions() -> Vec<((u32, u32), Vec<String>)> {
+    let file = File::open("ScriptExtensions.txt").expect("could not open ScriptExtensions.txt");
+    let mut extensions = Vec::new();
+    for line in io::BufReader::new(file).lines() {
+        let line = line.unwrap();
+        let data = line.split('#').next().unwrap();
+        let Some((codepoint_hexes, scripts)) = data.split_once(';') else {
+            continue;
+        };
+        let mut chars = Vec::new();
+        parse_line(&format!("{}; x #", codepoint_hexes), &mut chars, "x");
+        let scripts = scripts.split_whitespace().map(String::from).collect();
+        extensions.push((chars[0], scripts));
+    }
+    extensions
+}
+
+// Return the code points whose Script_Extensions contain a script, given the
+// packed code points \p chars of the script: those listed in ScriptExtensions.txt
+// with the script, and those of the script which are not listed at all.
+fn script_extensions_chars(
+    chars: &[(u32, u32)],
+    extensions: &[((u32, u32), Vec<String>)],
+    alias0: &str,
+    alias1: &str,
+    orig_name: &str,
+) -> Vec<(u32, u32)> {
+    let short_name = script_short_name(alias0, alias1, orig_name);
+    let listed = |c: u32| {
+        extensions
+            .iter()
+            .find(|((first, last), _)| (*first..=*last).contains(&c))
+            .map(|(_, scripts)| scripts.iter().any(|s| s == short_name))
+    };
+    let mut code_points: Vec<u32> = chars
+        .iter()
+        .flat_map(|&(first, last)| first..=last)
+        .filter(|&c| listed(c).is_none())
+        .collect();
+    for ((first, last), scripts) in extensions {
+        if scripts.iter().any(|s| s == short_name) {
+            code_points.extend(*first..=*last);
+        }
+    }
+    code_points.sort_unstable();
+    let mut result: Vec<(u32, u32)> = code_points.into_iter().map(|c| (c, c)).collect();
+    pack_adjacent_chars(&mut result);
+    result
 }
 
 // Structure: (Alias, Alias, Name, CamelCaseName)
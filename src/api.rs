@@ -1,29 +1,51 @@
+use crate::charclasses;
 use crate::classicalbacktrack;
 use crate::emit;
 use crate::exec;
+use crate::explain;
 use crate::indexing;
-use crate::insn::CompiledRegex;
+use crate::insn::{CompiledRegex, GroupNames, StartPredicate};
+use crate::ir;
+#[cfg(feature = "ir-visitor")]
+use crate::irview;
+use crate::lazydfa::{self, DfaCachePool};
 use crate::optimizer;
 use crate::parse;
+use crate::serialize;
+use crate::startpredicate;
+use crate::unicode;
 
 #[cfg(feature = "backend-pikevm")]
 use crate::pikevm;
 use crate::util::to_char_sat;
 
-use core::{fmt, str::FromStr};
-#[cfg(feature = "std")]
-use std::collections::{hash_map::Iter, HashMap};
 #[cfg(not(feature = "std"))]
-use {
-    alloc::{string::String, vec::Vec},
-    hashbrown::{hash_map::Iter, HashMap},
+use alloc::{borrow::Cow, string::String, sync::Arc, vec::Vec};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
 };
+#[cfg(feature = "std")]
+use std::{borrow::Cow, sync::Arc};
 
-pub use parse::Error;
+pub use crate::codepointset::{CodePoint, CodePointSet, Interval, CODE_POINT_MAX};
+pub use crate::explain::FailureReport;
+pub use crate::incremental::IncrementalMatcher;
+pub use crate::indexing::Input;
+#[cfg(feature = "ir-visitor")]
+pub use crate::irview::{Ir, IrAssertion, IrNode, IrVisitor};
+#[cfg(feature = "trace")]
+pub use crate::trace::{Instruction, TraceEvent};
+pub use crate::unicode::UNICODE_VERSION;
+pub use parse::{Error, DEFAULT_MAX_NESTING_DEPTH};
 
 /// Flags used to control regex parsing.
 /// The default flags are case-sensitive, not-multiline, and optimizing.
-#[derive(Debug, Copy, Clone, Default)]
+/// More flags may be added, so construct this from a flags string, or from
+/// `Flags::default()` by setting fields, rather than as a struct literal.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct Flags {
     /// If set, make the regex case-insensitive.
     /// Equivalent to the 'i' flag in JavaScript.
@@ -40,40 +62,371 @@ pub struct Flags {
     /// If set, disable regex IR passes.
     pub no_opt: bool,
 
+    /// If set, unescaped whitespace in the pattern is ignored, and `#` begins
+    /// a comment which extends to the end of the line. Whitespace within
+    /// brackets remains significant.
+    /// Equivalent to the 'x' flag in PCRE.
+    pub extended: bool,
+
     /// If set, the regex is interpreted as a Unicode regex.
     /// Equivalent to the 'u' flag in JavaScript.
     pub unicode: bool,
+
+    /// If set, the regex is interpreted as a Unicode regex, and brackets
+    /// support nested classes and the set operations `&&` and `--`.
+    /// This may not be combined with the 'u' flag.
+    /// Equivalent to the 'v' flag in JavaScript.
+    pub unicode_sets: bool,
+
+    /// If set, matches must begin exactly at the search position, and
+    /// iteration stops at the first position which does not match.
+    /// Equivalent to the 'y' flag in JavaScript.
+    pub sticky: bool,
+
+    /// If set, the caller intends to use the ranges of capture groups.
+    /// Matches always record group ranges, so this has no effect on matching;
+    /// see [`Match::group_indices`].
+    /// Equivalent to the 'd' flag in JavaScript.
+    pub has_indices: bool,
+
+    /// If set, `\R` matches any line break and `\N` matches any character
+    /// other than a line terminator, even with the 's' flag. `\R` is an
+    /// atomic unit matching `\r\n` or one of `\r`, `\n`, U+2028 and
+    /// U+2029, so a quantifier over it never splits a CRLF: `^\R{2}$` does
+    /// not match "\r\n". These escapes are not ECMAScript, which treats them
+    /// as identity escapes or errors, so there is no flag letter for them.
+    /// They are not recognized within brackets.
+    pub line_break_escapes: bool,
+
+    /// If set, `\<` matches at the start of a word and `\>` at the end of
+    /// one, as in Vim and GNU regex; `\b{start}` and `\b{end}` are
+    /// synonyms. Unlike `\b`, which matches at either, `\<` requires a word
+    /// character after the position and none (or the start of the input)
+    /// before it, and `\>` the reverse. `\b{start-half}` and `\b{end-half}`
+    /// check only the side outside the word: no word character before the
+    /// position, or none after it. Other variants, like `\b{wb}`, are errors.
+    /// With the 'u' or 'v' flag, word characters are those of `\w` in that
+    /// mode. These escapes are not ECMAScript, so there is no flag letter for
+    /// them.
+    pub word_edge_escapes: bool,
+
+    /// If set, `.` matches any character including line terminators, as in
+    /// engines where that is the default. Unlike the 's' flag, this holds
+    /// throughout the pattern, even within a `(?-s:...)` group. If both are
+    /// set they agree, so `.` matches any character. `\N` is not affected.
+    /// This is not ECMAScript, so there is no flag letter for it.
+    pub dot_matches_all: bool,
 }
 
 impl Flags {
     /// Construct a Flags from a Unicode codepoints iterator, using JavaScript field names.
-    /// 'i' means to ignore case, 'm' means multiline, 'u' means unicode.
+    /// 'i' means to ignore case, 'm' means multiline, 'u' means unicode,
+    /// 'v' means unicode sets, 'y' means sticky, 'd' means has indices,
+    /// 'x' means extended.
     /// Note the 'g' flag implies a stateful regex and is not supported.
     /// Other flags are not implemented and are ignored.
+    /// Use [`str::parse`] to reject unknown or repeated flags instead.
     #[inline]
     pub fn new<T: Iterator<Item = u32>>(chars: T) -> Self {
         let mut result = Self::default();
-        for c in chars {
-            match to_char_sat(c) {
-                'm' => {
-                    result.multiline = true;
-                }
-                'i' => {
-                    result.icase = true;
-                }
-                's' => {
-                    result.dot_all = true;
-                }
-                'u' => {
-                    result.unicode = true;
-                }
-                _ => {
-                    // Silently skip unsupported flags.
-                }
-            }
+        // Silently skip unsupported flags.
+        for flag in chars.filter_map(|c| Flag::from_char(to_char_sat(c))) {
+            result.set(flag);
         }
         result
     }
+
+    /// Construct a Flags from typed flags.
+    /// Returns an error if a flag is repeated, or if the 'u' and 'v' flags
+    /// are combined.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{Flag, Flags};
+    ///   let flags = Flags::from_flag_set([Flag::CaseInsensitive, Flag::Unicode]).unwrap();
+    ///   assert_eq!(flags.to_string(), "iu");
+    ///   assert!(Flags::from_flag_set([Flag::Unicode, Flag::UnicodeSets]).is_err());
+    ///   ```
+    pub fn from_flag_set<I: IntoIterator<Item = Flag>>(flags: I) -> Result<Self, Error> {
+        let mut result = Self::default();
+        for flag in flags {
+            if result.set(flag) {
+                return parse::error(format!("Duplicate flag '{}'", flag.as_char()));
+            }
+        }
+        if result.unicode && result.unicode_sets {
+            return parse::error("The 'u' and 'v' flags may not be combined");
+        }
+        Ok(result)
+    }
+
+    /// Set the field for \p flag.
+    /// \return whether it was already set.
+    fn set(&mut self, flag: Flag) -> bool {
+        let field = match flag {
+            Flag::CaseInsensitive => &mut self.icase,
+            Flag::Multiline => &mut self.multiline,
+            Flag::DotAll => &mut self.dot_all,
+            Flag::Extended => &mut self.extended,
+            Flag::Unicode => &mut self.unicode,
+            Flag::UnicodeSets => &mut self.unicode_sets,
+            Flag::Sticky => &mut self.sticky,
+            Flag::HasIndices => &mut self.has_indices,
+        };
+        core::mem::replace(field, true)
+    }
+}
+
+impl FromStr for Flags {
+    type Err = Error;
+
+    /// Parse a flags string like "giu", rejecting unknown and repeated
+    /// flags, and the combination of 'u' and 'v'. Unlike [`Flags::new`],
+    /// this does not skip 'g'.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let flags = s
+            .chars()
+            .map(|c| match Flag::from_char(c) {
+                Some(flag) => Ok(flag),
+                None => parse::error(format!("Unknown flag '{}'", c)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_flag_set(flags)
+    }
+}
+
+/// Options which modify a single search by [`Regex::find_with`], without
+/// compiling the regex again.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MatchOptions<'a> {
+    /// If set, ASCII letters match regardless of case, much as with the 'i'
+    /// flag; see [`Regex::find_with`] for the differences.
+    pub case_insensitive: bool,
+
+    /// If set, the reported range of the whole match is widened so that it
+    /// does not split an extended grapheme cluster, such as a letter and its
+    /// combining marks or an emoji ZWJ sequence: its start moves back and its
+    /// end moves forward to the nearest cluster boundaries. The clusters are
+    /// those of UAX #29 for [`UNICODE_VERSION`].
+    ///
+    /// This does not change what the pattern matches, only the boundaries
+    /// reported afterwards, so the widened range need not match the pattern.
+    /// The ranges of capture groups are reported as found.
+    pub grapheme_align: bool,
+
+    /// If set, only the capture groups with these indexes, counted from 1 as
+    /// in [`Match::group`], are recorded, and the others are reported as not
+    /// participating. Recording fewer groups makes matching cheaper for
+    /// patterns with many groups. Groups which backreferences refer to are
+    /// still recorded while matching, so what matches does not change.
+    /// Indexes with no group are ignored.
+    pub wanted_groups: Option<&'a [usize]>,
+}
+
+/// Which characters are word characters for the word boundary assertions `\b`
+/// and `\B`, and for `\<` and `\>`; see [`RegexBuilder::word_boundary`].
+///
+/// By default these are the ASCII word characters, or the Unicode ones with
+/// the 'u' or 'v' flag. Other classes, including `\w`, are not affected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WordBoundary {
+    /// The ASCII word characters `[A-Za-z0-9_]`, as without the 'u' or 'v'
+    /// flag.
+    Ascii,
+
+    /// The Unicode word characters: letters, marks, decimal numbers and
+    /// connector punctuation, as with the 'u' or 'v' flag.
+    Unicode,
+
+    /// The code points of a set, for example the Unicode word characters
+    /// without those of right-to-left scripts, so that a boundary falls
+    /// between a run of Hebrew and an adjacent Latin word. Word boundaries
+    /// are then checked by a search of the set rather than by a table, and
+    /// patterns using them are always matched by backtracking.
+    Custom(CodePointSet),
+}
+
+impl WordBoundary {
+    /// Returns the word characters of this choice, for example to extend them
+    /// for [`WordBoundary::Custom`].
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{RegexBuilder, WordBoundary};
+    ///   let mut word_chars = WordBoundary::Ascii.word_chars();
+    ///   word_chars.add_one('-' as u32);
+    ///   let re = RegexBuilder::new(r"\bx-ray\b")
+    ///       .word_boundary(WordBoundary::Custom(word_chars))
+    ///       .build()
+    ///       .unwrap();
+    ///   assert!(re.find("an x-ray").is_some());
+    ///   assert!(re.find("an x-ray-like").is_none());
+    ///   ```
+    pub fn word_chars(&self) -> CodePointSet {
+        match self {
+            WordBoundary::Ascii => {
+                CodePointSet::from_sorted_disjoint_intervals(charclasses::WORD_CHARS.to_vec())
+            }
+            WordBoundary::Unicode => unicode::word_char_code_points(),
+            WordBoundary::Custom(cps) => cps.clone(),
+        }
+    }
+}
+
+/// The unit in which a [`Regex`] also reports the ranges of its matches
+/// within a `&str`, alongside their byte ranges; see
+/// [`RegexBuilder::offset_unit`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum OffsetUnit {
+    /// Byte offsets, which may be used to slice the text.
+    #[default]
+    Bytes,
+
+    /// UTF-16 code unit offsets, as in JavaScript, so that a char outside the
+    /// Basic Multilingual Plane counts as two.
+    Utf16,
+
+    /// Code point offsets, so that every char counts as one.
+    CodePoints,
+}
+
+impl OffsetUnit {
+    /// \return the length of \p text in this unit.
+    pub(crate) fn len_of(self, text: &str) -> usize {
+        // Count the bytes which begin a char, and for UTF-16 also those which
+        // begin a four byte char, which is a surrogate pair. This is much
+        // faster than decoding the chars.
+        let bytes = text.as_bytes();
+        match self {
+            OffsetUnit::Bytes => bytes.len(),
+            OffsetUnit::Utf16 => bytes
+                .iter()
+                .map(|&b| usize::from((b as i8) >= -0x40) + usize::from(b >= 0xF0))
+                .sum(),
+            OffsetUnit::CodePoints => bytes.iter().filter(|&&b| (b as i8) >= -0x40).count(),
+        }
+    }
+}
+
+/// Converts the byte offsets of matches within a text to an OffsetUnit.
+/// Offsets are measured from the end of the last match converted, in either
+/// direction, so converting the matches of a search in order counts each
+/// piece of the text about once, or once more where matches overlap.
+#[derive(Debug, Clone)]
+struct OffsetConverter {
+    unit: OffsetUnit,
+    /// A byte offset into the text, and the offset in units it corresponds
+    /// to.
+    cursor: (usize, usize),
+}
+
+impl OffsetConverter {
+    fn new(unit: OffsetUnit) -> Self {
+        Self {
+            unit,
+            cursor: (0, 0),
+        }
+    }
+
+    /// Convert byte offset \p pos of \p text.
+    fn offset(&self, text: &str, pos: usize) -> usize {
+        let (byte, units) = self.cursor;
+        if pos >= byte {
+            units + self.unit.len_of(&text[byte..pos])
+        } else {
+            units - self.unit.len_of(&text[pos..byte])
+        }
+    }
+
+    /// Convert the byte range \p r of \p text.
+    fn range(&self, text: &str, r: Range) -> Range {
+        let start = self.offset(text, r.start);
+        start..start + self.unit.len_of(&text[r])
+    }
+
+    /// Record the ranges of \p m, a match in \p text, in our unit alongside
+    /// its byte ranges, and move the cursor to its end.
+    fn record(&mut self, text: &str, mut m: Match) -> Match {
+        m.offset_unit = self.unit;
+        if self.unit == OffsetUnit::Bytes {
+            return m;
+        }
+        let range = self.range(text, m.range());
+        m.unit_ranges.clear();
+        m.unit_ranges.push(Some(range.clone()));
+        for cap in &m.captures {
+            let cap = cap.clone().map(|r| self.range(text, r));
+            m.unit_ranges.push(cap);
+        }
+        // The next match usually begins at or after the end of this one. One
+        // which begins earlier, as with find_overlapping_iter, or a capture in a
+        // lookbehind, is converted by counting back from the cursor.
+        self.cursor = (m.end(), range.end);
+        m
+    }
+}
+
+/// A single flag of a regex, for constructing [`Flags`] from typed values
+/// rather than a string; see [`Regex::with_flag_set`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Flag {
+    /// The 'i' flag; see [`Flags::icase`].
+    CaseInsensitive,
+
+    /// The 'm' flag; see [`Flags::multiline`].
+    Multiline,
+
+    /// The 's' flag; see [`Flags::dot_all`].
+    DotAll,
+
+    /// The 'x' flag; see [`Flags::extended`].
+    Extended,
+
+    /// The 'u' flag; see [`Flags::unicode`].
+    Unicode,
+
+    /// The 'v' flag; see [`Flags::unicode_sets`].
+    UnicodeSets,
+
+    /// The 'y' flag; see [`Flags::sticky`].
+    Sticky,
+
+    /// The 'd' flag; see [`Flags::has_indices`].
+    HasIndices,
+}
+
+impl Flag {
+    /// Returns the flag written as the letter `c` in a flags string, or None
+    /// if there is none.
+    pub fn from_char(c: char) -> Option<Flag> {
+        match c {
+            'i' => Some(Flag::CaseInsensitive),
+            'm' => Some(Flag::Multiline),
+            's' => Some(Flag::DotAll),
+            'x' => Some(Flag::Extended),
+            'u' => Some(Flag::Unicode),
+            'v' => Some(Flag::UnicodeSets),
+            'y' => Some(Flag::Sticky),
+            'd' => Some(Flag::HasIndices),
+            _ => None,
+        }
+    }
+
+    /// Returns the letter of the flag in a flags string.
+    pub fn as_char(self) -> char {
+        match self {
+            Flag::CaseInsensitive => 'i',
+            Flag::Multiline => 'm',
+            Flag::DotAll => 's',
+            Flag::Extended => 'x',
+            Flag::Unicode => 'u',
+            Flag::UnicodeSets => 'v',
+            Flag::Sticky => 'y',
+            Flag::HasIndices => 'd',
+        }
+    }
 }
 
 impl From<&str> for Flags {
@@ -88,6 +441,9 @@ impl From<&str> for Flags {
 
 impl fmt::Display for Flags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.has_indices {
+            f.write_str("d")?;
+        }
         if self.multiline {
             f.write_str("m")?;
         }
@@ -97,9 +453,18 @@ impl fmt::Display for Flags {
         if self.dot_all {
             f.write_str("s")?;
         }
+        if self.extended {
+            f.write_str("x")?;
+        }
         if self.unicode {
             f.write_str("u")?;
         }
+        if self.unicode_sets {
+            f.write_str("v")?;
+        }
+        if self.sticky {
+            f.write_str("y")?;
+        }
         Ok(())
     }
 }
@@ -108,18 +473,58 @@ impl fmt::Display for Flags {
 /// string.
 pub type Range = core::ops::Range<usize>;
 
-/// An iterator type which yields `Match`es found in a string.
-pub type Matches<'r, 't> = exec::Matches<backends::DefaultExecutor<'r, 't>>;
+/// The matches of a search, with byte offsets regardless of the offset unit.
+type RawMatches<'r, 't> = exec::Matches<backends::DefaultExecutor<'r, 't>>;
+
+/// An iterator type which yields `Match`es found in a string, which also
+/// report their ranges in the [`OffsetUnit`] of the regex.
+#[derive(Debug)]
+pub struct Matches<'r, 't> {
+    matches: RawMatches<'r, 't>,
+    text: &'t str,
+    offsets: OffsetConverter,
+}
+
+impl<'r, 't> Matches<'r, 't> {
+    fn new(re: &Regex, matches: RawMatches<'r, 't>, text: &'t str) -> Self {
+        Self {
+            matches,
+            text,
+            offsets: OffsetConverter::new(re.offset_unit),
+        }
+    }
+}
+
+impl<'r, 't> Iterator for Matches<'r, 't> {
+    type Item = Match;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let m = self.matches.next()?;
+        Some(self.offsets.record(self.text, m))
+    }
+}
 
 /// An iterator type which yields `Match`es found in a string, supporting ASCII
 /// only.
 pub type AsciiMatches<'r, 't> = exec::Matches<backends::DefaultAsciiExecutor<'r, 't>>;
 
 /// A Match represents a portion of a string which was found to match a Regex.
+///
+/// Groups are indexed as in JavaScript: index 0 is the whole match, and
+/// index `n` for `n >= 1` is the `n`th capture group of the pattern. Ranges
+/// are byte offsets into the searched text, except for matches produced by
+/// methods documented otherwise: [`Regex::find_utf16`] reports UTF-16 code
+/// units.
+///
+/// A match of a regex built with [`RegexBuilder::offset_unit`], or produced
+/// by [`Regex::find_iter_chars`], also reports its ranges in that unit,
+/// through [`Match::unit_range`] and [`Match::unit_group`], while its byte
+/// ranges still slice the text.
 #[derive(Debug, Clone)]
 pub struct Match {
-    /// The total range of the match. Note this may be empty, if the regex
-    /// matched an empty string.
+    /// The total byte range of the match. Note this may be empty, if the
+    /// regex matched an empty string.
     pub range: Range,
 
     /// The list of captures. This has length equal to the number of capturing
@@ -129,35 +534,234 @@ pub struct Match {
     /// enclosed range.
     pub captures: Vec<Option<Range>>,
 
-    pub(crate) named_captures: HashMap<String, u16>,
+    pub(crate) named_captures: GroupNames,
+
+    /// The unit of unit_ranges.
+    pub(crate) offset_unit: OffsetUnit,
+
+    /// The range of the total match followed by those of the captures, in
+    /// offset_unit, or empty if that is bytes.
+    pub(crate) unit_ranges: Vec<Option<Range>>,
 }
 
 impl Match {
     /// Access a group by index, using the convention of Python's group()
-    /// function. Index 0 is the total match, index 1 is the first capture
-    /// group.
+    /// function. Index 0 is the total match, which is always present, and
+    /// index 1 is the first capture group. Returns None if the group did not
+    /// participate in the match, or if there is no such group.
+    ///
+    /// The range is in bytes unless the match is documented otherwise; see
+    /// [`Match`].
     #[inline]
     pub fn group(&self, idx: usize) -> Option<Range> {
-        if idx == 0 {
-            Some(self.range.clone())
-        } else {
-            self.captures[idx - 1].clone()
+        match idx {
+            0 => Some(self.range.clone()),
+            _ => self.captures.get(idx - 1)?.clone(),
         }
     }
 
+    /// Returns the number of capture groups, not counting the whole match at
+    /// index 0. This is [`Regex::group_count`] of the regex which produced
+    /// the match, so the valid indexes of [`Match::group`] are
+    /// `0..=group_count()`.
+    #[inline]
+    pub fn group_count(&self) -> usize {
+        self.captures.len()
+    }
+
     /// Access a named group by name.
     #[inline]
     pub fn named_group(&self, name: &str) -> Option<Range> {
-        let idx = *self.named_captures.get(name)?;
+        let idx = self.named_captures.get(name)?;
         self.captures[idx as usize].clone()
     }
 
-    /// Return an iterator over the named groups of a Match.
+    /// Returns the byte range of the group with index \p idx, using the same
+    /// convention as [`Match::group`]: index 0 is the total match.
+    /// This corresponds to the `indices` array produced by JavaScript's 'd'
+    /// flag. Returns None if the group did not participate in the match, or
+    /// if there is no such group.
+    #[inline]
+    pub fn group_indices(&self, idx: usize) -> Option<Range> {
+        match idx {
+            0 => Some(self.range()),
+            _ => self.captures.get(idx - 1)?.clone(),
+        }
+    }
+
+    /// Returns the byte range of the named group \p name, corresponding to
+    /// `indices.groups` in JavaScript. Returns None if the group did not
+    /// participate in the match, or if there is no such group.
     #[inline]
-    pub fn named_groups(&self) -> NamedGroups {
+    pub fn named_group_indices(&self, name: &str) -> Option<Range> {
+        let idx = self.named_captures.get(name)?;
+        self.group_indices(idx as usize + 1)
+    }
+
+    /// Return an iterator over the named groups of a Match, in the order in
+    /// which they are declared in the pattern. Groups which did not
+    /// participate in the match produce None.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<y>\d{4})-(?<m>\d{2})(?:-(?<d>\d{2}))?").unwrap();
+    ///   let m = re.find("2020-05").unwrap();
+    ///   let groups: Vec<_> = m.named_groups().collect();
+    ///   assert_eq!(groups, vec![("y", Some(0..4)), ("m", Some(5..7)), ("d", None)]);
+    ///   ```
+    #[inline]
+    pub fn named_groups(&self) -> NamedGroups<'_> {
         NamedGroups::new(self)
     }
 
+    /// Returns the text of the whole match within `text`, which must be the
+    /// string that was searched. The result borrows from `text`, not from
+    /// the match.
+    ///
+    /// This slices `text` by the byte range of the match, so it panics if
+    /// `text` is a different string which is too short, or whose char
+    /// boundaries differ.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<word>\w+)(\d)?").unwrap();
+    ///   let text = "¡hola!";
+    ///   let m = re.find(text).unwrap();
+    ///   assert_eq!(m.as_str(text), "hola");
+    ///   assert_eq!(m.group_str(text, 1), Some("hola"));
+    ///   assert_eq!(m.group_str(text, 2), None);
+    ///   assert_eq!(m.named_group_str(text, "word"), Some("hola"));
+    ///   ```
+    #[inline]
+    pub fn as_str<'t>(&self, text: &'t str) -> &'t str {
+        &text[self.range()]
+    }
+
+    /// Returns the text of the group with index `idx` within `text`, which
+    /// must be the string that was searched, using the same convention as
+    /// [`Match::group`]. Returns None if the group did not participate in the
+    /// match, or if there is no such group. This panics on a mismatched
+    /// `text` as [`Match::as_str`] does.
+    #[inline]
+    pub fn group_str<'t>(&self, text: &'t str, idx: usize) -> Option<&'t str> {
+        self.group(idx).map(|r| &text[r])
+    }
+
+    /// Returns the text of the named group `name` within `text`, which must
+    /// be the string that was searched. Returns None if the group did not
+    /// participate in the match, or if there is no such group. This panics on
+    /// a mismatched `text` as [`Match::as_str`] does.
+    #[inline]
+    pub fn named_group_str<'t>(&self, text: &'t str, name: &str) -> Option<&'t str> {
+        self.named_group(name).map(|r| &text[r])
+    }
+
+    /// Return an iterator over the named groups of a Match, like
+    /// [`Match::named_groups`], but producing the matched text of each group
+    /// within `text`, which must be the string that was searched.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<key>\w+)=(?<value>\w*)").unwrap();
+    ///   let text = "name=regress";
+    ///   let m = re.find(text).unwrap();
+    ///   let groups: Vec<_> = m.named_group_strs(text).collect();
+    ///   assert_eq!(groups, vec![("key", Some("name")), ("value", Some("regress"))]);
+    ///   ```
+    #[inline]
+    pub fn named_group_strs<'m, 't>(
+        &'m self,
+        text: &'t str,
+    ) -> impl Iterator<Item = (&'m str, Option<&'t str>)> + 'm
+    where
+        't: 'm,
+    {
+        self.named_groups()
+            .map(move |(name, range)| (name, range.map(|r| &text[r])))
+    }
+
+    /// Copies the text of the match and of each of its groups out of `text`,
+    /// which must be the string that was searched, into an [`OwnedMatch`].
+    /// This does not borrow `text`, so it may outlive it or be sent to
+    /// another thread. This panics on a mismatched `text` as
+    /// [`Match::as_str`] does.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<key>\w+)=(?<value>\w+)?").unwrap();
+    ///   let owned = {
+    ///       let text = String::from("name=");
+    ///       re.find(&text).unwrap().into_owned(&text)
+    ///   };
+    ///   assert_eq!(owned.as_str(), "name=");
+    ///   assert_eq!(owned.named_group("key"), Some("name"));
+    ///   assert_eq!(owned.named_group("value"), None);
+    ///   ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_owned(&self, text: &str) -> OwnedMatch {
+        OwnedMatch {
+            range: self.range(),
+            groups: self.groups().map(|r| r.map(|r| text[r].into())).collect(),
+            named_captures: self.named_captures.clone(),
+        }
+    }
+
+    /// Returns the unit of [`Match::unit_range`] and [`Match::unit_group`],
+    /// which is that set with [`RegexBuilder::offset_unit`] for the regex
+    /// which produced the match.
+    #[inline]
+    pub fn offset_unit(&self) -> OffsetUnit {
+        self.offset_unit
+    }
+
+    /// Returns the range of the match in its [`OffsetUnit`], such as the
+    /// UTF-16 offsets which JavaScript reports. This is [`Match::range`] if
+    /// the unit is bytes.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{OffsetUnit, RegexBuilder};
+    ///   let re = RegexBuilder::new(r"=(.)").offset_unit(OffsetUnit::Utf16).build().unwrap();
+    ///   let text = "\u{1F600}=\u{E9}";
+    ///   let m = re.find(text).unwrap();
+    ///   assert_eq!((m.unit_range(), m.unit_group(1)), (2..4, Some(3..4)));
+    ///   assert_eq!((m.range(), m.group(1)), (4..7, Some(5..7)));
+    ///   assert_eq!(m.group_str(text, 1), Some("\u{E9}"));
+    ///   ```
+    #[inline]
+    pub fn unit_range(&self) -> Range {
+        self.unit_group(0)
+            .expect("Total match should always be present")
+    }
+
+    /// Returns the range of the group with index `idx` in the [`OffsetUnit`]
+    /// of the match, using the same convention as [`Match::group`]. This is
+    /// [`Match::group`] if the unit is bytes.
+    #[inline]
+    pub fn unit_group(&self, idx: usize) -> Option<Range> {
+        match self.offset_unit {
+            OffsetUnit::Bytes => self.group(idx),
+            _ => self.unit_ranges.get(idx)?.clone(),
+        }
+    }
+
+    /// Returns the range of the named group `name` in the [`OffsetUnit`] of
+    /// the match. This is [`Match::named_group`] if the unit is bytes.
+    #[inline]
+    pub fn unit_named_group(&self, name: &str) -> Option<Range> {
+        let idx = self.named_captures.get(name)?;
+        self.unit_group(idx as usize + 1)
+    }
+
     /// Returns the range over the starting and ending byte offsets of the match in the haystack.
     ///
     /// This is a convenience function to work around
@@ -167,6 +771,23 @@ impl Match {
         self.range.clone()
     }
 
+    /// Move the match and its captures \p base bytes later, for a match
+    /// found within a slice of the text beginning at \p base.
+    fn offset_by(mut self, base: usize) -> Match {
+        let shift = |r: &mut Range| *r = r.start + base..r.end + base;
+        shift(&mut self.range);
+        self.captures.iter_mut().flatten().for_each(shift);
+        self
+    }
+
+    /// Move the ranges of the match in its offset unit \p base units later,
+    /// as offset_by does for its byte ranges.
+    fn offset_units_by(mut self, base: usize) -> Match {
+        let shift = |r: &mut Range| *r = r.start + base..r.end + base;
+        self.unit_ranges.iter_mut().flatten().for_each(shift);
+        self
+    }
+
     /// Returns the starting byte offset of the match in the haystack.
     #[inline]
     pub fn start(&self) -> usize {
@@ -180,11 +801,62 @@ impl Match {
     }
 
     /// Return an iterator over a Match. The first returned value is the total
-    /// match, and subsequent values represent the capture groups.
+    /// match, and subsequent values represent the capture groups. This is
+    /// the same as [`Match::all_groups`].
+    #[inline]
+    pub fn groups(&self) -> Groups<'_> {
+        Groups::new(self)
+    }
+
+    /// Return an iterator over every group of a Match in index order, from
+    /// the whole match at index 0 to the last capture group at index
+    /// [`Match::group_count`]. Each item is [`Match::group`] of its index, so
+    /// the first is always Some and the others are None for groups which did
+    /// not participate. The ranges are in the units of the match; see
+    /// [`Match`].
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(a)|(b)").unwrap();
+    ///   let m = re.find("xb").unwrap();
+    ///   let groups: Vec<_> = m.all_groups().collect();
+    ///   assert_eq!(groups, vec![Some(1..2), None, Some(1..2)]);
+    ///   assert_eq!(groups.len(), m.group_count() + 1);
+    ///   ```
     #[inline]
-    pub fn groups(&self) -> Groups {
+    pub fn all_groups(&self) -> Groups<'_> {
         Groups::new(self)
     }
+
+    /// Append the replacement template \p template to \p dst, with its `$`
+    /// substitutions resolved against this match of \p input, the text that
+    /// was searched. This is the expansion performed by [`Regex::replace`]
+    /// for each match, and supports the same substitutions; it is useful
+    /// for writing a replacement loop by hand.
+    ///
+    /// `$$` inserts a single `$`. A `$<name>` reference to a name which is
+    /// not a group expands to the empty string, as does a group which did
+    /// not participate in the match. If the regex has no named groups,
+    /// `$<` is literal.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<key>\w+)=(\w+)").unwrap();
+    ///   let text = "a=1 b=2";
+    ///   let mut dst = String::new();
+    ///   for m in re.find_iter(text) {
+    ///       m.expand(text, "[$<key>: $2$$$<nope>] ", &mut dst);
+    ///   }
+    ///   assert_eq!(dst, "[a: 1$] [b: 2$] ");
+    ///   ```
+    pub fn expand(&self, input: &str, template: &str, dst: &mut String) {
+        // Writing to a String cannot fail.
+        let _ = write_replacement(self, input, template, dst);
+    }
 }
 
 /// An iterator over the capture groups of a [`Match`]
@@ -224,8 +896,16 @@ impl<'m> Iterator for Groups<'m> {
             None
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.max - self.i;
+        (len, Some(len))
+    }
 }
 
+impl<'m> ExactSizeIterator for Groups<'m> {}
+
 /// An iterator over the named capture groups of a [`Match`]
 ///
 /// This struct is created by the [`named_groups`] method on [`Match`].
@@ -235,16 +915,13 @@ impl<'m> Iterator for Groups<'m> {
 #[derive(Clone)]
 pub struct NamedGroups<'m> {
     mat: &'m Match,
-    named_groups_iter: Iter<'m, String, u16>,
+    i: usize,
 }
 
 impl<'m> NamedGroups<'m> {
     #[inline]
     fn new(mat: &'m Match) -> Self {
-        Self {
-            mat,
-            named_groups_iter: mat.named_captures.iter(),
-        }
+        Self { mat, i: 0 }
     }
 }
 
@@ -253,139 +930,3266 @@ impl<'m> Iterator for NamedGroups<'m> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some((name, idx)) = self.named_groups_iter.next() {
-            if let Some(r) = self.mat.captures.get(*idx as usize) {
-                match r {
-                    Some(range) => Some((name.as_str(), Some(range.clone()))),
-                    None => Some((name.as_str(), None)),
-                }
-            } else {
-                Some((name.as_str(), None))
-            }
-        } else {
-            None
-        }
+        let (name, idx) = self.mat.named_captures.entry(self.i)?;
+        self.i += 1;
+        let range = self.mat.captures.get(idx as usize).cloned().flatten();
+        Some((name, range))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.mat.named_captures.len() - self.i;
+        (remaining, Some(remaining))
     }
 }
 
-/// A Regex is the compiled version of a pattern.
-#[derive(Debug, Clone)]
-pub struct Regex {
-    cr: CompiledRegex,
+impl<'m> ExactSizeIterator for NamedGroups<'m> {}
+
+/// The ranges of a match and its capture groups, independent of the input.
+///
+/// Captures owns only byte offsets, so it may be stored freely and later used
+/// to slice the original string. It may be produced by
+/// [`Regex::captures`] or [`Regex::captures_iter`], or converted from a
+/// [`Match`]. An empty Captures may be created with [`Captures::new`] and
+/// filled repeatedly by [`Regex::find_into`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Captures {
+    /// The total match at index 0, followed by each capture group.
+    groups: Vec<Option<Range>>,
+    named_captures: GroupNames,
+
+    /// The unit of unit_groups.
+    offset_unit: OffsetUnit,
+
+    /// The groups in offset_unit, or empty if that is bytes.
+    unit_groups: Vec<Option<Range>>,
+
+    /// The storage of the search of Regex::find_into, kept for the next.
+    scratch: classicalbacktrack::Scratch,
 }
 
-impl From<CompiledRegex> for Regex {
-    fn from(cr: CompiledRegex) -> Self {
-        Self { cr }
+impl Captures {
+    /// Create an empty Captures, for use with [`Regex::find_into`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
     }
-}
 
-impl Regex {
-    /// Construct a regex by parsing `pattern` using the default flags.
-    /// An Error may be returned if the syntax is invalid.
-    /// Note that this is rather expensive; prefer to cache a Regex which is
-    /// intended to be used more than once.
+    /// Access a group by index. Index 0 is the total match, index 1 is the
+    /// first capture group. Returns None if the group did not participate in
+    /// the match, or if there is no such group.
     #[inline]
-    pub fn new(pattern: &str) -> Result<Regex, Error> {
-        Self::with_flags(pattern, Flags::default())
+    pub fn get(&self, idx: usize) -> Option<Range> {
+        self.groups.get(idx)?.clone()
     }
 
-    /// Construct a regex by parsing `pattern` with `flags`.
-    /// An Error may be returned if the syntax is invalid.
-    //
-    /// Note it is preferable to cache a Regex which is intended to be used more
-    /// than once, as the parse may be expensive. For example:
+    /// Access a named group by name. Returns None if the group did not
+    /// participate in the match, or if there is no such group.
     #[inline]
-    pub fn with_flags<F>(pattern: &str, flags: F) -> Result<Regex, Error>
+    pub fn name(&self, name: &str) -> Option<Range> {
+        let idx = self.named_captures.get(name)?;
+        self.get(idx as usize + 1)
+    }
+
+    /// Returns the range of the total match.
+    #[inline]
+    pub fn range(&self) -> Range {
+        self.groups[0]
+            .clone()
+            .expect("Total match should always be present")
+    }
+
+    /// Return an iterator over the groups. The first returned value is the
+    /// total match, and subsequent values represent the capture groups.
+    #[inline]
+    pub fn iter(&self) -> core::iter::Cloned<core::slice::Iter<'_, Option<Range>>> {
+        self.groups.iter().cloned()
+    }
+
+    /// Returns the unit of [`Captures::unit_get`], as for
+    /// [`Match::offset_unit`].
+    #[inline]
+    pub fn offset_unit(&self) -> OffsetUnit {
+        self.offset_unit
+    }
+
+    /// Access a group by index as [`Captures::get`] does, but with its range
+    /// in the [`OffsetUnit`] of the regex which produced it, as for
+    /// [`Match::unit_group`].
+    #[inline]
+    pub fn unit_get(&self, idx: usize) -> Option<Range> {
+        match self.offset_unit {
+            OffsetUnit::Bytes => self.get(idx),
+            _ => self.unit_groups.get(idx)?.clone(),
+        }
+    }
+}
+
+impl From<Match> for Captures {
+    fn from(m: Match) -> Self {
+        let mut groups = Vec::with_capacity(m.captures.len() + 1);
+        groups.push(Some(m.range));
+        groups.extend(m.captures);
+        Captures {
+            groups,
+            named_captures: m.named_captures,
+            offset_unit: m.offset_unit,
+            unit_groups: m.unit_ranges,
+            scratch: Default::default(),
+        }
+    }
+}
+
+/// The text of a match and its capture groups, copied out of the searched
+/// string.
+///
+/// Unlike a [`Match`] or [`Captures`], which hold only ranges to slice the
+/// searched string with, an OwnedMatch holds the text itself, so it may be
+/// kept or sent to another thread after the string is gone. It is produced
+/// by [`Match::into_owned`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedMatch {
+    /// The byte range of the total match in the searched string.
+    range: Range,
+    /// The total match at index 0, followed by each capture group.
+    groups: Vec<Option<String>>,
+    named_captures: GroupNames,
+}
+
+impl OwnedMatch {
+    /// Returns the text of the total match.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.groups[0]
+            .as_deref()
+            .expect("Total match should always be present")
+    }
+
+    /// Returns the byte range which the total match spanned in the searched
+    /// string.
+    #[inline]
+    pub fn range(&self) -> Range {
+        self.range.clone()
+    }
+
+    /// Access the text of a group by index, using the same convention as
+    /// [`Match::group`]: index 0 is the total match. Returns None if the
+    /// group did not participate in the match, or if there is no such group.
+    #[inline]
+    pub fn group(&self, idx: usize) -> Option<&str> {
+        self.groups.get(idx)?.as_deref()
+    }
+
+    /// Returns the number of capture groups, not counting the whole match at
+    /// index 0.
+    #[inline]
+    pub fn group_count(&self) -> usize {
+        self.groups.len() - 1
+    }
+
+    /// Access the text of a named group by name. Returns None if the group
+    /// did not participate in the match, or if there is no such group.
+    #[inline]
+    pub fn named_group(&self, name: &str) -> Option<&str> {
+        let idx = self.named_captures.get(name)?;
+        self.group(idx as usize + 1)
+    }
+
+    /// Return an iterator over the text of the groups. The first returned
+    /// value is the total match, and subsequent values represent the capture
+    /// groups.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Option<&str>> + '_ {
+        self.groups.iter().map(|g| g.as_deref())
+    }
+
+    /// Return an iterator over the named groups and their text, in the order
+    /// in which they are declared in the pattern. Groups which did not
+    /// participate in the match produce None.
+    #[inline]
+    pub fn named_groups(&self) -> impl ExactSizeIterator<Item = (&str, Option<&str>)> + '_ {
+        self.named_captures
+            .iter()
+            .map(move |(name, idx)| (name, self.group(idx as usize + 1)))
+    }
+}
+
+/// An iterator over the [`Captures`] of non-overlapping matches in a string.
+///
+/// This struct is created by the [`captures_iter`] method on [`Regex`].
+///
+/// [`Regex`]: ../struct.Regex.html
+/// [`captures_iter`]: ../struct.Regex.html#method.captures_iter
+#[derive(Debug)]
+pub struct CapturesIter<'r, 't> {
+    matches: Matches<'r, 't>,
+}
+
+impl<'r, 't> Iterator for CapturesIter<'r, 't> {
+    type Item = Captures;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.matches.next().map(Captures::from)
+    }
+}
+
+/// An iterator over the matches within the valid UTF-8 runs of a byte
+/// string.
+///
+/// This struct is created by the [`find_iter_utf8_runs`] method on
+/// [`Regex`].
+///
+/// [`Regex`]: ../struct.Regex.html
+/// [`find_iter_utf8_runs`]: ../struct.Regex.html#method.find_iter_utf8_runs
+#[derive(Debug)]
+pub struct Utf8RunMatches<'r, 't> {
+    re: &'r Regex,
+    bytes: &'t [u8],
+    /// The offset of the current run in bytes and in the offset unit of the
+    /// regex, its text, and the matches within it.
+    run: Option<(usize, usize, &'t str, RawMatches<'r, 't>)>,
+    /// The offset of the next run, or None if there are no more.
+    next_run: Option<usize>,
+    /// The offset of the next run in the offset unit of the regex, in which
+    /// each invalid sequence counts as the U+FFFD which would replace it.
+    next_run_units: usize,
+    /// Converts the offsets of matches in the current run.
+    offsets: OffsetConverter,
+}
+
+impl<'r, 't> Iterator for Utf8RunMatches<'r, 't> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((base, base_units, run, matches)) = &mut self.run {
+                if let Some(m) = matches.next() {
+                    let m = self.offsets.record(run, m);
+                    return Some(m.offset_by(*base).offset_units_by(*base_units));
+                }
+                self.next_run_units = *base_units + self.offsets.offset(run, run.len()) + 1;
+                self.run = None;
+            }
+            // Take the longest valid prefix of the rest of the bytes as the
+            // next run, and skip the invalid sequence after it.
+            let start = self.next_run?;
+            let rest = &self.bytes[start..];
+            let run = match core::str::from_utf8(rest) {
+                Ok(run) => {
+                    self.next_run = None;
+                    run
+                }
+                Err(err) => {
+                    let len = err.valid_up_to();
+                    let invalid_len = err.error_len().unwrap_or(rest.len() - len);
+                    self.next_run = Some(start + len + invalid_len);
+                    // The prefix was just validated.
+                    core::str::from_utf8(&rest[..len]).unwrap_or_default()
+                }
+            };
+            self.run = Some((start, self.next_run_units, run, self.re.raw_matches(run)));
+            self.offsets = OffsetConverter::new(self.re.offset_unit);
+        }
+    }
+}
+
+/// UTF-16 text which has been prepared for searching with a regex, so that
+/// it is transcoded once however many matches are found in it.
+///
+/// This is created by the [`prepare_utf16`] method on [`Regex`], and searched
+/// with [`find_iter_utf16`].
+///
+/// [`Regex`]: ../struct.Regex.html
+/// [`prepare_utf16`]: ../struct.Regex.html#method.prepare_utf16
+/// [`find_iter_utf16`]: ../struct.Regex.html#method.find_iter_utf16
+#[derive(Debug, Clone)]
+pub struct PreparedUtf16<'t> {
+    units: &'t [u16],
+    /// Whether surrogate pairs were combined, as for a regex with the 'u'
+    /// flag.
+    pair_surrogates: bool,
+    text: indexing::Utf16Text,
+}
+
+impl<'t> PreparedUtf16<'t> {
+    /// Returns the code units which were prepared.
+    #[inline]
+    pub fn units(&self) -> &'t [u16] {
+        self.units
+    }
+}
+
+/// An iterator over non-overlapping matches in UTF-16 text, whose ranges are
+/// code unit indexes.
+///
+/// This struct is created by the [`find_iter_utf16`] method on [`Regex`].
+///
+/// [`Regex`]: ../struct.Regex.html
+/// [`find_iter_utf16`]: ../struct.Regex.html#method.find_iter_utf16
+#[derive(Debug)]
+pub struct Utf16Matches<'r, 'p> {
+    re: &'r Regex,
+    text: Cow<'p, indexing::Utf16Text>,
+    program: Option<Arc<CompiledRegex>>,
+    /// The byte offset of the transcoded text at which to search next, or
+    /// None if finished.
+    start: Option<usize>,
+}
+
+impl<'r, 'p> Iterator for Utf16Matches<'r, 'p> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.start?;
+        let m = backends::find_wtf8(self.re, self.program.as_deref(), &self.text, start, None)
+            .unwrap_or(None);
+        let Some(m) = m else {
+            self.start = None;
+            return None;
+        };
+        // Step over an empty match, so that it is not found again.
+        self.start = if m.end() > m.start() {
+            Some(m.end())
+        } else {
+            self.text.next_boundary(m.end())
+        };
+        Some(backends::utf16_match(&self.text, m))
+    }
+}
+
+/// An iterator over the leftmost match in each line of a string, with the
+/// line's byte range.
+///
+/// This struct is created by the [`find_line_iter`] method on [`Regex`].
+///
+/// [`Regex`]: ../struct.Regex.html
+/// [`find_line_iter`]: ../struct.Regex.html#method.find_line_iter
+#[derive(Debug)]
+pub struct LineMatches<'r, 't> {
+    re: &'r Regex,
+    text: &'t str,
+    /// The offset of the next line, or None if there are no more.
+    next_line: Option<usize>,
+    offsets: OffsetConverter,
+}
+
+impl<'r, 't> Iterator for LineMatches<'r, 't> {
+    type Item = (Range, Match);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.next_line?;
+            let rest = &self.text[start..];
+            let len = match rest.find(['\n', '\r', '\u{2028}', '\u{2029}']) {
+                Some(len) => {
+                    let terminator = if rest[len..].starts_with("\r\n") {
+                        2
+                    } else {
+                        rest[len..].chars().next().map_or(1, char::len_utf8)
+                    };
+                    // A terminator at the end of the text does not begin
+                    // another line.
+                    let next = start + len + terminator;
+                    self.next_line = (next < self.text.len()).then_some(next);
+                    len
+                }
+                None => {
+                    self.next_line = None;
+                    rest.len()
+                }
+            };
+            if let Some(m) = self.re.raw_matches(&rest[..len]).next() {
+                let m = self.offsets.record(self.text, m.offset_by(start));
+                return Some((start..start + len, m));
+            }
+        }
+    }
+}
+
+/// An iterator over possibly overlapping matches in a string.
+///
+/// This struct is created by the [`find_overlapping_iter`] method on
+/// [`Regex`].
+///
+/// [`Regex`]: ../struct.Regex.html
+/// [`find_overlapping_iter`]: ../struct.Regex.html#method.find_overlapping_iter
+#[derive(Debug)]
+pub struct OverlappingMatches<'r, 't> {
+    matches: RawMatches<'r, 't>,
+    text: &'t str,
+    offsets: OffsetConverter,
+}
+
+impl<'r, 't> Iterator for OverlappingMatches<'r, 't> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let m = self.matches.next()?;
+        // Resume one code point after the start of the match. At the end of
+        // the text there is nowhere left to start.
+        let start = m.start();
+        match self.text[start..].chars().next() {
+            Some(c) => self.matches.set_start(start + c.len_utf8()),
+            None => self.matches.set_start(self.text.len() + 1),
+        }
+        Some(self.offsets.record(self.text, m))
+    }
+}
+
+/// An iterator over non-overlapping matches in a string, which also report
+/// their ranges in code points.
+///
+/// This struct is created by the [`find_iter_chars`] method on [`Regex`].
+///
+/// [`Regex`]: ../struct.Regex.html
+/// [`find_iter_chars`]: ../struct.Regex.html#method.find_iter_chars
+#[derive(Debug)]
+pub struct CharMatches<'r, 't> {
+    matches: RawMatches<'r, 't>,
+    text: &'t str,
+    offsets: OffsetConverter,
+}
+
+impl<'r, 't> Iterator for CharMatches<'r, 't> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let m = self.matches.next()?;
+        Some(self.offsets.record(self.text, m))
+    }
+}
+
+/// An iterator over the substrings of a string which are separated by matches
+/// of a [`Regex`].
+///
+/// This struct is created by the [`split`] method on [`Regex`].
+///
+/// [`Regex`]: ../struct.Regex.html
+/// [`split`]: ../struct.Regex.html#method.split
+#[derive(Debug)]
+pub struct Split<'r, 't> {
+    matches: RawMatches<'r, 't>,
+    text: &'t str,
+    /// The start of the next piece to yield, or None if finished.
+    last_end: Option<usize>,
+    /// A separator match whose capture groups are still being yielded.
+    pending: Option<(Match, usize)>,
+}
+
+impl<'r, 't> Split<'r, 't> {
+    fn new(re: &'r Regex, text: &'t str) -> Self {
+        let mut last_end = Some(0);
+        if text.is_empty() && re.find(text).is_some() {
+            // An empty string matched by the separator produces no pieces.
+            last_end = None;
+        }
+        // As in JavaScript, the sticky flag does not affect splitting.
+        let executor = <backends::DefaultExecutor as exec::Executor>::new(&re.cr, text);
+        Self {
+            matches: exec::Matches::new(executor, 0),
+            text,
+            last_end,
+            pending: None,
+        }
+    }
+}
+
+impl<'r, 't> Iterator for Split<'r, 't> {
+    type Item = &'t str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Yield any captures from the last separator.
+        if let Some((m, idx)) = &mut self.pending {
+            if *idx <= m.captures.len() {
+                let group = m.group(*idx);
+                *idx += 1;
+                return Some(group.map_or("", |r| &self.text[r]));
+            }
+            self.pending = None;
+        }
+
+        let start = self.last_end?;
+        // Skip separators which are empty at the start of the piece, or which
+        // begin at the end of the text. This mirrors ES2022 22.2.5.14
+        // RegExp.prototype [ @@split ].
+        let next_sep = self
+            .matches
+            .by_ref()
+            .take_while(|m| m.start() < self.text.len())
+            .find(|m| m.end() != start);
+        match next_sep {
+            Some(m) => {
+                let piece = &self.text[start..m.start()];
+                self.last_end = Some(m.end());
+                if !m.captures.is_empty() {
+                    self.pending = Some((m, 1));
+                }
+                Some(piece)
+            }
+            None => {
+                self.last_end = None;
+                Some(&self.text[start..])
+            }
+        }
+    }
+}
+
+/// The error returned by [`Regex::find_with_budget`] when the match attempt
+/// takes more steps than its budget allows.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BudgetExceeded;
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Match step budget exceeded")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BudgetExceeded {}
+
+/// The pattern a regex was compiled from, which the methods that inspect its
+/// IR parse again when they are called, rather than keeping the IR.
+#[derive(Debug, Clone)]
+enum Pattern {
+    /// The pattern source.
+    Source,
+    /// The code points of a pattern which are not all chars, so that the
+    /// source, in which they are replaced by U+FFFD, differs from it.
+    CodePoints(Arc<[u32]>),
+    /// The IR a regex was built from by [`Regex::from_ir`], which stands in
+    /// for its source.
+    #[cfg(feature = "ir-visitor")]
+    Ir(Arc<Ir>),
+    /// The pattern of a regex wrapped from a compiled or deserialized
+    /// program, which is not known to match its source.
+    Unknown,
+}
+
+/// A Regex is the compiled version of a pattern.
+/// The compiled program is immutable and shared between clones, so cloning a
+/// Regex is cheap. All matching state is created per search, so a Regex may be
+/// used from multiple threads at once.
+///
+/// Regexes compare equal, and hash alike, if they have the same source, flags
+/// and settings; see the [`PartialEq`] impl.
+#[derive(Debug, Clone)]
+pub struct Regex {
+    cr: Arc<CompiledRegex>,
+    source: Arc<str>,
+    step_budget: Option<usize>,
+    offset_unit: OffsetUnit,
+    pattern: Pattern,
+}
+
+impl From<CompiledRegex> for Regex {
+    /// Wrap an already-compiled program. Its pattern source is unknown, so
+    /// [`Regex::as_str`] returns the empty string.
+    fn from(cr: CompiledRegex) -> Self {
+        Self {
+            cr: Arc::new(cr),
+            source: Arc::from(""),
+            step_budget: None,
+            offset_unit: OffsetUnit::Bytes,
+            pattern: Pattern::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Regex {
+    /// Prints the pattern source. The alternate form `{:#}` prints the source
+    /// and flags in JavaScript literal syntax, for example `/a+b/iu`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "/{}/{}", self.source, self.cr.flags)
+        } else {
+            f.write_str(&self.source)
+        }
+    }
+}
+
+/// Regexes are equal if their sources, as returned by [`Regex::as_str`], their
+/// flags, and their settings made with [`RegexBuilder`], like the
+/// [`WordBoundary`], the step budget and the [`OffsetUnit`], are equal. The
+/// compiled programs are not compared, so this does not depend on how the
+/// optimizer treats a pattern.
+///
+/// The comparison is syntactic rather than semantic: `abc` and `(?:abc)`
+/// are not equal, though they match the same way. To find regexes which
+/// differ only in spelling, compare their [`Regex::canonical_key`] instead.
+///
+/// The pattern of a regex wrapped from a compiled program with `From`, or
+/// made by [`Regex::from_serialized`], is not known, so the programs of these
+/// are compared as well, and they are never equal to a regex parsed from its
+/// pattern.
+///
+/// Example:
+///
+///  ```rust
+///   use regress::Regex;
+///   use std::collections::HashSet;
+///   let set: HashSet<Regex> = ["a+", "a+", "(?:a+)"]
+///       .into_iter()
+///       .map(|pattern| Regex::new(pattern).unwrap())
+///       .collect();
+///   assert_eq!(set.len(), 2);
+///   assert_ne!(Regex::new("a").unwrap(), Regex::with_flags("a", "i").unwrap());
+///   ```
+impl PartialEq for Regex {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "ir-visitor")]
+        if self.built_ir() != other.built_ir() {
+            return false;
+        }
+        self.source == other.source
+            && self.cr.flags == other.cr.flags
+            && self.cr.word_boundary == other.cr.word_boundary
+            && self.step_budget == other.step_budget
+            && self.offset_unit == other.offset_unit
+            && self.unknown_pattern_program() == other.unknown_pattern_program()
+    }
+}
+
+impl Eq for Regex {}
+
+impl Hash for Regex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.source.hash(state);
+        self.cr.flags.hash(state);
+        self.cr.word_boundary.hash(state);
+        self.step_budget.hash(state);
+        self.offset_unit.hash(state);
+        self.unknown_pattern_program().hash(state);
+        #[cfg(feature = "ir-visitor")]
+        self.built_ir().hash(state);
+    }
+}
+
+impl Regex {
+    /// Construct a regex by parsing `pattern` using the default flags.
+    /// An Error may be returned if the syntax is invalid.
+    /// Note that this is rather expensive; prefer to cache a Regex which is
+    /// intended to be used more than once.
+    #[inline]
+    pub fn new(pattern: &str) -> Result<Regex, Error> {
+        Self::with_flags(pattern, Flags::default())
+    }
+
+    /// Construct a regex by parsing `pattern` with `flags`.
+    /// An Error may be returned if the syntax is invalid.
+    //
+    /// Note it is preferable to cache a Regex which is intended to be used more
+    /// than once, as the parse may be expensive. For example:
+    #[inline]
+    pub fn with_flags<F>(pattern: &str, flags: F) -> Result<Regex, Error>
     where
         F: Into<Flags>,
     {
         Self::from_unicode(pattern.chars().map(u32::from), flags)
     }
 
-    /// Construct a regex by parsing `pattern` with `flags`, where
-    /// `pattern` is an iterator of `u32` Unicode codepoints.
-    /// An Error may be returned if the syntax is invalid.
-    /// This allows parsing regular expressions from exotic strings in
-    /// other encodings, such as UTF-16 or UTF-32.
-    pub fn from_unicode<I, F>(pattern: I, flags: F) -> Result<Regex, Error>
-    where
-        I: Iterator<Item = u32> + Clone,
-        F: Into<Flags>,
-    {
-        let flags = flags.into();
-        let mut ire = parse::try_parse(pattern, flags)?;
-        if !flags.no_opt {
-            optimizer::optimize(&mut ire);
+    /// Construct a regex by parsing `pattern` with typed `flags`.
+    /// An Error is returned if the flags are invalid, as for
+    /// [`Flags::from_flag_set`], or if the syntax is invalid.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{Flag, Regex};
+    ///   let re = Regex::with_flag_set(r"^b", [Flag::CaseInsensitive, Flag::Multiline]).unwrap();
+    ///   assert!(re.find("a\nB").is_some());
+    ///   assert!(Regex::with_flag_set("a", [Flag::Sticky, Flag::Sticky]).is_err());
+    ///   ```
+    pub fn with_flag_set<I>(pattern: &str, flags: I) -> Result<Regex, Error>
+    where
+        I: IntoIterator<Item = Flag>,
+    {
+        Self::with_flags(pattern, Flags::from_flag_set(flags)?)
+    }
+
+    /// Construct a regex by parsing `pattern` with `flags`, where
+    /// `pattern` is an iterator of `u32` Unicode codepoints.
+    /// An Error may be returned if the syntax is invalid.
+    /// This allows parsing regular expressions from exotic strings in
+    /// other encodings, such as UTF-16 or UTF-32.
+    pub fn from_unicode<I, F>(pattern: I, flags: F) -> Result<Regex, Error>
+    where
+        I: Iterator<Item = u32> + Clone,
+        F: Into<Flags>,
+    {
+        Self::compile(pattern, flags.into(), parse::Limits::default())
+    }
+
+    /// Construct a regex from `pattern`, rejecting it if it exceeds `limits`.
+    fn compile<I>(pattern: I, flags: Flags, limits: parse::Limits) -> Result<Regex, Error>
+    where
+        I: Iterator<Item = u32> + Clone,
+    {
+        let mut ire = parse::try_parse_with_limits(pattern.clone(), flags, limits)?;
+        if !flags.no_opt {
+            optimizer::optimize(&mut ire);
+        }
+        let cr = emit::emit(&ire);
+        let source: String = pattern
+            .clone()
+            .map(|c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+        let pattern = if pattern.clone().all(|c| char::from_u32(c).is_some()) {
+            Pattern::Source
+        } else {
+            Pattern::CodePoints(pattern.collect())
+        };
+        Ok(Regex {
+            cr: Arc::new(cr),
+            source: Arc::from(source),
+            step_budget: None,
+            offset_unit: OffsetUnit::Bytes,
+            pattern,
+        })
+    }
+
+    /// Construct a regex from `ir`, built programmatically, with `flags`.
+    /// This skips the parser, so the regex has exactly the structure of `ir`,
+    /// which is only optimized, unless the `no_opt` flag is set, and compiled.
+    ///
+    /// The nodes of `ir` carry the flags which affect parsing, such as 'i' and
+    /// 'm'. An Error is returned if `ir` violates one of the invariants
+    /// documented on [`Ir`], for example if a backreference refers to a group
+    /// which does not exist, or if the flags are invalid.
+    ///
+    /// The regex has no pattern source, so [`Regex::as_str`] returns the empty
+    /// string, but methods which inspect the pattern, like
+    /// [`Regex::walk_ir`], inspect `ir`.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{Flags, Ir, Regex};
+    ///   let a_plus = Ir::Quantifier {
+    ///       min: 1,
+    ///       max: None,
+    ///       greedy: true,
+    ///       contents: Box::new(Ir::Literal { c: 'a' as u32, icase: true }),
+    ///   };
+    ///   let ir = Ir::Concatenation(vec![
+    ///       Ir::Group { index: 1, name: None, contents: Box::new(a_plus) },
+    ///       Ir::BackRef { group: 1, icase: false },
+    ///   ]);
+    ///   let re = Regex::from_ir(ir, Flags::default()).unwrap();
+    ///   assert_eq!(re.find("xAaAa").unwrap().range(), 1..5);
+    ///
+    ///   let err = Regex::from_ir(Ir::BackRef { group: 1, icase: false }, "").unwrap_err();
+    ///   assert_eq!(err.to_string(), "Backreference to nonexistent group 1");
+    ///   ```
+    #[cfg(feature = "ir-visitor")]
+    pub fn from_ir<F>(ir: Ir, flags: F) -> Result<Regex, Error>
+    where
+        F: Into<Flags>,
+    {
+        let flags = flags.into();
+        let mut ire = irview::lower(&ir, flags)?;
+        if !flags.no_opt {
+            optimizer::optimize(&mut ire);
+        }
+        let mut re = Regex::from(emit::emit(&ire));
+        re.pattern = Pattern::Ir(Arc::new(ir));
+        Ok(re)
+    }
+
+    /// Checks the syntax of `pattern` with `flags`, returning the same Error
+    /// as [`Regex::with_flags`] would if it is invalid.
+    ///
+    /// This only parses the pattern: it skips optimization and bytecode
+    /// emission, so it is cheaper than constructing a Regex. Neither of those
+    /// later phases can fail, so every pattern which passes this check may
+    /// also be compiled.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   assert!(Regex::syntax_check(r"(a|b)+\1", "").is_ok());
+    ///   let err = Regex::syntax_check(r"ab(c", "u").unwrap_err();
+    ///   assert_eq!(err.offset(), Some(4));
+    ///   ```
+    pub fn syntax_check<F>(pattern: &str, flags: F) -> Result<(), Error>
+    where
+        F: Into<Flags>,
+    {
+        parse::try_parse(pattern.chars().map(u32::from), flags.into()).map(|_| ())
+    }
+
+    /// Returns the pattern source this regex was constructed from.
+    /// Code points which are not valid chars, such as lone surrogates passed
+    /// to [`Regex::from_unicode`], are replaced by U+FFFD.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::with_flags(r"a+\d", "i").unwrap();
+    ///   assert_eq!(re.as_str(), r"a+\d");
+    ///   assert_eq!(format!("{:#}", re), r"/a+\d/i");
+    ///   ```
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the number of capture groups, not counting the implicit group
+    /// for the whole match. This is the length of [`Match::captures`].
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(a)(?:b)(?<c>c)").unwrap();
+    ///   assert_eq!(re.group_count(), 2);
+    ///   ```
+    #[inline]
+    pub fn group_count(&self) -> usize {
+        self.cr.groups as usize
+    }
+
+    /// Returns an iterator over the capture groups in order, yielding the
+    /// name of each group, or None if it is unnamed. As with
+    /// [`Regex::group_count`], the whole match is not included.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<year>\d+)-(\d+)-(?<day>\d+)").unwrap();
+    ///   let names: Vec<_> = re.capture_names().collect();
+    ///   assert_eq!(names, vec![Some("year"), None, Some("day")]);
+    ///   ```
+    pub fn capture_names(&self) -> impl Iterator<Item = Option<&str>> + '_ {
+        let mut names = vec![None; self.group_count()];
+        for (name, idx) in self.cr.named_group_indices.iter() {
+            names[idx as usize] = Some(name);
+        }
+        names.into_iter()
+    }
+
+    /// Returns the index of the capture group named `name`, or None if there
+    /// is no such group. The index agrees with [`Match::group`], so the first
+    /// group has index 1.
+    ///
+    /// Names are unique within a pattern: a pattern which declares the same
+    /// name twice fails to compile with a "Duplicate capture group name"
+    /// error.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<year>\d+)-(\d+)-(?<day>\d+)").unwrap();
+    ///   assert_eq!(re.name_to_index("day"), Some(3));
+    ///   assert_eq!(re.name_to_index("month"), None);
+    ///   assert!(Regex::new(r"(?<a>x)(?<a>y)").is_err());
+    ///   ```
+    #[inline]
+    pub fn name_to_index(&self, name: &str) -> Option<usize> {
+        let idx = self.cr.named_group_indices.get(name)?;
+        Some(idx as usize + 1)
+    }
+
+    /// Returns the name of the capture group with index `idx`, using the
+    /// convention of [`Match::group`]. Returns None if the group is unnamed,
+    /// or if there is no such group; the whole match at index 0 is never
+    /// named.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<year>\d+)-(\d+)").unwrap();
+    ///   assert_eq!(re.index_to_name(1), Some("year"));
+    ///   assert_eq!(re.index_to_name(2), None);
+    ///   assert_eq!(re.index_to_name(0), None);
+    ///   ```
+    pub fn index_to_name(&self, idx: usize) -> Option<&str> {
+        let idx = idx.checked_sub(1)?;
+        let idx = u16::try_from(idx).ok()?;
+        self.cr.named_group_indices.name_of(idx)
+    }
+
+    /// Walks the parsed IR of this regex, calling `visitor` for each node.
+    ///
+    /// The IR is that of the pattern as written, before optimization; see
+    /// [`IrNode`]. It is recovered by parsing the pattern again, so an Error
+    /// is returned if this regex was made by [`Regex::from_serialized`] or
+    /// from a compiled program, whose pattern is unknown.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{IrNode, IrVisitor, Regex};
+    ///
+    ///   // Finds quantifiers nested within other quantifiers.
+    ///   #[derive(Default)]
+    ///   struct NestedQuantifiers {
+    ///       depth: usize,
+    ///       found: usize,
+    ///   }
+    ///   impl IrVisitor for NestedQuantifiers {
+    ///       fn enter(&mut self, node: &IrNode<'_>) {
+    ///           if let IrNode::Quantifier { .. } = node {
+    ///               self.found += (self.depth > 0) as usize;
+    ///               self.depth += 1;
+    ///           }
+    ///       }
+    ///       fn exit(&mut self, node: &IrNode<'_>) {
+    ///           if let IrNode::Quantifier { .. } = node {
+    ///               self.depth -= 1;
+    ///           }
+    ///       }
+    ///   }
+    ///
+    ///   let mut v = NestedQuantifiers::default();
+    ///   Regex::new(r"(a+)*b|c?").unwrap().walk_ir(&mut v).unwrap();
+    ///   assert_eq!(v.found, 1);
+    ///   ```
+    #[cfg(feature = "ir-visitor")]
+    pub fn walk_ir<V: IrVisitor>(&self, visitor: &mut V) -> Result<(), Error> {
+        let ire = self.parsed_ir()?;
+        irview::walk(&ire.node, visitor);
+        Ok(())
+    }
+
+    /// Returns whether the pattern avoids the constructs which make regex
+    /// matching slow: backreferences, lookarounds and atomic groups, which an
+    /// automaton cannot run, and unbounded quantifiers nested within another,
+    /// like `(a+)*`, the usual cause of exponential backtracking. The check of
+    /// nesting is syntactic, so it is conservative: `(-a+)*` is rejected,
+    /// though it cannot backtrack exponentially.
+    ///
+    /// If so, the pattern is regular, and [`Regex::is_match`] answers in time
+    /// linear in the length of the text. The backtracking engine used by
+    /// [`Regex::find`] and friends may still take super-linear time on some
+    /// such patterns, for example on ambiguous alternations like `(a|a)*b`;
+    /// use a step budget like [`Regex::find_with_budget`] to bound it.
+    ///
+    /// A regex made by [`Regex::from_serialized`] has no IR to check, so this
+    /// is false for it.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   assert!(Regex::new(r"^[a-z]+(-[a-z])*$").unwrap().is_linear_safe());
+    ///   assert!(!Regex::new(r"^(a+)+$").unwrap().is_linear_safe());
+    ///   assert!(!Regex::new(r"(\w)\1").unwrap().is_linear_safe());
+    ///   assert!(!Regex::new(r"a(?=b)").unwrap().is_linear_safe());
+    ///   ```
+    pub fn is_linear_safe(&self) -> bool {
+        // Without the IR, nested quantifiers cannot be told apart in the
+        // program, so the answer is the conservative one.
+        self.parsed_ir().is_ok_and(|ire| ire.is_linear_safe())
+    }
+
+    /// Returns whether every match of the pattern begins at the start of the
+    /// text, because each alternative begins with `^`. With the 'm' flag, or
+    /// within a `(?m:...)` group, `^` also matches after a line terminator,
+    /// so it does not anchor the pattern.
+    ///
+    /// This looks past assertions and other parts of the pattern which match
+    /// only the empty string, like `\b` and `(?=a)`, but is otherwise
+    /// conservative, so it is false for `a?^b`, whose matches must begin at
+    /// the start of the text. The sticky flag is not considered. It is
+    /// false for a regex made by [`Regex::from_serialized`], whose pattern is
+    /// unknown.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   assert!(Regex::new(r"^a|^b").unwrap().is_anchored_start());
+    ///   assert!(Regex::new(r"\b(^a)+").unwrap().is_anchored_start());
+    ///   assert!(!Regex::new(r"^a|b").unwrap().is_anchored_start());
+    ///   assert!(!Regex::with_flags(r"^a", "m").unwrap().is_anchored_start());
+    ///   ```
+    pub fn is_anchored_start(&self) -> bool {
+        self.parsed_ir().is_ok_and(|ire| ire.is_anchored_start())
+    }
+
+    /// Returns whether every match of the pattern ends at the end of the
+    /// text, because each alternative ends with `$`, as [`Regex::is_anchored_start`]
+    /// does for `^`. With the 'm' flag, `$` also matches before a line
+    /// terminator, so it does not anchor the pattern. Like that method, it is
+    /// false for a deserialized regex.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   assert!(Regex::new(r"(a$|b$)").unwrap().is_anchored_end());
+    ///   assert!(Regex::new(r"a(?=$)").unwrap().is_anchored_end());
+    ///   assert!(!Regex::new(r"a(?=$|b)").unwrap().is_anchored_end());
+    ///   assert!(!Regex::with_flags(r"a$", "m").unwrap().is_anchored_end());
+    ///   ```
+    pub fn is_anchored_end(&self) -> bool {
+        self.parsed_ir().is_ok_and(|ire| ire.is_anchored_end())
+    }
+
+    /// Returns a matcher which searches a text fed to it in chunks of UTF-8
+    /// bytes, such as a stream too long to buffer, for the matches which
+    /// [`Regex::find_iter`] would find in the whole text.
+    ///
+    /// This runs on an automaton, so an Error is returned if the pattern has
+    /// a backreference, lookaround or atomic group, or if the regex was built
+    /// with a [`WordBoundary::Custom`]; otherwise all patterns for which
+    /// [`Regex::is_linear_safe`] holds are supported. As the automaton does
+    /// not track capture groups, only the range of each match is reported.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\d+").unwrap();
+    ///   let mut m = re.incremental().unwrap();
+    ///   assert_eq!(m.feed(b"ab 12"), vec![]);
+    ///   assert_eq!(m.pending_start(), Some(3));
+    ///   assert_eq!(m.feed(b"34 c"), vec![3..7]);
+    ///   assert!(!m.is_pending());
+    ///   assert_eq!(m.feed(b"5"), vec![]);
+    ///   assert_eq!(m.finish(), vec![9..10]);
+    ///   assert!(Regex::new(r"(a)\1").unwrap().incremental().is_err());
+    ///   ```
+    pub fn incremental(&self) -> Result<IncrementalMatcher<'_>, Error> {
+        IncrementalMatcher::new(&self.cr)
+    }
+
+    /// Returns the set of code points with which a match of the pattern may
+    /// begin. A text containing none of them has no match, which makes this
+    /// useful to skip trying a regex on a text.
+    ///
+    /// The set is conservative: it may contain code points which cannot in
+    /// fact begin a match, for example because of a lookahead. If the
+    /// pattern can match the empty string, it may match anywhere, so the set
+    /// contains every code point, as it does for a regex made by
+    /// [`Regex::from_serialized`], whose pattern is unknown.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::with_flags(r"(?:foo|Bar)\d*", "i").unwrap();
+    ///   let set = re.first_codepoint_set();
+    ///   assert!(set.contains('F' as u32) && set.contains('b' as u32));
+    ///   assert!(!set.contains('o' as u32));
+    ///   assert!(Regex::new(r"x*").unwrap().first_codepoint_set().contains('y' as u32));
+    ///   ```
+    pub fn first_codepoint_set(&self) -> CodePointSet {
+        match self.parsed_ir() {
+            Ok(ire) => ire.first_code_points(),
+            Err(_) => CodePointSet::new().inverted(),
+        }
+    }
+
+    /// Returns the set of code points with which a match of the pattern may
+    /// end, to skip or search a text from its end. A text containing none of
+    /// them has no match.
+    ///
+    /// As with [`Regex::first_codepoint_set`], the set is conservative. It is
+    /// None if the pattern can match the empty string, so that a match need
+    /// not end with a code point, or if a match may end with a backreference,
+    /// whose code points are unknown, or if this regex was deserialized.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::with_flags(r"\d+(?:px|EM)?", "i").unwrap();
+    ///   let set = re.last_codepoint_set().unwrap();
+    ///   assert!(set.contains('7' as u32) && set.contains('X' as u32) && set.contains('m' as u32));
+    ///   assert!(!set.contains('p' as u32));
+    ///   assert!(Regex::new(r"a*").unwrap().last_codepoint_set().is_none());
+    ///   assert!(Regex::new(r"(a)\1").unwrap().last_codepoint_set().is_none());
+    ///   ```
+    pub fn last_codepoint_set(&self) -> Option<CodePointSet> {
+        self.parsed_ir().ok()?.last_code_points()
+    }
+
+    /// Returns the longest literal string with which every match of the
+    /// pattern begins, or None if there is none, for example because the
+    /// pattern begins with an alternation, a class or an assertion. A text
+    /// which does not contain the prefix has no match, so this is useful to
+    /// index patterns by their prefix. A deserialized regex has no known
+    /// prefix.
+    ///
+    /// With the 'i' flag, the prefix only extends over chars which have no
+    /// other case forms, so that it may be searched for exactly.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"foo(bar)+\d").unwrap();
+    ///   assert_eq!(re.required_prefix().as_deref(), Some("foobar"));
+    ///   let re = Regex::with_flags(r"2024-[a-z]+", "i").unwrap();
+    ///   assert_eq!(re.required_prefix().as_deref(), Some("2024-"));
+    ///   assert_eq!(Regex::new(r"foo|bar").unwrap().required_prefix(), None);
+    ///   ```
+    pub fn required_prefix(&self) -> Option<String> {
+        // The prefix is read from the optimized IR, in which case-exact chars
+        // are no longer case-insensitive.
+        let mut ire = self.parsed_ir().ok()?;
+        optimizer::optimize(&mut ire);
+        let prefix = startpredicate::required_prefix(&ire);
+        if prefix.is_empty() {
+            None
+        } else {
+            String::from_utf8(prefix).ok()
+        }
+    }
+
+    /// Returns a key which is the same for patterns which differ only in
+    /// spelling, so that compiled regexes may be cached by it rather than by
+    /// their source. For example `(?:abc)` and `abc`, `[a-c]` and `[abc]`,
+    /// and `a{2,}` and `aaa*` all have the same key.
+    ///
+    /// The key is derived from the optimized IR, the flags which affect
+    /// matching, and the [`WordBoundary`], step budget and [`OffsetUnit`] set
+    /// with [`RegexBuilder`], so it is a structural comparison: patterns with
+    /// equal keys match the same way, but some patterns which match the same
+    /// way, like `a|b` and `b|a`, have different keys. The format of the key
+    /// is unspecified, and may change between versions of this crate.
+    ///
+    /// A regex made by [`Regex::from_serialized`] has no IR, so its key is
+    /// derived from its program instead, and differs from the key of the
+    /// regex which was serialized.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let key = |pattern, flags| Regex::with_flags(pattern, flags).unwrap().canonical_key();
+    ///   assert_eq!(key(r"(?:abc)\d{1,}", ""), key(r"abc\d+", ""));
+    ///   assert_eq!(key(r"[a-c]", ""), key(r"[cba]", ""));
+    ///   assert_eq!(key(r"a b", "x"), key(r"ab", ""));
+    ///   assert_ne!(key(r"(abc)", ""), key(r"abc", ""));
+    ///   assert_ne!(key(r"abc", "i"), key(r"abc", ""));
+    ///   ```
+    pub fn canonical_key(&self) -> String {
+        // Extended mode, the non-ECMAScript escapes and dot_matches_all only
+        // affect parsing, and no_opt only compilation.
+        let mut flags = self.cr.flags;
+        flags.extended = false;
+        flags.line_break_escapes = false;
+        flags.word_edge_escapes = false;
+        flags.dot_matches_all = false;
+        flags.no_opt = false;
+        // The default word boundary is spelled out, as it depends on the flags.
+        let word_boundary = match &self.cr.word_boundary {
+            Some(wb) => wb.clone(),
+            None if flags.unicode || flags.unicode_sets => WordBoundary::Unicode,
+            None => WordBoundary::Ascii,
+        };
+        let settings = format!(
+            "/{}/ {:?} {:?} {:?}",
+            flags, word_boundary, self.step_budget, self.offset_unit
+        );
+        match self.parsed_ir() {
+            Ok(mut ire) => {
+                optimizer::optimize(&mut ire);
+                format!("{}\n{}", settings, ire)
+            }
+            // Without the IR the program stands in for it. Its serialization
+            // is as canonical, though the keys of patterns differing only in
+            // spelling then differ if their programs do.
+            Err(_) => {
+                let program: String = serialize::serialize(&self.cr)
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect();
+                format!("{}#{}", settings, program)
+            }
+        }
+    }
+
+    /// Returns a copy of this regex whose program is compiled without the
+    /// redundant constructs of its pattern, such as non-capturing groups
+    /// around a single node, `{1}` and `{1,1}` quantifiers, classes like `[a]`
+    /// of a single char, empty groups `(?:)`, and empty alternatives after
+    /// the first, as in `a||`. Capture groups are kept, so the copy numbers
+    /// and reports them exactly as this regex does, and matches the same way.
+    ///
+    /// The optimizer already removes these constructs, so this is mostly of
+    /// use for a regex compiled with the `no_opt` flag, whose program
+    /// otherwise mirrors the pattern, as when tracing it; the copy's program
+    /// is not optimized further. The copy keeps the source and settings of
+    /// this regex. Returns None if the pattern is unknown, as for a regex
+    /// wrapped from a compiled or deserialized program.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{Flags, Regex};
+    ///   let mut no_opt = Flags::default();
+    ///   no_opt.no_opt = true;
+    ///   let re = Regex::with_flags(r"(?:(?:a)(b){1}[c](?:))", no_opt).unwrap();
+    ///   let simplified = re.try_simplify().unwrap();
+    ///   assert!(re.debug_program().contains("Loop"));
+    ///   assert!(!simplified.debug_program().contains("Loop"));
+    ///   let m = simplified.find("xabc").unwrap();
+    ///   assert_eq!((m.range(), m.group(1)), (1..4, Some(2..3)));
+    ///   assert_eq!(simplified.as_str(), re.as_str());
+    ///   ```
+    pub fn try_simplify(&self) -> Option<Regex> {
+        let mut ire = self.parsed_ir().ok()?;
+        optimizer::simplify(&mut ire);
+        if !self.cr.flags.no_opt {
+            optimizer::optimize(&mut ire);
+        }
+        Some(Regex {
+            cr: Arc::new(self.emit(&ire)),
+            ..self.clone()
+        })
+    }
+
+    /// Compiles \p ire, the IR of this regex, with its word boundary setting.
+    fn emit(&self, ire: &ir::Regex) -> CompiledRegex {
+        let mut cr = emit::emit(ire);
+        if let Some(wb) = &self.cr.word_boundary {
+            cr.set_word_boundary(wb.clone());
+        }
+        cr
+    }
+
+    /// \return the IR the program was compiled from, before optimization,
+    /// to inspect the pattern, or an Error if it is unknown because the
+    /// regex was wrapped from a compiled or deserialized program. The
+    /// pattern is parsed again, without the limits it was checked against
+    /// when the regex was built.
+    fn parsed_ir(&self) -> Result<ir::Regex, Error> {
+        let flags = self.cr.flags;
+        let limits = parse::Limits::unlimited();
+        match &self.pattern {
+            Pattern::Source => {
+                parse::try_parse_with_limits(self.source.chars().map(u32::from), flags, limits)
+            }
+            Pattern::CodePoints(pattern) => {
+                parse::try_parse_with_limits(pattern.iter().copied(), flags, limits)
+            }
+            #[cfg(feature = "ir-visitor")]
+            Pattern::Ir(ir) => irview::lower(ir, flags),
+            Pattern::Unknown => {
+                parse::error("The pattern of a compiled or deserialized program is unknown")
+            }
+        }
+    }
+
+    /// \return the IR this regex was built from by [`Regex::from_ir`], if
+    /// any.
+    #[cfg(feature = "ir-visitor")]
+    fn built_ir(&self) -> Option<&Ir> {
+        match &self.pattern {
+            Pattern::Ir(ir) => Some(ir),
+            _ => None,
+        }
+    }
+
+    /// \return the serialized program, which stands in for the pattern in
+    /// comparisons, if the pattern is unknown because the regex was wrapped
+    /// from a compiled or deserialized program.
+    fn unknown_pattern_program(&self) -> Option<Vec<u8>> {
+        match self.pattern {
+            Pattern::Unknown => Some(serialize::serialize(&self.cr)),
+            _ => None,
+        }
+    }
+
+    /// Returns a listing of the compiled program, for debugging patterns and
+    /// reporting bugs.
+    ///
+    /// The listing gives the flags and start predicate, then one line per
+    /// instruction with its index, opcode and operands, then the contents of
+    /// each bracket. Jump targets are instruction indexes and `Bracket #n`
+    /// refers to the bracket list. The format may change between versions,
+    /// so it should not be parsed.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let listing = Regex::new(r"(a|[xy])").unwrap().debug_program();
+    ///   assert!(listing.contains("BeginCaptureGroup 0"));
+    ///   assert!(listing.contains("Alt else -> "));
+    ///   ```
+    pub fn debug_program(&self) -> String {
+        format!("{}", self.cr)
+    }
+
+    /// Returns a description of how searches such as [`Regex::find`] skip
+    /// ahead to the positions where a match may begin, for understanding why
+    /// a pattern is or is not fast to search for.
+    ///
+    /// The description begins with one of:
+    ///
+    /// - `literal prefix`, followed by up to four bytes which begin every
+    ///   match, quoted if they are UTF-8. These are searched for, using memchr
+    ///   for a single byte.
+    /// - `first byte`, followed by the bytes of which every match begins with
+    ///   one. Only positions at one of these are tried.
+    /// - `anchored`: there is nothing to search for, but the pattern only
+    ///   matches at the start of the text, so its anchor fails at once at
+    ///   every later position.
+    /// - `none`: the engine is run at every position.
+    ///
+    /// A rewrite which gives every alternative the same literal beginning,
+    /// or one of a few bytes, lets searches skip ahead. The format of what
+    /// follows the first word may change between versions.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"foo\d+").unwrap();
+    ///   assert_eq!(re.start_predicate_description(), r#"literal prefix "foo""#);
+    ///   let re = Regex::new(r"[ab]c").unwrap();
+    ///   assert_eq!(re.start_predicate_description(), "first byte any of 'a' 'b'");
+    ///   let re = Regex::new(r"^\w+").unwrap();
+    ///   assert_eq!(re.start_predicate_description(), "anchored at the start of the text");
+    ///   let re = Regex::new(r"\w+").unwrap();
+    ///   assert!(re.start_predicate_description().starts_with("first byte"));
+    ///   let re = Regex::new(r"x*").unwrap();
+    ///   assert_eq!(re.start_predicate_description(), "none: the engine runs at every position");
+    ///   ```
+    pub fn start_predicate_description(&self) -> String {
+        match self.cr.start_pred {
+            StartPredicate::Arbitrary if self.is_anchored_start() => {
+                "anchored at the start of the text".into()
+            }
+            StartPredicate::Arbitrary => "none: the engine runs at every position".into(),
+            StartPredicate::ByteSeq1(_)
+            | StartPredicate::ByteSeq2(_)
+            | StartPredicate::ByteSeq3(_)
+            | StartPredicate::ByteSeq4(_) => format!("literal prefix {}", self.cr.start_pred),
+            StartPredicate::ByteSet2(_)
+            | StartPredicate::ByteSet3(_)
+            | StartPredicate::ByteBracket(_) => format!("first byte {}", self.cr.start_pred),
+        }
+    }
+
+    /// Returns the compiled program as bytes, which may be stored and later
+    /// passed to [`Regex::from_serialized`] to recreate this regex without
+    /// parsing the pattern again.
+    ///
+    /// The bytes carry a format version, and are only accepted by a build of
+    /// this crate which uses the same format and Unicode tables. The pattern
+    /// source is not included.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::with_flags(r"(?<year>\d{4})-(\d\d)", "i").unwrap();
+    ///   let bytes = re.serialize_program();
+    ///   let re2 = Regex::from_serialized(&bytes, re.as_str()).unwrap();
+    ///   let m = re2.find("on 2020-04").unwrap();
+    ///   assert_eq!(m.named_group("year"), Some(3..7));
+    ///   assert_eq!(re2.serialize_program(), bytes);
+    ///   ```
+    pub fn serialize_program(&self) -> Vec<u8> {
+        serialize::serialize(&self.cr)
+    }
+
+    /// Recreates a regex from the output of [`Regex::serialize_program`].
+    /// `pattern` is used only as the source returned by [`Regex::as_str`].
+    ///
+    /// An Error is returned if `bytes` were produced by an incompatible
+    /// version of this crate, or are not a valid program.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let err = Regex::from_serialized(b"abc", "abc").unwrap_err();
+    ///   assert_eq!(err.to_string(), "Not a serialized program");
+    ///   ```
+    pub fn from_serialized(bytes: &[u8], pattern: &str) -> Result<Regex, Error> {
+        let cr = serialize::deserialize(bytes)?;
+        Ok(Regex {
+            cr: Arc::new(cr),
+            source: Arc::from(pattern),
+            step_budget: None,
+            offset_unit: OffsetUnit::Bytes,
+            pattern: Pattern::Unknown,
+        })
+    }
+
+    /// Returns whether `text` contains a match.
+    ///
+    /// Patterns without backreferences or lookarounds are regular, and are
+    /// checked with a lazy DFA, which runs in time linear in the length of
+    /// `text`. The states of the DFA are computed as they are needed and kept
+    /// with the regex, so later calls reuse them. Other patterns, and very
+    /// short texts, use the backtracker. In either case the start of a match
+    /// is first found with the same literal search as [`Regex::find`], and no
+    /// capture groups are recorded, so this is cheaper than
+    /// `find(text).is_some()`.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"^(a|a)*b$").unwrap();
+    ///   assert!(re.is_match("aaab"));
+    ///   assert!(!re.is_match(&"a".repeat(64)));
+    ///   ```
+    #[inline]
+    pub fn is_match(&self, text: &str) -> bool {
+        backends::is_match(self, text, 0)
+    }
+
+    /// Returns whether `text` contains a match beginning at or after the byte
+    /// index `start`, like [`Regex::find_from`] but without computing the
+    /// match. Characters before `start` are still visible to lookbehinds,
+    /// anchors and word boundaries.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\bcat").unwrap();
+    ///   assert!(re.is_match_at("cat", 0));
+    ///   assert!(!re.is_match_at("bobcat", 3));
+    ///   ```
+    #[inline]
+    pub fn is_match_at(&self, text: &str, start: usize) -> bool {
+        backends::is_match(self, text, start)
+    }
+
+    /// Returns the number of matches in `text`, as [`Regex::find_iter`]
+    /// would find them, but at most `limit`: the search stops once `limit`
+    /// matches are found. This tells whether there are "too many" matches
+    /// without finding them all, and without recording capture groups.
+    ///
+    /// Matches do not overlap, and after an empty match the search resumes
+    /// at the next character, as with `find_iter`.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\d+").unwrap();
+    ///   assert_eq!(re.count_matches_up_to("1 22 333 4444", 3), 3);
+    ///   assert_eq!(re.count_matches_up_to("1 22", 3), 2);
+    ///   assert_eq!(re.count_matches_up_to("1 22", 0), 0);
+    ///   // Empty matches count too.
+    ///   let re = Regex::new(r"x*").unwrap();
+    ///   assert_eq!(re.count_matches_up_to("axxb", 10), 4);
+    ///   ```
+    #[inline]
+    pub fn count_matches_up_to(&self, text: &str, limit: usize) -> usize {
+        backends::count_matches_up_to(self, text, limit)
+    }
+
+    /// Returns, for every position in `text`, whether a match begins there.
+    ///
+    /// Positions are indexed by code point: entry `i` is for the `i`th code
+    /// point of `text`, and there is one more entry, for the end of `text`,
+    /// where only an empty match can begin. So the result has
+    /// `text.chars().count() + 1` entries. This ignores the sticky flag, and
+    /// like [`Regex::is_match_at`] the text before each position is visible
+    /// to lookbehinds, anchors and word boundaries.
+    ///
+    /// This is a single left-to-right pass. For a pattern without
+    /// backreferences, lookarounds or atomic groups, the attempts from each
+    /// position share a lazy DFA, and attempts which reach the same state are
+    /// merged, so the time is linear in the length of `text` rather than
+    /// quadratic. Other patterns are searched with the backtracker, resuming
+    /// after each match start found.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\bab").unwrap();
+    ///   let mask = re.matches_at_each_position("abab ab");
+    ///   assert_eq!(mask, [true, false, false, false, false, true, false, false]);
+    ///   ```
+    pub fn matches_at_each_position(&self, text: &str) -> Vec<bool> {
+        backends::match_starts(self, text)
+    }
+
+    /// Returns whether the regex matches the whole of `text`, from its start
+    /// to its end.
+    ///
+    /// This is like testing against the pattern `^(?:...)$`, without changing
+    /// the pattern: the anchors are those of the whole input, even with the
+    /// multiline flag, and a match which ends early is backtracked into
+    /// until one reaching the end is found. Capture groups are not recorded.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"a|ab").unwrap();
+    ///   assert!(re.full_match("ab"));
+    ///   assert!(!re.full_match("abc"));
+    ///   let re = Regex::with_flags(r"\d+", "m").unwrap();
+    ///   assert!(!re.full_match("12\n34"));
+    ///   ```
+    #[inline]
+    pub fn full_match(&self, text: &str) -> bool {
+        backends::full_match(self, text)
+    }
+
+    /// Explains why the whole of `text` does not match, as tested by
+    /// [`Regex::full_match`], for telling a user which part of their input
+    /// is invalid. Returns None if it matches.
+    ///
+    /// The report gives the furthest offset which matching reached before
+    /// every path failed, as a byte offset and through
+    /// [`FailureReport::unit_offset`] in the unit of
+    /// [`RegexBuilder::offset_unit`], and what the pattern expected there.
+    /// Literal strings are matched char by char, so the offset may fall
+    /// within them. This is a heuristic: of
+    /// alternatives which fail at different offsets only the furthest are
+    /// reported, and what fails there may be an assertion or backreference,
+    /// which expects no particular char. A step budget set with
+    /// [`RegexBuilder::step_budget`] bounds the paths explored. For a
+    /// deserialized regex the optimized program is run, so a failure within a
+    /// literal string is reported at its start.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\d{3}-\d{4}").unwrap();
+    ///   let report = re.explain_failure("555-12x4").unwrap();
+    ///   assert_eq!(report.offset(), 6);
+    ///   assert_eq!(report.to_string(), "expected [0-9] at offset 6, found 'x'");
+    ///   let report = re.explain_failure("555-1234!").unwrap();
+    ///   assert_eq!(report.to_string(), "expected end of input at offset 8, found '!'");
+    ///   assert!(re.explain_failure("555-1234").is_none());
+    ///   ```
+    pub fn explain_failure(&self, text: &str) -> Option<FailureReport> {
+        // The unoptimized program matches literal strings char by char, so
+        // that a failure within them is located. Without the IR, the
+        // optimized program locates the failure at the start of a literal.
+        let unoptimized = self.parsed_ir().ok().map(|ire| self.emit(&ire));
+        let cr = unoptimized.as_ref().unwrap_or(&self.cr);
+        let mut report = explain::explain_failure(cr, text, self.step_budget)?;
+        report.unit_offset = OffsetConverter::new(self.offset_unit).offset(text, report.offset);
+        Some(report)
+    }
+
+    /// Returns the least and greatest length in bytes of any match, where
+    /// the greatest is None if matches may be arbitrarily long, for example
+    /// because of an unbounded quantifier or a backreference. The bounds are
+    /// conservative: some lengths within them may have no match.
+    ///
+    /// [`Regex::full_match`] uses them to reject a text of the wrong length
+    /// without matching, and they serve the same purpose for callers which
+    /// filter texts themselves.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"^\d{3}-\d{4}$").unwrap();
+    ///   assert_eq!(re.match_length_bounds(), (8, Some(8)));
+    ///   assert_eq!(Regex::new(r"ab?c*").unwrap().match_length_bounds(), (1, None));
+    ///   assert_eq!(Regex::new(r"é|x{2,3}").unwrap().match_length_bounds(), (2, Some(3)));
+    ///   ```
+    #[inline]
+    pub fn match_length_bounds(&self) -> (usize, Option<usize>) {
+        self.cr.match_length_bounds
+    }
+
+    /// Returns the end of the shortest match in `text` which begins at the
+    /// leftmost position where the regex matches, or None if there is no
+    /// match. The end is a byte offset, whatever the [`OffsetUnit`] of the
+    /// regex.
+    ///
+    /// The match begins where [`Regex::find`] would report it, but rather
+    /// than accepting the first match found, every path from that position
+    /// is explored and the earliest end is kept. Unlike making quantifiers
+    /// lazy, this changes which match is accepted and not how the pattern is
+    /// explored, so alternations and greedy quantifiers may still yield the
+    /// shortest end. Atomic groups and possessive quantifiers still discard
+    /// the paths they would discard in [`Regex::find`].
+    ///
+    /// Assertions are checked as usual, so the input at and after the end
+    /// may still be needed to decide the match: `$` and `\b` look at the
+    /// next character, and lookaheads may look arbitrarily far. A pattern
+    /// with none of these accepts a match once the input up to its end is
+    /// known.
+    ///
+    /// With the sticky flag, the match must begin at the start of `text`.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"a+|ab").unwrap();
+    ///   assert_eq!(re.find("xaab").unwrap().end(), 3);
+    ///   assert_eq!(re.shortest_match("xaab"), Some(2));
+    ///
+    ///   // The match must still satisfy the lookahead, so it is longer.
+    ///   let re = Regex::new(r"\d+(?=px)").unwrap();
+    ///   assert_eq!(re.shortest_match("width: 120px"), Some(10));
+    ///   ```
+    #[inline]
+    pub fn shortest_match(&self, text: &str) -> Option<usize> {
+        backends::shortest_match(self, text, 0)
+    }
+
+    /// Searches `text` to find the first match, like [`Regex::find`], passing
+    /// each step of the backtracking engine to `tracer`.
+    ///
+    /// The events report match attempts, each instruction run, backtracks
+    /// and capture group updates; see [`TraceEvent`]. They are meant for
+    /// debugging a pattern, and their details are not stable. The backtracking
+    /// engine is used regardless of the enabled backends.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{Regex, TraceEvent};
+    ///   let re = Regex::new(r"a(b|c)").unwrap();
+    ///   let mut backtracks = 0;
+    ///   let m = re.find_traced("ac", &mut |event| {
+    ///       if let TraceEvent::Backtrack { .. } = event {
+    ///           backtracks += 1;
+    ///       }
+    ///   });
+    ///   assert_eq!(m.unwrap().range(), 0..2);
+    ///   assert_eq!(backtracks, 1);
+    ///   ```
+    #[cfg(feature = "trace")]
+    pub fn find_traced(
+        &self,
+        text: &str,
+        tracer: &mut dyn FnMut(&TraceEvent<'_>),
+    ) -> Option<Match> {
+        let m = backends::find_traced(self, text, 0, tracer)?;
+        Some(self.report(text, m))
+    }
+
+    /// Searches `text` to find the first match, like [`Regex::find`], but
+    /// memoizing the states of the backtracking engine; see
+    /// [`Regex::find_iter_memoized`].
+    #[inline]
+    pub fn find_memoized(&self, text: &str) -> Option<Match> {
+        self.find_iter_memoized(text).next()
+    }
+
+    /// Searches `text`, returning an iterator over non-overlapping matches,
+    /// like [`Regex::find_iter`], but memoizing the states of the
+    /// backtracking engine.
+    ///
+    /// The engine remembers each state, a loop instruction at a position
+    /// with its iteration counts, at which it has already looked for a
+    /// match, and does not explore paths through it again. This bounds the
+    /// time taken by patterns which would otherwise backtrack exponentially,
+    /// like `(a|a)*b`, without rewriting them with atomic groups. The matches
+    /// and their captures are the same as those of [`Regex::find_iter`].
+    ///
+    /// Memory is used in proportion to the number of states visited, which
+    /// may be the length of `text` times the size of the pattern, and there
+    /// is some overhead at every loop iteration, so this is slower for
+    /// patterns which do not backtrack badly. Loops over a single character
+    /// are not memoized, so a pattern like `(?:a+)+b` still takes quadratic
+    /// time. Memoization is disabled for patterns with backreferences, whose
+    /// matching depends on the captures.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"^(a|a)*b$").unwrap();
+    ///   let text = "a".repeat(100);
+    ///   // Without memoization this takes about 2^100 steps.
+    ///   assert!(re.find_memoized(&text).is_none());
+    ///   ```
+    #[inline]
+    pub fn find_iter_memoized<'r, 't>(&'r self, text: &'t str) -> Matches<'r, 't> {
+        Matches::new(self, backends::find_memoized(self, text, 0), text)
+    }
+
+    /// Searches `text` to find the first match.
+    #[inline]
+    pub fn find(&self, text: &str) -> Option<Match> {
+        self.find_iter(text).next()
+    }
+
+    /// Searches `text` to find the first match, as modified by `options`.
+    ///
+    /// With [`MatchOptions::case_insensitive`], the program is not compiled
+    /// again: the search uses a copy of the program whose literals, classes
+    /// and property escapes are extended to the lowercase forms of their
+    /// ASCII letters, and which reads the ASCII letters of `text` as
+    /// lowercase. This differs from compiling the regex with the 'i' flag in
+    /// that:
+    ///
+    /// - Only ASCII letters are folded: `é` does not match `É`, and with the
+    ///   'u' flag, `k` does not match the Kelvin sign as it would with 'i'.
+    /// - It applies throughout the pattern, including within a `(?-i:...)`
+    ///   group.
+    /// - Property escapes like `\p{Lu}` match ASCII letters of either case,
+    ///   as they do with the 'i' flag in JavaScript, though not in this crate.
+    ///
+    /// Classes, including negated ones like `[^a]`, and backreferences
+    /// compare ASCII letters regardless of case, as with the 'i' flag. The
+    /// copy of the program is made by the first such search and kept by the
+    /// regex, given the `std` feature; `text` is not copied.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{MatchOptions, Regex};
+    ///   let re = Regex::new(r"hello, [A-Z]\w+").unwrap();
+    ///   let icase = MatchOptions {
+    ///       case_insensitive: true,
+    ///       ..Default::default()
+    ///   };
+    ///   assert!(re.find("HELLO, world").is_none());
+    ///   let m = re.find_with("Oh, HELLO, world", icase).unwrap();
+    ///   assert_eq!(m.range(), 4..16);
+    ///   assert!(re.find_with("HéLLO, world", icase).is_none());
+    ///
+    ///   // "e\u{301}" is e with a combining acute accent.
+    ///   let re = Regex::new(r"e").unwrap();
+    ///   let aligned = MatchOptions {
+    ///       grapheme_align: true,
+    ///       ..Default::default()
+    ///   };
+    ///   assert_eq!(re.find("cafe\u{301}").unwrap().range(), 3..4);
+    ///   assert_eq!(re.find_with("cafe\u{301}", aligned).unwrap().range(), 3..6);
+    ///
+    ///   let re = Regex::new(r"(\w+)@(\w+)\.(\w+)").unwrap();
+    ///   let domain_only = MatchOptions {
+    ///       wanted_groups: Some(&[2]),
+    ///       ..Default::default()
+    ///   };
+    ///   let m = re.find_with("ann@example.com", domain_only).unwrap();
+    ///   assert_eq!(m.group(2), Some(4..11));
+    ///   assert_eq!(m.group(1), None);
+    ///   assert_eq!(m.group(3), None);
+    ///   ```
+    pub fn find_with(&self, text: &str, options: MatchOptions) -> Option<Match> {
+        let wanted_groups = options.wanted_groups;
+        let mut m = match (options.case_insensitive, wanted_groups) {
+            (true, _) => self.find_ascii_case_insensitive(text, wanted_groups),
+            (false, Some(groups)) => backends::find_with_groups(self, text, 0, groups).next(),
+            (false, None) => self.raw_matches(text).next(),
+        }?;
+        if let Some(groups) = wanted_groups {
+            // Groups recorded only for backreferences are not reported.
+            for (idx, capture) in m.captures.iter_mut().enumerate() {
+                if !groups.contains(&(idx + 1)) {
+                    *capture = None;
+                }
+            }
+        }
+        if options.grapheme_align {
+            m.range = unicode::grapheme_aligned(text, m.range());
+        }
+        Some(self.report(text, m))
+    }
+
+    /// \return the matches in \p text, with byte offsets regardless of the
+    /// offset unit, for methods which slice the text by them.
+    #[inline]
+    fn raw_matches<'r, 't>(&'r self, text: &'t str) -> RawMatches<'r, 't> {
+        backends::find(self, text, 0)
+    }
+
+    /// Record the ranges of \p m, a match in \p text, in the offset unit of
+    /// the regex.
+    #[inline]
+    fn report(&self, text: &str, m: Match) -> Match {
+        OffsetConverter::new(self.offset_unit).record(text, m)
+    }
+
+    /// Searches `text` for the first match, with ASCII letters matching
+    /// regardless of case, for [`MatchOptions::case_insensitive`]. If
+    /// \p wanted_groups is set, only those groups are recorded.
+    fn find_ascii_case_insensitive(
+        &self,
+        text: &str,
+        wanted_groups: Option<&[usize]>,
+    ) -> Option<Match> {
+        let folded = self
+            .cr
+            .ascii_folded_program
+            .get_or_init(|| Some(self.cr.ascii_case_folded()))?;
+        backends::find_ascii_folded(&folded, text, wanted_groups)
+    }
+
+    /// Searches `text`, returning an iterator over non-overlapping matches.
+    /// Note that the resulting Iterator borrows both the regex `'r` and the
+    /// input string as `'t`.
+    #[inline]
+    pub fn find_iter<'r, 't>(&'r self, text: &'t str) -> Matches<'r, 't> {
+        self.find_from(text, 0)
+    }
+
+    /// Returns the rightmost match in `text`: the last match which
+    /// [`Regex::find_iter`] would return, or None if there is none.
+    ///
+    /// When the length of a match is bounded, as reported by
+    /// [`Regex::match_length_bounds`], this tries start positions from the
+    /// end of `text`, so it need not scan the whole of a long text. Otherwise,
+    /// or with the sticky flag, it searches forwards and keeps the last match.
+    ///
+    /// The rightmost match need not begin at the rightmost position where a
+    /// match could begin, because matches do not overlap.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\d{2}").unwrap();
+    ///   assert_eq!(re.rfind("12 34 56").unwrap().range(), 6..8);
+    ///   assert_eq!(re.rfind("123").unwrap().range(), 0..2);
+    ///   assert!(re.rfind("1 2").is_none());
+    ///   ```
+    #[inline]
+    pub fn rfind(&self, text: &str) -> Option<Match> {
+        let m = backends::rfind::<backends::DefaultExecutor>(self, text)?;
+        Some(self.report(text, m))
+    }
+
+    /// Returns an iterator for matches found in 'text' starting at byte index
+    /// `start`. Note this may be different from passing a sliced `text` in
+    /// the case of lookbehind assertions.
+    /// If `start` is not on a char boundary, it is rounded up to the next
+    /// one; if it is past the end of `text`, there are no matches.
+    /// If the regex has the sticky flag, each match must begin exactly where
+    /// the previous match ended, and iteration stops at the first failure.
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let text = "xyxy";
+    ///   let re = Regex::new(r"(?<=x)y").unwrap();
+    ///   let t1 = re.find(&text[1..]).unwrap().range();
+    ///   assert!(t1 == (2..3));
+    ///   let t2 = re.find_from(text, 1).next().unwrap().range();
+    ///   assert!(t2 == (1..2));
+    ///   ```
+    #[inline]
+    pub fn find_from<'r, 't>(&'r self, text: &'t str, start: usize) -> Matches<'r, 't> {
+        Matches::new(self, backends::find(self, text, start), text)
+    }
+
+    /// Returns an iterator over non-overlapping matches in `text`, beginning
+    /// the search at byte index `start`. This is the same as
+    /// [`Regex::find_from`]: offsets are relative to all of `text`, and the
+    /// text before `start` remains visible to lookbehinds, anchors and word
+    /// boundaries. A `start` which is not on a char boundary is rounded up to
+    /// the next one.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\b\w").unwrap();
+    ///   let text = "one two three";
+    ///   let starts: Vec<_> = re.find_iter_from(text, 1).map(|m| m.start()).collect();
+    ///   assert_eq!(starts, vec![4, 8]);
+    ///
+    ///   // Byte 1 is inside the 'é', so the search begins at byte 2.
+    ///   let re = Regex::new(r".").unwrap();
+    ///   let m = re.find_iter_from("\u{E9}x", 1).next().unwrap();
+    ///   assert_eq!(m.range(), 2..3);
+    ///   ```
+    #[inline]
+    pub fn find_iter_from<'r, 't>(&'r self, text: &'t str, start: usize) -> Matches<'r, 't> {
+        self.find_from(text, start)
+    }
+
+    /// Searches `text`, returning an iterator over matches which may overlap.
+    ///
+    /// After a match beginning at some position, the search resumes one code
+    /// point after that position rather than at the end of the match. So
+    /// each item is the match, with its captures, which [`Regex::find_from`]
+    /// would report from its start position: the leftmost match at or after
+    /// it. Every start position is passed at most once, so the iteration
+    /// terminates, even for empty matches. With the sticky flag, the matches
+    /// must begin at consecutive code points from the start of `text`.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\w\w").unwrap();
+    ///   let text = "abc d";
+    ///   let grams: Vec<_> = re.find_overlapping_iter(text).map(|m| &text[m.range()]).collect();
+    ///   assert_eq!(grams, vec!["ab", "bc"]);
+    ///   ```
+    #[inline]
+    pub fn find_overlapping_iter<'r, 't>(&'r self, text: &'t str) -> OverlappingMatches<'r, 't> {
+        OverlappingMatches {
+            matches: self.raw_matches(text),
+            text,
+            offsets: OffsetConverter::new(self.offset_unit),
+        }
+    }
+
+    /// Searches `text`, returning an iterator over non-overlapping matches
+    /// which also report their ranges, including those of capture groups, as
+    /// code point offsets into `text`. These are [`Match::unit_range`] and
+    /// [`Match::unit_group`], as if the regex had been built with
+    /// [`OffsetUnit::CodePoints`]; the byte ranges still slice `text`.
+    ///
+    /// The offsets are computed as the iteration proceeds, so converting all
+    /// of the matches costs about one pass over `text`.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"b(.)").unwrap();
+    ///   let text = "\u{E9}b\u{1F600}b!";
+    ///   let ranges: Vec<_> = re.find_iter_chars(text).map(|m| m.unit_range()).collect();
+    ///   assert_eq!(ranges, vec![1..3, 3..5]);
+    ///   let m = re.find_iter_chars(text).next().unwrap();
+    ///   assert_eq!(m.unit_group(1), Some(2..3));
+    ///   assert_eq!(m.as_str(text), "b\u{1F600}");
+    ///   ```
+    #[inline]
+    pub fn find_iter_chars<'r, 't>(&'r self, text: &'t str) -> CharMatches<'r, 't> {
+        CharMatches {
+            matches: self.raw_matches(text),
+            text,
+            offsets: OffsetConverter::new(OffsetUnit::CodePoints),
+        }
+    }
+
+    /// Searches `bytes`, which are expected to be UTF-8, to find the first
+    /// match. The returned ranges are byte offsets into `bytes`.
+    ///
+    /// Unlike converting with `str::from_utf8`, this does not validate the
+    /// input up front: code points are decoded as the matcher reaches them.
+    /// A match attempt which encounters an invalid UTF-8 sequence fails at
+    /// that point, and searching continues at the next byte.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\d+").unwrap();
+    ///   let bytes = b"ab\xFF\xFE 123";
+    ///   assert_eq!(re.find_bytes(bytes).unwrap().range(), 5..8);
+    ///   ```
+    #[inline]
+    pub fn find_bytes(&self, bytes: &[u8]) -> Option<Match> {
+        backends::find_bytes(self, bytes)
+    }
+
+    /// Searches each line of `text`, returning an iterator over the leftmost
+    /// match in each line which has one, together with the byte range of
+    /// that line. The ranges are byte offsets into `text`, and exclude the
+    /// line terminators.
+    ///
+    /// Lines are separated by the JavaScript line terminators, `\n`, `\r`,
+    /// U+2028 and U+2029, with `\r\n` treated as one terminator. As with
+    /// [`str::lines`], a terminator at the end of `text` does not begin an
+    /// empty last line, and an empty `text` has no lines.
+    ///
+    /// Each line is searched as if it were a separate string, without
+    /// copying it. So `^` and `$` match at the start and end of every line
+    /// whether or not the `m` flag is set, and lookarounds and `\b` do not
+    /// see past the line. A pattern cannot match a line terminator, so one
+    /// with its own newlines, like `a\nb`, never matches. Use
+    /// [`Regex::find_iter`] with the `m` flag to match across lines.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"^\w+$").unwrap();
+    ///   let text = "one\ntwo words\r\nthree\n";
+    ///   let lines: Vec<_> = re
+    ///       .find_line_iter(text)
+    ///       .map(|(line, m)| (line, &text[m.range()]))
+    ///       .collect();
+    ///   assert_eq!(lines, vec![(0..3, "one"), (15..20, "three")]);
+    ///   ```
+    pub fn find_line_iter<'r, 't>(&'r self, text: &'t str) -> LineMatches<'r, 't> {
+        LineMatches {
+            re: self,
+            text,
+            next_line: (!text.is_empty()).then_some(0),
+            offsets: OffsetConverter::new(self.offset_unit),
+        }
+    }
+
+    /// Searches `bytes`, returning an iterator over the matches within its
+    /// maximal runs of valid UTF-8. The returned ranges are byte offsets into
+    /// `bytes`. If the regex was built with [`RegexBuilder::offset_unit`],
+    /// the matches also report their ranges in that unit, counting each
+    /// invalid sequence as the U+FFFD which `String::from_utf8_lossy` would
+    /// replace it by.
+    ///
+    /// Invalid UTF-8 sequences are hard boundaries: each run is searched as
+    /// if it were a separate string, so no match, capture or lookaround
+    /// crosses an invalid byte, and `^`, `$` and `\b` treat the edges of a
+    /// run as the edges of the text. This differs from
+    /// [`Regex::find_bytes`], for which the bytes are one text that may not be
+    /// matched through. The input is never modified or converted lossily,
+    /// and invalid sequences do not cause a panic.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"^\w+").unwrap();
+    ///   let bytes = b"ab\xFFcd";
+    ///   let ranges: Vec<_> = re.find_iter_utf8_runs(bytes).map(|m| m.range()).collect();
+    ///   assert_eq!(ranges, vec![0..2, 3..5]);
+    ///   ```
+    pub fn find_iter_utf8_runs<'r, 't>(&'r self, bytes: &'t [u8]) -> Utf8RunMatches<'r, 't> {
+        Utf8RunMatches {
+            re: self,
+            bytes,
+            run: None,
+            next_run: Some(0),
+            next_run_units: 0,
+            offsets: OffsetConverter::new(self.offset_unit),
+        }
+    }
+
+    /// Searches the OS string `text`, like [`Regex::find_iter_utf8_runs`]
+    /// over its encoded bytes. The returned ranges are byte offsets into
+    /// `text.as_encoded_bytes()`, and the parts of `text` which are not
+    /// valid Unicode are never matched. A [`std::path::Path`] may be searched
+    /// through `Path::as_os_str`.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   use std::path::Path;
+    ///   let re = Regex::new(r"[^/]+\.rs$").unwrap();
+    ///   let path = Path::new("src/lib.rs");
+    ///   let m = re.find_iter_os_str(path.as_os_str()).next().unwrap();
+    ///   assert_eq!(m.range(), 4..10);
+    ///   ```
+    #[cfg(feature = "std")]
+    pub fn find_iter_os_str<'r, 't>(&'r self, text: &'t std::ffi::OsStr) -> Utf8RunMatches<'r, 't> {
+        self.find_iter_utf8_runs(text.as_encoded_bytes())
+    }
+
+    /// Searches each of `haystacks` for its first match, as [`Regex::find`]
+    /// does, spreading the haystacks across threads. The results are in the
+    /// order of the haystacks.
+    ///
+    /// The compiled program is shared by reference, and each search keeps its
+    /// state on its own thread, so this is equivalent to calling `find` on
+    /// each haystack in turn. With the `rayon` feature the haystacks are
+    /// searched on the rayon thread pool, which balances haystacks of uneven
+    /// length between its threads. Otherwise they are split into one run per
+    /// available CPU, each searched on a scoped thread, so that std alone
+    /// suffices.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\d+").unwrap();
+    ///   let found = re.par_find_all(&["a1", "b", "22c"]);
+    ///   let ranges: Vec<_> = found.iter().map(|m| m.as_ref().map(|m| m.range())).collect();
+    ///   assert_eq!(ranges, vec![Some(1..2), None, Some(0..2)]);
+    ///   ```
+    #[cfg(feature = "std")]
+    pub fn par_find_all(&self, haystacks: &[&str]) -> Vec<Option<Match>> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            haystacks.par_iter().map(|text| self.find(text)).collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        self.par_find_all_scoped(haystacks)
+    }
+
+    /// Search \p haystacks for par_find_all, in a run per CPU on scoped
+    /// threads.
+    #[cfg(all(feature = "std", not(feature = "rayon")))]
+    fn par_find_all_scoped(&self, haystacks: &[&str]) -> Vec<Option<Match>> {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_len = haystacks.len().div_ceil(threads).max(1);
+        if chunk_len == haystacks.len() {
+            return haystacks.iter().map(|text| self.find(text)).collect();
+        }
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = haystacks
+                .chunks(chunk_len)
+                .map(|chunk| {
+                    scope
+                        .spawn(move || chunk.iter().map(|text| self.find(text)).collect::<Vec<_>>())
+                })
+                .collect();
+            // Propagate a panic on a search thread with its original payload.
+            handles
+                .into_iter()
+                .flat_map(|handle| match handle.join() {
+                    Ok(found) => found,
+                    Err(e) => std::panic::resume_unwind(e),
+                })
+                .collect()
+        })
+    }
+
+    /// Searches a user-provided [`Input`] to find the first match. This
+    /// allows searching text which is not stored contiguously, such as a
+    /// rope. The returned ranges are byte offsets into the input.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{Input, Regex};
+    ///
+    ///   // Text stored in two pieces.
+    ///   struct Pieces<'a>(&'a str, &'a str);
+    ///
+    ///   impl Input for Pieces<'_> {
+    ///       fn len(&self) -> usize {
+    ///           self.0.len() + self.1.len()
+    ///       }
+    ///       fn byte_at(&self, offset: usize) -> u8 {
+    ///           match offset.checked_sub(self.0.len()) {
+    ///               None => self.0.as_bytes()[offset],
+    ///               Some(idx) => self.1.as_bytes()[idx],
+    ///           }
+    ///       }
+    ///   }
+    ///
+    ///   let re = Regex::new(r"wor\w+").unwrap();
+    ///   let m = re.find_input(&Pieces("hello wo", "rld")).unwrap();
+    ///   assert_eq!(m.range(), 6..11);
+    ///   ```
+    #[inline]
+    pub fn find_input<I: Input + ?Sized>(&self, input: &I) -> Option<Match> {
+        backends::find_input(self, input)
+    }
+
+    /// Searches the UTF-16 `text` to find the first match. The returned
+    /// ranges are offsets in code units, as in JavaScript.
+    ///
+    /// As in JavaScript, with the `u` or `v` flag a well-formed surrogate
+    /// pair is matched as the single code point it encodes, and without them
+    /// each code unit is a char, so that `^.$` does not match a pair, and
+    /// `\uD83D` matches its first half. An astral char in the pattern is
+    /// then the pair of code units which encode it. Lone surrogates are
+    /// matched as individual code points either way, so they may be matched
+    /// by `.`, by negated classes, by escapes like `\uD800`, or by property
+    /// escapes which hold them, like `\p{Cs}`.
+    ///
+    /// The text is transcoded before searching, which takes time
+    /// proportional to its length; to find many matches in the same text,
+    /// see [`Regex::prepare_utf16`]. Like [`Regex::find`], this takes no step
+    /// budget; see [`Regex::try_find_utf16`].
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let text: Vec<u16> = "a\u{1F600}b".encode_utf16().collect();
+    ///   let re = Regex::with_flags(r"\u{1F600}b", "u").unwrap();
+    ///   assert_eq!(re.find_utf16(&text).unwrap().range(), 1..4);
+    ///
+    ///   let re = Regex::new(r"[\uD800-\uDFFF]").unwrap();
+    ///   assert_eq!(re.find_utf16(&[0x61, 0xD800]).unwrap().range(), 1..2);
+    ///   assert_eq!(re.find_utf16(&text).unwrap().range(), 1..2);
+    ///   ```
+    #[inline]
+    pub fn find_utf16(&self, text: &[u16]) -> Option<Match> {
+        self.find_utf16_from(text, 0)
+    }
+
+    /// Searches the UTF-16 `text` to find the first match at or after the
+    /// code unit index `start`, as [`find_utf16`](Self::find_utf16) does. If
+    /// the regex has the sticky flag, the match must begin exactly at `start`.
+    /// Returns None if `start` is out of bounds or, with the `u` or `v` flag,
+    /// falls between the two halves of a surrogate pair.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let text: Vec<u16> = "x1 x2".encode_utf16().collect();
+    ///   let re = Regex::new(r"x(\d)").unwrap();
+    ///   let m = re.find_utf16_from(&text, 1).unwrap();
+    ///   assert_eq!(m.range(), 3..5);
+    ///   assert_eq!(m.group(1), Some(4..5));
+    ///   ```
+    #[inline]
+    pub fn find_utf16_from(&self, text: &[u16], start: usize) -> Option<Match> {
+        backends::find_utf16(self, text, start, None).unwrap_or(None)
+    }
+
+    /// Searches the UTF-16 `text` to find the first match, as
+    /// [`find_utf16`](Self::find_utf16) does, within the step budget set with
+    /// [`RegexBuilder::step_budget`], as [`Regex::try_find`] does for a
+    /// `&str`.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{BudgetExceeded, RegexBuilder};
+    ///   let re = RegexBuilder::new(r"(a+)+$").step_budget(10_000).build().unwrap();
+    ///   let text: Vec<u16> = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa!".encode_utf16().collect();
+    ///   assert_eq!(re.try_find_utf16(&text).unwrap_err(), BudgetExceeded);
+    ///   assert_eq!(re.try_find_utf16(&[0x61]).unwrap().unwrap().range(), 0..1);
+    ///   ```
+    pub fn try_find_utf16(&self, text: &[u16]) -> Result<Option<Match>, BudgetExceeded> {
+        backends::find_utf16(self, text, 0, self.step_budget)
+    }
+
+    /// Transcodes the UTF-16 `text` once, so that it may be searched with
+    /// [`find_iter_utf16`](Self::find_iter_utf16) without the cost which
+    /// [`find_utf16`](Self::find_utf16) pays on every call. The result may be
+    /// searched with any regex; one whose `u` or `v` flag differs from this
+    /// one's transcodes the text again.
+    #[inline]
+    pub fn prepare_utf16<'t>(&self, text: &'t [u16]) -> PreparedUtf16<'t> {
+        let pair_surrogates = self.pairs_surrogates();
+        PreparedUtf16 {
+            units: text,
+            pair_surrogates,
+            text: indexing::Utf16Text::new(text, pair_surrogates),
+        }
+    }
+
+    /// Searches the prepared UTF-16 `text`, returning an iterator over
+    /// non-overlapping matches. As with [`find_utf16`](Self::find_utf16),
+    /// the ranges of each match are code unit indexes into the text.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let units: Vec<u16> = "a1 \u{1F600}22 b333".encode_utf16().collect();
+    ///   let re = Regex::with_flags(r"\d+", "u").unwrap();
+    ///   let text = re.prepare_utf16(&units);
+    ///   let ranges: Vec<_> = re.find_iter_utf16(&text).map(|m| m.range()).collect();
+    ///   assert_eq!(ranges, vec![1..2, 5..7, 9..12]);
+    ///   ```
+    #[inline]
+    pub fn find_iter_utf16<'r, 'p>(&'r self, text: &'p PreparedUtf16<'_>) -> Utf16Matches<'r, 'p> {
+        let pair_surrogates = self.pairs_surrogates();
+        let text = if text.pair_surrogates == pair_surrogates {
+            Cow::Borrowed(&text.text)
+        } else {
+            Cow::Owned(indexing::Utf16Text::new(text.units, pair_surrogates))
+        };
+        Utf16Matches {
+            re: self,
+            text,
+            program: self.utf16_search_program(),
+            start: Some(0),
+        }
+    }
+
+    /// \return whether UTF-16 text is searched with its surrogate pairs
+    /// combined into the code points they encode, as with the 'u' flag.
+    fn pairs_surrogates(&self) -> bool {
+        self.cr.flags.unicode || self.cr.flags.unicode_sets
+    }
+
+    /// \return the program with which to search UTF-16 text, or None if it is
+    /// this regex's own program.
+    fn utf16_search_program(&self) -> Option<Arc<CompiledRegex>> {
+        if self.pairs_surrogates() {
+            None
+        } else {
+            self.utf16_program()
+        }
+    }
+
+    /// \return the program with which to search UTF-16 text without the 'u'
+    /// flag, in which each code unit is a char, or None if it is this regex's
+    /// own program. They differ only if the pattern has an astral char,
+    /// written as such or as a pair of escapes, which is then two chars, so
+    /// the pattern is compiled again from the UTF-16 encoding of its source.
+    /// That is only done if the source is known to be the pattern.
+    fn utf16_program(&self) -> Option<Arc<CompiledRegex>> {
+        self.cr.utf16_program.get_or_init(|| {
+            if !matches!(self.pattern, Pattern::Source) {
+                return None;
+            }
+            if self.source.chars().all(|c| c <= '\u{FFFF}') && !self.source.contains("\\u") {
+                return None;
+            }
+            let pattern = self.source.encode_utf16().map(u32::from);
+            let mut ire = parse::try_parse_code_units(pattern, self.cr.flags).ok()?;
+            if !self.cr.flags.no_opt {
+                optimizer::optimize(&mut ire);
+            }
+            Some(self.emit(&ire))
+        })
+    }
+
+    /// Searches `text` to find the first match, returning its capture groups
+    /// as a [`Captures`].
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<key>\w+)=(\d+)").unwrap();
+    ///   let text = "x=12";
+    ///   let caps = re.captures(text).unwrap();
+    ///   assert_eq!(&text[caps.name("key").unwrap()], "x");
+    ///   assert_eq!(&text[caps.get(2).unwrap()], "12");
+    ///   ```
+    #[inline]
+    pub fn captures(&self, text: &str) -> Option<Captures> {
+        self.find(text).map(Captures::from)
+    }
+
+    /// Searches `text`, returning an iterator over the [`Captures`] of
+    /// non-overlapping matches. The yielded values do not borrow `text`.
+    #[inline]
+    pub fn captures_iter<'r, 't>(&'r self, text: &'t str) -> CapturesIter<'r, 't> {
+        CapturesIter {
+            matches: self.find_iter(text),
+        }
+    }
+
+    /// Searches `text` to find the first match.
+    /// The input text is expected to be ascii-only: only ASCII case-folding is
+    /// supported.
+    #[inline]
+    pub fn find_ascii(&self, text: &str) -> Option<Match> {
+        self.find_iter_ascii(text).next()
+    }
+
+    /// Searches `text`, returning an iterator over non-overlapping matches.
+    /// The input text is expected to be ascii-only: only ASCII case-folding is
+    /// supported.
+    #[inline]
+    pub fn find_iter_ascii<'r, 't>(&'r self, text: &'t str) -> AsciiMatches<'r, 't> {
+        self.find_from_ascii(text, 0)
+    }
+
+    /// Returns an iterator for matches found in 'text' starting at byte index
+    /// `start`.
+    #[inline]
+    pub fn find_from_ascii<'r, 't>(&'r self, text: &'t str, start: usize) -> AsciiMatches<'r, 't> {
+        backends::find(self, text, start)
+    }
+
+    /// Returns an iterator over the substrings of `text` separated by matches
+    /// of this regex, following the semantics of JavaScript's
+    /// `String.prototype.split`.
+    ///
+    /// Each capture group of a separator is yielded after the piece preceding
+    /// it; groups which did not participate in the match yield the empty
+    /// string. A separator which matches the empty string splits `text` at
+    /// each character.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\s*(,)\s*").unwrap();
+    ///   let pieces: Vec<&str> = re.split("a , b,c").collect();
+    ///   assert_eq!(pieces, vec!["a", ",", "b", ",", "c"]);
+    ///   ```
+    pub fn split<'r, 't>(&'r self, text: &'t str) -> Split<'r, 't> {
+        Split::new(self, text)
+    }
+
+    /// Returns an iterator over at most `limit` of the items produced by
+    /// [`Regex::split`]. As in JavaScript, the remainder of `text` is
+    /// discarded once the limit is reached.
+    pub fn splitn<'r, 't>(
+        &'r self,
+        text: &'t str,
+        limit: usize,
+    ) -> core::iter::Take<Split<'r, 't>> {
+        self.split(text).take(limit)
+    }
+
+    /// Attempts to match starting exactly at byte offset `start` in `text`,
+    /// as if the 'y' flag were set. Returns None if there is no match which
+    /// begins at `start`. This is [`Regex::find_anchored`].
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\d+").unwrap();
+    ///   assert_eq!(re.find_sticky("ab12", 2).unwrap().range(), 2..4);
+    ///   assert!(re.find_sticky("ab12", 1).is_none());
+    ///   ```
+    #[inline]
+    pub fn find_sticky(&self, text: &str, start: usize) -> Option<Match> {
+        self.find_anchored(text, start)
+    }
+
+    /// Attempts a single match beginning exactly at byte offset `start` in
+    /// `text`, without searching later offsets. Returns None if there is no
+    /// match which begins at `start`, or if `start` is out of bounds.
+    ///
+    /// Unlike prefixing the pattern with `^`, this does not depend on the
+    /// multiline flag, and it is suitable for driving a tokenizer.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\s*(\d+|[a-z]+)").unwrap();
+    ///   let text = "abc 123";
+    ///   let first = re.find_anchored(text, 0).unwrap();
+    ///   assert_eq!(first.range(), 0..3);
+    ///   let second = re.find_anchored(text, first.end()).unwrap();
+    ///   assert_eq!(&text[second.group(1).unwrap()], "123");
+    ///   ```
+    #[inline]
+    pub fn find_anchored(&self, text: &str, start: usize) -> Option<Match> {
+        let m = backends::find_anchored::<backends::DefaultExecutor>(self, text, start)?;
+        Some(self.report(text, m))
+    }
+
+    /// Searches `text` for the first match at or after byte offset `start`,
+    /// writing it into `caps` and returning whether a match was found. On
+    /// failure `caps` is left empty.
+    ///
+    /// Unlike [`find_from`](Self::find_from), this reuses the storage of
+    /// `caps` rather than allocating a new [`Match`] each time, and `caps`
+    /// also keeps the backtracking stacks of the search for the next one, so
+    /// a loop which keeps a single Captures performs no allocation once it
+    /// has warmed up, unless the `prohibit-unsafe` feature is enabled. This
+    /// honors the sticky flag as `find_from` does.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{Captures, Regex};
+    ///   let re = Regex::new(r"(?<key>\w+)=(\w+)").unwrap();
+    ///   let mut caps = Captures::new();
+    ///   let text = "a=1 b=2";
+    ///   let mut start = 0;
+    ///   let mut keys = Vec::new();
+    ///   while re.find_into(text, start, &mut caps) {
+    ///       keys.push(&text[caps.name("key").unwrap()]);
+    ///       start = caps.range().end;
+    ///   }
+    ///   assert_eq!(keys, vec!["a", "b"]);
+    ///   ```
+    #[inline]
+    pub fn find_into(&self, text: &str, start: usize, caps: &mut Captures) -> bool {
+        let matched = backends::find_into(self, text, start, caps);
+        caps.offset_unit = self.offset_unit;
+        caps.unit_groups.clear();
+        if matched && self.offset_unit != OffsetUnit::Bytes {
+            let offsets = OffsetConverter::new(self.offset_unit);
+            let unit_groups = caps
+                .groups
+                .iter()
+                .map(|g| g.clone().map(|r| offsets.range(text, r)));
+            caps.unit_groups.extend(unit_groups);
+        }
+        matched
+    }
+
+    /// Searches `text` to find the first match, giving up once `max_steps`
+    /// steps have been taken. This guards against patterns like `(a+)+$`
+    /// whose running time may be exponential in the length of `text`.
+    ///
+    /// A step is a single backtrack: each time the matcher abandons a path
+    /// and resumes from a previously saved choice point, or gives up on a
+    /// start position, including within lookarounds. Steps are counted across
+    /// every start position tried, so the budget bounds the entire search.
+    ///
+    /// This always uses the backtracking engine. Returns `Err(BudgetExceeded)`
+    /// if the budget was exhausted before the search completed.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{BudgetExceeded, Regex};
+    ///   let re = Regex::new(r"(a+)+$").unwrap();
+    ///   let text = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa!";
+    ///   assert_eq!(re.find_with_budget(text, 10_000).unwrap_err(), BudgetExceeded);
+    ///   let m = re.find_with_budget("aaa", 10_000).unwrap().unwrap();
+    ///   assert_eq!(m.range(), 0..3);
+    ///   ```
+    pub fn find_with_budget(
+        &self,
+        text: &str,
+        max_steps: usize,
+    ) -> Result<Option<Match>, BudgetExceeded> {
+        let m = backends::find_with_budget(self, text, max_steps)?;
+        Ok(m.map(|m| self.report(text, m)))
+    }
+
+    /// Returns the step budget set with [`RegexBuilder::step_budget`], if
+    /// any.
+    #[inline]
+    pub fn step_budget(&self) -> Option<usize> {
+        self.step_budget
+    }
+
+    /// Returns the unit set with [`RegexBuilder::offset_unit`] in which
+    /// matches also report their ranges, which is [`OffsetUnit::Bytes`] by
+    /// default.
+    #[inline]
+    pub fn offset_unit(&self) -> OffsetUnit {
+        self.offset_unit
+    }
+
+    /// Searches `text` to find the first match, within the step budget set
+    /// with [`RegexBuilder::step_budget`]. This is [`Regex::find_with_budget`]
+    /// if there is a budget, and otherwise [`Regex::find`], which never fails.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{BudgetExceeded, RegexBuilder};
+    ///   let re = RegexBuilder::new(r"(a+)+$").step_budget(10_000).build().unwrap();
+    ///   let text = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa!";
+    ///   assert_eq!(re.try_find(text).unwrap_err(), BudgetExceeded);
+    ///   assert_eq!(re.try_find("aaa").unwrap().unwrap().range(), 0..3);
+    ///   ```
+    pub fn try_find(&self, text: &str) -> Result<Option<Match>, BudgetExceeded> {
+        match self.step_budget {
+            Some(max_steps) => self.find_with_budget(text, max_steps),
+            None => Ok(self.find(text)),
+        }
+    }
+
+    /// Replaces the first match in `text` with `replacement`.
+    /// The replacement string may contain `$` substitutions, following the
+    /// conventions of JavaScript's `String.prototype.replace`:
+    ///
+    /// - `$$` inserts a single `$`.
+    /// - `$&` inserts the matched text.
+    /// - `` $` `` inserts the text before the match.
+    /// - `$'` inserts the text after the match.
+    /// - `$1` through `$99` insert the corresponding capture group.
+    /// - `$<name>` inserts the named capture group.
+    ///
+    /// Groups which did not participate in the match expand to the empty
+    /// string. If there is no match, `text` is returned unchanged.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<year>\d{4})-(\d{2})").unwrap();
+    ///   let s = re.replace("Today is 2020-05", "$2/$<year>");
+    ///   assert_eq!(s, "Today is 05/2020");
+    ///   ```
+    pub fn replace<'t>(&self, text: &'t str, replacement: &str) -> Cow<'t, str> {
+        match self.raw_matches(text).next() {
+            None => Cow::Borrowed(text),
+            Some(m) => {
+                let mut result = String::with_capacity(text.len());
+                result.push_str(&text[..m.start()]);
+                m.expand(text, replacement, &mut result);
+                result.push_str(&text[m.end()..]);
+                Cow::Owned(result)
+            }
+        }
+    }
+
+    /// Replaces all non-overlapping matches in `text` with `replacement`.
+    /// The replacement string supports the same `$` substitutions as
+    /// [`Regex::replace`].
+    ///
+    /// An empty match inserts the replacement once, and the search then
+    /// resumes after the next character. If there is no match, `text` is
+    /// returned unchanged.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"a*").unwrap();
+    ///   assert_eq!(re.replace_all("baac", "-"), "-b--c-");
+    ///   ```
+    pub fn replace_all<'t>(&self, text: &'t str, replacement: &str) -> Cow<'t, str> {
+        self.replace_all_impl(text, usize::MAX, |m, text, dst| {
+            m.expand(text, replacement, dst)
+        })
+        .0
+    }
+
+    /// Replaces at most `count` non-overlapping matches in `text` with
+    /// `replacement`, and returns the result along with the number of
+    /// replacements made. A `count` of 0 replaces all matches, like
+    /// [`Regex::replace_all`].
+    ///
+    /// The replacement string supports the same `$` substitutions as
+    /// [`Regex::replace`], and empty matches are handled as in
+    /// [`Regex::replace_all`]: each counts as one replacement.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"\d+").unwrap();
+    ///   assert_eq!(re.replacen("1 22 333", 2, "#"), ("# # 333".into(), 2));
+    ///   assert_eq!(re.replacen("1 22 333", 0, "#"), ("# # #".into(), 3));
+    ///   assert_eq!(re.replacen("abc", 1, "#"), ("abc".into(), 0));
+    ///   ```
+    pub fn replacen<'t>(
+        &self,
+        text: &'t str,
+        count: usize,
+        replacement: &str,
+    ) -> (Cow<'t, str>, usize) {
+        let limit = if count == 0 { usize::MAX } else { count };
+        self.replace_all_impl(text, limit, |m, text, dst| m.expand(text, replacement, dst))
+    }
+
+    /// Replaces all non-overlapping matches in `text` with `replacement`,
+    /// like [`Regex::replace_all`], writing the result to `out` as the
+    /// matches are found rather than building it in memory.
+    ///
+    /// If writing fails, the error is returned at once, and `out` holds a
+    /// prefix of the result: everything written before the failing write.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(\w+)@(\w+)").unwrap();
+    ///   let mut out = String::new();
+    ///   re.replace_all_to("a@b, c@d", "$2 at $1", &mut out).unwrap();
+    ///   assert_eq!(out, "b at a, d at c");
+    ///   ```
+    pub fn replace_all_to<W: fmt::Write + ?Sized>(
+        &self,
+        text: &str,
+        replacement: &str,
+        out: &mut W,
+    ) -> fmt::Result {
+        let mut last_end = 0;
+        for m in self.raw_matches(text) {
+            out.write_str(&text[last_end..m.start()])?;
+            write_replacement(&m, text, replacement, out)?;
+            last_end = m.end();
+        }
+        out.write_str(&text[last_end..])
+    }
+
+    /// Replaces all non-overlapping matches in `text` with the result of
+    /// calling `f`. The closure receives each Match along with the original
+    /// `text`, so that it may inspect capture groups by index or by name.
+    /// Text between matches is copied verbatim.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::Regex;
+    ///   let re = Regex::new(r"(?<n>\d+)").unwrap();
+    ///   let s = re.replace_all_with("1 2 3", |m, text| {
+    ///       let n: u32 = text[m.named_group("n").unwrap()].parse().unwrap();
+    ///       (n * 2).to_string()
+    ///   });
+    ///   assert_eq!(s, "2 4 6");
+    ///   ```
+    pub fn replace_all_with<F>(&self, text: &str, mut f: F) -> String
+    where
+        F: FnMut(&Match, &str) -> String,
+    {
+        self.replace_all_impl(text, usize::MAX, |m, text, dst| dst.push_str(&f(m, text)))
+            .0
+            .into_owned()
+    }
+
+    /// Replace the first \p limit matches in \p text, invoking \p append to
+    /// write the replacement for each match.
+    /// \return the result and the number of matches replaced.
+    fn replace_all_impl<'t, F>(
+        &self,
+        text: &'t str,
+        limit: usize,
+        mut append: F,
+    ) -> (Cow<'t, str>, usize)
+    where
+        F: FnMut(&Match, &str, &mut String),
+    {
+        let mut matches = self.raw_matches(text).take(limit).peekable();
+        if matches.peek().is_none() {
+            return (Cow::Borrowed(text), 0);
+        }
+        let mut result = String::with_capacity(text.len());
+        let mut last_end = 0;
+        let mut replaced = 0;
+        for m in matches {
+            result.push_str(&text[last_end..m.start()]);
+            append(&m, text, &mut result);
+            last_end = m.end();
+            replaced += 1;
+        }
+        result.push_str(&text[last_end..]);
+        (Cow::Owned(result), replaced)
+    }
+}
+
+/// Write the replacement template \p replacement to \p dst, substituting
+/// references to the match \p m against \p text.
+/// This follows ES2022 22.1.3.19.1 GetSubstitution.
+fn write_replacement<W: fmt::Write + ?Sized>(
+    m: &Match,
+    text: &str,
+    replacement: &str,
+    dst: &mut W,
+) -> fmt::Result {
+    let group_count = m.captures.len();
+    let push_group = |dst: &mut W, idx: usize| match m.group(idx) {
+        Some(r) => dst.write_str(&text[r]),
+        None => Ok(()),
+    };
+    let mut rest = replacement;
+    while let Some(dollar) = rest.find('$') {
+        dst.write_str(&rest[..dollar])?;
+        rest = &rest[dollar..];
+        let bytes = rest.as_bytes();
+        // The number of bytes of the template consumed by this substitution.
+        let consumed = match bytes.get(1).copied() {
+            Some(b'$') => {
+                dst.write_char('$')?;
+                2
+            }
+            Some(b'&') => {
+                dst.write_str(&text[m.range()])?;
+                2
+            }
+            Some(b'`') => {
+                dst.write_str(&text[..m.start()])?;
+                2
+            }
+            Some(b'\'') => {
+                dst.write_str(&text[m.end()..])?;
+                2
+            }
+            Some(d1 @ b'0'..=b'9') => {
+                // Prefer a two digit group reference if it is in range.
+                let d1 = (d1 - b'0') as usize;
+                let two_digit = match bytes.get(2).copied() {
+                    Some(d2 @ b'0'..=b'9') => Some(d1 * 10 + (d2 - b'0') as usize),
+                    _ => None,
+                };
+                match two_digit {
+                    Some(idx) if 1 <= idx && idx <= group_count => {
+                        push_group(dst, idx)?;
+                        3
+                    }
+                    _ if 1 <= d1 && d1 <= group_count => {
+                        push_group(dst, d1)?;
+                        2
+                    }
+                    _ => {
+                        // Not a valid group reference; the $ is literal.
+                        dst.write_char('$')?;
+                        1
+                    }
+                }
+            }
+            Some(b'<') if !m.named_captures.is_empty() => match rest[2..].find('>') {
+                Some(name_len) => {
+                    let name = &rest[2..2 + name_len];
+                    if let Some(r) = m.named_group(name) {
+                        dst.write_str(&text[r])?;
+                    }
+                    2 + name_len + 1
+                }
+                None => {
+                    dst.write_str("$<")?;
+                    2
+                }
+            },
+            _ => {
+                dst.write_char('$')?;
+                1
+            }
+        };
+        rest = &rest[consumed..];
+    }
+    dst.write_str(rest)
+}
+
+/// Splits a leading byte order mark, U+FEFF, off `text`, returning the rest
+/// of the text and the length in bytes of what was removed: 3 if there was a
+/// BOM, otherwise 0.
+///
+/// Text read from files may begin with an invisible BOM, which stops
+/// patterns anchored with `^` from matching at the start. Matching the
+/// returned text instead treats the text as starting after the BOM. Offsets
+/// of matches are then relative to the returned text; add the returned
+/// length to get offsets into `text`. Passing the length to
+/// [`Regex::find_from`] does not help, since the BOM is still seen there, by
+/// `^` and by lookbehinds.
+///
+/// Example:
+///
+///  ```rust
+///   use regress::{strip_bom, Regex};
+///   let text = "\u{FEFF}key=value";
+///   let re = Regex::new(r"^(\w+)=").unwrap();
+///   assert!(re.find(text).is_none());
+///   let (rest, skipped) = strip_bom(text);
+///   assert_eq!(skipped, 3);
+///   let m = re.find(rest).unwrap();
+///   assert_eq!(m.range(), 0..4);
+///   assert_eq!(&text[m.start() + skipped..m.end() + skipped], "key=");
+///   ```
+pub fn strip_bom(text: &str) -> (&str, usize) {
+    match text.strip_prefix('\u{FEFF}') {
+        Some(rest) => (rest, text.len() - rest.len()),
+        None => (text, 0),
+    }
+}
+
+impl FromStr for Regex {
+    type Err = Error;
+
+    /// Attempts to parse a string into a regular expression
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::new(s)
+    }
+}
+
+/// The serialized form of a [`Regex`]: its source and its flags string.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Regex")]
+struct SerializedRegex {
+    source: String,
+    flags: String,
+}
+
+/// With the `serde` feature, a Regex is serialized as a struct of its source
+/// and its flags string, like `{"source": "a+", "flags": "iu"}`. The compiled
+/// program is not serialized, and neither are the options of a
+/// [`RegexBuilder`], such as a step budget.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Regex {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedRegex {
+            source: String::from(&*self.source),
+            flags: format!("{}", self.cr.flags),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializing compiles the source with its flags, as
+/// [`Regex::with_flags`] does. A pattern which does not compile is a
+/// deserialization error.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Regex {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedRegex::deserialize(deserializer)?;
+        let flags = serialized
+            .flags
+            .parse::<Flags>()
+            .map_err(serde::de::Error::custom)?;
+        Regex::with_flags(&serialized.source, flags).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A builder for a Regex, for setting flags and options programmatically
+/// rather than as a flags string.
+///
+/// Example:
+///
+///  ```rust
+///   use regress::RegexBuilder;
+///   let re = RegexBuilder::new(r"^b\w+")
+///       .case_insensitive(true)
+///       .multiline(true)
+///       .build()
+///       .unwrap();
+///   assert_eq!(re.find("abc\nBcd").unwrap().range(), 4..7);
+///   assert_eq!(format!("{:#}", re), r"/^b\w+/mi");
+///   ```
+#[derive(Debug, Clone)]
+pub struct RegexBuilder {
+    pattern: String,
+    flags: Flags,
+    step_budget: Option<usize>,
+    offset_unit: OffsetUnit,
+    limits: parse::Limits,
+    word_boundary: Option<WordBoundary>,
+}
+
+impl RegexBuilder {
+    /// Construct a builder for `pattern` with the default flags.
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.into(),
+            flags: Flags::default(),
+            step_budget: None,
+            offset_unit: OffsetUnit::Bytes,
+            limits: parse::Limits::default(),
+            word_boundary: None,
         }
-        let cr = emit::emit(&ire);
-        Ok(Regex { cr })
     }
 
-    /// Searches `text` to find the first match.
+    /// Sets all flags at once, replacing any set previously.
     #[inline]
-    pub fn find(&self, text: &str) -> Option<Match> {
-        self.find_iter(text).next()
+    pub fn flags<F: Into<Flags>>(&mut self, flags: F) -> &mut Self {
+        self.flags = flags.into();
+        self
     }
 
-    /// Searches `text`, returning an iterator over non-overlapping matches.
-    /// Note that the resulting Iterator borrows both the regex `'r` and the
-    /// input string as `'t`.
+    /// Sets whether the regex is case-insensitive, like the 'i' flag.
     #[inline]
-    pub fn find_iter<'r, 't>(&'r self, text: &'t str) -> Matches<'r, 't> {
-        self.find_from(text, 0)
+    pub fn case_insensitive(&mut self, yes: bool) -> &mut Self {
+        self.flags.icase = yes;
+        self
     }
 
-    /// Returns an iterator for matches found in 'text' starting at byte index
-    /// `start`. Note this may be different from passing a sliced `text` in
-    /// the case of lookbehind assertions.
+    /// Sets whether `^` and `$` match at line terminators, like the 'm' flag.
+    #[inline]
+    pub fn multiline(&mut self, yes: bool) -> &mut Self {
+        self.flags.multiline = yes;
+        self
+    }
+
+    /// Sets whether `.` matches line terminators, like the 's' flag.
+    #[inline]
+    pub fn dot_all(&mut self, yes: bool) -> &mut Self {
+        self.flags.dot_all = yes;
+        self
+    }
+
+    /// Sets whether whitespace and `#` comments in the pattern are ignored, like
+    /// the 'x' flag.
+    #[inline]
+    pub fn extended(&mut self, yes: bool) -> &mut Self {
+        self.flags.extended = yes;
+        self
+    }
+
+    /// Sets whether the pattern is a Unicode regex, like the 'u' flag.
+    #[inline]
+    pub fn unicode(&mut self, yes: bool) -> &mut Self {
+        self.flags.unicode = yes;
+        self
+    }
+
+    /// Sets whether the pattern is a Unicode regex with set operations in
+    /// brackets, like the 'v' flag.
+    #[inline]
+    pub fn unicode_sets(&mut self, yes: bool) -> &mut Self {
+        self.flags.unicode_sets = yes;
+        self
+    }
+
+    /// Sets whether matches must begin exactly at the search position, like the
+    /// 'y' flag.
+    #[inline]
+    pub fn sticky(&mut self, yes: bool) -> &mut Self {
+        self.flags.sticky = yes;
+        self
+    }
+
+    /// Sets whether the caller intends to use the ranges of capture groups,
+    /// like the 'd' flag.
+    #[inline]
+    pub fn has_indices(&mut self, yes: bool) -> &mut Self {
+        self.flags.has_indices = yes;
+        self
+    }
+
+    /// Sets whether `\R` and `\N` are recognized; see
+    /// [`Flags::line_break_escapes`].
+    #[inline]
+    pub fn line_break_escapes(&mut self, yes: bool) -> &mut Self {
+        self.flags.line_break_escapes = yes;
+        self
+    }
+
+    /// Sets whether `\<` and `\>` are recognized; see
+    /// [`Flags::word_edge_escapes`].
+    #[inline]
+    pub fn word_edge_escapes(&mut self, yes: bool) -> &mut Self {
+        self.flags.word_edge_escapes = yes;
+        self
+    }
+
+    /// Sets whether `.` always matches line terminators, regardless of the 's'
+    /// flag; see [`Flags::dot_matches_all`].
+    #[inline]
+    pub fn dot_matches_all(&mut self, yes: bool) -> &mut Self {
+        self.flags.dot_matches_all = yes;
+        self
+    }
+
+    /// Sets which characters are word characters for `\b`, `\B`, `\<` and
+    /// `\>`, overriding the choice made by the 'u' and 'v' flags.
+    ///
     /// Example:
     ///
     ///  ```rust
-    ///   use regress::Regex;
-    ///   let text = "xyxy";
-    ///   let re = Regex::new(r"(?<=x)y").unwrap();
-    ///   let t1 = re.find(&text[1..]).unwrap().range();
-    ///   assert!(t1 == (2..3));
-    ///   let t2 = re.find_from(text, 1).next().unwrap().range();
-    ///   assert!(t2 == (1..2));
+    ///   use regress::{RegexBuilder, WordBoundary};
+    ///   let build = |wb| RegexBuilder::new(r"\bé").word_boundary(wb).build().unwrap();
+    ///   assert!(build(WordBoundary::Ascii).find("café").is_some());
+    ///   assert!(build(WordBoundary::Unicode).find("café").is_none());
     ///   ```
     #[inline]
-    pub fn find_from<'r, 't>(&'r self, text: &'t str, start: usize) -> Matches<'r, 't> {
-        backends::find(self, text, start)
+    pub fn word_boundary(&mut self, strategy: WordBoundary) -> &mut Self {
+        self.word_boundary = Some(strategy);
+        self
     }
 
-    /// Searches `text` to find the first match.
-    /// The input text is expected to be ascii-only: only ASCII case-folding is
-    /// supported.
+    /// Sets whether to disable the optimizer.
     #[inline]
-    pub fn find_ascii(&self, text: &str) -> Option<Match> {
-        self.find_iter_ascii(text).next()
+    pub fn no_opt(&mut self, yes: bool) -> &mut Self {
+        self.flags.no_opt = yes;
+        self
     }
 
-    /// Searches `text`, returning an iterator over non-overlapping matches.
-    /// The input text is expected to be ascii-only: only ASCII case-folding is
-    /// supported.
+    /// Sets the step budget used by [`Regex::try_find`]; see
+    /// [`Regex::find_with_budget`] for how steps are counted.
     #[inline]
-    pub fn find_iter_ascii<'r, 't>(&'r self, text: &'t str) -> AsciiMatches<'r, 't> {
-        self.find_from_ascii(text, 0)
+    pub fn step_budget(&mut self, max_steps: usize) -> &mut Self {
+        self.step_budget = Some(max_steps);
+        self
     }
 
-    /// Returns an iterator for matches found in 'text' starting at byte index
-    /// `start`.
+    /// Sets the unit in which the regex also reports the ranges of its
+    /// matches and their groups within a `&str`, which defaults to
+    /// [`OffsetUnit::Bytes`].
+    ///
+    /// Every offset which a method of the regex takes or returns for a `&str`
+    /// is a byte offset, whatever the unit: the ranges of [`Match`] and
+    /// [`Captures`], so that [`Match::as_str`] and the like slice the text as
+    /// usual, the `start` offsets passed to [`Regex::find_from`],
+    /// [`Regex::find_iter_from`], [`Regex::find_anchored`],
+    /// [`Regex::find_into`] and [`Regex::is_match_at`], the end returned by
+    /// [`Regex::shortest_match`], [`FailureReport::offset`] and the offsets
+    /// of [`IncrementalMatcher`].
+    /// So the end of one match may be passed back to search for the next.
+    /// The unit is reported only through separate accessors:
+    /// [`Match::unit_range`], [`Match::unit_group`], [`Captures::unit_get`]
+    /// and [`FailureReport::unit_offset`]. The iterators count the units as
+    /// they advance from one match to the next, so an iteration costs about
+    /// one pass over the text rather than one per match. Methods which search
+    /// other inputs, like [`Regex::find_utf16`], take and report indexes into
+    /// that input.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{OffsetUnit, RegexBuilder};
+    ///   let text = "\u{1F600}=\u{E9}=x";
+    ///   let build = |unit| RegexBuilder::new(r"=(.)").offset_unit(unit).build().unwrap();
+    ///   let m = build(OffsetUnit::Bytes).find(text).unwrap();
+    ///   assert_eq!((m.unit_range(), m.unit_group(1)), (4..7, Some(5..7)));
+    ///   let m = build(OffsetUnit::Utf16).find(text).unwrap();
+    ///   assert_eq!((m.unit_range(), m.unit_group(1)), (2..4, Some(3..4)));
+    ///   let re = build(OffsetUnit::CodePoints);
+    ///   let m = re.find(text).unwrap();
+    ///   assert_eq!((m.unit_range(), m.unit_group(1)), (1..3, Some(2..3)));
+    ///   assert_eq!(m.as_str(text), "=\u{E9}");
+    ///   let next = re.find_from(text, m.end()).next().unwrap();
+    ///   assert_eq!((next.unit_range(), next.as_str(text)), (3..5, "=x"));
+    ///   ```
     #[inline]
-    pub fn find_from_ascii<'r, 't>(&'r self, text: &'t str, start: usize) -> AsciiMatches<'r, 't> {
-        backends::find(self, text, start)
+    pub fn offset_unit(&mut self, unit: OffsetUnit) -> &mut Self {
+        self.offset_unit = unit;
+        self
+    }
+
+    /// Sets how deeply groups and nested classes may nest, which defaults to
+    /// [`DEFAULT_MAX_NESTING_DEPTH`]. Parsing and compiling a pattern recurse
+    /// once per level, so a deeper pattern is rejected with an Error rather
+    /// than risk overflowing the stack. Raise it only if the thread building
+    /// the regex has stack to spare. Alternatives are not counted: however
+    /// many there are, they add only logarithmically to the depth.
+    #[inline]
+    pub fn max_nesting_depth(&mut self, depth: usize) -> &mut Self {
+        self.limits.max_depth = depth;
+        self
+    }
+
+    /// Sets the largest count which a quantifier like `{n}` or `{n,m}` may
+    /// give. By default any count is accepted, as in ECMAScript; a lower
+    /// limit rejects patterns like `a{1000000}` with an Error. Unbounded
+    /// quantifiers like `*` and `{n,}` have no upper count, so only their
+    /// lower count is checked.
+    #[inline]
+    pub fn max_repetition(&mut self, count: usize) -> &mut Self {
+        self.limits.max_repetition = count;
+        self
+    }
+
+    /// Parses the pattern with the configured flags, as
+    /// [`Regex::with_flags`] does.
+    pub fn build(&self) -> Result<Regex, Error> {
+        let mut re = Regex::compile(self.pattern.chars().map(u32::from), self.flags, self.limits)?;
+        re.step_budget = self.step_budget;
+        re.offset_unit = self.offset_unit;
+        if let Some(wb) = &self.word_boundary {
+            Arc::make_mut(&mut re.cr).set_word_boundary(wb.clone());
+        }
+        Ok(re)
     }
 }
 
-impl FromStr for Regex {
-    type Err = Error;
+/// A collection of regexes which may be tested against a string together,
+/// reporting which of them match.
+///
+/// The patterns without backreferences, lookarounds or atomic groups are
+/// matched together by one lazy DFA, which reads the text once however many
+/// patterns there are, and whose states are kept between searches. Each of
+/// the other patterns is searched for separately.
+///
+/// Example:
+///
+///  ```rust
+///   use regress::RegexSet;
+///   let set = RegexSet::new(&[r"\d+", r"^[a-z]+$", r"foo"]).unwrap();
+///   let hits: Vec<usize> = set.matches("foo123").collect();
+///   assert_eq!(hits, vec![0, 2]);
+///   assert!(set.is_match_any("bar"));
+///   assert!(!set.is_match_any("BAR"));
+///   ```
+#[derive(Debug, Clone)]
+pub struct RegexSet {
+    regexes: Vec<Regex>,
+    /// Whether each regex is matched by the DFA rather than separately.
+    regular: Vec<bool>,
+    dfa_caches: DfaCachePool,
+}
 
-    /// Attempts to parse a string into a regular expression
+impl RegexSet {
+    /// Construct a set by parsing each of `patterns` using the default flags.
+    /// An Error is returned if any pattern is invalid; its text names the
+    /// index of the offending pattern.
+    pub fn new<I, S>(patterns: I) -> Result<RegexSet, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::with_flags(patterns, Flags::default())
+    }
+
+    /// Construct a set by parsing each of `patterns` with `flags`.
+    pub fn with_flags<I, S, F>(patterns: I, flags: F) -> Result<RegexSet, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: Into<Flags>,
+    {
+        let flags = flags.into();
+        let mut regexes = Vec::new();
+        for (idx, pattern) in patterns.into_iter().enumerate() {
+            match Regex::with_flags(pattern.as_ref(), flags) {
+                Ok(re) => regexes.push(re),
+                Err(err) => {
+                    return Err(err.map_text(|text| format!("{} (in pattern {})", text, idx)))
+                }
+            }
+        }
+        let regular = regexes
+            .iter()
+            .map(|re| lazydfa::is_regular(&re.cr))
+            .collect();
+        Ok(RegexSet {
+            regexes,
+            regular,
+            dfa_caches: DfaCachePool::default(),
+        })
+    }
+
+    /// Returns the number of patterns in the set.
     #[inline]
-    fn from_str(s: &str) -> Result<Self, Error> {
-        Self::new(s)
+    pub fn len(&self) -> usize {
+        self.regexes.len()
+    }
+
+    /// Returns whether the set contains no patterns.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.regexes.is_empty()
+    }
+
+    /// Returns the regexes of the set, in the order they were given.
+    #[inline]
+    pub fn regexes(&self) -> &[Regex] {
+        &self.regexes
+    }
+
+    /// Returns an iterator over the indices of the patterns which match
+    /// anywhere in `text`, in increasing order. The patterns matched by the
+    /// DFA are tested when this is called, and the others lazily as the
+    /// iterator advances.
+    pub fn matches<'s, 't>(&'s self, text: &'t str) -> SetMatches<'s, 't> {
+        SetMatches {
+            regexes: self.regexes.iter().enumerate(),
+            regular: &self.regular,
+            found: self.dfa_matches(text, false),
+            text,
+        }
+    }
+
+    /// Returns whether any pattern matches `text`, stopping at the first hit.
+    pub fn is_match_any(&self, text: &str) -> bool {
+        self.dfa_matches(text, true).contains(&true)
+            || self
+                .regexes
+                .iter()
+                .zip(&self.regular)
+                .any(|(re, &regular)| !regular && re.find(text).is_some())
+    }
+
+    /// \return for each regex whether it is matched by the DFA and matches
+    /// \p text, or only some regex which does if \p first_only is set.
+    fn dfa_matches(&self, text: &str, first_only: bool) -> Vec<bool> {
+        let programs: Vec<&CompiledRegex> = self
+            .regexes
+            .iter()
+            .zip(&self.regular)
+            .filter_map(|(re, &regular)| regular.then_some(&*re.cr))
+            .collect();
+        let mut found =
+            lazydfa::set_matches(&programs, &self.dfa_caches, text, first_only).into_iter();
+        self.regular
+            .iter()
+            .map(|&regular| regular && found.next().unwrap())
+            .collect()
+    }
+}
+
+/// An iterator over the indices of the patterns in a [`RegexSet`] which match
+/// a string.
+///
+/// This struct is created by the [`matches`] method on [`RegexSet`].
+///
+/// [`RegexSet`]: ../struct.RegexSet.html
+/// [`matches`]: ../struct.RegexSet.html#method.matches
+#[derive(Debug, Clone)]
+pub struct SetMatches<'s, 't> {
+    regexes: core::iter::Enumerate<core::slice::Iter<'s, Regex>>,
+    regular: &'s [bool],
+    /// Whether each regex matched by the DFA matches.
+    found: Vec<bool>,
+    text: &'t str,
+}
+
+impl<'s, 't> Iterator for SetMatches<'s, 't> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (text, regular, found) = (self.text, self.regular, &self.found);
+        self.regexes
+            .find(|&(idx, re)| {
+                if regular[idx] {
+                    found[idx]
+                } else {
+                    re.find(text).is_some()
+                }
+            })
+            .map(|(idx, _)| idx)
     }
 }
 
@@ -397,8 +4201,12 @@ pub mod backends {
     use super::indexing;
     use super::Regex;
     pub use crate::emit::emit;
+    use crate::insn::CompiledRegex;
+    use crate::lazydfa;
     pub use crate::optimizer::optimize;
     pub use crate::parse::try_parse;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
 
     /// An Executor using the classical backtracking algorithm.
     pub type BacktrackExecutor<'r, 't> =
@@ -415,13 +4223,389 @@ pub mod backends {
     pub type DefaultAsciiExecutor<'r, 't> =
         <DefaultExecutor<'r, 't> as exec::Executor<'r, 't>>::AsAscii;
 
+    /// \return \p start, rounded up to a char boundary of \p text if it is
+    /// within \p text.
+    fn char_boundary_at_or_after(text: &str, mut start: usize) -> usize {
+        while start < text.len() && !text.is_char_boundary(start) {
+            start += 1;
+        }
+        start
+    }
+
     /// Searches `text`, returning an iterator over non-overlapping matches.
     pub fn find<'r, 't, Executor: exec::Executor<'r, 't>>(
         re: &'r Regex,
         text: &'t str,
         start: usize,
     ) -> exec::Matches<Executor> {
-        exec::Matches::new(Executor::new(&re.cr, text), start)
+        let start = char_boundary_at_or_after(text, start);
+        if re.cr.flags.sticky {
+            find_sticky(re, text, start)
+        } else {
+            exec::Matches::new(Executor::new(&re.cr, text), start)
+        }
+    }
+
+    /// Searches `text`, returning an iterator over matches which each begin
+    /// exactly where the previous match ended, starting at `start`.
+    pub fn find_sticky<'r, 't, Executor: exec::Executor<'r, 't>>(
+        re: &'r Regex,
+        text: &'t str,
+        start: usize,
+    ) -> exec::Matches<Executor> {
+        let start = char_boundary_at_or_after(text, start);
+        exec::Matches::new_sticky(Executor::new(&re.cr, text), start)
+    }
+
+    /// The shortest text, in bytes after the start, which `is_match` searches
+    /// with the lazy DFA. On shorter texts, building the states costs more
+    /// than backtracking.
+    const DFA_MIN_TEXT_LEN: usize = 8;
+
+    /// Returns whether `text` contains a match at or after `start`, using
+    /// the lazy DFA if the pattern is regular, and otherwise the backtracker
+    /// without recording capture groups.
+    pub fn is_match(re: &Regex, text: &str, start: usize) -> bool {
+        use exec::Executor;
+        let start = char_boundary_at_or_after(text, start);
+        if start > text.len() {
+            return false;
+        }
+        if text.len() - start >= DFA_MIN_TEXT_LEN && lazydfa::is_regular(&re.cr) {
+            return lazydfa::is_match(&re.cr, text, start);
+        }
+        let executor = BacktrackExecutor::new(&re.cr, text).without_captures();
+        if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, start).next_exists()
+        } else {
+            exec::Matches::new(executor, start).next_exists()
+        }
+    }
+
+    /// Returns the number of non-overlapping matches in `text`, but at most
+    /// `limit`, stopping the search once `limit` are found. No capture groups
+    /// are recorded.
+    pub fn count_matches_up_to(re: &Regex, text: &str, limit: usize) -> usize {
+        use exec::Executor;
+        let executor = BacktrackExecutor::new(&re.cr, text).without_captures();
+        let mut matches = if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, 0)
+        } else {
+            exec::Matches::new(executor, 0)
+        };
+        let mut count = 0;
+        while count < limit && matches.next_exists() {
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns, for each code point of `text` and for its end, whether a
+    /// match begins there.
+    pub fn match_starts(re: &Regex, text: &str) -> Vec<bool> {
+        use exec::Executor;
+        if lazydfa::is_regular(&re.cr) {
+            return lazydfa::match_starts(&re.cr, text);
+        }
+        // Each search finds the leftmost match start at or after where it
+        // begins, so the positions it passes over begin no match. The sticky
+        // flag is ignored since every start is tried anyway.
+        let mut starts = vec![false; text.chars().count() + 1];
+        let executor = BacktrackExecutor::new(&re.cr, text).without_captures();
+        let mut matches = exec::Matches::new(executor, 0);
+        let mut cursor = (0, 0);
+        while let Some(m) = matches.next() {
+            let start = m.start();
+            cursor.1 += text[cursor.0..start].chars().count();
+            cursor.0 = start;
+            starts[cursor.1] = true;
+            match text[start..].chars().next() {
+                Some(c) => matches.set_start(start + c.len_utf8()),
+                None => break,
+            }
+        }
+        starts
+    }
+
+    /// Finds the first match at or after `start` with the backtracker,
+    /// passing each step to `tracer`.
+    #[cfg(feature = "trace")]
+    pub fn find_traced(
+        re: &Regex,
+        text: &str,
+        start: usize,
+        tracer: &mut dyn FnMut(&crate::trace::TraceEvent<'_>),
+    ) -> Option<super::Match> {
+        use exec::Executor;
+        let start = char_boundary_at_or_after(text, start);
+        let executor = BacktrackExecutor::new(&re.cr, text).with_tracer(tracer);
+        if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, start).next()
+        } else {
+            exec::Matches::new(executor, start).next()
+        }
+    }
+
+    /// Returns the end of the shortest match beginning at the leftmost
+    /// position at or after `start` which matches.
+    pub fn shortest_match(re: &Regex, text: &str, start: usize) -> Option<usize> {
+        use exec::Executor;
+        let start = char_boundary_at_or_after(text, start);
+        let executor = BacktrackExecutor::new(&re.cr, text)
+            .without_captures()
+            .shortest_matches();
+        let m = if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, start).next()
+        } else {
+            exec::Matches::new(executor, start).next()
+        };
+        m.map(|m| m.end())
+    }
+
+    /// Searches `text` from `start` with the backtracker, recording only the
+    /// capture groups in `groups` and those which backreferences refer to.
+    pub fn find_with_groups<'r, 't>(
+        re: &'r Regex,
+        text: &'t str,
+        start: usize,
+        groups: &[usize],
+    ) -> super::RawMatches<'r, 't> {
+        use exec::Executor;
+        let start = char_boundary_at_or_after(text, start);
+        let executor = BacktrackExecutor::new(&re.cr, text).with_captures_only(groups);
+        if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, start)
+        } else {
+            exec::Matches::new(executor, start)
+        }
+    }
+
+    /// Searches `text` with the backtracker for the first match of `cr`, a
+    /// program folded by CompiledRegex::ascii_case_folded, seeing the ASCII
+    /// letters of `text` in lowercase. If `groups` is set, only those groups
+    /// and those which backreferences refer to are recorded.
+    pub fn find_ascii_folded(
+        cr: &super::CompiledRegex,
+        text: &str,
+        groups: Option<&[usize]>,
+    ) -> Option<super::Match> {
+        let mut executor = super::classicalbacktrack::BacktrackExecutor::new_ascii_folded(cr, text);
+        if let Some(groups) = groups {
+            executor = executor.with_captures_only(groups);
+        }
+        if cr.flags.sticky {
+            exec::Matches::new_sticky(executor, 0).next()
+        } else {
+            exec::Matches::new(executor, 0).next()
+        }
+    }
+
+    /// Searches `text` from `start` with the memoizing backtracker,
+    /// returning an iterator over non-overlapping matches.
+    pub fn find_memoized<'r, 't>(
+        re: &'r Regex,
+        text: &'t str,
+        start: usize,
+    ) -> super::RawMatches<'r, 't> {
+        use exec::Executor;
+        let start = char_boundary_at_or_after(text, start);
+        let executor = BacktrackExecutor::new(&re.cr, text).memoized();
+        if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, start)
+        } else {
+            exec::Matches::new(executor, start)
+        }
+    }
+
+    /// Returns whether the whole of `text` matches.
+    pub fn full_match(re: &Regex, text: &str) -> bool {
+        use exec::{Executor, MatchProducer};
+        // A text whose length no match can have is rejected without matching.
+        let (min_length, max_length) = re.cr.match_length_bounds;
+        if text.len() < min_length || max_length.is_some_and(|max| text.len() > max) {
+            return false;
+        }
+        let mut executor = BacktrackExecutor::new(&re.cr, text)
+            .without_captures()
+            .anchored_end();
+        match executor.initial_position(0) {
+            Some(pos) => executor.match_at_exists(pos, &mut None),
+            None => false,
+        }
+    }
+
+    /// Returns the last of the matches which [`find`] would return for `text`.
+    pub fn rfind<'r, 't, Executor: exec::Executor<'r, 't>>(
+        re: &'r Regex,
+        text: &'t str,
+    ) -> Option<super::Match> {
+        // Without a bound on the length of a match, one starting anywhere
+        // before the rightmost may cover it, so search forwards.
+        let max_length = match re.cr.match_length_bounds.1 {
+            Some(max) if !re.cr.flags.sticky => max,
+            _ => return find::<Executor>(re, text, 0).last(),
+        };
+
+        // Try each start from the right. The match at a start is the one which
+        // find would report if it reached it.
+        let mut executor = Executor::new(&re.cr, text);
+        let mut match_at = |start: usize| {
+            let pos = executor.initial_position(start)?;
+            executor.match_at(pos, &mut None)
+        };
+        let mut starts = text
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain(core::iter::once(text.len()))
+            .rev();
+        let (last_start, last) = starts
+            .by_ref()
+            .find_map(|start| match_at(start).map(|m| (start, m)))?;
+
+        // The last match of find is either this one, or one which begins
+        // earlier and extends past its start. In the latter case, which one
+        // depends on the earlier matches.
+        let covered = starts
+            .take_while(|&start| start.saturating_add(max_length) > last_start)
+            .any(|start| match_at(start).is_some_and(|m| m.end() > last_start));
+        if covered {
+            find::<Executor>(re, text, 0).last()
+        } else {
+            Some(last)
+        }
+    }
+
+    /// Attempts a single match beginning exactly at `start` in `text`.
+    pub fn find_anchored<'r, 't, Executor: exec::Executor<'r, 't>>(
+        re: &'r Regex,
+        text: &'t str,
+        start: usize,
+    ) -> Option<super::Match> {
+        let start = char_boundary_at_or_after(text, start);
+        let mut executor = Executor::new(&re.cr, text);
+        let pos = executor.initial_position(start)?;
+        executor.match_at(pos, &mut None)
+    }
+
+    /// Searches `text` with the backtracking executor for the first match at
+    /// or after `start`, writing it into `caps`, whose scratch storage the
+    /// executor uses.
+    pub fn find_into(re: &Regex, text: &str, start: usize, caps: &mut super::Captures) -> bool {
+        let start = char_boundary_at_or_after(text, start);
+        let executor = BacktrackExecutor::with_scratch(&re.cr, text, &mut caps.scratch);
+        let mut matches = if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, start)
+        } else {
+            exec::Matches::new(executor, start)
+        };
+        let matched = matches.next_into(&mut caps.groups);
+        matches.into_producer().into_scratch(&mut caps.scratch);
+        if !matched {
+            caps.groups.clear();
+        } else if caps.named_captures != re.cr.named_group_indices {
+            caps.named_captures.clone_from(&re.cr.named_group_indices);
+        }
+        matched
+    }
+
+    /// Searches the UTF-8 `bytes` with the backtracking executor to find the
+    /// first match.
+    pub fn find_bytes(re: &Regex, bytes: &[u8]) -> Option<super::Match> {
+        let executor = super::classicalbacktrack::BacktrackExecutor::new_bytes(&re.cr, bytes);
+        if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, 0).next()
+        } else {
+            exec::Matches::new(executor, 0).next()
+        }
+    }
+
+    /// Searches a user-provided `input` with the backtracking executor to
+    /// find the first match.
+    pub fn find_input<I: super::Input + ?Sized>(re: &Regex, input: &I) -> Option<super::Match> {
+        let executor = super::classicalbacktrack::BacktrackExecutor::new_input(&re.cr, input);
+        if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, 0).next()
+        } else {
+            exec::Matches::new(executor, 0).next()
+        }
+    }
+
+    /// Searches UTF-16 `text` with the backtracking executor to find the
+    /// first match at or after the code unit index `start`, taking at most
+    /// `max_steps` backtracks if given.
+    pub fn find_utf16(
+        re: &Regex,
+        text: &[u16],
+        start: usize,
+        max_steps: Option<usize>,
+    ) -> Result<Option<super::Match>, super::BudgetExceeded> {
+        let text = indexing::Utf16Text::new(text, re.pairs_surrogates());
+        let Some(start) = text.byte_offset(start) else {
+            return Ok(None);
+        };
+        let program = re.utf16_search_program();
+        let m = find_wtf8(re, program.as_deref(), &text, start, max_steps)?;
+        Ok(m.map(|m| utf16_match(&text, m)))
+    }
+
+    /// Searches the transcoded UTF-16 `text` to find the first match at or
+    /// after the byte offset `start`, with `program` if given or else the
+    /// program of `re`. The ranges of the match are byte offsets.
+    pub(crate) fn find_wtf8(
+        re: &Regex,
+        program: Option<&CompiledRegex>,
+        text: &indexing::Utf16Text,
+        start: usize,
+        max_steps: Option<usize>,
+    ) -> Result<Option<super::Match>, super::BudgetExceeded> {
+        let cr = program.unwrap_or(&re.cr);
+        let mut executor = super::classicalbacktrack::BacktrackExecutor::new_wtf8(cr, text.bytes());
+        if let Some(max_steps) = max_steps {
+            executor = executor.with_step_limit(max_steps);
+        }
+        let mut matches = if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, start)
+        } else {
+            exec::Matches::new(executor, start)
+        };
+        let m = matches.next();
+        if matches.producer().step_limit_exceeded() {
+            return Err(super::BudgetExceeded);
+        }
+        Ok(m)
+    }
+
+    /// Convert the byte ranges of \p m, a match in \p text, to code unit
+    /// indexes.
+    pub(crate) fn utf16_match(text: &indexing::Utf16Text, mut m: super::Match) -> super::Match {
+        let to_units = |r: super::Range| text.unit_offset(r.start)..text.unit_offset(r.end);
+        m.range = to_units(m.range);
+        for r in m.captures.iter_mut().flatten() {
+            *r = to_units(r.clone());
+        }
+        m
+    }
+
+    /// Searches `text` with the backtracking executor to find the first
+    /// match, taking at most `max_steps` backtracks.
+    pub fn find_with_budget(
+        re: &Regex,
+        text: &str,
+        max_steps: usize,
+    ) -> Result<Option<super::Match>, super::BudgetExceeded> {
+        use exec::Executor;
+        let executor = BacktrackExecutor::new(&re.cr, text).with_step_limit(max_steps);
+        let mut matches = if re.cr.flags.sticky {
+            exec::Matches::new_sticky(executor, 0)
+        } else {
+            exec::Matches::new(executor, 0)
+        };
+        let m = matches.next();
+        if matches.producer().step_limit_exceeded() {
+            Err(super::BudgetExceeded)
+        } else {
+            Ok(m)
+        }
     }
 
     /// Searches `text`, returning an iterator over non-overlapping matches.
@@ -20,6 +20,9 @@ pub trait ByteSeq: ByteSearcher + core::fmt::Debug + Copy + Clone {
     /// Test if a slice is equal.
     /// The slice must have exactly LENGTH bytes.
     fn equals_known_len(&self, rhs: &[u8]) -> bool;
+
+    /// \return the bytes themselves.
+    fn as_bytes(&self) -> &[u8];
 }
 
 extern "C" {
@@ -43,6 +46,11 @@ impl<const N: usize> ByteSeq for [u8; N] {
             unsafe { memcmp(self.as_ptr(), rhs.as_ptr(), Self::LENGTH) == 0 }
         }
     }
+
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
 }
 
 impl<const N: usize> ByteSearcher for [u8; N] {
@@ -343,6 +351,69 @@ impl fmt::Debug for ByteBitmap {
     }
 }
 
+/// \return the number of leading ASCII bytes of \p bytes.
+/// With the simd feature on x86_64 this tests 16 bytes at a time with SSE2.
+#[cfg(all(
+    feature = "simd",
+    target_arch = "x86_64",
+    not(feature = "prohibit-unsafe")
+))]
+#[inline]
+pub fn ascii_prefix_len(bytes: &[u8]) -> usize {
+    use core::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_movemask_epi8};
+    const LANES: usize = 16;
+    let mut offset = 0;
+    while offset + LANES <= bytes.len() {
+        // SSE2 is always available on x86_64, and the load may be unaligned.
+        // The mask has a bit set for each byte with its high bit set.
+        let mask = unsafe {
+            let chunk = _mm_loadu_si128(bytes.as_ptr().add(offset) as *const __m128i);
+            _mm_movemask_epi8(chunk)
+        };
+        if mask != 0 {
+            return offset + mask.trailing_zeros() as usize;
+        }
+        offset += LANES;
+    }
+    offset + scalar_ascii_prefix_len(&bytes[offset..])
+}
+
+/// \return the number of leading ASCII bytes of \p bytes.
+#[cfg(not(all(
+    feature = "simd",
+    target_arch = "x86_64",
+    not(feature = "prohibit-unsafe")
+)))]
+#[inline]
+pub fn ascii_prefix_len(bytes: &[u8]) -> usize {
+    scalar_ascii_prefix_len(bytes)
+}
+
+/// \return the number of leading ASCII bytes of \p bytes, testing 8 bytes at
+/// a time.
+#[inline]
+fn scalar_ascii_prefix_len(bytes: &[u8]) -> usize {
+    const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+    let mut chunks = bytes.chunks_exact(8);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        let mut word = [0; 8];
+        word.copy_from_slice(chunk);
+        // Use LE, so that the lowest set bit is in the earliest byte.
+        let high = u64::from_le_bytes(word) & HIGH_BITS;
+        if high != 0 {
+            return offset + (high.trailing_zeros() / 8) as usize;
+        }
+        offset += 8;
+    }
+    offset
+        + chunks
+            .remainder()
+            .iter()
+            .take_while(|b| b.is_ascii())
+            .count()
+}
+
 /// A trivial ByteSearcher corresponding to the empty string.
 #[derive(Debug, Copy, Clone)]
 pub struct EmptyString {}
@@ -393,6 +464,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ascii_prefix() {
+        assert_eq!(ascii_prefix_len(b""), 0);
+        assert_eq!(ascii_prefix_len(b"abc"), 3);
+        assert_eq!(ascii_prefix_len("\u{e9}abc".as_bytes()), 0);
+        let mut bytes = [b'x'; 100];
+        assert_eq!(ascii_prefix_len(&bytes), 100);
+        for idx in [0, 7, 8, 15, 16, 17, 31, 64, 99] {
+            bytes[idx] = 0x80;
+            assert_eq!(ascii_prefix_len(&bytes), idx);
+            assert_eq!(scalar_ascii_prefix_len(&bytes), idx);
+            assert_eq!(ascii_prefix_len(&bytes[idx + 1..]), 100 - idx - 1);
+            bytes[idx] = b'x';
+        }
+    }
+
     #[test]
     fn literal_search() {
         assert_eq!([0, 1, 2, 3].find_in(&[4, 5, 6, 7]), None);
@@ -77,5 +77,14 @@ pub const WHITESPACE: [Interval; 9] = [
 ];
 
 /// ES9 11.3
+/// This must agree with is_line_terminator().
 pub const LINE_TERMINATOR: [Interval; 3] =
     [r1('\u{000A}'), r1('\u{000D}'), r('\u{2028}', '\u{2029}')];
+
+/// \return whether \p c is a line terminator, which are excluded by '.' and
+/// delimit lines for multiline '^' and '$'. These are the same with or
+/// without the unicode flag.
+#[inline(always)]
+pub fn is_line_terminator(c: u32) -> bool {
+    matches!(c, 0x000A | 0x000D | 0x2028 | 0x2029)
+}
@@ -1,23 +1,45 @@
 //! Classical backtracking execution engine
 
-use crate::api::Match;
+use crate::api::{Match, OffsetUnit};
 use crate::bytesearch;
 use crate::cursor;
 use crate::cursor::{Backward, Direction, Forward};
 use crate::exec;
 use crate::indexing;
-use crate::indexing::{AsciiInput, ElementType, InputIndexer, Utf8Input};
-use crate::insn::{CompiledRegex, Insn, LoopFields, StartPredicate};
+use crate::indexing::{
+    AsciiFoldedInput, AsciiInput, CustomInput, ElementType, Input, InputIndexer, Utf8BytesInput,
+    Utf8Input, Wtf8Input,
+};
+use crate::insn::{is_word_edge, CompiledRegex, Insn, LoopFields, StartPredicate};
 use crate::matchers;
 use crate::matchers::CharProperties;
 use crate::position::PositionType;
 use crate::scm;
 use crate::scm::SingleCharMatcher;
+#[cfg(feature = "trace")]
+use crate::trace::{Instruction, TraceEvent, Tracer};
 use crate::types::{CaptureGroupID, GroupData, LoopData, LoopID, IP, MAX_CAPTURE_GROUPS};
 use crate::util::DebugCheckIndex;
-#[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
 use core::ops::Range;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(not(feature = "std"))]
+use {alloc::vec::Vec, hashbrown::HashSet};
+
+/// Report a trace event, if a tracer is installed. The event is only
+/// constructed when it is wanted, and without the trace feature this compiles
+/// to nothing.
+macro_rules! trace {
+    ($matcher:expr, $event:expr) => {
+        #[cfg(feature = "trace")]
+        if $matcher.tracer.is_some() {
+            let event = $event;
+            if let Some(tracer) = &mut $matcher.tracer {
+                (tracer.0)(&event);
+            }
+        }
+    };
+}
 
 #[derive(Clone, Debug)]
 enum BacktrackInsn<Input: InputIndexer> {
@@ -42,6 +64,8 @@ enum BacktrackInsn<Input: InputIndexer> {
         // The IP of the loop.
         // This is guaranteed to point to an EnterLoopInsn.
         ip: IP,
+        // The position at which to enter it, and its data before entering.
+        pos: Input::Position,
         data: LoopData<Input::Position>,
     },
 
@@ -64,25 +88,253 @@ struct State<Position: PositionType> {
     groups: Vec<GroupData<Position>>,
 }
 
+/// The states visited by a memoizing MatchAttempter; see visit_loop_state.
+#[derive(Debug)]
+struct Memo {
+    visited: HashSet<Vec<usize>>,
+    // The minimum and maximum iterations of each loop, by loop ID.
+    loop_bounds: Vec<(usize, usize)>,
+}
+
+/// The furthest position reached moving forwards, and the instructions run
+/// there; see BacktrackExecutor::tracking_furthest.
+#[derive(Debug)]
+struct Furthest<Position: PositionType> {
+    pos: Position,
+    ips: Vec<IP>,
+}
+
+impl<Position: PositionType> Furthest<Position> {
+    /// Record that the instruction at \p ip is run at \p pos.
+    fn visit(&mut self, ip: IP, pos: Position) {
+        if pos > self.pos {
+            self.pos = pos;
+            self.ips.clear();
+        }
+        if pos == self.pos && !self.ips.contains(&ip) {
+            self.ips.push(ip);
+        }
+    }
+}
+
+/// The storage of a MatchAttempter, which may outlive it to be reused.
+#[derive(Debug)]
+struct Buffers<Input: InputIndexer> {
+    bts: Vec<BacktrackInsn<Input>>,
+    loops: Vec<LoopData<Input::Position>>,
+    groups: Vec<GroupData<Input::Position>>,
+    spare_bts: Vec<Vec<BacktrackInsn<Input>>>,
+    spare_groups: Vec<Vec<GroupData<Input::Position>>>,
+}
+
+impl<Input: InputIndexer> Default for Buffers<Input> {
+    fn default() -> Self {
+        Self {
+            bts: Vec::new(),
+            loops: Vec::new(),
+            groups: Vec::new(),
+            spare_bts: Vec::new(),
+            spare_groups: Vec::new(),
+        }
+    }
+}
+
+#[cfg(not(feature = "prohibit-unsafe"))]
+impl<Input: InputIndexer> Buffers<Input> {
+    /// \return these empty buffers for another input type of the same
+    /// layout, such as the same type over a text of another lifetime,
+    /// keeping their allocations.
+    fn recycle<Other>(self) -> Buffers<Other>
+    where
+        Other: InputIndexer,
+    {
+        Buffers {
+            bts: recycle(self.bts),
+            loops: recycle(self.loops),
+            groups: recycle(self.groups),
+            spare_bts: self.spare_bts.into_iter().map(recycle).collect(),
+            spare_groups: self.spare_groups.into_iter().map(recycle).collect(),
+        }
+    }
+}
+
+/// \return \p v emptied, as a vector of another element type. If the types
+/// have the same layout, the allocation is kept.
+#[cfg(not(feature = "prohibit-unsafe"))]
+fn recycle<T, U>(mut v: Vec<T>) -> Vec<U> {
+    v.clear();
+    v.into_iter().map(|_| unreachable!()).collect()
+}
+
+/// The storage of a backtracking search of a `&str`, kept between searches
+/// so that it need not be allocated again; see Regex::find_into. With the
+/// prohibit-unsafe feature nothing is kept.
+#[derive(Default)]
+pub struct Scratch {
+    #[cfg(not(feature = "prohibit-unsafe"))]
+    buffers: Buffers<Utf8Input<'static>>,
+}
+
+// SAFETY: positions point into a text, so the buffers are neither Send nor
+// Sync. But the buffers of a Scratch are empty, as are the vectors within
+// them, so it holds no positions.
+#[cfg(not(feature = "prohibit-unsafe"))]
+unsafe impl Send for Scratch {}
+#[cfg(not(feature = "prohibit-unsafe"))]
+unsafe impl Sync for Scratch {}
+
+/// A cloned Scratch starts empty, since it holds nothing of a match.
+impl Clone for Scratch {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+/// All Scratches are equal, since they hold nothing of a match.
+impl PartialEq for Scratch {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Scratch {}
+
+impl core::fmt::Debug for Scratch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Scratch")
+    }
+}
+
 #[derive(Debug)]
 struct MatchAttempter<'a, Input: InputIndexer> {
     re: &'a CompiledRegex,
     bts: Vec<BacktrackInsn<Input>>,
     s: State<Input::Position>,
+    // The number of backtracks taken so far, and the maximum allowed.
+    steps: usize,
+    step_limit: usize,
+    // If set, which capture groups to record. The instructions of the others
+    // are skipped, so they never participate.
+    tracked_groups: Option<Vec<bool>>,
+    // Whether to explore every path to find the shortest match, rather than
+    // accepting the first. If so, the shortest end found so far.
+    shortest: bool,
+    shortest_end: Option<Input::Position>,
+    // If set, the only position at which a match may end.
+    required_end: Option<Input::Position>,
+    // If memoizing, the states already visited at loop instructions.
+    memo: Option<Memo>,
+    // The number of lookarounds and atomic groups being run. States within
+    // them are not memoized.
+    nested: usize,
+    // If set, the furthest position reached.
+    furthest: Option<Furthest<Input::Position>>,
+    // Empty backtrack stacks and lists of saved groups, for reuse by
+    // lookarounds and atomic groups.
+    spare_bts: Vec<Vec<BacktrackInsn<Input>>>,
+    spare_groups: Vec<Vec<GroupData<Input::Position>>>,
+    #[cfg(feature = "trace")]
+    tracer: Option<Tracer<'a>>,
 }
 
 impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
     fn new(re: &'a CompiledRegex, entry: Input::Position) -> Self {
+        Self::with_buffers(re, entry, Buffers::default())
+    }
+
+    /// Construct a MatchAttempter whose storage is taken from \p buffers.
+    fn with_buffers(
+        re: &'a CompiledRegex,
+        entry: Input::Position,
+        buffers: Buffers<Input>,
+    ) -> Self {
+        let Buffers {
+            mut bts,
+            mut loops,
+            mut groups,
+            spare_bts,
+            spare_groups,
+        } = buffers;
+        bts.push(BacktrackInsn::Exhausted);
+        loops.resize(re.loops as usize, LoopData::new(entry));
+        groups.resize(re.groups as usize, GroupData::new());
         Self {
             re,
-            bts: vec![BacktrackInsn::Exhausted],
-            s: State {
-                loops: vec![LoopData::new(entry); re.loops as usize],
-                groups: vec![GroupData::new(); re.groups as usize],
-            },
+            bts,
+            s: State { loops, groups },
+            steps: 0,
+            step_limit: usize::MAX,
+            tracked_groups: None,
+            shortest: false,
+            shortest_end: None,
+            required_end: None,
+            memo: None,
+            nested: 0,
+            furthest: None,
+            spare_bts,
+            spare_groups,
+            #[cfg(feature = "trace")]
+            tracer: None,
+        }
+    }
+
+    /// \return the storage of this MatchAttempter, emptied.
+    fn into_buffers(self) -> Buffers<Input> {
+        let mut buffers = Buffers {
+            bts: self.bts,
+            loops: self.s.loops,
+            groups: self.s.groups,
+            spare_bts: self.spare_bts,
+            spare_groups: self.spare_groups,
+        };
+        buffers.bts.clear();
+        buffers.loops.clear();
+        buffers.groups.clear();
+        buffers
+    }
+
+    /// \return an empty backtrack stack, for a lookaround or atomic group.
+    fn take_spare_bts(&mut self) -> Vec<BacktrackInsn<Input>> {
+        let mut bts = self.spare_bts.pop().unwrap_or_default();
+        bts.push(BacktrackInsn::Exhausted);
+        bts
+    }
+
+    /// \return a copy of the capture groups in \p range, to be restored
+    /// after a lookaround or atomic group.
+    fn take_saved_groups(&mut self, range: Range<usize>) -> Vec<GroupData<Input::Position>> {
+        let mut saved = self.spare_groups.pop().unwrap_or_default();
+        saved.extend_from_slice(self.s.groups.iat(range));
+        saved
+    }
+
+    /// Keep \p bts and \p saved_groups, emptied, for reuse.
+    fn put_spares(
+        &mut self,
+        mut bts: Vec<BacktrackInsn<Input>>,
+        mut saved_groups: Vec<GroupData<Input::Position>>,
+    ) {
+        bts.clear();
+        saved_groups.clear();
+        self.spare_bts.push(bts);
+        self.spare_groups.push(saved_groups);
+    }
+
+    /// \return whether the capture group \p cg_idx is recorded.
+    #[inline(always)]
+    fn group_tracked(&self, cg_idx: CaptureGroupID) -> bool {
+        match &self.tracked_groups {
+            Some(tracked) => tracked[cg_idx as usize],
+            None => true,
         }
     }
 
+    /// \return whether we have taken more backtracks than our step limit.
+    #[inline(always)]
+    fn step_limit_exceeded(&self) -> bool {
+        self.steps > self.step_limit
+    }
+
     #[inline(always)]
     fn push_backtrack(&mut self, bt: BacktrackInsn<Input>) {
         self.bts.push(bt)
@@ -151,10 +403,13 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                 Some(loop_taken_ip)
             }
             (true, true) if !loop_fields.greedy => {
-                // Both arms are viable; backtrack into the loop.
-                loop_data.entry = pos;
+                // Both arms are viable; backtrack into the loop. The loop data
+                // keeps the entry of the current iteration, as the exit path
+                // may backtrack into it, which must still be rejected if it
+                // turns out empty.
                 self.bts.push(BacktrackInsn::EnterNonGreedyLoop {
                     ip,
+                    pos,
                     data: *loop_data,
                 });
                 Some(loop_not_taken_ip)
@@ -186,21 +441,32 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
         debug_assert!(min <= max, "min should be <= max");
         // Drive the iteration min times.
         // That tells us the min position.
-        for _ in 0..min {
+        let mut remaining = min;
+        while remaining > 0 {
+            remaining -= matcher.skip_run(input, dir, &mut pos, remaining);
+            if remaining == 0 {
+                break;
+            }
             if !matcher.matches(input, dir, &mut pos) {
                 return None;
             }
+            remaining -= 1;
         }
         let min_pos = pos;
 
         // Drive it up to the max.
-        // TODO; this is dumb.
-        for _ in 0..(max - min) {
+        let mut remaining = max - min;
+        while remaining > 0 {
+            remaining -= matcher.skip_run(input, dir, &mut pos, remaining);
+            if remaining == 0 {
+                break;
+            }
             let saved = pos;
             if !matcher.matches(input, dir, &mut pos) {
                 pos = saved;
                 break;
             }
+            remaining -= 1;
         }
         let max_pos = pos;
         Some((min_pos, max_pos))
@@ -362,17 +628,17 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
         // Copy capture groups, because if the match fails (or if we are inverted)
         // we need to restore these.
         let range = (start_group as usize)..(end_group as usize);
-        // TODO: consider retaining storage here?
         // Temporarily defeat backtracking.
-        let saved_groups = self.s.groups.iat(range.clone()).to_vec();
+        let mut saved_groups = self.take_saved_groups(range.clone());
 
         // Start with an "empty" backtrack stack.
-        // TODO: consider using a stack-allocated array.
-        let mut saved_bts = vec![BacktrackInsn::Exhausted];
+        let mut saved_bts = self.take_spare_bts();
         core::mem::swap(&mut self.bts, &mut saved_bts);
 
         // Enter into the lookaround's instruction stream.
+        self.nested += 1;
         let matched = self.try_at_pos(*input, ip, pos, Dir::new()).is_some();
+        self.nested -= 1;
 
         // Put back our bts.
         core::mem::swap(&mut self.bts, &mut saved_bts);
@@ -389,11 +655,120 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                 });
             }
         } else {
-            self.s.groups.splice(range, saved_groups);
+            self.s.groups.splice(range, saved_groups.drain(..));
         }
+        self.put_spares(saved_bts, saved_groups);
         matched != negate
     }
 
+    // Run an atomic group in the given direction. The half-open range
+    // start_group..end_group is the range of contained capture groups.
+    // \return the position after the group if it matched. The group's own
+    // backtrack states are discarded, so it cannot be re-entered to try a
+    // different match.
+    fn run_atomic<Dir: Direction>(
+        &mut self,
+        input: &Input,
+        ip: IP,
+        pos: Input::Position,
+        start_group: CaptureGroupID,
+        end_group: CaptureGroupID,
+        dir: Dir,
+    ) -> Option<Input::Position> {
+        // As with lookarounds, save the contained groups so that backtracking
+        // past the group restores them.
+        let range = (start_group as usize)..(end_group as usize);
+        let mut saved_groups = self.take_saved_groups(range.clone());
+
+        // Match the contents against an "empty" backtrack stack.
+        let mut saved_bts = self.take_spare_bts();
+        core::mem::swap(&mut self.bts, &mut saved_bts);
+        self.nested += 1;
+        let end = self.try_at_pos(*input, ip, pos, dir);
+        self.nested -= 1;
+        core::mem::swap(&mut self.bts, &mut saved_bts);
+
+        if end.is_some() {
+            for (idx, cg) in saved_groups.iter().enumerate() {
+                debug_assert!(idx + (start_group as usize) < MAX_CAPTURE_GROUPS);
+                self.push_backtrack(BacktrackInsn::SetCaptureGroup {
+                    id: (idx as CaptureGroupID) + start_group,
+                    data: *cg,
+                });
+            }
+        } else {
+            self.s.groups.splice(range, saved_groups.drain(..));
+        }
+        self.put_spares(saved_bts, saved_groups);
+        end
+    }
+
+    #[cfg(feature = "trace")]
+    fn backtrack_event(&self, input: &Input, ip: IP, pos: Input::Position) -> TraceEvent<'a> {
+        TraceEvent::Backtrack {
+            ip,
+            pos: input.pos_to_offset(pos),
+            depth: self.bts.len() - 1,
+        }
+    }
+
+    #[cfg(feature = "trace")]
+    fn capture_event(&self, input: &Input, id: CaptureGroupID) -> TraceEvent<'a> {
+        let cg = self.s.groups.iat(id as usize);
+        TraceEvent::Capture {
+            group: id as usize + 1,
+            start: cg.start.map(|p| input.pos_to_offset(p)),
+            end: cg.end.map(|p| input.pos_to_offset(p)),
+        }
+    }
+
+    /// Record that the loop instruction at \p ip is reached at \p pos, if
+    /// memoizing. \return false if this state was reached before, so that
+    /// it cannot lead to a match and need not be explored again.
+    ///
+    /// Without backreferences, capture groups do not affect whether a match
+    /// is found, so the state is the IP, position and loop data. Once a state
+    /// is visited, either its paths are exhausted without a match, or they are
+    /// still being explored, in which case visiting it again is a cycle which
+    /// the empty match check prevents. So pruning repeated states preserves
+    /// the match. Within lookarounds and atomic groups, a successful match
+    /// discards unexplored paths, so states there are not recorded.
+    fn visit_loop_state(&mut self, input: &Input, ip: IP, pos: Input::Position) -> bool {
+        let Some(memo) = &mut self.memo else {
+            return true;
+        };
+        if self.nested > 0 {
+            return true;
+        }
+        let mut key = Vec::with_capacity(2 + 2 * self.s.loops.len());
+        key.push(ip);
+        key.push(input.pos_to_offset(pos));
+        for (data, &(min_iters, max_iters)) in self.s.loops.iter().zip(memo.loop_bounds.iter()) {
+            // Only the comparisons of the iteration count against the bounds
+            // matter. The entry is only compared with the position at the
+            // end of an iteration past the minimum, and outside of lookbehinds
+            // positions only advance, so all that matters is whether it is
+            // the current position.
+            let iters = if max_iters == usize::MAX {
+                data.iters.min(min_iters + 1)
+            } else {
+                data.iters
+            };
+            key.push(iters);
+            key.push((iters > min_iters && data.entry == pos) as usize);
+        }
+        memo.visited.insert(key)
+    }
+
+    /// \return whether a path resumed at \p pos cannot improve on the
+    /// shortest match found so far, when looking for it. Outside of
+    /// lookarounds, matching forwards only advances, so such a path can only
+    /// end at or past that match.
+    #[inline(always)]
+    fn past_shortest_end<Dir: Direction>(&self, pos: Input::Position) -> bool {
+        Dir::FORWARD && self.nested == 0 && self.shortest_end.is_some_and(|end| pos >= end)
+    }
+
     /// Attempt to backtrack.
     /// \return true if we backtracked, false if we exhaust the backtrack stack.
     fn try_backtrack<Dir: Direction>(
@@ -403,6 +778,13 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
         pos: &mut Input::Position,
         _dir: Dir,
     ) -> bool {
+        // Each backtrack counts as a step against the limit. Once exceeded,
+        // abandon the match attempt by behaving as if the stack is exhausted.
+        self.steps += 1;
+        if self.step_limit_exceeded() {
+            self.bts.truncate(1);
+            return false;
+        }
         loop {
             // We always have a single Exhausted instruction backstopping our stack,
             // so we do not need to check for empty bts.
@@ -421,6 +803,7 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                     *ip = *saved_ip;
                     *pos = *saved_pos;
                     self.pop_backtrack();
+                    trace!(self, self.backtrack_event(input, *ip, *pos));
                     return true;
                 }
                 BacktrackInsn::SetLoopData { id, data } => {
@@ -428,15 +811,21 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                     self.pop_backtrack();
                 }
                 BacktrackInsn::SetCaptureGroup { id, data } => {
-                    *self.s.groups.mat(*id as usize) = *data;
+                    let id = *id;
+                    *self.s.groups.mat(id as usize) = *data;
                     self.pop_backtrack();
+                    trace!(self, self.capture_event(input, id));
                 }
 
-                &mut BacktrackInsn::EnterNonGreedyLoop { ip: loop_ip, data } => {
+                &mut BacktrackInsn::EnterNonGreedyLoop {
+                    ip: loop_ip,
+                    pos: loop_pos,
+                    data,
+                } => {
                     // Must pop before we enter the loop.
                     self.pop_backtrack();
                     *ip = loop_ip + 1;
-                    *pos = data.entry;
+                    *pos = loop_pos;
                     let loop_fields = match &self.re.insns.iat(loop_ip) {
                         Insn::EnterLoop(loop_fields) => loop_fields,
                         _ => rs_unreachable!("EnterNonGreedyLoop must point at a loop instruction"),
@@ -449,6 +838,7 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                         loop_fields,
                         loop_data,
                     );
+                    trace!(self, self.backtrack_event(input, *ip, *pos));
                     return true;
                 }
 
@@ -481,6 +871,7 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                         rs_unreachable!("Should always be able to advance since min != max")
                     }
                     *ip = *continuation;
+                    trace!(self, self.backtrack_event(input, *ip, *pos));
                     return true;
                 }
 
@@ -512,6 +903,7 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                         rs_unreachable!("Should always be able to advance since min != max")
                     }
                     *ip = *continuation;
+                    trace!(self, self.backtrack_event(input, *ip, *pos));
                     return true;
                 }
             }
@@ -550,6 +942,20 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                     };
                 }
 
+                if Dir::FORWARD {
+                    if let Some(furthest) = &mut self.furthest {
+                        furthest.visit(ip, pos);
+                    }
+                }
+                trace!(
+                    self,
+                    TraceEvent::Step {
+                        ip,
+                        pos: input.pos_to_offset(pos),
+                        insn: Instruction(re.insns.iat(ip)),
+                        depth: self.bts.len() - 1,
+                    }
+                );
                 match re.insns.iat(ip) {
                     &Insn::Char(c) => {
                         let m = match <<Input as InputIndexer>::Element as ElementType>::try_from(c)
@@ -651,40 +1057,38 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                         scm::MatchAnyExceptLineTerminator::new().matches(input, dir, &mut pos)
                     ),
 
-                    &Insn::WordBoundary { invert } => {
+                    &Insn::WordBoundary { invert, unicode } => {
+                        let custom = self.re.custom_word_chars();
+                        let is_word_char =
+                            |c| Input::CharProps::is_boundary_word_char(custom, unicode, c);
                         // Copy the positions since these destructively move them.
-                        let prev_wordchar = input
-                            .peek_left(pos)
-                            .map_or(false, Input::CharProps::is_word_char);
-                        let curr_wordchar = input
-                            .peek_right(pos)
-                            .map_or(false, Input::CharProps::is_word_char);
+                        let prev_wordchar = input.peek_left(pos).is_some_and(is_word_char);
+                        let curr_wordchar = input.peek_right(pos).is_some_and(is_word_char);
                         let is_boundary = prev_wordchar != curr_wordchar;
                         next_or_bt!(is_boundary != invert)
                     }
 
-                    Insn::StartOfLine => {
+                    &Insn::WordEdge { end, half, unicode } => {
+                        let custom = self.re.custom_word_chars();
+                        let is_word_char =
+                            |c| Input::CharProps::is_boundary_word_char(custom, unicode, c);
+                        let prev_wordchar = input.peek_left(pos).is_some_and(is_word_char);
+                        let curr_wordchar = input.peek_right(pos).is_some_and(is_word_char);
+                        next_or_bt!(is_word_edge(end, half, prev_wordchar, curr_wordchar))
+                    }
+
+                    &Insn::StartOfLine { multiline } => {
                         let matches = match input.peek_left(pos) {
                             None => true,
-                            Some(c)
-                                if re.flags.multiline
-                                    && Input::CharProps::is_line_terminator(c) =>
-                            {
-                                true
-                            }
+                            Some(c) if multiline && Input::CharProps::is_line_terminator(c) => true,
                             _ => false,
                         };
                         next_or_bt!(matches)
                     }
-                    Insn::EndOfLine => {
+                    &Insn::EndOfLine { multiline } => {
                         let matches = match input.peek_right(pos) {
                             None => true, // we're at the right of the string
-                            Some(c)
-                                if re.flags.multiline
-                                    && Input::CharProps::is_line_terminator(c) =>
-                            {
-                                true
-                            }
+                            Some(c) if multiline && Input::CharProps::is_line_terminator(c) => true,
                             _ => false,
                         };
                         next_or_bt!(matches)
@@ -696,6 +1100,9 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                     }
 
                     &Insn::BeginCaptureGroup(cg_idx) => {
+                        if !self.group_tracked(cg_idx) {
+                            next_or_bt!(true)
+                        }
                         let cg = self.s.groups.mat(cg_idx as usize);
                         self.bts.push(BacktrackInsn::SetCaptureGroup {
                             id: cg_idx,
@@ -714,10 +1121,14 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                                 "Should not have already exited capture group we are entering"
                             )
                         }
+                        trace!(self, self.capture_event(input, cg_idx));
                         next_or_bt!(true)
                     }
 
                     &Insn::EndCaptureGroup(cg_idx) => {
+                        if !self.group_tracked(cg_idx) {
+                            next_or_bt!(true)
+                        }
                         let cg = self.s.groups.mat(cg_idx as usize);
                         if Dir::FORWARD {
                             debug_assert!(
@@ -732,27 +1143,35 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                             );
                             cg.start = Some(pos)
                         }
+                        trace!(self, self.capture_event(input, cg_idx));
                         next_or_bt!(true)
                     }
 
                     &Insn::ResetCaptureGroup(cg_idx) => {
+                        if !self.group_tracked(cg_idx) {
+                            next_or_bt!(true)
+                        }
                         let cg = self.s.groups.mat(cg_idx as usize);
                         self.bts.push(BacktrackInsn::SetCaptureGroup {
                             id: cg_idx,
                             data: *cg,
                         });
                         cg.reset();
+                        trace!(self, self.capture_event(input, cg_idx));
                         next_or_bt!(true)
                     }
 
-                    &Insn::BackRef(cg_idx) => {
+                    &Insn::BackRef {
+                        group: cg_idx,
+                        icase,
+                    } => {
                         let cg = self.s.groups.mat(cg_idx as usize);
                         // Backreferences to a capture group that did not match always succeed (ES5
                         // 15.10.2.9).
                         // Note we may be in the capture group we are examining, e.g. /(abc\1)/.
                         let matched;
                         if let Some(orig_range) = cg.as_range() {
-                            if re.flags.icase {
+                            if icase {
                                 matched = matchers::backref_icase(input, dir, orig_range, &mut pos);
                             } else {
                                 matched = matchers::backref(input, dir, orig_range, &mut pos);
@@ -807,6 +1226,19 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                         }
                     }
 
+                    &Insn::AtomicGroup {
+                        start_group,
+                        end_group,
+                        continuation,
+                    } => match self.run_atomic(input, ip + 1, pos, start_group, end_group, dir) {
+                        Some(end) => {
+                            pos = end;
+                            ip = continuation as IP;
+                            continue 'nextinsn;
+                        }
+                        None => break 'backtrack,
+                    },
+
                     &Insn::Alt { secondary } => {
                         self.push_backtrack(BacktrackInsn::SetPosition {
                             ip: secondary as IP,
@@ -816,8 +1248,20 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                     }
 
                     Insn::EnterLoop(fields) => {
-                        // Entering a loop, not re-entering it.
-                        self.s.loops.mat(fields.loop_id as usize).iters = 0;
+                        // Entering a loop, not re-entering it. An earlier entry of
+                        // this loop may still be backtracked into, so restore its
+                        // iteration count then.
+                        let loop_data = self.s.loops.mat(fields.loop_id as usize);
+                        if loop_data.iters != 0 {
+                            self.bts.push(BacktrackInsn::SetLoopData {
+                                id: fields.loop_id,
+                                data: *loop_data,
+                            });
+                            loop_data.iters = 0;
+                        }
+                        if !self.visit_loop_state(input, ip, pos) {
+                            break 'backtrack;
+                        }
                         match self.run_loop(fields, pos, ip) {
                             Some(next_ip) => {
                                 ip = next_ip;
@@ -830,6 +1274,9 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                     }
 
                     &Insn::LoopAgain { begin } => {
+                        if !self.visit_loop_state(input, ip, pos) {
+                            break 'backtrack;
+                        }
                         let act = match re.insns.iat(begin as IP) {
                             Insn::EnterLoop(fields) => self.run_loop(fields, pos, begin as IP),
                             _ => rs_unreachable!("EnterLoop should always refer to loop field"),
@@ -862,12 +1309,28 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
                         property_escape,
                         negate,
                     } => {
-                        let m = scm::UnicodePropertyEscape { property_escape }
-                            .matches(input, dir, &mut pos);
-                        next_or_bt!(m != *negate);
+                        let m = scm::UnicodePropertyEscape {
+                            property_escape,
+                            negate: *negate,
+                        }
+                        .matches(input, dir, &mut pos);
+                        next_or_bt!(m);
                     }
 
                     Insn::Goal => {
+                        // The last Goal is that of the regex; others end
+                        // lookarounds and atomic groups. When looking for the
+                        // shortest match, record the end and keep going.
+                        let is_final = ip + 1 == re.insns.len();
+                        if is_final && self.required_end.is_some_and(|end| pos != end) {
+                            break 'backtrack;
+                        }
+                        if self.shortest && is_final {
+                            if self.shortest_end.is_none_or(|end| pos < end) {
+                                self.shortest_end = Some(pos);
+                            }
+                            break 'backtrack;
+                        }
                         // Keep all but the initial give-up bts.
                         self.bts.truncate(1);
                         return Some(pos);
@@ -881,13 +1344,14 @@ impl<'a, Input: InputIndexer> MatchAttempter<'a, Input> {
 
             // This after the backtrack loop.
             // A break 'backtrack will jump here.
-            if self.try_backtrack(input, &mut ip, &mut pos, dir) {
-                continue 'nextinsn;
-            } else {
-                // We have exhausted the backtracking stack.
-                debug_assert!(self.bts.len() == 1, "Should have exhausted backtrack stack");
-                return None;
+            while self.try_backtrack(input, &mut ip, &mut pos, dir) {
+                if !self.past_shortest_end::<Dir>(pos) {
+                    continue 'nextinsn;
+                }
             }
+            // We have exhausted the backtracking stack.
+            debug_assert!(self.bts.len() == 1, "Should have exhausted backtrack stack");
+            return None;
         }
 
         // This is outside the nextinsn loop.
@@ -908,11 +1372,23 @@ pub struct BacktrackExecutor<'r, Input: InputIndexer> {
 
 impl<'r, Input: InputIndexer> BacktrackExecutor<'r, Input> {
     fn successful_match(&mut self, start: Input::Position, end: Input::Position) -> Match {
-        // We want to simultaneously map our groups to offsets, and clear the groups.
-        // A for loop is the easiest way to do this while satisfying the borrow checker.
-        // TODO: avoid allocating so much.
         let mut captures = Vec::new();
         captures.reserve_exact(self.matcher.s.groups.len());
+        self.take_captures(&mut captures);
+        Match {
+            range: self.input.pos_to_offset(start)..self.input.pos_to_offset(end),
+            captures,
+            named_captures: self.matcher.re.named_group_indices.clone(),
+            offset_unit: OffsetUnit::Bytes,
+            unit_ranges: Vec::new(),
+        }
+    }
+
+    /// Append the offsets of our capture groups to \p captures, clearing the
+    /// groups.
+    fn take_captures(&mut self, captures: &mut Vec<Option<Range<usize>>>) {
+        // We want to simultaneously map our groups to offsets, and clear the groups.
+        // A for loop is the easiest way to do this while satisfying the borrow checker.
         for gd in self.matcher.s.groups.iter_mut() {
             captures.push(match gd.as_range() {
                 Some(r) => Some(Range {
@@ -924,52 +1400,230 @@ impl<'r, Input: InputIndexer> BacktrackExecutor<'r, Input> {
             gd.start = None;
             gd.end = None;
         }
-        Match {
-            range: self.input.pos_to_offset(start)..self.input.pos_to_offset(end),
-            captures,
-            named_captures: self.matcher.re.named_group_indices.clone(),
+    }
+
+    /// Clear our capture groups after a match whose groups are not wanted.
+    fn clear_captures(&mut self) {
+        for gd in self.matcher.s.groups.iter_mut() {
+            gd.start = None;
+            gd.end = None;
         }
     }
 
-    /// \return the next match, searching the remaining bytes using the given
-    /// prefix searcher to quickly find the first potential match location.
+    /// Write a successful match into \p groups, with the total range at index
+    /// 0, reusing its storage.
+    fn successful_match_into(
+        &mut self,
+        start: Input::Position,
+        end: Input::Position,
+        groups: &mut Vec<Option<Range<usize>>>,
+    ) {
+        groups.clear();
+        groups.push(Some(
+            self.input.pos_to_offset(start)..self.input.pos_to_offset(end),
+        ));
+        self.take_captures(groups);
+    }
+
+    /// \return the start and end of the next match, searching the remaining
+    /// bytes using the given prefix searcher to quickly find the first
+    /// potential match location.
     fn next_match_with_prefix_search<PrefixSearch: bytesearch::ByteSearcher>(
         &mut self,
         mut pos: Input::Position,
         next_start: &mut Option<Input::Position>,
         prefix_search: &PrefixSearch,
-    ) -> Option<Match> {
+    ) -> Option<(Input::Position, Input::Position)> {
         let inp = self.input;
         loop {
             // Find the next start location, or None if none.
             pos = inp.find_bytes(pos, prefix_search)?;
-            if let Some(end) = self.matcher.try_at_pos(inp, 0, pos, Forward::new()) {
-                // If we matched the empty string, we have to increment.
-                if end != pos {
-                    *next_start = Some(end)
-                } else {
-                    *next_start = inp.next_right_pos(end);
-                }
-                return Some(self.successful_match(pos, end));
+            if let Some(end) = self.match_at_pos(pos, next_start) {
+                return Some((pos, end));
+            }
+            if self.matcher.step_limit_exceeded() {
+                return None;
             }
             // Didn't find it at this position, try the next one.
             pos = inp.next_right_pos(pos)?;
         }
     }
-}
 
-impl<'a, Input: InputIndexer> exec::MatchProducer for BacktrackExecutor<'a, Input> {
-    type Position = Input::Position;
+    /// Limit the number of backtracks this executor may take, across all
+    /// match attempts. Once the limit is exceeded, no further matches are
+    /// produced.
+    pub fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.matcher.step_limit = step_limit;
+        self
+    }
 
-    fn initial_position(&self, offset: usize) -> Option<Self::Position> {
-        self.input.try_move_right(self.input.left_end(), offset)
+    /// Do not record capture groups, so that matching is cheaper. Groups
+    /// which backreferences refer to are still recorded.
+    pub fn without_captures(self) -> Self {
+        self.with_captures_only(&[])
     }
 
-    fn next_match(
+    /// Record only the capture groups in \p groups, indexed from 1 as in
+    /// Match::group, and those which backreferences refer to. Matches report
+    /// the other groups as not participating.
+    pub fn with_captures_only(mut self, groups: &[usize]) -> Self {
+        let re = self.matcher.re;
+        let mut tracked = vec![false; re.groups as usize];
+        for &group in groups {
+            if let Some(t) = group.checked_sub(1).and_then(|idx| tracked.get_mut(idx)) {
+                *t = true;
+            }
+        }
+        for insn in re.insns.iter() {
+            if let &Insn::BackRef { group, .. } = insn {
+                tracked[group as usize] = true;
+            }
+        }
+        self.matcher.tracked_groups = Some(tracked);
+        self
+    }
+
+    /// Report the shortest match at the leftmost position which matches,
+    /// rather than the first found by backtracking. Every path from that
+    /// position is explored, so this may be slower.
+    pub fn shortest_matches(mut self) -> Self {
+        self.matcher.shortest = true;
+        self
+    }
+
+    /// Pass each step of matching to \p tracer.
+    #[cfg(feature = "trace")]
+    pub fn with_tracer(mut self, tracer: &'r mut dyn FnMut(&TraceEvent<'_>)) -> Self {
+        self.matcher.tracer = Some(Tracer(tracer));
+        self
+    }
+
+    /// Remember the states reached at loops in which no match was found, so
+    /// that they are not explored again. This bounds the time taken by
+    /// patterns like `(a|a)*b`, at the cost of memory proportional to the
+    /// number of states visited. The matches found are unchanged. This has
+    /// no effect if the regex has backreferences.
+    pub fn memoized(mut self) -> Self {
+        let re = self.matcher.re;
+        let has_backrefs = re
+            .insns
+            .iter()
+            .any(|insn| matches!(insn, Insn::BackRef { .. }));
+        if has_backrefs {
+            return self;
+        }
+        // Past the minimum, each iteration must consume some input. So if the
+        // maximum is out of reach, treat the loop as unbounded.
+        let len = self.input.bytelength();
+        let mut loop_bounds = vec![(0, 0); re.loops as usize];
+        for insn in re.insns.iter() {
+            if let Insn::EnterLoop(fields) = insn {
+                let max_iters = if fields.max_iters > fields.min_iters.saturating_add(len) {
+                    usize::MAX
+                } else {
+                    fields.max_iters
+                };
+                loop_bounds[fields.loop_id as usize] = (fields.min_iters, max_iters);
+            }
+        }
+        self.matcher.memo = Some(Memo {
+            visited: HashSet::new(),
+            loop_bounds,
+        });
+        self
+    }
+
+    /// Record the furthest position reached by matching forwards, and the
+    /// instructions run there, for tracked_furthest.
+    pub fn tracking_furthest(mut self) -> Self {
+        self.matcher.furthest = Some(Furthest {
+            pos: self.input.left_end(),
+            ips: Vec::new(),
+        });
+        self
+    }
+
+    /// \return the byte offset of the furthest position reached by matching
+    /// forwards, and the IPs of the instructions run there, if tracking.
+    /// Every instruction which consumes input and was run there failed, as
+    /// the position would otherwise be further.
+    pub fn tracked_furthest(&self) -> Option<(usize, &[IP])> {
+        let furthest = self.matcher.furthest.as_ref()?;
+        Some((self.input.pos_to_offset(furthest.pos), &furthest.ips))
+    }
+
+    /// Only accept matches which end at the end of the input, backtracking
+    /// out of any which end earlier.
+    pub fn anchored_end(mut self) -> Self {
+        self.matcher.required_end = Some(self.input.right_end());
+        self
+    }
+
+    /// \return whether matching was abandoned because the step limit was
+    /// exceeded.
+    pub fn step_limit_exceeded(&self) -> bool {
+        self.matcher.step_limit_exceeded()
+    }
+
+    /// \return the end of the match starting exactly at \p pos, or None if
+    /// none.
+    fn match_at_pos(
         &mut self,
         pos: Input::Position,
         next_start: &mut Option<Input::Position>,
-    ) -> Option<Match> {
+    ) -> Option<Input::Position> {
+        let inp = self.input;
+        trace!(
+            self.matcher,
+            TraceEvent::Attempt {
+                pos: inp.pos_to_offset(pos)
+            }
+        );
+        let mut end = self.matcher.try_at_pos(inp, 0, pos, Forward::new());
+        if self.matcher.shortest {
+            // The search always exhausts its paths.
+            debug_assert!(end.is_none(), "Shortest search should not return");
+            end = self.matcher.shortest_end.take();
+        }
+        // A lookaround abandoned due to the step limit may have let the
+        // attempt reach its goal; do not report such a match.
+        if self.matcher.step_limit_exceeded() {
+            *next_start = None;
+            return None;
+        }
+        trace!(
+            self.matcher,
+            match end {
+                Some(end) => TraceEvent::Matched {
+                    start: inp.pos_to_offset(pos),
+                    end: inp.pos_to_offset(end),
+                },
+                None => TraceEvent::Failed {
+                    pos: inp.pos_to_offset(pos)
+                },
+            }
+        );
+        let end = end?;
+        // The paths of a match attempt which succeeded were not all explored,
+        // so its states may lead to later matches.
+        if let Some(memo) = &mut self.matcher.memo {
+            memo.visited.clear();
+        }
+        // If we matched the empty string, we have to increment.
+        if end != pos {
+            *next_start = Some(end)
+        } else {
+            *next_start = inp.next_right_pos(end);
+        }
+        Some(end)
+    }
+
+    /// \return the start and end of the next match at or after \p pos.
+    fn find_next(
+        &mut self,
+        pos: Input::Position,
+        next_start: &mut Option<Input::Position>,
+    ) -> Option<(Input::Position, Input::Position)> {
         match &self.matcher.re.start_pred {
             StartPredicate::Arbitrary => {
                 self.next_match_with_prefix_search(pos, next_start, &bytesearch::EmptyString {})
@@ -991,6 +1645,11 @@ impl<'a, Input: InputIndexer> exec::MatchProducer for BacktrackExecutor<'a, Inpu
                 next_start,
                 &bytesearch::ByteArraySet(bytes),
             ),
+            &StartPredicate::ByteSet3(bytes) => self.next_match_with_prefix_search(
+                pos,
+                next_start,
+                &bytesearch::ByteArraySet(bytes),
+            ),
             StartPredicate::ByteBracket(bitmap) => {
                 self.next_match_with_prefix_search(pos, next_start, bitmap)
             }
@@ -998,6 +1657,82 @@ impl<'a, Input: InputIndexer> exec::MatchProducer for BacktrackExecutor<'a, Inpu
     }
 }
 
+impl<'a, Input: InputIndexer> exec::MatchProducer for BacktrackExecutor<'a, Input> {
+    type Position = Input::Position;
+
+    fn initial_position(&self, offset: usize) -> Option<Self::Position> {
+        self.input.try_move_right(self.input.left_end(), offset)
+    }
+
+    fn next_match(
+        &mut self,
+        pos: Input::Position,
+        next_start: &mut Option<Input::Position>,
+    ) -> Option<Match> {
+        let (start, end) = self.find_next(pos, next_start)?;
+        Some(self.successful_match(start, end))
+    }
+
+    fn match_at(
+        &mut self,
+        pos: Input::Position,
+        next_start: &mut Option<Input::Position>,
+    ) -> Option<Match> {
+        let end = self.match_at_pos(pos, next_start)?;
+        Some(self.successful_match(pos, end))
+    }
+
+    fn next_match_exists(
+        &mut self,
+        pos: Input::Position,
+        next_start: &mut Option<Input::Position>,
+    ) -> bool {
+        let found = self.find_next(pos, next_start).is_some();
+        self.clear_captures();
+        found
+    }
+
+    fn match_at_exists(
+        &mut self,
+        pos: Input::Position,
+        next_start: &mut Option<Input::Position>,
+    ) -> bool {
+        let found = self.match_at_pos(pos, next_start).is_some();
+        self.clear_captures();
+        found
+    }
+
+    fn next_match_into(
+        &mut self,
+        pos: Input::Position,
+        next_start: &mut Option<Input::Position>,
+        groups: &mut Vec<Option<Range<usize>>>,
+    ) -> bool {
+        match self.find_next(pos, next_start) {
+            Some((start, end)) => {
+                self.successful_match_into(start, end, groups);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn match_at_into(
+        &mut self,
+        pos: Input::Position,
+        next_start: &mut Option<Input::Position>,
+        groups: &mut Vec<Option<Range<usize>>>,
+    ) -> bool {
+        match self.match_at_pos(pos, next_start) {
+            Some(end) => {
+                self.successful_match_into(pos, end, groups);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 impl<'r, 't> exec::Executor<'r, 't> for BacktrackExecutor<'r, Utf8Input<'t>> {
     type AsAscii = BacktrackExecutor<'r, AsciiInput<'t>>;
 
@@ -1010,6 +1745,82 @@ impl<'r, 't> exec::Executor<'r, 't> for BacktrackExecutor<'r, Utf8Input<'t>> {
     }
 }
 
+impl<'r, 't> BacktrackExecutor<'r, Utf8Input<'t>> {
+    /// Construct an executor over \p text whose storage is taken from
+    /// \p scratch. It should be returned with into_scratch.
+    pub fn with_scratch(re: &'r CompiledRegex, text: &'t str, scratch: &mut Scratch) -> Self {
+        let input = Utf8Input::new(text);
+        #[cfg(not(feature = "prohibit-unsafe"))]
+        let buffers = core::mem::take(&mut scratch.buffers).recycle();
+        #[cfg(feature = "prohibit-unsafe")]
+        let buffers = {
+            let _ = scratch;
+            Buffers::default()
+        };
+        Self {
+            input,
+            matcher: MatchAttempter::with_buffers(re, input.left_end(), buffers),
+        }
+    }
+
+    /// Return the storage of this executor to \p scratch.
+    pub fn into_scratch(self, scratch: &mut Scratch) {
+        let buffers = self.matcher.into_buffers();
+        #[cfg(not(feature = "prohibit-unsafe"))]
+        {
+            scratch.buffers = buffers.recycle();
+        }
+        #[cfg(feature = "prohibit-unsafe")]
+        let _ = (buffers, scratch);
+    }
+}
+
+impl<'r, 't> BacktrackExecutor<'r, Utf8BytesInput<'t>> {
+    /// Construct an executor over bytes which are expected, but not known, to
+    /// be UTF-8.
+    pub fn new_bytes(re: &'r CompiledRegex, bytes: &'t [u8]) -> Self {
+        let input = Utf8BytesInput::new(bytes);
+        Self {
+            input,
+            matcher: MatchAttempter::new(re, input.left_end()),
+        }
+    }
+}
+
+impl<'r, 't> BacktrackExecutor<'r, Wtf8Input<'t>> {
+    /// Construct an executor over the bytes of a Utf16Text.
+    pub fn new_wtf8(re: &'r CompiledRegex, bytes: &'t [u8]) -> Self {
+        let input = Wtf8Input::new(bytes);
+        Self {
+            input,
+            matcher: MatchAttempter::new(re, input.left_end()),
+        }
+    }
+}
+
+impl<'r, 't> BacktrackExecutor<'r, AsciiFoldedInput<'t>> {
+    /// Construct an executor over \p text with its ASCII letters seen in
+    /// lowercase, for a program folded by CompiledRegex::ascii_case_folded.
+    pub fn new_ascii_folded(re: &'r CompiledRegex, text: &'t str) -> Self {
+        let input = AsciiFoldedInput::new(text);
+        Self {
+            input,
+            matcher: MatchAttempter::new(re, input.left_end()),
+        }
+    }
+}
+
+impl<'r, 't, T: Input + ?Sized> BacktrackExecutor<'r, CustomInput<'t, T>> {
+    /// Construct an executor over a user-provided Input.
+    pub fn new_input(re: &'r CompiledRegex, input: &'t T) -> Self {
+        let input = CustomInput::new(input);
+        Self {
+            input,
+            matcher: MatchAttempter::new(re, input.left_end()),
+        }
+    }
+}
+
 impl<'r, 't> exec::Executor<'r, 't> for BacktrackExecutor<'r, AsciiInput<'t>> {
     type AsAscii = BacktrackExecutor<'r, AsciiInput<'t>>;
 
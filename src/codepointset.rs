@@ -4,21 +4,39 @@ use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::iter::once;
 
+/// A Unicode code point. Surrogates are code points too, though they are not
+/// `char`s.
 pub type CodePoint = u32;
 
 /// The maximum (inclusive) code point.
 pub const CODE_POINT_MAX: CodePoint = 0x10FFFF;
 
-/// An list of sorted, inclusive, non-empty ranges of code points.
+/// A non-empty inclusive range of code points, from `first` to `last`.
 /// This is more efficient than InclusiveRange because it does not need to carry
 /// around the Option<bool>.
-#[derive(Debug, Copy, Clone)]
+///
+/// An interval is well-formed if `first <= last <= CODE_POINT_MAX`. Only
+/// well-formed intervals may be added to a [`CodePointSet`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Interval {
     pub first: CodePoint,
     pub last: CodePoint,
 }
 
 impl Interval {
+    /// Return the interval from \p first to \p last, inclusive.
+    ///
+    /// Panics if the interval is not well-formed.
+    pub fn new(first: CodePoint, last: CodePoint) -> Interval {
+        assert!(
+            first <= last && last <= CODE_POINT_MAX,
+            "Invalid interval {:#X}..={:#X}",
+            first,
+            last
+        );
+        Interval { first, last }
+    }
+
     /// Return whether self is before rhs.
     fn is_before(self, other: Interval) -> bool {
         self.last < other.first
@@ -70,6 +88,20 @@ impl Interval {
     pub fn count_codepoints(self) -> usize {
         (self.last - self.first + 1) as usize
     }
+
+    /// Return the code point halfway from first to last, rounding down.
+    pub fn midpoint(self) -> CodePoint {
+        self.first + (self.last - self.first) / 2
+    }
+
+    /// Return the distinct code points among the first, the midpoint and the
+    /// last, in order.
+    pub fn samples(self) -> impl Iterator<Item = CodePoint> + Clone {
+        let mid = self.midpoint();
+        once(self.first)
+            .chain((mid != self.first).then_some(mid))
+            .chain((self.last != mid).then_some(self.last))
+    }
 }
 
 /// Merge two intervals, which must be overlapping or abutting.
@@ -81,13 +113,29 @@ fn merge_intervals(x: Interval, y: &Interval) -> Interval {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+/// A set of code points stored via as disjoint, non-abutting, sorted intervals.
+///
+/// Example:
+///
+///  ```rust
+///   use regress::{CodePointSet, Interval};
+///   let mut letters = CodePointSet::new();
+///   letters.add(Interval::new('a' as u32, 'z' as u32));
+///   letters.add(Interval::new('A' as u32, 'Z' as u32));
+///   let mut vowels = CodePointSet::new();
+///   "aeiou".chars().for_each(|c| vowels.add_one(c as u32));
+///   let consonants = letters.difference(&vowels);
+///   assert!(consonants.contains('b' as u32));
+///   assert!(!consonants.contains('e' as u32));
+///   assert_eq!(consonants.intervals().len(), 6);
+///   ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct CodePointSet {
     ivs: Vec<Interval>,
 }
 
-/// A set of code points stored via as disjoint, non-abutting, sorted intervals.
 impl CodePointSet {
+    /// Return an empty set.
     pub fn new() -> CodePointSet {
         CodePointSet { ivs: Vec::new() }
     }
@@ -106,13 +154,14 @@ impl CodePointSet {
 
     /// Construct from sorted, disjoint intervals. Note these are not allowed to
     /// even abut.
-    pub fn from_sorted_disjoint_intervals(ivs: Vec<Interval>) -> CodePointSet {
+    pub(crate) fn from_sorted_disjoint_intervals(ivs: Vec<Interval>) -> CodePointSet {
         let res = CodePointSet { ivs };
         res.assert_is_well_formed();
         res
     }
 
-    /// Add an interval of code points to the set.
+    /// Add an interval of code points to the set. The interval must be
+    /// well-formed; see [`Interval`].
     pub fn add(&mut self, new_iv: Interval) {
         // Find the mergeable subarray, that is, the range of intervals that intersect
         // or abut new_iv.
@@ -159,13 +208,103 @@ impl CodePointSet {
         }
     }
 
-    /// \return the intervals
+    /// \return a set containing the code points in both the receiver and \p rhs.
+    pub fn intersection(&self, rhs: &CodePointSet) -> CodePointSet {
+        let mut ivs = Vec::new();
+        let (mut lhs_ivs, mut rhs_ivs) = (self.ivs.iter().peekable(), rhs.ivs.iter().peekable());
+        while let (Some(&&x), Some(&&y)) = (lhs_ivs.peek(), rhs_ivs.peek()) {
+            if x.overlaps(y) {
+                ivs.push(Interval {
+                    first: core::cmp::max(x.first, y.first),
+                    last: core::cmp::min(x.last, y.last),
+                });
+            }
+            // Advance whichever interval ends first.
+            if x.last < y.last {
+                lhs_ivs.next();
+            } else {
+                rhs_ivs.next();
+            }
+        }
+        CodePointSet::from_sorted_disjoint_intervals(ivs)
+    }
+
+    /// \return a set containing the code points in the receiver but not in
+    /// \p rhs.
+    pub fn difference(&self, rhs: &CodePointSet) -> CodePointSet {
+        self.intersection(&rhs.inverted())
+    }
+
+    /// \return whether the set contains the code point \p cp.
+    pub fn contains(&self, cp: CodePoint) -> bool {
+        self.ivs
+            .binary_search_by(|iv| {
+                if iv.last < cp {
+                    Ordering::Less
+                } else if iv.first > cp {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// \return the intervals of the set, which are sorted and neither overlap
+    /// nor abut.
     pub fn intervals(&self) -> &[Interval] {
         self.ivs.as_slice()
     }
 
+    /// \return an iterator over the intervals of the set, in order.
+    pub fn iter_intervals(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Interval> + DoubleEndedIterator + Clone + '_ {
+        self.ivs.iter().copied()
+    }
+
+    /// \return representative code points of the set, for generating test
+    /// inputs: the samples of each interval, which are its first, middle and
+    /// last code points, in order and without repeats. The surrogates
+    /// U+D800 to U+DFFF are skipped, as they are not chars: an interval which
+    /// spans them is sampled as the parts below and above. Nothing is
+    /// allocated.
+    ///
+    /// Example:
+    ///
+    ///  ```rust
+    ///   use regress::{CodePointSet, Interval};
+    ///   let mut set = CodePointSet::new();
+    ///   set.add(Interval::new('a' as u32, 'z' as u32));
+    ///   set.add_one('0' as u32);
+    ///   set.add(Interval::new('A' as u32, 'B' as u32));
+    ///   let samples: String = set.sample_code_points().filter_map(char::from_u32).collect();
+    ///   assert_eq!(samples, "0ABamz");
+    ///   ```
+    pub fn sample_code_points(&self) -> impl Iterator<Item = CodePoint> + Clone + '_ {
+        self.ivs.iter().flat_map(|iv| {
+            let below = Interval {
+                first: iv.first,
+                last: iv.last.min(0xD7FF),
+            };
+            let above = Interval {
+                first: iv.first.max(0xE000),
+                last: iv.last,
+            };
+            [below, above]
+                .into_iter()
+                .filter(|part| part.first <= part.last)
+                .flat_map(Interval::samples)
+        })
+    }
+
+    /// \return whether the set contains no code points.
+    pub fn is_empty(&self) -> bool {
+        self.ivs.is_empty()
+    }
+
     /// \return the number of intervals that would be produced by inverting.
-    pub fn inverted_interval_count(&self) -> usize {
+    pub(crate) fn inverted_interval_count(&self) -> usize {
         let mut result = 0;
         let mut start: CodePoint = 0;
         for iv in &self.ivs {
@@ -28,28 +28,24 @@ impl Direction for Backward {
     }
 }
 
-/// \return a slice of bytes of length \p len starting (or ending if not FORWARD) at \p pos.
+/// \return the range of bytes of length \p len starting (or ending if not FORWARD) at \p pos.
 /// Advance (retreat) pos by that many bytes.
 #[inline(always)]
-fn try_slice<'a, Input: InputIndexer, Dir: Direction>(
-    input: &'a Input,
+fn try_range<Input: InputIndexer, Dir: Direction>(
+    input: &Input,
     _dir: Dir,
     pos: &mut Input::Position,
     len: usize,
-) -> Option<&'a [u8]> {
+) -> Option<Input::Position> {
     // Note we may exit here if there's not enough bytes remaining.
-    let start;
-    let end;
     if Dir::FORWARD {
-        start = *pos;
-        end = input.try_move_right(start, len)?;
-        *pos = end;
+        let start = *pos;
+        *pos = input.try_move_right(start, len)?;
+        Some(start)
     } else {
-        end = *pos;
-        start = input.try_move_left(end, len)?;
-        *pos = start;
+        *pos = input.try_move_left(*pos, len)?;
+        Some(*pos)
     }
-    Some(input.slice(start, end))
 }
 
 /// \return whether we match some literal bytes.
@@ -63,8 +59,8 @@ pub fn try_match_lit<Input: InputIndexer, Dir: Direction, Bytes: ByteSeq>(
 ) -> bool {
     let len = Bytes::LENGTH;
     debug_assert!(len > 0, "Should not have zero length");
-    if let Some(subr_slice) = try_slice(input, dir, pos, len) {
-        bytes.equals_known_len(subr_slice)
+    if let Some(start) = try_range(input, dir, pos, len) {
+        input.bytes_eq(start, bytes)
     } else {
         false
     }
@@ -80,8 +76,8 @@ pub fn subrange_eq<Input: InputIndexer, Dir: Direction>(
     start: Input::Position,
     end: Input::Position,
 ) -> bool {
-    if let Some(subr_slice) = try_slice(input, dir, pos, end - start) {
-        subr_slice == input.slice(start, end)
+    if let Some(subr_start) = try_range(input, dir, pos, end - start) {
+        input.subrange_eq(subr_start, start..end)
     } else {
         false
     }
@@ -1,23 +1,24 @@
 //! Regex compiler back-end: transforms IR into a CompiledRegex
 
 use crate::bytesearch::{AsciiBitmap, ByteArraySet};
-use crate::insn::{CompiledRegex, Insn, LoopFields, MAX_BYTE_SEQ_LENGTH, MAX_CHAR_SET_LENGTH};
+use crate::insn::{
+    CompiledBracket, CompiledRegex, GroupNames, Insn, LoopFields, MAX_BYTE_SEQ_LENGTH,
+    MAX_CHAR_SET_LENGTH,
+};
 use crate::ir;
 use crate::ir::Node;
 use crate::startpredicate;
 use crate::types::{BracketContents, CaptureGroupID, LoopID};
 use crate::unicode;
-use core::convert::TryInto;
-#[cfg(feature = "std")]
-use std::collections::HashMap;
 #[cfg(not(feature = "std"))]
-use {alloc::vec::Vec, hashbrown::HashMap};
+use alloc::vec::Vec;
+use core::convert::TryInto;
 
 /// \return an anchor instruction for a given IR anchor.
-fn make_anchor(anchor_type: ir::AnchorType) -> Insn {
+fn make_anchor(anchor_type: ir::AnchorType, multiline: bool) -> Insn {
     match anchor_type {
-        ir::AnchorType::StartOfLine => Insn::StartOfLine,
-        ir::AnchorType::EndOfLine => Insn::EndOfLine,
+        ir::AnchorType::StartOfLine => Insn::StartOfLine { multiline },
+        ir::AnchorType::EndOfLine => Insn::EndOfLine { multiline },
     }
 }
 
@@ -131,7 +132,9 @@ impl Emitter {
                     self.emit_insn(Insn::AsciiBracket(ascii_contents))
                 } else {
                     let idx = self.result.brackets.len();
-                    self.result.brackets.push(contents.clone());
+                    self.result
+                        .brackets
+                        .push(CompiledBracket::new(contents.clone()));
                     self.emit_insn(Insn::Bracket(idx))
                 }
             }
@@ -139,7 +142,10 @@ impl Emitter {
             Node::MatchAnyExceptLineTerminator => {
                 self.emit_insn(Insn::MatchAnyExceptLineTerminator)
             }
-            Node::Anchor(anchor_type) => self.emit_insn(make_anchor(*anchor_type)),
+            &Node::Anchor {
+                anchor_type,
+                multiline,
+            } => self.emit_insn(make_anchor(anchor_type, multiline)),
             Node::Loop {
                 loopee,
                 quant,
@@ -186,6 +192,8 @@ impl Emitter {
             Node::NamedCaptureGroup(contents, group, name) => {
                 let group = *group as CaptureGroupID;
                 self.result.groups += 1;
+                // A group may be emitted more than once, if its quantifier was
+                // expanded; each copy has the same index.
                 self.result.named_group_indices.insert(name.clone(), group);
                 self.emit_insn(Insn::BeginCaptureGroup(group));
                 self.emit_node(contents);
@@ -231,11 +239,42 @@ impl Emitter {
                     _ => panic!("Should be a Lookaround instruction"),
                 }
             }
-            Node::WordBoundary { invert } => self.emit_insn(Insn::WordBoundary { invert: *invert }),
-            &Node::BackRef(group) => {
+            Node::AtomicGroup {
+                start_group,
+                end_group,
+                contents,
+            } => {
+                let atomic = self.emit_insn_offset(Insn::AtomicGroup {
+                    start_group: *start_group,
+                    end_group: *end_group,
+                    continuation: 0,
+                });
+                self.emit_node(contents);
+                self.emit_insn(Insn::Goal);
+
+                // Fix up the continuation.
+                let next_insn = self.next_offset();
+                match self.get_insn(atomic) {
+                    Insn::AtomicGroup {
+                        ref mut continuation,
+                        ..
+                    } => *continuation = next_insn,
+                    _ => panic!("Should be an AtomicGroup instruction"),
+                }
+            }
+            &Node::WordBoundary { invert, unicode } => {
+                self.emit_insn(Insn::WordBoundary { invert, unicode })
+            }
+            &Node::WordEdge { end, half, unicode } => {
+                self.emit_insn(Insn::WordEdge { end, half, unicode })
+            }
+            &Node::BackRef { group, icase } => {
                 debug_assert!(group >= 1, "Group should not be zero");
                 // -1 because \1 matches the first capture group, which has index 0.
-                self.emit_insn(Insn::BackRef(group - 1))
+                self.emit_insn(Insn::BackRef {
+                    group: group - 1,
+                    icase,
+                })
             }
 
             Node::ByteSet(bytes) => self.emit_insn(self.make_byte_set_insn(bytes)),
@@ -253,7 +292,16 @@ impl Emitter {
                     MAX_BYTE_SEQ_LENGTH == 16,
                     "Need to update our emitting logic"
                 );
-                for chunk in bytes.as_slice().chunks(MAX_BYTE_SEQ_LENGTH) {
+                // Split at character boundaries, so that each instruction
+                // holds whole UTF-8 sequences.
+                let mut rest = bytes.as_slice();
+                while !rest.is_empty() {
+                    let mut len = rest.len().min(MAX_BYTE_SEQ_LENGTH);
+                    while len < rest.len() && (rest[len] & 0xC0) == 0x80 {
+                        len -= 1;
+                    }
+                    let (chunk, tail) = rest.split_at(len);
+                    rest = tail;
                     let insn = match chunk.len() {
                         1 => Insn::ByteSeq1(chunk.try_into().unwrap()),
                         2 => Insn::ByteSeq2(chunk.try_into().unwrap()),
@@ -296,9 +344,14 @@ pub fn emit(n: &ir::Regex) -> CompiledRegex {
             brackets: Vec::new(),
             loops: 0,
             groups: 0,
-            named_group_indices: HashMap::new(),
+            named_group_indices: GroupNames::default(),
             flags: n.flags,
             start_pred: startpredicate::predicate_for_re(n),
+            match_length_bounds: n.match_length_bounds(),
+            word_boundary: None,
+            dfa_caches: Default::default(),
+            utf16_program: Default::default(),
+            ascii_folded_program: Default::default(),
         },
     };
     emitter.emit_node(&n.node);
@@ -3,6 +3,9 @@
 use crate::api::Match;
 use crate::insn::CompiledRegex;
 use crate::position::PositionType;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Range;
 
 /// A trait for finding the next match in a regex.
 /// This is broken out from Executor to avoid needing to thread lifetimes
@@ -22,6 +25,73 @@ pub trait MatchProducer: core::fmt::Debug {
         pos: Self::Position,
         next_start: &mut Option<Self::Position>,
     ) -> Option<Match>;
+
+    /// Attempt to match exactly at the given location, without searching
+    /// forward.
+    /// \return either the Match and the position to start looking for the next
+    /// match, or None on failure.
+    fn match_at(
+        &mut self,
+        pos: Self::Position,
+        next_start: &mut Option<Self::Position>,
+    ) -> Option<Match>;
+
+    /// Like next_match, but only report whether there is a match.
+    fn next_match_exists(
+        &mut self,
+        pos: Self::Position,
+        next_start: &mut Option<Self::Position>,
+    ) -> bool {
+        self.next_match(pos, next_start).is_some()
+    }
+
+    /// Like match_at, but only report whether there is a match.
+    fn match_at_exists(
+        &mut self,
+        pos: Self::Position,
+        next_start: &mut Option<Self::Position>,
+    ) -> bool {
+        self.match_at(pos, next_start).is_some()
+    }
+
+    /// Like next_match, but write the total range of the match followed by
+    /// its capture groups into \p groups, reusing its storage.
+    /// \return whether there was a match.
+    fn next_match_into(
+        &mut self,
+        pos: Self::Position,
+        next_start: &mut Option<Self::Position>,
+        groups: &mut Vec<Option<Range<usize>>>,
+    ) -> bool {
+        let m = self.next_match(pos, next_start);
+        write_match(m, groups)
+    }
+
+    /// Like match_at, but write the match into \p groups as next_match_into
+    /// does.
+    fn match_at_into(
+        &mut self,
+        pos: Self::Position,
+        next_start: &mut Option<Self::Position>,
+        groups: &mut Vec<Option<Range<usize>>>,
+    ) -> bool {
+        let m = self.match_at(pos, next_start);
+        write_match(m, groups)
+    }
+}
+
+/// Write the ranges of \p m into \p groups, returning whether there was a
+/// match.
+fn write_match(m: Option<Match>, groups: &mut Vec<Option<Range<usize>>>) -> bool {
+    match m {
+        Some(m) => {
+            groups.clear();
+            groups.push(Some(m.range));
+            groups.extend(m.captures);
+            true
+        }
+        None => false,
+    }
 }
 
 /// A trait for executing a regex.
@@ -38,12 +108,77 @@ pub trait Executor<'r, 't>: MatchProducer {
 pub struct Matches<Producer: MatchProducer> {
     mp: Producer,
     position: Option<Producer::Position>,
+    sticky: bool,
 }
 
 impl<Producer: MatchProducer> Matches<Producer> {
     pub fn new(mp: Producer, start: usize) -> Self {
         let position = mp.initial_position(start);
-        Matches { mp, position }
+        Matches {
+            mp,
+            position,
+            sticky: false,
+        }
+    }
+
+    /// Construct a Matches in which each match must begin exactly where the
+    /// previous match ended. Iteration stops at the first failure.
+    pub fn new_sticky(mp: Producer, start: usize) -> Self {
+        Matches {
+            sticky: true,
+            ..Self::new(mp, start)
+        }
+    }
+
+    /// Find the next match like Iterator::next, but write it into \p groups
+    /// as MatchProducer::next_match_into does.
+    /// \return whether there was a match.
+    pub fn next_into(&mut self, groups: &mut Vec<Option<Range<usize>>>) -> bool {
+        let Some(pos) = self.position else {
+            return false;
+        };
+        if self.sticky {
+            let matched = self.mp.match_at_into(pos, &mut self.position, groups);
+            if !matched {
+                self.position = None;
+            }
+            matched
+        } else {
+            self.mp.next_match_into(pos, &mut self.position, groups)
+        }
+    }
+
+    /// Advance past the next match like Iterator::next, but only report
+    /// whether there was one.
+    pub fn next_exists(&mut self) -> bool {
+        let Some(pos) = self.position else {
+            return false;
+        };
+        if self.sticky {
+            let matched = self.mp.match_at_exists(pos, &mut self.position);
+            if !matched {
+                self.position = None;
+            }
+            matched
+        } else {
+            self.mp.next_match_exists(pos, &mut self.position)
+        }
+    }
+
+    /// Continue the search from offset \p start, rather than where the last
+    /// match ended.
+    pub fn set_start(&mut self, start: usize) {
+        self.position = self.mp.initial_position(start);
+    }
+
+    /// \return the underlying MatchProducer.
+    pub fn producer(&self) -> &Producer {
+        &self.mp
+    }
+
+    /// \return the underlying MatchProducer, consuming this.
+    pub fn into_producer(self) -> Producer {
+        self.mp
     }
 }
 
@@ -51,6 +186,14 @@ impl<Producer: MatchProducer> Iterator for Matches<Producer> {
     type Item = Match;
     fn next(&mut self) -> Option<Self::Item> {
         let pos = self.position?;
-        self.mp.next_match(pos, &mut self.position)
+        if self.sticky {
+            let m = self.mp.match_at(pos, &mut self.position);
+            if m.is_none() {
+                self.position = None;
+            }
+            m
+        } else {
+            self.mp.next_match(pos, &mut self.position)
+        }
     }
 }
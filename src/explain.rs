@@ -0,0 +1,243 @@
+//! Explanations of why a text does not match a regex, for telling users which
+//! part of their input is invalid.
+
+use crate::api::backends::BacktrackExecutor;
+use crate::bytesearch::ByteSet;
+use crate::codepointset::{CodePointSet, CODE_POINT_MAX};
+use crate::exec::{Executor, MatchProducer};
+use crate::insn::{CompiledRegex, Insn};
+use crate::lazydfa::byte_seq;
+use crate::unicode;
+use core::fmt;
+
+/// The most intervals of an expected set which are described.
+const MAX_DESCRIBED_INTERVALS: usize = 8;
+
+/// Why a text does not match the whole of a regex, as returned by
+/// [`crate::Regex::explain_failure`].
+///
+/// Matching reaches some offset into the text before every path fails. The
+/// report gives the furthest such offset, so that the text before it is the
+/// longest prefix which matches the start of the pattern, and what the
+/// pattern expected there. Its `Display` output is a message like
+/// `expected [0-9] at offset 5, found 'x'`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureReport {
+    pub(crate) offset: usize,
+    pub(crate) unit_offset: usize,
+    pub(crate) found: Option<char>,
+    pub(crate) expected: CodePointSet,
+    pub(crate) expects_end: bool,
+}
+
+impl FailureReport {
+    /// Returns the furthest byte offset reached, which the `Display` output
+    /// reports.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the furthest offset reached, in the
+    /// [`OffsetUnit`](crate::OffsetUnit) of the regex.
+    #[inline]
+    pub fn unit_offset(&self) -> usize {
+        self.unit_offset
+    }
+
+    /// Returns the character at the offset, or None at the end of the text.
+    #[inline]
+    pub fn found(&self) -> Option<char> {
+        self.found
+    }
+
+    /// Returns the code points which would have let matching continue at the
+    /// offset. It is empty if only assertions like `\b`, or backreferences,
+    /// failed there.
+    #[inline]
+    pub fn expected(&self) -> &CodePointSet {
+        &self.expected
+    }
+
+    /// Returns whether the text would have matched had it ended at the
+    /// offset, for example at a `$` or the end of the pattern.
+    #[inline]
+    pub fn expects_end(&self) -> bool {
+        self.expects_end
+    }
+}
+
+impl fmt::Display for FailureReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.expected.is_empty(), self.expects_end) {
+            (true, false) => f.write_str("no match")?,
+            (true, true) => f.write_str("expected end of input")?,
+            (false, expects_end) => {
+                f.write_str("expected ")?;
+                write_set(f, &self.expected)?;
+                if expects_end {
+                    f.write_str(" or end of input")?;
+                }
+            }
+        }
+        write!(f, " at offset {}, found ", self.offset)?;
+        match self.found {
+            Some(c) => write!(f, "'{}'", c.escape_debug()),
+            None => f.write_str("end of input"),
+        }
+    }
+}
+
+/// Describe \p set like a bracket, or as a single quoted code point.
+fn write_set(f: &mut fmt::Formatter, set: &CodePointSet) -> fmt::Result {
+    let ivs = set.intervals();
+    if let [iv] = ivs {
+        if iv.first == 0 && iv.last == CODE_POINT_MAX {
+            return f.write_str("any character");
+        }
+        if iv.first == iv.last {
+            return match char::from_u32(iv.first) {
+                Some(c) => write!(f, "'{}'", c.escape_debug()),
+                None => write!(f, "'\\u{{{:x}}}'", iv.first),
+            };
+        }
+    }
+    // Describe whichever of the set and its complement is simpler.
+    let inverted = set.inverted();
+    let (negated, ivs) = if inverted.intervals().len() < ivs.len() {
+        (true, inverted.intervals())
+    } else {
+        (false, ivs)
+    };
+    f.write_str(if negated { "[^" } else { "[" })?;
+    for iv in ivs.iter().take(MAX_DESCRIBED_INTERVALS) {
+        write_bracket_code_point(f, iv.first)?;
+        if iv.last > iv.first + 1 {
+            f.write_str("-")?;
+        }
+        if iv.last > iv.first {
+            write_bracket_code_point(f, iv.last)?;
+        }
+    }
+    if ivs.len() > MAX_DESCRIBED_INTERVALS {
+        f.write_str("...")?;
+    }
+    f.write_str("]")
+}
+
+fn write_bracket_code_point(f: &mut fmt::Formatter, cp: u32) -> fmt::Result {
+    match char::from_u32(cp) {
+        Some(c) if "\\]-^[".contains(c) => write!(f, "\\{}", c),
+        Some(c) => write!(f, "{}", c.escape_debug()),
+        None => write!(f, "\\u{{{:x}}}", cp),
+    }
+}
+
+fn line_terminators() -> CodePointSet {
+    let mut terminators = CodePointSet::new();
+    for cp in [0x0A, 0x0D, 0x2028, 0x2029] {
+        terminators.add_one(cp);
+    }
+    terminators
+}
+
+/// \return the code points which the instruction \p insn of \p re matches
+/// first, or None if it does not match a code point.
+fn first_code_points(re: &CompiledRegex, insn: &Insn) -> Option<CodePointSet> {
+    let from_code_points = |cps: &mut dyn Iterator<Item = u32>| {
+        let mut set = CodePointSet::new();
+        cps.for_each(|cp| set.add_one(cp));
+        Some(set)
+    };
+    match insn {
+        &Insn::Char(c) => from_code_points(&mut core::iter::once(c)),
+        &Insn::CharICase(c) => from_code_points(&mut unicode::unfold_char(c).into_iter()),
+        Insn::CharSet(chars) => from_code_points(&mut chars.iter().copied()),
+        Insn::ByteSet2(set) => from_code_points(&mut set.0.iter().map(|&b| u32::from(b))),
+        Insn::ByteSet3(set) => from_code_points(&mut set.0.iter().map(|&b| u32::from(b))),
+        Insn::ByteSet4(set) => from_code_points(&mut set.0.iter().map(|&b| u32::from(b))),
+        Insn::AsciiBracket(bitmap) => {
+            from_code_points(&mut (0..128).filter(|&b| bitmap.contains(b)).map(u32::from))
+        }
+        &Insn::Bracket(idx) => {
+            let contents = &re.brackets[idx].contents;
+            Some(if contents.invert {
+                contents.cps.inverted()
+            } else {
+                contents.cps.clone()
+            })
+        }
+        Insn::MatchAny => Some(CodePointSet::new().inverted()),
+        Insn::MatchAnyExceptLineTerminator => Some(line_terminators().inverted()),
+        Insn::UnicodePropertyEscape {
+            property_escape,
+            negate,
+        } => {
+            let cps = unicode::property_escape_code_points(property_escape);
+            Some(if *negate { cps.inverted() } else { cps })
+        }
+        _ => {
+            // A byte sequence may end within a code point.
+            let bytes = byte_seq(insn)?;
+            let valid = match core::str::from_utf8(bytes) {
+                Ok(s) => s,
+                Err(err) => core::str::from_utf8(&bytes[..err.valid_up_to()]).ok()?,
+            };
+            from_code_points(&mut valid.chars().next().map(u32::from).into_iter())
+        }
+    }
+}
+
+/// Explain why \p text does not match the whole of \p re, or \return None if
+/// it does. The offset of the report is a byte offset. If \p step_limit is
+/// set, matching gives up after that many backtracks, and the report covers
+/// the paths explored until then.
+pub(crate) fn explain_failure(
+    re: &CompiledRegex,
+    text: &str,
+    step_limit: Option<usize>,
+) -> Option<FailureReport> {
+    let mut executor = BacktrackExecutor::new(re, text)
+        .without_captures()
+        .anchored_end()
+        .memoized()
+        .tracking_furthest();
+    if let Some(step_limit) = step_limit {
+        executor = executor.with_step_limit(step_limit);
+    }
+    let pos = executor.initial_position(0)?;
+    if executor.match_at_exists(pos, &mut None) {
+        return None;
+    }
+    let (offset, ips) = executor.tracked_furthest()?;
+    let found = text.get(offset..).and_then(|rest| rest.chars().next());
+    let mut expected = CodePointSet::new();
+    let mut expects_end = false;
+    // An end assertion which ran there only failed if it is not at the end of
+    // the text or, for a multiline $, a line.
+    let terminators = line_terminators();
+    let at_line_end = found.is_some_and(|c| terminators.contains(c as u32));
+    for &ip in ips {
+        match re.insns[ip] {
+            Insn::EndOfLine { multiline } if found.is_some() && !(multiline && at_line_end) => {
+                expects_end = true;
+                if multiline {
+                    expected.add_set(terminators.clone());
+                }
+            }
+            Insn::Goal if found.is_some() && ip + 1 == re.insns.len() => expects_end = true,
+            ref insn => {
+                if let Some(cps) = first_code_points(re, insn) {
+                    expected.add_set(cps);
+                }
+            }
+        }
+    }
+    Some(FailureReport {
+        offset,
+        unit_offset: offset,
+        found,
+        expected,
+        expects_end,
+    })
+}
@@ -0,0 +1,501 @@
+//! Incremental regex execution engine
+//!
+//! This finds the matches of a regular program in a text which is fed in
+//! chunks. Like the lazy DFA, it simulates the NFA over code points, but it
+//! keeps its threads in the order the backtracker would try them, so that it
+//! finds the same matches. Nothing is cached, so each character takes time
+//! proportional to the number of threads, which is bounded by the size of
+//! the program.
+
+use crate::api::{Error, Range};
+use crate::insn::{CompiledRegex, Insn, LoopFields};
+use crate::lazydfa::{
+    assertion_holds, byte_seq, char_matches, context_after, encode_utf8, is_regular, saturate,
+};
+use crate::parse;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(not(feature = "std"))]
+use {
+    alloc::{string::String, vec::Vec},
+    hashbrown::HashSet,
+};
+
+/// The state of an NFA thread, which determines what it may match next.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ThreadState {
+    /// Offset in the bytecode.
+    ip: u32,
+
+    /// For a Loop1CharBody, the number of iterations so far. For a byte
+    /// sequence, the number of bytes matched so far. Otherwise 0.
+    aux: usize,
+
+    /// The number of complete iterations of each loop. These are zeroed on
+    /// exiting a loop, and saturate once they no longer affect matching.
+    iters: Vec<usize>,
+
+    /// Whether the current iteration of each loop began at the current
+    /// position, so that an iteration matching the empty string may be
+    /// rejected (ES6 21.2.2.5.1 note 4).
+    entered_here: Vec<bool>,
+}
+
+#[derive(Debug, Clone)]
+struct Thread {
+    state: ThreadState,
+
+    /// The offset at which the thread's match began, in buf and in the text
+    /// fed.
+    start: (usize, usize),
+}
+
+/// An entry on the stack of the closure.
+enum Pending {
+    /// A thread whose non-consuming instructions are still to be followed.
+    Follow(Thread),
+
+    /// A thread which waits on the next character, once the threads above it
+    /// on the stack, which have priority, have been followed.
+    Wait(Thread),
+}
+
+/// Finds the matches of a regex in a text which is fed in chunks, as returned
+/// by [`Regex::incremental`](crate::Regex::incremental).
+///
+/// The matches, which are reported by [`IncrementalMatcher::feed`] and
+/// [`IncrementalMatcher::finish`] as ranges of byte offsets from the start
+/// of the whole text, are those which [`Regex::find_iter`](crate::Regex::find_iter)
+/// would find in the whole text. A match is reported once the text which
+/// follows it shows that no match of higher priority may replace it, so
+/// typically a character or more after its end. The text is not retained,
+/// except from the end of a match which is yet to be reported, as the search
+/// for the next match continues from there.
+///
+/// The text is fed as bytes, which are expected to be UTF-8. A chunk may end
+/// within a code point, whose bytes are held until the next chunk completes
+/// it. As for [`Regex::find_iter_utf8_runs`](crate::Regex::find_iter_utf8_runs),
+/// invalid UTF-8 sequences are hard boundaries: each maximal run of valid
+/// UTF-8 is searched as if it were a separate text.
+#[derive(Debug, Clone)]
+pub struct IncrementalMatcher<'r> {
+    re: &'r CompiledRegex,
+
+    /// The text which has been fed, from the offset buf_start, which may need
+    /// to be scanned again.
+    buf: String,
+    buf_start: usize,
+
+    /// The offset of the next character to scan in buf, and in the text fed,
+    /// which also counts the invalid sequences that buf omits.
+    pos: usize,
+    fed_pos: usize,
+
+    /// The length of the text fed so far.
+    fed_len: usize,
+
+    /// The bytes at the end of the last chunk which begin a code point that
+    /// the next chunk may complete.
+    partial: Vec<u8>,
+
+    /// The character before pos.
+    prev: Option<u32>,
+
+    /// The threads waiting on the character at pos, in priority order.
+    threads: Vec<Thread>,
+
+    /// The match of the highest priority found so far, which a thread of
+    /// higher priority may yet replace, as its range in buf and in the text
+    /// fed, and the character before its end.
+    best: Option<(Range, Range, Option<u32>)>,
+
+    /// The offset at which the next match may begin. If skip_one is set, the
+    /// previous match was empty and ended there, so the next match begins
+    /// after the character at start_at.
+    start_at: usize,
+    skip_one: bool,
+}
+
+impl<'r> IncrementalMatcher<'r> {
+    /// Construct a matcher for \p re, which must be regular.
+    pub(crate) fn new(re: &'r CompiledRegex) -> Result<Self, Error> {
+        if re.custom_word_chars().is_some() {
+            return parse::error("Incremental matching does not support custom word boundaries");
+        }
+        if !is_regular(re) {
+            return parse::error(
+                "Incremental matching does not support backreferences, lookarounds or atomic groups",
+            );
+        }
+        Ok(Self {
+            re,
+            buf: String::new(),
+            buf_start: 0,
+            pos: 0,
+            fed_pos: 0,
+            fed_len: 0,
+            partial: Vec::new(),
+            prev: None,
+            threads: Vec::new(),
+            best: None,
+            start_at: 0,
+            skip_one: false,
+        })
+    }
+
+    /// Feeds the next chunk of text, and returns the matches which it has
+    /// completed.
+    pub fn feed(&mut self, mut chunk: &[u8]) -> Vec<Range> {
+        let mut matches = Vec::new();
+        // Complete the code point which the last chunk ended within, or find
+        // that it is invalid. Each pass takes at least one byte of the chunk.
+        while !self.partial.is_empty() && !chunk.is_empty() {
+            let mut seq = core::mem::take(&mut self.partial);
+            let width = match seq[0] {
+                0xE0..=0xEF => 3,
+                0xF0..=0xFF => 4,
+                _ => 2,
+            };
+            let take = (width - seq.len()).min(chunk.len());
+            seq.extend_from_slice(&chunk[..take]);
+            chunk = &chunk[take..];
+            self.decode(&seq, &mut matches);
+        }
+        self.decode(chunk, &mut matches);
+        // Discard the text which will not be scanned again.
+        let keep_from = self.best.as_ref().map_or(self.pos, |(m, _, _)| m.end);
+        self.buf.drain(..keep_from - self.buf_start);
+        self.buf_start = keep_from;
+        matches
+    }
+
+    /// Ends the text, and returns the remaining matches.
+    pub fn finish(mut self) -> Vec<Range> {
+        let mut matches = Vec::new();
+        // A code point left incomplete is an invalid sequence.
+        if !self.partial.is_empty() {
+            let len = self.partial.len();
+            self.skip_invalid(len, &mut matches);
+        }
+        self.end_run(&mut matches);
+        matches
+    }
+
+    /// Returns the offset at which the earliest match which more text may
+    /// complete or extend begins, or None if there is no such match. Text
+    /// before this offset is not part of any match yet to be reported.
+    pub fn pending_start(&self) -> Option<usize> {
+        let thread_start = self.threads.iter().map(|t| t.start.1).min();
+        let best_start = self.best.as_ref().map(|(_, fed, _)| fed.start);
+        match (thread_start, best_start) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Returns whether more text may complete or extend a match.
+    pub fn is_pending(&self) -> bool {
+        self.pending_start().is_some()
+    }
+
+    /// Returns the length of the text fed so far, without the bytes of a code
+    /// point which the next chunk may complete.
+    pub fn offset(&self) -> usize {
+        self.fed_len
+    }
+
+    /// Search \p bytes, which follow the text fed so far, up to an incomplete
+    /// code point at their end, which is kept in partial.
+    fn decode(&mut self, mut bytes: &[u8], matches: &mut Vec<Range>) {
+        loop {
+            let (valid, rest) = match core::str::from_utf8(bytes) {
+                Ok(text) => (text, None),
+                Err(err) => {
+                    let (valid, rest) = bytes.split_at(err.valid_up_to());
+                    // The prefix was just validated.
+                    let valid = core::str::from_utf8(valid).unwrap_or_default();
+                    (valid, Some((rest, err.error_len())))
+                }
+            };
+            self.buf.push_str(valid);
+            self.fed_len += valid.len();
+            self.scan(matches);
+            match rest {
+                None => return,
+                Some((rest, None)) => {
+                    self.partial = rest.to_vec();
+                    return;
+                }
+                Some((rest, Some(len))) => {
+                    self.skip_invalid(len, matches);
+                    bytes = &rest[len..];
+                }
+            }
+        }
+    }
+
+    /// End the run of valid text before an invalid sequence of \p len bytes,
+    /// and begin a new run after it.
+    fn skip_invalid(&mut self, len: usize, matches: &mut Vec<Range>) {
+        self.end_run(matches);
+        self.fed_pos += len;
+        self.fed_len += len;
+        // The run's text is not needed again, and the next run is searched as
+        // a separate text.
+        self.buf.clear();
+        self.buf_start = self.pos;
+        self.prev = None;
+        self.start_at = self.pos;
+        self.skip_one = false;
+    }
+
+    /// Search the rest of the current run as if the text ended with it.
+    fn end_run(&mut self, matches: &mut Vec<Range>) {
+        loop {
+            self.scan(matches);
+            let count = matches.len();
+            self.advance(None, matches);
+            if matches.len() == count {
+                return;
+            }
+        }
+    }
+
+    /// Scan the characters of the buffer from pos.
+    fn scan(&mut self, matches: &mut Vec<Range>) {
+        while let Some(c) = self.buf[self.pos - self.buf_start..].chars().next() {
+            self.advance(Some(c.into()), matches);
+        }
+    }
+
+    /// \return whether a match may begin at pos.
+    fn may_start_here(&self) -> bool {
+        if self.skip_one || self.best.is_some() {
+            false
+        } else if self.re.flags.sticky {
+            self.pos == self.start_at
+        } else {
+            self.pos >= self.start_at
+        }
+    }
+
+    /// Advance the threads over \p next, the character at pos, or past the
+    /// end of the text if it is None. Completed matches are appended to
+    /// \p matches.
+    fn advance(&mut self, next: Option<u32>, matches: &mut Vec<Range>) {
+        let mut seeds = core::mem::take(&mut self.threads);
+        if self.may_start_here() {
+            seeds.push(Thread {
+                state: ThreadState {
+                    ip: 0,
+                    aux: 0,
+                    iters: vec![0; self.re.loops as usize],
+                    entered_here: vec![false; self.re.loops as usize],
+                },
+                start: (self.pos, self.fed_pos),
+            });
+        }
+        let mut waiting = Vec::new();
+        if let Some((start, fed_start)) = self.closure(seeds, next, &mut waiting) {
+            self.best = Some((start..self.pos, fed_start..self.fed_pos, self.prev));
+        }
+
+        if let Some(c) = next {
+            self.threads = waiting.iter().filter_map(|t| self.step(t, c)).collect();
+            if self.skip_one && self.pos == self.start_at {
+                self.start_at += encode_utf8(c).len();
+                self.skip_one = false;
+            }
+            let len = encode_utf8(c).len();
+            self.pos += len;
+            self.fed_pos += len;
+            self.prev = Some(c);
+        }
+
+        if self.threads.is_empty() {
+            if let Some((m, fed, prev)) = self.best.take() {
+                // Search again from the end of the match, which may be before
+                // pos.
+                self.pos = m.end;
+                self.fed_pos = fed.end;
+                self.prev = prev;
+                self.start_at = m.end;
+                self.skip_one = m.is_empty();
+                matches.push(fed);
+            }
+        }
+    }
+
+    /// Follow the non-consuming instructions from \p seeds, in priority
+    /// order, at a position followed by \p next, collecting the threads which
+    /// wait on a character into \p out. Threads of lower priority than one
+    /// which reaches the Goal are discarded.
+    /// \return the start of the match of the thread which reached the Goal, if
+    /// any, in buf and in the text fed.
+    fn closure(
+        &self,
+        seeds: Vec<Thread>,
+        next: Option<u32>,
+        out: &mut Vec<Thread>,
+    ) -> Option<(usize, usize)> {
+        let insns = &self.re.insns;
+        let ctx = context_after(self.prev);
+        let mut seen = HashSet::new();
+        for seed in seeds {
+            let mut stack = vec![Pending::Follow(seed)];
+            while let Some(pending) = stack.pop() {
+                let mut t = match pending {
+                    Pending::Follow(t) => t,
+                    Pending::Wait(t) => {
+                        out.push(t);
+                        continue;
+                    }
+                };
+                if !seen.insert(t.state.clone()) {
+                    continue;
+                }
+                let ip = t.state.ip as usize;
+                match &insns[ip] {
+                    Insn::Goal => return Some(t.start),
+                    Insn::JustFail => {}
+                    &Insn::Jump { target } => {
+                        t.state.ip = target;
+                        stack.push(Pending::Follow(t));
+                    }
+                    &Insn::Alt { secondary } => {
+                        let mut t2 = t.clone();
+                        t2.state.ip = secondary;
+                        stack.push(Pending::Follow(t2));
+                        t.state.ip += 1;
+                        stack.push(Pending::Follow(t));
+                    }
+                    Insn::BeginCaptureGroup(..)
+                    | Insn::EndCaptureGroup(..)
+                    | Insn::ResetCaptureGroup(..) => {
+                        t.state.ip += 1;
+                        stack.push(Pending::Follow(t));
+                    }
+                    insn @ (Insn::StartOfLine { .. }
+                    | Insn::EndOfLine { .. }
+                    | Insn::WordBoundary { .. }
+                    | Insn::WordEdge { .. }) => {
+                        if assertion_holds(insn, ctx, next) {
+                            t.state.ip += 1;
+                            stack.push(Pending::Follow(t));
+                        }
+                    }
+                    Insn::EnterLoop(fields) => {
+                        t.state.iters[fields.loop_id as usize] = 0;
+                        Self::run_loop(t, ip, fields, true, &mut stack);
+                    }
+                    &Insn::LoopAgain { begin } => match &insns[begin as usize] {
+                        Insn::EnterLoop(fields) => {
+                            Self::run_loop(t, begin as usize, fields, false, &mut stack)
+                        }
+                        _ => panic!("LoopAgain does not point at EnterLoop"),
+                    },
+                    &Insn::Loop1CharBody {
+                        min_iters,
+                        max_iters,
+                        greedy,
+                    } => {
+                        let exit = (t.state.aux >= min_iters).then(|| {
+                            let mut exit = t.clone();
+                            exit.state.ip += 2;
+                            exit.state.aux = 0;
+                            Pending::Follow(exit)
+                        });
+                        let again = (t.state.aux < max_iters).then_some(Pending::Wait(t));
+                        // The arm tried first is pushed last.
+                        if greedy {
+                            stack.extend(exit);
+                            stack.extend(again);
+                        } else {
+                            stack.extend(again);
+                            stack.extend(exit);
+                        }
+                    }
+                    _ => out.push(t),
+                }
+            }
+        }
+        None
+    }
+
+    /// Push the threads which enter and exit the loop beginning at \p begin,
+    /// in the order in which they are to be popped. \p initial is set when
+    /// entering the loop rather than completing an iteration.
+    fn run_loop(
+        mut t: Thread,
+        begin: usize,
+        fields: &LoopFields,
+        initial: bool,
+        stack: &mut Vec<Pending>,
+    ) {
+        let id = fields.loop_id as usize;
+        if !initial {
+            let iters = t.state.iters[id] + 1;
+            if iters > fields.min_iters && t.state.entered_here[id] {
+                // This iteration matched the empty string.
+                return;
+            }
+            // Keep counting past the minimum, for the test above.
+            t.state.iters[id] = saturate(iters, fields.min_iters + 1, fields.max_iters);
+        }
+        let iters = t.state.iters[id];
+        let exit = (iters >= fields.min_iters).then(|| {
+            let mut exit = t.clone();
+            exit.state.ip = fields.exit;
+            exit.state.iters[id] = 0;
+            exit.state.entered_here[id] = false;
+            Pending::Follow(exit)
+        });
+        let enter = (iters < fields.max_iters).then(|| {
+            t.state.ip = begin as u32 + 1;
+            t.state.entered_here[id] = true;
+            Pending::Follow(t)
+        });
+        if fields.greedy {
+            stack.extend(exit);
+            stack.extend(enter);
+        } else {
+            stack.extend(enter);
+            stack.extend(exit);
+        }
+    }
+
+    /// \return the thread which follows \p t after consuming the character
+    /// \p c, if any.
+    fn step(&self, t: &Thread, c: u32) -> Option<Thread> {
+        let insn = &self.re.insns[t.state.ip as usize];
+        let mut next = t.clone();
+        if let &Insn::Loop1CharBody {
+            min_iters,
+            max_iters,
+            ..
+        } = insn
+        {
+            if !char_matches(self.re, &self.re.insns[t.state.ip as usize + 1], c) {
+                return None;
+            }
+            next.state.aux = saturate(t.state.aux + 1, min_iters, max_iters);
+        } else if let Some(bytes) = byte_seq(insn) {
+            let encoded = encode_utf8(c);
+            if !bytes[t.state.aux..].starts_with(encoded.as_slice()) {
+                return None;
+            }
+            next.state.aux += encoded.len();
+            if next.state.aux == bytes.len() {
+                next.state.ip += 1;
+                next.state.aux = 0;
+            }
+        } else {
+            if !char_matches(self.re, insn, c) {
+                return None;
+            }
+            next.state.ip += 1;
+        }
+        next.state.entered_here.fill(false);
+        Some(next)
+    }
+}
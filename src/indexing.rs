@@ -1,7 +1,11 @@
 use crate::bytesearch;
+use crate::bytesearch::ByteSeq;
+use crate::codepointset::CODE_POINT_MAX;
 use crate::matchers;
-use crate::position::{DefPosition, PositionType};
+use crate::position::{DefPosition, IndexPosition, PositionType};
 use crate::util::{is_utf8_continuation, utf8_w2, utf8_w3, utf8_w4};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use core::convert::TryInto;
 use core::{ops, str};
 
@@ -67,16 +71,16 @@ where
     /// A type which references a position in the input string.
     type Position: PositionType;
 
-    /// \return the byte contents.
-    fn contents(&self) -> &[u8];
-
     /// \return the length of the contents, in bytes.
-    fn bytelength(&self) -> usize {
-        self.contents().len()
-    }
+    fn bytelength(&self) -> usize;
 
-    /// \return a slice of the contents.
-    fn slice(&self, start: Self::Position, end: Self::Position) -> &[u8];
+    /// \return whether the bytes starting at \p start are equal to \p bytes.
+    /// There must be at least Bytes::LENGTH bytes to the right of \p start.
+    fn bytes_eq<Bytes: ByteSeq>(&self, start: Self::Position, bytes: &Bytes) -> bool;
+
+    /// \return whether the bytes in \p range are equal to the bytes of the same
+    /// length starting at \p start, which must be in bounds.
+    fn subrange_eq(&self, start: Self::Position, range: ops::Range<Self::Position>) -> bool;
 
     /// \return a sub-input. Note that positions in the original may no longer be valid in the sub-input.
     fn subinput(&self, range: ops::Range<Self::Position>) -> Self;
@@ -139,6 +143,15 @@ where
     fn peek_left(&self, mut pos: Self::Position) -> Option<Self::Element> {
         self.next_left(&mut pos)
     }
+
+    /// \return the run of ASCII bytes starting at \p pos, of at most \p
+    /// max_len bytes. Each is an element of its own, so the position may be
+    /// moved right over any number of them. Inputs which cannot find runs
+    /// quickly return an empty run, to be matched one element at a time.
+    #[inline(always)]
+    fn ascii_run_right(&self, _pos: Self::Position, _max_len: usize) -> &[u8] {
+        &[]
+    }
 }
 
 /// \return the length of a UTF8 sequence starting with this byte.
@@ -222,20 +235,20 @@ impl<'a> Utf8Input<'a> {
         self.debug_assert_valid_pos(pos);
         debug_assert!(pos == self.right_end() || is_seq_start(self.getb(pos)));
     }
-}
-
-impl<'a> InputIndexer for Utf8Input<'a> {
-    type Position = DefPosition<'a>;
-    type Element = char;
-    type CharProps = matchers::UTF8CharProperties;
 
+    /// \return the byte contents.
     #[inline(always)]
     fn contents(&self) -> &[u8] {
         self.input.as_bytes()
     }
 
+    /// \return a slice of the contents.
     #[inline(always)]
-    fn slice(&self, start: Self::Position, end: Self::Position) -> &[u8] {
+    fn slice(
+        &self,
+        start: <Self as InputIndexer>::Position,
+        end: <Self as InputIndexer>::Position,
+    ) -> &[u8] {
         self.debug_assert_valid_pos(start);
         self.debug_assert_valid_pos(end);
         debug_assert!(end >= start, "Slice start after end");
@@ -252,12 +265,40 @@ impl<'a> InputIndexer for Utf8Input<'a> {
         debug_assert!(res.len() <= self.bytelength() && res.len() == end - start);
         res
     }
+}
+
+impl<'a> InputIndexer for Utf8Input<'a> {
+    type Position = DefPosition<'a>;
+    type Element = char;
+    type CharProps = matchers::UTF8CharProperties;
+
+    #[inline(always)]
+    fn bytelength(&self) -> usize {
+        self.contents().len()
+    }
+
+    #[inline(always)]
+    fn bytes_eq<Bytes: ByteSeq>(&self, start: Self::Position, bytes: &Bytes) -> bool {
+        bytes.equals_known_len(self.slice(start, start + Bytes::LENGTH))
+    }
+
+    #[inline(always)]
+    fn subrange_eq(&self, start: Self::Position, range: ops::Range<Self::Position>) -> bool {
+        self.slice(start, start + (range.end - range.start)) == self.slice(range.start, range.end)
+    }
 
     #[inline(always)]
     fn subinput(&self, range: ops::Range<Self::Position>) -> Self {
         Self::new(self.str_slice(range))
     }
 
+    #[inline(always)]
+    fn ascii_run_right(&self, pos: Self::Position, max_len: usize) -> &[u8] {
+        let rest = self.slice(pos, self.right_end());
+        let rest = &rest[..rest.len().min(max_len)];
+        &rest[..bytesearch::ascii_prefix_len(rest)]
+    }
+
     #[inline(always)]
     fn next_right(&self, pos: &mut Self::Position) -> Option<Self::Element> {
         self.debug_assert_boundary(*pos);
@@ -461,71 +502,1029 @@ impl<'a> InputIndexer for Utf8Input<'a> {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct AsciiInput<'a> {
-    input: &'a [u8],
+/// Decode the UTF-8 sequence at the start of \p bytes.
+/// \return the char and its length in bytes, or None if \p bytes is empty or
+/// does not start with a valid UTF-8 sequence.
+#[inline(always)]
+fn decode_utf8(bytes: &[u8]) -> Option<(char, usize)> {
+    let (codepoint, len) = decode_wtf8(bytes)?;
+    // from_u32 rejects surrogates.
+    core::char::from_u32(codepoint).map(|c| (c, len))
 }
 
-impl<'a> AsciiInput<'a> {
-    pub fn new(s: &'a str) -> Self {
-        // The big idea of RefPosition is enforced here.
-        <Self as InputIndexer>::Position::check_size();
+/// Decode the generalized UTF-8 sequence at the start of \p bytes. This is
+/// like decode_utf8, except that surrogate code points are permitted.
+/// \return the code point and its length in bytes.
+#[inline(always)]
+fn decode_wtf8(bytes: &[u8]) -> Option<(u32, usize)> {
+    let b0 = *bytes.first()?;
+    if b0 < 128 {
+        return Some((b0 as u32, 1));
+    }
+    // Reject continuation bytes, overlong two-byte leads, and leads beyond U+10FFFF.
+    if !(0xC2..=0xF4).contains(&b0) {
+        return None;
+    }
+    let len = utf8_seq_len(b0);
+    let seq = bytes.get(..len)?;
+    if !seq[1..].iter().all(|&b| is_utf8_continuation(b)) {
+        return None;
+    }
+    let (codepoint, min) = match len {
+        2 => (utf8_w2(b0, seq[1]), 0x80),
+        3 => (utf8_w3(b0, seq[1], seq[2]), 0x800),
+        _ => (utf8_w4(b0, seq[1], seq[2], seq[3]), 0x10000),
+    };
+    // Reject overlong encodings and out of range values.
+    if codepoint < min || codepoint > CODE_POINT_MAX {
+        return None;
+    }
+    Some((codepoint, len))
+}
+
+/// Decode the UTF-8 sequence at the end of \p bytes.
+/// \return the char and its length in bytes, or None if \p bytes is empty or
+/// does not end with a valid UTF-8 sequence.
+#[inline(always)]
+fn decode_utf8_rev(bytes: &[u8]) -> Option<(char, usize)> {
+    let (codepoint, len) = decode_wtf8_rev(bytes)?;
+    core::char::from_u32(codepoint).map(|c| (c, len))
+}
+
+/// Decode the generalized UTF-8 sequence at the end of \p bytes, permitting
+/// surrogate code points.
+#[inline(always)]
+fn decode_wtf8_rev(bytes: &[u8]) -> Option<(u32, usize)> {
+    let z = *bytes.last()?;
+    if z < 128 {
+        return Some((z as u32, 1));
+    }
+    // Find the start of the sequence, which is at most 4 bytes back.
+    let len = bytes
+        .iter()
+        .rev()
+        .take(4)
+        .position(|&b| !is_utf8_continuation(b))?
+        + 1;
+    match decode_wtf8(&bytes[bytes.len() - len..]) {
+        Some((c, clen)) if clen == len => Some((c, len)),
+        _ => None,
+    }
+}
+
+/// A source of text which may be searched by a [`Regex`](crate::Regex),
+/// without requiring that the text be stored contiguously. For example, this
+/// may be implemented for a rope or a piece table.
+///
+/// Text is addressed by byte offsets into its UTF-8 encoding, from 0 to
+/// [`Input::len`]. Implementations must be consistent: `byte_at`, `next_char`
+/// and `prev_char` must all describe the same sequence of bytes, and the
+/// length returned alongside each char must be its UTF-8 length. The engine
+/// only passes offsets which it has reached by stepping over complete chars,
+/// or over single bytes which do not begin a valid char.
+///
+/// Returning None from `next_char` or `prev_char` at an offset which is not
+/// at the end (or start) indicates invalid text there; no char can match at
+/// that offset. Note that searching an `Input` cannot use the fast literal
+/// prefix scan which is used for contiguous strings.
+pub trait Input {
+    /// Returns the length of the text, in bytes.
+    fn len(&self) -> usize;
+
+    /// Returns whether the text is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the byte at \p offset, which is less than `len()`.
+    fn byte_at(&self, offset: usize) -> u8;
+
+    /// Returns the char starting at byte \p offset and its length in bytes, or
+    /// None if \p offset is `len()` or does not begin a valid UTF-8 sequence.
+    /// The default implementation decodes the result from `byte_at`.
+    fn next_char(&self, offset: usize) -> Option<(char, usize)> {
+        let mut buff = [0; 4];
+        let len = core::cmp::min(4, self.len().checked_sub(offset)?);
+        for (idx, b) in buff[..len].iter_mut().enumerate() {
+            *b = self.byte_at(offset + idx);
+        }
+        decode_utf8(&buff[..len])
+    }
+
+    /// Returns the char ending just before byte \p offset and its length in
+    /// bytes, or None if \p offset is 0 or does not end a valid UTF-8
+    /// sequence. The default implementation decodes the result from `byte_at`.
+    fn prev_char(&self, offset: usize) -> Option<(char, usize)> {
+        let mut buff = [0; 4];
+        let len = core::cmp::min(4, offset);
+        for (idx, b) in buff[..len].iter_mut().enumerate() {
+            *b = self.byte_at(offset - len + idx);
+        }
+        decode_utf8_rev(&buff[..len])
+    }
+}
+
+impl Input for str {
+    #[inline]
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    #[inline]
+    fn byte_at(&self, offset: usize) -> u8 {
+        self.as_bytes()[offset]
+    }
+
+    #[inline]
+    fn next_char(&self, offset: usize) -> Option<(char, usize)> {
+        let c = self.get(offset..)?.chars().next()?;
+        Some((c, c.len_utf8()))
+    }
+
+    #[inline]
+    fn prev_char(&self, offset: usize) -> Option<(char, usize)> {
+        let c = self.get(..offset)?.chars().next_back()?;
+        Some((c, c.len_utf8()))
+    }
+}
+
+impl Input for [u8] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    #[inline]
+    fn byte_at(&self, offset: usize) -> u8 {
+        self[offset]
+    }
+
+    #[inline]
+    fn next_char(&self, offset: usize) -> Option<(char, usize)> {
+        decode_utf8(self.get(offset..)?)
+    }
+
+    #[inline]
+    fn prev_char(&self, offset: usize) -> Option<(char, usize)> {
+        decode_utf8_rev(self.get(..offset)?)
+    }
+}
+
+impl<T: Input + ?Sized> Input for &T {
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    #[inline]
+    fn byte_at(&self, offset: usize) -> u8 {
+        (**self).byte_at(offset)
+    }
+
+    #[inline]
+    fn next_char(&self, offset: usize) -> Option<(char, usize)> {
+        (**self).next_char(offset)
+    }
+
+    #[inline]
+    fn prev_char(&self, offset: usize) -> Option<(char, usize)> {
+        (**self).prev_char(offset)
+    }
+}
+
+/// An InputIndexer over a user-provided Input.
+/// Positions are absolute byte offsets into the Input, so that they remain
+/// valid in sub-inputs, which are windows [start, end) onto the same Input.
+pub struct CustomInput<'a, T: Input + ?Sized> {
+    input: &'a T,
+    start: usize,
+    end: usize,
+}
 
+impl<'a, T: Input + ?Sized> CustomInput<'a, T> {
+    pub fn new(input: &'a T) -> Self {
         Self {
-            input: s.as_bytes(),
+            input,
+            start: 0,
+            end: input.len(),
         }
     }
 
-    /// \return a byte at a given position.
-    /// This asserts that we are not at the right end.
+    /// \return the absolute offset of a position.
     #[inline(always)]
-    fn getb(&self, pos: <Self as InputIndexer>::Position) -> u8 {
-        debug_assert!(self.left_end() <= pos && pos < self.right_end());
-        if cfg!(feature = "prohibit-unsafe") {
-            self.contents()[self.pos_to_offset(pos)]
+    fn offset(&self, pos: <Self as InputIndexer>::Position) -> usize {
+        debug_assert!(self.left_end() <= pos && pos <= self.right_end());
+        pos - IndexPosition::new(0)
+    }
+}
+
+impl<'a, T: Input + ?Sized> Clone for CustomInput<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: Input + ?Sized> Copy for CustomInput<'a, T> {}
+
+impl<'a, T: Input + ?Sized> core::fmt::Debug for CustomInput<'a, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CustomInput({}..{})", self.start, self.end)
+    }
+}
+
+impl<'a, T: Input + ?Sized> InputIndexer for CustomInput<'a, T> {
+    type Position = IndexPosition<'a>;
+    type Element = char;
+    type CharProps = matchers::UTF8CharProperties;
+
+    #[inline(always)]
+    fn bytelength(&self) -> usize {
+        self.end - self.start
+    }
+
+    fn bytes_eq<Bytes: ByteSeq>(&self, start: Self::Position, bytes: &Bytes) -> bool {
+        let start = self.offset(start);
+        (0..Bytes::LENGTH).all(|idx| self.input.byte_at(start + idx) == bytes.as_bytes()[idx])
+    }
+
+    fn subrange_eq(&self, start: Self::Position, range: ops::Range<Self::Position>) -> bool {
+        let start = self.offset(start);
+        let (rstart, rend) = (self.offset(range.start), self.offset(range.end));
+        (0..rend - rstart)
+            .all(|idx| self.input.byte_at(start + idx) == self.input.byte_at(rstart + idx))
+    }
+
+    #[inline(always)]
+    fn subinput(&self, range: ops::Range<Self::Position>) -> Self {
+        Self {
+            input: self.input,
+            start: self.offset(range.start),
+            end: self.offset(range.end),
+        }
+    }
+
+    #[inline(always)]
+    fn next_right(&self, pos: &mut Self::Position) -> Option<Self::Element> {
+        let offset = self.offset(*pos);
+        if offset == self.end {
+            return None;
+        }
+        let (c, len) = self.input.next_char(offset)?;
+        if len > self.end - offset {
+            return None;
+        }
+        *pos += len;
+        Some(c)
+    }
+
+    #[inline(always)]
+    fn next_left(&self, pos: &mut Self::Position) -> Option<Self::Element> {
+        let offset = self.offset(*pos);
+        if offset == self.start {
+            return None;
+        }
+        let (c, len) = self.input.prev_char(offset)?;
+        if len > offset - self.start {
+            return None;
+        }
+        *pos -= len;
+        Some(c)
+    }
+
+    #[inline(always)]
+    fn next_right_pos(&self, mut pos: Self::Position) -> Option<Self::Position> {
+        if pos == self.right_end() {
+            return None;
+        }
+        // Step over invalid bytes one at a time.
+        if self.next_right(&mut pos).is_none() {
+            pos += 1;
+        }
+        Some(pos)
+    }
+
+    #[inline(always)]
+    fn next_left_pos(&self, mut pos: Self::Position) -> Option<Self::Position> {
+        if pos == self.left_end() {
+            return None;
+        }
+        if self.next_left(&mut pos).is_none() {
+            pos -= 1;
+        }
+        Some(pos)
+    }
+
+    #[inline(always)]
+    fn peek_byte_right(&self, pos: Self::Position) -> Option<u8> {
+        let offset = self.offset(pos);
+        if offset == self.end {
+            None
         } else {
-            unsafe { *self.contents().get_unchecked(self.pos_to_offset(pos)) }
+            Some(self.input.byte_at(offset))
         }
     }
 
     #[inline(always)]
-    fn debug_assert_valid_pos(&self, pos: <Self as InputIndexer>::Position) -> &Self {
-        debug_assert!(self.left_end() <= pos && pos <= self.right_end());
-        self
+    fn peek_byte_left(&self, pos: Self::Position) -> Option<u8> {
+        let offset = self.offset(pos);
+        if offset == self.start {
+            None
+        } else {
+            Some(self.input.byte_at(offset - 1))
+        }
+    }
+
+    #[inline(always)]
+    fn left_end(&self) -> Self::Position {
+        IndexPosition::new(self.start)
+    }
+
+    #[inline(always)]
+    fn right_end(&self) -> Self::Position {
+        IndexPosition::new(self.end)
+    }
+
+    #[inline(always)]
+    fn try_move_right(&self, pos: Self::Position, amt: usize) -> Option<Self::Position> {
+        if self.right_end() - pos < amt {
+            None
+        } else {
+            Some(pos + amt)
+        }
+    }
+
+    #[inline(always)]
+    fn try_move_left(&self, pos: Self::Position, amt: usize) -> Option<Self::Position> {
+        if pos - self.left_end() < amt {
+            None
+        } else {
+            Some(pos - amt)
+        }
+    }
+
+    #[inline(always)]
+    fn pos_to_offset(&self, pos: Self::Position) -> usize {
+        pos - self.left_end()
+    }
+
+    /// The text may not be contiguous, so we cannot scan it for the prefix.
+    /// Instead report every position as a candidate.
+    #[inline(always)]
+    fn find_bytes<Search: bytesearch::ByteSearcher>(
+        &self,
+        pos: Self::Position,
+        _search: &Search,
+    ) -> Option<Self::Position> {
+        Some(pos)
     }
 }
 
-impl<'a> InputIndexer for AsciiInput<'a> {
-    type Position = DefPosition<'a>;
-    type Element = u8;
-    type CharProps = matchers::ASCIICharProperties;
+/// An input over bytes which are expected to be UTF-8, but have not been
+/// validated. Code points are decoded and validated lazily as the matcher
+/// visits them. An invalid sequence decodes to no element at all, which causes
+/// any attempt to match a char there to fail.
+#[derive(Debug, Copy, Clone)]
+pub struct Utf8BytesInput<'a> {
+    input: &'a [u8],
+}
 
+impl<'a> Utf8BytesInput<'a> {
     #[inline(always)]
-    fn contents(&self) -> &[u8] {
-        self.input
+    pub fn new(bytes: &'a [u8]) -> Self {
+        <Self as InputIndexer>::Position::check_size();
+        Self { input: bytes }
     }
 
+    /// Decode the UTF-8 sequence starting at \p pos.
     #[inline(always)]
-    fn slice(&self, start: Self::Position, end: Self::Position) -> &[u8] {
-        self.debug_assert_valid_pos(start);
-        self.debug_assert_valid_pos(end);
+    fn decode_right(&self, pos: <Self as InputIndexer>::Position) -> Option<(char, usize)> {
+        decode_utf8(self.slice(pos, self.right_end()))
+    }
 
-        #[cfg(any(feature = "index-positions", feature = "prohibit-unsafe"))]
-        let res = &self.contents()[core::ops::Range {
-            start: self.pos_to_offset(start),
-            end: self.pos_to_offset(end),
-        }];
+    /// Decode the UTF-8 sequence ending just before \p pos.
+    #[inline(always)]
+    fn decode_left(&self, pos: <Self as InputIndexer>::Position) -> Option<(char, usize)> {
+        decode_utf8_rev(self.slice(self.left_end(), pos))
+    }
 
-        #[cfg(all(not(feature = "index-positions"), not(feature = "prohibit-unsafe")))]
-        let res = unsafe { core::slice::from_raw_parts(start.ptr(), end - start) };
+    /// \return the byte contents.
+    #[inline(always)]
+    fn contents(&self) -> &[u8] {
+        self.input
+    }
 
-        debug_assert!(res.len() <= self.bytelength() && res.len() == end - start);
-        res
+    /// \return a slice of the contents.
+    #[inline(always)]
+    fn slice(
+        &self,
+        start: <Self as InputIndexer>::Position,
+        end: <Self as InputIndexer>::Position,
+    ) -> &[u8] {
+        debug_assert!(self.left_end() <= start && end <= self.right_end());
+        debug_assert!(end >= start, "Slice start after end");
+        &self.input[self.pos_to_offset(start)..self.pos_to_offset(end)]
     }
+}
+
+impl<'a> InputIndexer for Utf8BytesInput<'a> {
+    type Position = DefPosition<'a>;
+    type Element = char;
+    type CharProps = matchers::UTF8CharProperties;
 
     #[inline(always)]
-    fn subinput(&self, range: ops::Range<Self::Position>) -> AsciiInput<'a> {
-        self.debug_assert_valid_pos(range.start);
+    fn bytelength(&self) -> usize {
+        self.contents().len()
+    }
+
+    #[inline(always)]
+    fn bytes_eq<Bytes: ByteSeq>(&self, start: Self::Position, bytes: &Bytes) -> bool {
+        bytes.equals_known_len(self.slice(start, start + Bytes::LENGTH))
+    }
+
+    #[inline(always)]
+    fn subrange_eq(&self, start: Self::Position, range: ops::Range<Self::Position>) -> bool {
+        self.slice(start, start + (range.end - range.start)) == self.slice(range.start, range.end)
+    }
+
+    #[inline(always)]
+    fn subinput(&self, range: ops::Range<Self::Position>) -> Self {
+        let input: &'a [u8] = self.input;
+        Self::new(&input[self.pos_to_offset(range.start)..self.pos_to_offset(range.end)])
+    }
+
+    #[inline(always)]
+    fn ascii_run_right(&self, pos: Self::Position, max_len: usize) -> &[u8] {
+        let rest = self.slice(pos, self.right_end());
+        let rest = &rest[..rest.len().min(max_len)];
+        &rest[..bytesearch::ascii_prefix_len(rest)]
+    }
+
+    #[inline(always)]
+    fn next_right(&self, pos: &mut Self::Position) -> Option<Self::Element> {
+        let (c, len) = self.decode_right(*pos)?;
+        *pos += len;
+        Some(c)
+    }
+
+    #[inline(always)]
+    fn next_left(&self, pos: &mut Self::Position) -> Option<Self::Element> {
+        let (c, len) = self.decode_left(*pos)?;
+        *pos -= len;
+        Some(c)
+    }
+
+    #[inline(always)]
+    fn next_right_pos(&self, pos: Self::Position) -> Option<Self::Position> {
+        if pos == self.right_end() {
+            return None;
+        }
+        // Step over invalid bytes one at a time.
+        let len = self.decode_right(pos).map_or(1, |(_, len)| len);
+        Some(pos + len)
+    }
+
+    #[inline(always)]
+    fn next_left_pos(&self, pos: Self::Position) -> Option<Self::Position> {
+        if pos == self.left_end() {
+            return None;
+        }
+        let len = self.decode_left(pos).map_or(1, |(_, len)| len);
+        Some(pos - len)
+    }
+
+    #[inline(always)]
+    fn peek_byte_right(&self, pos: Self::Position) -> Option<u8> {
+        self.input.get(self.pos_to_offset(pos)).copied()
+    }
+
+    #[inline(always)]
+    fn peek_byte_left(&self, pos: Self::Position) -> Option<u8> {
+        let offset = self.pos_to_offset(pos);
+        if offset == 0 {
+            None
+        } else {
+            Some(self.input[offset - 1])
+        }
+    }
+
+    #[inline(always)]
+    fn try_move_right(&self, pos: Self::Position, amt: usize) -> Option<Self::Position> {
+        if self.right_end() - pos < amt {
+            None
+        } else {
+            Some(pos + amt)
+        }
+    }
+
+    #[inline(always)]
+    fn try_move_left(&self, pos: Self::Position, amt: usize) -> Option<Self::Position> {
+        if pos - self.left_end() < amt {
+            None
+        } else {
+            Some(pos - amt)
+        }
+    }
+
+    #[cfg(feature = "index-positions")]
+    #[inline(always)]
+    fn left_end(&self) -> Self::Position {
+        Self::Position::new(0)
+    }
+
+    #[cfg(feature = "index-positions")]
+    #[inline(always)]
+    fn right_end(&self) -> Self::Position {
+        Self::Position::new(self.bytelength())
+    }
+
+    #[cfg(not(feature = "index-positions"))]
+    #[inline(always)]
+    fn left_end(&self) -> Self::Position {
+        Self::Position::new(self.contents().as_ptr())
+    }
+
+    #[cfg(not(feature = "index-positions"))]
+    #[inline(always)]
+    fn right_end(&self) -> Self::Position {
+        self.left_end() + self.bytelength()
+    }
+
+    #[inline(always)]
+    fn pos_to_offset(&self, pos: Self::Position) -> usize {
+        debug_assert!(self.left_end() <= pos && pos <= self.right_end());
+        pos - self.left_end()
+    }
+
+    #[inline(always)]
+    fn find_bytes<Search: bytesearch::ByteSearcher>(
+        &self,
+        pos: Self::Position,
+        search: &Search,
+    ) -> Option<Self::Position> {
+        let rem = self.slice(pos, self.right_end());
+        let idx = search.find_in(rem)?;
+        Some(pos + idx)
+    }
+}
+
+/// UTF-16 text which has been transcoded so that it may be searched.
+///
+/// The text is stored in WTF-8: well-formed surrogate pairs become the UTF-8
+/// encoding of their code point, and lone surrogates are encoded as if they
+/// were scalar values, in three bytes. This is a superset of UTF-8, so the
+/// matcher's byte-oriented literal searches work unchanged. Without the 'u'
+/// flag each code unit is a char, so no surrogates are paired, and each is
+/// encoded on its own.
+#[derive(Debug, Clone)]
+pub struct Utf16Text {
+    bytes: Vec<u8>,
+
+    /// The byte offset of each code unit, followed by the length of bytes.
+    /// The low half of a surrogate pair maps into the middle of the encoding
+    /// of its pair, so that the offsets are strictly increasing.
+    unit_offsets: Vec<usize>,
+}
+
+impl Utf16Text {
+    /// Transcode \p units, combining well-formed surrogate pairs into the
+    /// code point they encode if \p pair_surrogates is set.
+    pub fn new(units: &[u16], pair_surrogates: bool) -> Self {
+        let mut bytes = Vec::with_capacity(units.len());
+        let mut unit_offsets = Vec::with_capacity(units.len() + 1);
+        let mut decoded_units = core::char::decode_utf16(units.iter().copied());
+        let mut lone_units = units.iter().map(|&u| char::from_u32(u32::from(u)).ok_or(u));
+        loop {
+            let decoded = if pair_surrogates {
+                decoded_units
+                    .next()
+                    .map(|d| d.map_err(|err| err.unpaired_surrogate()))
+            } else {
+                lone_units.next()
+            };
+            let Some(decoded) = decoded else {
+                break;
+            };
+            let start = bytes.len();
+            match decoded {
+                Ok(c) => {
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                    unit_offsets.push(start);
+                    if c.len_utf16() == 2 {
+                        unit_offsets.push(start + 2);
+                    }
+                }
+                Err(unit) => {
+                    let cp = u32::from(unit);
+                    bytes.extend_from_slice(&[
+                        0xE0 | (cp >> 12) as u8,
+                        0x80 | ((cp >> 6) & 0x3F) as u8,
+                        0x80 | (cp & 0x3F) as u8,
+                    ]);
+                    unit_offsets.push(start);
+                }
+            }
+        }
+        unit_offsets.push(bytes.len());
+        Self {
+            bytes,
+            unit_offsets,
+        }
+    }
+
+    /// \return the transcoded bytes.
+    #[inline(always)]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// \return the byte offset of the code unit at index \p unit, or None if
+    /// it is out of bounds or is the low half of a surrogate pair.
+    pub fn byte_offset(&self, unit: usize) -> Option<usize> {
+        let offset = *self.unit_offsets.get(unit)?;
+        match self.bytes.get(offset) {
+            Some(&b) if is_utf8_continuation(b) => None,
+            _ => Some(offset),
+        }
+    }
+
+    /// \return the code unit index of the byte offset \p offset, which must
+    /// fall on a code point boundary.
+    pub fn unit_offset(&self, offset: usize) -> usize {
+        let idx = self.unit_offsets.binary_search(&offset);
+        debug_assert!(idx.is_ok(), "Offset is not on a code point boundary");
+        idx.unwrap_or_else(|idx| idx)
+    }
+
+    /// \return the byte offset of the code point boundary after \p offset,
+    /// which must fall on one, or None if \p offset is the end of the text.
+    pub fn next_boundary(&self, offset: usize) -> Option<usize> {
+        let unit = self.unit_offset(offset);
+        self.unit_offsets
+            .get(unit + 1..)?
+            .iter()
+            .copied()
+            .find(|&next| {
+                self.bytes
+                    .get(next)
+                    .is_none_or(|&b| !is_utf8_continuation(b))
+            })
+    }
+}
+
+/// An input over the bytes of a Utf16Text. Unlike Utf8BytesInput, the
+/// elements are code points rather than chars, so that lone surrogates may be
+/// matched.
+#[derive(Debug, Copy, Clone)]
+pub struct Wtf8Input<'a> {
+    input: Utf8BytesInput<'a>,
+}
+
+impl<'a> Wtf8Input<'a> {
+    #[inline(always)]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            input: Utf8BytesInput::new(bytes),
+        }
+    }
+
+    /// Decode the sequence starting at \p pos.
+    #[inline(always)]
+    fn decode_right(&self, pos: <Self as InputIndexer>::Position) -> Option<(u32, usize)> {
+        let input = &self.input;
+        decode_wtf8(input.slice(pos, input.right_end()))
+    }
+
+    /// Decode the sequence ending just before \p pos.
+    #[inline(always)]
+    fn decode_left(&self, pos: <Self as InputIndexer>::Position) -> Option<(u32, usize)> {
+        let input = &self.input;
+        decode_wtf8_rev(input.slice(input.left_end(), pos))
+    }
+}
+
+impl<'a> InputIndexer for Wtf8Input<'a> {
+    type Position = DefPosition<'a>;
+    type Element = u32;
+    type CharProps = matchers::CodePointCharProperties;
+
+    #[inline(always)]
+    fn bytelength(&self) -> usize {
+        self.input.bytelength()
+    }
+
+    #[inline(always)]
+    fn bytes_eq<Bytes: ByteSeq>(&self, start: Self::Position, bytes: &Bytes) -> bool {
+        self.input.bytes_eq(start, bytes)
+    }
+
+    #[inline(always)]
+    fn subrange_eq(&self, start: Self::Position, range: ops::Range<Self::Position>) -> bool {
+        self.input.subrange_eq(start, range)
+    }
+
+    #[inline(always)]
+    fn subinput(&self, range: ops::Range<Self::Position>) -> Self {
+        Self {
+            input: self.input.subinput(range),
+        }
+    }
+
+    #[inline(always)]
+    fn ascii_run_right(&self, pos: Self::Position, max_len: usize) -> &[u8] {
+        self.input.ascii_run_right(pos, max_len)
+    }
+
+    #[inline(always)]
+    fn next_right(&self, pos: &mut Self::Position) -> Option<Self::Element> {
+        let (c, len) = self.decode_right(*pos)?;
+        *pos += len;
+        Some(c)
+    }
+
+    #[inline(always)]
+    fn next_left(&self, pos: &mut Self::Position) -> Option<Self::Element> {
+        let (c, len) = self.decode_left(*pos)?;
+        *pos -= len;
+        Some(c)
+    }
+
+    #[inline(always)]
+    fn next_right_pos(&self, pos: Self::Position) -> Option<Self::Position> {
+        if pos == self.right_end() {
+            return None;
+        }
+        let len = self.decode_right(pos).map_or(1, |(_, len)| len);
+        Some(pos + len)
+    }
+
+    #[inline(always)]
+    fn next_left_pos(&self, pos: Self::Position) -> Option<Self::Position> {
+        if pos == self.left_end() {
+            return None;
+        }
+        let len = self.decode_left(pos).map_or(1, |(_, len)| len);
+        Some(pos - len)
+    }
+
+    #[inline(always)]
+    fn peek_byte_right(&self, pos: Self::Position) -> Option<u8> {
+        self.input.peek_byte_right(pos)
+    }
+
+    #[inline(always)]
+    fn peek_byte_left(&self, pos: Self::Position) -> Option<u8> {
+        self.input.peek_byte_left(pos)
+    }
+
+    #[inline(always)]
+    fn try_move_right(&self, pos: Self::Position, amt: usize) -> Option<Self::Position> {
+        self.input.try_move_right(pos, amt)
+    }
+
+    #[inline(always)]
+    fn try_move_left(&self, pos: Self::Position, amt: usize) -> Option<Self::Position> {
+        self.input.try_move_left(pos, amt)
+    }
+
+    #[inline(always)]
+    fn left_end(&self) -> Self::Position {
+        self.input.left_end()
+    }
+
+    #[inline(always)]
+    fn right_end(&self) -> Self::Position {
+        self.input.right_end()
+    }
+
+    #[inline(always)]
+    fn pos_to_offset(&self, pos: Self::Position) -> usize {
+        self.input.pos_to_offset(pos)
+    }
+
+    #[inline(always)]
+    fn find_bytes<Search: bytesearch::ByteSearcher>(
+        &self,
+        pos: Self::Position,
+        search: &Search,
+    ) -> Option<Self::Position> {
+        self.input.find_bytes(pos, search)
+    }
+}
+
+/// An input over a str whose ASCII letters are seen in lowercase, for a
+/// program folded by CompiledRegex::ascii_case_folded. The text is not
+/// copied: chars and bytes are lowercased as they are read, and literal
+/// bytes and backreferences are compared regardless of ASCII case.
+#[derive(Debug, Copy, Clone)]
+pub struct AsciiFoldedInput<'a> {
+    input: Utf8Input<'a>,
+}
+
+impl<'a> AsciiFoldedInput<'a> {
+    #[inline(always)]
+    pub fn new(s: &'a str) -> Self {
+        Self {
+            input: Utf8Input::new(s),
+        }
+    }
+}
+
+impl<'a> InputIndexer for AsciiFoldedInput<'a> {
+    type Position = DefPosition<'a>;
+    type Element = char;
+    type CharProps = matchers::UTF8CharProperties;
+
+    #[inline(always)]
+    fn bytelength(&self) -> usize {
+        self.input.bytelength()
+    }
+
+    #[inline(always)]
+    fn bytes_eq<Bytes: ByteSeq>(&self, start: Self::Position, bytes: &Bytes) -> bool {
+        // The folded bytes are lowercase.
+        let text = self.input.slice(start, start + Bytes::LENGTH);
+        text.eq_ignore_ascii_case(bytes.as_bytes())
+    }
+
+    #[inline(always)]
+    fn subrange_eq(&self, start: Self::Position, range: ops::Range<Self::Position>) -> bool {
+        let input = &self.input;
+        let prefix = input.slice(start, start + (range.end - range.start));
+        prefix.eq_ignore_ascii_case(input.slice(range.start, range.end))
+    }
+
+    #[inline(always)]
+    fn subinput(&self, range: ops::Range<Self::Position>) -> Self {
+        Self {
+            input: self.input.subinput(range),
+        }
+    }
+
+    /// The run is only skipped by `.`, which matches ASCII letters of either
+    /// case, so it is not lowercased.
+    #[inline(always)]
+    fn ascii_run_right(&self, pos: Self::Position, max_len: usize) -> &[u8] {
+        self.input.ascii_run_right(pos, max_len)
+    }
+
+    #[inline(always)]
+    fn next_right(&self, pos: &mut Self::Position) -> Option<Self::Element> {
+        self.input.next_right(pos).map(|c| c.to_ascii_lowercase())
+    }
+
+    #[inline(always)]
+    fn next_left(&self, pos: &mut Self::Position) -> Option<Self::Element> {
+        self.input.next_left(pos).map(|c| c.to_ascii_lowercase())
+    }
+
+    #[inline(always)]
+    fn next_right_pos(&self, pos: Self::Position) -> Option<Self::Position> {
+        self.input.next_right_pos(pos)
+    }
+
+    #[inline(always)]
+    fn next_left_pos(&self, pos: Self::Position) -> Option<Self::Position> {
+        self.input.next_left_pos(pos)
+    }
+
+    #[inline(always)]
+    fn peek_byte_right(&self, pos: Self::Position) -> Option<u8> {
+        self.input
+            .peek_byte_right(pos)
+            .map(|b| b.to_ascii_lowercase())
+    }
+
+    #[inline(always)]
+    fn peek_byte_left(&self, pos: Self::Position) -> Option<u8> {
+        self.input
+            .peek_byte_left(pos)
+            .map(|b| b.to_ascii_lowercase())
+    }
+
+    #[inline(always)]
+    fn try_move_right(&self, pos: Self::Position, amt: usize) -> Option<Self::Position> {
+        self.input.try_move_right(pos, amt)
+    }
+
+    #[inline(always)]
+    fn try_move_left(&self, pos: Self::Position, amt: usize) -> Option<Self::Position> {
+        self.input.try_move_left(pos, amt)
+    }
+
+    #[inline(always)]
+    fn left_end(&self) -> Self::Position {
+        self.input.left_end()
+    }
+
+    #[inline(always)]
+    fn right_end(&self) -> Self::Position {
+        self.input.right_end()
+    }
+
+    #[inline(always)]
+    fn pos_to_offset(&self, pos: Self::Position) -> usize {
+        self.input.pos_to_offset(pos)
+    }
+
+    /// The searcher is the start predicate of the folded program, which
+    /// finds ASCII letters of either case, so the text is searched as is.
+    #[inline(always)]
+    fn find_bytes<Search: bytesearch::ByteSearcher>(
+        &self,
+        pos: Self::Position,
+        search: &Search,
+    ) -> Option<Self::Position> {
+        self.input.find_bytes(pos, search)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct AsciiInput<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> AsciiInput<'a> {
+    pub fn new(s: &'a str) -> Self {
+        // The big idea of RefPosition is enforced here.
+        <Self as InputIndexer>::Position::check_size();
+
+        Self {
+            input: s.as_bytes(),
+        }
+    }
+
+    /// \return a byte at a given position.
+    /// This asserts that we are not at the right end.
+    #[inline(always)]
+    fn getb(&self, pos: <Self as InputIndexer>::Position) -> u8 {
+        debug_assert!(self.left_end() <= pos && pos < self.right_end());
+        if cfg!(feature = "prohibit-unsafe") {
+            self.contents()[self.pos_to_offset(pos)]
+        } else {
+            unsafe { *self.contents().get_unchecked(self.pos_to_offset(pos)) }
+        }
+    }
+
+    #[inline(always)]
+    fn debug_assert_valid_pos(&self, pos: <Self as InputIndexer>::Position) -> &Self {
+        debug_assert!(self.left_end() <= pos && pos <= self.right_end());
+        self
+    }
+
+    /// \return the byte contents.
+    #[inline(always)]
+    fn contents(&self) -> &[u8] {
+        self.input
+    }
+
+    /// \return a slice of the contents.
+    #[inline(always)]
+    fn slice(
+        &self,
+        start: <Self as InputIndexer>::Position,
+        end: <Self as InputIndexer>::Position,
+    ) -> &[u8] {
+        self.debug_assert_valid_pos(start);
+        self.debug_assert_valid_pos(end);
+
+        #[cfg(any(feature = "index-positions", feature = "prohibit-unsafe"))]
+        let res = &self.contents()[core::ops::Range {
+            start: self.pos_to_offset(start),
+            end: self.pos_to_offset(end),
+        }];
+
+        #[cfg(all(not(feature = "index-positions"), not(feature = "prohibit-unsafe")))]
+        let res = unsafe { core::slice::from_raw_parts(start.ptr(), end - start) };
+
+        debug_assert!(res.len() <= self.bytelength() && res.len() == end - start);
+        res
+    }
+}
+
+impl<'a> InputIndexer for AsciiInput<'a> {
+    type Position = DefPosition<'a>;
+    type Element = u8;
+    type CharProps = matchers::ASCIICharProperties;
+
+    #[inline(always)]
+    fn bytelength(&self) -> usize {
+        self.contents().len()
+    }
+
+    #[inline(always)]
+    fn bytes_eq<Bytes: ByteSeq>(&self, start: Self::Position, bytes: &Bytes) -> bool {
+        bytes.equals_known_len(self.slice(start, start + Bytes::LENGTH))
+    }
+
+    #[inline(always)]
+    fn subrange_eq(&self, start: Self::Position, range: ops::Range<Self::Position>) -> bool {
+        self.slice(start, start + (range.end - range.start)) == self.slice(range.start, range.end)
+    }
+
+    #[inline(always)]
+    fn subinput(&self, range: ops::Range<Self::Position>) -> AsciiInput<'a> {
+        self.debug_assert_valid_pos(range.start);
         self.debug_assert_valid_pos(range.end);
         debug_assert!(range.end >= range.start);
         AsciiInput {
@@ -536,6 +1535,13 @@ impl<'a> InputIndexer for AsciiInput<'a> {
         }
     }
 
+    #[inline(always)]
+    fn ascii_run_right(&self, pos: Self::Position, max_len: usize) -> &[u8] {
+        let rest = self.slice(pos, self.right_end());
+        let rest = &rest[..rest.len().min(max_len)];
+        &rest[..bytesearch::ascii_prefix_len(rest)]
+    }
+
     #[inline(always)]
     fn next_right(&self, pos: &mut Self::Position) -> Option<Self::Element> {
         self.debug_assert_valid_pos(*pos);
@@ -1,17 +1,17 @@
 //! Bytecode instructions for a compiled regex
 
-#[cfg(feature = "std")]
-use std::collections::HashMap;
 #[cfg(not(feature = "std"))]
-use {
-    alloc::{string::String, vec::Vec},
-    hashbrown::HashMap,
-};
+use alloc::{string::String, sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
 use crate::api;
-use crate::bytesearch::{AsciiBitmap, ByteArraySet, ByteBitmap};
+use crate::bytesearch::{AsciiBitmap, ByteArraySet, ByteBitmap, ByteSearcher, ByteSet};
+use crate::codepointset::CodePointSet;
+use crate::lazydfa::DfaCachePool;
 use crate::types::{BracketContents, CaptureGroupID, LoopID};
-use crate::unicode::PropertyEscape;
+use crate::unicode::{self, PropertyEscape};
+use core::fmt;
 
 type JumpTarget = u32;
 
@@ -47,11 +47,15 @@ pub enum Insn {
     /// Match a single char, case-insensitive.
     CharICase(u32),
 
-    /// Match the start of a line (if multiline); emitted by '^'
-    StartOfLine,
+    /// Match the start of a line (if multiline) or the input; emitted by '^'
+    StartOfLine {
+        multiline: bool,
+    },
 
-    /// Match the end of a line; emitted by '$'
-    EndOfLine,
+    /// Match the end of a line (if multiline) or the input; emitted by '$'
+    EndOfLine {
+        multiline: bool,
+    },
 
     /// Match any character except a line terminator; emitted by '.' only when
     /// the dot_all flag is set to true.
@@ -96,8 +100,11 @@ pub enum Insn {
     /// Clear a capture group.
     ResetCaptureGroup(CaptureGroupID),
 
-    /// Perform a backreference match.
-    BackRef(u32),
+    /// Perform a backreference match, optionally case-insensitive.
+    BackRef {
+        group: u32,
+        icase: bool,
+    },
 
     /// Match the next character against the bracket contents, stored at the given index in the CompiledRegex.
     Bracket(usize),
@@ -122,9 +129,29 @@ pub enum Insn {
         continuation: JumpTarget,
     },
 
-    /// \w or \W word boundaries.
+    /// Match an atomic group, whose contents begin at the next instruction
+    /// and end with a Goal, then continue without retaining any of the
+    /// group's backtracking state.
+    AtomicGroup {
+        start_group: CaptureGroupID,
+        end_group: CaptureGroupID,
+        continuation: JumpTarget,
+    },
+
+    /// \b or \B word boundaries.
     WordBoundary {
         invert: bool,
+        /// Whether to use Unicode rather than ASCII word characters.
+        unicode: bool,
+    },
+
+    /// \< or \> start or end of word, or \b{start-half} or \b{end-half}
+    /// if half is set.
+    WordEdge {
+        end: bool,
+        half: bool,
+        /// Whether to use Unicode rather than ASCII word characters.
+        unicode: bool,
     },
 
     /// Match any of the contained chars
@@ -180,18 +207,634 @@ pub enum StartPredicate {
 
     /// Look for any of the contained bytes.
     ByteSet2([u8; 2]),
+    ByteSet3([u8; 3]),
 
     /// Look for a byte which matches the bitmap.
     ByteBracket(ByteBitmap),
 }
 
+impl StartPredicate {
+    /// \return the offset of the first position in \p bytes where a match
+    /// may begin, or None if there is none.
+    pub fn find_in(&self, bytes: &[u8]) -> Option<usize> {
+        match self {
+            StartPredicate::Arbitrary => Some(0),
+            StartPredicate::ByteSeq1(seq) => seq.find_in(bytes),
+            StartPredicate::ByteSeq2(seq) => seq.find_in(bytes),
+            StartPredicate::ByteSeq3(seq) => seq.find_in(bytes),
+            StartPredicate::ByteSeq4(seq) => seq.find_in(bytes),
+            &StartPredicate::ByteSet2(set) => ByteArraySet(set).find_in(bytes),
+            &StartPredicate::ByteSet3(set) => ByteArraySet(set).find_in(bytes),
+            StartPredicate::ByteBracket(bitmap) => bitmap.find_in(bytes),
+        }
+    }
+}
+
+/// A bracket of a compiled regex.
+/// The ASCII characters which it matches are also stored in a bitmap, so that
+/// these may be tested without searching the code points.
+#[derive(Debug, Clone)]
+pub struct CompiledBracket {
+    pub contents: BracketContents,
+    pub ascii: AsciiBitmap,
+}
+
+impl CompiledBracket {
+    pub fn new(contents: BracketContents) -> CompiledBracket {
+        let mut ascii = AsciiBitmap::default();
+        for b in 0..128 {
+            if contents.cps.contains(u32::from(b)) != contents.invert {
+                ascii.set(b)
+            }
+        }
+        CompiledBracket { contents, ascii }
+    }
+
+    /// \return whether the bracket matches the code point \p cp.
+    /// Respects 'invert'.
+    #[inline(always)]
+    pub fn contains(&self, cp: u32) -> bool {
+        if cp < 128 {
+            self.ascii.contains(cp as u8)
+        } else {
+            self.contents.cps.contains(cp) != self.contents.invert
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CompiledRegex {
     pub insns: Vec<Insn>,
-    pub brackets: Vec<BracketContents>,
+    pub brackets: Vec<CompiledBracket>,
     pub start_pred: StartPredicate,
     pub loops: u32,
     pub groups: u32,
-    pub named_group_indices: HashMap<String, u16>,
+    pub named_group_indices: GroupNames,
     pub flags: api::Flags,
+
+    /// The least and greatest length in bytes of a match, where the greatest
+    /// is None if it is unbounded.
+    pub match_length_bounds: (usize, Option<usize>),
+
+    /// The word chars of the word boundary assertions, if not those chosen by
+    /// the flags.
+    pub word_boundary: Option<api::WordBoundary>,
+
+    /// The caches of the lazy DFA, kept between calls of is_match.
+    pub dfa_caches: DfaCachePool,
+
+    /// The program for UTF-16 text without the 'u' flag, if it differs.
+    pub utf16_program: LazyProgram,
+
+    /// The program for matching ASCII letters regardless of case.
+    pub ascii_folded_program: LazyProgram,
+}
+
+/// A program derived from another for some kind of input, compiled when it
+/// is first needed. With std it is then kept; without, it is compiled again
+/// each time.
+#[derive(Default)]
+pub struct LazyProgram {
+    #[cfg(feature = "std")]
+    cell: std::sync::OnceLock<Option<Arc<CompiledRegex>>>,
+}
+
+impl LazyProgram {
+    /// \return the program, compiling it with \p compile if it is not kept.
+    /// None means that the original program serves.
+    pub fn get_or_init<F>(&self, compile: F) -> Option<Arc<CompiledRegex>>
+    where
+        F: FnOnce() -> Option<CompiledRegex>,
+    {
+        #[cfg(feature = "std")]
+        return self.cell.get_or_init(|| compile().map(Arc::new)).clone();
+        #[cfg(not(feature = "std"))]
+        compile().map(Arc::new)
+    }
+}
+
+/// A cloned program starts without the derived one, since it may be modified.
+impl Clone for LazyProgram {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for LazyProgram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LazyProgram")
+    }
+}
+
+/// The named capture groups of a regex with their indices, ordered by index,
+/// which is the order of declaration. Patterns have few named groups, so a
+/// lookup by name is a linear scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupNames {
+    entries: Vec<(String, u16)>,
+}
+
+impl GroupNames {
+    /// Add the group \p name with index \p idx.
+    /// \return false, leaving the names unchanged, if \p name is present.
+    pub fn insert(&mut self, name: String, idx: u16) -> bool {
+        if self.get(&name).is_some() {
+            return false;
+        }
+        let pos = self.entries.partition_point(|&(_, other)| other < idx);
+        self.entries.insert(pos, (name, idx));
+        true
+    }
+
+    /// \return the index of the group \p name, if any.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<u16> {
+        self.entries
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|&(_, idx)| idx)
+    }
+
+    /// \return the name of the group with index \p idx, if it is named.
+    pub fn name_of(&self, idx: u16) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|&&(_, other)| other == idx)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// \return the name and index of the \p i'th group in order of index.
+    #[inline]
+    pub fn entry(&self, i: usize) -> Option<(&str, u16)> {
+        let (name, idx) = self.entries.get(i)?;
+        Some((name.as_str(), *idx))
+    }
+
+    /// \return the names and their indices, in order of index.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, u16)> + Clone + '_ {
+        self.entries.iter().map(|(name, idx)| (name.as_str(), *idx))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// \return whether a WordEdge assertion with \p end and \p half holds at a
+/// position, given whether word characters are before and after it.
+pub fn is_word_edge(end: bool, half: bool, prev_wordchar: bool, curr_wordchar: bool) -> bool {
+    let (inside, outside) = if end {
+        (prev_wordchar, curr_wordchar)
+    } else {
+        (curr_wordchar, prev_wordchar)
+    };
+    !outside && (half || inside)
+}
+
+/// \return \p cps with the lowercase form of each of its ASCII uppercase
+/// letters added.
+fn ascii_case_closed(cps: &CodePointSet) -> CodePointSet {
+    let mut result = cps.clone();
+    for b in b'A'..=b'Z' {
+        if cps.contains(u32::from(b)) {
+            result.add_one(u32::from(b.to_ascii_lowercase()));
+        }
+    }
+    result
+}
+
+impl CompiledRegex {
+    /// Make the word boundary assertions use the word chars of \p wb.
+    pub fn set_word_boundary(&mut self, wb: api::WordBoundary) {
+        for insn in self.insns.iter_mut() {
+            if let Insn::WordBoundary { unicode, .. } | Insn::WordEdge { unicode, .. } = insn {
+                *unicode = wb == api::WordBoundary::Unicode;
+            }
+        }
+        self.word_boundary = Some(wb);
+    }
+
+    /// \return the word chars of the word boundary assertions, if they are
+    /// neither the ASCII nor the Unicode ones.
+    #[inline]
+    pub fn custom_word_chars(&self) -> Option<&CodePointSet> {
+        match &self.word_boundary {
+            Some(api::WordBoundary::Custom(cps)) => Some(cps),
+            _ => None,
+        }
+    }
+
+    /// \return a program which matches, in a text whose ASCII letters are
+    /// seen in lowercase as by AsciiFoldedInput, what this program would
+    /// match if its ASCII letters matched regardless of case. The
+    /// instructions keep their positions, so jump targets are unchanged.
+    pub fn ascii_case_folded(&self) -> CompiledRegex {
+        let mut result = self.clone();
+        if let Some(api::WordBoundary::Custom(cps)) = &mut result.word_boundary {
+            *cps = ascii_case_closed(cps);
+        }
+        for bracket in result.brackets.iter_mut() {
+            *bracket = CompiledBracket::new(BracketContents {
+                invert: bracket.contents.invert,
+                cps: ascii_case_closed(&bracket.contents.cps),
+            });
+        }
+        for insn in result.insns.iter_mut() {
+            match insn {
+                Insn::Char(c) | Insn::CharICase(c) => {
+                    if let Ok(b) = u8::try_from(*c) {
+                        *c = u32::from(b.to_ascii_lowercase());
+                    }
+                }
+                Insn::CharSet(chars) => {
+                    for c in chars.iter_mut() {
+                        if let Ok(b) = u8::try_from(*c) {
+                            *c = u32::from(b.to_ascii_lowercase());
+                        }
+                    }
+                }
+                Insn::AsciiBracket(bitmap) => {
+                    for b in b'A'..=b'Z' {
+                        if bitmap.contains(b) {
+                            bitmap.set(b.to_ascii_lowercase());
+                        }
+                    }
+                }
+                Insn::ByteSet2(set) => set.0.make_ascii_lowercase(),
+                Insn::ByteSet3(set) => set.0.make_ascii_lowercase(),
+                Insn::ByteSet4(set) => set.0.make_ascii_lowercase(),
+                Insn::ByteSeq1(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq2(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq3(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq4(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq5(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq6(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq7(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq8(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq9(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq10(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq11(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq12(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq13(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq14(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq15(v) => v.make_ascii_lowercase(),
+                Insn::ByteSeq16(v) => v.make_ascii_lowercase(),
+                Insn::UnicodePropertyEscape {
+                    property_escape,
+                    negate,
+                } => {
+                    // The property must also match the lowercased forms of its
+                    // ASCII letters, so it becomes a bracket.
+                    let cps = unicode::property_escape_code_points(property_escape);
+                    result.brackets.push(CompiledBracket::new(BracketContents {
+                        invert: *negate,
+                        cps: ascii_case_closed(&cps),
+                    }));
+                    *insn = Insn::Bracket(result.brackets.len() - 1);
+                }
+                _ => {}
+            }
+        }
+        // The start predicate searches the text as it is, so it must find
+        // ASCII letters of either case. A literal prefix which has letters
+        // is reduced to its first byte.
+        let case_closed = |bytes: &[u8]| {
+            let mut bitmap = ByteBitmap::new(bytes);
+            for &b in bytes {
+                bitmap.set(b.to_ascii_lowercase());
+                bitmap.set(b.to_ascii_uppercase());
+            }
+            StartPredicate::ByteBracket(bitmap)
+        };
+        let has_letters = |bytes: &[u8]| bytes.iter().any(u8::is_ascii_alphabetic);
+        result.start_pred = match result.start_pred {
+            StartPredicate::ByteSeq1(v) if has_letters(&v) => case_closed(&v[..1]),
+            StartPredicate::ByteSeq2(v) if has_letters(&v) => case_closed(&v[..1]),
+            StartPredicate::ByteSeq3(v) if has_letters(&v) => case_closed(&v[..1]),
+            StartPredicate::ByteSeq4(v) if has_letters(&v) => case_closed(&v[..1]),
+            StartPredicate::ByteSet2(v) if has_letters(&v) => case_closed(&v),
+            StartPredicate::ByteSet3(v) if has_letters(&v) => case_closed(&v),
+            StartPredicate::ByteBracket(mut bitmap) => {
+                for b in b'A'..=b'Z' {
+                    let lower = b.to_ascii_lowercase();
+                    if bitmap.contains(b) || bitmap.contains(lower) {
+                        bitmap.set(b);
+                        bitmap.set(lower);
+                    }
+                }
+                StartPredicate::ByteBracket(bitmap)
+            }
+            start_pred => start_pred,
+        };
+        result
+    }
+}
+
+/// The greatest number of intervals of a bracket which are displayed.
+const MAX_DISPLAYED_INTERVALS: usize = 16;
+
+/// Write the code point \p c, as a quoted char if it is one, and otherwise in
+/// hex.
+fn write_code_point(f: &mut fmt::Formatter, c: u32) -> fmt::Result {
+    match char::from_u32(c) {
+        Some(c) => write!(f, "{:?}", c),
+        None => write!(f, "{:#x}", c),
+    }
+}
+
+/// Write the bytes \p bytes, as a quoted string if they are UTF-8, and
+/// otherwise in hex.
+fn write_bytes(f: &mut fmt::Formatter, bytes: &[u8]) -> fmt::Result {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => write!(f, "{:?}", s),
+        Err(_) => write!(f, "{:x?}", bytes),
+    }
+}
+
+/// Write the byte \p b, as a quoted char if it is ASCII, and otherwise in hex.
+fn write_byte(f: &mut fmt::Formatter, b: u8) -> fmt::Result {
+    if b.is_ascii() {
+        write!(f, "{:?}", b as char)
+    } else {
+        write!(f, "{:#x}", b)
+    }
+}
+
+/// Write each of \p bytes, separated by spaces.
+fn write_byte_set(f: &mut fmt::Formatter, bytes: &[u8]) -> fmt::Result {
+    for (i, &b) in bytes.iter().enumerate() {
+        if i > 0 {
+            write!(f, " ")?;
+        }
+        write_byte(f, b)?;
+    }
+    Ok(())
+}
+
+/// Write the bytes for which \p contains is true, as ranges in brackets.
+fn write_byte_ranges(f: &mut fmt::Formatter, contains: impl Fn(u8) -> bool) -> fmt::Result {
+    write!(f, "[")?;
+    let mut b = 0usize;
+    let mut first = true;
+    while b < 256 {
+        if !contains(b as u8) {
+            b += 1;
+            continue;
+        }
+        let mut last = b;
+        while last < 255 && contains(last as u8 + 1) {
+            last += 1;
+        }
+        if !first {
+            write!(f, " ")?;
+        }
+        first = false;
+        write_byte(f, b as u8)?;
+        if last > b {
+            write!(f, "-")?;
+            write_byte(f, last as u8)?;
+        }
+        b = last + 1;
+    }
+    write!(f, "]")
+}
+
+fn write_max_iters(f: &mut fmt::Formatter, max_iters: usize) -> fmt::Result {
+    if max_iters == usize::MAX {
+        write!(f, "inf")
+    } else {
+        write!(f, "{}", max_iters)
+    }
+}
+
+impl Insn {
+    /// \return the bytes matched by a byte sequence instruction.
+    fn as_byte_seq(&self) -> Option<&[u8]> {
+        Some(match self {
+            Insn::ByteSeq1(v) => v,
+            Insn::ByteSeq2(v) => v,
+            Insn::ByteSeq3(v) => v,
+            Insn::ByteSeq4(v) => v,
+            Insn::ByteSeq5(v) => v,
+            Insn::ByteSeq6(v) => v,
+            Insn::ByteSeq7(v) => v,
+            Insn::ByteSeq8(v) => v,
+            Insn::ByteSeq9(v) => v,
+            Insn::ByteSeq10(v) => v,
+            Insn::ByteSeq11(v) => v,
+            Insn::ByteSeq12(v) => v,
+            Insn::ByteSeq13(v) => v,
+            Insn::ByteSeq14(v) => v,
+            Insn::ByteSeq15(v) => v,
+            Insn::ByteSeq16(v) => v,
+            _ => return None,
+        })
+    }
+}
+
+/// Disassembles one instruction. Jump targets are instruction indexes, and
+/// brackets are indexes into the program's bracket list.
+impl fmt::Display for Insn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(bytes) = self.as_byte_seq() {
+            write!(f, "ByteSeq{} ", bytes.len())?;
+            return write_bytes(f, bytes);
+        }
+        match self {
+            Insn::Goal => write!(f, "Goal"),
+            &Insn::Char(c) => {
+                write!(f, "Char ")?;
+                write_code_point(f, c)
+            }
+            &Insn::CharICase(c) => {
+                write!(f, "CharICase ")?;
+                write_code_point(f, c)
+            }
+            Insn::StartOfLine { multiline } => write!(f, "StartOfLine multiline: {}", multiline),
+            Insn::EndOfLine { multiline } => write!(f, "EndOfLine multiline: {}", multiline),
+            Insn::MatchAny => write!(f, "MatchAny"),
+            Insn::MatchAnyExceptLineTerminator => write!(f, "MatchAnyExceptLineTerminator"),
+            Insn::EnterLoop(fields) => {
+                let kind = if fields.greedy {
+                    "greedy"
+                } else {
+                    "non-greedy"
+                };
+                write!(f, "EnterLoop #{} {{{}, ", fields.loop_id, fields.min_iters)?;
+                write_max_iters(f, fields.max_iters)?;
+                write!(f, "}} {} exit -> {}", kind, fields.exit)
+            }
+            Insn::LoopAgain { begin } => write!(f, "LoopAgain -> {}", begin),
+            &Insn::Loop1CharBody {
+                min_iters,
+                max_iters,
+                greedy,
+            } => {
+                let kind = if greedy { "greedy" } else { "non-greedy" };
+                write!(f, "Loop1CharBody {{{}, ", min_iters)?;
+                write_max_iters(f, max_iters)?;
+                write!(f, "}} {}", kind)
+            }
+            Insn::Jump { target } => write!(f, "Jump -> {}", target),
+            Insn::Alt { secondary } => write!(f, "Alt else -> {}", secondary),
+            Insn::BeginCaptureGroup(group) => write!(f, "BeginCaptureGroup {}", group),
+            Insn::EndCaptureGroup(group) => write!(f, "EndCaptureGroup {}", group),
+            Insn::ResetCaptureGroup(group) => write!(f, "ResetCaptureGroup {}", group),
+            Insn::BackRef { group, icase } => write!(f, "BackRef {} icase: {}", group, icase),
+            Insn::Bracket(idx) => write!(f, "Bracket #{}", idx),
+            Insn::AsciiBracket(bitmap) => {
+                write!(f, "AsciiBracket ")?;
+                write_byte_ranges(f, |b| bitmap.contains(b))
+            }
+            &Insn::Lookahead {
+                negate,
+                start_group,
+                end_group,
+                continuation,
+            }
+            | &Insn::Lookbehind {
+                negate,
+                start_group,
+                end_group,
+                continuation,
+            } => {
+                let name = if matches!(self, Insn::Lookahead { .. }) {
+                    "Lookahead"
+                } else {
+                    "Lookbehind"
+                };
+                let sense = if negate { "negative" } else { "positive" };
+                write!(
+                    f,
+                    "{} {} groups {}..{} continue -> {}",
+                    name, sense, start_group, end_group, continuation
+                )
+            }
+            Insn::AtomicGroup {
+                start_group,
+                end_group,
+                continuation,
+            } => write!(
+                f,
+                "AtomicGroup groups {}..{} continue -> {}",
+                start_group, end_group, continuation
+            ),
+            &Insn::WordBoundary { invert, unicode } => {
+                let kind = if invert { "\\B" } else { "\\b" };
+                write!(f, "WordBoundary {} unicode: {}", kind, unicode)
+            }
+            &Insn::WordEdge { end, half, unicode } => {
+                let kind = crate::ir::word_edge_escape(end, half);
+                write!(f, "WordEdge {} unicode: {}", kind, unicode)
+            }
+            Insn::CharSet(chars) => {
+                write!(f, "CharSet")?;
+                for &c in chars {
+                    write!(f, " ")?;
+                    write_code_point(f, c)?;
+                }
+                Ok(())
+            }
+            Insn::ByteSet2(set) => {
+                write!(f, "ByteSet2 ")?;
+                write_byte_set(f, &set.0)
+            }
+            Insn::ByteSet3(set) => {
+                write!(f, "ByteSet3 ")?;
+                write_byte_set(f, &set.0)
+            }
+            Insn::ByteSet4(set) => {
+                write!(f, "ByteSet4 ")?;
+                write_byte_set(f, &set.0)
+            }
+            Insn::UnicodePropertyEscape {
+                property_escape,
+                negate,
+            } => {
+                let sense = if *negate { "negative " } else { "" };
+                write!(
+                    f,
+                    "UnicodePropertyEscape {}{:?} {:?}",
+                    sense, property_escape.name, property_escape.value
+                )
+            }
+            Insn::JustFail => write!(f, "JustFail"),
+            _ => unreachable!("Byte sequences are handled above"),
+        }
+    }
+}
+
+/// Writes the bytes which the predicate looks for: a quoted literal, or "any
+/// of" a set of bytes.
+impl fmt::Display for StartPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StartPredicate::Arbitrary => write!(f, "Arbitrary"),
+            StartPredicate::ByteSeq1(v) => write_bytes(f, v),
+            StartPredicate::ByteSeq2(v) => write_bytes(f, v),
+            StartPredicate::ByteSeq3(v) => write_bytes(f, v),
+            StartPredicate::ByteSeq4(v) => write_bytes(f, v),
+            StartPredicate::ByteSet2(v) => {
+                write!(f, "any of ")?;
+                write_byte_set(f, v)
+            }
+            StartPredicate::ByteSet3(v) => {
+                write!(f, "any of ")?;
+                write_byte_set(f, v)
+            }
+            StartPredicate::ByteBracket(bitmap) => {
+                write!(f, "any of ")?;
+                write_byte_ranges(f, |b| bitmap.contains(b))
+            }
+        }
+    }
+}
+
+/// Disassembles the program: the start predicate, then one line per
+/// instruction, then the brackets. This is for debugging, and the format is
+/// not stable.
+impl fmt::Display for CompiledRegex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "flags: /{}/ groups: {} loops: {}",
+            self.flags, self.groups, self.loops
+        )?;
+        writeln!(f, "start predicate: {}", self.start_pred)?;
+        let width = self.insns.len().max(2).saturating_sub(1).ilog10() as usize + 1;
+        for (idx, insn) in self.insns.iter().enumerate() {
+            writeln!(f, "{:>width$}: {}", idx, insn, width = width)?;
+        }
+        for (idx, bracket) in self.brackets.iter().enumerate() {
+            write!(f, "bracket #{}: [", idx)?;
+            if bracket.contents.invert {
+                write!(f, "^")?;
+            }
+            // Large classes, like Unicode properties, are abbreviated.
+            let intervals = bracket.contents.cps.intervals();
+            for (i, iv) in intervals.iter().enumerate() {
+                if i == MAX_DISPLAYED_INTERVALS {
+                    write!(f, " ...{} more", intervals.len() - i)?;
+                    break;
+                }
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                write_code_point(f, iv.first)?;
+                if iv.last != iv.first {
+                    write!(f, "-")?;
+                    write_code_point(f, iv.last)?;
+                }
+            }
+            writeln!(f, "]")?;
+        }
+        Ok(())
+    }
 }
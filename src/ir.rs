@@ -1,11 +1,12 @@
 //! Intermediate representation for a regex
 
 use crate::api;
+use crate::codepointset::CodePointSet;
 use crate::types::{BracketContents, CaptureGroupID, CaptureGroupName};
-use crate::unicode::PropertyEscape;
+use crate::unicode::{self, PropertyEscape};
 #[cfg(not(feature = "std"))]
 use alloc::{boxed::Box, string::ToString, vec::Vec};
-use core::fmt;
+use core::{cmp, fmt};
 
 #[derive(Debug, Copy, Clone)]
 pub enum AnchorType {
@@ -27,7 +28,7 @@ pub struct Quantifier {
 }
 
 /// The node types of our IR.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Node {
     /// Matches the empty string.
     Empty,
@@ -62,10 +63,25 @@ pub enum Node {
     MatchAnyExceptLineTerminator,
 
     /// Match an anchor like ^ or $
-    Anchor(AnchorType),
+    Anchor {
+        anchor_type: AnchorType,
+        multiline: bool,
+    },
 
-    /// Word boundary (\b or \B).
-    WordBoundary { invert: bool },
+    /// Word boundary (\b or \B). If unicode is set, word characters are
+    /// Unicode word characters rather than only ASCII.
+    WordBoundary { invert: bool, unicode: bool },
+
+    /// Start or end of a word (\< or \>): a word character only after the
+    /// position, if end is not set, or only before it, if end is set. If half
+    /// is set, as for \b{start-half} and \b{end-half}, only the side outside
+    /// the word is checked. If unicode is set, word characters are Unicode
+    /// word characters.
+    WordEdge {
+        end: bool,
+        half: bool,
+        unicode: bool,
+    },
 
     /// A capturing group.
     CaptureGroup(Box<Node>, CaptureGroupID),
@@ -74,7 +90,7 @@ pub enum Node {
     NamedCaptureGroup(Box<Node>, CaptureGroupID, CaptureGroupName),
 
     /// A backreference.
-    BackRef(u32),
+    BackRef { group: u32, icase: bool },
 
     /// A bracket.
     Bracket(BracketContents),
@@ -88,6 +104,15 @@ pub enum Node {
         contents: Box<Node>,
     },
 
+    /// An atomic group like (?>...), which discards its backtracking state once
+    /// it has matched. The half-open range start_group..end_group is the range
+    /// of contained capture groups.
+    AtomicGroup {
+        start_group: CaptureGroupID,
+        end_group: CaptureGroupID,
+        contents: Box<Node>,
+    },
+
     /// A loop like /.*/ or /x{3, 5}?/
     Loop {
         loopee: Box<Node>,
@@ -161,7 +186,13 @@ impl Node {
             }
             Node::MatchAny => Node::MatchAny,
             Node::MatchAnyExceptLineTerminator => Node::MatchAnyExceptLineTerminator,
-            &Node::Anchor(anchor_type) => Node::Anchor(anchor_type),
+            &Node::Anchor {
+                anchor_type,
+                multiline,
+            } => Node::Anchor {
+                anchor_type,
+                multiline,
+            },
 
             Node::Loop {
                 loopee,
@@ -187,8 +218,9 @@ impl Node {
             Node::CaptureGroup(..) | Node::NamedCaptureGroup(..) => {
                 panic!("Refusing to duplicate a capture group");
             }
-            &Node::WordBoundary { invert } => Node::WordBoundary { invert },
-            &Node::BackRef(idx) => Node::BackRef(idx),
+            &Node::WordBoundary { invert, unicode } => Node::WordBoundary { invert, unicode },
+            &Node::WordEdge { end, half, unicode } => Node::WordEdge { end, half, unicode },
+            &Node::BackRef { group, icase } => Node::BackRef { group, icase },
             Node::Bracket(bc) => Node::Bracket(bc.clone()),
             // Do not reverse into lookarounds, they already have the right sense.
             Node::LookaroundAssertion {
@@ -210,6 +242,21 @@ impl Node {
                     contents: Box::new((*contents).duplicate()),
                 }
             }
+            Node::AtomicGroup {
+                start_group,
+                end_group,
+                contents,
+            } => {
+                assert!(
+                    start_group >= end_group,
+                    "Cannot duplicate an atomic group with enclosed groups"
+                );
+                Node::AtomicGroup {
+                    start_group: *start_group,
+                    end_group: *end_group,
+                    contents: Box::new((*contents).duplicate()),
+                }
+            }
             Node::UnicodePropertyEscape {
                 property_escape,
                 negate: negated,
@@ -257,6 +304,7 @@ where
             | Node::ByteSet(..)
             | Node::CharSet(..)
             | Node::WordBoundary { .. }
+            | Node::WordEdge { .. }
             | Node::BackRef { .. }
             | Node::Bracket { .. }
             | Node::UnicodePropertyEscape { .. }
@@ -277,6 +325,7 @@ where
             Node::CaptureGroup(contents, ..) | Node::NamedCaptureGroup(contents, ..) => {
                 self.process(contents.as_ref())
             }
+            Node::AtomicGroup { contents, .. } => self.process(contents.as_ref()),
 
             Node::LookaroundAssertion {
                 backwards,
@@ -333,6 +382,7 @@ where
             | Node::MatchAnyExceptLineTerminator
             | Node::Anchor { .. }
             | Node::WordBoundary { .. }
+            | Node::WordEdge { .. }
             | Node::BackRef { .. }
             | Node::Bracket { .. }
             | Node::UnicodePropertyEscape { .. } => {}
@@ -350,6 +400,7 @@ where
             Node::CaptureGroup(contents, ..) | Node::NamedCaptureGroup(contents, ..) => {
                 self.process(contents.as_mut())
             }
+            Node::AtomicGroup { contents, .. } => self.process(contents.as_mut()),
 
             Node::LookaroundAssertion {
                 backwards,
@@ -414,12 +465,377 @@ where
 }
 
 /// A regex in IR form.
+#[derive(Debug, Clone)]
 pub struct Regex {
     pub node: Node,
     pub flags: api::Flags,
 }
 
-impl Regex {}
+impl Regex {
+    /// \return whether the regex is regular, having no backreferences,
+    /// lookarounds or atomic groups, and has no unbounded loop within
+    /// another.
+    pub fn is_linear_safe(&self) -> bool {
+        is_linear_safe(&self.node, false)
+    }
+
+    /// \return the least and greatest length in bytes of a match, where the
+    /// greatest is None if it is unbounded.
+    pub fn match_length_bounds(&self) -> (usize, Option<usize>) {
+        match_length_bounds(&self.node)
+    }
+
+    /// \return the code points with which a match may begin. If the regex
+    /// may match the empty string, this is every code point.
+    pub fn first_code_points(&self) -> CodePointSet {
+        match first_code_points(&self.node) {
+            (cps, false) => cps,
+            (_, true) => all_code_points(),
+        }
+    }
+
+    /// \return the code points with which a match may end, or None if that
+    /// is unknown because the regex may match the empty string, or may end
+    /// with a backreference.
+    pub fn last_code_points(&self) -> Option<CodePointSet> {
+        match last_code_points(&self.node)? {
+            (cps, false) => Some(cps),
+            (_, true) => None,
+        }
+    }
+
+    /// \return whether every match begins at the start of the text, because
+    /// it must pass a non-multiline ^ before matching anything.
+    pub fn is_anchored_start(&self) -> bool {
+        is_anchored(&self.node, AnchorType::StartOfLine)
+    }
+
+    /// \return whether every match ends at the end of the text, because it
+    /// must pass a non-multiline $ after matching everything.
+    pub fn is_anchored_end(&self) -> bool {
+        is_anchored(&self.node, AnchorType::EndOfLine)
+    }
+}
+
+/// \return the length in bytes of \p cp when encoded as UTF-8. Surrogates
+/// take three bytes, as in WTF-8.
+fn utf8_len(cp: u32) -> usize {
+    char::from_u32(cp).map_or(3, char::len_utf8)
+}
+
+/// \return the least and greatest UTF-8 length of the code points of \p cps.
+fn code_point_length_bounds(cps: &CodePointSet) -> (usize, Option<usize>) {
+    // UTF-8 lengths increase with the code point.
+    match (cps.intervals().first(), cps.intervals().last()) {
+        (Some(first), Some(last)) => (utf8_len(first.first), Some(utf8_len(last.last))),
+        // An empty set never matches, so any bounds hold.
+        _ => (0, Some(0)),
+    }
+}
+
+/// \return the least and greatest length in bytes of a match of \p node,
+/// where the greatest is None if it is unbounded.
+fn match_length_bounds(node: &Node) -> (usize, Option<usize>) {
+    match node {
+        Node::Empty
+        | Node::Goal
+        | Node::Anchor { .. }
+        | Node::WordBoundary { .. }
+        | Node::WordEdge { .. }
+        | Node::LookaroundAssertion { .. } => (0, Some(0)),
+        Node::BackRef { .. } => (0, None),
+        Node::MatchAny | Node::MatchAnyExceptLineTerminator => (1, Some(4)),
+        &Node::Char { c, icase: false } => (utf8_len(c), Some(utf8_len(c))),
+        &Node::Char { c, icase: true } => {
+            let lens = || unicode::unfold_char(c).into_iter().map(utf8_len);
+            (lens().min().unwrap_or(0), lens().max())
+        }
+        Node::ByteSequence(bytes) => (bytes.len(), Some(bytes.len())),
+        Node::ByteSet(..) => (1, Some(1)),
+        Node::CharSet(chars) => (
+            chars.iter().map(|&c| utf8_len(c)).min().unwrap_or(0),
+            Some(chars.iter().map(|&c| utf8_len(c)).max().unwrap_or(0)),
+        ),
+        Node::Bracket(BracketContents { invert, cps }) => {
+            if *invert {
+                code_point_length_bounds(&cps.inverted())
+            } else {
+                code_point_length_bounds(cps)
+            }
+        }
+        // Case-insensitive property escapes may match other case forms.
+        Node::UnicodePropertyEscape { .. } => (1, Some(4)),
+        Node::Cat(nodes) => {
+            let mut result: (usize, Option<usize>) = (0, Some(0));
+            for (min, max) in nodes.iter().map(match_length_bounds) {
+                result.0 = result.0.saturating_add(min);
+                result.1 = result.1.zip(max).and_then(|(x, y)| x.checked_add(y));
+            }
+            result
+        }
+        Node::Alt(left, right) => {
+            let (left_min, left_max) = match_length_bounds(left);
+            let (right_min, right_max) = match_length_bounds(right);
+            (
+                cmp::min(left_min, right_min),
+                left_max.zip(right_max).map(|(x, y)| cmp::max(x, y)),
+            )
+        }
+        Node::CaptureGroup(contents, ..)
+        | Node::NamedCaptureGroup(contents, ..)
+        | Node::AtomicGroup { contents, .. } => match_length_bounds(contents),
+        Node::Loop { loopee, quant, .. } | Node::Loop1CharBody { loopee, quant } => {
+            let (min, max) = match_length_bounds(loopee);
+            let max = match max {
+                Some(0) => Some(0),
+                _ if quant.max == usize::MAX => None,
+                _ => max.and_then(|max| max.checked_mul(quant.max)),
+            };
+            (min.saturating_mul(quant.min), max)
+        }
+    }
+}
+
+fn all_code_points() -> CodePointSet {
+    CodePointSet::new().inverted()
+}
+
+/// \return the code points which \p node may match first, and whether it
+/// may match the empty string, so that what follows it may match first
+/// instead. Assertions are treated as matching the empty string, so the set
+/// may be larger than needed, but it is never too small.
+fn first_code_points(node: &Node) -> (CodePointSet, bool) {
+    let from_code_points = |cps: &mut dyn Iterator<Item = u32>| {
+        let mut set = CodePointSet::new();
+        cps.for_each(|cp| set.add_one(cp));
+        (set, false)
+    };
+    match node {
+        Node::Empty
+        | Node::Goal
+        | Node::Anchor { .. }
+        | Node::WordBoundary { .. }
+        | Node::WordEdge { .. }
+        | Node::LookaroundAssertion { .. } => (CodePointSet::new(), true),
+        // A backreference may match anything, or nothing.
+        Node::BackRef { .. } => (all_code_points(), true),
+        Node::MatchAny => (all_code_points(), false),
+        Node::MatchAnyExceptLineTerminator => {
+            let mut terminators = CodePointSet::new();
+            for cp in [0x0A, 0x0D, 0x2028, 0x2029] {
+                terminators.add_one(cp);
+            }
+            (terminators.inverted(), false)
+        }
+        &Node::Char { c, icase } => {
+            if icase {
+                from_code_points(&mut unicode::unfold_char(c).into_iter())
+            } else {
+                from_code_points(&mut core::iter::once(c))
+            }
+        }
+        Node::ByteSequence(bytes) => match core::str::from_utf8(bytes)
+            .ok()
+            .and_then(|s| s.chars().next())
+        {
+            Some(c) => from_code_points(&mut core::iter::once(c as u32)),
+            None => (all_code_points(), bytes.is_empty()),
+        },
+        Node::ByteSet(bytes) => from_code_points(&mut bytes.iter().map(|&b| u32::from(b))),
+        Node::CharSet(chars) => from_code_points(&mut chars.iter().copied()),
+        Node::Bracket(BracketContents { invert, cps }) => {
+            (if *invert { cps.inverted() } else { cps.clone() }, false)
+        }
+        Node::UnicodePropertyEscape {
+            property_escape,
+            negate,
+        } => {
+            let cps = unicode::property_escape_code_points(property_escape);
+            (if *negate { cps.inverted() } else { cps }, false)
+        }
+        Node::Cat(nodes) => {
+            let mut result = CodePointSet::new();
+            for node in nodes {
+                let (cps, nullable) = first_code_points(node);
+                result.add_set(cps);
+                if !nullable {
+                    return (result, false);
+                }
+            }
+            (result, true)
+        }
+        Node::Alt(left, right) => {
+            let (mut cps, left_nullable) = first_code_points(left);
+            let (right_cps, right_nullable) = first_code_points(right);
+            cps.add_set(right_cps);
+            (cps, left_nullable || right_nullable)
+        }
+        Node::CaptureGroup(contents, ..)
+        | Node::NamedCaptureGroup(contents, ..)
+        | Node::AtomicGroup { contents, .. } => first_code_points(contents),
+        Node::Loop { loopee, quant, .. } | Node::Loop1CharBody { loopee, quant } => {
+            if quant.max == 0 {
+                return (CodePointSet::new(), true);
+            }
+            let (cps, nullable) = first_code_points(loopee);
+            (cps, nullable || quant.min == 0)
+        }
+    }
+}
+
+/// \return the code points which \p node may match last, and whether it may
+/// match the empty string, so that what precedes it may match last instead.
+/// As in first_code_points, assertions are treated as matching the empty
+/// string. \return None if a backreference may match last, as what it
+/// matches is unknown.
+fn last_code_points(node: &Node) -> Option<(CodePointSet, bool)> {
+    match node {
+        Node::BackRef { .. } => None,
+        Node::ByteSequence(bytes) => match core::str::from_utf8(bytes)
+            .ok()
+            .and_then(|s| s.chars().next_back())
+        {
+            Some(c) => {
+                let mut set = CodePointSet::new();
+                set.add_one(c as u32);
+                Some((set, false))
+            }
+            None => Some((all_code_points(), bytes.is_empty())),
+        },
+        Node::Cat(nodes) => {
+            let mut result = CodePointSet::new();
+            for node in nodes.iter().rev() {
+                let (cps, nullable) = last_code_points(node)?;
+                result.add_set(cps);
+                if !nullable {
+                    return Some((result, false));
+                }
+            }
+            Some((result, true))
+        }
+        Node::Alt(left, right) => {
+            let (mut cps, left_nullable) = last_code_points(left)?;
+            let (right_cps, right_nullable) = last_code_points(right)?;
+            cps.add_set(right_cps);
+            Some((cps, left_nullable || right_nullable))
+        }
+        Node::CaptureGroup(contents, ..)
+        | Node::NamedCaptureGroup(contents, ..)
+        | Node::AtomicGroup { contents, .. } => last_code_points(contents),
+        Node::Loop { loopee, quant, .. } | Node::Loop1CharBody { loopee, quant } => {
+            if quant.max == 0 {
+                return Some((CodePointSet::new(), true));
+            }
+            let (cps, nullable) = last_code_points(loopee)?;
+            Some((cps, nullable || quant.min == 0))
+        }
+        // These match at most one code point, so they end as they begin.
+        Node::Empty
+        | Node::Goal
+        | Node::Anchor { .. }
+        | Node::WordBoundary { .. }
+        | Node::WordEdge { .. }
+        | Node::LookaroundAssertion { .. }
+        | Node::MatchAny
+        | Node::MatchAnyExceptLineTerminator
+        | Node::Char { .. }
+        | Node::ByteSet(..)
+        | Node::CharSet(..)
+        | Node::Bracket(..)
+        | Node::UnicodePropertyEscape { .. } => Some(first_code_points(node)),
+    }
+}
+
+/// \return whether every match of \p node passes a non-multiline anchor of
+/// type \p anchor_type at its start, for StartOfLine, or at its end, for
+/// EndOfLine. This is conservative: it looks only past assertions and other
+/// nodes which match the empty string, so the anchor in `a?^` is not seen.
+fn is_anchored(node: &Node, anchor_type: AnchorType) -> bool {
+    let at_end = matches!(anchor_type, AnchorType::EndOfLine);
+    let zero_width = |node: &Node| match_length_bounds(node).1 == Some(0);
+    match node {
+        &Node::Anchor {
+            anchor_type: ty,
+            multiline: false,
+        } => matches!(ty, AnchorType::EndOfLine) == at_end,
+        Node::Cat(nodes) => {
+            let mut is_anchored_at = |node: &Node| {
+                if is_anchored(node, anchor_type) {
+                    Some(true)
+                } else if zero_width(node) {
+                    None
+                } else {
+                    Some(false)
+                }
+            };
+            let found = match at_end {
+                false => nodes.iter().find_map(&mut is_anchored_at),
+                true => nodes.iter().rev().find_map(&mut is_anchored_at),
+            };
+            found.unwrap_or(false)
+        }
+        Node::Alt(left, right) => is_anchored(left, anchor_type) && is_anchored(right, anchor_type),
+        Node::CaptureGroup(contents, ..)
+        | Node::NamedCaptureGroup(contents, ..)
+        | Node::AtomicGroup { contents, .. } => is_anchored(contents, anchor_type),
+        Node::Loop { loopee, quant, .. } => quant.min > 0 && is_anchored(loopee, anchor_type),
+        // A lookahead tests the position where it appears, so an anchor at
+        // its start applies there. An anchor at its end applies there only if
+        // the lookahead matches no text.
+        Node::LookaroundAssertion {
+            negate: false,
+            backwards: false,
+            contents,
+            ..
+        } => is_anchored(contents, anchor_type) && (!at_end || zero_width(contents)),
+        _ => false,
+    }
+}
+
+/// \return whether \p node is regular and has no nested unbounded loops.
+/// \p in_unbounded_loop is set if \p node is within an unbounded loop.
+fn is_linear_safe(node: &Node, in_unbounded_loop: bool) -> bool {
+    match node {
+        Node::Empty
+        | Node::Goal
+        | Node::Char { .. }
+        | Node::ByteSequence(..)
+        | Node::ByteSet(..)
+        | Node::CharSet(..)
+        | Node::WordBoundary { .. }
+        | Node::WordEdge { .. }
+        | Node::Bracket { .. }
+        | Node::UnicodePropertyEscape { .. }
+        | Node::MatchAny
+        | Node::MatchAnyExceptLineTerminator
+        | Node::Anchor { .. } => true,
+        Node::BackRef { .. } | Node::LookaroundAssertion { .. } | Node::AtomicGroup { .. } => false,
+        Node::Cat(nodes) => nodes.iter().all(|n| is_linear_safe(n, in_unbounded_loop)),
+        Node::Alt(left, right) => {
+            is_linear_safe(left, in_unbounded_loop) && is_linear_safe(right, in_unbounded_loop)
+        }
+        Node::CaptureGroup(contents, ..) | Node::NamedCaptureGroup(contents, ..) => {
+            is_linear_safe(contents, in_unbounded_loop)
+        }
+        Node::Loop { loopee, quant, .. } | Node::Loop1CharBody { loopee, quant } => {
+            let unbounded = quant.max == usize::MAX;
+            if unbounded && in_unbounded_loop {
+                return false;
+            }
+            is_linear_safe(loopee, in_unbounded_loop || unbounded)
+        }
+    }
+}
+
+/// \return the escape of a WordEdge node with \p end and \p half.
+pub(crate) fn word_edge_escape(end: bool, half: bool) -> &'static str {
+    match (end, half) {
+        (false, false) => "\\<",
+        (true, false) => "\\>",
+        (false, true) => "\\b{start-half}",
+        (true, true) => "\\b{end-half}",
+    }
+}
 
 fn display_node(node: &Node, depth: usize, f: &mut fmt::Formatter) -> fmt::Result {
     for _ in 0..depth {
@@ -432,8 +848,9 @@ fn display_node(node: &Node, depth: usize, f: &mut fmt::Formatter) -> fmt::Resul
         Node::Goal => {
             writeln!(f, "Goal")?;
         }
-        Node::Char { c, icase: _ } => {
-            writeln!(f, "'{}'", &c.to_string())?;
+        &Node::Char { c, icase } => {
+            let suffix = if icase { " icase" } else { "" };
+            writeln!(f, "'{}'{}", &c.to_string(), suffix)?;
         }
         Node::ByteSequence(bytes) => {
             write!(f, "ByteSeq{} 0x", bytes.len())?;
@@ -472,8 +889,11 @@ fn display_node(node: &Node, depth: usize, f: &mut fmt::Formatter) -> fmt::Resul
         Node::MatchAnyExceptLineTerminator => {
             writeln!(f, "MatchAnyExceptLineTerminator")?;
         }
-        Node::Anchor(anchor_type) => {
-            writeln!(f, "Anchor {:?}", anchor_type)?;
+        Node::Anchor {
+            anchor_type,
+            multiline,
+        } => {
+            writeln!(f, "Anchor {:?} multiline: {}", anchor_type, multiline)?;
         }
         Node::Loop {
             quant,
@@ -491,12 +911,16 @@ fn display_node(node: &Node, depth: usize, f: &mut fmt::Formatter) -> fmt::Resul
         Node::NamedCaptureGroup(_node, _, name) => {
             writeln!(f, "NamedCaptureGroup {:?}", name)?;
         }
-        &Node::WordBoundary { invert } => {
+        &Node::WordBoundary { invert, unicode } => {
             let kind = if invert { "\\B" } else { "\\b" };
-            writeln!(f, "WordBoundary {:?} ", kind)?;
+            writeln!(f, "WordBoundary {:?} unicode: {}", kind, unicode)?;
         }
-        &Node::BackRef(group) => {
-            writeln!(f, "BackRef {:?} ", group)?;
+        &Node::WordEdge { end, half, unicode } => {
+            let kind = word_edge_escape(end, half);
+            writeln!(f, "WordEdge {:?} unicode: {}", kind, unicode)?;
+        }
+        &Node::BackRef { group, icase } => {
+            writeln!(f, "BackRef {:?} icase: {}", group, icase)?;
         }
         Node::Bracket(contents) => {
             writeln!(f, "Bracket {:?}", contents)?;
@@ -517,6 +941,13 @@ fn display_node(node: &Node, depth: usize, f: &mut fmt::Formatter) -> fmt::Resul
                 sense, direction, start_group, end_group
             )?;
         }
+        &Node::AtomicGroup {
+            start_group,
+            end_group,
+            ..
+        } => {
+            writeln!(f, "AtomicGroup {:?} {:?}", start_group, end_group)?;
+        }
         Node::UnicodePropertyEscape {
             property_escape,
             negate,
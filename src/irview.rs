@@ -0,0 +1,528 @@
+//! A public view of the IR, for tools which inspect patterns or build them
+//! programmatically.
+
+use crate::api::Flags;
+use crate::codepointset::{CodePointSet, CODE_POINT_MAX};
+use crate::ir::{self, AnchorType, Node, Quantifier};
+use crate::parse::{error, is_group_name, make_alt, make_cat, Error, DEFAULT_MAX_NESTING_DEPTH};
+use crate::types::{BracketContents, MAX_CAPTURE_GROUPS, MAX_LOOPS};
+use crate::unicode;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+/// A node of a parsed regex, as passed to an [`IrVisitor`].
+///
+/// Nodes are reported as they were parsed, before optimization, and in
+/// pattern order. The children of a node are visited between the calls to
+/// [`IrVisitor::enter`] and [`IrVisitor::exit`] for that node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IrNode<'a> {
+    /// Matches the empty string, for example an empty alternative.
+    Empty,
+
+    /// A literal character. `icase` is set if it matches case-insensitively.
+    Literal { c: u32, icase: bool },
+
+    /// The `.` character class. If `dot_all` is set, it also matches line
+    /// terminators.
+    Dot { dot_all: bool },
+
+    /// A character class: a bracket like `[a-z]`, an escape like `\d`, or a
+    /// Unicode property escape like `\p{L}`. `negated` is set for brackets
+    /// like `[^a]` and escapes like `\P{L}`; escapes like `\D` are instead
+    /// parsed as the complemented class, and so are not negated.
+    Class { negated: bool },
+
+    /// A sequence of nodes, each of which is a child.
+    Concatenation,
+
+    /// An alternation like `a|b|c`, whose children are the alternatives.
+    Alternation,
+
+    /// A capture group, whose child is its contents. `index` agrees with
+    /// [`crate::Match::group`], so the first group has index 1.
+    Group { index: usize, name: Option<&'a str> },
+
+    /// A quantified node like `a*` or `a{2,5}?`, whose child is the quantified
+    /// node. `max` is None if the repetition is unbounded.
+    Quantifier {
+        min: usize,
+        max: Option<usize>,
+        greedy: bool,
+    },
+
+    /// An atomic group like `(?>...)`, or the group implied by a possessive
+    /// quantifier like `a*+`, whose child is its contents.
+    Atomic,
+
+    /// A zero-width assertion like `^`, `$` or `\b`.
+    Assertion(IrAssertion),
+
+    /// A backreference like `\1` or `\k<name>`. `group` agrees with
+    /// [`crate::Match::group`].
+    BackRef { group: usize, icase: bool },
+
+    /// A lookaround like `(?=...)` or `(?<!...)`, whose child is its contents.
+    Lookaround { negated: bool, behind: bool },
+}
+
+/// The zero-width assertions of [`IrNode::Assertion`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IrAssertion {
+    /// `^`. If `multiline` is set, it also matches after line terminators.
+    Start { multiline: bool },
+
+    /// `$`. If `multiline` is set, it also matches before line terminators.
+    End { multiline: bool },
+
+    /// `\b`, or `\B` if `negated` is set.
+    WordBoundary { negated: bool },
+
+    /// `\<`, the start of a word, or `\>`, the end of a word, if `end` is
+    /// set. If `half` is set, it is `\b{start-half}` or `\b{end-half}`,
+    /// which check only the side of the position outside the word.
+    WordEdge { end: bool, half: bool },
+}
+
+/// An owned regex IR, which may be built programmatically and compiled by
+/// [`crate::Regex::from_ir`] without writing a pattern and parsing it.
+///
+/// Its nodes are those of [`IrNode`], in pattern order, with their children
+/// and character classes. The parser upholds some invariants of the IR, which
+/// `from_ir` instead checks, returning an Error if any is violated:
+///
+/// - capture groups are numbered from 1 in pattern order, that is, the `n`th
+///   group entered by a preorder walk has index `n`;
+/// - group names are unique, and are valid identifiers, as in `(?<name>...)`;
+/// - backreferences refer to groups which exist;
+/// - quantifiers have `min <= max`, and literals are at most
+///   [`CODE_POINT_MAX`] and are not surrogates, U+D800 to U+DFFF;
+/// - there are at most 65535 groups and 65535 quantifiers, and nodes are
+///   nested at most [`DEFAULT_MAX_NESTING_DEPTH`] deep.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Ir {
+    /// Matches the empty string.
+    Empty,
+
+    /// A literal character. If `icase` is set, it matches case-insensitively.
+    Literal { c: u32, icase: bool },
+
+    /// The `.` character class. If `dot_all` is set, it also matches line
+    /// terminators.
+    Dot { dot_all: bool },
+
+    /// A character class matching the code points of `set`, or those not in
+    /// it if `negated` is set. If `icase` is set, it also matches their other
+    /// case forms.
+    Class {
+        set: CodePointSet,
+        negated: bool,
+        icase: bool,
+    },
+
+    /// A sequence of nodes. An empty sequence matches the empty string.
+    Concatenation(Vec<Ir>),
+
+    /// An alternation, whose alternatives are tried in order. An empty
+    /// alternation matches nothing.
+    Alternation(Vec<Ir>),
+
+    /// A capture group. `index` agrees with [`crate::Match::group`].
+    Group {
+        index: usize,
+        name: Option<String>,
+        contents: Box<Ir>,
+    },
+
+    /// A quantified node. `max` is None if the repetition is unbounded.
+    Quantifier {
+        min: usize,
+        max: Option<usize>,
+        greedy: bool,
+        contents: Box<Ir>,
+    },
+
+    /// An atomic group, which discards its backtracking state once it has
+    /// matched.
+    Atomic(Box<Ir>),
+
+    /// A zero-width assertion. Word boundaries are Unicode-aware if the 'u' or
+    /// 'v' flag is set.
+    Assertion(IrAssertion),
+
+    /// A backreference. `group` agrees with [`crate::Match::group`].
+    BackRef { group: usize, icase: bool },
+
+    /// A lookaround, whose contents are in pattern order even if `behind` is
+    /// set.
+    Lookaround {
+        negated: bool,
+        behind: bool,
+        contents: Box<Ir>,
+    },
+}
+
+/// A callback interface for walking the IR of a regex; see
+/// [`crate::Regex::walk_ir`].
+pub trait IrVisitor {
+    /// Called before the children of \p node are visited.
+    fn enter(&mut self, node: &IrNode<'_>);
+
+    /// Called after the children of \p node are visited.
+    fn exit(&mut self, _node: &IrNode<'_>) {}
+}
+
+/// Walk the IR \p node of a parsed regex, calling \p visitor for each node.
+pub fn walk<V: IrVisitor>(node: &Node, visitor: &mut V) {
+    // The parser terminates the regex with a Goal, which is not interesting.
+    match node {
+        Node::Cat(nodes) if matches!(nodes.last(), Some(Node::Goal)) => {
+            let body = &nodes[..nodes.len() - 1];
+            match body {
+                [single] => visit(single, false, visitor),
+                _ => visit_cat(body, false, visitor),
+            }
+        }
+        _ => visit(node, false, visitor),
+    }
+}
+
+fn visit_cat<V: IrVisitor>(nodes: &[Node], in_lookbehind: bool, visitor: &mut V) {
+    let node = IrNode::Concatenation;
+    visitor.enter(&node);
+    // The parser reverses catenations in lookbehinds, as they are matched
+    // backwards. Report them in pattern order.
+    if in_lookbehind {
+        nodes
+            .iter()
+            .rev()
+            .for_each(|n| visit(n, in_lookbehind, visitor));
+    } else {
+        nodes.iter().for_each(|n| visit(n, in_lookbehind, visitor));
+    }
+    visitor.exit(&node);
+}
+
+fn visit<V: IrVisitor>(node: &Node, in_lookbehind: bool, visitor: &mut V) {
+    let leaf = |visitor: &mut V, n: IrNode| {
+        visitor.enter(&n);
+        visitor.exit(&n);
+    };
+    let parent = |visitor: &mut V, n: IrNode, child: &Node, in_lookbehind: bool| {
+        visitor.enter(&n);
+        visit(child, in_lookbehind, visitor);
+        visitor.exit(&n);
+    };
+    match node {
+        Node::Empty => leaf(visitor, IrNode::Empty),
+        Node::Goal => {}
+        &Node::Char { c, icase } => leaf(visitor, IrNode::Literal { c, icase }),
+        Node::Cat(nodes) => visit_cat(nodes, in_lookbehind, visitor),
+        Node::Alt(..) => {
+            // Alternations are nested as a balanced tree; flatten it, in order.
+            let n = IrNode::Alternation;
+            visitor.enter(&n);
+            let mut pending = vec![node];
+            while let Some(cursor) = pending.pop() {
+                match cursor {
+                    Node::Alt(left, right) => {
+                        pending.push(right);
+                        pending.push(left);
+                    }
+                    _ => visit(cursor, in_lookbehind, visitor),
+                }
+            }
+            visitor.exit(&n);
+        }
+        Node::MatchAny => leaf(visitor, IrNode::Dot { dot_all: true }),
+        Node::MatchAnyExceptLineTerminator => leaf(visitor, IrNode::Dot { dot_all: false }),
+        &Node::Anchor {
+            anchor_type,
+            multiline,
+        } => {
+            let assertion = match anchor_type {
+                AnchorType::StartOfLine => IrAssertion::Start { multiline },
+                AnchorType::EndOfLine => IrAssertion::End { multiline },
+            };
+            leaf(visitor, IrNode::Assertion(assertion))
+        }
+        &Node::WordBoundary { invert, .. } => leaf(
+            visitor,
+            IrNode::Assertion(IrAssertion::WordBoundary { negated: invert }),
+        ),
+        &Node::WordEdge { end, half, .. } => leaf(
+            visitor,
+            IrNode::Assertion(IrAssertion::WordEdge { end, half }),
+        ),
+        Node::CaptureGroup(contents, id) => parent(
+            visitor,
+            IrNode::Group {
+                index: *id as usize + 1,
+                name: None,
+            },
+            contents,
+            in_lookbehind,
+        ),
+        Node::NamedCaptureGroup(contents, id, name) => parent(
+            visitor,
+            IrNode::Group {
+                index: *id as usize + 1,
+                name: Some(name),
+            },
+            contents,
+            in_lookbehind,
+        ),
+        &Node::BackRef { group, icase } => leaf(
+            visitor,
+            IrNode::BackRef {
+                group: group as usize,
+                icase,
+            },
+        ),
+        Node::Bracket(bc) => leaf(visitor, IrNode::Class { negated: bc.invert }),
+        &Node::UnicodePropertyEscape { negate, .. } => {
+            leaf(visitor, IrNode::Class { negated: negate })
+        }
+        Node::LookaroundAssertion {
+            negate,
+            backwards,
+            contents,
+            ..
+        } => parent(
+            visitor,
+            IrNode::Lookaround {
+                negated: *negate,
+                behind: *backwards,
+            },
+            contents,
+            *backwards,
+        ),
+        Node::AtomicGroup { contents, .. } => {
+            parent(visitor, IrNode::Atomic, contents, in_lookbehind)
+        }
+        Node::Loop { loopee, quant, .. } => parent(
+            visitor,
+            IrNode::Quantifier {
+                min: quant.min,
+                max: if quant.max == usize::MAX {
+                    None
+                } else {
+                    Some(quant.max)
+                },
+                greedy: quant.greedy,
+            },
+            loopee,
+            in_lookbehind,
+        ),
+        Node::ByteSequence(..)
+        | Node::ByteSet(..)
+        | Node::CharSet(..)
+        | Node::Loop1CharBody { .. } => {
+            unreachable!("Node is only produced by the optimizer")
+        }
+    }
+}
+
+/// Lower \p ir to the IR of a regex with \p flags, checking the invariants
+/// documented on [`Ir`].
+pub(crate) fn lower(ir: &Ir, flags: Flags) -> Result<ir::Regex, Error> {
+    if flags.unicode && flags.unicode_sets {
+        return error("The 'u' and 'v' flags may not be combined");
+    }
+    let mut lowerer = Lowerer {
+        unicode: flags.unicode || flags.unicode_sets,
+        group_count: 0,
+        loop_count: 0,
+        names: Vec::new(),
+        max_backref: 0,
+        has_lookbehind: false,
+        depth: 0,
+    };
+    let body = lowerer.lower(ir)?;
+    if lowerer.max_backref > lowerer.group_count as usize {
+        return error(format!(
+            "Backreference to nonexistent group {}",
+            lowerer.max_backref
+        ));
+    }
+    lowerer.names.sort_unstable();
+    if lowerer.names.windows(2).any(|w| w[0] == w[1]) {
+        return error("Duplicate capture group name");
+    }
+    let mut node = make_cat(vec![body, Node::Goal]);
+    // As in the parser, lookbehinds are matched backwards.
+    if lowerer.has_lookbehind {
+        ir::walk_mut(false, &mut node, &mut Node::reverse_cats);
+    }
+    Ok(ir::Regex { node, flags })
+}
+
+struct Lowerer<'a> {
+    unicode: bool,
+    group_count: u16,
+    loop_count: usize,
+    names: Vec<&'a str>,
+    max_backref: usize,
+    has_lookbehind: bool,
+    depth: usize,
+}
+
+impl<'a> Lowerer<'a> {
+    /// Lower \p ir, a child of the node being lowered.
+    fn lower_child(&mut self, ir: &'a Ir) -> Result<Node, Error> {
+        if self.depth >= DEFAULT_MAX_NESTING_DEPTH {
+            return error("Nesting depth limit exceeded");
+        }
+        self.depth += 1;
+        let node = self.lower(ir);
+        self.depth -= 1;
+        node
+    }
+
+    fn lower_children(&mut self, irs: &'a [Ir]) -> Result<ir::NodeList, Error> {
+        irs.iter().map(|ir| self.lower_child(ir)).collect()
+    }
+
+    fn lower(&mut self, ir: &'a Ir) -> Result<Node, Error> {
+        Ok(match ir {
+            Ir::Empty => Node::Empty,
+            &Ir::Literal { c, icase } => {
+                if c > CODE_POINT_MAX || (0xD800..=0xDFFF).contains(&c) {
+                    return error(format!("Invalid code point {:#X}", c));
+                }
+                Node::Char {
+                    c: if icase { unicode::fold(c) } else { c },
+                    icase,
+                }
+            }
+            &Ir::Dot { dot_all: true } => Node::MatchAny,
+            &Ir::Dot { dot_all: false } => Node::MatchAnyExceptLineTerminator,
+            Ir::Class {
+                set,
+                negated,
+                icase,
+            } => Node::Bracket(BracketContents {
+                invert: *negated,
+                cps: if *icase {
+                    unicode::fold_code_points(set.clone())
+                } else {
+                    set.clone()
+                },
+            }),
+            Ir::Concatenation(irs) => make_cat(self.lower_children(irs)?),
+            // An empty bracket matches nothing.
+            Ir::Alternation(irs) if irs.is_empty() => Node::Bracket(BracketContents {
+                invert: false,
+                cps: CodePointSet::new(),
+            }),
+            Ir::Alternation(irs) => make_alt(self.lower_children(irs)?),
+            Ir::Group {
+                index,
+                name,
+                contents,
+            } => {
+                if self.group_count as usize >= MAX_CAPTURE_GROUPS {
+                    return error("Capture group count limit exceeded");
+                }
+                let id = self.group_count;
+                self.group_count += 1;
+                if *index != self.group_count as usize {
+                    return error(format!(
+                        "Capture group {} is out of order, expected {}",
+                        index, self.group_count
+                    ));
+                }
+                let contents = Box::new(self.lower_child(contents)?);
+                match name {
+                    None => Node::CaptureGroup(contents, id),
+                    Some(name) => {
+                        if !is_group_name(name) {
+                            return error(format!("Invalid capture group name '{}'", name));
+                        }
+                        self.names.push(name);
+                        Node::NamedCaptureGroup(contents, id, name.clone())
+                    }
+                }
+            }
+            &Ir::Quantifier {
+                min,
+                max,
+                greedy,
+                ref contents,
+            } => {
+                let max = max.unwrap_or(usize::MAX);
+                if min > max {
+                    return error("Invalid quantifier");
+                }
+                if self.loop_count >= MAX_LOOPS {
+                    return error("Loop count limit exceeded");
+                }
+                self.loop_count += 1;
+                let start_group = self.group_count;
+                let loopee = Box::new(self.lower_child(contents)?);
+                Node::Loop {
+                    loopee,
+                    quant: Quantifier { min, max, greedy },
+                    enclosed_groups: start_group..self.group_count,
+                }
+            }
+            Ir::Atomic(contents) => {
+                let start_group = self.group_count;
+                let contents = Box::new(self.lower_child(contents)?);
+                Node::AtomicGroup {
+                    start_group,
+                    end_group: self.group_count,
+                    contents,
+                }
+            }
+            &Ir::Assertion(assertion) => match assertion {
+                IrAssertion::Start { multiline } => Node::Anchor {
+                    anchor_type: AnchorType::StartOfLine,
+                    multiline,
+                },
+                IrAssertion::End { multiline } => Node::Anchor {
+                    anchor_type: AnchorType::EndOfLine,
+                    multiline,
+                },
+                IrAssertion::WordBoundary { negated } => Node::WordBoundary {
+                    invert: negated,
+                    unicode: self.unicode,
+                },
+                IrAssertion::WordEdge { end, half } => Node::WordEdge {
+                    end,
+                    half,
+                    unicode: self.unicode,
+                },
+            },
+            &Ir::BackRef { group, icase } => {
+                if group == 0 {
+                    return error("Backreference to nonexistent group 0");
+                }
+                self.max_backref = self.max_backref.max(group);
+                Node::BackRef {
+                    group: group.min(MAX_CAPTURE_GROUPS) as u32,
+                    icase,
+                }
+            }
+            &Ir::Lookaround {
+                negated,
+                behind,
+                ref contents,
+            } => {
+                self.has_lookbehind |= behind;
+                let start_group = self.group_count;
+                let contents = Box::new(self.lower_child(contents)?);
+                Node::LookaroundAssertion {
+                    negate: negated,
+                    backwards: behind,
+                    start_group,
+                    end_group: self.group_count,
+                    contents,
+                }
+            }
+        })
+    }
+}
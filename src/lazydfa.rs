@@ -0,0 +1,827 @@
+//! Lazy DFA regex execution engine
+//!
+//! This answers only whether a match exists, and supports only programs
+//! without backreferences, lookarounds or atomic groups, which are regular.
+//! The DFA is simulated over code points: each state is a set of NFA threads
+//! waiting on the next character, and transitions are computed on demand and
+//! cached. The cache is bounded, and is flushed when it grows too large, so
+//! memory use is limited regardless of the pattern or alphabet.
+//!
+//! Several programs may be run together by one DFA, whose threads record
+//! the program they belong to, to find which of them match in a single pass.
+
+use crate::bytesearch::{charset_contains, ByteSet};
+use crate::insn::{is_word_edge, CompiledRegex, Insn, LoopFields, StartPredicate};
+use crate::matchers::{CharProperties, CodePointCharProperties};
+use crate::unicode::is_character_class;
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+};
+#[cfg(not(feature = "std"))]
+use {
+    alloc::vec::Vec,
+    hashbrown::{HashMap, HashSet},
+};
+
+type CharProps = CodePointCharProperties;
+
+/// The maximum number of states which may be cached before the cache is
+/// flushed.
+const MAX_CACHED_STATES: usize = 2048;
+
+/// The maximum number of threads, summed over all cached states, before the
+/// cache is flushed.
+const MAX_CACHED_THREADS: usize = 1 << 16;
+
+/// Transition targets which are not states.
+const UNKNOWN: u32 = u32::MAX;
+const MATCH: u32 = u32::MAX - 1;
+const DEAD: u32 = u32::MAX - 2;
+
+// Bits describing the character before the current position, which are needed
+// to evaluate anchors and word boundaries.
+const AT_START: u8 = 1 << 0;
+const AFTER_LINE_TERMINATOR: u8 = 1 << 1;
+const AFTER_WORD_CHAR: u8 = 1 << 2;
+const AFTER_UNICODE_WORD_CHAR: u8 = 1 << 3;
+
+/// \return the context bits for a position following \p prev.
+pub fn context_after(prev: Option<u32>) -> u8 {
+    match prev {
+        None => AT_START,
+        Some(c) => {
+            let mut ctx = 0;
+            if CharProps::is_line_terminator(c) {
+                ctx |= AFTER_LINE_TERMINATOR;
+            }
+            if CharProps::is_word_char(c) {
+                ctx |= AFTER_WORD_CHAR;
+            }
+            if CharProps::is_unicode_word_char(c) {
+                ctx |= AFTER_UNICODE_WORD_CHAR;
+            }
+            ctx
+        }
+    }
+}
+
+/// \return whether the program \p cr may be run by this engine.
+pub fn is_regular(cr: &CompiledRegex) -> bool {
+    // The context of a position only records ASCII and Unicode word chars.
+    cr.custom_word_chars().is_none()
+        && !cr.insns.iter().any(|insn| {
+            matches!(
+                insn,
+                Insn::BackRef { .. }
+                    | Insn::Lookahead { .. }
+                    | Insn::Lookbehind { .. }
+                    | Insn::AtomicGroup { .. }
+            )
+        })
+}
+
+/// An NFA thread.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Thread {
+    /// Index of the program whose bytecode the thread runs.
+    program: u32,
+
+    /// Offset in the bytecode.
+    ip: u32,
+
+    /// For a Loop1CharBody, the number of iterations so far. For a byte
+    /// sequence, the number of bytes matched so far. Otherwise 0.
+    aux: usize,
+
+    /// The iteration count of each loop. These are zeroed on exiting a loop,
+    /// and saturate once they no longer affect matching, so that equivalent
+    /// threads compare equal.
+    iters: Vec<usize>,
+}
+
+/// \return \p count, saturated to the largest value which is distinguishable
+/// given the iteration bounds.
+pub fn saturate(count: usize, min_iters: usize, max_iters: usize) -> usize {
+    if max_iters == usize::MAX {
+        count.min(min_iters)
+    } else {
+        count
+    }
+}
+
+struct State {
+    /// The threads waiting on the next character, sorted and deduplicated.
+    kernel: Vec<Thread>,
+
+    /// Context bits for the character before this state.
+    ctx: u8,
+
+    /// Transitions for ASCII characters.
+    ascii: [u32; 128],
+
+    /// Transitions for other characters.
+    other: HashMap<u32, u32>,
+
+    /// The programs which matched on the transition into this state, when
+    /// running several programs. Their threads are not in the kernel.
+    matched: Vec<u32>,
+
+    /// The programs which match at the end of the input, once computed.
+    matches_at_end: Option<Vec<u32>>,
+
+    /// Whether the kernel is only the start threads.
+    start_only: bool,
+}
+
+/// What identifies a state: its kernel, context and matched programs.
+type StateKey = (Vec<Thread>, u8, Vec<u32>);
+
+/// The states of a lazy DFA and their transitions, which may be kept between
+/// searches of the same program so that later searches reuse them.
+pub struct DfaCache {
+    states: Vec<State>,
+    ids: HashMap<StateKey, u32>,
+    cached_threads: usize,
+
+    /// The state of only the start threads, for each context, or UNKNOWN.
+    start_ids: [u32; 16],
+}
+
+impl Default for DfaCache {
+    fn default() -> Self {
+        Self {
+            states: Vec::new(),
+            ids: HashMap::new(),
+            cached_threads: 0,
+            start_ids: [UNKNOWN; 16],
+        }
+    }
+}
+
+/// The most caches which a pool keeps once searches are done with them.
+#[cfg(feature = "std")]
+const MAX_POOLED_CACHES: usize = 4;
+
+/// The caches of the unanchored DFA of a program, for [`is_match`]. A search
+/// takes a cache from the pool, or makes a new one if the pool is empty, and
+/// returns it afterwards, so that concurrent searches each have their own.
+/// Without std there is no pool, and each search makes a new cache.
+#[derive(Default)]
+pub struct DfaCachePool {
+    #[cfg(feature = "std")]
+    caches: Mutex<Vec<DfaCache>>,
+}
+
+impl DfaCachePool {
+    /// \return a cache from the pool, or a new one.
+    fn take(&self) -> DfaCache {
+        #[cfg(feature = "std")]
+        if let Some(cache) = self.caches.lock().ok().and_then(|mut c| c.pop()) {
+            return cache;
+        }
+        DfaCache::default()
+    }
+
+    /// Return \p cache to the pool, if it has room.
+    fn put(&self, cache: DfaCache) {
+        #[cfg(feature = "std")]
+        if let Ok(mut caches) = self.caches.lock() {
+            if caches.len() < MAX_POOLED_CACHES {
+                caches.push(cache);
+            }
+        }
+        #[cfg(not(feature = "std"))]
+        drop(cache);
+    }
+}
+
+/// A cloned program starts with an empty pool, since it may be modified.
+impl Clone for DfaCachePool {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl core::fmt::Debug for DfaCachePool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("DfaCachePool")
+    }
+}
+
+struct LazyDFA<'r> {
+    /// The programs run together, which have the same flags. Reaching the
+    /// Goal of every one of them is a MATCH.
+    programs: &'r [&'r CompiledRegex],
+    cache: DfaCache,
+    flushes: usize,
+    /// The thread starting each program.
+    start_threads: Vec<Thread>,
+    sticky: bool,
+
+    /// If set, the cache is not flushed as states are added, and the caller
+    /// flushes it between steps with flush_keeping.
+    defer_flush: bool,
+}
+
+impl<'r> LazyDFA<'r> {
+    fn new(programs: &'r [&'r CompiledRegex], cache: DfaCache) -> Self {
+        let start_threads = programs
+            .iter()
+            .enumerate()
+            .map(|(idx, re)| Thread {
+                program: idx as u32,
+                ip: 0,
+                aux: 0,
+                iters: vec![0; re.loops as usize],
+            })
+            .collect();
+        Self {
+            programs,
+            cache,
+            flushes: 0,
+            start_threads,
+            sticky: programs[0].flags.sticky,
+            defer_flush: false,
+        }
+    }
+
+    /// \return whether the cache has grown large enough to be flushed.
+    fn over_capacity(&self) -> bool {
+        self.cache.states.len() >= MAX_CACHED_STATES
+            || self.cache.cached_threads >= MAX_CACHED_THREADS
+    }
+
+    fn flush(&mut self) {
+        self.cache.states.clear();
+        self.cache.ids.clear();
+        self.cache.cached_threads = 0;
+        self.cache.start_ids = [UNKNOWN; 16];
+        self.flushes += 1;
+    }
+
+    /// \return the id of the state of only the start threads, in context \p
+    /// ctx.
+    fn start_state(&mut self, ctx: u8) -> u32 {
+        let id = self.cache.start_ids[ctx as usize];
+        if id != UNKNOWN {
+            return id;
+        }
+        self.intern(self.start_threads.clone(), ctx, Vec::new())
+    }
+
+    /// Flush the cache, keeping the states \p ids, which are renumbered in
+    /// place.
+    fn flush_keeping(&mut self, ids: &mut [u32]) {
+        let kept: Vec<StateKey> = ids
+            .iter()
+            .map(|&id| {
+                let state = &self.cache.states[id as usize];
+                (state.kernel.clone(), state.ctx, state.matched.clone())
+            })
+            .collect();
+        self.flush();
+        for (id, (kernel, ctx, matched)) in ids.iter_mut().zip(kept) {
+            *id = self.intern(kernel, ctx, matched);
+        }
+    }
+
+    /// \return the id of the state for \p kernel, \p ctx and the sorted
+    /// programs \p matched, adding it if necessary.
+    fn intern(&mut self, kernel: Vec<Thread>, ctx: u8, matched: Vec<u32>) -> u32 {
+        let key = (kernel, ctx, matched);
+        if let Some(&id) = self.cache.ids.get(&key) {
+            return id;
+        }
+        if !self.defer_flush && self.over_capacity() {
+            self.flush();
+        }
+        let id = self.cache.states.len() as u32;
+        let start_only = key.0 == self.start_threads && key.2.is_empty();
+        if start_only {
+            self.cache.start_ids[ctx as usize] = id;
+        }
+        self.cache.cached_threads += key.0.len();
+        self.cache.states.push(State {
+            kernel: key.0.clone(),
+            ctx,
+            ascii: [UNKNOWN; 128],
+            other: HashMap::new(),
+            matched: key.2.clone(),
+            matches_at_end: None,
+            start_only,
+        });
+        self.cache.ids.insert(key, id);
+        id
+    }
+
+    /// Follow the non-consuming instructions from \p kernel, at a position
+    /// preceded by context \p ctx and followed by \p next, collecting the
+    /// threads which wait on a character into \p out, and the programs whose
+    /// Goal was reached into \p matched.
+    /// \return true if the Goal of every program was reached.
+    fn closure(
+        &self,
+        kernel: &[Thread],
+        ctx: u8,
+        next: Option<u32>,
+        out: &mut Vec<Thread>,
+        matched: &mut Vec<u32>,
+    ) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<Thread> = kernel.iter().rev().cloned().collect();
+        while let Some(mut t) = stack.pop() {
+            if !seen.insert(t.clone()) {
+                continue;
+            }
+            let insns = &self.programs[t.program as usize].insns;
+            let ip = t.ip as usize;
+            match &insns[ip] {
+                Insn::Goal => {
+                    if !matched.contains(&t.program) {
+                        matched.push(t.program);
+                        if matched.len() == self.programs.len() {
+                            return true;
+                        }
+                    }
+                }
+                Insn::JustFail => {}
+                &Insn::Jump { target } => {
+                    t.ip = target;
+                    stack.push(t);
+                }
+                &Insn::Alt { secondary } => {
+                    let mut t2 = t.clone();
+                    t2.ip = secondary;
+                    stack.push(t2);
+                    t.ip += 1;
+                    stack.push(t);
+                }
+                Insn::BeginCaptureGroup(..)
+                | Insn::EndCaptureGroup(..)
+                | Insn::ResetCaptureGroup(..) => {
+                    t.ip += 1;
+                    stack.push(t);
+                }
+                insn @ (Insn::StartOfLine { .. }
+                | Insn::EndOfLine { .. }
+                | Insn::WordBoundary { .. }
+                | Insn::WordEdge { .. }) => {
+                    if assertion_holds(insn, ctx, next) {
+                        t.ip += 1;
+                        stack.push(t);
+                    }
+                }
+                Insn::EnterLoop(fields) => {
+                    // Entering a loop, not re-entering it.
+                    t.iters[fields.loop_id as usize] = 0;
+                    Self::run_loop(t, ip, fields, &mut stack);
+                }
+                &Insn::LoopAgain { begin } => match &insns[begin as usize] {
+                    Insn::EnterLoop(fields) => {
+                        Self::run_loop(t, begin as usize, fields, &mut stack)
+                    }
+                    _ => panic!("LoopAgain does not point at EnterLoop"),
+                },
+                &Insn::Loop1CharBody {
+                    min_iters,
+                    max_iters,
+                    ..
+                } => {
+                    if t.aux >= min_iters {
+                        let mut exit = t.clone();
+                        exit.ip += 2;
+                        exit.aux = 0;
+                        stack.push(exit);
+                    }
+                    if t.aux < max_iters {
+                        out.push(t);
+                    }
+                }
+                _ => out.push(t),
+            }
+        }
+        false
+    }
+
+    /// Push the threads which enter and exit the loop beginning at \p begin.
+    fn run_loop(mut t: Thread, begin: usize, fields: &LoopFields, stack: &mut Vec<Thread>) {
+        let id = fields.loop_id as usize;
+        let iteration = t.iters[id];
+        if iteration >= fields.min_iters {
+            let mut exit = t.clone();
+            exit.ip = fields.exit;
+            exit.iters[id] = 0;
+            stack.push(exit);
+        }
+        if iteration < fields.max_iters {
+            t.ip = begin as u32 + 1;
+            t.iters[id] = saturate(iteration + 1, fields.min_iters, fields.max_iters);
+            stack.push(t);
+        }
+    }
+
+    /// \return the thread which follows \p t after consuming the character
+    /// \p c, if any.
+    fn step(&self, t: &Thread, c: u32) -> Option<Thread> {
+        let re = self.programs[t.program as usize];
+        let insn = &re.insns[t.ip as usize];
+        let mut next = t.clone();
+        if let &Insn::Loop1CharBody {
+            min_iters,
+            max_iters,
+            ..
+        } = insn
+        {
+            if !char_matches(re, &re.insns[t.ip as usize + 1], c) {
+                return None;
+            }
+            next.aux = saturate(t.aux + 1, min_iters, max_iters);
+        } else if let Some(bytes) = byte_seq(insn) {
+            let encoded = encode_utf8(c);
+            if !bytes[t.aux..].starts_with(encoded.as_slice()) {
+                return None;
+            }
+            next.aux += encoded.len();
+            if next.aux == bytes.len() {
+                next.ip += 1;
+                next.aux = 0;
+            }
+        } else {
+            if !char_matches(re, insn, c) {
+                return None;
+            }
+            next.ip += 1;
+        }
+        Some(next)
+    }
+
+    /// Compute the transition from the state \p id on the character \p c.
+    fn transition(&mut self, id: u32, c: u32) -> u32 {
+        let state = &self.cache.states[id as usize];
+        let cached = match state.ascii.get(c as usize) {
+            Some(&target) => target,
+            None => state.other.get(&c).copied().unwrap_or(UNKNOWN),
+        };
+        if cached != UNKNOWN {
+            return cached;
+        }
+
+        let mut waiting = Vec::new();
+        let mut matched = Vec::new();
+        let target = if self.closure(
+            &state.kernel,
+            state.ctx,
+            Some(c),
+            &mut waiting,
+            &mut matched,
+        ) {
+            MATCH
+        } else {
+            // The threads of the programs which matched have nothing left to
+            // find.
+            let mut kernel: Vec<Thread> = waiting
+                .iter()
+                .filter(|t| !matched.contains(&t.program))
+                .filter_map(|t| self.step(t, c))
+                .collect();
+            if !self.sticky {
+                kernel.extend(self.start_threads.iter().cloned());
+            }
+            kernel.sort_unstable();
+            kernel.dedup();
+            matched.sort_unstable();
+            if kernel.is_empty() && matched.is_empty() {
+                DEAD
+            } else {
+                let flushes = self.flushes;
+                let target = self.intern(kernel, context_after(Some(c)), matched);
+                if self.flushes != flushes {
+                    // The cache was flushed, so there is no source state in
+                    // which to record the transition.
+                    return target;
+                }
+                target
+            }
+        };
+
+        let state = &mut self.cache.states[id as usize];
+        match state.ascii.get_mut(c as usize) {
+            Some(slot) => *slot = target,
+            None => {
+                state.other.insert(c, target);
+            }
+        }
+        target
+    }
+
+    /// \return the programs which match at the end of the input, in the
+    /// state \p id.
+    fn matches_at_end(&mut self, id: u32) -> &[u32] {
+        let state = &self.cache.states[id as usize];
+        if state.matches_at_end.is_none() {
+            let mut matched = Vec::new();
+            self.closure(
+                &state.kernel,
+                state.ctx,
+                None,
+                &mut Vec::new(),
+                &mut matched,
+            );
+            self.cache.states[id as usize].matches_at_end = Some(matched);
+        }
+        self.cache.states[id as usize]
+            .matches_at_end
+            .as_deref()
+            .unwrap()
+    }
+}
+
+/// \return whether the single-character instruction \p insn of \p re matches
+/// \p c.
+pub fn char_matches(re: &CompiledRegex, insn: &Insn, c: u32) -> bool {
+    let b = c as u8;
+    match insn {
+        &Insn::Char(c2) => c == c2,
+        &Insn::CharICase(c2) => c == c2 || CharProps::fold(c) == c2,
+        Insn::MatchAny => true,
+        Insn::MatchAnyExceptLineTerminator => !CharProps::is_line_terminator(c),
+        &Insn::Bracket(idx) => CharProps::bracket(&re.brackets[idx], c),
+        Insn::AsciiBracket(bitmap) => c < 128 && bitmap.contains(b),
+        Insn::CharSet(chars) => charset_contains(chars, c),
+        Insn::ByteSet2(set) => c < 128 && set.0.contains(&b),
+        Insn::ByteSet3(set) => c < 128 && set.0.contains(&b),
+        Insn::ByteSet4(set) => c < 128 && set.0.contains(&b),
+        Insn::UnicodePropertyEscape {
+            property_escape,
+            negate,
+        } => is_character_class(c, property_escape) != *negate,
+        _ => match byte_seq(insn) {
+            Some(bytes) => encode_utf8(c).as_slice() == bytes,
+            None => false,
+        },
+    }
+}
+
+/// \return whether the assertion \p insn, which is an anchor or word
+/// boundary, holds at a position preceded by context \p ctx and followed by
+/// \p next.
+pub fn assertion_holds(insn: &Insn, ctx: u8, next: Option<u32>) -> bool {
+    match *insn {
+        Insn::StartOfLine { multiline } => {
+            ctx & AT_START != 0 || (multiline && ctx & AFTER_LINE_TERMINATOR != 0)
+        }
+        Insn::EndOfLine { multiline } => match next {
+            None => true,
+            Some(c) => multiline && CharProps::is_line_terminator(c),
+        },
+        Insn::WordBoundary { invert, unicode } => {
+            let (prev_bit, is_word_char): (u8, fn(u32) -> bool) = if unicode {
+                (AFTER_UNICODE_WORD_CHAR, CharProps::is_unicode_word_char)
+            } else {
+                (AFTER_WORD_CHAR, CharProps::is_word_char)
+            };
+            let prev_wordchar = ctx & prev_bit != 0;
+            let curr_wordchar = next.is_some_and(is_word_char);
+            (prev_wordchar != curr_wordchar) != invert
+        }
+        Insn::WordEdge { end, half, unicode } => {
+            let (prev_bit, is_word_char): (u8, fn(u32) -> bool) = if unicode {
+                (AFTER_UNICODE_WORD_CHAR, CharProps::is_unicode_word_char)
+            } else {
+                (AFTER_WORD_CHAR, CharProps::is_word_char)
+            };
+            let prev_wordchar = ctx & prev_bit != 0;
+            let curr_wordchar = next.is_some_and(is_word_char);
+            is_word_edge(end, half, prev_wordchar, curr_wordchar)
+        }
+        _ => panic!("Not an assertion"),
+    }
+}
+
+/// \return the bytes of \p insn if it is a byte sequence.
+pub fn byte_seq(insn: &Insn) -> Option<&[u8]> {
+    Some(match insn {
+        Insn::ByteSeq1(v) => v,
+        Insn::ByteSeq2(v) => v,
+        Insn::ByteSeq3(v) => v,
+        Insn::ByteSeq4(v) => v,
+        Insn::ByteSeq5(v) => v,
+        Insn::ByteSeq6(v) => v,
+        Insn::ByteSeq7(v) => v,
+        Insn::ByteSeq8(v) => v,
+        Insn::ByteSeq9(v) => v,
+        Insn::ByteSeq10(v) => v,
+        Insn::ByteSeq11(v) => v,
+        Insn::ByteSeq12(v) => v,
+        Insn::ByteSeq13(v) => v,
+        Insn::ByteSeq14(v) => v,
+        Insn::ByteSeq15(v) => v,
+        Insn::ByteSeq16(v) => v,
+        _ => return None,
+    })
+}
+
+/// A code point encoded as UTF-8.
+pub struct Utf8Char {
+    buf: [u8; 4],
+    len: usize,
+}
+
+impl Utf8Char {
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// \return the UTF-8 encoding of \p c, which is always a char since the input
+/// is a str.
+pub fn encode_utf8(c: u32) -> Utf8Char {
+    let mut buf = [0; 4];
+    let len = char::from_u32(c).map_or(0, |c| c.encode_utf8(&mut buf).len());
+    Utf8Char { buf, len }
+}
+
+/// \return whether the program \p re, which must be regular, matches \p text
+/// at or after the byte offset \p start.
+///
+/// The states are cached in the program's pool, so that they are computed
+/// once rather than by every search. Unless the regex is sticky, whenever
+/// the DFA is left with only the start thread, no match is in progress, so
+/// the start predicate may skip to the next position where one may begin.
+pub fn is_match(re: &CompiledRegex, text: &str, start: usize) -> bool {
+    debug_assert!(is_regular(re), "Program is not regular");
+    let programs = [re];
+    let mut dfa = LazyDFA::new(&programs, re.dfa_caches.take());
+    let result = run_unanchored(&mut dfa, text, start);
+    re.dfa_caches.put(dfa.cache);
+    result
+}
+
+/// \return for each of the programs \p res, which must be regular and have
+/// the same flags, whether it matches \p text, or just whether the first
+/// program found to match does if \p first_only is set.
+///
+/// The programs are run together by one DFA whose states are cached in \p
+/// caches, so the text is read once however many programs there are.
+pub fn set_matches(
+    res: &[&CompiledRegex],
+    caches: &DfaCachePool,
+    text: &str,
+    first_only: bool,
+) -> Vec<bool> {
+    debug_assert!(
+        res.iter().all(|re| is_regular(re)),
+        "Program is not regular"
+    );
+    let mut found = vec![false; res.len()];
+    if res.is_empty() {
+        return found;
+    }
+    let mut dfa = LazyDFA::new(res, caches.take());
+    let mut remaining = res.len();
+    let mut id = dfa.start_state(context_after(None));
+    let mut chars = text.chars();
+    loop {
+        let c = chars.next();
+        let matched = match c {
+            None => dfa.matches_at_end(id),
+            Some(c) => match dfa.transition(id, c.into()) {
+                MATCH => {
+                    found.fill(true);
+                    break;
+                }
+                DEAD => break,
+                next => {
+                    id = next;
+                    dfa.cache.states[id as usize].matched.as_slice()
+                }
+            },
+        };
+        for &idx in matched {
+            if !core::mem::replace(&mut found[idx as usize], true) {
+                remaining -= 1;
+            }
+        }
+        if c.is_none() || remaining == 0 || (first_only && remaining < res.len()) {
+            break;
+        }
+    }
+    caches.put(dfa.cache);
+    found
+}
+
+fn run_unanchored(dfa: &mut LazyDFA, text: &str, start: usize) -> bool {
+    let bytes = text.as_bytes();
+    let re = dfa.programs[0];
+    let prefilter = !dfa.sticky && !matches!(re.start_pred, StartPredicate::Arbitrary);
+    let ctx_at = |pos: usize| context_after(text[..pos].chars().next_back().map(u32::from));
+    let mut pos = start;
+    let mut id = dfa.start_state(ctx_at(pos));
+    loop {
+        if prefilter && dfa.cache.states[id as usize].start_only {
+            match re.start_pred.find_in(&bytes[pos..]) {
+                None => return false,
+                Some(0) => {}
+                Some(skip) => {
+                    pos += skip;
+                    id = dfa.start_state(ctx_at(pos));
+                }
+            }
+        }
+        let Some(c) = text[pos..].chars().next() else {
+            return !dfa.matches_at_end(id).is_empty();
+        };
+        pos += c.len_utf8();
+        match dfa.transition(id, c.into()) {
+            MATCH => return true,
+            DEAD => return false,
+            next => id = next,
+        }
+    }
+}
+
+/// \return for each code point of \p text, followed by the end of \p text,
+/// whether a match of the program \p re, which must be regular, begins there.
+///
+/// This is a single pass: a run of the anchored DFA is begun at each
+/// position, and since runs which reach the same state have the same future,
+/// they are merged, so the work at each character is bounded by the number of
+/// distinct states rather than the number of runs. The merged runs form a
+/// union-find forest over the start positions, and each root records whether
+/// its run matched.
+pub fn match_starts(re: &CompiledRegex, text: &str) -> Vec<bool> {
+    debug_assert!(is_regular(re), "Program is not regular");
+    // The anchored runs have states of their own, so they do not share the
+    // cache of is_match.
+    let programs = [re];
+    let mut dfa = LazyDFA::new(&programs, DfaCache::default());
+    dfa.sticky = true;
+    dfa.defer_flush = true;
+    let positions = text.chars().count() + 1;
+    let mut parent: Vec<usize> = Vec::with_capacity(positions);
+    let mut matched = vec![false; positions];
+    // The live runs, as their state and the root of their start positions.
+    let mut runs: Vec<(u32, usize)> = Vec::new();
+    let mut next_runs = Vec::new();
+    let mut by_state: HashMap<u32, usize> = HashMap::new();
+    let mut prev = None;
+    let mut chars = text.chars();
+    for pos in 0..positions {
+        parent.push(pos);
+        let start = dfa.intern(dfa.start_threads.clone(), context_after(prev), Vec::new());
+        runs.push((start, pos));
+        let Some(c) = chars.next().map(u32::from) else {
+            for &(id, root) in &runs {
+                matched[root] = !dfa.matches_at_end(id).is_empty();
+            }
+            break;
+        };
+        by_state.clear();
+        for &(id, root) in &runs {
+            match dfa.transition(id, c) {
+                MATCH => matched[root] = true,
+                DEAD => {}
+                target => match by_state.get(&target) {
+                    Some(&other) => parent[root] = other,
+                    None => {
+                        by_state.insert(target, root);
+                        next_runs.push((target, root));
+                    }
+                },
+            }
+        }
+        core::mem::swap(&mut runs, &mut next_runs);
+        next_runs.clear();
+        if dfa.over_capacity() {
+            let mut ids: Vec<u32> = runs.iter().map(|&(id, _)| id).collect();
+            dfa.flush_keeping(&mut ids);
+            for (run, id) in runs.iter_mut().zip(ids) {
+                run.0 = id;
+            }
+        }
+        prev = Some(c);
+    }
+
+    (0..positions)
+        .map(|pos| {
+            let mut root = pos;
+            while parent[root] != root {
+                root = parent[root];
+            }
+            // Compress the path so later lookups are short.
+            let mut node = pos;
+            while parent[node] != root {
+                let next = parent[node];
+                parent[node] = root;
+                node = next;
+            }
+            matched[root]
+        })
+        .collect()
+}
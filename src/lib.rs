@@ -49,6 +49,16 @@ println!("Year: {}", &text[group]);
 
 regress targets ES 2018 syntax. You can refer to the many resources about JavaScript regex syntax.
 
+Modifier groups like `(?i:...)` and `(?-i:...)` are also supported, as are toggles like `(?i)`, which apply until the end of the enclosing group. These may change the `i`, `m`, `s` and `x` flags.
+
+Atomic groups like `(?>...)` and possessive quantifiers like `a*+`, `a++` and `a?+` are also supported. Once these have matched, they do not give back characters to let the rest of the pattern match.
+
+The `x` flag enables extended mode, in which unescaped whitespace is ignored and `#` begins a comment, as in PCRE.
+
+The `v` flag is supported, including nested classes and the set operations `&&` and `--` within brackets. Brackets may also contain string literals like `\q{abc|def}`. With the `v` flag, the properties of strings from UTS #51 such as `\p{RGI_Emoji}` are also supported, which match whole emoji sequences like flags and ZWJ sequences; they may not be negated.
+
+In addition to the properties of ES 2018, property escapes support the `Age` property, for example `\p{Age=9.0}` for the characters assigned in Unicode 9.0, as in the UCD. The version of the Unicode data is `regress::UNICODE_VERSION`. A property escape may also be negated with `!=`, so `\p{Age!=9.0}` is `\P{Age=9.0}`.
+
 There are some features which have yet to be implemented:
 
 - Named character classes liks `[[:alpha:]]`
@@ -78,6 +88,14 @@ assert!(re.find("\u{0065}\u{0301}").is_none());
 
 This agrees with JavaScript semantics. Perform any required normalization before regex matching.
 
+Unlike JavaScript, with the `u` or `v` flags `\w`, `\W`, `\b` and `\B` use Unicode word characters: letters, marks, decimal numbers and connector punctuation. Without these flags, word characters are ASCII only, as in JavaScript.
+
+```rust
+use regress::Regex;
+assert_eq!(Regex::new(r"\w+").unwrap().find("café").unwrap().range(), 0..3);
+assert_eq!(Regex::with_flags(r"\w+", "u").unwrap().find("café").unwrap().range(), 0..5);
+```
+
 ## Ascii matching
 
 regress has an "ASCII mode" which treats each 8-bit quantity as a separate character.
@@ -102,11 +120,25 @@ to the architecture: regex uses finite automata while regress uses "classical ba
 
 fancy-regex wraps the regex crate and extends it with PCRE-style syntactic features. regress has more complete support for these features: backreferences may be case-insensitive, and lookbehinds may be arbitrary-width.
 
+# Crate features
+
+- `std` (default): use the standard library. Without it regress is `no_std` and requires only `core` and `alloc`.
+- `backend-pikevm` (default): include the PikeVM backend.
+- `index-positions`: prefer indexes to pointers for bytecode IP and string positions.
+- `ir-visitor`: expose the parsed IR of a regex through `Regex::walk_ir`, for tools like linters, and compile an IR built programmatically with `Regex::from_ir`.
+- `prohibit-unsafe`: prohibit all uses of unsafe code.
+- `rayon`: search the haystacks of `Regex::par_find_all` on the rayon thread pool. Without it they are searched on scoped threads, one run of haystacks per CPU.
+- `serde`: implement `Serialize` and `Deserialize` for `Regex`, which is serialized as its source and flags and compiled again when deserialized.
+- `simd`: skip over runs of ASCII text in loops like `.*` using SIMD instructions where available, currently SSE2 on x86_64. Otherwise, or with `prohibit-unsafe`, the runs are found a word at a time. Matches are the same either way.
+- `trace`: add `Regex::find_traced`, which reports each step of the backtracking engine to a callback. Without it the engine carries no tracing code.
+
+The features may be combined freely; for example `prohibit-unsafe` may be used without `std`. To check that the crate still builds without `std`, run `cargo check-no-std`, an alias for `cargo check --no-default-features` with the other features enabled.
+
 # Architecture
 
-regress has a parser, intermediate representation, optimizer which acts on the IR, bytecode emitter, and two bytecode interpreters, referred to as "backends".
+regress has a parser, intermediate representation, optimizer which acts on the IR, bytecode emitter, and four bytecode interpreters, referred to as "backends".
 
-The major interpreter is the "classical backtracking" which uses an explicit backtracking stack, similar to JS implementations. There is also the "PikeVM" pseudo-toy backend which is mainly used for testing and verification.
+The major interpreter is the "classical backtracking" which uses an explicit backtracking stack, similar to JS implementations. There is also the "PikeVM" pseudo-toy backend which is mainly used for testing and verification. Finally, `Regex::is_match` runs patterns without backreferences or lookarounds on a lazy DFA, which can report whether there is a match in linear time but cannot find its extent. `Regex::incremental` runs such patterns on an NFA simulation which keeps its threads in backtracking order, so that it finds the same matches in a text fed in chunks.
 */
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -132,15 +164,23 @@ mod codepointset;
 mod cursor;
 mod emit;
 mod exec;
+mod explain;
+mod incremental;
 mod indexing;
 mod insn;
 mod ir;
+#[cfg(feature = "ir-visitor")]
+mod irview;
+mod lazydfa;
 mod matchers;
 mod optimizer;
 mod parse;
 mod position;
 mod scm;
+mod serialize;
 mod startpredicate;
+#[cfg(feature = "trace")]
+mod trace;
 mod types;
 mod unicode;
 mod unicodetables;
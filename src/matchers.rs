@@ -1,7 +1,9 @@
+use crate::charclasses;
+use crate::codepointset::CodePointSet;
 use crate::cursor;
 use crate::cursor::Direction;
 use crate::indexing::{ElementType, InputIndexer};
-use crate::types::BracketContents;
+use crate::insn::CompiledBracket;
 use crate::unicode;
 
 pub trait CharProperties {
@@ -25,18 +27,38 @@ pub trait CharProperties {
             || c == '_' as u32
     }
 
+    /// \return whether this is a Unicode word char: a letter, mark, decimal
+    /// number or connector punctuation. This is used under the 'u' flag.
+    fn is_unicode_word_char(c: Self::Element) -> bool {
+        unicode::is_word_char(c.as_u32())
+    }
+
     /// ES9 11.3
     fn is_line_terminator(c: Self::Element) -> bool {
-        matches!(c.as_u32(), 0x000A | 0x000D | 0x2028 | 0x2029)
+        charclasses::is_line_terminator(c.as_u32())
+    }
+
+    /// \return whether this is a word char for the word boundary assertions:
+    /// one of \p custom if set, and otherwise a Unicode or ASCII word char as
+    /// \p unicode says.
+    #[inline(always)]
+    fn is_boundary_word_char(
+        custom: Option<&CodePointSet>,
+        unicode: bool,
+        c: Self::Element,
+    ) -> bool {
+        match custom {
+            Some(cps) => cps.contains(c.as_u32()),
+            None if unicode => Self::is_unicode_word_char(c),
+            None => Self::is_word_char(c),
+        }
     }
 
     /// \return whether the bracket \p bc matches the given character \p c,
     /// respecting case. Respects 'invert'.
     #[inline(always)]
-    fn bracket(bc: &BracketContents, c: Self::Element) -> bool {
-        let cp = c.into();
-        let contained = bc.cps.intervals().iter().any(|r| r.contains(cp));
-        contained ^ bc.invert
+    fn bracket(bc: &CompiledBracket, c: Self::Element) -> bool {
+        bc.contains(c.into())
     }
 }
 
@@ -50,6 +72,18 @@ impl CharProperties for UTF8CharProperties {
     }
 }
 
+/// CharProperties for code points which may not be chars, such as lone
+/// surrogates in UTF-16 text.
+pub struct CodePointCharProperties {}
+
+impl CharProperties for CodePointCharProperties {
+    type Element = u32;
+
+    fn fold(c: Self::Element) -> Self::Element {
+        unicode::fold(c)
+    }
+}
+
 pub struct ASCIICharProperties {}
 impl CharProperties for ASCIICharProperties {
     type Element = u8;
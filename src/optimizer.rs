@@ -2,14 +2,20 @@
 
 use crate::insn::{MAX_BYTE_SET_LENGTH, MAX_CHAR_SET_LENGTH};
 use crate::ir::*;
+use crate::parse::make_alt;
 use crate::types::BracketContents;
 use crate::unicode;
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 /// When unrolling a loop, the largest minimum count we will unroll.
 const LOOP_UNROLL_THRESHOLD: usize = 5;
 
+/// When unrolling a loop, the largest size we will produce, as measured by
+/// unrolled_size. This keeps nested loops like ((a{5}){5}){5} from growing
+/// exponentially.
+const LOOP_UNROLL_MAX_SIZE: usize = 64;
+
 /// Things that a Pass may do.
 pub enum PassAction {
     // Do nothing to the given node.
@@ -71,13 +77,18 @@ where
 
     fn run_to_fixpoint(&mut self, n: &mut Node) {
         debug_assert!(!self.changed, "Pass has already been run");
+        // Record whether any run changed something, not just the last one,
+        // which by definition did not.
+        let mut changed = false;
         loop {
             self.changed = false;
             self.run_postorder(n);
             if !self.changed {
                 break;
             }
+            changed = true;
         }
+        self.changed = changed;
     }
 }
 
@@ -154,7 +165,10 @@ fn remove_empties(n: &mut Node, _w: &Walk) -> PassAction {
             // Capture groups could in principle be optimized if they only match empties.
             PassAction::Keep
         }
-        Node::WordBoundary { .. } | Node::BackRef { .. } | Node::Bracket { .. } => PassAction::Keep,
+        Node::WordBoundary { .. }
+        | Node::WordEdge { .. }
+        | Node::BackRef { .. }
+        | Node::Bracket { .. } => PassAction::Keep,
         Node::LookaroundAssertion {
             negate, contents, ..
         } => {
@@ -166,6 +180,7 @@ fn remove_empties(n: &mut Node, _w: &Walk) -> PassAction {
                 PassAction::Keep
             }
         }
+        Node::AtomicGroup { .. } => PassAction::Keep,
         Node::UnicodePropertyEscape { .. } => PassAction::Keep,
     }
 }
@@ -205,6 +220,94 @@ fn decat(n: &mut Node, _w: &Walk) -> PassAction {
     }
 }
 
+/// \return the alternatives of the alternation \p n, in order.
+fn alternatives(n: &Node) -> Vec<&Node> {
+    let mut alts = Vec::new();
+    let mut pending = vec![n];
+    while let Some(cursor) = pending.pop() {
+        match cursor {
+            Node::Alt(left, right) => {
+                pending.push(right);
+                pending.push(left);
+            }
+            _ => alts.push(cursor),
+        }
+    }
+    alts
+}
+
+/// \return the alternatives of the alternation \p n, in order, without the
+/// empty ones after the first empty alternative, which can never be needed:
+/// they would continue from the same state as that one, and fail in the same
+/// way.
+fn into_needed_alternatives(n: Node) -> NodeList {
+    let mut alts = NodeList::new();
+    let mut seen_empty = false;
+    let mut pending = vec![n];
+    while let Some(cursor) = pending.pop() {
+        match cursor {
+            Node::Alt(left, right) => {
+                pending.push(*right);
+                pending.push(*left);
+            }
+            Node::Empty if seen_empty => {}
+            _ => {
+                seen_empty |= cursor.is_empty();
+                alts.push(cursor);
+            }
+        }
+    }
+    alts
+}
+
+// Remove constructs which do nothing: loops which run exactly once, classes
+// of a single char, and empty alternatives after the first.
+fn remove_redundancies(n: &mut Node, _w: &Walk) -> PassAction {
+    match n {
+        Node::Loop { loopee, quant, .. } | Node::Loop1CharBody { loopee, quant }
+            if quant.min == 1 && quant.max == 1 =>
+        {
+            // Enclosed groups need no reset, as only one iteration sets them.
+            PassAction::Replace(core::mem::replace(loopee.as_mut(), Node::Empty))
+        }
+        Node::Bracket(bc) if !bc.invert => match bc.cps.intervals() {
+            [iv] if iv.first == iv.last => PassAction::Replace(Node::Char {
+                c: iv.first,
+                icase: false,
+            }),
+            _ => PassAction::Keep,
+        },
+        Node::CharSet(chars) if chars.len() == 1 => PassAction::Replace(Node::Char {
+            c: chars[0],
+            icase: false,
+        }),
+        Node::ByteSet(bytes) if bytes.len() == 1 => PassAction::Replace(Node::Char {
+            c: u32::from(bytes[0]),
+            icase: false,
+        }),
+        Node::Alt(left, right) => {
+            // Rebuild the alternation if it has unneeded empty alternatives,
+            // or is not balanced, as when it was made from nested ones.
+            let mut alts = alternatives(left);
+            let left_len = alts.len();
+            alts.extend(alternatives(right));
+            let needed = match alts.iter().position(|alt| alt.is_empty()) {
+                Some(first) => !alts[first + 1..].iter().any(|alt| alt.is_empty()),
+                None => true,
+            };
+            if needed && left_len == alts.len() / 2 {
+                return PassAction::Keep;
+            }
+            let alts = into_needed_alternatives(core::mem::replace(n, Node::Empty));
+            match make_alt(alts) {
+                Node::Empty => PassAction::Remove,
+                alt => PassAction::Replace(alt),
+            }
+        }
+        _ => PassAction::Keep,
+    }
+}
+
 /// Unfold icase chars.
 /// That means for case-insensitive characters, figure out everything that they
 /// could match.
@@ -234,6 +337,20 @@ fn unfold_icase_chars(n: &mut Node, _w: &Walk) -> PassAction {
     }
 }
 
+/// \return the size of \p n for the purpose of unrolling: the number of
+/// nodes, where each byte of a byte sequence counts as a node, because
+/// literals are merged into sequences as loops are unrolled.
+fn unrolled_size(n: &Node) -> usize {
+    let mut size = 0;
+    walk(false, n, &mut |n, _| {
+        size += match n {
+            Node::ByteSequence(bytes) => bytes.len(),
+            _ => 1,
+        }
+    });
+    size
+}
+
 // Perform simple unrolling of loops that have a minimum.
 fn unroll_loops(n: &mut Node, _w: &Walk) -> PassAction {
     match n {
@@ -252,6 +369,9 @@ fn unroll_loops(n: &mut Node, _w: &Walk) -> PassAction {
             if quant.min == 0 || quant.min > LOOP_UNROLL_THRESHOLD {
                 return PassAction::Keep;
             }
+            if quant.min * unrolled_size(loopee) > LOOP_UNROLL_MAX_SIZE {
+                return PassAction::Keep;
+            }
 
             // We made it through. Replace us with a cat.
             let mut unrolled = Vec::new();
@@ -260,8 +380,10 @@ fn unroll_loops(n: &mut Node, _w: &Walk) -> PassAction {
             }
 
             // We unrolled 'min' elements.
-            // Maybe our loop is now empty.
-            quant.max -= quant.min;
+            // Maybe our loop is now empty. An unbounded loop stays unbounded.
+            if quant.max != usize::MAX {
+                quant.max -= quant.min;
+            }
             quant.min = 0;
             if quant.max > 0 {
                 // Move the loop to the end of unrolled.
@@ -426,14 +548,28 @@ fn simplify_brackets(n: &mut Node, _walk: &Walk) -> PassAction {
     }
 }
 
+/// Remove redundant constructs from \p r, such as nested concatenations,
+/// loops which run exactly once and classes of a single char, without the
+/// other transformations of optimize. Capture groups are kept.
+pub fn simplify(r: &mut Regex) {
+    loop {
+        let mut changed = false;
+        changed |= run_pass(r, &mut decat);
+        changed |= run_pass(r, &mut remove_redundancies);
+        changed |= run_pass(r, &mut remove_empties);
+        if !changed {
+            break;
+        }
+    }
+}
+
 pub fn optimize(r: &mut Regex) {
     run_pass(r, &mut simplify_brackets);
     loop {
         let mut changed = false;
         changed |= run_pass(r, &mut decat);
-        if r.flags.icase {
-            changed |= run_pass(r, &mut unfold_icase_chars);
-        }
+        changed |= run_pass(r, &mut remove_redundancies);
+        changed |= run_pass(r, &mut unfold_icase_chars);
         changed |= run_pass(r, &mut unroll_loops);
         changed |= run_pass(r, &mut promote_1char_loops);
         changed |= run_pass(r, &mut form_literal_bytes);
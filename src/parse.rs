@@ -2,14 +2,17 @@
 
 use crate::{
     api, charclasses,
-    codepointset::{CodePointSet, Interval},
+    codepointset::{CodePointSet, Interval, CODE_POINT_MAX},
     ir,
     types::{
         BracketContents, CaptureGroupID, CaptureGroupName, CharacterClassType, MAX_CAPTURE_GROUPS,
         MAX_LOOPS,
     },
     unicode::{self, unicode_property_value_from_str, PropertyEscape},
-    unicodetables::{is_id_continue, is_id_start},
+    unicodetables::{
+        is_id_continue, is_id_start, unicode_property_binary_from_str,
+        unicode_property_of_strings_from_str, UnicodePropertyOfStrings,
+    },
     util::to_char_sat,
 };
 use core::{fmt, iter::Peekable};
@@ -27,10 +30,41 @@ use {
 
 /// Represents an error encountered during regex compilation.
 ///
-/// The text contains a human-readable error message.
+/// The text contains a human-readable error message. Errors are made only by
+/// this crate, so the struct is non-exhaustive: it may gain fields.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub struct Error {
     pub text: String,
+
+    /// Byte offset into the pattern at which the error was detected.
+    offset: Option<usize>,
+}
+
+impl Error {
+    /// \return the byte offset into the pattern at which parsing failed, or
+    /// None if the error does not relate to a particular position (for
+    /// example, incompatible flags).
+    ///
+    /// Code points of a pattern passed to [`Regex::from_unicode`] which are
+    /// not valid chars count as U+FFFD, so the offset is into
+    /// [`Regex::as_str`].
+    ///
+    /// [`Regex::from_unicode`]: crate::Regex::from_unicode
+    /// [`Regex::as_str`]: crate::Regex::as_str
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// \return a copy of the error with the text transformed by \p f,
+    /// preserving the offset.
+    pub(crate) fn map_text<F: FnOnce(&str) -> String>(&self, f: F) -> Error {
+        Error {
+            text: f(&self.text),
+            offset: self.offset,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -48,18 +82,198 @@ enum ClassAtom {
         class_type: CharacterClassType,
         positive: bool,
     },
+    PropertyEscape {
+        property_escape: PropertyEscape,
+        negate: bool,
+    },
+}
+
+/// The contents of a 'v'-mode bracket, which may match strings as well as
+/// single code points.
+#[derive(Debug, Default)]
+struct ClassSet {
+    cps: CodePointSet,
+
+    /// Strings whose length is not one, sorted and deduplicated. Strings of
+    /// length one are stored in cps.
+    strings: Vec<Vec<u32>>,
+
+    /// ES2024 22.2.1.6 MayContainStrings. This is determined by the syntax,
+    /// so it may be set even if there are no strings.
+    may_contain_strings: bool,
+
+    /// If may_contain_strings is set, a description of the syntax which set
+    /// it, for error messages.
+    strings_source: Option<String>,
+}
+
+impl ClassSet {
+    fn from_code_points(cps: CodePointSet) -> Self {
+        Self {
+            cps,
+            ..Self::default()
+        }
+    }
+
+    /// \return the set of the sequences of \p strings, which may contain
+    /// strings whatever their lengths, as described by \p source.
+    fn from_strings(strings: impl Iterator<Item = Vec<u32>>, source: String) -> Self {
+        let mut set = Self {
+            may_contain_strings: true,
+            strings_source: Some(source),
+            ..Self::default()
+        };
+        for s in strings {
+            if s.len() == 1 {
+                set.cps.add_one(s[0]);
+            } else {
+                set.strings.push(s);
+            }
+        }
+        set.strings.sort_unstable();
+        set.strings.dedup();
+        set
+    }
+
+    fn add_string(&mut self, s: Vec<u32>) {
+        if s.len() == 1 {
+            self.cps.add_one(s[0]);
+            return;
+        }
+        if !self.may_contain_strings {
+            self.may_contain_strings = true;
+            self.strings_source = Some(if s.is_empty() {
+                String::from("the empty string of \\q{...}")
+            } else {
+                let s: String = s.iter().filter_map(|&c| char::from_u32(c)).collect();
+                format!("the string {:?} of \\q{{...}}", s)
+            });
+        }
+        self.strings.push(s);
+        self.strings.sort_unstable();
+        self.strings.dedup();
+    }
+
+    fn union(&mut self, rhs: ClassSet) {
+        self.cps.add_set(rhs.cps);
+        self.strings.extend(rhs.strings);
+        self.strings.sort_unstable();
+        self.strings.dedup();
+        if !self.may_contain_strings {
+            self.may_contain_strings = rhs.may_contain_strings;
+            self.strings_source = rhs.strings_source;
+        }
+    }
+
+    fn intersection(&mut self, rhs: &ClassSet) {
+        self.cps = self.cps.intersection(&rhs.cps);
+        self.strings
+            .retain(|s| rhs.strings.binary_search(s).is_ok());
+        self.may_contain_strings &= rhs.may_contain_strings;
+    }
+
+    fn difference(&mut self, rhs: &ClassSet) {
+        self.cps = self.cps.difference(&rhs.cps);
+        self.strings
+            .retain(|s| rhs.strings.binary_search(s).is_err());
+    }
 }
 
-fn error<S, T>(text: S) -> Result<T, Error>
+/// An operand of a set operation in a 'v'-mode bracket.
+enum ClassSetOperand {
+    CodePoint(u32),
+    Set(ClassSet),
+}
+
+/// \return whether \p c is a ClassSetSyntaxCharacter, which must be escaped
+/// in a 'v'-mode bracket.
+/// ES2024 22.2.1 ClassSetSyntaxCharacter.
+fn is_class_set_syntax_char(c: char) -> bool {
+    matches!(
+        c,
+        '(' | ')' | '[' | ']' | '{' | '}' | '/' | '-' | '\\' | '|'
+    )
+}
+
+/// \return whether \p c is a ClassSetReservedPunctuator. These may be
+/// escaped, and may not be doubled unescaped.
+/// ES2024 22.2.1 ClassSetReservedPunctuator.
+fn is_class_set_reserved_punctuator(c: char) -> bool {
+    matches!(
+        c,
+        '&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~'
+    )
+}
+
+/// \return whether a doubled \p c is a ClassSetReservedDoublePunctuator.
+fn is_class_set_reserved_double_punctuator(c: char) -> bool {
+    matches!(
+        c,
+        '&' | '!'
+            | '#'
+            | '$'
+            | '%'
+            | '*'
+            | '+'
+            | ','
+            | '.'
+            | ':'
+            | ';'
+            | '<'
+            | '='
+            | '>'
+            | '?'
+            | '@'
+            | '^'
+            | '`'
+            | '~'
+    )
+}
+
+pub(crate) fn error<S, T>(text: S) -> Result<T, Error>
 where
     S: ToString,
 {
     Err(Error {
         text: text.to_string(),
+        offset: None,
     })
 }
 
-fn make_cat(nodes: ir::NodeList) -> ir::Node {
+/// \return the length of the code points \p cps when encoded as UTF-8,
+/// counting invalid code points as U+FFFD.
+fn utf8_len<I: Iterator<Item = u32>>(cps: I) -> usize {
+    cps.map(|c| char::from_u32(c).map_or(3, char::len_utf8))
+        .sum()
+}
+
+/// \return whether \p c is whitespace which is ignored in extended mode.
+fn is_extended_whitespace(c: u32) -> bool {
+    matches!(
+        char::from_u32(c),
+        Some(' ' | '\t' | '\n' | '\x0B' | '\x0C' | '\r')
+    )
+}
+
+/// \return whether \p c may begin a RegExpIdentifierName, as of a group name.
+fn is_group_name_start(c: char) -> bool {
+    is_id_start(c) || c == '$' || c == '_'
+}
+
+/// \return whether \p c may continue a RegExpIdentifierName.
+fn is_group_name_continue(c: char) -> bool {
+    // U+200C and U+200D are ZWNJ and ZWJ.
+    is_id_continue(c) || c == '$' || c == '_' || c == '\u{200C}' || c == '\u{200D}'
+}
+
+/// \return whether \p name is a RegExpIdentifierName, as a group name must be.
+#[cfg(feature = "ir-visitor")]
+pub(crate) fn is_group_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(is_group_name_start) && chars.all(is_group_name_continue)
+}
+
+pub(crate) fn make_cat(nodes: ir::NodeList) -> ir::Node {
     match nodes.len() {
         0 => ir::Node::Empty,
         1 => nodes.into_iter().next().unwrap(),
@@ -67,15 +281,18 @@ fn make_cat(nodes: ir::NodeList) -> ir::Node {
     }
 }
 
-fn make_alt(nodes: ir::NodeList) -> ir::Node {
-    let mut mright = None;
-    for node in nodes.into_iter().rev() {
-        match mright {
-            None => mright = Some(node),
-            Some(right) => mright = Some(ir::Node::Alt(Box::new(node), Box::new(right))),
+/// \return an alternation of \p nodes, which are tried in order. It is nested
+/// as a balanced tree, so that a long list of alternatives, which the nesting
+/// limit does not count, does not make a deep one.
+pub(crate) fn make_alt(mut nodes: ir::NodeList) -> ir::Node {
+    match nodes.len() {
+        0 => ir::Node::Empty,
+        1 => nodes.pop().unwrap(),
+        len => {
+            let right = nodes.split_off(len / 2);
+            ir::Node::Alt(Box::new(make_alt(nodes)), Box::new(make_alt(right)))
         }
     }
-    mright.unwrap_or(ir::Node::Empty)
 }
 
 /// \return a CodePointSet for a given character escape (positive or negative).
@@ -89,6 +306,7 @@ fn codepoints_from_class(ct: CharacterClassType, positive: bool) -> CodePointSet
         CharacterClassType::Words => {
             cps = CodePointSet::from_sorted_disjoint_intervals(charclasses::WORD_CHARS.to_vec())
         }
+        CharacterClassType::UnicodeWords => cps = unicode::word_char_code_points(),
         CharacterClassType::Spaces => {
             cps = CodePointSet::from_sorted_disjoint_intervals(charclasses::WHITESPACE.to_vec());
             for &iv in charclasses::LINE_TERMINATOR.iter() {
@@ -119,6 +337,13 @@ fn add_class_atom(bc: &mut BracketContents, atom: ClassAtom) {
         } => {
             bc.cps.add_set(codepoints_from_class(class_type, positive));
         }
+        ClassAtom::PropertyEscape {
+            property_escape,
+            negate,
+        } => {
+            let cps = unicode::property_escape_code_points(&property_escape);
+            bc.cps.add_set(if negate { cps.inverted() } else { cps });
+        }
     }
 }
 
@@ -135,6 +360,9 @@ where
     /// The remaining input.
     input: Peekable<I>,
 
+    /// Length in bytes of the entire pattern, used to report error offsets.
+    pattern_len: usize,
+
     /// Flags used.
     flags: api::Flags,
 
@@ -156,6 +384,16 @@ where
 
     /// Whether a lookbehind was encountered.
     has_lookbehind: bool,
+
+    /// Number of groups and nested classes enclosing the current position.
+    depth: usize,
+
+    /// Limits on the pattern.
+    limits: Limits,
+
+    /// Whether the pattern is UTF-16 code units, to be matched against UTF-16
+    /// text, so that surrogate escapes are not paired without the 'u' flag.
+    code_units: bool,
 }
 
 impl<I> Parser<I>
@@ -188,6 +426,43 @@ where
         true
     }
 
+    /// In extended mode, skip any whitespace and comments.
+    fn skip_extended_whitespace(&mut self) {
+        if !self.flags.extended {
+            return;
+        }
+        while let Some(c) = self.peek() {
+            if is_extended_whitespace(c) {
+                self.next();
+            } else if c == u32::from('#') {
+                // Comments extend to the end of the line.
+                while let Some(c) = self.next() {
+                    if c == u32::from('\n') {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// \return whether the pattern is interpreted as Unicode, as with either
+    /// the 'u' or 'v' flags.
+    fn unicode(&self) -> bool {
+        self.flags.unicode || self.flags.unicode_sets
+    }
+
+    /// \return the class type for \w, which is Unicode-aware with either the
+    /// 'u' or 'v' flags.
+    fn word_class(&self) -> CharacterClassType {
+        if self.unicode() {
+            CharacterClassType::UnicodeWords
+        } else {
+            CharacterClassType::Words
+        }
+    }
+
     /// Peek at the next character.
     fn peek(&mut self) -> Option<u32> {
         self.input.peek().copied()
@@ -199,12 +474,16 @@ where
     }
 
     fn try_parse(&mut self) -> Result<ir::Regex, Error> {
-        self.parse_capture_groups()?;
+        if self.flags.unicode && self.flags.unicode_sets {
+            return error("The 'u' and 'v' flags may not be combined");
+        }
+        self.parse_capture_groups()
+            .map_err(|err| self.locate(err))?;
 
         // Parse a catenation. If we consume everything, it's success. If there's
         // something left, it's an error (for example, an excess closing paren).
-        let body = self.consume_disjunction()?;
-        match self.input.peek().copied() {
+        let body = self.consume_disjunction().map_err(|err| self.locate(err))?;
+        let unconsumed = match self.input.peek().copied() {
             Some(c) if c == ')' as u32 => error("Unbalanced parenthesis"),
             Some(c) => error(format!(
                 "Unexpected char: {}",
@@ -212,22 +491,56 @@ where
                     .map(String::from)
                     .unwrap_or_else(|| format!("\\u{c:04X}"))
             )),
-            None => self.finalize(ir::Regex {
-                node: make_cat(vec![body, ir::Node::Goal]),
-                flags: self.flags,
-            }),
+            None => Ok(()),
+        };
+        unconsumed.map_err(|err| self.locate(err))?;
+        self.finalize(ir::Regex {
+            node: make_cat(vec![body, ir::Node::Goal]),
+            flags: self.flags,
+        })
+    }
+
+    /// \return \p err with its offset set to the current position, if it
+    /// does not already have one.
+    fn locate(&self, mut err: Error) -> Error {
+        if err.offset.is_none() {
+            err.offset = Some(self.pattern_len - utf8_len(self.input.clone()));
         }
+        err
+    }
+
+    /// Enter a group or nested class, which the caller leaves by decrementing
+    /// the depth. Each level of nesting recurses, so this bounds the stack
+    /// used by parsing, and by the later phases which walk the IR.
+    fn enter_nested(&mut self) -> Result<(), Error> {
+        if self.depth >= self.limits.max_depth {
+            return error("Nesting depth limit exceeded");
+        }
+        self.depth += 1;
+        Ok(())
     }
 
     /// ES6 21.2.2.3 Disjunction.
     fn consume_disjunction(&mut self) -> Result<ir::Node, Error> {
+        // Inline modifiers like (?i) apply until the end of the enclosing group.
+        let saved_flags = self.flags;
         let mut terms = vec![self.consume_term()?];
         while self.try_consume('|') {
             terms.push(self.consume_term()?)
         }
+        self.flags = saved_flags;
         Ok(make_alt(terms))
     }
 
+    /// \return a Char node for \p c, folding it if case-insensitive.
+    fn make_char(&self, c: u32) -> ir::Node {
+        let icase = self.flags.icase;
+        ir::Node::Char {
+            c: if icase { unicode::fold(c) } else { c },
+            icase,
+        }
+    }
+
     /// ES6 21.2.2.5 Term.
     fn consume_term(&mut self) -> Result<ir::Node, Error> {
         let mut result: Vec<ir::Node> = Vec::new();
@@ -236,6 +549,7 @@ where
             let start_offset = result.len();
             let mut quantifier_allowed = true;
 
+            self.skip_extended_whitespace();
             let nc = self.peek();
             if nc.is_none() {
                 return Ok(make_cat(result));
@@ -246,13 +560,19 @@ where
                 ')' | '|' => break,
                 '^' => {
                     self.consume('^');
-                    result.push(ir::Node::Anchor(ir::AnchorType::StartOfLine));
+                    result.push(ir::Node::Anchor {
+                        anchor_type: ir::AnchorType::StartOfLine,
+                        multiline: self.flags.multiline,
+                    });
                     quantifier_allowed = false;
                 }
 
                 '$' => {
                     self.consume('$');
-                    result.push(ir::Node::Anchor(ir::AnchorType::EndOfLine));
+                    result.push(ir::Node::Anchor {
+                        anchor_type: ir::AnchorType::EndOfLine,
+                        multiline: self.flags.multiline,
+                    });
                     quantifier_allowed = false;
                 }
 
@@ -263,7 +583,7 @@ where
 
                 '.' => {
                     self.consume('.');
-                    result.push(if self.flags.dot_all {
+                    result.push(if self.flags.dot_all || self.flags.dot_matches_all {
                         ir::Node::MatchAny
                     } else {
                         ir::Node::MatchAnyExceptLineTerminator
@@ -271,16 +591,19 @@ where
                 }
 
                 '(' => {
+                    self.enter_nested()?;
                     if self.try_consume_str("(?=") {
                         // Positive lookahead.
-                        quantifier_allowed = false;
+                        // Annex B: outside of unicode mode, lookaheads may be
+                        // quantified.
+                        quantifier_allowed = !self.unicode();
                         result.push(self.consume_lookaround_assertion(LookaroundParams {
                             negate: false,
                             backwards: false,
                         })?);
                     } else if self.try_consume_str("(?!") {
                         // Negative lookahead.
-                        quantifier_allowed = false;
+                        quantifier_allowed = !self.unicode();
                         result.push(self.consume_lookaround_assertion(LookaroundParams {
                             negate: true,
                             backwards: false,
@@ -304,6 +627,30 @@ where
                     } else if self.try_consume_str("(?:") {
                         // Non-capturing group.
                         result.push(self.consume_disjunction()?);
+                    } else if self.try_consume_str("(?>") {
+                        // Atomic group.
+                        let contents = self.consume_disjunction()?;
+                        result.push(ir::Node::AtomicGroup {
+                            start_group,
+                            end_group: self.group_count,
+                            contents: Box::new(contents),
+                        });
+                    } else if let Some((modified, scoped)) = self.try_consume_modifiers()? {
+                        if scoped {
+                            // Modifier group like (?i:...).
+                            let saved_flags = self.flags;
+                            self.flags = modified;
+                            let contents = self.consume_disjunction();
+                            self.flags = saved_flags;
+                            result.push(contents?);
+                        } else {
+                            // Modifier toggle like (?i), which applies to the remainder of
+                            // the enclosing group. It may not be quantified; a following
+                            // quantifier is reported as having nothing to repeat.
+                            self.flags = modified;
+                            self.depth -= 1;
+                            continue;
+                        }
                     } else {
                         // Capturing group.
                         self.consume('(');
@@ -336,13 +683,18 @@ where
                     if !self.try_consume(')') {
                         return error("Unbalanced parenthesis");
                     }
+                    self.depth -= 1;
                 }
 
                 '[' => {
-                    result.push(self.consume_bracket()?);
+                    if self.flags.unicode_sets {
+                        result.push(self.consume_class_set()?);
+                    } else {
+                        result.push(self.consume_bracket()?);
+                    }
                 }
 
-                ']' if self.flags.unicode => {
+                ']' if self.unicode() => {
                     return error("Unbalanced bracket");
                 }
 
@@ -352,22 +704,18 @@ where
                     // Note this covers *, +, ? as well.
                     let saved = self.input.clone();
                     if self.try_consume_quantifier()?.is_some() {
+                        // Report the error at the start of the quantifier.
+                        self.input = saved;
                         return error("Nothing to repeat");
                     }
                     self.input = saved;
-                    let mut cc = c;
-                    self.consume(cc);
-                    if self.flags.icase {
-                        cc = unicode::fold(cc)
-                    }
-                    result.push(ir::Node::Char {
-                        c: cc,
-                        icase: self.flags.icase,
-                    })
+                    self.consume(c);
+                    result.push(self.make_char(c))
                 }
             }
 
             // We just parsed a term; try parsing a quantifier.
+            self.skip_extended_whitespace();
             if let Some(quant) = self.try_consume_quantifier()? {
                 if !quantifier_allowed {
                     return error("Quantifier not allowed here");
@@ -379,16 +727,33 @@ where
                 if quant.min > quant.max {
                     return error("Invalid quantifier");
                 }
+                let max_repetition = self.limits.max_repetition;
+                if quant.min > max_repetition
+                    || (quant.max != usize::MAX && quant.max > max_repetition)
+                {
+                    return error("Repetition count limit exceeded");
+                }
                 let quantifee = result.split_off(start_offset);
                 if self.loop_count as usize >= MAX_LOOPS {
                     return error("Loop count limit exceeded");
                 }
                 self.loop_count += 1;
-                result.push(ir::Node::Loop {
+                let greedy = quant.greedy;
+                let mut quantified = ir::Node::Loop {
                     loopee: Box::new(make_cat(quantifee)),
                     quant,
                     enclosed_groups: start_group..self.group_count,
-                });
+                };
+                // A trailing + makes a greedy quantifier possessive, which is
+                // the same as wrapping the loop in an atomic group.
+                if greedy && self.try_consume('+') {
+                    quantified = ir::Node::AtomicGroup {
+                        start_group,
+                        end_group: self.group_count,
+                        contents: Box::new(quantified),
+                    };
+                }
+                result.push(quantified);
             }
         }
         Ok(make_cat(result))
@@ -451,7 +816,7 @@ where
                 continue;
             }
 
-            if self.flags.unicode {
+            if self.unicode() {
                 return error("Invalid character range");
             }
 
@@ -487,7 +852,7 @@ where
                         let class_type = match to_char_sat(ec) {
                             'd' | 'D' => CharacterClassType::Digits,
                             's' | 'S' => CharacterClassType::Spaces,
-                            'w' | 'W' => CharacterClassType::Words,
+                            'w' | 'W' => self.word_class(),
                             _ => panic!("Unreachable"),
                         };
                         Ok(Some(ClassAtom::CharacterClass {
@@ -502,6 +867,23 @@ where
                         Ok(Some(ClassAtom::CodePoint(u32::from('\x08'))))
                     }
 
+                    'c' if !self.unicode() => {
+                        // Annex B ClassControlLetter: outside of unicode mode,
+                        // brackets also accept \c followed by a digit or _.
+                        let orig_input = self.input.clone();
+                        self.consume('c');
+                        match self.next().and_then(char::from_u32) {
+                            Some(nc) if nc.is_ascii_digit() || nc == '_' => {
+                                Ok(Some(ClassAtom::CodePoint((nc as u32) % 32)))
+                            }
+                            _ => {
+                                self.input = orig_input;
+                                let cc = self.consume_character_escape()?;
+                                Ok(Some(ClassAtom::CodePoint(cc)))
+                            }
+                        }
+                    }
+
                     '-' => {
                         // ES6 21.2.1 ClassEscape: \- escapes - in Unicode
                         // expressions.
@@ -509,18 +891,19 @@ where
                         Ok(Some(ClassAtom::CodePoint(u32::from('-'))))
                     }
 
-                    // TODO: implement property escape in brackets
-                    //                    'p' | 'P' => {
-                    //                        self.consume(ec);
-                    //
-                    //                        let property_escape = self.try_consume_unicode_property_escape()?;
-                    //                        let negate = ec == 'P';
-                    //
-                    //                        Ok(Some(ClassAtom::UnicodePropertyEscape {
-                    //                            property_escape,
-                    //                            negate,
-                    //                        }))
-                    //                    }
+                    // Property escapes in brackets are only supported in Unicode mode; otherwise
+                    // \p is an identity escape.
+                    'p' | 'P' if self.unicode() => {
+                        self.consume(ec);
+                        if self.try_consume_property_of_strings().is_some() {
+                            return error("Property of strings requires the v flag");
+                        }
+                        let (property_escape, ne) = self.try_consume_unicode_property_escape()?;
+                        Ok(Some(ClassAtom::PropertyEscape {
+                            property_escape,
+                            negate: (ec == 'P' as u32) != ne,
+                        }))
+                    }
                     _ => {
                         let cc = self.consume_character_escape()?;
                         Ok(Some(ClassAtom::CodePoint(cc)))
@@ -532,6 +915,294 @@ where
         }
     }
 
+    /// ES2024 22.2.1 CharacterClass, with the 'v' flag.
+    /// Strings are matched longest first, then single code points, and
+    /// finally the empty string.
+    fn consume_class_set(&mut self) -> Result<ir::Node, Error> {
+        let set = self.consume_class_set_nested()?;
+        Ok(self.make_class_set(set))
+    }
+
+    /// \return the node matching the strings and code points of \p set, as
+    /// consume_class_set does.
+    fn make_class_set(&self, set: ClassSet) -> ir::Node {
+        let ClassSet {
+            mut cps,
+            mut strings,
+            ..
+        } = set;
+        if self.flags.icase {
+            cps = unicode::fold_code_points(cps);
+        }
+        let bracket = ir::Node::Bracket(BracketContents { invert: false, cps });
+        if strings.is_empty() {
+            return bracket;
+        }
+        strings.sort_by_key(|s| core::cmp::Reverse(s.len()));
+        let mut alts: ir::NodeList = Vec::new();
+        let mut has_empty = false;
+        for s in strings {
+            if s.is_empty() {
+                has_empty = true;
+            } else {
+                alts.push(make_cat(s.into_iter().map(|c| self.make_char(c)).collect()));
+            }
+        }
+        match &bracket {
+            ir::Node::Bracket(bc) if bc.cps.intervals().is_empty() => {}
+            _ => alts.push(bracket),
+        }
+        if has_empty {
+            alts.push(ir::Node::Empty);
+        }
+        make_alt(alts)
+    }
+
+    /// ES2024 22.2.1 NestedClass, beginning with '['.
+    fn consume_class_set_nested(&mut self) -> Result<ClassSet, Error> {
+        self.enter_nested()?;
+        self.consume('[');
+        let invert = self.try_consume('^');
+        let set = self.consume_class_set_expression()?;
+        if !self.try_consume(']') {
+            return error("Unbalanced bracket");
+        }
+        self.depth -= 1;
+        if !invert {
+            return Ok(set);
+        }
+        if set.may_contain_strings {
+            return error(format!(
+                "Negated character class may contain strings: {}",
+                set.strings_source.unwrap_or_default()
+            ));
+        }
+        Ok(ClassSet::from_code_points(
+            self.character_complement(&set.cps),
+        ))
+    }
+
+    /// ES2024 22.2.1 ClassSetExpression.
+    /// Union, intersection and subtraction may not be mixed without nesting,
+    /// so there is no precedence to resolve.
+    fn consume_class_set_expression(&mut self) -> Result<ClassSet, Error> {
+        if self.peek() == Some(u32::from(']')) {
+            return Ok(ClassSet::default());
+        }
+        let first = self.consume_class_set_operand()?;
+
+        // ClassIntersection and ClassSubtraction.
+        for (op, is_intersection) in [("&&", true), ("--", false)] {
+            if !self.try_consume_str(op) {
+                continue;
+            }
+            let mut result = self.class_set_of(first);
+            loop {
+                if self.peek() == Some(u32::from('&')) {
+                    return error("Invalid set operation in character class");
+                }
+                let rhs = self.consume_class_set_operand()?;
+                let rhs = self.class_set_of(rhs);
+                if is_intersection {
+                    result.intersection(&rhs)
+                } else {
+                    result.difference(&rhs)
+                }
+                if self.try_consume_str(op) {
+                    continue;
+                }
+                if self.peek() == Some(u32::from(']')) {
+                    return Ok(result);
+                }
+                return error("Invalid set operation in character class");
+            }
+        }
+
+        // ClassUnion.
+        let mut result = ClassSet::default();
+        let mut operand = first;
+        loop {
+            match operand {
+                ClassSetOperand::CodePoint(c1) if self.try_consume_class_set_range_dash() => {
+                    let ClassSetOperand::CodePoint(c2) = self.consume_class_set_operand()? else {
+                        return error("Invalid character range");
+                    };
+                    if c1 > c2 {
+                        return error(
+                            "Range values reversed, start char code is greater than end char code.",
+                        );
+                    }
+                    let mut range = CodePointSet::new();
+                    range.add(Interval {
+                        first: c1,
+                        last: c2,
+                    });
+                    result.cps.add_set(self.maybe_simple_case_folding(range));
+                }
+                ClassSetOperand::Set(..) if self.try_consume_class_set_range_dash() => {
+                    return error("Invalid character range");
+                }
+                _ => result.union(self.class_set_of(operand)),
+            }
+            if self.peek() == Some(u32::from(']')) {
+                return Ok(result);
+            }
+            if self.try_consume_str("&&") || self.try_consume_str("--") {
+                return error("Invalid set operation in character class");
+            }
+            operand = self.consume_class_set_operand()?;
+        }
+    }
+
+    /// Consume a single '-' indicating a range, but not '--'.
+    fn try_consume_class_set_range_dash(&mut self) -> bool {
+        let mut cursor = self.input.clone();
+        if cursor.next() != Some(u32::from('-')) || cursor.peek() == Some(&u32::from('-')) {
+            return false;
+        }
+        self.input = cursor;
+        true
+    }
+
+    /// ES2024 22.2.1 ClassSetOperand, or a ClassSetCharacter that may begin a
+    /// range.
+    fn consume_class_set_operand(&mut self) -> Result<ClassSetOperand, Error> {
+        let Some(c) = self.peek().map(to_char_sat) else {
+            return error("Unbalanced bracket");
+        };
+        match c {
+            '[' => Ok(ClassSetOperand::Set(self.consume_class_set_nested()?)),
+            '\\' => {
+                self.consume('\\');
+                let Some(ec) = self.peek().map(to_char_sat) else {
+                    return error("Incomplete escape");
+                };
+                match ec {
+                    'd' | 'D' | 's' | 'S' | 'w' | 'W' => {
+                        self.consume(ec);
+                        let class_type = match ec {
+                            'd' | 'D' => CharacterClassType::Digits,
+                            's' | 'S' => CharacterClassType::Spaces,
+                            _ => self.word_class(),
+                        };
+                        let cps =
+                            self.maybe_simple_case_folding(codepoints_from_class(class_type, true));
+                        Ok(ClassSetOperand::Set(ClassSet::from_code_points(
+                            if ec.is_ascii_lowercase() {
+                                cps
+                            } else {
+                                self.character_complement(&cps)
+                            },
+                        )))
+                    }
+                    'p' | 'P' => {
+                        self.consume(ec);
+                        if let Some((value, name)) = self.try_consume_property_of_strings() {
+                            if ec == 'P' {
+                                return error(format!(
+                                    "Negated property of strings \\P{{{}}}",
+                                    name
+                                ));
+                            }
+                            return Ok(ClassSetOperand::Set(
+                                self.property_of_strings_set(value, name),
+                            ));
+                        }
+                        let (property_escape, ne) = self.try_consume_unicode_property_escape()?;
+                        Ok(ClassSetOperand::Set(ClassSet::from_code_points(
+                            self.property_class_set_code_points(
+                                &property_escape,
+                                (ec == 'P') != ne,
+                            ),
+                        )))
+                    }
+                    'q' => {
+                        self.consume('q');
+                        Ok(ClassSetOperand::Set(
+                            self.consume_class_string_disjunction()?,
+                        ))
+                    }
+                    _ => Ok(ClassSetOperand::CodePoint(
+                        self.consume_class_set_character_escape(ec)?,
+                    )),
+                }
+            }
+            _ => Ok(ClassSetOperand::CodePoint(
+                self.consume_class_set_character()?,
+            )),
+        }
+    }
+
+    /// ES2024 22.2.1 ClassStringDisjunction, following '\q'.
+    fn consume_class_string_disjunction(&mut self) -> Result<ClassSet, Error> {
+        if !self.try_consume('{') {
+            return error("Invalid escape");
+        }
+        let mut result = ClassSet::default();
+        loop {
+            let mut s = Vec::new();
+            loop {
+                match self.peek().map(to_char_sat) {
+                    None => return error("Unterminated class string disjunction"),
+                    Some('|' | '}') => break,
+                    Some(_) => {
+                        let c = self.consume_class_set_character()?;
+                        s.push(if self.flags.icase {
+                            unicode::fold(c)
+                        } else {
+                            c
+                        });
+                    }
+                }
+            }
+            result.add_string(s);
+            if self.try_consume('}') {
+                return Ok(result);
+            }
+            self.consume('|');
+        }
+    }
+
+    /// ES2024 22.2.1 ClassSetCharacter.
+    fn consume_class_set_character(&mut self) -> Result<u32, Error> {
+        let Some(c) = self.peek().map(to_char_sat) else {
+            return error("Unbalanced bracket");
+        };
+        match c {
+            '\\' => {
+                self.consume('\\');
+                let Some(ec) = self.peek().map(to_char_sat) else {
+                    return error("Incomplete escape");
+                };
+                self.consume_class_set_character_escape(ec)
+            }
+            c if is_class_set_syntax_char(c) => error(format!(
+                "Character '{}' must be escaped in a character class",
+                c
+            )),
+            c => {
+                let cp = self.consume(c);
+                if is_class_set_reserved_double_punctuator(c) && self.peek() == Some(cp) {
+                    return error("Invalid set operation in character class");
+                }
+                Ok(cp)
+            }
+        }
+    }
+
+    /// Consume the escape \p ec, following a backslash, in a
+    /// ClassSetCharacter.
+    fn consume_class_set_character_escape(&mut self, ec: char) -> Result<u32, Error> {
+        match ec {
+            'b' => {
+                self.consume('b');
+                Ok(u32::from('\x08'))
+            }
+            c if is_class_set_reserved_punctuator(c) => Ok(self.consume(c)),
+            _ => self.consume_character_escape(),
+        }
+    }
+
     fn try_consume_quantifier(&mut self) -> Result<Option<ir::Quantifier>, Error> {
         if let Some(mut quant) = self.try_consume_quantifier_prefix()? {
             quant.greedy = !self.try_consume('?');
@@ -552,7 +1223,7 @@ where
                 self.consume('+');
                 Ok(Some(ir::Quantifier {
                     min: 1,
-                    max: core::usize::MAX,
+                    max: usize::MAX,
                     greedy: true,
                 }))
             }
@@ -560,7 +1231,7 @@ where
                 self.consume('*');
                 Ok(Some(ir::Quantifier {
                     min: 0,
-                    max: core::usize::MAX,
+                    max: usize::MAX,
                     greedy: true,
                 }))
             }
@@ -575,7 +1246,7 @@ where
             Some('{') => {
                 if let Some(quantifier) = self.try_consume_braced_quantifier() {
                     Ok(Some(quantifier))
-                } else if self.flags.unicode {
+                } else if self.unicode() {
                     // if there was a brace '{' that doesn't parse into a valid quantifier,
                     // it's not valid with the unicode flag
                     error("Invalid quantifier")
@@ -608,7 +1279,7 @@ where
                 quant.max = max;
             } else {
                 // Like {3,}
-                quant.max = usize::max_value();
+                quant.max = usize::MAX;
             }
         } else {
             // Like {3}.
@@ -685,13 +1356,31 @@ where
             }
             'c' => {
                 // Control escape.
+                let orig_input = self.input.clone();
                 self.consume('c');
                 if let Some(nc) = self.next().and_then(char::from_u32) {
                     if nc.is_ascii_lowercase() || nc.is_ascii_uppercase() {
                         return Ok((nc as u32) % 32);
                     }
                 }
-                error("Invalid character escape")
+                if self.unicode() {
+                    return error("Invalid character escape");
+                }
+                // Annex B: outside of unicode mode, \c not followed by a
+                // letter is a literal backslash, and the c is then parsed as
+                // an ordinary character.
+                self.input = orig_input;
+                Ok('\\' as u32)
+            }
+            '0'..='7' if !self.unicode() => {
+                // Annex B: outside of unicode mode, digits begin a legacy
+                // octal escape.
+                Ok(self.consume_legacy_octal_escape())
+            }
+            '8' | '9' if !self.unicode() => {
+                // Annex B: outside of unicode mode, these are identity
+                // escapes.
+                Ok(self.consume(c))
             }
             '0' => {
                 // CharacterEscape :: "0 [lookahead != DecimalDigit]"
@@ -707,11 +1396,18 @@ where
                 // See ES6 11.8.3 HexDigit
                 let hex_to_digit = |c: char| c.to_digit(16);
                 self.consume('x');
+                let orig_input = self.input.clone();
                 let x1 = self.next().and_then(char::from_u32).and_then(hex_to_digit);
                 let x2 = self.next().and_then(char::from_u32).and_then(hex_to_digit);
                 match (x1, x2) {
                     (Some(x1), Some(x2)) => Ok(x1 * 16 + x2),
-                    _ => error("Invalid character escape"),
+                    _ if self.unicode() => error("Invalid character escape"),
+                    _ => {
+                        // Annex B: outside of unicode mode, \x not followed by
+                        // two hex digits is an identity escape.
+                        self.input = orig_input;
+                        Ok('x' as u32)
+                    }
                 }
             }
 
@@ -720,8 +1416,12 @@ where
                 self.consume('u');
                 if let Some(c) = self.try_escape_unicode_sequence() {
                     Ok(c)
-                } else {
+                } else if self.unicode() {
                     error("Invalid unicode escape")
+                } else {
+                    // Annex B: outside of unicode mode, a malformed \u is an
+                    // identity escape.
+                    Ok('u' as u32)
                 }
             }
 
@@ -729,12 +1429,21 @@ where
             '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
             | '/' => Ok(self.consume(c)),
 
+            // Annex B: in a pattern with named groups, `\k` is not an identity
+            // escape, even within a class.
+            'k' if !self.named_group_indices.is_empty() => error("Invalid character escape"),
+
+            // In extended mode, whitespace and # may be escaped to match literally.
+            c if self.flags.extended && (c == '#' || is_extended_whitespace(c as u32)) => {
+                Ok(self.consume(c))
+            }
+
             // TODO: currently we reject numeric characters in IdentityEscape to help some PCRE
             // tests pass.
             // Specifically a regex of the form [\p{Nd}]: in non-Unicode mode this is not a
             // character property test and is expected to parse as just a bracket where \p is
             // IdentityEscaped to p.
-            c if (!self.flags.unicode && !c.is_ascii_digit()) || c.is_ascii_alphabetic() => {
+            c if (!self.unicode() && !c.is_ascii_digit()) || c.is_ascii_alphabetic() => {
                 Ok(self.consume(c))
             }
 
@@ -742,6 +1451,27 @@ where
         }
     }
 
+    /// Consume an Annex B LegacyOctalEscapeSequence like `\0` or `\377`,
+    /// following the backslash. The next character must be an octal digit.
+    /// \return the escaped code point.
+    fn consume_legacy_octal_escape(&mut self) -> u32 {
+        let octal_digit = |c: Option<u32>| c.and_then(char::from_u32).and_then(|c| c.to_digit(8));
+        let first = octal_digit(self.next()).expect("Should have an octal digit");
+        // A third digit is only taken if the value still fits in a byte.
+        let max_len = if first <= 3 { 3 } else { 2 };
+        let mut value = first;
+        for _ in 1..max_len {
+            match octal_digit(self.peek()) {
+                Some(digit) => {
+                    self.next();
+                    value = value * 8 + digit;
+                }
+                None => break,
+            }
+        }
+        value
+    }
+
     fn consume_atom_escape(&mut self) -> Result<ir::Node, Error> {
         let nc = self.peek();
         if nc.is_none() {
@@ -751,8 +1481,44 @@ where
         match to_char_sat(c) {
             'b' | 'B' => {
                 self.consume(c);
+                if c == 'b' as u32 && self.flags.word_edge_escapes {
+                    for (name, end, half) in [
+                        ("{start}", false, false),
+                        ("{end}", true, false),
+                        ("{start-half}", false, true),
+                        ("{end-half}", true, true),
+                    ] {
+                        if self.try_consume_str(name) {
+                            return Ok(ir::Node::WordEdge {
+                                end,
+                                half,
+                                unicode: self.unicode(),
+                            });
+                        }
+                    }
+                    // Other variants, like the \b{wb} of some engines, are
+                    // not supported; a quantifier like \b{2} is left as one.
+                    let mut lookahead = self.input.clone();
+                    if lookahead.next() == Some('{' as u32)
+                        && lookahead
+                            .next()
+                            .is_some_and(|c| to_char_sat(c).is_ascii_alphabetic())
+                    {
+                        return error("Invalid word boundary variant");
+                    }
+                }
                 Ok(ir::Node::WordBoundary {
                     invert: c == 'B' as u32,
+                    unicode: self.unicode(),
+                })
+            }
+
+            '<' | '>' if self.flags.word_edge_escapes => {
+                self.consume(c);
+                Ok(ir::Node::WordEdge {
+                    end: c == '>' as u32,
+                    half: false,
+                    unicode: self.unicode(),
                 })
             }
 
@@ -774,17 +1540,61 @@ where
 
             'w' | 'W' => {
                 self.consume(c);
-                Ok(make_bracket_class(
-                    CharacterClassType::Words,
-                    c == 'w' as u32,
-                ))
+                Ok(make_bracket_class(self.word_class(), c == 'w' as u32))
+            }
+
+            'R' if self.flags.line_break_escapes => {
+                // A line break, with CRLF as a single unit: (?>\r\n|[\r\n\u2028\u2029]).
+                self.consume(c);
+                let mut cps = CodePointSet::new();
+                for c in ['\r', '\n', '\u{2028}', '\u{2029}'] {
+                    cps.add_one(c as u32);
+                }
+                let crlf = ir::Node::Cat(vec![
+                    ir::Node::Char {
+                        c: '\r' as u32,
+                        icase: false,
+                    },
+                    ir::Node::Char {
+                        c: '\n' as u32,
+                        icase: false,
+                    },
+                ]);
+                let single = ir::Node::Bracket(BracketContents { invert: false, cps });
+                Ok(ir::Node::AtomicGroup {
+                    start_group: self.group_count,
+                    end_group: self.group_count,
+                    contents: Box::new(ir::Node::Alt(Box::new(crlf), Box::new(single))),
+                })
+            }
+
+            'N' if self.flags.line_break_escapes => {
+                // Like '.' without the 's' flag.
+                self.consume(c);
+                Ok(ir::Node::MatchAnyExceptLineTerminator)
             }
 
             'p' | 'P' => {
                 self.consume(c);
 
-                let property_escape = self.try_consume_unicode_property_escape()?;
-                let negate = c == 'P' as u32;
+                if let Some((value, name)) = self.try_consume_property_of_strings() {
+                    if !self.flags.unicode_sets {
+                        return error("Property of strings requires the v flag");
+                    }
+                    if c == 'P' as u32 {
+                        return error(format!("Negated property of strings \\P{{{}}}", name));
+                    }
+                    let set = self.property_of_strings_set(value, name);
+                    return Ok(self.make_class_set(set));
+                }
+
+                let (property_escape, ne) = self.try_consume_unicode_property_escape()?;
+                let negate = (c == 'P' as u32) != ne;
+                if self.flags.unicode_sets && self.flags.icase {
+                    // The complement is taken after case folding.
+                    let cps = self.property_class_set_code_points(&property_escape, negate);
+                    return Ok(self.make_class_set(ClassSet::from_code_points(cps)));
+                }
 
                 Ok(ir::Node::UnicodePropertyEscape {
                     property_escape,
@@ -793,6 +1603,7 @@ where
             }
 
             '1'..='9' => {
+                let orig_input = self.input.clone();
                 let val = self.try_consume_decimal_integer_literal().unwrap();
 
                 // This is a backreference.
@@ -803,7 +1614,16 @@ where
                     }
                     let group = val as u32;
                     self.max_backref = core::cmp::max(self.max_backref, group);
-                    Ok(ir::Node::BackRef(group))
+                    Ok(ir::Node::BackRef {
+                        group,
+                        icase: self.flags.icase,
+                    })
+                } else if !self.unicode() {
+                    // Annex B: outside of unicode mode, a number which is not
+                    // a group is a legacy octal or identity escape.
+                    self.input = orig_input;
+                    let c = self.consume_character_escape()?;
+                    Ok(self.make_char(c))
                 } else {
                     error("Invalid character escape")
                 }
@@ -812,10 +1632,19 @@ where
             'k' => {
                 self.consume('k');
 
-                // The sequence `\k` must be the start of a backreference to a named capture group.
+                // Annex B: outside of unicode mode, in a pattern without named
+                // groups, `\k` is an identity escape for 'k'.
+                if !self.unicode() && self.named_group_indices.is_empty() {
+                    return Ok(self.make_char('k' as u32));
+                }
+
+                // Otherwise `\k` must begin a backreference to a named capture group.
                 if let Some(group_name) = self.try_consume_named_capture_group_name() {
                     if let Some(index) = self.named_group_indices.get(&group_name) {
-                        Ok(ir::Node::BackRef(*index + 1))
+                        Ok(ir::Node::BackRef {
+                            group: *index + 1,
+                            icase: self.flags.icase,
+                        })
                     } else {
                         error(format!(
                             "Backreference to invalid named capture group: {}",
@@ -826,10 +1655,10 @@ where
                     error("Unexpected end of named backreference")
                 }
             }
-            _ => Ok(ir::Node::Char {
-                c: self.consume_character_escape()?,
-                icase: self.flags.icase,
-            }),
+            _ => {
+                let c = self.consume_character_escape()?;
+                Ok(self.make_char(c))
+            }
         }
     }
 
@@ -852,9 +1681,14 @@ where
                 }
             }
 
+            // Note from_str_radix would accept a leading sign.
+            if !s.chars().all(|c| c.is_ascii_hexdigit()) {
+                self.input = orig_input;
+                return None;
+            }
             match u32::from_str_radix(&s, 16) {
                 Ok(u) => {
-                    if u > 0x10_FFFF {
+                    if u > CODE_POINT_MAX {
                         self.input = orig_input;
                         None
                     } else {
@@ -870,7 +1704,11 @@ where
             // Hex4Digits
             let mut s = String::new();
             for _ in 0..4 {
-                if let Some(c) = self.next().and_then(char::from_u32) {
+                if let Some(c) = self
+                    .next()
+                    .and_then(char::from_u32)
+                    .filter(char::is_ascii_hexdigit)
+                {
                     s.push(c);
                 } else {
                     // Surrogates are not hex digits.
@@ -880,14 +1718,15 @@ where
             }
             match u16::from_str_radix(&s, 16) {
                 Ok(u) => {
-                    if (0xD800..=0xDBFF).contains(&u) {
+                    let pair = self.flags.unicode || self.flags.unicode_sets || !self.code_units;
+                    if pair && (0xD800..=0xDBFF).contains(&u) {
                         // Found a high surrogate. Try to parse a low surrogate next
                         // to see if we can rebuild the original `char`
 
+                        orig_input = self.input.clone();
                         if !self.try_consume_str("\\u") {
                             return Some(u as u32);
                         }
-                        orig_input = self.input.clone();
 
                         // A poor man's try block to handle the backtracking
                         // in a single place instead of every time we want to return.
@@ -896,7 +1735,10 @@ where
                         let result = (|| {
                             let mut s = String::new();
                             for _ in 0..4 {
-                                let c = self.next().and_then(char::from_u32)?;
+                                let c = self
+                                    .next()
+                                    .and_then(char::from_u32)
+                                    .filter(char::is_ascii_hexdigit)?;
                                 s.push(c);
                             }
 
@@ -923,6 +1765,68 @@ where
         }
     }
 
+    /// Try consuming the modifiers of a group like `(?i:` or `(?m-s)`,
+    /// following the opening paren. Only the 'i', 'm', 's' and 'x' flags may
+    /// be modified.
+    /// \return the modified flags, and whether the modifiers are scoped to a
+    /// group (terminated by ':') rather than a toggle (terminated by ')'), or
+    /// None if the input is not a modifier group. A toggle's closing paren is
+    /// consumed.
+    fn try_consume_modifiers(&mut self) -> Result<Option<(api::Flags, bool)>, Error> {
+        let orig_input = self.input.clone();
+        if !self.try_consume_str("(?") {
+            return Ok(None);
+        }
+        let mut flags = self.flags;
+        let mut seen = String::new();
+        let mut removing = false;
+        loop {
+            let Some(c) = self.next().map(to_char_sat) else {
+                self.input = orig_input;
+                return Ok(None);
+            };
+            match c {
+                'i' | 'm' | 's' | 'x' => {
+                    if seen.contains(c) {
+                        return error("Repeated flag in modifier group");
+                    }
+                    seen.push(c);
+                    let value = !removing;
+                    match c {
+                        'i' => flags.icase = value,
+                        'm' => flags.multiline = value,
+                        's' => flags.dot_all = value,
+                        _ => flags.extended = value,
+                    }
+                }
+                '-' if !removing => removing = true,
+                ':' | ')' => {
+                    if seen.is_empty() {
+                        return error("Invalid modifier group");
+                    }
+                    return Ok(Some((flags, c == ':')));
+                }
+                _ => {
+                    self.input = orig_input;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    /// Consume a single char of a capture group name, which may be written as
+    /// a `\u` escape.
+    fn consume_group_name_char(&mut self) -> Option<char> {
+        let c = self.next().and_then(char::from_u32)?;
+        if c == '\\' {
+            if !self.try_consume('u') {
+                return None;
+            }
+            return self.try_escape_unicode_sequence().and_then(char::from_u32);
+        }
+        Some(c)
+    }
+
     fn try_consume_named_capture_group_name(&mut self) -> Option<String> {
         if !self.try_consume('<') {
             return None;
@@ -931,17 +1835,8 @@ where
         let orig_input = self.input.clone();
         let mut group_name = String::new();
 
-        if let Some(mut c) = self.next().and_then(char::from_u32) {
-            if self.try_consume('u') {
-                if let Some(escaped) = self.try_escape_unicode_sequence().and_then(char::from_u32) {
-                    c = escaped;
-                } else {
-                    self.input = orig_input;
-                    return None;
-                }
-            }
-
-            if is_id_start(c) || c == '$' || c == '_' {
+        if let Some(c) = self.consume_group_name_char() {
+            if is_group_name_start(c) {
                 group_name.push(c);
             } else {
                 self.input = orig_input;
@@ -953,25 +1848,12 @@ where
         }
 
         loop {
-            if let Some(mut c) = self.next().and_then(char::from_u32) {
-                if self.try_consume('u') {
-                    if let Some(escaped) =
-                        self.try_escape_unicode_sequence().and_then(char::from_u32)
-                    {
-                        c = escaped;
-                    } else {
-                        self.input = orig_input;
-                        return None;
-                    }
-                }
-
+            if let Some(c) = self.consume_group_name_char() {
                 if c == '>' {
                     break;
                 }
 
-                if is_id_continue(c) || c == '$' || c == '_' || c == '\u{200C}' /* <ZWNJ> */ || c == '\u{200D}'
-                /* <ZWJ> */
-                {
+                if is_group_name_continue(c) {
                     group_name.push(c);
                 } else {
                     self.input = orig_input;
@@ -996,6 +1878,10 @@ where
                     self.next();
                     continue;
                 }
+                // Skip comments, which may contain parens.
+                Some('#') if self.flags.extended => {
+                    while !matches!(self.next().map(to_char_sat), Some('\n') | None) {}
+                }
                 Some('[') => loop {
                     match self.next().map(to_char_sat) {
                         Some('\\') => {
@@ -1009,14 +1895,19 @@ where
                 },
                 Some('(') => {
                     if self.try_consume_str("?") {
-                        if let Some(name) = self.try_consume_named_capture_group_name() {
-                            if self
-                                .named_group_indices
-                                .insert(name, self.group_count_max)
-                                .is_some()
-                            {
-                                return error("Duplicate capture group name");
+                        // Only named groups capture; skip assertions and
+                        // non-capturing groups.
+                        match self.try_consume_named_capture_group_name() {
+                            Some(name) => {
+                                if self
+                                    .named_group_indices
+                                    .insert(name, self.group_count_max)
+                                    .is_some()
+                                {
+                                    return error("Duplicate capture group name");
+                                }
                             }
+                            None => continue,
                         }
                     }
                     self.group_count_max = if self.group_count_max + 1 > MAX_CAPTURE_GROUPS as u32 {
@@ -1035,27 +1926,130 @@ where
         Ok(())
     }
 
-    fn try_consume_unicode_property_escape(&mut self) -> Result<PropertyEscape, Error> {
+    /// Consume the braced part of a property of strings like `\p{RGI_Emoji}`,
+    /// if that is what follows; otherwise consume nothing.
+    fn try_consume_property_of_strings(&mut self) -> Option<(UnicodePropertyOfStrings, String)> {
+        let orig_input = self.input.clone();
+        if self.try_consume('{') {
+            let mut name = String::new();
+            while let Some(c) = self.peek().and_then(char::from_u32) {
+                if !c.is_ascii_alphanumeric() && c != '_' {
+                    break;
+                }
+                self.consume(c);
+                name.push(c);
+            }
+            if self.try_consume('}') {
+                if let Some(value) = unicode_property_of_strings_from_str(&name) {
+                    return Some((value, name));
+                }
+            }
+        }
+        self.input = orig_input;
+        None
+    }
+
+    /// \return the ClassSet of the strings matched by a property of strings,
+    /// written with the name \p name.
+    fn property_of_strings_set(&self, value: UnicodePropertyOfStrings, name: String) -> ClassSet {
+        let icase = self.flags.icase;
+        ClassSet::from_strings(
+            unicode::property_of_strings_sequences(value).map(|seq| {
+                seq.iter()
+                    .map(|&c| if icase { unicode::fold(c) } else { c })
+                    .collect()
+            }),
+            format!("the property of strings \\p{{{}}}", name),
+        )
+    }
+
+    /// \return the code points of \p property_escape, or of its complement
+    /// if \p negate is set, for a 'v'-mode class.
+    fn property_class_set_code_points(
+        &self,
+        property_escape: &PropertyEscape,
+        negate: bool,
+    ) -> CodePointSet {
+        let cps =
+            self.maybe_simple_case_folding(unicode::property_escape_code_points(property_escape));
+        if negate {
+            self.character_complement(&cps)
+        } else {
+            cps
+        }
+    }
+
+    /// \return \p cps case folded, if folding applies to the contents of
+    /// 'v'-mode classes, which is with the 'i' flag. The folded sets are
+    /// only closed over case once the whole class is built, so that
+    /// complements, intersections and differences are of folded characters.
+    /// ES2024 22.2.2.9.3 MaybeSimpleCaseFolding.
+    fn maybe_simple_case_folding(&self, cps: CodePointSet) -> CodePointSet {
+        if self.flags.unicode_sets && self.flags.icase {
+            unicode::simple_case_folded(&cps)
+        } else {
+            cps
+        }
+    }
+
+    /// \return the complement of \p cps in a 'v'-mode class. With the 'i'
+    /// flag, this is relative to the characters which are their own case
+    /// folding, so that \P{Ll} matches no letter which has a lowercase
+    /// form, rather than every letter as with the 'u' flag.
+    /// ES2024 22.2.2.9.5 CharacterComplement.
+    fn character_complement(&self, cps: &CodePointSet) -> CodePointSet {
+        if self.flags.unicode_sets && self.flags.icase {
+            unicode::fold_invariant_code_points().difference(cps)
+        } else {
+            cps.inverted()
+        }
+    }
+
+    /// \return the set of the class set operand \p operand, case folded.
+    fn class_set_of(&self, operand: ClassSetOperand) -> ClassSet {
+        match operand {
+            ClassSetOperand::CodePoint(c) => {
+                let mut cps = CodePointSet::new();
+                cps.add_one(c);
+                ClassSet::from_code_points(self.maybe_simple_case_folding(cps))
+            }
+            ClassSetOperand::Set(set) => set,
+        }
+    }
+
+    /// Consume the braced part of a property escape like `\p{Script=Greek}`.
+    /// \return the escape, and whether it was written with `!=` like
+    /// `\p{Age!=9.0}`, which negates it.
+    fn try_consume_unicode_property_escape(&mut self) -> Result<(PropertyEscape, bool), Error> {
         if !self.try_consume('{') {
             return error("Invalid character at property escape start");
         }
 
         let mut name = String::new();
+        let mut negate = false;
 
         while let Some(c) = self.peek().and_then(char::from_u32) {
             match c {
                 '}' => {
                     self.consume(c);
                     if let Some(value) = unicode_property_value_from_str(&name) {
-                        return Ok(PropertyEscape { name: None, value });
+                        return Ok((PropertyEscape { name: None, value }, false));
                     } else {
-                        return error("Invalid property name");
+                        return error(format!("Invalid property name '{}'", name));
                     }
                 }
                 '=' => {
                     self.consume(c);
                     break;
                 }
+                '!' => {
+                    self.consume(c);
+                    if !self.try_consume('=') {
+                        return error("Invalid property name");
+                    }
+                    negate = true;
+                    break;
+                }
                 c if c.is_ascii_alphanumeric() || c == '_' => {
                     self.consume(c);
                     name.push(c);
@@ -1074,21 +2068,28 @@ where
                     self.consume(c);
                     let name = if let Some(name) = unicode::unicode_property_name_from_str(&name) {
                         name
+                    } else if unicode_property_binary_from_str(&name).is_some() {
+                        return error(format!("Binary property '{}' does not take a value", name));
                     } else {
-                        return error("Invalid property name");
+                        return error(format!("Invalid property name '{}'", name));
                     };
-                    let value =
-                        if let Some(value) = unicode::unicode_property_value_from_str(&value) {
-                            value
-                        } else {
-                            return error("Invalid property name");
-                        };
-                    return Ok(PropertyEscape {
-                        name: Some(name),
-                        value,
-                    });
+                    let value = if let Some(value) =
+                        unicode::unicode_property_value_for_name(name, &value)
+                    {
+                        value
+                    } else {
+                        return error(format!("Invalid property value '{}'", value));
+                    };
+                    return Ok((
+                        PropertyEscape {
+                            name: Some(name),
+                            value,
+                        },
+                        negate,
+                    ));
                 }
-                c if c.is_ascii_alphanumeric() || c == '_' => {
+                // Age values are versions like 9.0.
+                c if c.is_ascii_alphanumeric() || c == '_' || c == '.' => {
                     self.consume(c);
                     value.push(c);
                 }
@@ -1111,9 +2112,54 @@ where
     }
 }
 
+/// The default maximum nesting depth of groups and nested classes.
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 256;
+
+/// Limits on a pattern, beyond which it is rejected with an Error.
+#[derive(Debug, Copy, Clone)]
+pub struct Limits {
+    /// Maximum nesting depth of groups and nested classes.
+    pub max_depth: usize,
+
+    /// Maximum finite bound of a quantifier.
+    pub max_repetition: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_depth: DEFAULT_MAX_NESTING_DEPTH,
+            max_repetition: usize::MAX,
+        }
+    }
+}
+
+impl Limits {
+    /// No limits, for parsing again a pattern which was accepted before.
+    pub fn unlimited() -> Self {
+        Limits {
+            max_depth: usize::MAX,
+            max_repetition: usize::MAX,
+        }
+    }
+}
+
 /// Try parsing a given pattern.
 /// Return the resulting IR regex, or an error.
 pub fn try_parse<I>(pattern: I, flags: api::Flags) -> Result<ir::Regex, Error>
+where
+    I: Iterator<Item = u32> + Clone,
+{
+    try_parse_with_limits(pattern, flags, Limits::default())
+}
+
+/// Try parsing a given pattern, rejecting it if it exceeds \p limits.
+/// Return the resulting IR regex, or an error.
+pub fn try_parse_with_limits<I>(
+    pattern: I,
+    flags: api::Flags,
+    limits: Limits,
+) -> Result<ir::Regex, Error>
 where
     I: Iterator<Item = u32> + Clone,
 {
@@ -1127,6 +2173,33 @@ where
     // }
 
     let mut p = Parser {
+        pattern_len: utf8_len(pattern.clone()),
+        input: pattern.peekable(),
+        flags,
+        loop_count: 0,
+        group_count: 0,
+        named_group_indices: HashMap::new(),
+        group_count_max: 0,
+        max_backref: 0,
+        has_lookbehind: false,
+        depth: 0,
+        limits,
+        code_units: false,
+    };
+    p.try_parse()
+}
+
+/// Try parsing a pattern of UTF-16 code units, which is to be matched
+/// against UTF-16 text. Without the 'u' flag, each code unit is then a char,
+/// and a pair of surrogate escapes like `\uD83D\uDE00` is two of them. The
+/// pattern has been parsed before, so no limits are applied.
+/// Return the resulting IR regex, or an error.
+pub fn try_parse_code_units<I>(pattern: I, flags: api::Flags) -> Result<ir::Regex, Error>
+where
+    I: Iterator<Item = u32> + Clone,
+{
+    let mut p = Parser {
+        pattern_len: utf8_len(pattern.clone()),
         input: pattern.peekable(),
         flags,
         loop_count: 0,
@@ -1135,6 +2208,12 @@ where
         group_count_max: 0,
         max_backref: 0,
         has_lookbehind: false,
+        depth: 0,
+        limits: Limits {
+            max_depth: usize::MAX,
+            max_repetition: usize::MAX,
+        },
+        code_units: true,
     };
     p.try_parse()
 }
@@ -1,12 +1,12 @@
 //! PikeVM regex execution engine
 
-use crate::api::Match;
+use crate::api::{Match, OffsetUnit};
 use crate::bytesearch::charset_contains;
 use crate::cursor;
 use crate::cursor::{Backward, Direction, Forward};
 use crate::exec;
 use crate::indexing::{AsciiInput, ElementType, InputIndexer, Utf8Input};
-use crate::insn::{CompiledRegex, Insn, LoopFields};
+use crate::insn::{is_word_edge, CompiledRegex, GroupNames, Insn, LoopFields};
 use crate::matchers;
 use crate::matchers::CharProperties;
 use crate::position::PositionType;
@@ -14,14 +14,9 @@ use crate::scm;
 use crate::scm::SingleCharMatcher;
 use crate::types::{GroupData, LoopData};
 use crate::util::DebugCheckIndex;
-use core::ops::Range;
-#[cfg(feature = "std")]
-use std::collections::HashMap;
 #[cfg(not(feature = "std"))]
-use {
-    alloc::{string::String, vec::Vec},
-    hashbrown::HashMap,
-};
+use alloc::vec::Vec;
+use core::ops::Range;
 
 #[derive(Debug, Clone)]
 struct State<Position: PositionType> {
@@ -151,19 +146,19 @@ fn try_match_state<Input: InputIndexer, Dir: Direction>(
         Insn::ByteSeq15(v) => nextinsn_or_fail!(cursor::try_match_lit(input, dir, &mut s.pos, v)),
         Insn::ByteSeq16(v) => nextinsn_or_fail!(cursor::try_match_lit(input, dir, &mut s.pos, v)),
 
-        Insn::StartOfLine => {
+        &Insn::StartOfLine { multiline } => {
             let matches = match input.peek_left(s.pos) {
                 None => true,
-                Some(c) if re.flags.multiline && Input::CharProps::is_line_terminator(c) => true,
+                Some(c) if multiline && Input::CharProps::is_line_terminator(c) => true,
                 _ => false,
             };
             nextinsn_or_fail!(matches)
         }
 
-        Insn::EndOfLine => {
+        &Insn::EndOfLine { multiline } => {
             let matches = match input.peek_right(s.pos) {
                 None => true, // we're at the right of the string
-                Some(c) if re.flags.multiline && Input::CharProps::is_line_terminator(c) => true,
+                Some(c) if multiline && Input::CharProps::is_line_terminator(c) => true,
                 _ => false,
             };
             nextinsn_or_fail!(matches)
@@ -224,11 +219,14 @@ fn try_match_state<Input: InputIndexer, Dir: Direction>(
             nextinsn_or_fail!(true)
         }
 
-        &Insn::BackRef(group_idx) => {
+        &Insn::BackRef {
+            group: group_idx,
+            icase,
+        } => {
             let matched;
             let group = &mut s.groups[group_idx as usize];
             if let Some(orig_range) = group.as_range() {
-                if re.flags.icase {
+                if icase {
                     matched = matchers::backref_icase(input, dir, orig_range, &mut s.pos);
                 } else {
                     matched = matchers::backref(input, dir, orig_range, &mut s.pos)
@@ -281,6 +279,17 @@ fn try_match_state<Input: InputIndexer, Dir: Direction>(
                 StateMatch::Fail
             }
         }
+        &Insn::AtomicGroup { continuation, .. } => {
+            // Run the contents to their first match only, keeping the resulting
+            // position and captures.
+            s.ip += 1;
+            if MatchAttempter::new(re).try_at_pos(*input, s, dir) {
+                s.ip = continuation as usize;
+                StateMatch::Continue
+            } else {
+                StateMatch::Fail
+            }
+        }
         Insn::EnterLoop(lf) => run_loop(s, lf, true),
         &Insn::LoopAgain { begin } => {
             s.ip = begin as usize;
@@ -308,28 +317,32 @@ fn try_match_state<Input: InputIndexer, Dir: Direction>(
             nextinsn_or_fail!(scm::MatchByteArraySet { bytes }.matches(input, dir, &mut s.pos))
         }
 
-        &Insn::WordBoundary { invert } => {
-            let prev_wordchar = input
-                .peek_left(s.pos)
-                .map_or(false, Input::CharProps::is_word_char);
-            let curr_wordchar = input
-                .peek_right(s.pos)
-                .map_or(false, Input::CharProps::is_word_char);
+        &Insn::WordBoundary { invert, unicode } => {
+            let custom = re.custom_word_chars();
+            let is_word_char = |c| Input::CharProps::is_boundary_word_char(custom, unicode, c);
+            let prev_wordchar = input.peek_left(s.pos).is_some_and(is_word_char);
+            let curr_wordchar = input.peek_right(s.pos).is_some_and(is_word_char);
             let is_boundary = prev_wordchar != curr_wordchar;
             nextinsn_or_fail!(is_boundary != invert)
         }
 
+        &Insn::WordEdge { end, half, unicode } => {
+            let custom = re.custom_word_chars();
+            let is_word_char = |c| Input::CharProps::is_boundary_word_char(custom, unicode, c);
+            let prev_wordchar = input.peek_left(s.pos).is_some_and(is_word_char);
+            let curr_wordchar = input.peek_right(s.pos).is_some_and(is_word_char);
+            nextinsn_or_fail!(is_word_edge(end, half, prev_wordchar, curr_wordchar))
+        }
+
         Insn::UnicodePropertyEscape {
             property_escape,
             negate,
         } => {
-            let m = if (scm::UnicodePropertyEscape { property_escape })
-                .matches(input, dir, &mut s.pos)
-            {
-                !*negate
-            } else {
-                *negate
-            };
+            let m = scm::UnicodePropertyEscape {
+                property_escape,
+                negate: *negate,
+            }
+            .matches(input, dir, &mut s.pos);
             nextinsn_or_fail!(m)
         }
     }
@@ -339,7 +352,7 @@ fn successful_match<Input: InputIndexer>(
     input: Input,
     start: Input::Position,
     state: &State<Input::Position>,
-    named_captures: HashMap<String, u16>,
+    named_captures: GroupNames,
 ) -> Match {
     let group_to_offset = |mr: &GroupData<Input::Position>| -> Option<Range<usize>> {
         mr.as_range().map(|r| Range {
@@ -352,6 +365,8 @@ fn successful_match<Input: InputIndexer>(
         range: input.pos_to_offset(start)..input.pos_to_offset(state.pos),
         captures,
         named_captures,
+        offset_unit: OffsetUnit::Bytes,
+        unit_ranges: Vec::new(),
     }
 }
 
@@ -427,6 +442,45 @@ impl<'r, 't> exec::Executor<'r, 't> for PikeVMExecutor<'r, AsciiInput<'t>> {
     }
 }
 
+impl<'a, Input: InputIndexer> PikeVMExecutor<'a, Input> {
+    /// \return a new initial state at \p pos.
+    fn initial_state(&self, pos: Input::Position) -> State<Input::Position> {
+        let re = self.matcher.re;
+        // Note the "initial" loop position is ignored. Use whatever is most convenient.
+        State {
+            pos,
+            ip: 0,
+            loops: vec![LoopData::new(pos); re.loops as usize],
+            groups: vec![GroupData::new(); re.groups as usize],
+        }
+    }
+
+    /// Attempt to match starting at the position of \p state.
+    /// \return the Match on success, or None on failure.
+    fn match_from_state(
+        &mut self,
+        state: &mut State<Input::Position>,
+        next_start: &mut Option<Input::Position>,
+    ) -> Option<Match> {
+        let start = state.pos;
+        if !self.matcher.try_at_pos(self.input, state, Forward::new()) {
+            return None;
+        }
+        let end = state.pos;
+        if end != start {
+            *next_start = Some(end)
+        } else {
+            *next_start = self.input.next_right_pos(end)
+        }
+        Some(successful_match(
+            self.input,
+            start,
+            state,
+            self.matcher.re.named_group_indices.clone(),
+        ))
+    }
+}
+
 impl<'a, Input: InputIndexer> exec::MatchProducer for PikeVMExecutor<'a, Input> {
     type Position = Input::Position;
 
@@ -439,32 +493,11 @@ impl<'a, Input: InputIndexer> exec::MatchProducer for PikeVMExecutor<'a, Input>
         pos: Self::Position,
         next_start: &mut Option<Self::Position>,
     ) -> Option<Match> {
-        let re = self.matcher.re;
-        // Note the "initial" loop position is ignored. Use whatever is most convenient.
-        let mut state = State {
-            pos,
-            ip: 0,
-            loops: vec![LoopData::new(pos); re.loops as usize],
-            groups: vec![GroupData::new(); re.groups as usize],
-        };
+        let mut state = self.initial_state(pos);
         loop {
             let start = state.pos;
-            if self
-                .matcher
-                .try_at_pos(self.input, &mut state, Forward::new())
-            {
-                let end = state.pos;
-                if end != start {
-                    *next_start = Some(end)
-                } else {
-                    *next_start = self.input.next_right_pos(end)
-                }
-                return Some(successful_match(
-                    self.input,
-                    start,
-                    &state,
-                    re.named_group_indices.clone(),
-                ));
+            if let Some(m) = self.match_from_state(&mut state, next_start) {
+                return Some(m);
             }
             match self.input.next_right_pos(start) {
                 Some(nextpos) => state.pos = nextpos,
@@ -473,4 +506,13 @@ impl<'a, Input: InputIndexer> exec::MatchProducer for PikeVMExecutor<'a, Input>
         }
         None
     }
+
+    fn match_at(
+        &mut self,
+        pos: Self::Position,
+        next_start: &mut Option<Self::Position>,
+    ) -> Option<Match> {
+        let mut state = self.initial_state(pos);
+        self.match_from_state(&mut state, next_start)
+    }
 }
@@ -2,9 +2,8 @@ use crate::bytesearch::{charset_contains, ByteArraySet, ByteSeq, ByteSet, SmallA
 use crate::cursor;
 use crate::cursor::Direction;
 use crate::indexing::{ElementType, InputIndexer};
-use crate::insn::MAX_CHAR_SET_LENGTH;
+use crate::insn::{CompiledBracket, MAX_CHAR_SET_LENGTH};
 use crate::matchers::CharProperties;
-use crate::types::BracketContents;
 use crate::unicode::{is_character_class, PropertyEscape};
 
 /// A trait for things that match a single Element.
@@ -12,6 +11,20 @@ pub trait SingleCharMatcher<Input: InputIndexer, Dir: Direction> {
     /// \return whether we match the character at the given position, advancing
     /// the position if so. On a false return, the position is unspecified.
     fn matches(&self, input: &Input, dir: Dir, pos: &mut Input::Position) -> bool;
+
+    /// Advance the position over up to \p limit characters which match, where
+    /// this is faster than matching them one at a time.
+    /// \return how many were skipped; the rest are left for `matches`.
+    #[inline(always)]
+    fn skip_run(
+        &self,
+        _input: &Input,
+        _dir: Dir,
+        _pos: &mut Input::Position,
+        _limit: usize,
+    ) -> usize {
+        0
+    }
 }
 
 /// Insn::Char
@@ -59,7 +72,7 @@ impl<'a, Input: InputIndexer, Dir: Direction> SingleCharMatcher<Input, Dir> for
 
 /// Insn::Bracket
 pub struct Bracket<'a> {
-    pub bc: &'a BracketContents,
+    pub bc: &'a CompiledBracket,
 }
 
 impl<'a, Input: InputIndexer, Dir: Direction> SingleCharMatcher<Input, Dir> for Bracket<'a> {
@@ -85,6 +98,16 @@ impl<Input: InputIndexer, Dir: Direction> SingleCharMatcher<Input, Dir> for Matc
         // If there is a character, it counts as a match.
         cursor::next(input, dir, pos).is_some()
     }
+
+    #[inline(always)]
+    fn skip_run(&self, input: &Input, _dir: Dir, pos: &mut Input::Position, limit: usize) -> usize {
+        if !Dir::FORWARD {
+            return 0;
+        }
+        let skipped = input.ascii_run_right(*pos, limit).len();
+        *pos += skipped;
+        skipped
+    }
 }
 
 /// Insn::MatchAnyExceptLineTerminator
@@ -104,6 +127,18 @@ impl<Input: InputIndexer, Dir: Direction> SingleCharMatcher<Input, Dir>
             _ => false,
         }
     }
+
+    #[inline(always)]
+    fn skip_run(&self, input: &Input, _dir: Dir, pos: &mut Input::Position, limit: usize) -> usize {
+        if !Dir::FORWARD {
+            return 0;
+        }
+        // The only ASCII line terminators are \n and \r.
+        let run = input.ascii_run_right(*pos, limit);
+        let skipped = memchr::memchr2(b'\n', b'\r', run).unwrap_or(run.len());
+        *pos += skipped;
+        skipped
+    }
 }
 
 /// Any ByteSet may match a single char.
@@ -161,9 +196,12 @@ impl<'a, Input: InputIndexer, Dir: Direction, Bytes: ByteSeq> SingleCharMatcher<
     }
 }
 
-/// TODO: doc comment
+/// Matches a single char against a Unicode property escape.
+/// If `negate` is set, matches any char not in the property; the end of
+/// input never matches.
 pub struct UnicodePropertyEscape<'a> {
     pub property_escape: &'a PropertyEscape,
+    pub negate: bool,
 }
 
 impl<'a, Input: InputIndexer, Dir: Direction> SingleCharMatcher<Input, Dir>
@@ -172,7 +210,7 @@ impl<'a, Input: InputIndexer, Dir: Direction> SingleCharMatcher<Input, Dir>
     #[inline(always)]
     fn matches(&self, input: &Input, dir: Dir, pos: &mut Input::Position) -> bool {
         match cursor::next(input, dir, pos) {
-            Some(c2) => is_character_class(c2.into(), self.property_escape),
+            Some(c2) => is_character_class(c2.into(), self.property_escape) != self.negate,
             _ => false,
         }
     }
@@ -0,0 +1,943 @@
+//! Serialization of compiled programs, so that they may be reloaded without
+//! parsing or emitting again.
+//!
+//! The format is a header followed by the fields of the CompiledRegex. All
+//! integers are little-endian. A program is validated as it is loaded, so
+//! that the matchers' assumptions about jump targets and indexes hold even if
+//! the bytes were corrupted.
+
+use crate::api;
+use crate::bytesearch::{AsciiBitmap, ByteArraySet, ByteBitmap};
+use crate::codepointset::{CodePointSet, Interval, CODE_POINT_MAX};
+use crate::insn::{
+    CompiledBracket, CompiledRegex, GroupNames, Insn, LoopFields, StartPredicate,
+    MAX_CHAR_SET_LENGTH,
+};
+use crate::parse::{error, Error};
+use crate::types::{BracketContents, MAX_CAPTURE_GROUPS, MAX_LOOPS};
+use crate::unicode::{PropertyEscape, UnicodePropertyName, UnicodePropertyValue, UNICODE_VERSION};
+use crate::unicodetables::{
+    UNICODE_PROPERTY_BINARY_VARIANTS, UNICODE_PROPERTY_VALUE_AGE_VARIANTS,
+    UNICODE_PROPERTY_VALUE_GENERAL_CATEGORY_VARIANTS, UNICODE_PROPERTY_VALUE_SCRIPT_VARIANTS,
+};
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// Identifies a serialized program.
+const MAGIC: &[u8; 4] = b"RGXP";
+
+/// The version of the format. This must be bumped whenever the encoding, or
+/// the meaning of any instruction, changes.
+const FORMAT_VERSION: u32 = 6;
+
+// Instruction tags.
+const GOAL: u8 = 0;
+const CHAR: u8 = 1;
+const CHAR_ICASE: u8 = 2;
+const START_OF_LINE: u8 = 3;
+const END_OF_LINE: u8 = 4;
+const MATCH_ANY: u8 = 5;
+const MATCH_ANY_EXCEPT_LINE_TERMINATOR: u8 = 6;
+const ENTER_LOOP: u8 = 7;
+const LOOP_AGAIN: u8 = 8;
+const LOOP_1CHAR_BODY: u8 = 9;
+const JUMP: u8 = 10;
+const ALT: u8 = 11;
+const BEGIN_CAPTURE_GROUP: u8 = 12;
+const END_CAPTURE_GROUP: u8 = 13;
+const RESET_CAPTURE_GROUP: u8 = 14;
+const BACK_REF: u8 = 15;
+const BRACKET: u8 = 16;
+const ASCII_BRACKET: u8 = 17;
+const LOOKAHEAD: u8 = 18;
+const LOOKBEHIND: u8 = 19;
+const ATOMIC_GROUP: u8 = 20;
+const WORD_BOUNDARY: u8 = 21;
+const CHAR_SET: u8 = 22;
+const BYTE_SET2: u8 = 23;
+const BYTE_SET3: u8 = 24;
+const BYTE_SET4: u8 = 25;
+const BYTE_SEQ: u8 = 26;
+const UNICODE_PROPERTY_ESCAPE: u8 = 27;
+const JUST_FAIL: u8 = 28;
+const WORD_EDGE: u8 = 29;
+
+struct Writer {
+    out: Vec<u8>,
+}
+
+impl Writer {
+    fn u8(&mut self, v: u8) {
+        self.out.push(v)
+    }
+
+    fn bool(&mut self, v: bool) {
+        self.u8(v as u8)
+    }
+
+    fn u16(&mut self, v: u16) {
+        self.out.extend_from_slice(&v.to_le_bytes())
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.out.extend_from_slice(&v.to_le_bytes())
+    }
+
+    fn usize(&mut self, v: usize) {
+        self.out.extend_from_slice(&(v as u64).to_le_bytes())
+    }
+
+    fn bytes(&mut self, v: &[u8]) {
+        self.out.extend_from_slice(v)
+    }
+
+    fn str(&mut self, v: &str) {
+        self.u32(v.len() as u32);
+        self.bytes(v.as_bytes());
+    }
+
+    fn flags(&mut self, flags: &api::Flags) {
+        let bits = [
+            flags.icase,
+            flags.multiline,
+            flags.dot_all,
+            flags.no_opt,
+            flags.extended,
+            flags.unicode,
+            flags.unicode_sets,
+            flags.sticky,
+            flags.has_indices,
+            flags.line_break_escapes,
+            flags.word_edge_escapes,
+            flags.dot_matches_all,
+        ];
+        let mut v = 0u16;
+        for (i, &bit) in bits.iter().enumerate() {
+            v |= (bit as u16) << i;
+        }
+        self.u16(v)
+    }
+
+    fn word_boundary(&mut self, wb: &Option<api::WordBoundary>) {
+        match wb {
+            None => self.u8(0),
+            Some(api::WordBoundary::Ascii) => self.u8(1),
+            Some(api::WordBoundary::Unicode) => self.u8(2),
+            Some(api::WordBoundary::Custom(cps)) => {
+                self.u8(3);
+                self.brackets(&BracketContents {
+                    invert: false,
+                    cps: cps.clone(),
+                })
+            }
+        }
+    }
+
+    fn brackets(&mut self, bc: &BracketContents) {
+        self.bool(bc.invert);
+        let ivs = bc.cps.intervals();
+        self.u32(ivs.len() as u32);
+        for iv in ivs {
+            self.u32(iv.first);
+            self.u32(iv.last);
+        }
+    }
+
+    fn property_escape(&mut self, pe: &PropertyEscape) {
+        self.u8(match pe.name {
+            None => 0,
+            Some(UnicodePropertyName::GeneralCategory) => 1,
+            Some(UnicodePropertyName::Script) => 2,
+            Some(UnicodePropertyName::ScriptExtensions) => 3,
+            Some(UnicodePropertyName::Age) => 4,
+        });
+        let (tag, idx) = match pe.value {
+            UnicodePropertyValue::Binary(v) => (0, v as u16),
+            UnicodePropertyValue::GeneralCategory(v) => (1, v as u16),
+            UnicodePropertyValue::Script(v) => (2, v as u16),
+            UnicodePropertyValue::Age(v) => (3, v as u16),
+        };
+        self.u8(tag);
+        self.u16(idx);
+    }
+
+    fn byte_bitmap(&mut self, bitmap: &ByteBitmap) {
+        let mut bits = [0u8; 32];
+        for b in 0..=255u8 {
+            if bitmap.contains(b) {
+                bits[(b >> 3) as usize] |= 1 << (b & 7);
+            }
+        }
+        self.bytes(&bits)
+    }
+
+    fn start_pred(&mut self, sp: &StartPredicate) {
+        match sp {
+            StartPredicate::Arbitrary => self.u8(0),
+            StartPredicate::ByteSeq1(v) => {
+                self.u8(1);
+                self.bytes(v)
+            }
+            StartPredicate::ByteSeq2(v) => {
+                self.u8(2);
+                self.bytes(v)
+            }
+            StartPredicate::ByteSeq3(v) => {
+                self.u8(3);
+                self.bytes(v)
+            }
+            StartPredicate::ByteSeq4(v) => {
+                self.u8(4);
+                self.bytes(v)
+            }
+            StartPredicate::ByteSet2(v) => {
+                self.u8(5);
+                self.bytes(v)
+            }
+            StartPredicate::ByteSet3(v) => {
+                self.u8(6);
+                self.bytes(v)
+            }
+            StartPredicate::ByteBracket(v) => {
+                self.u8(7);
+                self.byte_bitmap(v)
+            }
+        }
+    }
+
+    fn insn(&mut self, insn: &Insn) {
+        match insn {
+            Insn::Goal => self.u8(GOAL),
+            &Insn::Char(c) => {
+                self.u8(CHAR);
+                self.u32(c)
+            }
+            &Insn::CharICase(c) => {
+                self.u8(CHAR_ICASE);
+                self.u32(c)
+            }
+            &Insn::StartOfLine { multiline } => {
+                self.u8(START_OF_LINE);
+                self.bool(multiline)
+            }
+            &Insn::EndOfLine { multiline } => {
+                self.u8(END_OF_LINE);
+                self.bool(multiline)
+            }
+            Insn::MatchAny => self.u8(MATCH_ANY),
+            Insn::MatchAnyExceptLineTerminator => self.u8(MATCH_ANY_EXCEPT_LINE_TERMINATOR),
+            Insn::EnterLoop(fields) => {
+                self.u8(ENTER_LOOP);
+                self.u16(fields.loop_id);
+                self.usize(fields.min_iters);
+                self.usize(fields.max_iters);
+                self.bool(fields.greedy);
+                self.u32(fields.exit);
+            }
+            &Insn::LoopAgain { begin } => {
+                self.u8(LOOP_AGAIN);
+                self.u32(begin)
+            }
+            &Insn::Loop1CharBody {
+                min_iters,
+                max_iters,
+                greedy,
+            } => {
+                self.u8(LOOP_1CHAR_BODY);
+                self.usize(min_iters);
+                self.usize(max_iters);
+                self.bool(greedy);
+            }
+            &Insn::Jump { target } => {
+                self.u8(JUMP);
+                self.u32(target)
+            }
+            &Insn::Alt { secondary } => {
+                self.u8(ALT);
+                self.u32(secondary)
+            }
+            &Insn::BeginCaptureGroup(id) => {
+                self.u8(BEGIN_CAPTURE_GROUP);
+                self.u16(id)
+            }
+            &Insn::EndCaptureGroup(id) => {
+                self.u8(END_CAPTURE_GROUP);
+                self.u16(id)
+            }
+            &Insn::ResetCaptureGroup(id) => {
+                self.u8(RESET_CAPTURE_GROUP);
+                self.u16(id)
+            }
+            &Insn::BackRef { group, icase } => {
+                self.u8(BACK_REF);
+                self.u32(group);
+                self.bool(icase);
+            }
+            &Insn::Bracket(idx) => {
+                self.u8(BRACKET);
+                self.u32(idx as u32)
+            }
+            Insn::AsciiBracket(bitmap) => {
+                self.u8(ASCII_BRACKET);
+                self.bytes(&bitmap.0)
+            }
+            &Insn::Lookahead {
+                negate,
+                start_group,
+                end_group,
+                continuation,
+            } => {
+                self.u8(LOOKAHEAD);
+                self.bool(negate);
+                self.u16(start_group);
+                self.u16(end_group);
+                self.u32(continuation);
+            }
+            &Insn::Lookbehind {
+                negate,
+                start_group,
+                end_group,
+                continuation,
+            } => {
+                self.u8(LOOKBEHIND);
+                self.bool(negate);
+                self.u16(start_group);
+                self.u16(end_group);
+                self.u32(continuation);
+            }
+            &Insn::AtomicGroup {
+                start_group,
+                end_group,
+                continuation,
+            } => {
+                self.u8(ATOMIC_GROUP);
+                self.u16(start_group);
+                self.u16(end_group);
+                self.u32(continuation);
+            }
+            &Insn::WordBoundary { invert, unicode } => {
+                self.u8(WORD_BOUNDARY);
+                self.bool(invert);
+                self.bool(unicode);
+            }
+            &Insn::WordEdge { end, half, unicode } => {
+                self.u8(WORD_EDGE);
+                self.bool(end);
+                self.bool(half);
+                self.bool(unicode);
+            }
+            Insn::CharSet(chars) => {
+                self.u8(CHAR_SET);
+                for &c in chars {
+                    self.u32(c)
+                }
+            }
+            Insn::ByteSet2(set) => {
+                self.u8(BYTE_SET2);
+                self.bytes(&set.0)
+            }
+            Insn::ByteSet3(set) => {
+                self.u8(BYTE_SET3);
+                self.bytes(&set.0)
+            }
+            Insn::ByteSet4(set) => {
+                self.u8(BYTE_SET4);
+                self.bytes(&set.0)
+            }
+            Insn::ByteSeq1(v) => self.byte_seq(v),
+            Insn::ByteSeq2(v) => self.byte_seq(v),
+            Insn::ByteSeq3(v) => self.byte_seq(v),
+            Insn::ByteSeq4(v) => self.byte_seq(v),
+            Insn::ByteSeq5(v) => self.byte_seq(v),
+            Insn::ByteSeq6(v) => self.byte_seq(v),
+            Insn::ByteSeq7(v) => self.byte_seq(v),
+            Insn::ByteSeq8(v) => self.byte_seq(v),
+            Insn::ByteSeq9(v) => self.byte_seq(v),
+            Insn::ByteSeq10(v) => self.byte_seq(v),
+            Insn::ByteSeq11(v) => self.byte_seq(v),
+            Insn::ByteSeq12(v) => self.byte_seq(v),
+            Insn::ByteSeq13(v) => self.byte_seq(v),
+            Insn::ByteSeq14(v) => self.byte_seq(v),
+            Insn::ByteSeq15(v) => self.byte_seq(v),
+            Insn::ByteSeq16(v) => self.byte_seq(v),
+            Insn::UnicodePropertyEscape {
+                property_escape,
+                negate,
+            } => {
+                self.u8(UNICODE_PROPERTY_ESCAPE);
+                self.property_escape(property_escape);
+                self.bool(*negate);
+            }
+            Insn::JustFail => self.u8(JUST_FAIL),
+        }
+    }
+
+    fn byte_seq(&mut self, v: &[u8]) {
+        self.u8(BYTE_SEQ);
+        self.u8(v.len() as u8);
+        self.bytes(v)
+    }
+}
+
+/// \return the serialized form of a compiled regex.
+pub fn serialize(cr: &CompiledRegex) -> Vec<u8> {
+    let mut w = Writer { out: Vec::new() };
+    w.bytes(MAGIC);
+    w.u32(FORMAT_VERSION);
+    w.str(UNICODE_VERSION);
+
+    w.flags(&cr.flags);
+    w.word_boundary(&cr.word_boundary);
+    w.u32(cr.loops);
+    w.u32(cr.groups);
+
+    // Names are in order of group index, so the output is deterministic.
+    w.u32(cr.named_group_indices.len() as u32);
+    for (name, idx) in cr.named_group_indices.iter() {
+        w.str(name);
+        w.u16(idx);
+    }
+
+    w.start_pred(&cr.start_pred);
+    let (min_length, max_length) = cr.match_length_bounds;
+    w.usize(min_length);
+    w.bool(max_length.is_some());
+    if let Some(max_length) = max_length {
+        w.usize(max_length);
+    }
+
+    w.u32(cr.brackets.len() as u32);
+    for bc in &cr.brackets {
+        w.brackets(&bc.contents);
+    }
+
+    w.u32(cr.insns.len() as u32);
+    for insn in &cr.insns {
+        w.insn(insn);
+    }
+    w.out
+}
+
+struct Reader<'a> {
+    input: &'a [u8],
+}
+
+/// \return an error for a malformed program.
+fn invalid<T>() -> Result<T, Error> {
+    error("Invalid serialized program")
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.input.len() < len {
+            return invalid();
+        }
+        let (res, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(res)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut res = [0; N];
+        res.copy_from_slice(self.bytes(N)?);
+        Ok(res)
+    }
+
+    /// Read the bytes of a ByteSet, which only ever holds ASCII: a matcher
+    /// which consumed any other byte would stop inside a character.
+    fn ascii_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let res = self.array::<N>()?;
+        if !res.is_ascii() {
+            return invalid();
+        }
+        Ok(res)
+    }
+
+    /// Read the bytes of a ByteSeq, which are whole UTF-8 sequences.
+    fn utf8_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let res = self.array::<N>()?;
+        if core::str::from_utf8(&res).is_err() {
+            return invalid();
+        }
+        Ok(res)
+    }
+
+    fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn bool(&mut self) -> Result<bool, Error> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => invalid(),
+        }
+    }
+
+    fn u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn usize(&mut self) -> Result<usize, Error> {
+        let v = u64::from_le_bytes(self.array()?);
+        // Loops may be unbounded, in which case their maximum is usize::MAX.
+        if v == u64::MAX {
+            Ok(usize::MAX)
+        } else {
+            usize::try_from(v).or_else(|_| invalid())
+        }
+    }
+
+    /// Read a length, which must not exceed the remaining input, so that
+    /// corrupt lengths do not provoke huge allocations.
+    fn len(&mut self) -> Result<usize, Error> {
+        let len = self.u32()? as usize;
+        if len > self.input.len() {
+            return invalid();
+        }
+        Ok(len)
+    }
+
+    fn str(&mut self) -> Result<String, Error> {
+        let len = self.len()?;
+        match core::str::from_utf8(self.bytes(len)?) {
+            Ok(s) => Ok(s.into()),
+            Err(_) => invalid(),
+        }
+    }
+
+    fn flags(&mut self) -> Result<api::Flags, Error> {
+        let v = self.u16()?;
+        if v >> 12 != 0 {
+            return invalid();
+        }
+        let bit = |i: u16| v & (1 << i) != 0;
+        Ok(api::Flags {
+            icase: bit(0),
+            multiline: bit(1),
+            dot_all: bit(2),
+            no_opt: bit(3),
+            extended: bit(4),
+            unicode: bit(5),
+            unicode_sets: bit(6),
+            sticky: bit(7),
+            has_indices: bit(8),
+            line_break_escapes: bit(9),
+            word_edge_escapes: bit(10),
+            dot_matches_all: bit(11),
+        })
+    }
+
+    fn word_boundary(&mut self) -> Result<Option<api::WordBoundary>, Error> {
+        Ok(match self.u8()? {
+            0 => None,
+            1 => Some(api::WordBoundary::Ascii),
+            2 => Some(api::WordBoundary::Unicode),
+            3 => match self.brackets()? {
+                BracketContents { invert: false, cps } => Some(api::WordBoundary::Custom(cps)),
+                _ => return invalid(),
+            },
+            _ => return invalid(),
+        })
+    }
+
+    fn brackets(&mut self) -> Result<BracketContents, Error> {
+        let invert = self.bool()?;
+        let count = self.len()?;
+        let mut ivs: Vec<Interval> = Vec::with_capacity(count);
+        for _ in 0..count {
+            let iv = Interval {
+                first: self.u32()?,
+                last: self.u32()?,
+            };
+            // Intervals must be sorted, and may not overlap or even abut.
+            let after_prev = match ivs.last() {
+                Some(prev) => prev.last.checked_add(1).is_some_and(|end| iv.first > end),
+                None => true,
+            };
+            if !after_prev || iv.first > iv.last || iv.last > CODE_POINT_MAX {
+                return invalid();
+            }
+            ivs.push(iv);
+        }
+        Ok(BracketContents {
+            invert,
+            cps: CodePointSet::from_sorted_disjoint_intervals(ivs),
+        })
+    }
+
+    fn property_escape(&mut self) -> Result<PropertyEscape, Error> {
+        let name = match self.u8()? {
+            0 => None,
+            1 => Some(UnicodePropertyName::GeneralCategory),
+            2 => Some(UnicodePropertyName::Script),
+            3 => Some(UnicodePropertyName::ScriptExtensions),
+            4 => Some(UnicodePropertyName::Age),
+            _ => return invalid(),
+        };
+        let tag = self.u8()?;
+        let idx = self.u16()? as usize;
+        let value = match tag {
+            0 => UNICODE_PROPERTY_BINARY_VARIANTS
+                .get(idx)
+                .map(|&v| UnicodePropertyValue::Binary(v)),
+            1 => UNICODE_PROPERTY_VALUE_GENERAL_CATEGORY_VARIANTS
+                .get(idx)
+                .map(|&v| UnicodePropertyValue::GeneralCategory(v)),
+            2 => UNICODE_PROPERTY_VALUE_SCRIPT_VARIANTS
+                .get(idx)
+                .map(|&v| UnicodePropertyValue::Script(v)),
+            3 => UNICODE_PROPERTY_VALUE_AGE_VARIANTS
+                .get(idx)
+                .map(|&v| UnicodePropertyValue::Age(v)),
+            _ => None,
+        };
+        match value {
+            Some(value) => Ok(PropertyEscape { name, value }),
+            None => invalid(),
+        }
+    }
+
+    /// Read a start predicate. A match is tried at each position which this
+    /// finds, so it must not admit a UTF-8 continuation byte.
+    fn start_pred(&mut self) -> Result<StartPredicate, Error> {
+        let pred = match self.u8()? {
+            0 => StartPredicate::Arbitrary,
+            1 => StartPredicate::ByteSeq1(self.array()?),
+            2 => StartPredicate::ByteSeq2(self.array()?),
+            3 => StartPredicate::ByteSeq3(self.array()?),
+            4 => StartPredicate::ByteSeq4(self.array()?),
+            5 => StartPredicate::ByteSet2(self.array()?),
+            6 => StartPredicate::ByteSet3(self.array()?),
+            7 => {
+                let bits: [u8; 32] = self.array()?;
+                let bytes: Vec<u8> = (0..=255u8)
+                    .filter(|&b| bits[(b >> 3) as usize] & (1 << (b & 7)) != 0)
+                    .collect();
+                StartPredicate::ByteBracket(ByteBitmap::new(&bytes))
+            }
+            _ => return invalid(),
+        };
+        let is_continuation = |b: u8| (b & 0xC0) == 0x80;
+        let ok = match &pred {
+            StartPredicate::Arbitrary => true,
+            StartPredicate::ByteSeq1(v) => !is_continuation(v[0]),
+            StartPredicate::ByteSeq2(v) => !is_continuation(v[0]),
+            StartPredicate::ByteSeq3(v) => !is_continuation(v[0]),
+            StartPredicate::ByteSeq4(v) => !is_continuation(v[0]),
+            StartPredicate::ByteSet2(v) => !v.iter().any(|&b| is_continuation(b)),
+            StartPredicate::ByteSet3(v) => !v.iter().any(|&b| is_continuation(b)),
+            StartPredicate::ByteBracket(bm) => !(0x80..=0xBF).any(|b| bm.contains(b)),
+        };
+        if !ok {
+            return invalid();
+        }
+        Ok(pred)
+    }
+
+    fn insn(&mut self) -> Result<Insn, Error> {
+        Ok(match self.u8()? {
+            GOAL => Insn::Goal,
+            CHAR => Insn::Char(self.u32()?),
+            CHAR_ICASE => Insn::CharICase(self.u32()?),
+            START_OF_LINE => Insn::StartOfLine {
+                multiline: self.bool()?,
+            },
+            END_OF_LINE => Insn::EndOfLine {
+                multiline: self.bool()?,
+            },
+            MATCH_ANY => Insn::MatchAny,
+            MATCH_ANY_EXCEPT_LINE_TERMINATOR => Insn::MatchAnyExceptLineTerminator,
+            ENTER_LOOP => Insn::EnterLoop(LoopFields {
+                loop_id: self.u16()?,
+                min_iters: self.usize()?,
+                max_iters: self.usize()?,
+                greedy: self.bool()?,
+                exit: self.u32()?,
+            }),
+            LOOP_AGAIN => Insn::LoopAgain { begin: self.u32()? },
+            LOOP_1CHAR_BODY => Insn::Loop1CharBody {
+                min_iters: self.usize()?,
+                max_iters: self.usize()?,
+                greedy: self.bool()?,
+            },
+            JUMP => Insn::Jump {
+                target: self.u32()?,
+            },
+            ALT => Insn::Alt {
+                secondary: self.u32()?,
+            },
+            BEGIN_CAPTURE_GROUP => Insn::BeginCaptureGroup(self.u16()?),
+            END_CAPTURE_GROUP => Insn::EndCaptureGroup(self.u16()?),
+            RESET_CAPTURE_GROUP => Insn::ResetCaptureGroup(self.u16()?),
+            BACK_REF => Insn::BackRef {
+                group: self.u32()?,
+                icase: self.bool()?,
+            },
+            BRACKET => Insn::Bracket(self.u32()? as usize),
+            ASCII_BRACKET => Insn::AsciiBracket(AsciiBitmap(self.array()?)),
+            LOOKAHEAD => Insn::Lookahead {
+                negate: self.bool()?,
+                start_group: self.u16()?,
+                end_group: self.u16()?,
+                continuation: self.u32()?,
+            },
+            LOOKBEHIND => Insn::Lookbehind {
+                negate: self.bool()?,
+                start_group: self.u16()?,
+                end_group: self.u16()?,
+                continuation: self.u32()?,
+            },
+            ATOMIC_GROUP => Insn::AtomicGroup {
+                start_group: self.u16()?,
+                end_group: self.u16()?,
+                continuation: self.u32()?,
+            },
+            WORD_BOUNDARY => Insn::WordBoundary {
+                invert: self.bool()?,
+                unicode: self.bool()?,
+            },
+            WORD_EDGE => Insn::WordEdge {
+                end: self.bool()?,
+                half: self.bool()?,
+                unicode: self.bool()?,
+            },
+            CHAR_SET => {
+                let mut chars = [0; MAX_CHAR_SET_LENGTH];
+                for c in chars.iter_mut() {
+                    *c = self.u32()?;
+                }
+                Insn::CharSet(chars)
+            }
+            BYTE_SET2 => Insn::ByteSet2(ByteArraySet(self.ascii_array()?)),
+            BYTE_SET3 => Insn::ByteSet3(ByteArraySet(self.ascii_array()?)),
+            BYTE_SET4 => Insn::ByteSet4(ByteArraySet(self.ascii_array()?)),
+            BYTE_SEQ => match self.u8()? {
+                1 => Insn::ByteSeq1(self.utf8_array()?),
+                2 => Insn::ByteSeq2(self.utf8_array()?),
+                3 => Insn::ByteSeq3(self.utf8_array()?),
+                4 => Insn::ByteSeq4(self.utf8_array()?),
+                5 => Insn::ByteSeq5(self.utf8_array()?),
+                6 => Insn::ByteSeq6(self.utf8_array()?),
+                7 => Insn::ByteSeq7(self.utf8_array()?),
+                8 => Insn::ByteSeq8(self.utf8_array()?),
+                9 => Insn::ByteSeq9(self.utf8_array()?),
+                10 => Insn::ByteSeq10(self.utf8_array()?),
+                11 => Insn::ByteSeq11(self.utf8_array()?),
+                12 => Insn::ByteSeq12(self.utf8_array()?),
+                13 => Insn::ByteSeq13(self.utf8_array()?),
+                14 => Insn::ByteSeq14(self.utf8_array()?),
+                15 => Insn::ByteSeq15(self.utf8_array()?),
+                16 => Insn::ByteSeq16(self.utf8_array()?),
+                _ => return invalid(),
+            },
+            UNICODE_PROPERTY_ESCAPE => Insn::UnicodePropertyEscape {
+                property_escape: self.property_escape()?,
+                negate: self.bool()?,
+            },
+            JUST_FAIL => Insn::JustFail,
+            _ => return invalid(),
+        })
+    }
+}
+
+/// \return whether \p insn always matches exactly one character, and so may be
+/// the body of a Loop1CharBody.
+fn is_single_char(insn: &Insn) -> bool {
+    matches!(
+        insn,
+        Insn::Char(..)
+            | Insn::CharICase(..)
+            | Insn::Bracket(..)
+            | Insn::AsciiBracket(..)
+            | Insn::MatchAny
+            | Insn::MatchAnyExceptLineTerminator
+            | Insn::CharSet(..)
+            | Insn::ByteSet2(..)
+            | Insn::ByteSet3(..)
+            | Insn::ByteSet4(..)
+            | Insn::ByteSeq1(..)
+            | Insn::ByteSeq2(..)
+            | Insn::ByteSeq3(..)
+            | Insn::ByteSeq4(..)
+            | Insn::ByteSeq5(..)
+            | Insn::ByteSeq6(..)
+    )
+}
+
+/// Check the invariants which the emitter guarantees and the matchers rely
+/// on: every group, loop and bracket refers to something which exists, and
+/// control cannot run off the end of the program. Jumps only go forwards,
+/// except from the end of a loop to its beginning, so that a corrupt program
+/// cannot cycle forever.
+/// Capture groups nest, and no jump leaves or enters a group, so that the end
+/// of a group is only reached after its beginning.
+fn validate(cr: &CompiledRegex) -> Result<(), Error> {
+    let insns = &cr.insns;
+    let group_ok = |g: u16| u32::from(g) < cr.groups;
+    let span_ok = |start: u16, end: u16| start <= end && u32::from(end) <= cr.groups;
+    if !matches!(insns.last(), Some(Insn::Goal)) {
+        return invalid();
+    }
+
+    // scopes[ip] identifies the innermost group which is open at ip, where
+    // each BeginCaptureGroup opens a distinct scope and 0 is the top level.
+    let mut scopes = Vec::with_capacity(insns.len());
+    let mut open: Vec<(u16, usize)> = Vec::new();
+    let mut scope_count = 0;
+    for insn in insns {
+        scopes.push(open.last().map_or(0, |&(_, scope)| scope));
+        match *insn {
+            Insn::BeginCaptureGroup(id) => {
+                scope_count += 1;
+                open.push((id, scope_count));
+            }
+            Insn::EndCaptureGroup(id) => match open.pop() {
+                Some((open_id, _)) if open_id == id => (),
+                _ => return invalid(),
+            },
+            _ => (),
+        }
+    }
+    if !open.is_empty() {
+        return invalid();
+    }
+
+    for (ip, insn) in insns.iter().enumerate() {
+        let forward_ok = |t: u32| {
+            ip < t as usize && (t as usize) < insns.len() && scopes[t as usize] == scopes[ip]
+        };
+        let ok = match insn {
+            Insn::EnterLoop(fields) => {
+                u32::from(fields.loop_id) < cr.loops
+                    && fields.min_iters <= fields.max_iters
+                    && forward_ok(fields.exit)
+                    && matches!(
+                        insns[fields.exit as usize - 1],
+                        Insn::LoopAgain { begin } if begin as usize == ip
+                    )
+            }
+            &Insn::LoopAgain { begin } => match insns.get(begin as usize) {
+                Some(Insn::EnterLoop(fields)) => fields.exit as usize == ip + 1,
+                _ => false,
+            },
+            &Insn::Loop1CharBody {
+                min_iters,
+                max_iters,
+                ..
+            } => min_iters <= max_iters && insns.get(ip + 1).is_some_and(is_single_char),
+            &Insn::Jump { target } => forward_ok(target),
+            &Insn::Alt { secondary } => forward_ok(secondary),
+            &Insn::BeginCaptureGroup(id)
+            | &Insn::EndCaptureGroup(id)
+            | &Insn::ResetCaptureGroup(id) => group_ok(id),
+            &Insn::BackRef { group, .. } => group < cr.groups,
+            &Insn::Bracket(idx) => idx < cr.brackets.len(),
+            &Insn::Lookahead {
+                start_group,
+                end_group,
+                continuation,
+                ..
+            }
+            | &Insn::Lookbehind {
+                start_group,
+                end_group,
+                continuation,
+                ..
+            }
+            | &Insn::AtomicGroup {
+                start_group,
+                end_group,
+                continuation,
+            } => span_ok(start_group, end_group) && forward_ok(continuation),
+            _ => true,
+        };
+        if !ok {
+            return invalid();
+        }
+    }
+    if cr.named_group_indices.iter().any(|(_, idx)| !group_ok(idx)) {
+        return invalid();
+    }
+    Ok(())
+}
+
+/// \return a compiled regex from the output of serialize(), or an Error if it
+/// is malformed or was produced by an incompatible version.
+pub fn deserialize(bytes: &[u8]) -> Result<CompiledRegex, Error> {
+    let mut r = Reader { input: bytes };
+    if r.bytes(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+        return error("Not a serialized program");
+    }
+    let version = r.u32()?;
+    if version != FORMAT_VERSION {
+        return error(format!(
+            "Unsupported serialized program version {} (expected {})",
+            version, FORMAT_VERSION
+        ));
+    }
+    if r.str()? != UNICODE_VERSION {
+        return error("Serialized program uses a different Unicode version");
+    }
+
+    let flags = r.flags()?;
+    let word_boundary = r.word_boundary()?;
+    let loops = r.u32()?;
+    let groups = r.u32()?;
+    if loops as usize > MAX_LOOPS || groups as usize > MAX_CAPTURE_GROUPS {
+        return invalid();
+    }
+
+    let name_count = r.len()?;
+    let mut named_group_indices = GroupNames::default();
+    for _ in 0..name_count {
+        let name = r.str()?;
+        let idx = r.u16()?;
+        if !named_group_indices.insert(name, idx) {
+            return invalid();
+        }
+    }
+
+    let start_pred = r.start_pred()?;
+    let min_length = r.usize()?;
+    let max_length = if r.bool()? { Some(r.usize()?) } else { None };
+    if max_length.is_some_and(|max| max < min_length) {
+        return invalid();
+    }
+
+    let bracket_count = r.len()?;
+    let mut brackets = Vec::with_capacity(bracket_count);
+    for _ in 0..bracket_count {
+        brackets.push(CompiledBracket::new(r.brackets()?));
+    }
+
+    let insn_count = r.len()?;
+    let mut insns = Vec::with_capacity(insn_count);
+    for _ in 0..insn_count {
+        insns.push(r.insn()?);
+    }
+    if !r.input.is_empty() {
+        return invalid();
+    }
+
+    let cr = CompiledRegex {
+        insns,
+        brackets,
+        start_pred,
+        loops,
+        groups,
+        named_group_indices,
+        flags,
+        match_length_bounds: (min_length, max_length),
+        word_boundary,
+        dfa_caches: Default::default(),
+        utf16_program: Default::default(),
+        ascii_folded_program: Default::default(),
+    };
+    validate(&cr)?;
+    Ok(cr)
+}
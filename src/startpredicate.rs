@@ -1,27 +1,30 @@
+//! Support for quickly finding potential match locations.
+
 use crate::bytesearch::ByteBitmap;
 use crate::codepointset;
 use crate::insn::StartPredicate;
 use crate::ir;
 use crate::ir::Node;
+use crate::unicode;
 use crate::util::utf8_first_byte;
 #[cfg(not(feature = "std"))]
 use alloc::{boxed::Box, vec::Vec};
 use core::cmp;
 use core::convert::TryInto;
 
-/// Support for quickly finding potential match locations.
-
 /// Convert the code point set to a first-byte bitmap.
 /// That is, make a list of all of the possible first bytes of every contained
 /// code point, and store that in a bitmap.
 fn cps_to_first_byte_bitmap(input: &codepointset::CodePointSet) -> Box<ByteBitmap> {
     // Rather than walking over all contained codepoints, which could be very
     // expensive, we perform searches over all bytes.
-    // Note that UTF-8 first-bytes increase monotone and contiguously.
-    // Increasing a code point will either increase the first byte by 1 or 0.
+    // Note that UTF-8 first-bytes increase monotone and contiguously, except
+    // that no first byte is a continuation byte 0x80-0xBF. Increasing a code
+    // point will either increase the first byte by 1 or 0, or skip that gap.
+    // Skip it here too, so that a match is never tried inside a character.
     let mut bitmap = Box::<ByteBitmap>::default();
     let mut ivs = input.intervals();
-    for target_byte in 0..=255 {
+    for target_byte in (0..=0x7F).chain(0xC0..=0xFF) {
         let search = ivs.binary_search_by(|iv| {
             let left_byte = utf8_first_byte(iv.first);
             let right_byte = utf8_first_byte(iv.last);
@@ -111,12 +114,28 @@ impl AbstractStartPredicate {
                 0 => StartPredicate::Arbitrary,
                 1 => StartPredicate::ByteSeq1(bm.to_vec()[..].try_into().unwrap()),
                 2 => StartPredicate::ByteSet2(bm.to_vec()[..].try_into().unwrap()),
+                3 => StartPredicate::ByteSet3(bm.to_vec()[..].try_into().unwrap()),
                 _ => StartPredicate::ByteBracket(*bm),
             },
         }
     }
 }
 
+/// \return the UTF-8 bytes which a node must match exactly, if it is a
+/// case-sensitive literal.
+fn literal_bytes(n: &Node) -> Option<Vec<u8>> {
+    match n {
+        Node::ByteSequence(bytevec) => Some(bytevec.clone()),
+        &Node::Char { c, icase: false } => {
+            // Surrogates have no UTF-8 encoding; let these fall back.
+            let c = char::from_u32(c)?;
+            let mut buff = [0; 4];
+            Some(c.encode_utf8(&mut buff).as_bytes().to_vec())
+        }
+        _ => None,
+    }
+}
+
 /// Compute any start-predicate for a node..
 /// If this returns None, then the instruction is conceptually zero-width (e.g.
 /// lookahead assertion) and does not contribute to the predicate.
@@ -131,7 +150,7 @@ fn compute_start_predicate(n: &Node) -> Option<AbstractStartPredicate> {
 
         Node::Empty => arbitrary,
         Node::Goal => arbitrary,
-        Node::BackRef(..) => arbitrary,
+        Node::BackRef { .. } => arbitrary,
 
         Node::CharSet(chars) => {
             // Pick the first bytes out.
@@ -144,13 +163,41 @@ fn compute_start_predicate(n: &Node) -> Option<AbstractStartPredicate> {
             ))))
         }
 
-        // We assume that most char nodes have been optimized to ByteSeq or AnyBytes2, so skip
-        // these.
-        // TODO: we could support icase through bitmap of de-folded first bytes.
-        Node::Char { .. } => arbitrary,
+        // Most char nodes will have been optimized to ByteSeq or CharSet, but not if the
+        // optimizer is disabled. Case-insensitive chars use the first bytes of every
+        // char which folds to the same value.
+        &Node::Char { c, icase } => {
+            if icase {
+                let bytes = unicode::unfold_char(c)
+                    .into_iter()
+                    .map(utf8_first_byte)
+                    .collect::<Vec<_>>();
+                Some(AbstractStartPredicate::Set(Box::new(ByteBitmap::new(
+                    &bytes,
+                ))))
+            } else {
+                literal_bytes(n)
+                    .map(AbstractStartPredicate::Sequence)
+                    .or(arbitrary)
+            }
+        }
 
-        // Cats return the first non-None value, if any.
-        Node::Cat(nodes) => nodes.iter().filter_map(compute_start_predicate).next(),
+        // Cats join any leading literals into a single sequence.
+        // Otherwise they return the first non-None value, if any.
+        Node::Cat(nodes) => {
+            let mut seq = Vec::new();
+            for bytes in nodes.iter().map_while(literal_bytes) {
+                seq.extend_from_slice(&bytes);
+                if seq.len() >= 4 {
+                    break;
+                }
+            }
+            if !seq.is_empty() {
+                Some(AbstractStartPredicate::Sequence(seq))
+            } else {
+                nodes.iter().filter_map(compute_start_predicate).next()
+            }
+        }
 
         // MatchAny (aka .) is too common to do a fast prefix search for.
         Node::MatchAny => arbitrary,
@@ -159,14 +206,16 @@ fn compute_start_predicate(n: &Node) -> Option<AbstractStartPredicate> {
         Node::MatchAnyExceptLineTerminator => arbitrary,
 
         // TODO: can probably exploit some of these.
-        Node::Anchor(..) => arbitrary,
-        Node::WordBoundary { .. } => arbitrary,
+        Node::Anchor { .. } => arbitrary,
+        Node::WordBoundary { .. } | Node::WordEdge { .. } => arbitrary,
 
         // Capture groups delegate to their contents.
         Node::CaptureGroup(child, ..) | Node::NamedCaptureGroup(child, ..) => {
             compute_start_predicate(child)
         }
 
+        Node::AtomicGroup { contents, .. } => compute_start_predicate(contents),
+
         // Zero-width assertions are one of the few instructions that impose no start predicate.
         Node::LookaroundAssertion { .. } => None,
 
@@ -226,3 +275,46 @@ pub fn predicate_for_re(re: &ir::Regex) -> StartPredicate {
         .unwrap_or(AbstractStartPredicate::Arbitrary)
         .resolve_to_insn()
 }
+
+/// Append to \p prefix the bytes which every match of \p n must begin with.
+/// \return whether \p n matches exactly those bytes, so that a following node
+/// may extend the prefix.
+fn extend_required_prefix(n: &Node, prefix: &mut Vec<u8>) -> bool {
+    match n {
+        Node::Cat(nodes) => nodes
+            .iter()
+            .all(|node| extend_required_prefix(node, prefix)),
+        Node::CaptureGroup(child, ..) | Node::NamedCaptureGroup(child, ..) => {
+            extend_required_prefix(child, prefix)
+        }
+        Node::AtomicGroup { contents, .. } => extend_required_prefix(contents, prefix),
+        Node::Empty => true,
+
+        // Only the first iteration is known to begin here.
+        Node::Loop { loopee, quant, .. } | Node::Loop1CharBody { loopee, quant } => {
+            if quant.min > 0 {
+                extend_required_prefix(loopee, prefix);
+            }
+            false
+        }
+
+        // Alternations, classes and assertions end the prefix, and so do
+        // case-insensitive chars, which the optimizer leaves only if they have
+        // several case forms.
+        _ => match literal_bytes(n) {
+            Some(bytes) => {
+                prefix.extend_from_slice(&bytes);
+                true
+            }
+            None => false,
+        },
+    }
+}
+
+/// \return the UTF-8 bytes which every match of an optimized Regex must begin
+/// with. This is empty if there is no such literal prefix.
+pub fn required_prefix(re: &ir::Regex) -> Vec<u8> {
+    let mut prefix = Vec::new();
+    extend_required_prefix(&re.node, &mut prefix);
+    prefix
+}
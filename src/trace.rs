@@ -0,0 +1,70 @@
+//! Step logs from the backtracking engine, for diagnosing why a pattern does
+//! or does not match.
+
+use crate::insn::Insn;
+use core::fmt;
+
+/// An instruction of a compiled regex, as reported in a [`TraceEvent`]. Its
+/// `Display` output describes the instruction, but is not stable.
+#[derive(Copy, Clone)]
+pub struct Instruction<'a>(pub(crate) &'a Insn);
+
+impl fmt::Display for Instruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl fmt::Debug for Instruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+/// A step of the backtracking engine, as passed to the callback of
+/// [`crate::Regex::find_traced`].
+///
+/// Positions are byte offsets into the text. `depth` is the number of entries
+/// on the backtrack stack, so pushes show up as an increase between steps.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum TraceEvent<'a> {
+    /// A match attempt begins at `pos`.
+    Attempt { pos: usize },
+
+    /// The instruction `insn` at index `ip` is about to run at `pos`.
+    Step {
+        ip: usize,
+        pos: usize,
+        insn: Instruction<'a>,
+        depth: usize,
+    },
+
+    /// A backtrack entry was popped, resuming at instruction `ip` and `pos`.
+    /// `depth` is what remains on the stack.
+    Backtrack { ip: usize, pos: usize, depth: usize },
+
+    /// Capture group `group` was updated; `group` agrees with
+    /// [`crate::Match::group`]. `start` and `end` are None if that side of
+    /// the group is unset, as when the group is reset or only entered.
+    Capture {
+        group: usize,
+        start: Option<usize>,
+        end: Option<usize>,
+    },
+
+    /// The match attempt at `start` reached the end of the regex at `end`.
+    Matched { start: usize, end: usize },
+
+    /// The match attempt at `pos` failed.
+    Failed { pos: usize },
+}
+
+/// The callback receiving trace events.
+pub(crate) struct Tracer<'a>(pub(crate) &'a mut dyn FnMut(&TraceEvent<'_>));
+
+impl fmt::Debug for Tracer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Tracer")
+    }
+}
@@ -23,6 +23,8 @@ pub enum CharacterClassType {
     Digits,
     Spaces,
     Words,
+    /// Unicode word characters, used for \w under the 'u' and 'v' flags.
+    UnicodeWords,
 }
 
 /// The stuff in a bracket.
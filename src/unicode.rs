@@ -72,6 +72,18 @@ impl CodePointRangeUnpacked {
         CodePointRangeUnpacked(start, end)
     }
 
+    // \return the first codepoint in the range.
+    #[inline(always)]
+    pub const fn first(self) -> u32 {
+        self.0
+    }
+
+    // \return the last codepoint in the range.
+    #[inline(always)]
+    pub const fn last(self) -> u32 {
+        self.1
+    }
+
     // Compares the range to a single codepoint.
     #[inline(always)]
     pub fn compare(self, cp: u32) -> Ordering {
@@ -85,6 +97,65 @@ impl CodePointRangeUnpacked {
     }
 }
 
+/// CodePointTable is how the tables of a property value are combined, so that
+/// its code points may be listed without testing each one.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum CodePointTable {
+    Packed(&'static [CodePointRange]),
+    Unpacked(&'static [CodePointRangeUnpacked]),
+    /// The code points in any of the tables.
+    Union(&'static [CodePointTable]),
+    /// The code points not in the table.
+    Complement(&'static CodePointTable),
+}
+
+impl CodePointTable {
+    /// \return the set of code points in the table.
+    fn code_points(self) -> CodePointSet {
+        // Abutting ranges must be merged to form a set.
+        fn from_ranges(ranges: impl Iterator<Item = (u32, u32)>) -> CodePointSet {
+            let mut ivs: Vec<Interval> = Vec::new();
+            for (first, last) in ranges {
+                match ivs.last_mut() {
+                    Some(iv) if iv.last + 1 == first => iv.last = last,
+                    _ => ivs.push(Interval { first, last }),
+                }
+            }
+            CodePointSet::from_sorted_disjoint_intervals(ivs)
+        }
+        match self {
+            CodePointTable::Packed(ranges) => {
+                from_ranges(ranges.iter().map(|r| (r.first(), r.last())))
+            }
+            CodePointTable::Unpacked(ranges) => {
+                from_ranges(ranges.iter().map(|r| (r.first(), r.last())))
+            }
+            CodePointTable::Union(tables) => {
+                let mut cps = CodePointSet::new();
+                for table in tables {
+                    cps.add_set(table.code_points());
+                }
+                cps
+            }
+            CodePointTable::Complement(table) => table.code_points().inverted(),
+        }
+    }
+
+    /// \return whether the table holds the code point \p cp.
+    fn contains(self, cp: u32) -> bool {
+        match self {
+            CodePointTable::Packed(ranges) => {
+                ranges.binary_search_by(|&cpr| cpr.compare(cp)).is_ok()
+            }
+            CodePointTable::Unpacked(ranges) => {
+                ranges.binary_search_by(|&cpr| cpr.compare(cp)).is_ok()
+            }
+            CodePointTable::Union(tables) => tables.iter().any(|&table| table.contains(cp)),
+            CodePointTable::Complement(&table) => !table.contains(cp),
+        }
+    }
+}
+
 // The "extra" field contains a predicate mask in the low bits and a signed delta amount in the high bits.
 // A code point only transforms if its difference from the range base is 0 once masked.
 const PREDICATE_MASK_BITS: u32 = 4;
@@ -100,7 +171,10 @@ pub(crate) struct FoldRange {
 impl FoldRange {
     #[inline(always)]
     pub const fn from(start: u32, length: u32, delta: i32, modulo: u8) -> Self {
-        let mask = (1 << modulo) - 1;
+        // The modulo is the stride between transformed code points, which is a
+        // power of 2.
+        const_assert_true!(modulo.is_power_of_two());
+        let mask = (modulo as i32) - 1;
         const_assert_true!(mask < (1 << PREDICATE_MASK_BITS));
         const_assert_true!(((delta << PREDICATE_MASK_BITS) >> PREDICATE_MASK_BITS) == delta);
         let extra = mask | (delta << PREDICATE_MASK_BITS);
@@ -126,7 +200,7 @@ impl FoldRange {
 
     #[inline(always)]
     fn predicate_mask(&self) -> u32 {
-        (self.extra as u32) & PREDICATE_MASK_BITS
+        (self.extra as u32) & ((1 << PREDICATE_MASK_BITS) - 1)
     }
 
     fn add_delta(&self, cu: u32) -> u32 {
@@ -261,6 +335,52 @@ pub fn unfold_char(c: u32) -> Vec<u32> {
     res
 }
 
+/// \return the simple case folding of each character of \p input.
+/// ES2024 22.2.2.9.3 MaybeSimpleCaseFolding, where folding applies.
+pub fn simple_case_folded(input: &CodePointSet) -> CodePointSet {
+    let mut folds = CodePointSet::new();
+    let mut folded_away = CodePointSet::new();
+    for iv in input.intervals() {
+        let overlaps = FOLDS.equal_range_by(|tr| {
+            if tr.first() > iv.last {
+                Ordering::Greater
+            } else if tr.last() < iv.first {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        });
+        for fr in &FOLDS[overlaps] {
+            let first_trans = core::cmp::max(fr.first(), iv.first);
+            let last_trans = core::cmp::min(fr.last(), iv.last);
+            for cu in first_trans..(last_trans + 1) {
+                let cs = fr.apply(cu);
+                if cs != cu {
+                    folds.add_one(cs);
+                    folded_away.add_one(cu);
+                }
+            }
+        }
+    }
+    let mut result = input.difference(&folded_away);
+    result.add_set(folds);
+    result
+}
+
+/// \return the code points which are their own simple case folding.
+/// ES2024 22.2.2.9.4 AllCharacters, with the 'v' and 'i' flags.
+pub fn fold_invariant_code_points() -> CodePointSet {
+    let mut folded_away = CodePointSet::new();
+    for fr in FOLDS.iter() {
+        for cu in fr.transformed_from().codepoints() {
+            if fr.apply(cu) != cu {
+                folded_away.add_one(cu);
+            }
+        }
+    }
+    folded_away.inverted()
+}
+
 // Fold every character in \p input, then find all the prefolds.
 pub fn fold_code_points(mut input: CodePointSet) -> CodePointSet {
     let mut folded = input.clone();
@@ -276,6 +396,10 @@ pub fn fold_code_points(mut input: CodePointSet) -> CodePointSet {
     input
 }
 
+/// The version of Unicode from which the property and case folding tables are
+/// derived, as "major.minor.update".
+pub const UNICODE_VERSION: &str = "13.0.0";
+
 #[derive(Debug, Copy, Clone)]
 pub struct PropertyEscape {
     pub name: Option<UnicodePropertyName>,
@@ -284,6 +408,7 @@ pub struct PropertyEscape {
 
 #[derive(Debug, Copy, Clone)]
 pub enum UnicodePropertyName {
+    Age,
     GeneralCategory,
     Script,
     ScriptExtensions,
@@ -293,6 +418,7 @@ pub fn unicode_property_name_from_str(s: &str) -> Option<UnicodePropertyName> {
     use UnicodePropertyName::*;
 
     match s {
+        "Age" | "age" => Some(Age),
         "General_Category" | "gc" => Some(GeneralCategory),
         "Script" | "sc" => Some(Script),
         "Script_Extensions" | "scx" => Some(ScriptExtensions),
@@ -302,6 +428,7 @@ pub fn unicode_property_name_from_str(s: &str) -> Option<UnicodePropertyName> {
 
 #[derive(Debug, Copy, Clone)]
 pub enum UnicodePropertyValue {
+    Age(unicodetables::UnicodePropertyValueAge),
     Binary(unicodetables::UnicodePropertyBinary),
     GeneralCategory(unicodetables::UnicodePropertyValueGeneralCategory),
     Script(unicodetables::UnicodePropertyValueScript),
@@ -317,9 +444,311 @@ pub fn unicode_property_value_from_str(s: &str) -> Option<UnicodePropertyValue>
     }
 }
 
+/// \return whether \p c is a Unicode word character: a letter, mark, decimal
+/// number or connector punctuation.
+pub(crate) fn is_word_char(c: u32) -> bool {
+    match char::from_u32(c) {
+        Some(c) if c.is_ascii() => c.is_ascii_alphanumeric() || c == '_',
+        Some(c) => {
+            unicodetables::is_letter(c)
+                || unicodetables::is_mark(c)
+                || unicodetables::is_decimal_number(c)
+                || unicodetables::is_connector_punctuation(c)
+        }
+        None => false,
+    }
+}
+
+/// \return the set of Unicode word characters, as tested by is_word_char.
+pub(crate) fn word_char_code_points() -> CodePointSet {
+    let tables: [&[CodePointRange]; 10] = [
+        &unicodetables::LOWERCASE_LETTER,
+        &unicodetables::MODIFIER_LETTER,
+        &unicodetables::OTHER_LETTER,
+        &unicodetables::TITLECASE_LETTER,
+        &unicodetables::UPPERCASE_LETTER,
+        &unicodetables::ENCLOSING_MARK,
+        &unicodetables::NONSPACING_MARK,
+        &unicodetables::SPACING_MARK,
+        &unicodetables::DECIMAL_NUMBER,
+        &unicodetables::CONNECTOR_PUNCTUATION,
+    ];
+    let mut ivs: Vec<Interval> = tables
+        .iter()
+        .flat_map(|table| table.iter())
+        .map(|cpr| Interval {
+            first: cpr.first(),
+            last: cpr.last(),
+        })
+        .collect();
+    ivs.sort_unstable_by_key(|iv| iv.first);
+
+    // The categories are disjoint, but their ranges may abut.
+    let mut merged: Vec<Interval> = Vec::with_capacity(ivs.len());
+    for iv in ivs {
+        match merged.last_mut() {
+            Some(last) if last.last + 1 == iv.first => last.last = iv.last,
+            _ => merged.push(iv),
+        }
+    }
+    CodePointSet::from_sorted_disjoint_intervals(merged)
+}
+
+/// The Grapheme_Cluster_Break property of a character, from UAX #29.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum GraphemeBreak {
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    Other,
+}
+
+/// The characters with Grapheme_Cluster_Break=Prepend. These are the
+/// Prepended_Concatenation_Mark characters and the Indic consonants which
+/// precede a repha or are prefixed, which we do not otherwise have tables for.
+const GRAPHEME_PREPEND: [CodePointRange; 13] = [
+    CodePointRange::from(0x600, 6),
+    CodePointRange::from(0x6DD, 1),
+    CodePointRange::from(0x70F, 1),
+    CodePointRange::from(0x8E2, 1),
+    CodePointRange::from(0xD4E, 1),
+    CodePointRange::from(0x110BD, 1),
+    CodePointRange::from(0x110CD, 1),
+    CodePointRange::from(0x111C2, 2),
+    CodePointRange::from(0x1193F, 1),
+    CodePointRange::from(0x11941, 1),
+    CodePointRange::from(0x11A3A, 1),
+    CodePointRange::from(0x11A84, 6),
+    CodePointRange::from(0x11D46, 1),
+];
+
+/// The spacing marks which do not have Grapheme_Cluster_Break=SpacingMark.
+const NON_GRAPHEME_SPACING_MARK: [CodePointRange; 13] = [
+    CodePointRange::from(0x102B, 2),
+    CodePointRange::from(0x1038, 1),
+    CodePointRange::from(0x1062, 3),
+    CodePointRange::from(0x1067, 7),
+    CodePointRange::from(0x1083, 1),
+    CodePointRange::from(0x1087, 6),
+    CodePointRange::from(0x108F, 1),
+    CodePointRange::from(0x109A, 3),
+    CodePointRange::from(0x1A61, 1),
+    CodePointRange::from(0x1A63, 2),
+    CodePointRange::from(0xAA7B, 1),
+    CodePointRange::from(0xAA7D, 1),
+    CodePointRange::from(0x11720, 2),
+];
+
+/// \return the Grapheme_Cluster_Break property of \p c, derived from its
+/// other properties as described by UAX #29.
+pub(crate) fn grapheme_break(c: char) -> GraphemeBreak {
+    let in_table =
+        |table: &[CodePointRange]| table.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok();
+    match c {
+        '\r' => return GraphemeBreak::CR,
+        '\n' => return GraphemeBreak::LF,
+        '\u{200D}' => return GraphemeBreak::ZWJ,
+        // Hangul syllables are LV if they have no trailing consonant.
+        '\u{AC00}'..='\u{D7A3}' => {
+            return match (c as u32 - 0xAC00) % 28 {
+                0 => GraphemeBreak::LV,
+                _ => GraphemeBreak::LVT,
+            }
+        }
+        '\u{1100}'..='\u{115F}' | '\u{A960}'..='\u{A97C}' => return GraphemeBreak::L,
+        '\u{1160}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7C6}' => return GraphemeBreak::V,
+        '\u{11A8}'..='\u{11FF}' | '\u{D7CB}'..='\u{D7FB}' => return GraphemeBreak::T,
+        '\u{E33}' | '\u{EB3}' => return GraphemeBreak::SpacingMark,
+        _ if c.is_ascii() => {
+            return match c.is_ascii_control() {
+                true => GraphemeBreak::Control,
+                false => GraphemeBreak::Other,
+            }
+        }
+        _ => {}
+    }
+    if unicodetables::is_grapheme_extend(c) || unicodetables::is_emoji_modifier(c) {
+        GraphemeBreak::Extend
+    } else if unicodetables::is_regional_indicator(c) {
+        GraphemeBreak::RegionalIndicator
+    } else if in_table(&GRAPHEME_PREPEND) {
+        GraphemeBreak::Prepend
+    } else if unicodetables::is_spacing_mark(c) && !in_table(&NON_GRAPHEME_SPACING_MARK) {
+        GraphemeBreak::SpacingMark
+    } else if unicodetables::is_control(c)
+        || unicodetables::is_line_separator(c)
+        || unicodetables::is_paragraph_separator(c)
+        || (unicodetables::is_format(c) && c != '\u{200C}')
+        || (unicodetables::is_unassigned(c) && unicodetables::is_default_ignorable_code_point(c))
+    {
+        GraphemeBreak::Control
+    } else {
+        GraphemeBreak::Other
+    }
+}
+
+/// \return whether the byte offset \p pos of \p text, which must be a char
+/// boundary, is an extended grapheme cluster boundary, following the rules
+/// of UAX #29 for the Unicode version of our tables.
+pub(crate) fn is_grapheme_boundary(text: &str, pos: usize) -> bool {
+    use GraphemeBreak::*;
+    let (before, after) = text.split_at(pos);
+    let (prev, next) = match (before.chars().next_back(), after.chars().next()) {
+        (Some(prev), Some(next)) => (prev, next),
+        // GB1, GB2: break at the start and end of text.
+        _ => return true,
+    };
+    match (grapheme_break(prev), grapheme_break(next)) {
+        // GB3 to GB5: CR LF is a cluster, and controls are alone.
+        (CR, LF) => false,
+        (CR | LF | Control, _) | (_, CR | LF | Control) => true,
+        // GB6 to GB8: Hangul syllable sequences.
+        (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => false,
+        // GB9, GB9a, GB9b: extenders and spacing marks attach to what
+        // precedes them, and prepended characters to what follows them.
+        (_, Extend | ZWJ | SpacingMark) | (Prepend, _) => false,
+        // GB11: emoji ZWJ sequences.
+        (ZWJ, _) if unicodetables::is_extended_pictographic(next) => !before
+            .chars()
+            .rev()
+            .skip(1)
+            .find(|&c| grapheme_break(c) != Extend)
+            .is_some_and(unicodetables::is_extended_pictographic),
+        // GB12, GB13: regional indicators pair up, so break after an even
+        // number of them.
+        (RegionalIndicator, RegionalIndicator) => {
+            let run = before
+                .chars()
+                .rev()
+                .take_while(|&c| grapheme_break(c) == RegionalIndicator)
+                .count();
+            run % 2 == 0
+        }
+        // GB999: otherwise, break.
+        _ => true,
+    }
+}
+
+/// \return the range \p range of \p text, whose ends must be char boundaries,
+/// with its start moved back and its end moved forward to the nearest
+/// extended grapheme cluster boundaries.
+pub(crate) fn grapheme_aligned(
+    text: &str,
+    range: core::ops::Range<usize>,
+) -> core::ops::Range<usize> {
+    let mut start = range.start;
+    while !is_grapheme_boundary(text, start) {
+        start -= text[..start].chars().next_back().map_or(0, char::len_utf8);
+    }
+    let mut end = range.end.max(start);
+    while !is_grapheme_boundary(text, end) {
+        end += text[end..].chars().next().map_or(0, char::len_utf8);
+    }
+    start..end
+}
+
+/// \return the table of code points matched by a property escape, or None if
+/// it matches nothing.
+fn property_escape_table(property_escape: &PropertyEscape) -> Option<CodePointTable> {
+    Some(match (property_escape.name, &property_escape.value) {
+        (Some(UnicodePropertyName::Age) | None, UnicodePropertyValue::Age(t)) => {
+            unicodetables::property_value_age_table(t)
+        }
+        (
+            Some(UnicodePropertyName::GeneralCategory) | None,
+            UnicodePropertyValue::GeneralCategory(t),
+        ) => unicodetables::property_value_general_category_table(t),
+        (Some(UnicodePropertyName::Script) | None, UnicodePropertyValue::Script(t)) => {
+            unicodetables::property_value_script_table(t)
+        }
+        (Some(UnicodePropertyName::ScriptExtensions), UnicodePropertyValue::Script(t)) => {
+            unicodetables::property_value_script_extensions_table(t)
+        }
+        (None, UnicodePropertyValue::Binary(t)) => unicodetables::property_binary_table(t),
+        // A value of another property matches nothing, as in is_character_class.
+        _ => return None,
+    })
+}
+
+/// \return the set of code points matched by a property escape.
+/// This includes any surrogates which the property holds, such as for
+/// `\p{Cs}`: these never occur in a str, but may be matched in UTF-16 text.
+pub(crate) fn property_escape_code_points(property_escape: &PropertyEscape) -> CodePointSet {
+    property_escape_table(property_escape).map_or_else(CodePointSet::new, |t| t.code_points())
+}
+
+/// \return the sequences of code points matched by a property of strings
+/// like `\p{RGI_Emoji}`. Some of these are a single code point.
+pub(crate) fn property_of_strings_sequences(
+    value: unicodetables::UnicodePropertyOfStrings,
+) -> impl Iterator<Item = &'static [u32]> {
+    use unicodetables::UnicodePropertyOfStrings::*;
+    let tables: [&'static [&'static [u32]]; 6] = [
+        &unicodetables::BASIC_EMOJI,
+        &unicodetables::EMOJI_KEYCAP_SEQUENCE,
+        &unicodetables::RGI_EMOJI_FLAG_SEQUENCE,
+        &unicodetables::RGI_EMOJI_MODIFIER_SEQUENCE,
+        &unicodetables::RGI_EMOJI_TAG_SEQUENCE,
+        &unicodetables::RGI_EMOJI_ZWJ_SEQUENCE,
+    ];
+    let index = match value {
+        BasicEmoji => Some(0),
+        EmojiKeycapSequence => Some(1),
+        RgiEmojiFlagSequence => Some(2),
+        RgiEmojiModifierSequence => Some(3),
+        RgiEmojiTagSequence => Some(4),
+        RgiEmojiZwjSequence => Some(5),
+        // RGI_Emoji is the union of the others.
+        RgiEmoji => None,
+    };
+    tables
+        .into_iter()
+        .enumerate()
+        .filter(move |&(i, _)| index.is_none_or(|index| i == index))
+        .flat_map(|(_, table)| table.iter().copied())
+}
+
+/// \return the value of a property escape like `\p{name=value}`, or None if
+/// \p value is not a value of the property \p name.
+/// Script_Extensions takes the same values as Script.
+/// Age values like `9.0` match the code points assigned in exactly that
+/// version, as in the UCD.
+pub fn unicode_property_value_for_name(
+    name: UnicodePropertyName,
+    value: &str,
+) -> Option<UnicodePropertyValue> {
+    match name {
+        UnicodePropertyName::Age => {
+            unicodetables::unicode_property_value_age_from_str(value).map(UnicodePropertyValue::Age)
+        }
+        UnicodePropertyName::GeneralCategory => {
+            unicodetables::unicode_property_value_general_category_from_str(value)
+                .map(UnicodePropertyValue::GeneralCategory)
+        }
+        UnicodePropertyName::Script | UnicodePropertyName::ScriptExtensions => {
+            unicodetables::unicode_property_value_script_from_str(value)
+                .map(UnicodePropertyValue::Script)
+        }
+    }
+}
+
 pub(crate) fn is_character_class(c: u32, property_escape: &PropertyEscape) -> bool {
     if let Some(c) = char::from_u32(c) {
         match property_escape.name {
+            Some(UnicodePropertyName::Age) => match &property_escape.value {
+                UnicodePropertyValue::Age(t) => unicodetables::is_property_value_age(c, t),
+                _ => false,
+            },
             Some(UnicodePropertyName::GeneralCategory) => match &property_escape.value {
                 UnicodePropertyValue::GeneralCategory(t) => {
                     unicodetables::is_property_value_general_category(c, t)
@@ -331,10 +760,13 @@ pub(crate) fn is_character_class(c: u32, property_escape: &PropertyEscape) -> bo
                 _ => false,
             },
             Some(UnicodePropertyName::ScriptExtensions) => match &property_escape.value {
-                UnicodePropertyValue::Script(t) => unicodetables::is_property_value_script(c, t),
+                UnicodePropertyValue::Script(t) => {
+                    unicodetables::is_property_value_script_extensions(c, t)
+                }
                 _ => false,
             },
             None => match &property_escape.value {
+                UnicodePropertyValue::Age(t) => unicodetables::is_property_value_age(c, t),
                 UnicodePropertyValue::Binary(t) => unicodetables::is_property_binary(c, t),
                 UnicodePropertyValue::GeneralCategory(t) => {
                     unicodetables::is_property_value_general_category(c, t)
@@ -343,6 +775,7 @@ pub(crate) fn is_character_class(c: u32, property_escape: &PropertyEscape) -> bo
             },
         }
     } else {
-        false
+        // A lone surrogate, from UTF-16 text.
+        property_escape_table(property_escape).is_some_and(|t| t.contains(c))
     }
 }
@@ -1,5 +1,5 @@
 // DO NOT EDIT! This file is autogenerated from gen-unicode.
-use crate::unicode::{CodePointRange, CodePointRangeUnpacked, FoldRange};
+use crate::unicode::{CodePointRange, CodePointRangeUnpacked, CodePointTable, FoldRange};
 
 pub(crate) const ALPHABETIC: [CodePointRange; 756] = [
     CodePointRange::from(65, 26),
@@ -12214,6 +12214,65 @@ pub(crate) fn is_property_binary(c: char, value: &UnicodePropertyBinary) -> bool
     }
 }
 
+pub(crate) fn property_binary_table(value: &UnicodePropertyBinary) -> CodePointTable {
+    use UnicodePropertyBinary::*;
+    match value {
+        Alphabetic => CodePointTable::Packed(&ALPHABETIC),
+        CaseIgnorable => CodePointTable::Packed(&CASE_IGNORABLE),
+        Cased => CodePointTable::Packed(&CASED),
+        ChangesWhenCasefolded => CodePointTable::Packed(&CHANGES_WHEN_CASEFOLDED),
+        ChangesWhenCasemapped => CodePointTable::Packed(&CHANGES_WHEN_CASEMAPPED),
+        ChangesWhenLowercased => CodePointTable::Packed(&CHANGES_WHEN_LOWERCASED),
+        ChangesWhenTitlecased => CodePointTable::Packed(&CHANGES_WHEN_TITLECASED),
+        ChangesWhenUppercased => CodePointTable::Packed(&CHANGES_WHEN_UPPERCASED),
+        DefaultIgnorableCodePoint => CodePointTable::Packed(&DEFAULT_IGNORABLE_CODE_POINT),
+        GraphemeBase => CodePointTable::Packed(&GRAPHEME_BASE),
+        GraphemeExtend => CodePointTable::Packed(&GRAPHEME_EXTEND),
+        IDContinue => CodePointTable::Packed(&ID_CONTINUE),
+        IDStart => CodePointTable::Packed(&ID_START),
+        Math => CodePointTable::Packed(&MATH),
+        XIDContinue => CodePointTable::Packed(&XID_CONTINUE),
+        XIDStart => CodePointTable::Packed(&XID_START),
+        ASCIIHexDigit => CodePointTable::Packed(&ASCII_HEX_DIGIT),
+        BidiControl => CodePointTable::Packed(&BIDI_CONTROL),
+        Dash => CodePointTable::Packed(&DASH),
+        Deprecated => CodePointTable::Packed(&DEPRECATED),
+        Diacritic => CodePointTable::Packed(&DIACRITIC),
+        Extender => CodePointTable::Packed(&EXTENDER),
+        HexDigit => CodePointTable::Packed(&HEX_DIGIT),
+        IDSBinaryOperator => CodePointTable::Packed(&IDS_BINARY_OPERATOR),
+        IDSTrinaryOperator => CodePointTable::Packed(&IDS_TRINARY_OPERATOR),
+        Ideographic => CodePointTable::Packed(&IDEOGRAPHIC),
+        JoinControl => CodePointTable::Packed(&JOIN_CONTROL),
+        LogicalOrderException => CodePointTable::Packed(&LOGICAL_ORDER_EXCEPTION),
+        Lowercase => CodePointTable::Packed(&LOWERCASE),
+        NoncharacterCodePoint => CodePointTable::Unpacked(&NONCHARACTER_CODE_POINT),
+        PatternSyntax => CodePointTable::Packed(&PATTERN_SYNTAX),
+        PatternWhiteSpace => CodePointTable::Packed(&PATTERN_WHITE_SPACE),
+        QuotationMark => CodePointTable::Packed(&QUOTATION_MARK),
+        Radical => CodePointTable::Packed(&RADICAL),
+        RegionalIndicator => CodePointTable::Packed(&REGIONAL_INDICATOR),
+        SentenceTerminal => CodePointTable::Packed(&SENTENCE_TERMINAL),
+        SoftDotted => CodePointTable::Packed(&SOFT_DOTTED),
+        TerminalPunctuation => CodePointTable::Packed(&TERMINAL_PUNCTUATION),
+        UnifiedIdeograph => CodePointTable::Packed(&UNIFIED_IDEOGRAPH),
+        Uppercase => CodePointTable::Packed(&UPPERCASE),
+        VariationSelector => CodePointTable::Packed(&VARIATION_SELECTOR),
+        WhiteSpace => CodePointTable::Packed(&WHITE_SPACE),
+        Emoji => CodePointTable::Packed(&EMOJI),
+        EmojiComponent => CodePointTable::Packed(&EMOJI_COMPONENT),
+        EmojiModifier => CodePointTable::Packed(&EMOJI_MODIFIER),
+        EmojiModifierBase => CodePointTable::Packed(&EMOJI_MODIFIER_BASE),
+        EmojiPresentation => CodePointTable::Packed(&EMOJI_PRESENTATION),
+        ExtendedPictographic => CodePointTable::Packed(&EXTENDED_PICTOGRAPHIC),
+        ChangesWhenNFKCCasefolded => CodePointTable::Packed(&CHANGES_WHEN_NFKC_CASEFOLDED),
+        BidiMirrored => CodePointTable::Packed(&BIDI_MIRRORED),
+        Ascii => CodePointTable::Packed(&ASCII),
+        Any => CodePointTable::Unpacked(&ANY),
+        Assigned => CodePointTable::Complement(&CodePointTable::Unpacked(&UNASSIGNED)),
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum UnicodePropertyBinary {
     Alphabetic,
@@ -12331,6 +12390,63 @@ pub fn unicode_property_binary_from_str(s: &str) -> Option<UnicodePropertyBinary
     }
 }
 
+/// Every UnicodePropertyBinary, in declaration order.
+pub(crate) const UNICODE_PROPERTY_BINARY_VARIANTS: [UnicodePropertyBinary; 53] = [
+    UnicodePropertyBinary::Alphabetic,
+    UnicodePropertyBinary::CaseIgnorable,
+    UnicodePropertyBinary::Cased,
+    UnicodePropertyBinary::ChangesWhenCasefolded,
+    UnicodePropertyBinary::ChangesWhenCasemapped,
+    UnicodePropertyBinary::ChangesWhenLowercased,
+    UnicodePropertyBinary::ChangesWhenTitlecased,
+    UnicodePropertyBinary::ChangesWhenUppercased,
+    UnicodePropertyBinary::DefaultIgnorableCodePoint,
+    UnicodePropertyBinary::GraphemeBase,
+    UnicodePropertyBinary::GraphemeExtend,
+    UnicodePropertyBinary::IDContinue,
+    UnicodePropertyBinary::IDStart,
+    UnicodePropertyBinary::Math,
+    UnicodePropertyBinary::XIDContinue,
+    UnicodePropertyBinary::XIDStart,
+    UnicodePropertyBinary::ASCIIHexDigit,
+    UnicodePropertyBinary::BidiControl,
+    UnicodePropertyBinary::Dash,
+    UnicodePropertyBinary::Deprecated,
+    UnicodePropertyBinary::Diacritic,
+    UnicodePropertyBinary::Extender,
+    UnicodePropertyBinary::HexDigit,
+    UnicodePropertyBinary::IDSBinaryOperator,
+    UnicodePropertyBinary::IDSTrinaryOperator,
+    UnicodePropertyBinary::Ideographic,
+    UnicodePropertyBinary::JoinControl,
+    UnicodePropertyBinary::LogicalOrderException,
+    UnicodePropertyBinary::Lowercase,
+    UnicodePropertyBinary::NoncharacterCodePoint,
+    UnicodePropertyBinary::PatternSyntax,
+    UnicodePropertyBinary::PatternWhiteSpace,
+    UnicodePropertyBinary::QuotationMark,
+    UnicodePropertyBinary::Radical,
+    UnicodePropertyBinary::RegionalIndicator,
+    UnicodePropertyBinary::SentenceTerminal,
+    UnicodePropertyBinary::SoftDotted,
+    UnicodePropertyBinary::TerminalPunctuation,
+    UnicodePropertyBinary::UnifiedIdeograph,
+    UnicodePropertyBinary::Uppercase,
+    UnicodePropertyBinary::VariationSelector,
+    UnicodePropertyBinary::WhiteSpace,
+    UnicodePropertyBinary::Emoji,
+    UnicodePropertyBinary::EmojiComponent,
+    UnicodePropertyBinary::EmojiModifier,
+    UnicodePropertyBinary::EmojiModifierBase,
+    UnicodePropertyBinary::EmojiPresentation,
+    UnicodePropertyBinary::ExtendedPictographic,
+    UnicodePropertyBinary::ChangesWhenNFKCCasefolded,
+    UnicodePropertyBinary::BidiMirrored,
+    UnicodePropertyBinary::Ascii,
+    UnicodePropertyBinary::Any,
+    UnicodePropertyBinary::Assigned,
+];
+
 pub(crate) const FOLDS: [FoldRange; 201] = [
     FoldRange::from(0x41, 26, 32, 1),
     FoldRange::from(0xB5, 1, 775, 1),
@@ -12535,6 +12651,21 @@ pub(crate) const FOLDS: [FoldRange; 201] = [
     FoldRange::from(0x1E900, 34, 34, 1),
 ];
 
+pub(crate) const ADLAM_EXTENSIONS: [CodePointRange; 5] = [
+    CodePointRange::from(1567, 1),
+    CodePointRange::from(1600, 1),
+    CodePointRange::from(125184, 76),
+    CodePointRange::from(125264, 10),
+    CodePointRange::from(125278, 2),
+];
+
+/// Return whether c has 'Adlam' in its Script_Extensions Unicode property.
+pub(crate) fn is_adlam_extensions(c: char) -> bool {
+    ADLAM_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const ADLAM: [CodePointRange; 3] = [
     CodePointRange::from(125184, 76),
     CodePointRange::from(125264, 10),
@@ -12566,6 +12697,68 @@ pub(crate) fn is_anatolian_hieroglyphs(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const ARABIC_EXTENSIONS: [CodePointRange; 52] = [
+    CodePointRange::from(1536, 5),
+    CodePointRange::from(1542, 215),
+    CodePointRange::from(1758, 34),
+    CodePointRange::from(1872, 48),
+    CodePointRange::from(2160, 31),
+    CodePointRange::from(2192, 2),
+    CodePointRange::from(2200, 74),
+    CodePointRange::from(2275, 29),
+    CodePointRange::from(64336, 115),
+    CodePointRange::from(64467, 445),
+    CodePointRange::from(64914, 54),
+    CodePointRange::from(64975, 1),
+    CodePointRange::from(65008, 16),
+    CodePointRange::from(65136, 5),
+    CodePointRange::from(65142, 135),
+    CodePointRange::from(66272, 28),
+    CodePointRange::from(69216, 31),
+    CodePointRange::from(69373, 3),
+    CodePointRange::from(126464, 4),
+    CodePointRange::from(126469, 27),
+    CodePointRange::from(126497, 2),
+    CodePointRange::from(126500, 1),
+    CodePointRange::from(126503, 1),
+    CodePointRange::from(126505, 10),
+    CodePointRange::from(126516, 4),
+    CodePointRange::from(126521, 1),
+    CodePointRange::from(126523, 1),
+    CodePointRange::from(126530, 1),
+    CodePointRange::from(126535, 1),
+    CodePointRange::from(126537, 1),
+    CodePointRange::from(126539, 1),
+    CodePointRange::from(126541, 3),
+    CodePointRange::from(126545, 2),
+    CodePointRange::from(126548, 1),
+    CodePointRange::from(126551, 1),
+    CodePointRange::from(126553, 1),
+    CodePointRange::from(126555, 1),
+    CodePointRange::from(126557, 1),
+    CodePointRange::from(126559, 1),
+    CodePointRange::from(126561, 2),
+    CodePointRange::from(126564, 1),
+    CodePointRange::from(126567, 4),
+    CodePointRange::from(126572, 7),
+    CodePointRange::from(126580, 4),
+    CodePointRange::from(126585, 4),
+    CodePointRange::from(126590, 1),
+    CodePointRange::from(126592, 10),
+    CodePointRange::from(126603, 17),
+    CodePointRange::from(126625, 3),
+    CodePointRange::from(126629, 5),
+    CodePointRange::from(126635, 17),
+    CodePointRange::from(126704, 2),
+];
+
+/// Return whether c has 'Arabic' in its Script_Extensions Unicode property.
+pub(crate) fn is_arabic_extensions(c: char) -> bool {
+    ARABIC_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const ARABIC: [CodePointRange; 58] = [
     CodePointRange::from(1536, 5),
     CodePointRange::from(1542, 6),
@@ -12704,6 +12897,42 @@ pub(crate) fn is_batak(c: char) -> bool {
     BATAK.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const BENGALI_EXTENSIONS: [CodePointRange; 26] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(2432, 4),
+    CodePointRange::from(2437, 8),
+    CodePointRange::from(2447, 2),
+    CodePointRange::from(2451, 22),
+    CodePointRange::from(2474, 7),
+    CodePointRange::from(2482, 1),
+    CodePointRange::from(2486, 4),
+    CodePointRange::from(2492, 9),
+    CodePointRange::from(2503, 2),
+    CodePointRange::from(2507, 4),
+    CodePointRange::from(2519, 1),
+    CodePointRange::from(2524, 2),
+    CodePointRange::from(2527, 5),
+    CodePointRange::from(2534, 25),
+    CodePointRange::from(7376, 1),
+    CodePointRange::from(7378, 1),
+    CodePointRange::from(7381, 2),
+    CodePointRange::from(7384, 1),
+    CodePointRange::from(7393, 1),
+    CodePointRange::from(7402, 1),
+    CodePointRange::from(7405, 1),
+    CodePointRange::from(7410, 1),
+    CodePointRange::from(7413, 3),
+    CodePointRange::from(43249, 1),
+];
+
+/// Return whether c has 'Bengali' in its Script_Extensions Unicode property.
+pub(crate) fn is_bengali_extensions(c: char) -> bool {
+    BENGALI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const BENGALI: [CodePointRange; 14] = [
     CodePointRange::from(2432, 4),
     CodePointRange::from(2437, 8),
@@ -12742,6 +12971,28 @@ pub(crate) fn is_bhaiksuki(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const BOPOMOFO_EXTENSIONS: [CodePointRange; 12] = [
+    CodePointRange::from(746, 2),
+    CodePointRange::from(12289, 3),
+    CodePointRange::from(12296, 10),
+    CodePointRange::from(12307, 13),
+    CodePointRange::from(12330, 4),
+    CodePointRange::from(12336, 1),
+    CodePointRange::from(12343, 1),
+    CodePointRange::from(12539, 1),
+    CodePointRange::from(12549, 43),
+    CodePointRange::from(12704, 32),
+    CodePointRange::from(65093, 2),
+    CodePointRange::from(65377, 5),
+];
+
+/// Return whether c has 'Bopomofo' in its Script_Extensions Unicode property.
+pub(crate) fn is_bopomofo_extensions(c: char) -> bool {
+    BOPOMOFO_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const BOPOMOFO: [CodePointRange; 3] = [
     CodePointRange::from(746, 2),
     CodePointRange::from(12549, 43),
@@ -12777,6 +13028,19 @@ pub(crate) fn is_braille(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const BUGINESE_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(6656, 28),
+    CodePointRange::from(6686, 2),
+    CodePointRange::from(43471, 1),
+];
+
+/// Return whether c has 'Buginese' in its Script_Extensions Unicode property.
+pub(crate) fn is_buginese_extensions(c: char) -> bool {
+    BUGINESE_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const BUGINESE: [CodePointRange; 2] = [
     CodePointRange::from(6656, 28),
     CodePointRange::from(6686, 2),
@@ -12789,6 +13053,18 @@ pub(crate) fn is_buginese(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const BUHID_EXTENSIONS: [CodePointRange; 2] = [
+    CodePointRange::from(5941, 2),
+    CodePointRange::from(5952, 20),
+];
+
+/// Return whether c has 'Buhid' in its Script_Extensions Unicode property.
+pub(crate) fn is_buhid_extensions(c: char) -> bool {
+    BUHID_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const BUHID: [CodePointRange; 1] = [CodePointRange::from(5952, 20)];
 
 /// Return whether c has the 'Buhid' Unicode property.
@@ -12830,6 +13106,20 @@ pub(crate) fn is_caucasian_albanian(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const CHAKMA_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(2534, 10),
+    CodePointRange::from(4160, 10),
+    CodePointRange::from(69888, 53),
+    CodePointRange::from(69942, 18),
+];
+
+/// Return whether c has 'Chakma' in its Script_Extensions Unicode property.
+pub(crate) fn is_chakma_extensions(c: char) -> bool {
+    CHAKMA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const CHAKMA: [CodePointRange; 2] = [
     CodePointRange::from(69888, 53),
     CodePointRange::from(69942, 18),
@@ -12876,7 +13166,7 @@ pub(crate) fn is_cherokee(c: char) -> bool {
         .is_ok()
 }
 
-pub(crate) const COMMON: [CodePointRange; 173] = [
+pub(crate) const COMMON_EXTENSIONS: [CodePointRange; 147] = [
     CodePointRange::from(0, 65),
     CodePointRange::from(91, 6),
     CodePointRange::from(123, 47),
@@ -12892,28 +13182,14 @@ pub(crate) const COMMON: [CodePointRange; 173] = [
     CodePointRange::from(901, 1),
     CodePointRange::from(903, 1),
     CodePointRange::from(1541, 1),
-    CodePointRange::from(1548, 1),
-    CodePointRange::from(1563, 1),
-    CodePointRange::from(1567, 1),
-    CodePointRange::from(1600, 1),
     CodePointRange::from(1757, 1),
     CodePointRange::from(2274, 1),
-    CodePointRange::from(2404, 2),
     CodePointRange::from(3647, 1),
     CodePointRange::from(4053, 4),
-    CodePointRange::from(4347, 1),
     CodePointRange::from(5867, 3),
-    CodePointRange::from(5941, 2),
-    CodePointRange::from(6146, 2),
-    CodePointRange::from(6149, 1),
-    CodePointRange::from(7379, 1),
-    CodePointRange::from(7393, 1),
-    CodePointRange::from(7401, 4),
-    CodePointRange::from(7406, 6),
-    CodePointRange::from(7413, 3),
-    CodePointRange::from(7418, 1),
     CodePointRange::from(8192, 12),
-    CodePointRange::from(8206, 87),
+    CodePointRange::from(8206, 33),
+    CodePointRange::from(8240, 53),
     CodePointRange::from(8294, 11),
     CodePointRange::from(8308, 11),
     CodePointRange::from(8320, 15),
@@ -12930,51 +13206,40 @@ pub(crate) const COMMON: [CodePointRange; 173] = [
     CodePointRange::from(10496, 628),
     CodePointRange::from(11126, 32),
     CodePointRange::from(11159, 105),
-    CodePointRange::from(11776, 94),
+    CodePointRange::from(11776, 67),
+    CodePointRange::from(11844, 26),
     CodePointRange::from(12272, 12),
-    CodePointRange::from(12288, 5),
-    CodePointRange::from(12294, 1),
-    CodePointRange::from(12296, 25),
-    CodePointRange::from(12336, 8),
-    CodePointRange::from(12348, 4),
-    CodePointRange::from(12443, 2),
-    CodePointRange::from(12448, 1),
-    CodePointRange::from(12539, 2),
-    CodePointRange::from(12688, 16),
-    CodePointRange::from(12736, 36),
-    CodePointRange::from(12832, 64),
-    CodePointRange::from(12927, 81),
-    CodePointRange::from(13055, 1),
-    CodePointRange::from(13144, 168),
+    CodePointRange::from(12288, 1),
+    CodePointRange::from(12292, 1),
+    CodePointRange::from(12306, 1),
+    CodePointRange::from(12320, 1),
+    CodePointRange::from(12342, 1),
+    CodePointRange::from(12872, 24),
+    CodePointRange::from(12927, 1),
+    CodePointRange::from(12977, 15),
+    CodePointRange::from(13004, 4),
+    CodePointRange::from(13169, 10),
+    CodePointRange::from(13184, 96),
+    CodePointRange::from(13311, 1),
     CodePointRange::from(19904, 64),
-    CodePointRange::from(42752, 34),
+    CodePointRange::from(42760, 26),
     CodePointRange::from(42888, 3),
-    CodePointRange::from(43056, 10),
-    CodePointRange::from(43310, 1),
-    CodePointRange::from(43471, 1),
     CodePointRange::from(43867, 1),
     CodePointRange::from(43882, 2),
-    CodePointRange::from(64830, 2),
     CodePointRange::from(65040, 10),
-    CodePointRange::from(65072, 35),
+    CodePointRange::from(65072, 21),
+    CodePointRange::from(65095, 12),
     CodePointRange::from(65108, 19),
     CodePointRange::from(65128, 4),
     CodePointRange::from(65279, 1),
     CodePointRange::from(65281, 32),
     CodePointRange::from(65339, 6),
-    CodePointRange::from(65371, 11),
-    CodePointRange::from(65392, 1),
-    CodePointRange::from(65438, 2),
+    CodePointRange::from(65371, 6),
     CodePointRange::from(65504, 7),
     CodePointRange::from(65512, 7),
     CodePointRange::from(65529, 5),
-    CodePointRange::from(65792, 3),
-    CodePointRange::from(65799, 45),
-    CodePointRange::from(65847, 9),
     CodePointRange::from(65936, 13),
     CodePointRange::from(66000, 45),
-    CodePointRange::from(66273, 27),
-    CodePointRange::from(113824, 4),
     CodePointRange::from(118608, 116),
     CodePointRange::from(118784, 246),
     CodePointRange::from(119040, 39),
@@ -12986,7 +13251,7 @@ pub(crate) const COMMON: [CodePointRange; 173] = [
     CodePointRange::from(119488, 20),
     CodePointRange::from(119520, 20),
     CodePointRange::from(119552, 87),
-    CodePointRange::from(119648, 25),
+    CodePointRange::from(119666, 7),
     CodePointRange::from(119808, 85),
     CodePointRange::from(119894, 71),
     CodePointRange::from(119966, 2),
@@ -13021,7 +13286,6 @@ pub(crate) const COMMON: [CodePointRange; 173] = [
     CodePointRange::from(127489, 2),
     CodePointRange::from(127504, 44),
     CodePointRange::from(127552, 9),
-    CodePointRange::from(127568, 2),
     CodePointRange::from(127584, 6),
     CodePointRange::from(127744, 984),
     CodePointRange::from(128732, 17),
@@ -13052,145 +13316,428 @@ pub(crate) const COMMON: [CodePointRange; 173] = [
     CodePointRange::from(917536, 96),
 ];
 
-/// Return whether c has the 'Common' Unicode property.
-pub(crate) fn is_common(c: char) -> bool {
-    COMMON
-        .binary_search_by(|&cpr| cpr.compare(c as u32))
-        .is_ok()
-}
-
-pub(crate) const COPTIC: [CodePointRange; 3] = [
-    CodePointRange::from(994, 14),
-    CodePointRange::from(11392, 116),
-    CodePointRange::from(11513, 7),
-];
-
-/// Return whether c has the 'Coptic' Unicode property.
-pub(crate) fn is_coptic(c: char) -> bool {
-    COPTIC
-        .binary_search_by(|&cpr| cpr.compare(c as u32))
-        .is_ok()
-}
-
-pub(crate) const CUNEIFORM: [CodePointRange; 4] = [
-    CodePointRange::from(73728, 922),
-    CodePointRange::from(74752, 111),
-    CodePointRange::from(74864, 5),
-    CodePointRange::from(74880, 196),
-];
-
-/// Return whether c has the 'Cuneiform' Unicode property.
-pub(crate) fn is_cuneiform(c: char) -> bool {
-    CUNEIFORM
-        .binary_search_by(|&cpr| cpr.compare(c as u32))
-        .is_ok()
-}
-
-pub(crate) const CYPRIOT: [CodePointRange; 6] = [
-    CodePointRange::from(67584, 6),
-    CodePointRange::from(67592, 1),
-    CodePointRange::from(67594, 44),
-    CodePointRange::from(67639, 2),
-    CodePointRange::from(67644, 1),
-    CodePointRange::from(67647, 1),
-];
-
-/// Return whether c has the 'Cypriot' Unicode property.
-pub(crate) fn is_cypriot(c: char) -> bool {
-    CYPRIOT
+/// Return whether c has 'Common' in its Script_Extensions Unicode property.
+pub(crate) fn is_common_extensions(c: char) -> bool {
+    COMMON_EXTENSIONS
         .binary_search_by(|&cpr| cpr.compare(c as u32))
         .is_ok()
 }
 
-pub(crate) const CYRILLIC: [CodePointRange; 10] = [
-    CodePointRange::from(1024, 133),
-    CodePointRange::from(1159, 169),
-    CodePointRange::from(7296, 9),
-    CodePointRange::from(7467, 1),
-    CodePointRange::from(7544, 1),
-    CodePointRange::from(11744, 32),
-    CodePointRange::from(42560, 96),
-    CodePointRange::from(65070, 2),
-    CodePointRange::from(122928, 62),
-    CodePointRange::from(123023, 1),
-];
-
-/// Return whether c has the 'Cyrillic' Unicode property.
-pub(crate) fn is_cyrillic(c: char) -> bool {
-    CYRILLIC
-        .binary_search_by(|&cpr| cpr.compare(c as u32))
-        .is_ok()
-}
-
-pub(crate) const DESERET: [CodePointRange; 1] = [CodePointRange::from(66560, 80)];
+pub(crate) const COMMON: [CodePointRange; 173] = [
+    CodePointRange::from(0, 65),
+    CodePointRange::from(91, 6),
+    CodePointRange::from(123, 47),
+    CodePointRange::from(171, 15),
+    CodePointRange::from(187, 5),
+    CodePointRange::from(215, 1),
+    CodePointRange::from(247, 1),
+    CodePointRange::from(697, 39),
+    CodePointRange::from(741, 5),
+    CodePointRange::from(748, 20),
+    CodePointRange::from(884, 1),
+    CodePointRange::from(894, 1),
+    CodePointRange::from(901, 1),
+    CodePointRange::from(903, 1),
+    CodePointRange::from(1541, 1),
+    CodePointRange::from(1548, 1),
+    CodePointRange::from(1563, 1),
+    CodePointRange::from(1567, 1),
+    CodePointRange::from(1600, 1),
+    CodePointRange::from(1757, 1),
+    CodePointRange::from(2274, 1),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(3647, 1),
+    CodePointRange::from(4053, 4),
+    CodePointRange::from(4347, 1),
+    CodePointRange::from(5867, 3),
+    CodePointRange::from(5941, 2),
+    CodePointRange::from(6146, 2),
+    CodePointRange::from(6149, 1),
+    CodePointRange::from(7379, 1),
+    CodePointRange::from(7393, 1),
+    CodePointRange::from(7401, 4),
+    CodePointRange::from(7406, 6),
+    CodePointRange::from(7413, 3),
+    CodePointRange::from(7418, 1),
+    CodePointRange::from(8192, 12),
+    CodePointRange::from(8206, 87),
+    CodePointRange::from(8294, 11),
+    CodePointRange::from(8308, 11),
+    CodePointRange::from(8320, 15),
+    CodePointRange::from(8352, 33),
+    CodePointRange::from(8448, 38),
+    CodePointRange::from(8487, 3),
+    CodePointRange::from(8492, 6),
+    CodePointRange::from(8499, 27),
+    CodePointRange::from(8527, 17),
+    CodePointRange::from(8585, 3),
+    CodePointRange::from(8592, 663),
+    CodePointRange::from(9280, 11),
+    CodePointRange::from(9312, 928),
+    CodePointRange::from(10496, 628),
+    CodePointRange::from(11126, 32),
+    CodePointRange::from(11159, 105),
+    CodePointRange::from(11776, 94),
+    CodePointRange::from(12272, 12),
+    CodePointRange::from(12288, 5),
+    CodePointRange::from(12294, 1),
+    CodePointRange::from(12296, 25),
+    CodePointRange::from(12336, 8),
+    CodePointRange::from(12348, 4),
+    CodePointRange::from(12443, 2),
+    CodePointRange::from(12448, 1),
+    CodePointRange::from(12539, 2),
+    CodePointRange::from(12688, 16),
+    CodePointRange::from(12736, 36),
+    CodePointRange::from(12832, 64),
+    CodePointRange::from(12927, 81),
+    CodePointRange::from(13055, 1),
+    CodePointRange::from(13144, 168),
+    CodePointRange::from(19904, 64),
+    CodePointRange::from(42752, 34),
+    CodePointRange::from(42888, 3),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(43310, 1),
+    CodePointRange::from(43471, 1),
+    CodePointRange::from(43867, 1),
+    CodePointRange::from(43882, 2),
+    CodePointRange::from(64830, 2),
+    CodePointRange::from(65040, 10),
+    CodePointRange::from(65072, 35),
+    CodePointRange::from(65108, 19),
+    CodePointRange::from(65128, 4),
+    CodePointRange::from(65279, 1),
+    CodePointRange::from(65281, 32),
+    CodePointRange::from(65339, 6),
+    CodePointRange::from(65371, 11),
+    CodePointRange::from(65392, 1),
+    CodePointRange::from(65438, 2),
+    CodePointRange::from(65504, 7),
+    CodePointRange::from(65512, 7),
+    CodePointRange::from(65529, 5),
+    CodePointRange::from(65792, 3),
+    CodePointRange::from(65799, 45),
+    CodePointRange::from(65847, 9),
+    CodePointRange::from(65936, 13),
+    CodePointRange::from(66000, 45),
+    CodePointRange::from(66273, 27),
+    CodePointRange::from(113824, 4),
+    CodePointRange::from(118608, 116),
+    CodePointRange::from(118784, 246),
+    CodePointRange::from(119040, 39),
+    CodePointRange::from(119081, 62),
+    CodePointRange::from(119146, 17),
+    CodePointRange::from(119171, 2),
+    CodePointRange::from(119180, 30),
+    CodePointRange::from(119214, 61),
+    CodePointRange::from(119488, 20),
+    CodePointRange::from(119520, 20),
+    CodePointRange::from(119552, 87),
+    CodePointRange::from(119648, 25),
+    CodePointRange::from(119808, 85),
+    CodePointRange::from(119894, 71),
+    CodePointRange::from(119966, 2),
+    CodePointRange::from(119970, 1),
+    CodePointRange::from(119973, 2),
+    CodePointRange::from(119977, 4),
+    CodePointRange::from(119982, 12),
+    CodePointRange::from(119995, 1),
+    CodePointRange::from(119997, 7),
+    CodePointRange::from(120005, 65),
+    CodePointRange::from(120071, 4),
+    CodePointRange::from(120077, 8),
+    CodePointRange::from(120086, 7),
+    CodePointRange::from(120094, 28),
+    CodePointRange::from(120123, 4),
+    CodePointRange::from(120128, 5),
+    CodePointRange::from(120134, 1),
+    CodePointRange::from(120138, 7),
+    CodePointRange::from(120146, 340),
+    CodePointRange::from(120488, 292),
+    CodePointRange::from(120782, 50),
+    CodePointRange::from(126065, 68),
+    CodePointRange::from(126209, 61),
+    CodePointRange::from(126976, 44),
+    CodePointRange::from(127024, 100),
+    CodePointRange::from(127136, 15),
+    CodePointRange::from(127153, 15),
+    CodePointRange::from(127169, 15),
+    CodePointRange::from(127185, 37),
+    CodePointRange::from(127232, 174),
+    CodePointRange::from(127462, 26),
+    CodePointRange::from(127489, 2),
+    CodePointRange::from(127504, 44),
+    CodePointRange::from(127552, 9),
+    CodePointRange::from(127568, 2),
+    CodePointRange::from(127584, 6),
+    CodePointRange::from(127744, 984),
+    CodePointRange::from(128732, 17),
+    CodePointRange::from(128752, 13),
+    CodePointRange::from(128768, 119),
+    CodePointRange::from(128891, 95),
+    CodePointRange::from(128992, 12),
+    CodePointRange::from(129008, 1),
+    CodePointRange::from(129024, 12),
+    CodePointRange::from(129040, 56),
+    CodePointRange::from(129104, 10),
+    CodePointRange::from(129120, 40),
+    CodePointRange::from(129168, 30),
+    CodePointRange::from(129200, 2),
+    CodePointRange::from(129280, 340),
+    CodePointRange::from(129632, 14),
+    CodePointRange::from(129648, 13),
+    CodePointRange::from(129664, 9),
+    CodePointRange::from(129680, 46),
+    CodePointRange::from(129727, 7),
+    CodePointRange::from(129742, 14),
+    CodePointRange::from(129760, 9),
+    CodePointRange::from(129776, 9),
+    CodePointRange::from(129792, 147),
+    CodePointRange::from(129940, 55),
+    CodePointRange::from(130032, 10),
+    CodePointRange::from(917505, 1),
+    CodePointRange::from(917536, 96),
+];
 
-/// Return whether c has the 'Deseret' Unicode property.
-pub(crate) fn is_deseret(c: char) -> bool {
-    DESERET
+/// Return whether c has the 'Common' Unicode property.
+pub(crate) fn is_common(c: char) -> bool {
+    COMMON
         .binary_search_by(|&cpr| cpr.compare(c as u32))
         .is_ok()
 }
 
-pub(crate) const DEVANAGARI: [CodePointRange; 5] = [
-    CodePointRange::from(2304, 81),
-    CodePointRange::from(2389, 15),
-    CodePointRange::from(2406, 26),
-    CodePointRange::from(43232, 32),
-    CodePointRange::from(72448, 10),
+pub(crate) const COPTIC_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(994, 14),
+    CodePointRange::from(11392, 116),
+    CodePointRange::from(11513, 7),
+    CodePointRange::from(66272, 28),
 ];
 
-/// Return whether c has the 'Devanagari' Unicode property.
-pub(crate) fn is_devanagari(c: char) -> bool {
-    DEVANAGARI
+/// Return whether c has 'Coptic' in its Script_Extensions Unicode property.
+pub(crate) fn is_coptic_extensions(c: char) -> bool {
+    COPTIC_EXTENSIONS
         .binary_search_by(|&cpr| cpr.compare(c as u32))
         .is_ok()
 }
 
-pub(crate) const DIVES_AKURU: [CodePointRange; 8] = [
-    CodePointRange::from(71936, 7),
-    CodePointRange::from(71945, 1),
-    CodePointRange::from(71948, 8),
-    CodePointRange::from(71957, 2),
-    CodePointRange::from(71960, 30),
-    CodePointRange::from(71991, 2),
-    CodePointRange::from(71995, 12),
-    CodePointRange::from(72016, 10),
+pub(crate) const COPTIC: [CodePointRange; 3] = [
+    CodePointRange::from(994, 14),
+    CodePointRange::from(11392, 116),
+    CodePointRange::from(11513, 7),
 ];
 
-/// Return whether c has the 'Dives_Akuru' Unicode property.
-pub(crate) fn is_dives_akuru(c: char) -> bool {
-    DIVES_AKURU
+/// Return whether c has the 'Coptic' Unicode property.
+pub(crate) fn is_coptic(c: char) -> bool {
+    COPTIC
         .binary_search_by(|&cpr| cpr.compare(c as u32))
         .is_ok()
 }
 
-pub(crate) const DOGRA: [CodePointRange; 1] = [CodePointRange::from(71680, 60)];
-
-/// Return whether c has the 'Dogra' Unicode property.
-pub(crate) fn is_dogra(c: char) -> bool {
-    DOGRA.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
-}
-
-pub(crate) const DUPLOYAN: [CodePointRange; 5] = [
-    CodePointRange::from(113664, 107),
-    CodePointRange::from(113776, 13),
-    CodePointRange::from(113792, 9),
-    CodePointRange::from(113808, 10),
-    CodePointRange::from(113820, 4),
+pub(crate) const CUNEIFORM: [CodePointRange; 4] = [
+    CodePointRange::from(73728, 922),
+    CodePointRange::from(74752, 111),
+    CodePointRange::from(74864, 5),
+    CodePointRange::from(74880, 196),
 ];
 
-/// Return whether c has the 'Duployan' Unicode property.
-pub(crate) fn is_duployan(c: char) -> bool {
-    DUPLOYAN
+/// Return whether c has the 'Cuneiform' Unicode property.
+pub(crate) fn is_cuneiform(c: char) -> bool {
+    CUNEIFORM
         .binary_search_by(|&cpr| cpr.compare(c as u32))
         .is_ok()
 }
 
-pub(crate) const EGYPTIAN_HIEROGLYPHS: [CodePointRange; 1] = [CodePointRange::from(77824, 1110)];
+pub(crate) const CYPRIOT_EXTENSIONS: [CodePointRange; 9] = [
+    CodePointRange::from(65792, 3),
+    CodePointRange::from(65799, 45),
+    CodePointRange::from(65847, 9),
+    CodePointRange::from(67584, 6),
+    CodePointRange::from(67592, 1),
+    CodePointRange::from(67594, 44),
+    CodePointRange::from(67639, 2),
+    CodePointRange::from(67644, 1),
+    CodePointRange::from(67647, 1),
+];
 
-/// Return whether c has the 'Egyptian_Hieroglyphs' Unicode property.
-pub(crate) fn is_egyptian_hieroglyphs(c: char) -> bool {
-    EGYPTIAN_HIEROGLYPHS
+/// Return whether c has 'Cypriot' in its Script_Extensions Unicode property.
+pub(crate) fn is_cypriot_extensions(c: char) -> bool {
+    CYPRIOT_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const CYPRIOT: [CodePointRange; 6] = [
+    CodePointRange::from(67584, 6),
+    CodePointRange::from(67592, 1),
+    CodePointRange::from(67594, 44),
+    CodePointRange::from(67639, 2),
+    CodePointRange::from(67644, 1),
+    CodePointRange::from(67647, 1),
+];
+
+/// Return whether c has the 'Cypriot' Unicode property.
+pub(crate) fn is_cypriot(c: char) -> bool {
+    CYPRIOT
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const CYRILLIC_EXTENSIONS: [CodePointRange; 11] = [
+    CodePointRange::from(1024, 304),
+    CodePointRange::from(7296, 9),
+    CodePointRange::from(7467, 1),
+    CodePointRange::from(7544, 1),
+    CodePointRange::from(7672, 1),
+    CodePointRange::from(11744, 32),
+    CodePointRange::from(11843, 1),
+    CodePointRange::from(42560, 96),
+    CodePointRange::from(65070, 2),
+    CodePointRange::from(122928, 62),
+    CodePointRange::from(123023, 1),
+];
+
+/// Return whether c has 'Cyrillic' in its Script_Extensions Unicode property.
+pub(crate) fn is_cyrillic_extensions(c: char) -> bool {
+    CYRILLIC_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const CYRILLIC: [CodePointRange; 10] = [
+    CodePointRange::from(1024, 133),
+    CodePointRange::from(1159, 169),
+    CodePointRange::from(7296, 9),
+    CodePointRange::from(7467, 1),
+    CodePointRange::from(7544, 1),
+    CodePointRange::from(11744, 32),
+    CodePointRange::from(42560, 96),
+    CodePointRange::from(65070, 2),
+    CodePointRange::from(122928, 62),
+    CodePointRange::from(123023, 1),
+];
+
+/// Return whether c has the 'Cyrillic' Unicode property.
+pub(crate) fn is_cyrillic(c: char) -> bool {
+    CYRILLIC
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const DESERET: [CodePointRange; 1] = [CodePointRange::from(66560, 80)];
+
+/// Return whether c has the 'Deseret' Unicode property.
+pub(crate) fn is_deseret(c: char) -> bool {
+    DESERET
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const DEVANAGARI_EXTENSIONS: [CodePointRange; 8] = [
+    CodePointRange::from(2304, 83),
+    CodePointRange::from(2389, 43),
+    CodePointRange::from(7376, 39),
+    CodePointRange::from(7416, 2),
+    CodePointRange::from(8432, 1),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(43232, 32),
+    CodePointRange::from(72448, 10),
+];
+
+/// Return whether c has 'Devanagari' in its Script_Extensions Unicode property.
+pub(crate) fn is_devanagari_extensions(c: char) -> bool {
+    DEVANAGARI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const DEVANAGARI: [CodePointRange; 5] = [
+    CodePointRange::from(2304, 81),
+    CodePointRange::from(2389, 15),
+    CodePointRange::from(2406, 26),
+    CodePointRange::from(43232, 32),
+    CodePointRange::from(72448, 10),
+];
+
+/// Return whether c has the 'Devanagari' Unicode property.
+pub(crate) fn is_devanagari(c: char) -> bool {
+    DEVANAGARI
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const DIVES_AKURU: [CodePointRange; 8] = [
+    CodePointRange::from(71936, 7),
+    CodePointRange::from(71945, 1),
+    CodePointRange::from(71948, 8),
+    CodePointRange::from(71957, 2),
+    CodePointRange::from(71960, 30),
+    CodePointRange::from(71991, 2),
+    CodePointRange::from(71995, 12),
+    CodePointRange::from(72016, 10),
+];
+
+/// Return whether c has the 'Dives_Akuru' Unicode property.
+pub(crate) fn is_dives_akuru(c: char) -> bool {
+    DIVES_AKURU
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const DOGRA_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(2404, 12),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(71680, 60),
+];
+
+/// Return whether c has 'Dogra' in its Script_Extensions Unicode property.
+pub(crate) fn is_dogra_extensions(c: char) -> bool {
+    DOGRA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const DOGRA: [CodePointRange; 1] = [CodePointRange::from(71680, 60)];
+
+/// Return whether c has the 'Dogra' Unicode property.
+pub(crate) fn is_dogra(c: char) -> bool {
+    DOGRA.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
+}
+
+pub(crate) const DUPLOYAN_EXTENSIONS: [CodePointRange; 5] = [
+    CodePointRange::from(113664, 107),
+    CodePointRange::from(113776, 13),
+    CodePointRange::from(113792, 9),
+    CodePointRange::from(113808, 10),
+    CodePointRange::from(113820, 8),
+];
+
+/// Return whether c has 'Duployan' in its Script_Extensions Unicode property.
+pub(crate) fn is_duployan_extensions(c: char) -> bool {
+    DUPLOYAN_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const DUPLOYAN: [CodePointRange; 5] = [
+    CodePointRange::from(113664, 107),
+    CodePointRange::from(113776, 13),
+    CodePointRange::from(113792, 9),
+    CodePointRange::from(113808, 10),
+    CodePointRange::from(113820, 4),
+];
+
+/// Return whether c has the 'Duployan' Unicode property.
+pub(crate) fn is_duployan(c: char) -> bool {
+    DUPLOYAN
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const EGYPTIAN_HIEROGLYPHS: [CodePointRange; 1] = [CodePointRange::from(77824, 1110)];
+
+/// Return whether c has the 'Egyptian_Hieroglyphs' Unicode property.
+pub(crate) fn is_egyptian_hieroglyphs(c: char) -> bool {
+    EGYPTIAN_HIEROGLYPHS
         .binary_search_by(|&cpr| cpr.compare(c as u32))
         .is_ok()
 }
@@ -13259,6 +13806,25 @@ pub(crate) fn is_ethiopic(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const GEORGIAN_EXTENSIONS: [CodePointRange; 9] = [
+    CodePointRange::from(4256, 38),
+    CodePointRange::from(4295, 1),
+    CodePointRange::from(4301, 1),
+    CodePointRange::from(4304, 48),
+    CodePointRange::from(7312, 43),
+    CodePointRange::from(7357, 3),
+    CodePointRange::from(11520, 38),
+    CodePointRange::from(11559, 1),
+    CodePointRange::from(11565, 1),
+];
+
+/// Return whether c has 'Georgian' in its Script_Extensions Unicode property.
+pub(crate) fn is_georgian_extensions(c: char) -> bool {
+    GEORGIAN_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const GEORGIAN: [CodePointRange; 10] = [
     CodePointRange::from(4256, 38),
     CodePointRange::from(4295, 1),
@@ -13279,6 +13845,26 @@ pub(crate) fn is_georgian(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const GLAGOLITIC_EXTENSIONS: [CodePointRange; 10] = [
+    CodePointRange::from(1156, 1),
+    CodePointRange::from(1159, 1),
+    CodePointRange::from(11264, 96),
+    CodePointRange::from(11843, 1),
+    CodePointRange::from(42607, 1),
+    CodePointRange::from(122880, 7),
+    CodePointRange::from(122888, 17),
+    CodePointRange::from(122907, 7),
+    CodePointRange::from(122915, 2),
+    CodePointRange::from(122918, 5),
+];
+
+/// Return whether c has 'Glagolitic' in its Script_Extensions Unicode property.
+pub(crate) fn is_glagolitic_extensions(c: char) -> bool {
+    GLAGOLITIC_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const GLAGOLITIC: [CodePointRange; 6] = [
     CodePointRange::from(11264, 96),
     CodePointRange::from(122880, 7),
@@ -13304,6 +13890,41 @@ pub(crate) fn is_gothic(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const GRANTHA_EXTENSIONS: [CodePointRange; 25] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(3046, 14),
+    CodePointRange::from(7376, 1),
+    CodePointRange::from(7378, 2),
+    CodePointRange::from(7410, 3),
+    CodePointRange::from(7416, 2),
+    CodePointRange::from(8432, 1),
+    CodePointRange::from(70400, 4),
+    CodePointRange::from(70405, 8),
+    CodePointRange::from(70415, 2),
+    CodePointRange::from(70419, 22),
+    CodePointRange::from(70442, 7),
+    CodePointRange::from(70450, 2),
+    CodePointRange::from(70453, 5),
+    CodePointRange::from(70459, 10),
+    CodePointRange::from(70471, 2),
+    CodePointRange::from(70475, 3),
+    CodePointRange::from(70480, 1),
+    CodePointRange::from(70487, 1),
+    CodePointRange::from(70493, 7),
+    CodePointRange::from(70502, 7),
+    CodePointRange::from(70512, 5),
+    CodePointRange::from(73680, 2),
+    CodePointRange::from(73683, 1),
+];
+
+/// Return whether c has 'Grantha' in its Script_Extensions Unicode property.
+pub(crate) fn is_grantha_extensions(c: char) -> bool {
+    GRANTHA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const GRANTHA: [CodePointRange; 15] = [
     CodePointRange::from(70400, 4),
     CodePointRange::from(70405, 8),
@@ -13329,6 +13950,54 @@ pub(crate) fn is_grantha(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const GREEK_EXTENSIONS: [CodePointRange; 38] = [
+    CodePointRange::from(834, 1),
+    CodePointRange::from(837, 1),
+    CodePointRange::from(880, 4),
+    CodePointRange::from(885, 3),
+    CodePointRange::from(890, 4),
+    CodePointRange::from(895, 1),
+    CodePointRange::from(900, 1),
+    CodePointRange::from(902, 1),
+    CodePointRange::from(904, 3),
+    CodePointRange::from(908, 1),
+    CodePointRange::from(910, 20),
+    CodePointRange::from(931, 63),
+    CodePointRange::from(1008, 16),
+    CodePointRange::from(7462, 5),
+    CodePointRange::from(7517, 5),
+    CodePointRange::from(7526, 5),
+    CodePointRange::from(7615, 3),
+    CodePointRange::from(7936, 22),
+    CodePointRange::from(7960, 6),
+    CodePointRange::from(7968, 38),
+    CodePointRange::from(8008, 6),
+    CodePointRange::from(8016, 8),
+    CodePointRange::from(8025, 1),
+    CodePointRange::from(8027, 1),
+    CodePointRange::from(8029, 1),
+    CodePointRange::from(8031, 31),
+    CodePointRange::from(8064, 53),
+    CodePointRange::from(8118, 15),
+    CodePointRange::from(8134, 14),
+    CodePointRange::from(8150, 6),
+    CodePointRange::from(8157, 19),
+    CodePointRange::from(8178, 3),
+    CodePointRange::from(8182, 9),
+    CodePointRange::from(8486, 1),
+    CodePointRange::from(43877, 1),
+    CodePointRange::from(65856, 79),
+    CodePointRange::from(65952, 1),
+    CodePointRange::from(119296, 70),
+];
+
+/// Return whether c has 'Greek' in its Script_Extensions Unicode property.
+pub(crate) fn is_greek_extensions(c: char) -> bool {
+    GREEK_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const GREEK: [CodePointRange; 36] = [
     CodePointRange::from(880, 4),
     CodePointRange::from(885, 3),
@@ -13373,7 +14042,9 @@ pub(crate) fn is_greek(c: char) -> bool {
     GREEK.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
-pub(crate) const GUJARATI: [CodePointRange; 14] = [
+pub(crate) const GUJARATI_EXTENSIONS: [CodePointRange; 17] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
     CodePointRange::from(2689, 3),
     CodePointRange::from(2693, 9),
     CodePointRange::from(2703, 3),
@@ -13388,23 +14059,65 @@ pub(crate) const GUJARATI: [CodePointRange; 14] = [
     CodePointRange::from(2784, 4),
     CodePointRange::from(2790, 12),
     CodePointRange::from(2809, 7),
+    CodePointRange::from(43056, 10),
 ];
 
-/// Return whether c has the 'Gujarati' Unicode property.
-pub(crate) fn is_gujarati(c: char) -> bool {
-    GUJARATI
+/// Return whether c has 'Gujarati' in its Script_Extensions Unicode property.
+pub(crate) fn is_gujarati_extensions(c: char) -> bool {
+    GUJARATI_EXTENSIONS
         .binary_search_by(|&cpr| cpr.compare(c as u32))
         .is_ok()
 }
 
-pub(crate) const GUNJALA_GONDI: [CodePointRange; 6] = [
-    CodePointRange::from(73056, 6),
-    CodePointRange::from(73063, 2),
-    CodePointRange::from(73066, 37),
-    CodePointRange::from(73104, 2),
-    CodePointRange::from(73107, 6),
-    CodePointRange::from(73120, 10),
-];
+pub(crate) const GUJARATI: [CodePointRange; 14] = [
+    CodePointRange::from(2689, 3),
+    CodePointRange::from(2693, 9),
+    CodePointRange::from(2703, 3),
+    CodePointRange::from(2707, 22),
+    CodePointRange::from(2730, 7),
+    CodePointRange::from(2738, 2),
+    CodePointRange::from(2741, 5),
+    CodePointRange::from(2748, 10),
+    CodePointRange::from(2759, 3),
+    CodePointRange::from(2763, 3),
+    CodePointRange::from(2768, 1),
+    CodePointRange::from(2784, 4),
+    CodePointRange::from(2790, 12),
+    CodePointRange::from(2809, 7),
+];
+
+/// Return whether c has the 'Gujarati' Unicode property.
+pub(crate) fn is_gujarati(c: char) -> bool {
+    GUJARATI
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const GUNJALA_GONDI_EXTENSIONS: [CodePointRange; 7] = [
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(73056, 6),
+    CodePointRange::from(73063, 2),
+    CodePointRange::from(73066, 37),
+    CodePointRange::from(73104, 2),
+    CodePointRange::from(73107, 6),
+    CodePointRange::from(73120, 10),
+];
+
+/// Return whether c has 'Gunjala_Gondi' in its Script_Extensions Unicode property.
+pub(crate) fn is_gunjala_gondi_extensions(c: char) -> bool {
+    GUNJALA_GONDI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const GUNJALA_GONDI: [CodePointRange; 6] = [
+    CodePointRange::from(73056, 6),
+    CodePointRange::from(73063, 2),
+    CodePointRange::from(73066, 37),
+    CodePointRange::from(73104, 2),
+    CodePointRange::from(73107, 6),
+    CodePointRange::from(73120, 10),
+];
 
 /// Return whether c has the 'Gunjala_Gondi' Unicode property.
 pub(crate) fn is_gunjala_gondi(c: char) -> bool {
@@ -13413,6 +14126,35 @@ pub(crate) fn is_gunjala_gondi(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const GURMUKHI_EXTENSIONS: [CodePointRange; 19] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(2561, 3),
+    CodePointRange::from(2565, 6),
+    CodePointRange::from(2575, 2),
+    CodePointRange::from(2579, 22),
+    CodePointRange::from(2602, 7),
+    CodePointRange::from(2610, 2),
+    CodePointRange::from(2613, 2),
+    CodePointRange::from(2616, 2),
+    CodePointRange::from(2620, 1),
+    CodePointRange::from(2622, 5),
+    CodePointRange::from(2631, 2),
+    CodePointRange::from(2635, 3),
+    CodePointRange::from(2641, 1),
+    CodePointRange::from(2649, 4),
+    CodePointRange::from(2654, 1),
+    CodePointRange::from(2662, 17),
+    CodePointRange::from(43056, 10),
+];
+
+/// Return whether c has 'Gurmukhi' in its Script_Extensions Unicode property.
+pub(crate) fn is_gurmukhi_extensions(c: char) -> bool {
+    GURMUKHI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const GURMUKHI: [CodePointRange; 16] = [
     CodePointRange::from(2561, 3),
     CodePointRange::from(2565, 6),
@@ -13439,6 +14181,75 @@ pub(crate) fn is_gurmukhi(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const HAN_EXTENSIONS: [CodePointRange; 59] = [
+    CodePointRange::from(11904, 26),
+    CodePointRange::from(11931, 89),
+    CodePointRange::from(12032, 214),
+    CodePointRange::from(12289, 3),
+    CodePointRange::from(12293, 13),
+    CodePointRange::from(12307, 13),
+    CodePointRange::from(12321, 13),
+    CodePointRange::from(12336, 1),
+    CodePointRange::from(12343, 9),
+    CodePointRange::from(12539, 1),
+    CodePointRange::from(12688, 16),
+    CodePointRange::from(12736, 36),
+    CodePointRange::from(12832, 40),
+    CodePointRange::from(12928, 49),
+    CodePointRange::from(12992, 12),
+    CodePointRange::from(13055, 1),
+    CodePointRange::from(13144, 25),
+    CodePointRange::from(13179, 5),
+    CodePointRange::from(13280, 31),
+    CodePointRange::from(13312, 4096),
+    CodePointRange::from(17408, 2496),
+    CodePointRange::from(19968, 4096),
+    CodePointRange::from(24064, 4096),
+    CodePointRange::from(28160, 4096),
+    CodePointRange::from(32256, 4096),
+    CodePointRange::from(36352, 4096),
+    CodePointRange::from(40448, 512),
+    CodePointRange::from(42752, 8),
+    CodePointRange::from(63744, 366),
+    CodePointRange::from(64112, 106),
+    CodePointRange::from(65093, 2),
+    CodePointRange::from(65377, 5),
+    CodePointRange::from(94178, 2),
+    CodePointRange::from(94192, 2),
+    CodePointRange::from(119648, 18),
+    CodePointRange::from(127568, 2),
+    CodePointRange::from(131072, 4096),
+    CodePointRange::from(135168, 4096),
+    CodePointRange::from(139264, 4096),
+    CodePointRange::from(143360, 4096),
+    CodePointRange::from(147456, 4096),
+    CodePointRange::from(151552, 4096),
+    CodePointRange::from(155648, 4096),
+    CodePointRange::from(159744, 4096),
+    CodePointRange::from(163840, 4096),
+    CodePointRange::from(167936, 4096),
+    CodePointRange::from(172032, 1760),
+    CodePointRange::from(173824, 4096),
+    CodePointRange::from(177920, 58),
+    CodePointRange::from(177984, 222),
+    CodePointRange::from(178208, 4096),
+    CodePointRange::from(182304, 1666),
+    CodePointRange::from(183984, 4096),
+    CodePointRange::from(188080, 3377),
+    CodePointRange::from(194560, 542),
+    CodePointRange::from(196608, 4096),
+    CodePointRange::from(200704, 843),
+    CodePointRange::from(201552, 4096),
+    CodePointRange::from(205648, 96),
+];
+
+/// Return whether c has 'Han' in its Script_Extensions Unicode property.
+pub(crate) fn is_han_extensions(c: char) -> bool {
+    HAN_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const HAN: [CodePointRange; 42] = [
     CodePointRange::from(11904, 26),
     CodePointRange::from(11931, 89),
@@ -13489,6 +14300,39 @@ pub(crate) fn is_han(c: char) -> bool {
     HAN.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const HANGUL_EXTENSIONS: [CodePointRange; 23] = [
+    CodePointRange::from(4352, 256),
+    CodePointRange::from(12289, 3),
+    CodePointRange::from(12296, 10),
+    CodePointRange::from(12307, 13),
+    CodePointRange::from(12334, 3),
+    CodePointRange::from(12343, 1),
+    CodePointRange::from(12539, 1),
+    CodePointRange::from(12593, 94),
+    CodePointRange::from(12800, 31),
+    CodePointRange::from(12896, 31),
+    CodePointRange::from(43360, 29),
+    CodePointRange::from(44032, 4096),
+    CodePointRange::from(48128, 4096),
+    CodePointRange::from(52224, 2980),
+    CodePointRange::from(55216, 23),
+    CodePointRange::from(55243, 49),
+    CodePointRange::from(65093, 2),
+    CodePointRange::from(65377, 5),
+    CodePointRange::from(65440, 31),
+    CodePointRange::from(65474, 6),
+    CodePointRange::from(65482, 6),
+    CodePointRange::from(65490, 6),
+    CodePointRange::from(65498, 3),
+];
+
+/// Return whether c has 'Hangul' in its Script_Extensions Unicode property.
+pub(crate) fn is_hangul_extensions(c: char) -> bool {
+    HANGUL_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const HANGUL: [CodePointRange; 16] = [
     CodePointRange::from(4352, 256),
     CodePointRange::from(12334, 2),
@@ -13515,6 +14359,23 @@ pub(crate) fn is_hangul(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const HANIFI_ROHINGYA_EXTENSIONS: [CodePointRange; 7] = [
+    CodePointRange::from(1548, 1),
+    CodePointRange::from(1563, 1),
+    CodePointRange::from(1567, 1),
+    CodePointRange::from(1600, 1),
+    CodePointRange::from(1748, 1),
+    CodePointRange::from(68864, 40),
+    CodePointRange::from(68912, 10),
+];
+
+/// Return whether c has 'Hanifi_Rohingya' in its Script_Extensions Unicode property.
+pub(crate) fn is_hanifi_rohingya_extensions(c: char) -> bool {
+    HANIFI_ROHINGYA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const HANIFI_ROHINGYA: [CodePointRange; 2] = [
     CodePointRange::from(68864, 40),
     CodePointRange::from(68912, 10),
@@ -13527,6 +14388,15 @@ pub(crate) fn is_hanifi_rohingya(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const HANUNOO_EXTENSIONS: [CodePointRange; 1] = [CodePointRange::from(5920, 23)];
+
+/// Return whether c has 'Hanunoo' in its Script_Extensions Unicode property.
+pub(crate) fn is_hanunoo_extensions(c: char) -> bool {
+    HANUNOO_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const HANUNOO: [CodePointRange; 1] = [CodePointRange::from(5920, 21)];
 
 /// Return whether c has the 'Hanunoo' Unicode property.
@@ -13568,6 +14438,33 @@ pub(crate) fn is_hebrew(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const HIRAGANA_EXTENSIONS: [CodePointRange; 17] = [
+    CodePointRange::from(12289, 3),
+    CodePointRange::from(12296, 10),
+    CodePointRange::from(12307, 13),
+    CodePointRange::from(12336, 6),
+    CodePointRange::from(12343, 1),
+    CodePointRange::from(12348, 2),
+    CodePointRange::from(12353, 86),
+    CodePointRange::from(12441, 8),
+    CodePointRange::from(12539, 2),
+    CodePointRange::from(65093, 2),
+    CodePointRange::from(65377, 5),
+    CodePointRange::from(65392, 1),
+    CodePointRange::from(65438, 2),
+    CodePointRange::from(110593, 287),
+    CodePointRange::from(110898, 1),
+    CodePointRange::from(110928, 3),
+    CodePointRange::from(127488, 1),
+];
+
+/// Return whether c has 'Hiragana' in its Script_Extensions Unicode property.
+pub(crate) fn is_hiragana_extensions(c: char) -> bool {
+    HIRAGANA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const HIRAGANA: [CodePointRange; 6] = [
     CodePointRange::from(12353, 86),
     CodePointRange::from(12445, 3),
@@ -13596,6 +14493,36 @@ pub(crate) fn is_imperial_aramaic(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const INHERITED_EXTENSIONS: [CodePointRange; 20] = [
+    CodePointRange::from(768, 66),
+    CodePointRange::from(835, 2),
+    CodePointRange::from(838, 29),
+    CodePointRange::from(2387, 2),
+    CodePointRange::from(6832, 31),
+    CodePointRange::from(7618, 54),
+    CodePointRange::from(7673, 1),
+    CodePointRange::from(7675, 5),
+    CodePointRange::from(8204, 2),
+    CodePointRange::from(8400, 32),
+    CodePointRange::from(65024, 16),
+    CodePointRange::from(65056, 14),
+    CodePointRange::from(66045, 1),
+    CodePointRange::from(118528, 46),
+    CodePointRange::from(118576, 23),
+    CodePointRange::from(119143, 3),
+    CodePointRange::from(119163, 8),
+    CodePointRange::from(119173, 7),
+    CodePointRange::from(119210, 4),
+    CodePointRange::from(917760, 240),
+];
+
+/// Return whether c has 'Inherited' in its Script_Extensions Unicode property.
+pub(crate) fn is_inherited_extensions(c: char) -> bool {
+    INHERITED_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const INHERITED: [CodePointRange; 29] = [
     CodePointRange::from(768, 112),
     CodePointRange::from(1157, 2),
@@ -13659,6 +14586,19 @@ pub(crate) fn is_inscriptional_parthian(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const JAVANESE_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(43392, 78),
+    CodePointRange::from(43471, 11),
+    CodePointRange::from(43486, 2),
+];
+
+/// Return whether c has 'Javanese' in its Script_Extensions Unicode property.
+pub(crate) fn is_javanese_extensions(c: char) -> bool {
+    JAVANESE_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const JAVANESE: [CodePointRange; 3] = [
     CodePointRange::from(43392, 78),
     CodePointRange::from(43472, 10),
@@ -13672,6 +14612,20 @@ pub(crate) fn is_javanese(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const KAITHI_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(2406, 10),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(69760, 67),
+    CodePointRange::from(69837, 1),
+];
+
+/// Return whether c has 'Kaithi' in its Script_Extensions Unicode property.
+pub(crate) fn is_kaithi_extensions(c: char) -> bool {
+    KAITHI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const KAITHI: [CodePointRange; 2] = [
     CodePointRange::from(69760, 67),
     CodePointRange::from(69837, 1),
@@ -13684,6 +14638,37 @@ pub(crate) fn is_kaithi(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const KANNADA_EXTENSIONS: [CodePointRange; 21] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(3200, 13),
+    CodePointRange::from(3214, 3),
+    CodePointRange::from(3218, 23),
+    CodePointRange::from(3242, 10),
+    CodePointRange::from(3253, 5),
+    CodePointRange::from(3260, 9),
+    CodePointRange::from(3270, 3),
+    CodePointRange::from(3274, 4),
+    CodePointRange::from(3285, 2),
+    CodePointRange::from(3293, 2),
+    CodePointRange::from(3296, 4),
+    CodePointRange::from(3302, 10),
+    CodePointRange::from(3313, 3),
+    CodePointRange::from(7376, 1),
+    CodePointRange::from(7378, 1),
+    CodePointRange::from(7386, 1),
+    CodePointRange::from(7410, 1),
+    CodePointRange::from(7412, 1),
+    CodePointRange::from(43056, 6),
+];
+
+/// Return whether c has 'Kannada' in its Script_Extensions Unicode property.
+pub(crate) fn is_kannada_extensions(c: char) -> bool {
+    KANNADA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const KANNADA: [CodePointRange; 13] = [
     CodePointRange::from(3200, 13),
     CodePointRange::from(3214, 3),
@@ -13707,14 +14692,20 @@ pub(crate) fn is_kannada(c: char) -> bool {
         .is_ok()
 }
 
-pub(crate) const KATAKANA: [CodePointRange; 14] = [
-    CodePointRange::from(12449, 90),
-    CodePointRange::from(12541, 3),
+pub(crate) const KATAKANA_EXTENSIONS: [CodePointRange; 20] = [
+    CodePointRange::from(12289, 3),
+    CodePointRange::from(12296, 10),
+    CodePointRange::from(12307, 13),
+    CodePointRange::from(12336, 6),
+    CodePointRange::from(12343, 1),
+    CodePointRange::from(12348, 2),
+    CodePointRange::from(12441, 4),
+    CodePointRange::from(12448, 96),
     CodePointRange::from(12784, 16),
     CodePointRange::from(13008, 47),
     CodePointRange::from(13056, 88),
-    CodePointRange::from(65382, 10),
-    CodePointRange::from(65393, 45),
+    CodePointRange::from(65093, 2),
+    CodePointRange::from(65377, 63),
     CodePointRange::from(110576, 4),
     CodePointRange::from(110581, 7),
     CodePointRange::from(110589, 2),
@@ -13724,16 +14715,49 @@ pub(crate) const KATAKANA: [CodePointRange; 14] = [
     CodePointRange::from(110948, 4),
 ];
 
-/// Return whether c has the 'Katakana' Unicode property.
-pub(crate) fn is_katakana(c: char) -> bool {
-    KATAKANA
+/// Return whether c has 'Katakana' in its Script_Extensions Unicode property.
+pub(crate) fn is_katakana_extensions(c: char) -> bool {
+    KATAKANA_EXTENSIONS
         .binary_search_by(|&cpr| cpr.compare(c as u32))
         .is_ok()
 }
 
-pub(crate) const KAYAH_LI: [CodePointRange; 2] = [
-    CodePointRange::from(43264, 46),
-    CodePointRange::from(43311, 1),
+pub(crate) const KATAKANA: [CodePointRange; 14] = [
+    CodePointRange::from(12449, 90),
+    CodePointRange::from(12541, 3),
+    CodePointRange::from(12784, 16),
+    CodePointRange::from(13008, 47),
+    CodePointRange::from(13056, 88),
+    CodePointRange::from(65382, 10),
+    CodePointRange::from(65393, 45),
+    CodePointRange::from(110576, 4),
+    CodePointRange::from(110581, 7),
+    CodePointRange::from(110589, 2),
+    CodePointRange::from(110592, 1),
+    CodePointRange::from(110880, 3),
+    CodePointRange::from(110933, 1),
+    CodePointRange::from(110948, 4),
+];
+
+/// Return whether c has the 'Katakana' Unicode property.
+pub(crate) fn is_katakana(c: char) -> bool {
+    KATAKANA
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const KAYAH_LI_EXTENSIONS: [CodePointRange; 1] = [CodePointRange::from(43264, 48)];
+
+/// Return whether c has 'Kayah_Li' in its Script_Extensions Unicode property.
+pub(crate) fn is_kayah_li_extensions(c: char) -> bool {
+    KAYAH_LI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const KAYAH_LI: [CodePointRange; 2] = [
+    CodePointRange::from(43264, 46),
+    CodePointRange::from(43311, 1),
 ];
 
 /// Return whether c has the 'Kayah_Li' Unicode property.
@@ -13785,6 +14809,20 @@ pub(crate) fn is_khmer(c: char) -> bool {
     KHMER.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const KHOJKI_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(2790, 10),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(70144, 18),
+    CodePointRange::from(70163, 47),
+];
+
+/// Return whether c has 'Khojki' in its Script_Extensions Unicode property.
+pub(crate) fn is_khojki_extensions(c: char) -> bool {
+    KHOJKI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const KHOJKI: [CodePointRange; 2] = [
     CodePointRange::from(70144, 18),
     CodePointRange::from(70163, 47),
@@ -13797,6 +14835,20 @@ pub(crate) fn is_khojki(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const KHUDAWADI_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(70320, 59),
+    CodePointRange::from(70384, 10),
+];
+
+/// Return whether c has 'Khudawadi' in its Script_Extensions Unicode property.
+pub(crate) fn is_khudawadi_extensions(c: char) -> bool {
+    KHUDAWADI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const KHUDAWADI: [CodePointRange; 2] = [
     CodePointRange::from(70320, 59),
     CodePointRange::from(70384, 10),
@@ -13828,6 +14880,63 @@ pub(crate) fn is_lao(c: char) -> bool {
     LAO.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const LATIN_EXTENSIONS: [CodePointRange; 47] = [
+    CodePointRange::from(65, 26),
+    CodePointRange::from(97, 26),
+    CodePointRange::from(170, 1),
+    CodePointRange::from(186, 1),
+    CodePointRange::from(192, 23),
+    CodePointRange::from(216, 31),
+    CodePointRange::from(248, 449),
+    CodePointRange::from(736, 5),
+    CodePointRange::from(867, 13),
+    CodePointRange::from(1157, 2),
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(4347, 1),
+    CodePointRange::from(7424, 38),
+    CodePointRange::from(7468, 49),
+    CodePointRange::from(7522, 4),
+    CodePointRange::from(7531, 13),
+    CodePointRange::from(7545, 70),
+    CodePointRange::from(7680, 256),
+    CodePointRange::from(8239, 1),
+    CodePointRange::from(8305, 1),
+    CodePointRange::from(8319, 1),
+    CodePointRange::from(8336, 13),
+    CodePointRange::from(8432, 1),
+    CodePointRange::from(8490, 2),
+    CodePointRange::from(8498, 1),
+    CodePointRange::from(8526, 1),
+    CodePointRange::from(8544, 41),
+    CodePointRange::from(11360, 32),
+    CodePointRange::from(42752, 8),
+    CodePointRange::from(42786, 102),
+    CodePointRange::from(42891, 64),
+    CodePointRange::from(42960, 2),
+    CodePointRange::from(42963, 1),
+    CodePointRange::from(42965, 5),
+    CodePointRange::from(42994, 14),
+    CodePointRange::from(43310, 1),
+    CodePointRange::from(43824, 43),
+    CodePointRange::from(43868, 9),
+    CodePointRange::from(43878, 4),
+    CodePointRange::from(64256, 7),
+    CodePointRange::from(65313, 26),
+    CodePointRange::from(65345, 26),
+    CodePointRange::from(67456, 6),
+    CodePointRange::from(67463, 42),
+    CodePointRange::from(67506, 9),
+    CodePointRange::from(122624, 31),
+    CodePointRange::from(122661, 6),
+];
+
+/// Return whether c has 'Latin' in its Script_Extensions Unicode property.
+pub(crate) fn is_latin_extensions(c: char) -> bool {
+    LATIN_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const LATIN: [CodePointRange; 39] = [
     CodePointRange::from(65, 26),
     CodePointRange::from(97, 26),
@@ -13888,6 +14997,22 @@ pub(crate) fn is_lepcha(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const LIMBU_EXTENSIONS: [CodePointRange; 6] = [
+    CodePointRange::from(2405, 1),
+    CodePointRange::from(6400, 31),
+    CodePointRange::from(6432, 12),
+    CodePointRange::from(6448, 12),
+    CodePointRange::from(6464, 1),
+    CodePointRange::from(6468, 12),
+];
+
+/// Return whether c has 'Limbu' in its Script_Extensions Unicode property.
+pub(crate) fn is_limbu_extensions(c: char) -> bool {
+    LIMBU_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const LIMBU: [CodePointRange; 5] = [
     CodePointRange::from(6400, 31),
     CodePointRange::from(6432, 12),
@@ -13901,6 +15026,20 @@ pub(crate) fn is_limbu(c: char) -> bool {
     LIMBU.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const LINEAR_A_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(65799, 45),
+    CodePointRange::from(67072, 311),
+    CodePointRange::from(67392, 22),
+    CodePointRange::from(67424, 8),
+];
+
+/// Return whether c has 'Linear_A' in its Script_Extensions Unicode property.
+pub(crate) fn is_linear_a_extensions(c: char) -> bool {
+    LINEAR_A_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const LINEAR_A: [CodePointRange; 3] = [
     CodePointRange::from(67072, 311),
     CodePointRange::from(67392, 22),
@@ -13914,6 +15053,26 @@ pub(crate) fn is_linear_a(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const LINEAR_B_EXTENSIONS: [CodePointRange; 10] = [
+    CodePointRange::from(65536, 12),
+    CodePointRange::from(65549, 26),
+    CodePointRange::from(65576, 19),
+    CodePointRange::from(65596, 2),
+    CodePointRange::from(65599, 15),
+    CodePointRange::from(65616, 14),
+    CodePointRange::from(65664, 123),
+    CodePointRange::from(65792, 3),
+    CodePointRange::from(65799, 45),
+    CodePointRange::from(65847, 9),
+];
+
+/// Return whether c has 'Linear_B' in its Script_Extensions Unicode property.
+pub(crate) fn is_linear_b_extensions(c: char) -> bool {
+    LINEAR_B_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const LINEAR_B: [CodePointRange; 7] = [
     CodePointRange::from(65536, 12),
     CodePointRange::from(65549, 26),
@@ -13962,6 +15121,19 @@ pub(crate) fn is_lydian(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const MAHAJANI_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(2404, 12),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(69968, 39),
+];
+
+/// Return whether c has 'Mahajani' in its Script_Extensions Unicode property.
+pub(crate) fn is_mahajani_extensions(c: char) -> bool {
+    MAHAJANI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MAHAJANI: [CodePointRange; 1] = [CodePointRange::from(69968, 39)];
 
 /// Return whether c has the 'Mahajani' Unicode property.
@@ -13980,6 +15152,27 @@ pub(crate) fn is_makasar(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const MALAYALAM_EXTENSIONS: [CodePointRange; 11] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(3328, 13),
+    CodePointRange::from(3342, 3),
+    CodePointRange::from(3346, 51),
+    CodePointRange::from(3398, 3),
+    CodePointRange::from(3402, 6),
+    CodePointRange::from(3412, 16),
+    CodePointRange::from(3430, 26),
+    CodePointRange::from(7386, 1),
+    CodePointRange::from(43056, 3),
+];
+
+/// Return whether c has 'Malayalam' in its Script_Extensions Unicode property.
+pub(crate) fn is_malayalam_extensions(c: char) -> bool {
+    MALAYALAM_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MALAYALAM: [CodePointRange; 7] = [
     CodePointRange::from(3328, 13),
     CodePointRange::from(3342, 3),
@@ -13997,6 +15190,19 @@ pub(crate) fn is_malayalam(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const MANDAIC_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(1600, 1),
+    CodePointRange::from(2112, 28),
+    CodePointRange::from(2142, 1),
+];
+
+/// Return whether c has 'Mandaic' in its Script_Extensions Unicode property.
+pub(crate) fn is_mandaic_extensions(c: char) -> bool {
+    MANDAIC_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MANDAIC: [CodePointRange; 2] = [
     CodePointRange::from(2112, 28),
     CodePointRange::from(2142, 1),
@@ -14009,6 +15215,19 @@ pub(crate) fn is_mandaic(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const MANICHAEAN_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(1600, 1),
+    CodePointRange::from(68288, 39),
+    CodePointRange::from(68331, 12),
+];
+
+/// Return whether c has 'Manichaean' in its Script_Extensions Unicode property.
+pub(crate) fn is_manichaean_extensions(c: char) -> bool {
+    MANICHAEAN_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MANICHAEAN: [CodePointRange; 2] = [
     CodePointRange::from(68288, 39),
     CodePointRange::from(68331, 12),
@@ -14043,6 +15262,24 @@ pub(crate) fn is_medefaidrin(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const MASARAM_GONDI_EXTENSIONS: [CodePointRange; 8] = [
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(72960, 7),
+    CodePointRange::from(72968, 2),
+    CodePointRange::from(72971, 44),
+    CodePointRange::from(73018, 1),
+    CodePointRange::from(73020, 2),
+    CodePointRange::from(73023, 9),
+    CodePointRange::from(73040, 10),
+];
+
+/// Return whether c has 'Masaram_Gondi' in its Script_Extensions Unicode property.
+pub(crate) fn is_masaram_gondi_extensions(c: char) -> bool {
+    MASARAM_GONDI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MASARAM_GONDI: [CodePointRange; 7] = [
     CodePointRange::from(72960, 7),
     CodePointRange::from(72968, 2),
@@ -14118,6 +15355,19 @@ pub(crate) fn is_miao(c: char) -> bool {
     MIAO.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const MODI_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(71168, 69),
+    CodePointRange::from(71248, 10),
+];
+
+/// Return whether c has 'Modi' in its Script_Extensions Unicode property.
+pub(crate) fn is_modi_extensions(c: char) -> bool {
+    MODI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MODI: [CodePointRange; 2] = [
     CodePointRange::from(71168, 69),
     CodePointRange::from(71248, 10),
@@ -14128,6 +15378,21 @@ pub(crate) fn is_modi(c: char) -> bool {
     MODI.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const MONGOLIAN_EXTENSIONS: [CodePointRange; 5] = [
+    CodePointRange::from(6144, 26),
+    CodePointRange::from(6176, 89),
+    CodePointRange::from(6272, 43),
+    CodePointRange::from(8239, 1),
+    CodePointRange::from(71264, 13),
+];
+
+/// Return whether c has 'Mongolian' in its Script_Extensions Unicode property.
+pub(crate) fn is_mongolian_extensions(c: char) -> bool {
+    MONGOLIAN_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MONGOLIAN: [CodePointRange; 6] = [
     CodePointRange::from(6144, 2),
     CodePointRange::from(6148, 1),
@@ -14155,6 +15420,22 @@ pub(crate) fn is_mro(c: char) -> bool {
     MRO.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const MULTANI_EXTENSIONS: [CodePointRange; 6] = [
+    CodePointRange::from(2662, 10),
+    CodePointRange::from(70272, 7),
+    CodePointRange::from(70280, 1),
+    CodePointRange::from(70282, 4),
+    CodePointRange::from(70287, 15),
+    CodePointRange::from(70303, 11),
+];
+
+/// Return whether c has 'Multani' in its Script_Extensions Unicode property.
+pub(crate) fn is_multani_extensions(c: char) -> bool {
+    MULTANI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MULTANI: [CodePointRange; 5] = [
     CodePointRange::from(70272, 7),
     CodePointRange::from(70280, 1),
@@ -14170,6 +15451,20 @@ pub(crate) fn is_multani(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const MYANMAR_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(4096, 160),
+    CodePointRange::from(43310, 1),
+    CodePointRange::from(43488, 31),
+    CodePointRange::from(43616, 32),
+];
+
+/// Return whether c has 'Myanmar' in its Script_Extensions Unicode property.
+pub(crate) fn is_myanmar_extensions(c: char) -> bool {
+    MYANMAR_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const MYANMAR: [CodePointRange; 3] = [
     CodePointRange::from(4096, 160),
     CodePointRange::from(43488, 31),
@@ -14195,6 +15490,25 @@ pub(crate) fn is_nabataean(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const NANDINAGARI_EXTENSIONS: [CodePointRange; 9] = [
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(3302, 10),
+    CodePointRange::from(7401, 1),
+    CodePointRange::from(7410, 1),
+    CodePointRange::from(7418, 1),
+    CodePointRange::from(43056, 6),
+    CodePointRange::from(72096, 8),
+    CodePointRange::from(72106, 46),
+    CodePointRange::from(72154, 11),
+];
+
+/// Return whether c has 'Nandinagari' in its Script_Extensions Unicode property.
+pub(crate) fn is_nandinagari_extensions(c: char) -> bool {
+    NANDINAGARI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const NANDINAGARI: [CodePointRange; 3] = [
     CodePointRange::from(72096, 8),
     CodePointRange::from(72106, 46),
@@ -14232,17 +15546,33 @@ pub(crate) fn is_newa(c: char) -> bool {
     NEWA.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
-pub(crate) const NKO: [CodePointRange; 2] = [
+pub(crate) const NKO_EXTENSIONS: [CodePointRange; 6] = [
+    CodePointRange::from(1548, 1),
+    CodePointRange::from(1563, 1),
+    CodePointRange::from(1567, 1),
     CodePointRange::from(1984, 59),
     CodePointRange::from(2045, 3),
+    CodePointRange::from(64830, 2),
 ];
 
-/// Return whether c has the 'Nko' Unicode property.
-pub(crate) fn is_nko(c: char) -> bool {
-    NKO.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
+/// Return whether c has 'Nko' in its Script_Extensions Unicode property.
+pub(crate) fn is_nko_extensions(c: char) -> bool {
+    NKO_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
 }
 
-pub(crate) const NUSHU: [CodePointRange; 2] = [
+pub(crate) const NKO: [CodePointRange; 2] = [
+    CodePointRange::from(1984, 59),
+    CodePointRange::from(2045, 3),
+];
+
+/// Return whether c has the 'Nko' Unicode property.
+pub(crate) fn is_nko(c: char) -> bool {
+    NKO.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
+}
+
+pub(crate) const NUSHU: [CodePointRange; 2] = [
     CodePointRange::from(94177, 1),
     CodePointRange::from(110960, 396),
 ];
@@ -14316,6 +15646,18 @@ pub(crate) fn is_old_north_arabian(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const OLD_PERMIC_EXTENSIONS: [CodePointRange; 2] = [
+    CodePointRange::from(1155, 1),
+    CodePointRange::from(66384, 43),
+];
+
+/// Return whether c has 'Old_Permic' in its Script_Extensions Unicode property.
+pub(crate) fn is_old_permic_extensions(c: char) -> bool {
+    OLD_PERMIC_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const OLD_PERMIC: [CodePointRange; 1] = [CodePointRange::from(66384, 43)];
 
 /// Return whether c has the 'Old_Permic' Unicode property.
@@ -14364,6 +15706,34 @@ pub(crate) fn is_old_turkic(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const ORIYA_EXTENSIONS: [CodePointRange; 18] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(2817, 3),
+    CodePointRange::from(2821, 8),
+    CodePointRange::from(2831, 2),
+    CodePointRange::from(2835, 22),
+    CodePointRange::from(2858, 7),
+    CodePointRange::from(2866, 2),
+    CodePointRange::from(2869, 5),
+    CodePointRange::from(2876, 9),
+    CodePointRange::from(2887, 2),
+    CodePointRange::from(2891, 3),
+    CodePointRange::from(2901, 3),
+    CodePointRange::from(2908, 2),
+    CodePointRange::from(2911, 5),
+    CodePointRange::from(2918, 18),
+    CodePointRange::from(7386, 1),
+    CodePointRange::from(7410, 1),
+];
+
+/// Return whether c has 'Oriya' in its Script_Extensions Unicode property.
+pub(crate) fn is_oriya_extensions(c: char) -> bool {
+    ORIYA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const ORIYA: [CodePointRange; 14] = [
     CodePointRange::from(2817, 3),
     CodePointRange::from(2821, 8),
@@ -14441,6 +15811,19 @@ pub(crate) fn is_pau_cin_hau(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const PHAGS_PA_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(6146, 2),
+    CodePointRange::from(6149, 1),
+    CodePointRange::from(43072, 56),
+];
+
+/// Return whether c has 'Phags_Pa' in its Script_Extensions Unicode property.
+pub(crate) fn is_phags_pa_extensions(c: char) -> bool {
+    PHAGS_PA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const PHAGS_PA: [CodePointRange; 1] = [CodePointRange::from(43072, 56)];
 
 /// Return whether c has the 'Phags_Pa' Unicode property.
@@ -14462,6 +15845,20 @@ pub(crate) fn is_phoenician(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const PSALTER_PAHLAVI_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(1600, 1),
+    CodePointRange::from(68480, 18),
+    CodePointRange::from(68505, 4),
+    CodePointRange::from(68521, 7),
+];
+
+/// Return whether c has 'Psalter_Pahlavi' in its Script_Extensions Unicode property.
+pub(crate) fn is_psalter_pahlavi_extensions(c: char) -> bool {
+    PSALTER_PAHLAVI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const PSALTER_PAHLAVI: [CodePointRange; 3] = [
     CodePointRange::from(68480, 18),
     CodePointRange::from(68505, 4),
@@ -14521,6 +15918,22 @@ pub(crate) fn is_saurashtra(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const SHARADA_EXTENSIONS: [CodePointRange; 6] = [
+    CodePointRange::from(2385, 1),
+    CodePointRange::from(7383, 1),
+    CodePointRange::from(7385, 1),
+    CodePointRange::from(7388, 2),
+    CodePointRange::from(7392, 1),
+    CodePointRange::from(70016, 96),
+];
+
+/// Return whether c has 'Sharada' in its Script_Extensions Unicode property.
+pub(crate) fn is_sharada_extensions(c: char) -> bool {
+    SHARADA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const SHARADA: [CodePointRange; 1] = [CodePointRange::from(70016, 96)];
 
 /// Return whether c has the 'Sharada' Unicode property.
@@ -14564,6 +15977,30 @@ pub(crate) fn is_signwriting(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const SINHALA_EXTENSIONS: [CodePointRange; 14] = [
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(3457, 3),
+    CodePointRange::from(3461, 18),
+    CodePointRange::from(3482, 24),
+    CodePointRange::from(3507, 9),
+    CodePointRange::from(3517, 1),
+    CodePointRange::from(3520, 7),
+    CodePointRange::from(3530, 1),
+    CodePointRange::from(3535, 6),
+    CodePointRange::from(3542, 1),
+    CodePointRange::from(3544, 8),
+    CodePointRange::from(3558, 10),
+    CodePointRange::from(3570, 3),
+    CodePointRange::from(70113, 20),
+];
+
+/// Return whether c has 'Sinhala' in its Script_Extensions Unicode property.
+pub(crate) fn is_sinhala_extensions(c: char) -> bool {
+    SINHALA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const SINHALA: [CodePointRange; 13] = [
     CodePointRange::from(3457, 3),
     CodePointRange::from(3461, 18),
@@ -14587,6 +16024,18 @@ pub(crate) fn is_sinhala(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const SOGDIAN_EXTENSIONS: [CodePointRange; 2] = [
+    CodePointRange::from(1600, 1),
+    CodePointRange::from(69424, 42),
+];
+
+/// Return whether c has 'Sogdian' in its Script_Extensions Unicode property.
+pub(crate) fn is_sogdian_extensions(c: char) -> bool {
+    SOGDIAN_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const SOGDIAN: [CodePointRange; 1] = [CodePointRange::from(69424, 42)];
 
 /// Return whether c has the 'Sogdian' Unicode property.
@@ -14629,6 +16078,19 @@ pub(crate) fn is_sundanese(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const SYLOTI_NAGRI_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(2534, 10),
+    CodePointRange::from(43008, 45),
+];
+
+/// Return whether c has 'Syloti_Nagri' in its Script_Extensions Unicode property.
+pub(crate) fn is_syloti_nagri_extensions(c: char) -> bool {
+    SYLOTI_NAGRI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const SYLOTI_NAGRI: [CodePointRange; 1] = [CodePointRange::from(43008, 45)];
 
 /// Return whether c has the 'Syloti_Nagri' Unicode property.
@@ -14638,6 +16100,28 @@ pub(crate) fn is_syloti_nagri(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const SYRIAC_EXTENSIONS: [CodePointRange; 12] = [
+    CodePointRange::from(1548, 1),
+    CodePointRange::from(1563, 2),
+    CodePointRange::from(1567, 1),
+    CodePointRange::from(1600, 1),
+    CodePointRange::from(1611, 11),
+    CodePointRange::from(1648, 1),
+    CodePointRange::from(1792, 14),
+    CodePointRange::from(1807, 60),
+    CodePointRange::from(1869, 3),
+    CodePointRange::from(2144, 11),
+    CodePointRange::from(7672, 1),
+    CodePointRange::from(7674, 1),
+];
+
+/// Return whether c has 'Syriac' in its Script_Extensions Unicode property.
+pub(crate) fn is_syriac_extensions(c: char) -> bool {
+    SYRIAC_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const SYRIAC: [CodePointRange; 4] = [
     CodePointRange::from(1792, 14),
     CodePointRange::from(1807, 60),
@@ -14652,6 +16136,19 @@ pub(crate) fn is_syriac(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const TAGALOG_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(5888, 22),
+    CodePointRange::from(5919, 1),
+    CodePointRange::from(5941, 2),
+];
+
+/// Return whether c has 'Tagalog' in its Script_Extensions Unicode property.
+pub(crate) fn is_tagalog_extensions(c: char) -> bool {
+    TAGALOG_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const TAGALOG: [CodePointRange; 2] = [
     CodePointRange::from(5888, 22),
     CodePointRange::from(5919, 1),
@@ -14664,6 +16161,20 @@ pub(crate) fn is_tagalog(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const TAGBANWA_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(5941, 2),
+    CodePointRange::from(5984, 13),
+    CodePointRange::from(5998, 3),
+    CodePointRange::from(6002, 2),
+];
+
+/// Return whether c has 'Tagbanwa' in its Script_Extensions Unicode property.
+pub(crate) fn is_tagbanwa_extensions(c: char) -> bool {
+    TAGBANWA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const TAGBANWA: [CodePointRange; 3] = [
     CodePointRange::from(5984, 13),
     CodePointRange::from(5998, 3),
@@ -14677,6 +16188,19 @@ pub(crate) fn is_tagbanwa(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const TAI_LE_EXTENSIONS: [CodePointRange; 3] = [
+    CodePointRange::from(4160, 10),
+    CodePointRange::from(6480, 30),
+    CodePointRange::from(6512, 5),
+];
+
+/// Return whether c has 'Tai_Le' in its Script_Extensions Unicode property.
+pub(crate) fn is_tai_le_extensions(c: char) -> bool {
+    TAI_LE_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const TAI_LE: [CodePointRange; 2] = [
     CodePointRange::from(6480, 30),
     CodePointRange::from(6512, 5),
@@ -14716,6 +16240,20 @@ pub(crate) fn is_tai_viet(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const TAKRI_EXTENSIONS: [CodePointRange; 4] = [
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(71296, 58),
+    CodePointRange::from(71360, 10),
+];
+
+/// Return whether c has 'Takri' in its Script_Extensions Unicode property.
+pub(crate) fn is_takri_extensions(c: char) -> bool {
+    TAKRI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const TAKRI: [CodePointRange; 2] = [
     CodePointRange::from(71296, 58),
     CodePointRange::from(71360, 10),
@@ -14726,6 +16264,41 @@ pub(crate) fn is_takri(c: char) -> bool {
     TAKRI.binary_search_by(|&cpr| cpr.compare(c as u32)).is_ok()
 }
 
+pub(crate) const TAMIL_EXTENSIONS: [CodePointRange; 25] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(2946, 2),
+    CodePointRange::from(2949, 6),
+    CodePointRange::from(2958, 3),
+    CodePointRange::from(2962, 4),
+    CodePointRange::from(2969, 2),
+    CodePointRange::from(2972, 1),
+    CodePointRange::from(2974, 2),
+    CodePointRange::from(2979, 2),
+    CodePointRange::from(2984, 3),
+    CodePointRange::from(2990, 12),
+    CodePointRange::from(3006, 5),
+    CodePointRange::from(3014, 3),
+    CodePointRange::from(3018, 4),
+    CodePointRange::from(3024, 1),
+    CodePointRange::from(3031, 1),
+    CodePointRange::from(3046, 21),
+    CodePointRange::from(7386, 1),
+    CodePointRange::from(43251, 1),
+    CodePointRange::from(70401, 1),
+    CodePointRange::from(70403, 1),
+    CodePointRange::from(70459, 2),
+    CodePointRange::from(73664, 50),
+    CodePointRange::from(73727, 1),
+];
+
+/// Return whether c has 'Tamil' in its Script_Extensions Unicode property.
+pub(crate) fn is_tamil_extensions(c: char) -> bool {
+    TAMIL_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const TAMIL: [CodePointRange; 18] = [
     CodePointRange::from(2946, 2),
     CodePointRange::from(2949, 6),
@@ -14767,6 +16340,33 @@ pub(crate) fn is_tangut(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const TELUGU_EXTENSIONS: [CodePointRange; 17] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(3072, 13),
+    CodePointRange::from(3086, 3),
+    CodePointRange::from(3090, 23),
+    CodePointRange::from(3114, 16),
+    CodePointRange::from(3132, 9),
+    CodePointRange::from(3142, 3),
+    CodePointRange::from(3146, 4),
+    CodePointRange::from(3157, 2),
+    CodePointRange::from(3160, 3),
+    CodePointRange::from(3165, 1),
+    CodePointRange::from(3168, 4),
+    CodePointRange::from(3174, 10),
+    CodePointRange::from(3191, 9),
+    CodePointRange::from(7386, 1),
+    CodePointRange::from(7410, 1),
+];
+
+/// Return whether c has 'Telugu' in its Script_Extensions Unicode property.
+pub(crate) fn is_telugu_extensions(c: char) -> bool {
+    TELUGU_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const TELUGU: [CodePointRange; 13] = [
     CodePointRange::from(3072, 13),
     CodePointRange::from(3086, 3),
@@ -14790,6 +16390,23 @@ pub(crate) fn is_telugu(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const THAANA_EXTENSIONS: [CodePointRange; 7] = [
+    CodePointRange::from(1548, 1),
+    CodePointRange::from(1563, 2),
+    CodePointRange::from(1567, 1),
+    CodePointRange::from(1632, 10),
+    CodePointRange::from(1920, 50),
+    CodePointRange::from(65010, 1),
+    CodePointRange::from(65021, 1),
+];
+
+/// Return whether c has 'Thaana' in its Script_Extensions Unicode property.
+pub(crate) fn is_thaana_extensions(c: char) -> bool {
+    THAANA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const THAANA: [CodePointRange; 1] = [CodePointRange::from(1920, 50)];
 
 /// Return whether c has the 'Thaana' Unicode property.
@@ -14839,6 +16456,22 @@ pub(crate) fn is_tifinagh(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const TIRHUTA_EXTENSIONS: [CodePointRange; 6] = [
+    CodePointRange::from(2385, 2),
+    CodePointRange::from(2404, 2),
+    CodePointRange::from(7410, 1),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(70784, 72),
+    CodePointRange::from(70864, 10),
+];
+
+/// Return whether c has 'Tirhuta' in its Script_Extensions Unicode property.
+pub(crate) fn is_tirhuta_extensions(c: char) -> bool {
+    TIRHUTA_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const TIRHUTA: [CodePointRange; 2] = [
     CodePointRange::from(70784, 72),
     CodePointRange::from(70864, 10),
@@ -14894,6 +16527,23 @@ pub(crate) fn is_warang_citi(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const YEZIDI_EXTENSIONS: [CodePointRange; 7] = [
+    CodePointRange::from(1548, 1),
+    CodePointRange::from(1563, 1),
+    CodePointRange::from(1567, 1),
+    CodePointRange::from(1632, 10),
+    CodePointRange::from(69248, 42),
+    CodePointRange::from(69291, 3),
+    CodePointRange::from(69296, 2),
+];
+
+/// Return whether c has 'Yezidi' in its Script_Extensions Unicode property.
+pub(crate) fn is_yezidi_extensions(c: char) -> bool {
+    YEZIDI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const YEZIDI: [CodePointRange; 3] = [
     CodePointRange::from(69248, 42),
     CodePointRange::from(69291, 3),
@@ -14907,6 +16557,23 @@ pub(crate) fn is_yezidi(c: char) -> bool {
         .is_ok()
 }
 
+pub(crate) const YI_EXTENSIONS: [CodePointRange; 7] = [
+    CodePointRange::from(12289, 2),
+    CodePointRange::from(12296, 10),
+    CodePointRange::from(12308, 8),
+    CodePointRange::from(12539, 1),
+    CodePointRange::from(40960, 1165),
+    CodePointRange::from(42128, 55),
+    CodePointRange::from(65377, 5),
+];
+
+/// Return whether c has 'Yi' in its Script_Extensions Unicode property.
+pub(crate) fn is_yi_extensions(c: char) -> bool {
+    YI_EXTENSIONS
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
 pub(crate) const YI: [CodePointRange; 2] = [
     CodePointRange::from(40960, 1165),
     CodePointRange::from(42128, 55),
@@ -15088,20 +16755,511 @@ pub(crate) fn is_property_value_script(c: char, value: &UnicodePropertyValueScri
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum UnicodePropertyValueScript {
-    Adlam,
-    Ahom,
-    AnatolianHieroglyphs,
-    Arabic,
-    Armenian,
-    Avestan,
-    Balinese,
-    Bamum,
-    BassaVah,
-    Batak,
-    Bengali,
-    Bhaiksuki,
+pub(crate) fn is_property_value_script_extensions(
+    c: char,
+    value: &UnicodePropertyValueScript,
+) -> bool {
+    use UnicodePropertyValueScript::*;
+    match value {
+        Adlam => is_adlam_extensions(c),
+        Ahom => is_ahom(c),
+        AnatolianHieroglyphs => is_anatolian_hieroglyphs(c),
+        Arabic => is_arabic_extensions(c),
+        Armenian => is_armenian(c),
+        Avestan => is_avestan(c),
+        Balinese => is_balinese(c),
+        Bamum => is_bamum(c),
+        BassaVah => is_bassa_vah(c),
+        Batak => is_batak(c),
+        Bengali => is_bengali_extensions(c),
+        Bhaiksuki => is_bhaiksuki(c),
+        Bopomofo => is_bopomofo_extensions(c),
+        Brahmi => is_brahmi(c),
+        Braille => is_braille(c),
+        Buginese => is_buginese_extensions(c),
+        Buhid => is_buhid_extensions(c),
+        CanadianAboriginal => is_canadian_aboriginal(c),
+        Carian => is_carian(c),
+        CaucasianAlbanian => is_caucasian_albanian(c),
+        Chakma => is_chakma_extensions(c),
+        Cham => is_cham(c),
+        Chorasmian => is_chorasmian(c),
+        Cherokee => is_cherokee(c),
+        Common => is_common_extensions(c),
+        Coptic => is_coptic_extensions(c),
+        Cuneiform => is_cuneiform(c),
+        Cypriot => is_cypriot_extensions(c),
+        Cyrillic => is_cyrillic_extensions(c),
+        Deseret => is_deseret(c),
+        Devanagari => is_devanagari_extensions(c),
+        DivesAkuru => is_dives_akuru(c),
+        Dogra => is_dogra_extensions(c),
+        Duployan => is_duployan_extensions(c),
+        EgyptianHieroglyphs => is_egyptian_hieroglyphs(c),
+        Elbasan => is_elbasan(c),
+        Elymaic => is_elymaic(c),
+        Ethiopic => is_ethiopic(c),
+        Georgian => is_georgian_extensions(c),
+        Glagolitic => is_glagolitic_extensions(c),
+        Gothic => is_gothic(c),
+        Grantha => is_grantha_extensions(c),
+        Greek => is_greek_extensions(c),
+        Gujarati => is_gujarati_extensions(c),
+        GunjalaGondi => is_gunjala_gondi_extensions(c),
+        Gurmukhi => is_gurmukhi_extensions(c),
+        Han => is_han_extensions(c),
+        Hangul => is_hangul_extensions(c),
+        HanifiRohingya => is_hanifi_rohingya_extensions(c),
+        Hanunoo => is_hanunoo_extensions(c),
+        Hatran => is_hatran(c),
+        Hebrew => is_hebrew(c),
+        Hiragana => is_hiragana_extensions(c),
+        ImperialAramaic => is_imperial_aramaic(c),
+        Inherited => is_inherited_extensions(c),
+        InscriptionalPahlavi => is_inscriptional_pahlavi(c),
+        InscriptionalParthian => is_inscriptional_parthian(c),
+        Javanese => is_javanese_extensions(c),
+        Kaithi => is_kaithi_extensions(c),
+        Kannada => is_kannada_extensions(c),
+        Katakana => is_katakana_extensions(c),
+        KayahLi => is_kayah_li_extensions(c),
+        Kharoshthi => is_kharoshthi(c),
+        KhitanSmallScript => is_khitan_small_script(c),
+        Khmer => is_khmer(c),
+        Khojki => is_khojki_extensions(c),
+        Khudawadi => is_khudawadi_extensions(c),
+        Lao => is_lao(c),
+        Latin => is_latin_extensions(c),
+        Lepcha => is_lepcha(c),
+        Limbu => is_limbu_extensions(c),
+        LinearA => is_linear_a_extensions(c),
+        LinearB => is_linear_b_extensions(c),
+        Lisu => is_lisu(c),
+        Lycian => is_lycian(c),
+        Lydian => is_lydian(c),
+        Mahajani => is_mahajani_extensions(c),
+        Makasar => is_makasar(c),
+        Malayalam => is_malayalam_extensions(c),
+        Mandaic => is_mandaic_extensions(c),
+        Manichaean => is_manichaean_extensions(c),
+        Marchen => is_marchen(c),
+        Medefaidrin => is_medefaidrin(c),
+        MasaramGondi => is_masaram_gondi_extensions(c),
+        MeeteiMayek => is_meetei_mayek(c),
+        MendeKikakui => is_mende_kikakui(c),
+        MeroiticCursive => is_meroitic_cursive(c),
+        MeroiticHieroglyphs => is_meroitic_hieroglyphs(c),
+        Miao => is_miao(c),
+        Modi => is_modi_extensions(c),
+        Mongolian => is_mongolian_extensions(c),
+        Mro => is_mro(c),
+        Multani => is_multani_extensions(c),
+        Myanmar => is_myanmar_extensions(c),
+        Nabataean => is_nabataean(c),
+        Nandinagari => is_nandinagari_extensions(c),
+        NewTaiLue => is_new_tai_lue(c),
+        Newa => is_newa(c),
+        Nko => is_nko_extensions(c),
+        Nushu => is_nushu(c),
+        NyiakengPuachueHmong => is_nyiakeng_puachue_hmong(c),
+        Ogham => is_ogham(c),
+        OlChiki => is_ol_chiki(c),
+        OldHungarian => is_old_hungarian(c),
+        OldItalic => is_old_italic(c),
+        OldNorthArabian => is_old_north_arabian(c),
+        OldPermic => is_old_permic_extensions(c),
+        OldPersian => is_old_persian(c),
+        OldSogdian => is_old_sogdian(c),
+        OldSouthArabian => is_old_south_arabian(c),
+        OldTurkic => is_old_turkic(c),
+        Oriya => is_oriya_extensions(c),
+        Osage => is_osage(c),
+        Osmanya => is_osmanya(c),
+        PahawhHmong => is_pahawh_hmong(c),
+        Palmyrene => is_palmyrene(c),
+        PauCinHau => is_pau_cin_hau(c),
+        PhagsPa => is_phags_pa_extensions(c),
+        Phoenician => is_phoenician(c),
+        PsalterPahlavi => is_psalter_pahlavi_extensions(c),
+        Rejang => is_rejang(c),
+        Runic => is_runic(c),
+        Samaritan => is_samaritan(c),
+        Saurashtra => is_saurashtra(c),
+        Sharada => is_sharada_extensions(c),
+        Shavian => is_shavian(c),
+        Siddham => is_siddham(c),
+        SignWriting => is_signwriting(c),
+        Sinhala => is_sinhala_extensions(c),
+        Sogdian => is_sogdian_extensions(c),
+        SoraSompeng => is_sora_sompeng(c),
+        Soyombo => is_soyombo(c),
+        Sundanese => is_sundanese(c),
+        SylotiNagri => is_syloti_nagri_extensions(c),
+        Syriac => is_syriac_extensions(c),
+        Tagalog => is_tagalog_extensions(c),
+        Tagbanwa => is_tagbanwa_extensions(c),
+        TaiLe => is_tai_le_extensions(c),
+        TaiTham => is_tai_tham(c),
+        TaiViet => is_tai_viet(c),
+        Takri => is_takri_extensions(c),
+        Tamil => is_tamil_extensions(c),
+        Tangut => is_tangut(c),
+        Telugu => is_telugu_extensions(c),
+        Thaana => is_thaana_extensions(c),
+        Thai => is_thai(c),
+        Tibetan => is_tibetan(c),
+        Tifinagh => is_tifinagh(c),
+        Tirhuta => is_tirhuta_extensions(c),
+        Ugaritic => is_ugaritic(c),
+        Vai => is_vai(c),
+        Wancho => is_wancho(c),
+        WarangCiti => is_warang_citi(c),
+        Yezidi => is_yezidi_extensions(c),
+        Yi => is_yi_extensions(c),
+        ZanabazarSquare => is_zanabazar_square(c),
+    }
+}
+
+pub(crate) fn property_value_script_table(value: &UnicodePropertyValueScript) -> CodePointTable {
+    use UnicodePropertyValueScript::*;
+    match value {
+        Adlam => CodePointTable::Packed(&ADLAM),
+        Ahom => CodePointTable::Packed(&AHOM),
+        AnatolianHieroglyphs => CodePointTable::Packed(&ANATOLIAN_HIEROGLYPHS),
+        Arabic => CodePointTable::Packed(&ARABIC),
+        Armenian => CodePointTable::Packed(&ARMENIAN),
+        Avestan => CodePointTable::Packed(&AVESTAN),
+        Balinese => CodePointTable::Packed(&BALINESE),
+        Bamum => CodePointTable::Packed(&BAMUM),
+        BassaVah => CodePointTable::Packed(&BASSA_VAH),
+        Batak => CodePointTable::Packed(&BATAK),
+        Bengali => CodePointTable::Packed(&BENGALI),
+        Bhaiksuki => CodePointTable::Packed(&BHAIKSUKI),
+        Bopomofo => CodePointTable::Packed(&BOPOMOFO),
+        Brahmi => CodePointTable::Packed(&BRAHMI),
+        Braille => CodePointTable::Packed(&BRAILLE),
+        Buginese => CodePointTable::Packed(&BUGINESE),
+        Buhid => CodePointTable::Packed(&BUHID),
+        CanadianAboriginal => CodePointTable::Packed(&CANADIAN_ABORIGINAL),
+        Carian => CodePointTable::Packed(&CARIAN),
+        CaucasianAlbanian => CodePointTable::Packed(&CAUCASIAN_ALBANIAN),
+        Chakma => CodePointTable::Packed(&CHAKMA),
+        Cham => CodePointTable::Packed(&CHAM),
+        Chorasmian => CodePointTable::Packed(&CHORASMIAN),
+        Cherokee => CodePointTable::Packed(&CHEROKEE),
+        Common => CodePointTable::Packed(&COMMON),
+        Coptic => CodePointTable::Packed(&COPTIC),
+        Cuneiform => CodePointTable::Packed(&CUNEIFORM),
+        Cypriot => CodePointTable::Packed(&CYPRIOT),
+        Cyrillic => CodePointTable::Packed(&CYRILLIC),
+        Deseret => CodePointTable::Packed(&DESERET),
+        Devanagari => CodePointTable::Packed(&DEVANAGARI),
+        DivesAkuru => CodePointTable::Packed(&DIVES_AKURU),
+        Dogra => CodePointTable::Packed(&DOGRA),
+        Duployan => CodePointTable::Packed(&DUPLOYAN),
+        EgyptianHieroglyphs => CodePointTable::Packed(&EGYPTIAN_HIEROGLYPHS),
+        Elbasan => CodePointTable::Packed(&ELBASAN),
+        Elymaic => CodePointTable::Packed(&ELYMAIC),
+        Ethiopic => CodePointTable::Packed(&ETHIOPIC),
+        Georgian => CodePointTable::Packed(&GEORGIAN),
+        Glagolitic => CodePointTable::Packed(&GLAGOLITIC),
+        Gothic => CodePointTable::Packed(&GOTHIC),
+        Grantha => CodePointTable::Packed(&GRANTHA),
+        Greek => CodePointTable::Packed(&GREEK),
+        Gujarati => CodePointTable::Packed(&GUJARATI),
+        GunjalaGondi => CodePointTable::Packed(&GUNJALA_GONDI),
+        Gurmukhi => CodePointTable::Packed(&GURMUKHI),
+        Han => CodePointTable::Packed(&HAN),
+        Hangul => CodePointTable::Packed(&HANGUL),
+        HanifiRohingya => CodePointTable::Packed(&HANIFI_ROHINGYA),
+        Hanunoo => CodePointTable::Packed(&HANUNOO),
+        Hatran => CodePointTable::Packed(&HATRAN),
+        Hebrew => CodePointTable::Packed(&HEBREW),
+        Hiragana => CodePointTable::Packed(&HIRAGANA),
+        ImperialAramaic => CodePointTable::Packed(&IMPERIAL_ARAMAIC),
+        Inherited => CodePointTable::Packed(&INHERITED),
+        InscriptionalPahlavi => CodePointTable::Packed(&INSCRIPTIONAL_PAHLAVI),
+        InscriptionalParthian => CodePointTable::Packed(&INSCRIPTIONAL_PARTHIAN),
+        Javanese => CodePointTable::Packed(&JAVANESE),
+        Kaithi => CodePointTable::Packed(&KAITHI),
+        Kannada => CodePointTable::Packed(&KANNADA),
+        Katakana => CodePointTable::Packed(&KATAKANA),
+        KayahLi => CodePointTable::Packed(&KAYAH_LI),
+        Kharoshthi => CodePointTable::Packed(&KHAROSHTHI),
+        KhitanSmallScript => CodePointTable::Packed(&KHITAN_SMALL_SCRIPT),
+        Khmer => CodePointTable::Packed(&KHMER),
+        Khojki => CodePointTable::Packed(&KHOJKI),
+        Khudawadi => CodePointTable::Packed(&KHUDAWADI),
+        Lao => CodePointTable::Packed(&LAO),
+        Latin => CodePointTable::Packed(&LATIN),
+        Lepcha => CodePointTable::Packed(&LEPCHA),
+        Limbu => CodePointTable::Packed(&LIMBU),
+        LinearA => CodePointTable::Packed(&LINEAR_A),
+        LinearB => CodePointTable::Packed(&LINEAR_B),
+        Lisu => CodePointTable::Packed(&LISU),
+        Lycian => CodePointTable::Packed(&LYCIAN),
+        Lydian => CodePointTable::Packed(&LYDIAN),
+        Mahajani => CodePointTable::Packed(&MAHAJANI),
+        Makasar => CodePointTable::Packed(&MAKASAR),
+        Malayalam => CodePointTable::Packed(&MALAYALAM),
+        Mandaic => CodePointTable::Packed(&MANDAIC),
+        Manichaean => CodePointTable::Packed(&MANICHAEAN),
+        Marchen => CodePointTable::Packed(&MARCHEN),
+        Medefaidrin => CodePointTable::Packed(&MEDEFAIDRIN),
+        MasaramGondi => CodePointTable::Packed(&MASARAM_GONDI),
+        MeeteiMayek => CodePointTable::Packed(&MEETEI_MAYEK),
+        MendeKikakui => CodePointTable::Packed(&MENDE_KIKAKUI),
+        MeroiticCursive => CodePointTable::Packed(&MEROITIC_CURSIVE),
+        MeroiticHieroglyphs => CodePointTable::Packed(&MEROITIC_HIEROGLYPHS),
+        Miao => CodePointTable::Packed(&MIAO),
+        Modi => CodePointTable::Packed(&MODI),
+        Mongolian => CodePointTable::Packed(&MONGOLIAN),
+        Mro => CodePointTable::Packed(&MRO),
+        Multani => CodePointTable::Packed(&MULTANI),
+        Myanmar => CodePointTable::Packed(&MYANMAR),
+        Nabataean => CodePointTable::Packed(&NABATAEAN),
+        Nandinagari => CodePointTable::Packed(&NANDINAGARI),
+        NewTaiLue => CodePointTable::Packed(&NEW_TAI_LUE),
+        Newa => CodePointTable::Packed(&NEWA),
+        Nko => CodePointTable::Packed(&NKO),
+        Nushu => CodePointTable::Packed(&NUSHU),
+        NyiakengPuachueHmong => CodePointTable::Packed(&NYIAKENG_PUACHUE_HMONG),
+        Ogham => CodePointTable::Packed(&OGHAM),
+        OlChiki => CodePointTable::Packed(&OL_CHIKI),
+        OldHungarian => CodePointTable::Packed(&OLD_HUNGARIAN),
+        OldItalic => CodePointTable::Packed(&OLD_ITALIC),
+        OldNorthArabian => CodePointTable::Packed(&OLD_NORTH_ARABIAN),
+        OldPermic => CodePointTable::Packed(&OLD_PERMIC),
+        OldPersian => CodePointTable::Packed(&OLD_PERSIAN),
+        OldSogdian => CodePointTable::Packed(&OLD_SOGDIAN),
+        OldSouthArabian => CodePointTable::Packed(&OLD_SOUTH_ARABIAN),
+        OldTurkic => CodePointTable::Packed(&OLD_TURKIC),
+        Oriya => CodePointTable::Packed(&ORIYA),
+        Osage => CodePointTable::Packed(&OSAGE),
+        Osmanya => CodePointTable::Packed(&OSMANYA),
+        PahawhHmong => CodePointTable::Packed(&PAHAWH_HMONG),
+        Palmyrene => CodePointTable::Packed(&PALMYRENE),
+        PauCinHau => CodePointTable::Packed(&PAU_CIN_HAU),
+        PhagsPa => CodePointTable::Packed(&PHAGS_PA),
+        Phoenician => CodePointTable::Packed(&PHOENICIAN),
+        PsalterPahlavi => CodePointTable::Packed(&PSALTER_PAHLAVI),
+        Rejang => CodePointTable::Packed(&REJANG),
+        Runic => CodePointTable::Packed(&RUNIC),
+        Samaritan => CodePointTable::Packed(&SAMARITAN),
+        Saurashtra => CodePointTable::Packed(&SAURASHTRA),
+        Sharada => CodePointTable::Packed(&SHARADA),
+        Shavian => CodePointTable::Packed(&SHAVIAN),
+        Siddham => CodePointTable::Packed(&SIDDHAM),
+        SignWriting => CodePointTable::Packed(&SIGNWRITING),
+        Sinhala => CodePointTable::Packed(&SINHALA),
+        Sogdian => CodePointTable::Packed(&SOGDIAN),
+        SoraSompeng => CodePointTable::Packed(&SORA_SOMPENG),
+        Soyombo => CodePointTable::Packed(&SOYOMBO),
+        Sundanese => CodePointTable::Packed(&SUNDANESE),
+        SylotiNagri => CodePointTable::Packed(&SYLOTI_NAGRI),
+        Syriac => CodePointTable::Packed(&SYRIAC),
+        Tagalog => CodePointTable::Packed(&TAGALOG),
+        Tagbanwa => CodePointTable::Packed(&TAGBANWA),
+        TaiLe => CodePointTable::Packed(&TAI_LE),
+        TaiTham => CodePointTable::Packed(&TAI_THAM),
+        TaiViet => CodePointTable::Packed(&TAI_VIET),
+        Takri => CodePointTable::Packed(&TAKRI),
+        Tamil => CodePointTable::Packed(&TAMIL),
+        Tangut => CodePointTable::Packed(&TANGUT),
+        Telugu => CodePointTable::Packed(&TELUGU),
+        Thaana => CodePointTable::Packed(&THAANA),
+        Thai => CodePointTable::Packed(&THAI),
+        Tibetan => CodePointTable::Packed(&TIBETAN),
+        Tifinagh => CodePointTable::Packed(&TIFINAGH),
+        Tirhuta => CodePointTable::Packed(&TIRHUTA),
+        Ugaritic => CodePointTable::Packed(&UGARITIC),
+        Vai => CodePointTable::Packed(&VAI),
+        Wancho => CodePointTable::Packed(&WANCHO),
+        WarangCiti => CodePointTable::Packed(&WARANG_CITI),
+        Yezidi => CodePointTable::Packed(&YEZIDI),
+        Yi => CodePointTable::Packed(&YI),
+        ZanabazarSquare => CodePointTable::Packed(&ZANABAZAR_SQUARE),
+    }
+}
+
+pub(crate) fn property_value_script_extensions_table(
+    value: &UnicodePropertyValueScript,
+) -> CodePointTable {
+    use UnicodePropertyValueScript::*;
+    match value {
+        Adlam => CodePointTable::Packed(&ADLAM_EXTENSIONS),
+        Ahom => CodePointTable::Packed(&AHOM),
+        AnatolianHieroglyphs => CodePointTable::Packed(&ANATOLIAN_HIEROGLYPHS),
+        Arabic => CodePointTable::Packed(&ARABIC_EXTENSIONS),
+        Armenian => CodePointTable::Packed(&ARMENIAN),
+        Avestan => CodePointTable::Packed(&AVESTAN),
+        Balinese => CodePointTable::Packed(&BALINESE),
+        Bamum => CodePointTable::Packed(&BAMUM),
+        BassaVah => CodePointTable::Packed(&BASSA_VAH),
+        Batak => CodePointTable::Packed(&BATAK),
+        Bengali => CodePointTable::Packed(&BENGALI_EXTENSIONS),
+        Bhaiksuki => CodePointTable::Packed(&BHAIKSUKI),
+        Bopomofo => CodePointTable::Packed(&BOPOMOFO_EXTENSIONS),
+        Brahmi => CodePointTable::Packed(&BRAHMI),
+        Braille => CodePointTable::Packed(&BRAILLE),
+        Buginese => CodePointTable::Packed(&BUGINESE_EXTENSIONS),
+        Buhid => CodePointTable::Packed(&BUHID_EXTENSIONS),
+        CanadianAboriginal => CodePointTable::Packed(&CANADIAN_ABORIGINAL),
+        Carian => CodePointTable::Packed(&CARIAN),
+        CaucasianAlbanian => CodePointTable::Packed(&CAUCASIAN_ALBANIAN),
+        Chakma => CodePointTable::Packed(&CHAKMA_EXTENSIONS),
+        Cham => CodePointTable::Packed(&CHAM),
+        Chorasmian => CodePointTable::Packed(&CHORASMIAN),
+        Cherokee => CodePointTable::Packed(&CHEROKEE),
+        Common => CodePointTable::Packed(&COMMON_EXTENSIONS),
+        Coptic => CodePointTable::Packed(&COPTIC_EXTENSIONS),
+        Cuneiform => CodePointTable::Packed(&CUNEIFORM),
+        Cypriot => CodePointTable::Packed(&CYPRIOT_EXTENSIONS),
+        Cyrillic => CodePointTable::Packed(&CYRILLIC_EXTENSIONS),
+        Deseret => CodePointTable::Packed(&DESERET),
+        Devanagari => CodePointTable::Packed(&DEVANAGARI_EXTENSIONS),
+        DivesAkuru => CodePointTable::Packed(&DIVES_AKURU),
+        Dogra => CodePointTable::Packed(&DOGRA_EXTENSIONS),
+        Duployan => CodePointTable::Packed(&DUPLOYAN_EXTENSIONS),
+        EgyptianHieroglyphs => CodePointTable::Packed(&EGYPTIAN_HIEROGLYPHS),
+        Elbasan => CodePointTable::Packed(&ELBASAN),
+        Elymaic => CodePointTable::Packed(&ELYMAIC),
+        Ethiopic => CodePointTable::Packed(&ETHIOPIC),
+        Georgian => CodePointTable::Packed(&GEORGIAN_EXTENSIONS),
+        Glagolitic => CodePointTable::Packed(&GLAGOLITIC_EXTENSIONS),
+        Gothic => CodePointTable::Packed(&GOTHIC),
+        Grantha => CodePointTable::Packed(&GRANTHA_EXTENSIONS),
+        Greek => CodePointTable::Packed(&GREEK_EXTENSIONS),
+        Gujarati => CodePointTable::Packed(&GUJARATI_EXTENSIONS),
+        GunjalaGondi => CodePointTable::Packed(&GUNJALA_GONDI_EXTENSIONS),
+        Gurmukhi => CodePointTable::Packed(&GURMUKHI_EXTENSIONS),
+        Han => CodePointTable::Packed(&HAN_EXTENSIONS),
+        Hangul => CodePointTable::Packed(&HANGUL_EXTENSIONS),
+        HanifiRohingya => CodePointTable::Packed(&HANIFI_ROHINGYA_EXTENSIONS),
+        Hanunoo => CodePointTable::Packed(&HANUNOO_EXTENSIONS),
+        Hatran => CodePointTable::Packed(&HATRAN),
+        Hebrew => CodePointTable::Packed(&HEBREW),
+        Hiragana => CodePointTable::Packed(&HIRAGANA_EXTENSIONS),
+        ImperialAramaic => CodePointTable::Packed(&IMPERIAL_ARAMAIC),
+        Inherited => CodePointTable::Packed(&INHERITED_EXTENSIONS),
+        InscriptionalPahlavi => CodePointTable::Packed(&INSCRIPTIONAL_PAHLAVI),
+        InscriptionalParthian => CodePointTable::Packed(&INSCRIPTIONAL_PARTHIAN),
+        Javanese => CodePointTable::Packed(&JAVANESE_EXTENSIONS),
+        Kaithi => CodePointTable::Packed(&KAITHI_EXTENSIONS),
+        Kannada => CodePointTable::Packed(&KANNADA_EXTENSIONS),
+        Katakana => CodePointTable::Packed(&KATAKANA_EXTENSIONS),
+        KayahLi => CodePointTable::Packed(&KAYAH_LI_EXTENSIONS),
+        Kharoshthi => CodePointTable::Packed(&KHAROSHTHI),
+        KhitanSmallScript => CodePointTable::Packed(&KHITAN_SMALL_SCRIPT),
+        Khmer => CodePointTable::Packed(&KHMER),
+        Khojki => CodePointTable::Packed(&KHOJKI_EXTENSIONS),
+        Khudawadi => CodePointTable::Packed(&KHUDAWADI_EXTENSIONS),
+        Lao => CodePointTable::Packed(&LAO),
+        Latin => CodePointTable::Packed(&LATIN_EXTENSIONS),
+        Lepcha => CodePointTable::Packed(&LEPCHA),
+        Limbu => CodePointTable::Packed(&LIMBU_EXTENSIONS),
+        LinearA => CodePointTable::Packed(&LINEAR_A_EXTENSIONS),
+        LinearB => CodePointTable::Packed(&LINEAR_B_EXTENSIONS),
+        Lisu => CodePointTable::Packed(&LISU),
+        Lycian => CodePointTable::Packed(&LYCIAN),
+        Lydian => CodePointTable::Packed(&LYDIAN),
+        Mahajani => CodePointTable::Packed(&MAHAJANI_EXTENSIONS),
+        Makasar => CodePointTable::Packed(&MAKASAR),
+        Malayalam => CodePointTable::Packed(&MALAYALAM_EXTENSIONS),
+        Mandaic => CodePointTable::Packed(&MANDAIC_EXTENSIONS),
+        Manichaean => CodePointTable::Packed(&MANICHAEAN_EXTENSIONS),
+        Marchen => CodePointTable::Packed(&MARCHEN),
+        Medefaidrin => CodePointTable::Packed(&MEDEFAIDRIN),
+        MasaramGondi => CodePointTable::Packed(&MASARAM_GONDI_EXTENSIONS),
+        MeeteiMayek => CodePointTable::Packed(&MEETEI_MAYEK),
+        MendeKikakui => CodePointTable::Packed(&MENDE_KIKAKUI),
+        MeroiticCursive => CodePointTable::Packed(&MEROITIC_CURSIVE),
+        MeroiticHieroglyphs => CodePointTable::Packed(&MEROITIC_HIEROGLYPHS),
+        Miao => CodePointTable::Packed(&MIAO),
+        Modi => CodePointTable::Packed(&MODI_EXTENSIONS),
+        Mongolian => CodePointTable::Packed(&MONGOLIAN_EXTENSIONS),
+        Mro => CodePointTable::Packed(&MRO),
+        Multani => CodePointTable::Packed(&MULTANI_EXTENSIONS),
+        Myanmar => CodePointTable::Packed(&MYANMAR_EXTENSIONS),
+        Nabataean => CodePointTable::Packed(&NABATAEAN),
+        Nandinagari => CodePointTable::Packed(&NANDINAGARI_EXTENSIONS),
+        NewTaiLue => CodePointTable::Packed(&NEW_TAI_LUE),
+        Newa => CodePointTable::Packed(&NEWA),
+        Nko => CodePointTable::Packed(&NKO_EXTENSIONS),
+        Nushu => CodePointTable::Packed(&NUSHU),
+        NyiakengPuachueHmong => CodePointTable::Packed(&NYIAKENG_PUACHUE_HMONG),
+        Ogham => CodePointTable::Packed(&OGHAM),
+        OlChiki => CodePointTable::Packed(&OL_CHIKI),
+        OldHungarian => CodePointTable::Packed(&OLD_HUNGARIAN),
+        OldItalic => CodePointTable::Packed(&OLD_ITALIC),
+        OldNorthArabian => CodePointTable::Packed(&OLD_NORTH_ARABIAN),
+        OldPermic => CodePointTable::Packed(&OLD_PERMIC_EXTENSIONS),
+        OldPersian => CodePointTable::Packed(&OLD_PERSIAN),
+        OldSogdian => CodePointTable::Packed(&OLD_SOGDIAN),
+        OldSouthArabian => CodePointTable::Packed(&OLD_SOUTH_ARABIAN),
+        OldTurkic => CodePointTable::Packed(&OLD_TURKIC),
+        Oriya => CodePointTable::Packed(&ORIYA_EXTENSIONS),
+        Osage => CodePointTable::Packed(&OSAGE),
+        Osmanya => CodePointTable::Packed(&OSMANYA),
+        PahawhHmong => CodePointTable::Packed(&PAHAWH_HMONG),
+        Palmyrene => CodePointTable::Packed(&PALMYRENE),
+        PauCinHau => CodePointTable::Packed(&PAU_CIN_HAU),
+        PhagsPa => CodePointTable::Packed(&PHAGS_PA_EXTENSIONS),
+        Phoenician => CodePointTable::Packed(&PHOENICIAN),
+        PsalterPahlavi => CodePointTable::Packed(&PSALTER_PAHLAVI_EXTENSIONS),
+        Rejang => CodePointTable::Packed(&REJANG),
+        Runic => CodePointTable::Packed(&RUNIC),
+        Samaritan => CodePointTable::Packed(&SAMARITAN),
+        Saurashtra => CodePointTable::Packed(&SAURASHTRA),
+        Sharada => CodePointTable::Packed(&SHARADA_EXTENSIONS),
+        Shavian => CodePointTable::Packed(&SHAVIAN),
+        Siddham => CodePointTable::Packed(&SIDDHAM),
+        SignWriting => CodePointTable::Packed(&SIGNWRITING),
+        Sinhala => CodePointTable::Packed(&SINHALA_EXTENSIONS),
+        Sogdian => CodePointTable::Packed(&SOGDIAN_EXTENSIONS),
+        SoraSompeng => CodePointTable::Packed(&SORA_SOMPENG),
+        Soyombo => CodePointTable::Packed(&SOYOMBO),
+        Sundanese => CodePointTable::Packed(&SUNDANESE),
+        SylotiNagri => CodePointTable::Packed(&SYLOTI_NAGRI_EXTENSIONS),
+        Syriac => CodePointTable::Packed(&SYRIAC_EXTENSIONS),
+        Tagalog => CodePointTable::Packed(&TAGALOG_EXTENSIONS),
+        Tagbanwa => CodePointTable::Packed(&TAGBANWA_EXTENSIONS),
+        TaiLe => CodePointTable::Packed(&TAI_LE_EXTENSIONS),
+        TaiTham => CodePointTable::Packed(&TAI_THAM),
+        TaiViet => CodePointTable::Packed(&TAI_VIET),
+        Takri => CodePointTable::Packed(&TAKRI_EXTENSIONS),
+        Tamil => CodePointTable::Packed(&TAMIL_EXTENSIONS),
+        Tangut => CodePointTable::Packed(&TANGUT),
+        Telugu => CodePointTable::Packed(&TELUGU_EXTENSIONS),
+        Thaana => CodePointTable::Packed(&THAANA_EXTENSIONS),
+        Thai => CodePointTable::Packed(&THAI),
+        Tibetan => CodePointTable::Packed(&TIBETAN),
+        Tifinagh => CodePointTable::Packed(&TIFINAGH),
+        Tirhuta => CodePointTable::Packed(&TIRHUTA_EXTENSIONS),
+        Ugaritic => CodePointTable::Packed(&UGARITIC),
+        Vai => CodePointTable::Packed(&VAI),
+        Wancho => CodePointTable::Packed(&WANCHO),
+        WarangCiti => CodePointTable::Packed(&WARANG_CITI),
+        Yezidi => CodePointTable::Packed(&YEZIDI_EXTENSIONS),
+        Yi => CodePointTable::Packed(&YI_EXTENSIONS),
+        ZanabazarSquare => CodePointTable::Packed(&ZANABAZAR_SQUARE),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum UnicodePropertyValueScript {
+    Adlam,
+    Ahom,
+    AnatolianHieroglyphs,
+    Arabic,
+    Armenian,
+    Avestan,
+    Balinese,
+    Bamum,
+    BassaVah,
+    Batak,
+    Bengali,
+    Bhaiksuki,
     Bopomofo,
     Brahmi,
     Braille,
@@ -15411,6 +17569,166 @@ pub fn unicode_property_value_script_from_str(s: &str) -> Option<UnicodeProperty
     }
 }
 
+/// Every UnicodePropertyValueScript, in declaration order.
+pub(crate) const UNICODE_PROPERTY_VALUE_SCRIPT_VARIANTS: [UnicodePropertyValueScript; 156] = [
+    UnicodePropertyValueScript::Adlam,
+    UnicodePropertyValueScript::Ahom,
+    UnicodePropertyValueScript::AnatolianHieroglyphs,
+    UnicodePropertyValueScript::Arabic,
+    UnicodePropertyValueScript::Armenian,
+    UnicodePropertyValueScript::Avestan,
+    UnicodePropertyValueScript::Balinese,
+    UnicodePropertyValueScript::Bamum,
+    UnicodePropertyValueScript::BassaVah,
+    UnicodePropertyValueScript::Batak,
+    UnicodePropertyValueScript::Bengali,
+    UnicodePropertyValueScript::Bhaiksuki,
+    UnicodePropertyValueScript::Bopomofo,
+    UnicodePropertyValueScript::Brahmi,
+    UnicodePropertyValueScript::Braille,
+    UnicodePropertyValueScript::Buginese,
+    UnicodePropertyValueScript::Buhid,
+    UnicodePropertyValueScript::CanadianAboriginal,
+    UnicodePropertyValueScript::Carian,
+    UnicodePropertyValueScript::CaucasianAlbanian,
+    UnicodePropertyValueScript::Chakma,
+    UnicodePropertyValueScript::Cham,
+    UnicodePropertyValueScript::Chorasmian,
+    UnicodePropertyValueScript::Cherokee,
+    UnicodePropertyValueScript::Common,
+    UnicodePropertyValueScript::Coptic,
+    UnicodePropertyValueScript::Cuneiform,
+    UnicodePropertyValueScript::Cypriot,
+    UnicodePropertyValueScript::Cyrillic,
+    UnicodePropertyValueScript::Deseret,
+    UnicodePropertyValueScript::Devanagari,
+    UnicodePropertyValueScript::DivesAkuru,
+    UnicodePropertyValueScript::Dogra,
+    UnicodePropertyValueScript::Duployan,
+    UnicodePropertyValueScript::EgyptianHieroglyphs,
+    UnicodePropertyValueScript::Elbasan,
+    UnicodePropertyValueScript::Elymaic,
+    UnicodePropertyValueScript::Ethiopic,
+    UnicodePropertyValueScript::Georgian,
+    UnicodePropertyValueScript::Glagolitic,
+    UnicodePropertyValueScript::Gothic,
+    UnicodePropertyValueScript::Grantha,
+    UnicodePropertyValueScript::Greek,
+    UnicodePropertyValueScript::Gujarati,
+    UnicodePropertyValueScript::GunjalaGondi,
+    UnicodePropertyValueScript::Gurmukhi,
+    UnicodePropertyValueScript::Han,
+    UnicodePropertyValueScript::Hangul,
+    UnicodePropertyValueScript::HanifiRohingya,
+    UnicodePropertyValueScript::Hanunoo,
+    UnicodePropertyValueScript::Hatran,
+    UnicodePropertyValueScript::Hebrew,
+    UnicodePropertyValueScript::Hiragana,
+    UnicodePropertyValueScript::ImperialAramaic,
+    UnicodePropertyValueScript::Inherited,
+    UnicodePropertyValueScript::InscriptionalPahlavi,
+    UnicodePropertyValueScript::InscriptionalParthian,
+    UnicodePropertyValueScript::Javanese,
+    UnicodePropertyValueScript::Kaithi,
+    UnicodePropertyValueScript::Kannada,
+    UnicodePropertyValueScript::Katakana,
+    UnicodePropertyValueScript::KayahLi,
+    UnicodePropertyValueScript::Kharoshthi,
+    UnicodePropertyValueScript::KhitanSmallScript,
+    UnicodePropertyValueScript::Khmer,
+    UnicodePropertyValueScript::Khojki,
+    UnicodePropertyValueScript::Khudawadi,
+    UnicodePropertyValueScript::Lao,
+    UnicodePropertyValueScript::Latin,
+    UnicodePropertyValueScript::Lepcha,
+    UnicodePropertyValueScript::Limbu,
+    UnicodePropertyValueScript::LinearA,
+    UnicodePropertyValueScript::LinearB,
+    UnicodePropertyValueScript::Lisu,
+    UnicodePropertyValueScript::Lycian,
+    UnicodePropertyValueScript::Lydian,
+    UnicodePropertyValueScript::Mahajani,
+    UnicodePropertyValueScript::Makasar,
+    UnicodePropertyValueScript::Malayalam,
+    UnicodePropertyValueScript::Mandaic,
+    UnicodePropertyValueScript::Manichaean,
+    UnicodePropertyValueScript::Marchen,
+    UnicodePropertyValueScript::Medefaidrin,
+    UnicodePropertyValueScript::MasaramGondi,
+    UnicodePropertyValueScript::MeeteiMayek,
+    UnicodePropertyValueScript::MendeKikakui,
+    UnicodePropertyValueScript::MeroiticCursive,
+    UnicodePropertyValueScript::MeroiticHieroglyphs,
+    UnicodePropertyValueScript::Miao,
+    UnicodePropertyValueScript::Modi,
+    UnicodePropertyValueScript::Mongolian,
+    UnicodePropertyValueScript::Mro,
+    UnicodePropertyValueScript::Multani,
+    UnicodePropertyValueScript::Myanmar,
+    UnicodePropertyValueScript::Nabataean,
+    UnicodePropertyValueScript::Nandinagari,
+    UnicodePropertyValueScript::NewTaiLue,
+    UnicodePropertyValueScript::Newa,
+    UnicodePropertyValueScript::Nko,
+    UnicodePropertyValueScript::Nushu,
+    UnicodePropertyValueScript::NyiakengPuachueHmong,
+    UnicodePropertyValueScript::Ogham,
+    UnicodePropertyValueScript::OlChiki,
+    UnicodePropertyValueScript::OldHungarian,
+    UnicodePropertyValueScript::OldItalic,
+    UnicodePropertyValueScript::OldNorthArabian,
+    UnicodePropertyValueScript::OldPermic,
+    UnicodePropertyValueScript::OldPersian,
+    UnicodePropertyValueScript::OldSogdian,
+    UnicodePropertyValueScript::OldSouthArabian,
+    UnicodePropertyValueScript::OldTurkic,
+    UnicodePropertyValueScript::Oriya,
+    UnicodePropertyValueScript::Osage,
+    UnicodePropertyValueScript::Osmanya,
+    UnicodePropertyValueScript::PahawhHmong,
+    UnicodePropertyValueScript::Palmyrene,
+    UnicodePropertyValueScript::PauCinHau,
+    UnicodePropertyValueScript::PhagsPa,
+    UnicodePropertyValueScript::Phoenician,
+    UnicodePropertyValueScript::PsalterPahlavi,
+    UnicodePropertyValueScript::Rejang,
+    UnicodePropertyValueScript::Runic,
+    UnicodePropertyValueScript::Samaritan,
+    UnicodePropertyValueScript::Saurashtra,
+    UnicodePropertyValueScript::Sharada,
+    UnicodePropertyValueScript::Shavian,
+    UnicodePropertyValueScript::Siddham,
+    UnicodePropertyValueScript::SignWriting,
+    UnicodePropertyValueScript::Sinhala,
+    UnicodePropertyValueScript::Sogdian,
+    UnicodePropertyValueScript::SoraSompeng,
+    UnicodePropertyValueScript::Soyombo,
+    UnicodePropertyValueScript::Sundanese,
+    UnicodePropertyValueScript::SylotiNagri,
+    UnicodePropertyValueScript::Syriac,
+    UnicodePropertyValueScript::Tagalog,
+    UnicodePropertyValueScript::Tagbanwa,
+    UnicodePropertyValueScript::TaiLe,
+    UnicodePropertyValueScript::TaiTham,
+    UnicodePropertyValueScript::TaiViet,
+    UnicodePropertyValueScript::Takri,
+    UnicodePropertyValueScript::Tamil,
+    UnicodePropertyValueScript::Tangut,
+    UnicodePropertyValueScript::Telugu,
+    UnicodePropertyValueScript::Thaana,
+    UnicodePropertyValueScript::Thai,
+    UnicodePropertyValueScript::Tibetan,
+    UnicodePropertyValueScript::Tifinagh,
+    UnicodePropertyValueScript::Tirhuta,
+    UnicodePropertyValueScript::Ugaritic,
+    UnicodePropertyValueScript::Vai,
+    UnicodePropertyValueScript::Wancho,
+    UnicodePropertyValueScript::WarangCiti,
+    UnicodePropertyValueScript::Yezidi,
+    UnicodePropertyValueScript::Yi,
+    UnicodePropertyValueScript::ZanabazarSquare,
+];
+
 pub(crate) const CLOSE_PUNCTUATION: [CodePointRange; 76] = [
     CodePointRange::from(41, 1),
     CodePointRange::from(93, 1),
@@ -19831,6 +22149,93 @@ pub(crate) fn is_property_value_general_category(
     }
 }
 
+pub(crate) fn property_value_general_category_table(
+    value: &UnicodePropertyValueGeneralCategory,
+) -> CodePointTable {
+    use UnicodePropertyValueGeneralCategory::*;
+    match value {
+        ClosePunctuation => CodePointTable::Packed(&CLOSE_PUNCTUATION),
+        ConnectorPunctuation => CodePointTable::Packed(&CONNECTOR_PUNCTUATION),
+        Control => CodePointTable::Packed(&CONTROL),
+        CurrencySymbol => CodePointTable::Packed(&CURRENCY_SYMBOL),
+        DashPunctuation => CodePointTable::Packed(&DASH_PUNCTUATION),
+        DecimalNumber => CodePointTable::Packed(&DECIMAL_NUMBER),
+        EnclosingMark => CodePointTable::Packed(&ENCLOSING_MARK),
+        FinalPunctuation => CodePointTable::Packed(&FINAL_PUNCTUATION),
+        Format => CodePointTable::Packed(&FORMAT),
+        InitialPunctuation => CodePointTable::Packed(&INITIAL_PUNCTUATION),
+        LetterNumber => CodePointTable::Packed(&LETTER_NUMBER),
+        LineSeparator => CodePointTable::Packed(&LINE_SEPARATOR),
+        LowercaseLetter => CodePointTable::Packed(&LOWERCASE_LETTER),
+        MathSymbol => CodePointTable::Packed(&MATH_SYMBOL),
+        ModifierLetter => CodePointTable::Packed(&MODIFIER_LETTER),
+        ModifierSymbol => CodePointTable::Packed(&MODIFIER_SYMBOL),
+        NonspacingMark => CodePointTable::Packed(&NONSPACING_MARK),
+        OpenPunctuation => CodePointTable::Packed(&OPEN_PUNCTUATION),
+        OtherLetter => CodePointTable::Packed(&OTHER_LETTER),
+        OtherNumber => CodePointTable::Packed(&OTHER_NUMBER),
+        OtherPunctuation => CodePointTable::Packed(&OTHER_PUNCTUATION),
+        OtherSymbol => CodePointTable::Packed(&OTHER_SYMBOL),
+        ParagraphSeparator => CodePointTable::Packed(&PARAGRAPH_SEPARATOR),
+        PrivateUse => CodePointTable::Unpacked(&PRIVATE_USE),
+        SpaceSeparator => CodePointTable::Packed(&SPACE_SEPARATOR),
+        SpacingMark => CodePointTable::Packed(&SPACING_MARK),
+        Surrogate => CodePointTable::Packed(&SURROGATE),
+        TitlecaseLetter => CodePointTable::Packed(&TITLECASE_LETTER),
+        Unassigned => CodePointTable::Unpacked(&UNASSIGNED),
+        UppercaseLetter => CodePointTable::Packed(&UPPERCASE_LETTER),
+        CasedLetter => CodePointTable::Union(&[
+            CodePointTable::Packed(&LOWERCASE_LETTER),
+            CodePointTable::Packed(&TITLECASE_LETTER),
+            CodePointTable::Packed(&UPPERCASE_LETTER),
+        ]),
+        Other => CodePointTable::Union(&[
+            CodePointTable::Packed(&CONTROL),
+            CodePointTable::Packed(&FORMAT),
+            CodePointTable::Packed(&SURROGATE),
+            CodePointTable::Unpacked(&UNASSIGNED),
+            CodePointTable::Unpacked(&PRIVATE_USE),
+        ]),
+        Letter => CodePointTable::Union(&[
+            CodePointTable::Packed(&LOWERCASE_LETTER),
+            CodePointTable::Packed(&MODIFIER_LETTER),
+            CodePointTable::Packed(&OTHER_LETTER),
+            CodePointTable::Packed(&TITLECASE_LETTER),
+            CodePointTable::Packed(&UPPERCASE_LETTER),
+        ]),
+        Mark => CodePointTable::Union(&[
+            CodePointTable::Packed(&SPACING_MARK),
+            CodePointTable::Packed(&ENCLOSING_MARK),
+            CodePointTable::Packed(&NONSPACING_MARK),
+        ]),
+        Number => CodePointTable::Union(&[
+            CodePointTable::Packed(&DECIMAL_NUMBER),
+            CodePointTable::Packed(&LETTER_NUMBER),
+            CodePointTable::Packed(&OTHER_NUMBER),
+        ]),
+        Punctuation => CodePointTable::Union(&[
+            CodePointTable::Packed(&CONNECTOR_PUNCTUATION),
+            CodePointTable::Packed(&DASH_PUNCTUATION),
+            CodePointTable::Packed(&CLOSE_PUNCTUATION),
+            CodePointTable::Packed(&FINAL_PUNCTUATION),
+            CodePointTable::Packed(&INITIAL_PUNCTUATION),
+            CodePointTable::Packed(&OTHER_PUNCTUATION),
+            CodePointTable::Packed(&OPEN_PUNCTUATION),
+        ]),
+        Symbol => CodePointTable::Union(&[
+            CodePointTable::Packed(&CURRENCY_SYMBOL),
+            CodePointTable::Packed(&MODIFIER_SYMBOL),
+            CodePointTable::Packed(&MATH_SYMBOL),
+            CodePointTable::Packed(&OTHER_SYMBOL),
+        ]),
+        Separator => CodePointTable::Union(&[
+            CodePointTable::Packed(&LINE_SEPARATOR),
+            CodePointTable::Packed(&PARAGRAPH_SEPARATOR),
+            CodePointTable::Packed(&SPACE_SEPARATOR),
+        ]),
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum UnicodePropertyValueGeneralCategory {
     ClosePunctuation,
@@ -19919,3 +22324,5374 @@ pub fn unicode_property_value_general_category_from_str(
         _ => None,
     }
 }
+
+/// Every UnicodePropertyValueGeneralCategory, in declaration order.
+pub(crate) const UNICODE_PROPERTY_VALUE_GENERAL_CATEGORY_VARIANTS:
+    [UnicodePropertyValueGeneralCategory; 38] = [
+    UnicodePropertyValueGeneralCategory::ClosePunctuation,
+    UnicodePropertyValueGeneralCategory::ConnectorPunctuation,
+    UnicodePropertyValueGeneralCategory::Control,
+    UnicodePropertyValueGeneralCategory::CurrencySymbol,
+    UnicodePropertyValueGeneralCategory::DashPunctuation,
+    UnicodePropertyValueGeneralCategory::DecimalNumber,
+    UnicodePropertyValueGeneralCategory::EnclosingMark,
+    UnicodePropertyValueGeneralCategory::FinalPunctuation,
+    UnicodePropertyValueGeneralCategory::Format,
+    UnicodePropertyValueGeneralCategory::InitialPunctuation,
+    UnicodePropertyValueGeneralCategory::LetterNumber,
+    UnicodePropertyValueGeneralCategory::LineSeparator,
+    UnicodePropertyValueGeneralCategory::LowercaseLetter,
+    UnicodePropertyValueGeneralCategory::MathSymbol,
+    UnicodePropertyValueGeneralCategory::ModifierLetter,
+    UnicodePropertyValueGeneralCategory::ModifierSymbol,
+    UnicodePropertyValueGeneralCategory::NonspacingMark,
+    UnicodePropertyValueGeneralCategory::OpenPunctuation,
+    UnicodePropertyValueGeneralCategory::OtherLetter,
+    UnicodePropertyValueGeneralCategory::OtherNumber,
+    UnicodePropertyValueGeneralCategory::OtherPunctuation,
+    UnicodePropertyValueGeneralCategory::OtherSymbol,
+    UnicodePropertyValueGeneralCategory::ParagraphSeparator,
+    UnicodePropertyValueGeneralCategory::PrivateUse,
+    UnicodePropertyValueGeneralCategory::SpaceSeparator,
+    UnicodePropertyValueGeneralCategory::SpacingMark,
+    UnicodePropertyValueGeneralCategory::Surrogate,
+    UnicodePropertyValueGeneralCategory::TitlecaseLetter,
+    UnicodePropertyValueGeneralCategory::Unassigned,
+    UnicodePropertyValueGeneralCategory::UppercaseLetter,
+    UnicodePropertyValueGeneralCategory::CasedLetter,
+    UnicodePropertyValueGeneralCategory::Other,
+    UnicodePropertyValueGeneralCategory::Letter,
+    UnicodePropertyValueGeneralCategory::Mark,
+    UnicodePropertyValueGeneralCategory::Number,
+    UnicodePropertyValueGeneralCategory::Punctuation,
+    UnicodePropertyValueGeneralCategory::Symbol,
+    UnicodePropertyValueGeneralCategory::Separator,
+];
+
+pub(crate) const AGE_V1_1: [CodePointRange; 294] = [
+    CodePointRange::from(0, 502),
+    CodePointRange::from(506, 30),
+    CodePointRange::from(592, 89),
+    CodePointRange::from(688, 47),
+    CodePointRange::from(736, 10),
+    CodePointRange::from(768, 70),
+    CodePointRange::from(864, 2),
+    CodePointRange::from(884, 2),
+    CodePointRange::from(890, 1),
+    CodePointRange::from(894, 1),
+    CodePointRange::from(900, 7),
+    CodePointRange::from(908, 1),
+    CodePointRange::from(910, 20),
+    CodePointRange::from(931, 44),
+    CodePointRange::from(976, 7),
+    CodePointRange::from(986, 1),
+    CodePointRange::from(988, 1),
+    CodePointRange::from(990, 1),
+    CodePointRange::from(992, 1),
+    CodePointRange::from(994, 18),
+    CodePointRange::from(1025, 12),
+    CodePointRange::from(1038, 66),
+    CodePointRange::from(1105, 12),
+    CodePointRange::from(1118, 41),
+    CodePointRange::from(1168, 53),
+    CodePointRange::from(1223, 2),
+    CodePointRange::from(1227, 2),
+    CodePointRange::from(1232, 28),
+    CodePointRange::from(1262, 8),
+    CodePointRange::from(1272, 2),
+    CodePointRange::from(1329, 38),
+    CodePointRange::from(1369, 7),
+    CodePointRange::from(1377, 39),
+    CodePointRange::from(1417, 1),
+    CodePointRange::from(1456, 10),
+    CodePointRange::from(1467, 9),
+    CodePointRange::from(1488, 27),
+    CodePointRange::from(1520, 5),
+    CodePointRange::from(1548, 1),
+    CodePointRange::from(1563, 1),
+    CodePointRange::from(1567, 1),
+    CodePointRange::from(1569, 26),
+    CodePointRange::from(1600, 19),
+    CodePointRange::from(1632, 14),
+    CodePointRange::from(1648, 72),
+    CodePointRange::from(1722, 5),
+    CodePointRange::from(1728, 15),
+    CodePointRange::from(1744, 30),
+    CodePointRange::from(1776, 10),
+    CodePointRange::from(2305, 3),
+    CodePointRange::from(2309, 53),
+    CodePointRange::from(2364, 18),
+    CodePointRange::from(2384, 5),
+    CodePointRange::from(2392, 25),
+    CodePointRange::from(2433, 3),
+    CodePointRange::from(2437, 8),
+    CodePointRange::from(2447, 2),
+    CodePointRange::from(2451, 22),
+    CodePointRange::from(2474, 7),
+    CodePointRange::from(2482, 1),
+    CodePointRange::from(2486, 4),
+    CodePointRange::from(2492, 1),
+    CodePointRange::from(2494, 7),
+    CodePointRange::from(2503, 2),
+    CodePointRange::from(2507, 3),
+    CodePointRange::from(2519, 1),
+    CodePointRange::from(2524, 2),
+    CodePointRange::from(2527, 5),
+    CodePointRange::from(2534, 21),
+    CodePointRange::from(2562, 1),
+    CodePointRange::from(2565, 6),
+    CodePointRange::from(2575, 2),
+    CodePointRange::from(2579, 22),
+    CodePointRange::from(2602, 7),
+    CodePointRange::from(2610, 2),
+    CodePointRange::from(2613, 2),
+    CodePointRange::from(2616, 2),
+    CodePointRange::from(2620, 1),
+    CodePointRange::from(2622, 5),
+    CodePointRange::from(2631, 2),
+    CodePointRange::from(2635, 3),
+    CodePointRange::from(2649, 4),
+    CodePointRange::from(2654, 1),
+    CodePointRange::from(2662, 15),
+    CodePointRange::from(2689, 3),
+    CodePointRange::from(2693, 7),
+    CodePointRange::from(2701, 1),
+    CodePointRange::from(2703, 3),
+    CodePointRange::from(2707, 22),
+    CodePointRange::from(2730, 7),
+    CodePointRange::from(2738, 2),
+    CodePointRange::from(2741, 5),
+    CodePointRange::from(2748, 10),
+    CodePointRange::from(2759, 3),
+    CodePointRange::from(2763, 3),
+    CodePointRange::from(2768, 1),
+    CodePointRange::from(2784, 1),
+    CodePointRange::from(2790, 10),
+    CodePointRange::from(2817, 3),
+    CodePointRange::from(2821, 8),
+    CodePointRange::from(2831, 2),
+    CodePointRange::from(2835, 22),
+    CodePointRange::from(2858, 7),
+    CodePointRange::from(2866, 2),
+    CodePointRange::from(2870, 4),
+    CodePointRange::from(2876, 8),
+    CodePointRange::from(2887, 2),
+    CodePointRange::from(2891, 3),
+    CodePointRange::from(2902, 2),
+    CodePointRange::from(2908, 2),
+    CodePointRange::from(2911, 3),
+    CodePointRange::from(2918, 11),
+    CodePointRange::from(2946, 2),
+    CodePointRange::from(2949, 6),
+    CodePointRange::from(2958, 3),
+    CodePointRange::from(2962, 4),
+    CodePointRange::from(2969, 2),
+    CodePointRange::from(2972, 1),
+    CodePointRange::from(2974, 2),
+    CodePointRange::from(2979, 2),
+    CodePointRange::from(2984, 3),
+    CodePointRange::from(2990, 8),
+    CodePointRange::from(2999, 3),
+    CodePointRange::from(3006, 5),
+    CodePointRange::from(3014, 3),
+    CodePointRange::from(3018, 4),
+    CodePointRange::from(3031, 1),
+    CodePointRange::from(3047, 12),
+    CodePointRange::from(3073, 3),
+    CodePointRange::from(3077, 8),
+    CodePointRange::from(3086, 3),
+    CodePointRange::from(3090, 23),
+    CodePointRange::from(3114, 10),
+    CodePointRange::from(3125, 5),
+    CodePointRange::from(3134, 7),
+    CodePointRange::from(3142, 3),
+    CodePointRange::from(3146, 4),
+    CodePointRange::from(3157, 2),
+    CodePointRange::from(3168, 2),
+    CodePointRange::from(3174, 10),
+    CodePointRange::from(3202, 2),
+    CodePointRange::from(3205, 8),
+    CodePointRange::from(3214, 3),
+    CodePointRange::from(3218, 23),
+    CodePointRange::from(3242, 10),
+    CodePointRange::from(3253, 5),
+    CodePointRange::from(3262, 7),
+    CodePointRange::from(3270, 3),
+    CodePointRange::from(3274, 4),
+    CodePointRange::from(3285, 2),
+    CodePointRange::from(3294, 1),
+    CodePointRange::from(3296, 2),
+    CodePointRange::from(3302, 10),
+    CodePointRange::from(3330, 2),
+    CodePointRange::from(3333, 8),
+    CodePointRange::from(3342, 3),
+    CodePointRange::from(3346, 23),
+    CodePointRange::from(3370, 16),
+    CodePointRange::from(3390, 6),
+    CodePointRange::from(3398, 3),
+    CodePointRange::from(3402, 4),
+    CodePointRange::from(3415, 1),
+    CodePointRange::from(3424, 2),
+    CodePointRange::from(3430, 10),
+    CodePointRange::from(3585, 58),
+    CodePointRange::from(3647, 29),
+    CodePointRange::from(3713, 2),
+    CodePointRange::from(3716, 1),
+    CodePointRange::from(3719, 2),
+    CodePointRange::from(3722, 1),
+    CodePointRange::from(3725, 1),
+    CodePointRange::from(3732, 4),
+    CodePointRange::from(3737, 7),
+    CodePointRange::from(3745, 3),
+    CodePointRange::from(3749, 1),
+    CodePointRange::from(3751, 1),
+    CodePointRange::from(3754, 2),
+    CodePointRange::from(3757, 13),
+    CodePointRange::from(3771, 3),
+    CodePointRange::from(3776, 5),
+    CodePointRange::from(3782, 1),
+    CodePointRange::from(3784, 6),
+    CodePointRange::from(3792, 10),
+    CodePointRange::from(3804, 2),
+    CodePointRange::from(4256, 38),
+    CodePointRange::from(4304, 39),
+    CodePointRange::from(4347, 1),
+    CodePointRange::from(4352, 90),
+    CodePointRange::from(4447, 68),
+    CodePointRange::from(4520, 82),
+    CodePointRange::from(7680, 155),
+    CodePointRange::from(7840, 90),
+    CodePointRange::from(7936, 22),
+    CodePointRange::from(7960, 6),
+    CodePointRange::from(7968, 38),
+    CodePointRange::from(8008, 6),
+    CodePointRange::from(8016, 8),
+    CodePointRange::from(8025, 1),
+    CodePointRange::from(8027, 1),
+    CodePointRange::from(8029, 1),
+    CodePointRange::from(8031, 31),
+    CodePointRange::from(8064, 53),
+    CodePointRange::from(8118, 15),
+    CodePointRange::from(8134, 14),
+    CodePointRange::from(8150, 6),
+    CodePointRange::from(8157, 19),
+    CodePointRange::from(8178, 3),
+    CodePointRange::from(8182, 9),
+    CodePointRange::from(8192, 47),
+    CodePointRange::from(8240, 23),
+    CodePointRange::from(8298, 7),
+    CodePointRange::from(8308, 27),
+    CodePointRange::from(8352, 11),
+    CodePointRange::from(8400, 18),
+    CodePointRange::from(8448, 57),
+    CodePointRange::from(8531, 48),
+    CodePointRange::from(8592, 91),
+    CodePointRange::from(8704, 242),
+    CodePointRange::from(8960, 1),
+    CodePointRange::from(8962, 121),
+    CodePointRange::from(9216, 37),
+    CodePointRange::from(9280, 11),
+    CodePointRange::from(9312, 139),
+    CodePointRange::from(9472, 150),
+    CodePointRange::from(9632, 80),
+    CodePointRange::from(9728, 20),
+    CodePointRange::from(9754, 86),
+    CodePointRange::from(9985, 4),
+    CodePointRange::from(9990, 4),
+    CodePointRange::from(9996, 28),
+    CodePointRange::from(10025, 35),
+    CodePointRange::from(10061, 1),
+    CodePointRange::from(10063, 4),
+    CodePointRange::from(10070, 1),
+    CodePointRange::from(10072, 7),
+    CodePointRange::from(10081, 7),
+    CodePointRange::from(10102, 31),
+    CodePointRange::from(10136, 24),
+    CodePointRange::from(10161, 14),
+    CodePointRange::from(12288, 56),
+    CodePointRange::from(12351, 1),
+    CodePointRange::from(12353, 84),
+    CodePointRange::from(12441, 6),
+    CodePointRange::from(12449, 94),
+    CodePointRange::from(12549, 40),
+    CodePointRange::from(12593, 94),
+    CodePointRange::from(12688, 16),
+    CodePointRange::from(12800, 29),
+    CodePointRange::from(12832, 36),
+    CodePointRange::from(12896, 28),
+    CodePointRange::from(12927, 50),
+    CodePointRange::from(12992, 12),
+    CodePointRange::from(13008, 47),
+    CodePointRange::from(13056, 119),
+    CodePointRange::from(13179, 99),
+    CodePointRange::from(13280, 31),
+    CodePointRange::from(19968, 4096),
+    CodePointRange::from(24064, 4096),
+    CodePointRange::from(28160, 4096),
+    CodePointRange::from(32256, 4096),
+    CodePointRange::from(36352, 4096),
+    CodePointRange::from(40448, 422),
+    CodePointRange::from(57344, 4096),
+    CodePointRange::from(61440, 2606),
+    CodePointRange::from(64256, 7),
+    CodePointRange::from(64275, 5),
+    CodePointRange::from(64286, 25),
+    CodePointRange::from(64312, 5),
+    CodePointRange::from(64318, 1),
+    CodePointRange::from(64320, 2),
+    CodePointRange::from(64323, 2),
+    CodePointRange::from(64326, 108),
+    CodePointRange::from(64467, 365),
+    CodePointRange::from(64848, 64),
+    CodePointRange::from(64914, 54),
+    CodePointRange::from(65008, 12),
+    CodePointRange::from(65056, 4),
+    CodePointRange::from(65072, 21),
+    CodePointRange::from(65097, 10),
+    CodePointRange::from(65108, 19),
+    CodePointRange::from(65128, 4),
+    CodePointRange::from(65136, 3),
+    CodePointRange::from(65140, 1),
+    CodePointRange::from(65142, 135),
+    CodePointRange::from(65279, 1),
+    CodePointRange::from(65281, 94),
+    CodePointRange::from(65377, 94),
+    CodePointRange::from(65474, 6),
+    CodePointRange::from(65482, 6),
+    CodePointRange::from(65490, 6),
+    CodePointRange::from(65498, 3),
+    CodePointRange::from(65504, 7),
+    CodePointRange::from(65512, 7),
+    CodePointRange::from(65533, 3),
+];
+
+/// Return whether c was assigned in Unicode version 1.1.
+pub(crate) fn is_age_v1_1(c: char) -> bool {
+    AGE_V1_1
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V2_0: [CodePointRangeUnpacked; 29] = [
+    CodePointRangeUnpacked::from(1425, 1441),
+    CodePointRangeUnpacked::from(1443, 1455),
+    CodePointRangeUnpacked::from(1476, 1476),
+    CodePointRangeUnpacked::from(3840, 3911),
+    CodePointRangeUnpacked::from(3913, 3945),
+    CodePointRangeUnpacked::from(3953, 3979),
+    CodePointRangeUnpacked::from(3984, 3989),
+    CodePointRangeUnpacked::from(3991, 3991),
+    CodePointRangeUnpacked::from(3993, 4013),
+    CodePointRangeUnpacked::from(4017, 4023),
+    CodePointRangeUnpacked::from(4025, 4025),
+    CodePointRangeUnpacked::from(7835, 7835),
+    CodePointRangeUnpacked::from(8363, 8363),
+    CodePointRangeUnpacked::from(44032, 55203),
+    CodePointRangeUnpacked::from(55296, 57343),
+    CodePointRangeUnpacked::from(131070, 131071),
+    CodePointRangeUnpacked::from(196606, 196607),
+    CodePointRangeUnpacked::from(262142, 262143),
+    CodePointRangeUnpacked::from(327678, 327679),
+    CodePointRangeUnpacked::from(393214, 393215),
+    CodePointRangeUnpacked::from(458750, 458751),
+    CodePointRangeUnpacked::from(524286, 524287),
+    CodePointRangeUnpacked::from(589822, 589823),
+    CodePointRangeUnpacked::from(655358, 655359),
+    CodePointRangeUnpacked::from(720894, 720895),
+    CodePointRangeUnpacked::from(786430, 786431),
+    CodePointRangeUnpacked::from(851966, 851967),
+    CodePointRangeUnpacked::from(917502, 917503),
+    CodePointRangeUnpacked::from(983038, 1114111),
+];
+
+/// Return whether c was assigned in Unicode version 2.0.
+pub(crate) fn is_age_v2_0(c: char) -> bool {
+    AGE_V2_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V2_1: [CodePointRange; 2] = [
+    CodePointRange::from(8364, 1),
+    CodePointRange::from(65532, 1),
+];
+
+/// Return whether c was assigned in Unicode version 2.1.
+pub(crate) fn is_age_v2_1(c: char) -> bool {
+    AGE_V2_1
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V3_0: [CodePointRange; 122] = [
+    CodePointRange::from(502, 4),
+    CodePointRange::from(536, 8),
+    CodePointRange::from(546, 18),
+    CodePointRange::from(681, 5),
+    CodePointRange::from(735, 1),
+    CodePointRange::from(746, 5),
+    CodePointRange::from(838, 9),
+    CodePointRange::from(866, 1),
+    CodePointRange::from(983, 1),
+    CodePointRange::from(987, 1),
+    CodePointRange::from(989, 1),
+    CodePointRange::from(991, 1),
+    CodePointRange::from(993, 1),
+    CodePointRange::from(1024, 1),
+    CodePointRange::from(1037, 1),
+    CodePointRange::from(1104, 1),
+    CodePointRange::from(1117, 1),
+    CodePointRange::from(1160, 2),
+    CodePointRange::from(1164, 4),
+    CodePointRange::from(1260, 2),
+    CodePointRange::from(1418, 1),
+    CodePointRange::from(1619, 3),
+    CodePointRange::from(1720, 2),
+    CodePointRange::from(1727, 1),
+    CodePointRange::from(1743, 1),
+    CodePointRange::from(1786, 5),
+    CodePointRange::from(1792, 14),
+    CodePointRange::from(1807, 30),
+    CodePointRange::from(1840, 27),
+    CodePointRange::from(1920, 49),
+    CodePointRange::from(3458, 2),
+    CodePointRange::from(3461, 18),
+    CodePointRange::from(3482, 24),
+    CodePointRange::from(3507, 9),
+    CodePointRange::from(3517, 1),
+    CodePointRange::from(3520, 7),
+    CodePointRange::from(3530, 1),
+    CodePointRange::from(3535, 6),
+    CodePointRange::from(3542, 1),
+    CodePointRange::from(3544, 8),
+    CodePointRange::from(3570, 3),
+    CodePointRange::from(3946, 1),
+    CodePointRange::from(3990, 1),
+    CodePointRange::from(4014, 3),
+    CodePointRange::from(4024, 1),
+    CodePointRange::from(4026, 3),
+    CodePointRange::from(4030, 15),
+    CodePointRange::from(4047, 1),
+    CodePointRange::from(4096, 34),
+    CodePointRange::from(4131, 5),
+    CodePointRange::from(4137, 2),
+    CodePointRange::from(4140, 7),
+    CodePointRange::from(4150, 4),
+    CodePointRange::from(4160, 26),
+    CodePointRange::from(4608, 7),
+    CodePointRange::from(4616, 63),
+    CodePointRange::from(4680, 1),
+    CodePointRange::from(4682, 4),
+    CodePointRange::from(4688, 7),
+    CodePointRange::from(4696, 1),
+    CodePointRange::from(4698, 4),
+    CodePointRange::from(4704, 39),
+    CodePointRange::from(4744, 1),
+    CodePointRange::from(4746, 4),
+    CodePointRange::from(4752, 31),
+    CodePointRange::from(4784, 1),
+    CodePointRange::from(4786, 4),
+    CodePointRange::from(4792, 7),
+    CodePointRange::from(4800, 1),
+    CodePointRange::from(4802, 4),
+    CodePointRange::from(4808, 7),
+    CodePointRange::from(4816, 7),
+    CodePointRange::from(4824, 23),
+    CodePointRange::from(4848, 31),
+    CodePointRange::from(4880, 1),
+    CodePointRange::from(4882, 4),
+    CodePointRange::from(4888, 7),
+    CodePointRange::from(4896, 39),
+    CodePointRange::from(4936, 19),
+    CodePointRange::from(4961, 28),
+    CodePointRange::from(5024, 85),
+    CodePointRange::from(5121, 630),
+    CodePointRange::from(5760, 29),
+    CodePointRange::from(5792, 81),
+    CodePointRange::from(6016, 93),
+    CodePointRange::from(6112, 10),
+    CodePointRange::from(6144, 15),
+    CodePointRange::from(6160, 10),
+    CodePointRange::from(6176, 88),
+    CodePointRange::from(6272, 42),
+    CodePointRange::from(8239, 1),
+    CodePointRange::from(8264, 6),
+    CodePointRange::from(8365, 3),
+    CodePointRange::from(8418, 2),
+    CodePointRange::from(8505, 2),
+    CodePointRange::from(8579, 1),
+    CodePointRange::from(8683, 9),
+    CodePointRange::from(8961, 1),
+    CodePointRange::from(9083, 1),
+    CodePointRange::from(9085, 30),
+    CodePointRange::from(9253, 2),
+    CodePointRange::from(9712, 8),
+    CodePointRange::from(9753, 1),
+    CodePointRange::from(9840, 2),
+    CodePointRange::from(10240, 256),
+    CodePointRange::from(11904, 26),
+    CodePointRange::from(11931, 89),
+    CodePointRange::from(12032, 214),
+    CodePointRange::from(12272, 12),
+    CodePointRange::from(12344, 3),
+    CodePointRange::from(12350, 1),
+    CodePointRange::from(12704, 24),
+    CodePointRange::from(13312, 4096),
+    CodePointRange::from(17408, 2486),
+    CodePointRange::from(40960, 1165),
+    CodePointRange::from(42128, 18),
+    CodePointRange::from(42148, 16),
+    CodePointRange::from(42165, 12),
+    CodePointRange::from(42178, 3),
+    CodePointRange::from(42182, 1),
+    CodePointRange::from(64285, 1),
+    CodePointRange::from(65529, 3),
+];
+
+/// Return whether c was assigned in Unicode version 3.0.
+pub(crate) fn is_age_v3_0(c: char) -> bool {
+    AGE_V3_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V3_1: [CodePointRange; 46] = [
+    CodePointRange::from(1012, 2),
+    CodePointRange::from(64976, 32),
+    CodePointRange::from(66304, 31),
+    CodePointRange::from(66336, 4),
+    CodePointRange::from(66352, 27),
+    CodePointRange::from(66560, 38),
+    CodePointRange::from(66600, 38),
+    CodePointRange::from(118784, 246),
+    CodePointRange::from(119040, 39),
+    CodePointRange::from(119082, 180),
+    CodePointRange::from(119808, 85),
+    CodePointRange::from(119894, 71),
+    CodePointRange::from(119966, 2),
+    CodePointRange::from(119970, 1),
+    CodePointRange::from(119973, 2),
+    CodePointRange::from(119977, 4),
+    CodePointRange::from(119982, 12),
+    CodePointRange::from(119995, 1),
+    CodePointRange::from(119997, 4),
+    CodePointRange::from(120002, 2),
+    CodePointRange::from(120005, 65),
+    CodePointRange::from(120071, 4),
+    CodePointRange::from(120077, 8),
+    CodePointRange::from(120086, 7),
+    CodePointRange::from(120094, 28),
+    CodePointRange::from(120123, 4),
+    CodePointRange::from(120128, 5),
+    CodePointRange::from(120134, 1),
+    CodePointRange::from(120138, 7),
+    CodePointRange::from(120146, 338),
+    CodePointRange::from(120488, 290),
+    CodePointRange::from(120782, 50),
+    CodePointRange::from(131072, 4096),
+    CodePointRange::from(135168, 4096),
+    CodePointRange::from(139264, 4096),
+    CodePointRange::from(143360, 4096),
+    CodePointRange::from(147456, 4096),
+    CodePointRange::from(151552, 4096),
+    CodePointRange::from(155648, 4096),
+    CodePointRange::from(159744, 4096),
+    CodePointRange::from(163840, 4096),
+    CodePointRange::from(167936, 4096),
+    CodePointRange::from(172032, 1751),
+    CodePointRange::from(194560, 542),
+    CodePointRange::from(917505, 1),
+    CodePointRange::from(917536, 96),
+];
+
+/// Return whether c was assigned in Unicode version 3.1.
+pub(crate) fn is_age_v3_1(c: char) -> bool {
+    AGE_V3_1
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V3_2: [CodePointRange; 59] = [
+    CodePointRange::from(544, 1),
+    CodePointRange::from(847, 1),
+    CodePointRange::from(867, 13),
+    CodePointRange::from(984, 2),
+    CodePointRange::from(1014, 1),
+    CodePointRange::from(1162, 2),
+    CodePointRange::from(1221, 2),
+    CodePointRange::from(1225, 2),
+    CodePointRange::from(1229, 2),
+    CodePointRange::from(1280, 16),
+    CodePointRange::from(1646, 2),
+    CodePointRange::from(1969, 1),
+    CodePointRange::from(4343, 2),
+    CodePointRange::from(5888, 13),
+    CodePointRange::from(5902, 7),
+    CodePointRange::from(5920, 23),
+    CodePointRange::from(5952, 20),
+    CodePointRange::from(5984, 13),
+    CodePointRange::from(5998, 3),
+    CodePointRange::from(6002, 2),
+    CodePointRange::from(8263, 1),
+    CodePointRange::from(8270, 5),
+    CodePointRange::from(8279, 1),
+    CodePointRange::from(8287, 5),
+    CodePointRange::from(8305, 1),
+    CodePointRange::from(8368, 2),
+    CodePointRange::from(8420, 7),
+    CodePointRange::from(8509, 15),
+    CodePointRange::from(8692, 12),
+    CodePointRange::from(8946, 14),
+    CodePointRange::from(9084, 1),
+    CodePointRange::from(9115, 52),
+    CodePointRange::from(9451, 20),
+    CodePointRange::from(9622, 10),
+    CodePointRange::from(9720, 8),
+    CodePointRange::from(9750, 2),
+    CodePointRange::from(9842, 12),
+    CodePointRange::from(9856, 10),
+    CodePointRange::from(10088, 14),
+    CodePointRange::from(10192, 28),
+    CodePointRange::from(10224, 16),
+    CodePointRange::from(10496, 512),
+    CodePointRange::from(12347, 3),
+    CodePointRange::from(12437, 2),
+    CodePointRange::from(12447, 2),
+    CodePointRange::from(12543, 1),
+    CodePointRange::from(12784, 16),
+    CodePointRange::from(12881, 15),
+    CodePointRange::from(12977, 15),
+    CodePointRange::from(42146, 2),
+    CodePointRange::from(42164, 1),
+    CodePointRange::from(42177, 1),
+    CodePointRange::from(42181, 1),
+    CodePointRange::from(64048, 59),
+    CodePointRange::from(65020, 1),
+    CodePointRange::from(65024, 16),
+    CodePointRange::from(65093, 2),
+    CodePointRange::from(65139, 1),
+    CodePointRange::from(65375, 2),
+];
+
+/// Return whether c was assigned in Unicode version 3.2.
+pub(crate) fn is_age_v3_2(c: char) -> bool {
+    AGE_V3_2
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V4_0: [CodePointRange; 77] = [
+    CodePointRange::from(545, 1),
+    CodePointRange::from(564, 3),
+    CodePointRange::from(686, 2),
+    CodePointRange::from(751, 17),
+    CodePointRange::from(848, 8),
+    CodePointRange::from(861, 3),
+    CodePointRange::from(1015, 5),
+    CodePointRange::from(1536, 4),
+    CodePointRange::from(1549, 9),
+    CodePointRange::from(1622, 3),
+    CodePointRange::from(1774, 2),
+    CodePointRange::from(1791, 1),
+    CodePointRange::from(1837, 3),
+    CodePointRange::from(1869, 3),
+    CodePointRange::from(2308, 1),
+    CodePointRange::from(2493, 1),
+    CodePointRange::from(2561, 1),
+    CodePointRange::from(2563, 1),
+    CodePointRange::from(2700, 1),
+    CodePointRange::from(2785, 3),
+    CodePointRange::from(2801, 1),
+    CodePointRange::from(2869, 1),
+    CodePointRange::from(2929, 1),
+    CodePointRange::from(3059, 8),
+    CodePointRange::from(3260, 2),
+    CodePointRange::from(6109, 1),
+    CodePointRange::from(6128, 10),
+    CodePointRange::from(6400, 29),
+    CodePointRange::from(6432, 12),
+    CodePointRange::from(6448, 12),
+    CodePointRange::from(6464, 1),
+    CodePointRange::from(6468, 42),
+    CodePointRange::from(6512, 5),
+    CodePointRange::from(6624, 32),
+    CodePointRange::from(7424, 108),
+    CodePointRange::from(8275, 2),
+    CodePointRange::from(8507, 1),
+    CodePointRange::from(9167, 2),
+    CodePointRange::from(9471, 1),
+    CodePointRange::from(9748, 2),
+    CodePointRange::from(9866, 8),
+    CodePointRange::from(9888, 2),
+    CodePointRange::from(11008, 14),
+    CodePointRange::from(12829, 2),
+    CodePointRange::from(12880, 1),
+    CodePointRange::from(12924, 2),
+    CodePointRange::from(13004, 4),
+    CodePointRange::from(13175, 4),
+    CodePointRange::from(13278, 2),
+    CodePointRange::from(13311, 1),
+    CodePointRange::from(19904, 64),
+    CodePointRange::from(65021, 1),
+    CodePointRange::from(65095, 2),
+    CodePointRange::from(65536, 12),
+    CodePointRange::from(65549, 26),
+    CodePointRange::from(65576, 19),
+    CodePointRange::from(65596, 2),
+    CodePointRange::from(65599, 15),
+    CodePointRange::from(65616, 14),
+    CodePointRange::from(65664, 123),
+    CodePointRange::from(65792, 3),
+    CodePointRange::from(65799, 45),
+    CodePointRange::from(65847, 9),
+    CodePointRange::from(66432, 30),
+    CodePointRange::from(66463, 1),
+    CodePointRange::from(66598, 2),
+    CodePointRange::from(66638, 80),
+    CodePointRange::from(66720, 10),
+    CodePointRange::from(67584, 6),
+    CodePointRange::from(67592, 1),
+    CodePointRange::from(67594, 44),
+    CodePointRange::from(67639, 2),
+    CodePointRange::from(67644, 1),
+    CodePointRange::from(67647, 1),
+    CodePointRange::from(119552, 87),
+    CodePointRange::from(120001, 1),
+    CodePointRange::from(917760, 240),
+];
+
+/// Return whether c was assigned in Unicode version 4.0.
+pub(crate) fn is_age_v4_0(c: char) -> bool {
+    AGE_V4_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V4_1: [CodePointRange; 86] = [
+    CodePointRange::from(567, 11),
+    CodePointRange::from(856, 5),
+    CodePointRange::from(1020, 4),
+    CodePointRange::from(1270, 2),
+    CodePointRange::from(1442, 1),
+    CodePointRange::from(1477, 3),
+    CodePointRange::from(1547, 1),
+    CodePointRange::from(1566, 1),
+    CodePointRange::from(1625, 6),
+    CodePointRange::from(1872, 30),
+    CodePointRange::from(2429, 1),
+    CodePointRange::from(2510, 1),
+    CodePointRange::from(2998, 1),
+    CodePointRange::from(3046, 1),
+    CodePointRange::from(4048, 2),
+    CodePointRange::from(4345, 2),
+    CodePointRange::from(4348, 1),
+    CodePointRange::from(4615, 1),
+    CodePointRange::from(4679, 1),
+    CodePointRange::from(4743, 1),
+    CodePointRange::from(4783, 1),
+    CodePointRange::from(4815, 1),
+    CodePointRange::from(4847, 1),
+    CodePointRange::from(4879, 1),
+    CodePointRange::from(4895, 1),
+    CodePointRange::from(4935, 1),
+    CodePointRange::from(4959, 2),
+    CodePointRange::from(4992, 26),
+    CodePointRange::from(6528, 42),
+    CodePointRange::from(6576, 26),
+    CodePointRange::from(6608, 10),
+    CodePointRange::from(6622, 2),
+    CodePointRange::from(6656, 28),
+    CodePointRange::from(6686, 2),
+    CodePointRange::from(7532, 88),
+    CodePointRange::from(8277, 2),
+    CodePointRange::from(8280, 7),
+    CodePointRange::from(8336, 5),
+    CodePointRange::from(8370, 4),
+    CodePointRange::from(8427, 1),
+    CodePointRange::from(8508, 1),
+    CodePointRange::from(8524, 1),
+    CodePointRange::from(9169, 11),
+    CodePointRange::from(9752, 1),
+    CodePointRange::from(9854, 2),
+    CodePointRange::from(9874, 11),
+    CodePointRange::from(9890, 16),
+    CodePointRange::from(10176, 7),
+    CodePointRange::from(11022, 6),
+    CodePointRange::from(11264, 47),
+    CodePointRange::from(11312, 47),
+    CodePointRange::from(11392, 107),
+    CodePointRange::from(11513, 45),
+    CodePointRange::from(11568, 54),
+    CodePointRange::from(11631, 1),
+    CodePointRange::from(11648, 23),
+    CodePointRange::from(11680, 7),
+    CodePointRange::from(11688, 7),
+    CodePointRange::from(11696, 7),
+    CodePointRange::from(11704, 7),
+    CodePointRange::from(11712, 7),
+    CodePointRange::from(11720, 7),
+    CodePointRange::from(11728, 7),
+    CodePointRange::from(11736, 7),
+    CodePointRange::from(11776, 24),
+    CodePointRange::from(11804, 2),
+    CodePointRange::from(12736, 16),
+    CodePointRange::from(12926, 1),
+    CodePointRange::from(40870, 22),
+    CodePointRange::from(42752, 23),
+    CodePointRange::from(43008, 44),
+    CodePointRange::from(64112, 106),
+    CodePointRange::from(65040, 10),
+    CodePointRange::from(65856, 75),
+    CodePointRange::from(66464, 36),
+    CodePointRange::from(66504, 14),
+    CodePointRange::from(68096, 4),
+    CodePointRange::from(68101, 2),
+    CodePointRange::from(68108, 8),
+    CodePointRange::from(68117, 3),
+    CodePointRange::from(68121, 27),
+    CodePointRange::from(68152, 3),
+    CodePointRange::from(68159, 9),
+    CodePointRange::from(68176, 9),
+    CodePointRange::from(119296, 70),
+    CodePointRange::from(120484, 2),
+];
+
+/// Return whether c was assigned in Unicode version 4.1.
+pub(crate) fn is_age_v4_1(c: char) -> bool {
+    AGE_V4_1
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V5_0: [CodePointRange; 35] = [
+    CodePointRange::from(578, 14),
+    CodePointRange::from(891, 3),
+    CodePointRange::from(1231, 1),
+    CodePointRange::from(1274, 6),
+    CodePointRange::from(1296, 4),
+    CodePointRange::from(1466, 1),
+    CodePointRange::from(1984, 59),
+    CodePointRange::from(2427, 2),
+    CodePointRange::from(2430, 2),
+    CodePointRange::from(3298, 2),
+    CodePointRange::from(3313, 2),
+    CodePointRange::from(6912, 76),
+    CodePointRange::from(6992, 45),
+    CodePointRange::from(7620, 7),
+    CodePointRange::from(7678, 2),
+    CodePointRange::from(8428, 4),
+    CodePointRange::from(8525, 2),
+    CodePointRange::from(8580, 1),
+    CodePointRange::from(9180, 12),
+    CodePointRange::from(9906, 1),
+    CodePointRange::from(10183, 4),
+    CodePointRange::from(11028, 7),
+    CodePointRange::from(11040, 4),
+    CodePointRange::from(11360, 13),
+    CodePointRange::from(11380, 4),
+    CodePointRange::from(42775, 4),
+    CodePointRange::from(42784, 2),
+    CodePointRange::from(43072, 56),
+    CodePointRange::from(67840, 26),
+    CodePointRange::from(67871, 1),
+    CodePointRange::from(73728, 879),
+    CodePointRange::from(74752, 99),
+    CodePointRange::from(74864, 4),
+    CodePointRange::from(119648, 18),
+    CodePointRange::from(120778, 2),
+];
+
+/// Return whether c was assigned in Unicode version 5.0.
+pub(crate) fn is_age_v5_0(c: char) -> bool {
+    AGE_V5_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V5_1: [CodePointRange; 89] = [
+    CodePointRange::from(880, 4),
+    CodePointRange::from(886, 2),
+    CodePointRange::from(975, 1),
+    CodePointRange::from(1159, 1),
+    CodePointRange::from(1300, 16),
+    CodePointRange::from(1542, 5),
+    CodePointRange::from(1558, 5),
+    CodePointRange::from(1595, 5),
+    CodePointRange::from(1902, 18),
+    CodePointRange::from(2417, 2),
+    CodePointRange::from(2641, 1),
+    CodePointRange::from(2677, 1),
+    CodePointRange::from(2884, 1),
+    CodePointRange::from(2914, 2),
+    CodePointRange::from(3024, 1),
+    CodePointRange::from(3133, 1),
+    CodePointRange::from(3160, 2),
+    CodePointRange::from(3170, 2),
+    CodePointRange::from(3192, 8),
+    CodePointRange::from(3389, 1),
+    CodePointRange::from(3396, 1),
+    CodePointRange::from(3426, 2),
+    CodePointRange::from(3440, 6),
+    CodePointRange::from(3449, 7),
+    CodePointRange::from(3947, 2),
+    CodePointRange::from(4046, 1),
+    CodePointRange::from(4050, 3),
+    CodePointRange::from(4130, 1),
+    CodePointRange::from(4136, 1),
+    CodePointRange::from(4139, 1),
+    CodePointRange::from(4147, 3),
+    CodePointRange::from(4154, 6),
+    CodePointRange::from(4186, 64),
+    CodePointRange::from(4254, 2),
+    CodePointRange::from(6314, 1),
+    CodePointRange::from(7040, 43),
+    CodePointRange::from(7086, 12),
+    CodePointRange::from(7168, 56),
+    CodePointRange::from(7227, 15),
+    CodePointRange::from(7245, 51),
+    CodePointRange::from(7627, 28),
+    CodePointRange::from(7836, 4),
+    CodePointRange::from(7930, 6),
+    CodePointRange::from(8292, 1),
+    CodePointRange::from(8432, 1),
+    CodePointRange::from(8527, 1),
+    CodePointRange::from(8581, 4),
+    CodePointRange::from(9885, 1),
+    CodePointRange::from(9907, 10),
+    CodePointRange::from(9920, 4),
+    CodePointRange::from(10188, 1),
+    CodePointRange::from(10220, 4),
+    CodePointRange::from(11035, 5),
+    CodePointRange::from(11044, 41),
+    CodePointRange::from(11088, 5),
+    CodePointRange::from(11373, 3),
+    CodePointRange::from(11377, 3),
+    CodePointRange::from(11384, 6),
+    CodePointRange::from(11744, 32),
+    CodePointRange::from(11800, 4),
+    CodePointRange::from(11806, 19),
+    CodePointRange::from(12589, 1),
+    CodePointRange::from(12752, 20),
+    CodePointRange::from(40892, 8),
+    CodePointRange::from(42240, 300),
+    CodePointRange::from(42560, 32),
+    CodePointRange::from(42594, 18),
+    CodePointRange::from(42620, 28),
+    CodePointRange::from(42779, 5),
+    CodePointRange::from(42786, 107),
+    CodePointRange::from(43003, 5),
+    CodePointRange::from(43136, 69),
+    CodePointRange::from(43214, 12),
+    CodePointRange::from(43264, 84),
+    CodePointRange::from(43359, 1),
+    CodePointRange::from(43520, 55),
+    CodePointRange::from(43584, 14),
+    CodePointRange::from(43600, 10),
+    CodePointRange::from(43612, 4),
+    CodePointRange::from(65060, 3),
+    CodePointRange::from(65936, 12),
+    CodePointRange::from(66000, 46),
+    CodePointRange::from(66176, 29),
+    CodePointRange::from(66208, 49),
+    CodePointRange::from(67872, 26),
+    CodePointRange::from(67903, 1),
+    CodePointRange::from(119081, 1),
+    CodePointRange::from(126976, 44),
+    CodePointRange::from(127024, 100),
+];
+
+/// Return whether c was assigned in Unicode version 5.1.
+pub(crate) fn is_age_v5_1(c: char) -> bool {
+    AGE_V5_1
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V5_2: [CodePointRange; 91] = [
+    CodePointRange::from(1316, 2),
+    CodePointRange::from(2048, 46),
+    CodePointRange::from(2096, 15),
+    CodePointRange::from(2304, 1),
+    CodePointRange::from(2382, 1),
+    CodePointRange::from(2389, 1),
+    CodePointRange::from(2425, 2),
+    CodePointRange::from(2555, 1),
+    CodePointRange::from(4053, 4),
+    CodePointRange::from(4250, 4),
+    CodePointRange::from(4442, 5),
+    CodePointRange::from(4515, 5),
+    CodePointRange::from(4602, 6),
+    CodePointRange::from(5120, 1),
+    CodePointRange::from(5751, 9),
+    CodePointRange::from(6320, 70),
+    CodePointRange::from(6570, 2),
+    CodePointRange::from(6618, 1),
+    CodePointRange::from(6688, 63),
+    CodePointRange::from(6752, 29),
+    CodePointRange::from(6783, 11),
+    CodePointRange::from(6800, 10),
+    CodePointRange::from(6816, 14),
+    CodePointRange::from(7376, 35),
+    CodePointRange::from(7677, 1),
+    CodePointRange::from(8374, 3),
+    CodePointRange::from(8528, 3),
+    CodePointRange::from(8585, 1),
+    CodePointRange::from(9192, 1),
+    CodePointRange::from(9886, 2),
+    CodePointRange::from(9917, 3),
+    CodePointRange::from(9924, 10),
+    CodePointRange::from(9935, 19),
+    CodePointRange::from(9955, 1),
+    CodePointRange::from(9960, 24),
+    CodePointRange::from(10071, 1),
+    CodePointRange::from(11093, 5),
+    CodePointRange::from(11376, 1),
+    CodePointRange::from(11390, 2),
+    CodePointRange::from(11499, 7),
+    CodePointRange::from(11825, 1),
+    CodePointRange::from(12868, 12),
+    CodePointRange::from(40900, 8),
+    CodePointRange::from(42192, 48),
+    CodePointRange::from(42656, 88),
+    CodePointRange::from(43056, 10),
+    CodePointRange::from(43232, 28),
+    CodePointRange::from(43360, 29),
+    CodePointRange::from(43392, 78),
+    CodePointRange::from(43471, 11),
+    CodePointRange::from(43486, 2),
+    CodePointRange::from(43616, 28),
+    CodePointRange::from(43648, 67),
+    CodePointRange::from(43739, 5),
+    CodePointRange::from(43968, 46),
+    CodePointRange::from(44016, 10),
+    CodePointRange::from(55216, 23),
+    CodePointRange::from(55243, 49),
+    CodePointRange::from(64107, 3),
+    CodePointRange::from(67648, 22),
+    CodePointRange::from(67671, 9),
+    CodePointRange::from(67866, 2),
+    CodePointRange::from(68192, 32),
+    CodePointRange::from(68352, 54),
+    CodePointRange::from(68409, 29),
+    CodePointRange::from(68440, 27),
+    CodePointRange::from(68472, 8),
+    CodePointRange::from(68608, 73),
+    CodePointRange::from(69216, 31),
+    CodePointRange::from(69760, 66),
+    CodePointRange::from(77824, 1071),
+    CodePointRange::from(127232, 11),
+    CodePointRange::from(127248, 31),
+    CodePointRange::from(127281, 1),
+    CodePointRange::from(127293, 1),
+    CodePointRange::from(127295, 1),
+    CodePointRange::from(127298, 1),
+    CodePointRange::from(127302, 1),
+    CodePointRange::from(127306, 5),
+    CodePointRange::from(127319, 1),
+    CodePointRange::from(127327, 1),
+    CodePointRange::from(127353, 1),
+    CodePointRange::from(127355, 2),
+    CodePointRange::from(127359, 1),
+    CodePointRange::from(127370, 4),
+    CodePointRange::from(127376, 1),
+    CodePointRange::from(127488, 1),
+    CodePointRange::from(127504, 34),
+    CodePointRange::from(127552, 9),
+    CodePointRange::from(173824, 4096),
+    CodePointRange::from(177920, 53),
+];
+
+/// Return whether c was assigned in Unicode version 5.2.
+pub(crate) fn is_age_v5_2(c: char) -> bool {
+    AGE_V5_2
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V6_0: [CodePointRange; 106] = [
+    CodePointRange::from(1318, 2),
+    CodePointRange::from(1568, 1),
+    CodePointRange::from(1631, 1),
+    CodePointRange::from(2112, 28),
+    CodePointRange::from(2142, 1),
+    CodePointRange::from(2362, 2),
+    CodePointRange::from(2383, 1),
+    CodePointRange::from(2390, 2),
+    CodePointRange::from(2419, 5),
+    CodePointRange::from(2930, 6),
+    CodePointRange::from(3369, 1),
+    CodePointRange::from(3386, 1),
+    CodePointRange::from(3406, 1),
+    CodePointRange::from(3980, 4),
+    CodePointRange::from(4057, 2),
+    CodePointRange::from(4957, 2),
+    CodePointRange::from(7104, 52),
+    CodePointRange::from(7164, 4),
+    CodePointRange::from(7676, 1),
+    CodePointRange::from(8341, 8),
+    CodePointRange::from(8377, 1),
+    CodePointRange::from(9193, 11),
+    CodePointRange::from(9934, 1),
+    CodePointRange::from(9954, 1),
+    CodePointRange::from(9956, 4),
+    CodePointRange::from(9989, 1),
+    CodePointRange::from(9994, 2),
+    CodePointRange::from(10024, 1),
+    CodePointRange::from(10060, 1),
+    CodePointRange::from(10062, 1),
+    CodePointRange::from(10067, 3),
+    CodePointRange::from(10079, 2),
+    CodePointRange::from(10133, 3),
+    CodePointRange::from(10160, 1),
+    CodePointRange::from(10175, 1),
+    CodePointRange::from(10190, 2),
+    CodePointRange::from(11632, 1),
+    CodePointRange::from(11647, 1),
+    CodePointRange::from(12728, 3),
+    CodePointRange::from(42592, 2),
+    CodePointRange::from(42893, 2),
+    CodePointRange::from(42896, 2),
+    CodePointRange::from(42912, 10),
+    CodePointRange::from(43002, 1),
+    CodePointRange::from(43777, 6),
+    CodePointRange::from(43785, 6),
+    CodePointRange::from(43793, 6),
+    CodePointRange::from(43808, 7),
+    CodePointRange::from(43816, 7),
+    CodePointRange::from(64434, 16),
+    CodePointRange::from(69632, 78),
+    CodePointRange::from(69714, 30),
+    CodePointRange::from(92160, 569),
+    CodePointRange::from(110592, 2),
+    CodePointRange::from(127136, 15),
+    CodePointRange::from(127153, 14),
+    CodePointRange::from(127169, 15),
+    CodePointRange::from(127185, 15),
+    CodePointRange::from(127280, 1),
+    CodePointRange::from(127282, 11),
+    CodePointRange::from(127294, 1),
+    CodePointRange::from(127296, 2),
+    CodePointRange::from(127299, 3),
+    CodePointRange::from(127303, 3),
+    CodePointRange::from(127311, 8),
+    CodePointRange::from(127320, 7),
+    CodePointRange::from(127328, 10),
+    CodePointRange::from(127344, 9),
+    CodePointRange::from(127354, 1),
+    CodePointRange::from(127357, 2),
+    CodePointRange::from(127360, 10),
+    CodePointRange::from(127374, 2),
+    CodePointRange::from(127377, 10),
+    CodePointRange::from(127462, 26),
+    CodePointRange::from(127489, 2),
+    CodePointRange::from(127538, 9),
+    CodePointRange::from(127568, 2),
+    CodePointRange::from(127744, 33),
+    CodePointRange::from(127792, 6),
+    CodePointRange::from(127799, 70),
+    CodePointRange::from(127872, 20),
+    CodePointRange::from(127904, 37),
+    CodePointRange::from(127942, 5),
+    CodePointRange::from(127968, 17),
+    CodePointRange::from(128000, 63),
+    CodePointRange::from(128064, 1),
+    CodePointRange::from(128066, 182),
+    CodePointRange::from(128249, 4),
+    CodePointRange::from(128256, 62),
+    CodePointRange::from(128336, 24),
+    CodePointRange::from(128507, 5),
+    CodePointRange::from(128513, 16),
+    CodePointRange::from(128530, 3),
+    CodePointRange::from(128534, 1),
+    CodePointRange::from(128536, 1),
+    CodePointRange::from(128538, 1),
+    CodePointRange::from(128540, 3),
+    CodePointRange::from(128544, 6),
+    CodePointRange::from(128552, 4),
+    CodePointRange::from(128557, 1),
+    CodePointRange::from(128560, 4),
+    CodePointRange::from(128565, 12),
+    CodePointRange::from(128581, 11),
+    CodePointRange::from(128640, 70),
+    CodePointRange::from(128768, 116),
+    CodePointRange::from(177984, 222),
+];
+
+/// Return whether c was assigned in Unicode version 6.0.
+pub(crate) fn is_age_v6_0(c: char) -> bool {
+    AGE_V6_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V6_1: [CodePointRange; 89] = [
+    CodePointRange::from(1423, 1),
+    CodePointRange::from(1540, 1),
+    CodePointRange::from(2208, 1),
+    CodePointRange::from(2210, 11),
+    CodePointRange::from(2276, 27),
+    CodePointRange::from(2800, 1),
+    CodePointRange::from(3806, 2),
+    CodePointRange::from(4295, 1),
+    CodePointRange::from(4301, 1),
+    CodePointRange::from(4349, 3),
+    CodePointRange::from(7083, 3),
+    CodePointRange::from(7098, 6),
+    CodePointRange::from(7360, 8),
+    CodePointRange::from(7411, 4),
+    CodePointRange::from(10187, 1),
+    CodePointRange::from(10189, 1),
+    CodePointRange::from(11506, 2),
+    CodePointRange::from(11559, 1),
+    CodePointRange::from(11565, 1),
+    CodePointRange::from(11622, 2),
+    CodePointRange::from(11826, 10),
+    CodePointRange::from(40908, 1),
+    CodePointRange::from(42612, 8),
+    CodePointRange::from(42655, 1),
+    CodePointRange::from(42898, 2),
+    CodePointRange::from(42922, 1),
+    CodePointRange::from(43000, 2),
+    CodePointRange::from(43744, 23),
+    CodePointRange::from(64046, 2),
+    CodePointRange::from(67968, 56),
+    CodePointRange::from(68030, 2),
+    CodePointRange::from(69840, 25),
+    CodePointRange::from(69872, 10),
+    CodePointRange::from(69888, 53),
+    CodePointRange::from(69942, 14),
+    CodePointRange::from(70016, 73),
+    CodePointRange::from(70096, 10),
+    CodePointRange::from(71296, 56),
+    CodePointRange::from(71360, 10),
+    CodePointRange::from(93952, 69),
+    CodePointRange::from(94032, 47),
+    CodePointRange::from(94095, 17),
+    CodePointRange::from(126464, 4),
+    CodePointRange::from(126469, 27),
+    CodePointRange::from(126497, 2),
+    CodePointRange::from(126500, 1),
+    CodePointRange::from(126503, 1),
+    CodePointRange::from(126505, 10),
+    CodePointRange::from(126516, 4),
+    CodePointRange::from(126521, 1),
+    CodePointRange::from(126523, 1),
+    CodePointRange::from(126530, 1),
+    CodePointRange::from(126535, 1),
+    CodePointRange::from(126537, 1),
+    CodePointRange::from(126539, 1),
+    CodePointRange::from(126541, 3),
+    CodePointRange::from(126545, 2),
+    CodePointRange::from(126548, 1),
+    CodePointRange::from(126551, 1),
+    CodePointRange::from(126553, 1),
+    CodePointRange::from(126555, 1),
+    CodePointRange::from(126557, 1),
+    CodePointRange::from(126559, 1),
+    CodePointRange::from(126561, 2),
+    CodePointRange::from(126564, 1),
+    CodePointRange::from(126567, 4),
+    CodePointRange::from(126572, 7),
+    CodePointRange::from(126580, 4),
+    CodePointRange::from(126585, 4),
+    CodePointRange::from(126590, 1),
+    CodePointRange::from(126592, 10),
+    CodePointRange::from(126603, 17),
+    CodePointRange::from(126625, 3),
+    CodePointRange::from(126629, 5),
+    CodePointRange::from(126635, 17),
+    CodePointRange::from(126704, 2),
+    CodePointRange::from(127338, 2),
+    CodePointRange::from(128320, 4),
+    CodePointRange::from(128512, 1),
+    CodePointRange::from(128529, 1),
+    CodePointRange::from(128533, 1),
+    CodePointRange::from(128535, 1),
+    CodePointRange::from(128537, 1),
+    CodePointRange::from(128539, 1),
+    CodePointRange::from(128543, 1),
+    CodePointRange::from(128550, 2),
+    CodePointRange::from(128556, 1),
+    CodePointRange::from(128558, 2),
+    CodePointRange::from(128564, 1),
+];
+
+/// Return whether c was assigned in Unicode version 6.1.
+pub(crate) fn is_age_v6_1(c: char) -> bool {
+    AGE_V6_1
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V6_2: [CodePointRange; 1] = [CodePointRange::from(8378, 1)];
+
+/// Return whether c was assigned in Unicode version 6.2.
+pub(crate) fn is_age_v6_2(c: char) -> bool {
+    AGE_V6_2
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V6_3: [CodePointRange; 2] =
+    [CodePointRange::from(1564, 1), CodePointRange::from(8294, 4)];
+
+/// Return whether c was assigned in Unicode version 6.3.
+pub(crate) fn is_age_v6_3(c: char) -> bool {
+    AGE_V6_3
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V7_0: [CodePointRange; 141] = [
+    CodePointRange::from(895, 1),
+    CodePointRange::from(1320, 8),
+    CodePointRange::from(1421, 2),
+    CodePointRange::from(1541, 1),
+    CodePointRange::from(2209, 1),
+    CodePointRange::from(2221, 6),
+    CodePointRange::from(2303, 1),
+    CodePointRange::from(2424, 1),
+    CodePointRange::from(2432, 1),
+    CodePointRange::from(3072, 1),
+    CodePointRange::from(3124, 1),
+    CodePointRange::from(3201, 1),
+    CodePointRange::from(3329, 1),
+    CodePointRange::from(3558, 10),
+    CodePointRange::from(5873, 8),
+    CodePointRange::from(6429, 2),
+    CodePointRange::from(6832, 15),
+    CodePointRange::from(7416, 2),
+    CodePointRange::from(7655, 15),
+    CodePointRange::from(8379, 3),
+    CodePointRange::from(9204, 7),
+    CodePointRange::from(9984, 1),
+    CodePointRange::from(11085, 3),
+    CodePointRange::from(11098, 26),
+    CodePointRange::from(11126, 32),
+    CodePointRange::from(11160, 34),
+    CodePointRange::from(11197, 12),
+    CodePointRange::from(11210, 8),
+    CodePointRange::from(11836, 7),
+    CodePointRange::from(42648, 6),
+    CodePointRange::from(42900, 12),
+    CodePointRange::from(42923, 3),
+    CodePointRange::from(42928, 2),
+    CodePointRange::from(42999, 1),
+    CodePointRange::from(43488, 31),
+    CodePointRange::from(43644, 4),
+    CodePointRange::from(43824, 48),
+    CodePointRange::from(43876, 2),
+    CodePointRange::from(65063, 7),
+    CodePointRange::from(65931, 2),
+    CodePointRange::from(65952, 1),
+    CodePointRange::from(66272, 28),
+    CodePointRange::from(66335, 1),
+    CodePointRange::from(66384, 43),
+    CodePointRange::from(66816, 40),
+    CodePointRange::from(66864, 52),
+    CodePointRange::from(66927, 1),
+    CodePointRange::from(67072, 311),
+    CodePointRange::from(67392, 22),
+    CodePointRange::from(67424, 8),
+    CodePointRange::from(67680, 63),
+    CodePointRange::from(67751, 9),
+    CodePointRange::from(68224, 32),
+    CodePointRange::from(68288, 39),
+    CodePointRange::from(68331, 12),
+    CodePointRange::from(68480, 18),
+    CodePointRange::from(68505, 4),
+    CodePointRange::from(68521, 7),
+    CodePointRange::from(69759, 1),
+    CodePointRange::from(69968, 39),
+    CodePointRange::from(70093, 1),
+    CodePointRange::from(70106, 1),
+    CodePointRange::from(70113, 20),
+    CodePointRange::from(70144, 18),
+    CodePointRange::from(70163, 43),
+    CodePointRange::from(70320, 59),
+    CodePointRange::from(70384, 10),
+    CodePointRange::from(70401, 3),
+    CodePointRange::from(70405, 8),
+    CodePointRange::from(70415, 2),
+    CodePointRange::from(70419, 22),
+    CodePointRange::from(70442, 7),
+    CodePointRange::from(70450, 2),
+    CodePointRange::from(70453, 5),
+    CodePointRange::from(70460, 9),
+    CodePointRange::from(70471, 2),
+    CodePointRange::from(70475, 3),
+    CodePointRange::from(70487, 1),
+    CodePointRange::from(70493, 7),
+    CodePointRange::from(70502, 7),
+    CodePointRange::from(70512, 5),
+    CodePointRange::from(70784, 72),
+    CodePointRange::from(70864, 10),
+    CodePointRange::from(71040, 54),
+    CodePointRange::from(71096, 18),
+    CodePointRange::from(71168, 69),
+    CodePointRange::from(71248, 10),
+    CodePointRange::from(71840, 83),
+    CodePointRange::from(71935, 1),
+    CodePointRange::from(72384, 57),
+    CodePointRange::from(74607, 42),
+    CodePointRange::from(74851, 12),
+    CodePointRange::from(74868, 1),
+    CodePointRange::from(92736, 31),
+    CodePointRange::from(92768, 10),
+    CodePointRange::from(92782, 2),
+    CodePointRange::from(92880, 30),
+    CodePointRange::from(92912, 6),
+    CodePointRange::from(92928, 70),
+    CodePointRange::from(93008, 10),
+    CodePointRange::from(93019, 7),
+    CodePointRange::from(93027, 21),
+    CodePointRange::from(93053, 19),
+    CodePointRange::from(113664, 107),
+    CodePointRange::from(113776, 13),
+    CodePointRange::from(113792, 9),
+    CodePointRange::from(113808, 10),
+    CodePointRange::from(113820, 8),
+    CodePointRange::from(124928, 197),
+    CodePointRange::from(125127, 16),
+    CodePointRange::from(127167, 1),
+    CodePointRange::from(127200, 22),
+    CodePointRange::from(127243, 2),
+    CodePointRange::from(127777, 12),
+    CodePointRange::from(127798, 1),
+    CodePointRange::from(127869, 1),
+    CodePointRange::from(127892, 12),
+    CodePointRange::from(127941, 1),
+    CodePointRange::from(127947, 4),
+    CodePointRange::from(127956, 12),
+    CodePointRange::from(127985, 7),
+    CodePointRange::from(128063, 1),
+    CodePointRange::from(128065, 1),
+    CodePointRange::from(128248, 1),
+    CodePointRange::from(128253, 2),
+    CodePointRange::from(128318, 2),
+    CodePointRange::from(128324, 7),
+    CodePointRange::from(128360, 18),
+    CodePointRange::from(128379, 41),
+    CodePointRange::from(128421, 86),
+    CodePointRange::from(128577, 2),
+    CodePointRange::from(128592, 48),
+    CodePointRange::from(128710, 10),
+    CodePointRange::from(128736, 13),
+    CodePointRange::from(128752, 4),
+    CodePointRange::from(128896, 85),
+    CodePointRange::from(129024, 12),
+    CodePointRange::from(129040, 56),
+    CodePointRange::from(129104, 10),
+    CodePointRange::from(129120, 40),
+    CodePointRange::from(129168, 30),
+];
+
+/// Return whether c was assigned in Unicode version 7.0.
+pub(crate) fn is_age_v7_0(c: char) -> bool {
+    AGE_V7_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V8_0: [CodePointRange; 60] = [
+    CodePointRange::from(2227, 2),
+    CodePointRange::from(2275, 1),
+    CodePointRange::from(2809, 1),
+    CodePointRange::from(3162, 1),
+    CodePointRange::from(3423, 1),
+    CodePointRange::from(5109, 1),
+    CodePointRange::from(5112, 6),
+    CodePointRange::from(8382, 1),
+    CodePointRange::from(8586, 2),
+    CodePointRange::from(11244, 4),
+    CodePointRange::from(40909, 9),
+    CodePointRange::from(42654, 1),
+    CodePointRange::from(42895, 1),
+    CodePointRange::from(42930, 6),
+    CodePointRange::from(43260, 2),
+    CodePointRange::from(43872, 4),
+    CodePointRange::from(43888, 80),
+    CodePointRange::from(65070, 2),
+    CodePointRange::from(67808, 19),
+    CodePointRange::from(67828, 2),
+    CodePointRange::from(67835, 5),
+    CodePointRange::from(68028, 2),
+    CodePointRange::from(68032, 16),
+    CodePointRange::from(68050, 46),
+    CodePointRange::from(68736, 51),
+    CodePointRange::from(68800, 51),
+    CodePointRange::from(68858, 6),
+    CodePointRange::from(70089, 4),
+    CodePointRange::from(70107, 5),
+    CodePointRange::from(70272, 7),
+    CodePointRange::from(70280, 1),
+    CodePointRange::from(70282, 4),
+    CodePointRange::from(70287, 15),
+    CodePointRange::from(70303, 11),
+    CodePointRange::from(70400, 1),
+    CodePointRange::from(70480, 1),
+    CodePointRange::from(71114, 20),
+    CodePointRange::from(71424, 26),
+    CodePointRange::from(71453, 15),
+    CodePointRange::from(71472, 16),
+    CodePointRange::from(74649, 1),
+    CodePointRange::from(74880, 196),
+    CodePointRange::from(82944, 583),
+    CodePointRange::from(119262, 11),
+    CodePointRange::from(120832, 652),
+    CodePointRange::from(121499, 5),
+    CodePointRange::from(121505, 15),
+    CodePointRange::from(127789, 3),
+    CodePointRange::from(127870, 2),
+    CodePointRange::from(127951, 5),
+    CodePointRange::from(127992, 8),
+    CodePointRange::from(128255, 1),
+    CodePointRange::from(128331, 5),
+    CodePointRange::from(128579, 2),
+    CodePointRange::from(128720, 1),
+    CodePointRange::from(129296, 9),
+    CodePointRange::from(129408, 5),
+    CodePointRange::from(129472, 1),
+    CodePointRange::from(178208, 4096),
+    CodePointRange::from(182304, 1666),
+];
+
+/// Return whether c was assigned in Unicode version 8.0.
+pub(crate) fn is_age_v8_0(c: char) -> bool {
+    AGE_V8_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V9_0: [CodePointRange; 53] = [
+    CodePointRange::from(2230, 8),
+    CodePointRange::from(2260, 15),
+    CodePointRange::from(3200, 1),
+    CodePointRange::from(3407, 1),
+    CodePointRange::from(3412, 3),
+    CodePointRange::from(3416, 7),
+    CodePointRange::from(3446, 3),
+    CodePointRange::from(7296, 9),
+    CodePointRange::from(7675, 1),
+    CodePointRange::from(9211, 4),
+    CodePointRange::from(11843, 2),
+    CodePointRange::from(42926, 1),
+    CodePointRange::from(43205, 1),
+    CodePointRange::from(65933, 2),
+    CodePointRange::from(66736, 36),
+    CodePointRange::from(66776, 36),
+    CodePointRange::from(70206, 1),
+    CodePointRange::from(70656, 90),
+    CodePointRange::from(70747, 1),
+    CodePointRange::from(70749, 1),
+    CodePointRange::from(71264, 13),
+    CodePointRange::from(72704, 9),
+    CodePointRange::from(72714, 45),
+    CodePointRange::from(72760, 14),
+    CodePointRange::from(72784, 29),
+    CodePointRange::from(72816, 32),
+    CodePointRange::from(72850, 22),
+    CodePointRange::from(72873, 14),
+    CodePointRange::from(94176, 1),
+    CodePointRange::from(94208, 4096),
+    CodePointRange::from(98304, 2029),
+    CodePointRange::from(100352, 755),
+    CodePointRange::from(122880, 7),
+    CodePointRange::from(122888, 17),
+    CodePointRange::from(122907, 7),
+    CodePointRange::from(122915, 2),
+    CodePointRange::from(122918, 5),
+    CodePointRange::from(125184, 75),
+    CodePointRange::from(125264, 10),
+    CodePointRange::from(125278, 2),
+    CodePointRange::from(127387, 18),
+    CodePointRange::from(127547, 1),
+    CodePointRange::from(128378, 1),
+    CodePointRange::from(128420, 1),
+    CodePointRange::from(128721, 2),
+    CodePointRange::from(128756, 3),
+    CodePointRange::from(129305, 6),
+    CodePointRange::from(129312, 8),
+    CodePointRange::from(129328, 1),
+    CodePointRange::from(129331, 12),
+    CodePointRange::from(129344, 12),
+    CodePointRange::from(129360, 15),
+    CodePointRange::from(129413, 13),
+];
+
+/// Return whether c was assigned in Unicode version 9.0.
+pub(crate) fn is_age_v9_0(c: char) -> bool {
+    AGE_V9_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V10_0: [CodePointRange; 41] = [
+    CodePointRange::from(2144, 11),
+    CodePointRange::from(2556, 2),
+    CodePointRange::from(2810, 6),
+    CodePointRange::from(3328, 1),
+    CodePointRange::from(3387, 2),
+    CodePointRange::from(7415, 1),
+    CodePointRange::from(7670, 4),
+    CodePointRange::from(8383, 1),
+    CodePointRange::from(9215, 1),
+    CodePointRange::from(11218, 1),
+    CodePointRange::from(11845, 5),
+    CodePointRange::from(12590, 1),
+    CodePointRange::from(40918, 21),
+    CodePointRange::from(66349, 3),
+    CodePointRange::from(72192, 72),
+    CodePointRange::from(72272, 52),
+    CodePointRange::from(72326, 23),
+    CodePointRange::from(72350, 5),
+    CodePointRange::from(72960, 7),
+    CodePointRange::from(72968, 2),
+    CodePointRange::from(72971, 44),
+    CodePointRange::from(73018, 1),
+    CodePointRange::from(73020, 2),
+    CodePointRange::from(73023, 9),
+    CodePointRange::from(73040, 10),
+    CodePointRange::from(94177, 1),
+    CodePointRange::from(110594, 285),
+    CodePointRange::from(110960, 396),
+    CodePointRange::from(127584, 6),
+    CodePointRange::from(128723, 2),
+    CodePointRange::from(128759, 2),
+    CodePointRange::from(129280, 12),
+    CodePointRange::from(129311, 1),
+    CodePointRange::from(129320, 8),
+    CodePointRange::from(129329, 2),
+    CodePointRange::from(129356, 1),
+    CodePointRange::from(129375, 13),
+    CodePointRange::from(129426, 6),
+    CodePointRange::from(129488, 23),
+    CodePointRange::from(183984, 4096),
+    CodePointRange::from(188080, 3377),
+];
+
+/// Return whether c was assigned in Unicode version 10.0.
+pub(crate) fn is_age_v10_0(c: char) -> bool {
+    AGE_V10_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V11_0: [CodePointRange; 59] = [
+    CodePointRange::from(1376, 1),
+    CodePointRange::from(1416, 1),
+    CodePointRange::from(1519, 1),
+    CodePointRange::from(2045, 3),
+    CodePointRange::from(2259, 1),
+    CodePointRange::from(2558, 1),
+    CodePointRange::from(2678, 1),
+    CodePointRange::from(3076, 1),
+    CodePointRange::from(3204, 1),
+    CodePointRange::from(6264, 1),
+    CodePointRange::from(7312, 43),
+    CodePointRange::from(7357, 3),
+    CodePointRange::from(11194, 3),
+    CodePointRange::from(11219, 25),
+    CodePointRange::from(11248, 15),
+    CodePointRange::from(11850, 5),
+    CodePointRange::from(12591, 1),
+    CodePointRange::from(40939, 5),
+    CodePointRange::from(42927, 1),
+    CodePointRange::from(42936, 2),
+    CodePointRange::from(43262, 2),
+    CodePointRange::from(68148, 2),
+    CodePointRange::from(68168, 1),
+    CodePointRange::from(68864, 40),
+    CodePointRange::from(68912, 10),
+    CodePointRange::from(69376, 40),
+    CodePointRange::from(69424, 42),
+    CodePointRange::from(69837, 1),
+    CodePointRange::from(69956, 3),
+    CodePointRange::from(70459, 1),
+    CodePointRange::from(70750, 1),
+    CodePointRange::from(71450, 1),
+    CodePointRange::from(71680, 60),
+    CodePointRange::from(72349, 1),
+    CodePointRange::from(73056, 6),
+    CodePointRange::from(73063, 2),
+    CodePointRange::from(73066, 37),
+    CodePointRange::from(73104, 2),
+    CodePointRange::from(73107, 6),
+    CodePointRange::from(73120, 10),
+    CodePointRange::from(73440, 25),
+    CodePointRange::from(93760, 91),
+    CodePointRange::from(100333, 5),
+    CodePointRange::from(119520, 20),
+    CodePointRange::from(119666, 7),
+    CodePointRange::from(126065, 68),
+    CodePointRange::from(127279, 1),
+    CodePointRange::from(128761, 1),
+    CodePointRange::from(128981, 4),
+    CodePointRange::from(129357, 3),
+    CodePointRange::from(129388, 5),
+    CodePointRange::from(129395, 4),
+    CodePointRange::from(129402, 1),
+    CodePointRange::from(129404, 4),
+    CodePointRange::from(129432, 11),
+    CodePointRange::from(129456, 10),
+    CodePointRange::from(129473, 2),
+    CodePointRange::from(129511, 25),
+    CodePointRange::from(129632, 14),
+];
+
+/// Return whether c was assigned in Unicode version 11.0.
+pub(crate) fn is_age_v11_0(c: char) -> bool {
+    AGE_V11_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V12_0: [CodePointRange; 60] = [
+    CodePointRange::from(3191, 1),
+    CodePointRange::from(3718, 1),
+    CodePointRange::from(3721, 1),
+    CodePointRange::from(3724, 1),
+    CodePointRange::from(3726, 6),
+    CodePointRange::from(3736, 1),
+    CodePointRange::from(3744, 1),
+    CodePointRange::from(3752, 2),
+    CodePointRange::from(3756, 1),
+    CodePointRange::from(3770, 1),
+    CodePointRange::from(7418, 1),
+    CodePointRange::from(11209, 1),
+    CodePointRange::from(11263, 1),
+    CodePointRange::from(11855, 1),
+    CodePointRange::from(42938, 6),
+    CodePointRange::from(42946, 5),
+    CodePointRange::from(43878, 2),
+    CodePointRange::from(69600, 23),
+    CodePointRange::from(70751, 1),
+    CodePointRange::from(71352, 1),
+    CodePointRange::from(72096, 8),
+    CodePointRange::from(72106, 46),
+    CodePointRange::from(72154, 11),
+    CodePointRange::from(72324, 2),
+    CodePointRange::from(73664, 50),
+    CodePointRange::from(73727, 1),
+    CodePointRange::from(78896, 9),
+    CodePointRange::from(94021, 6),
+    CodePointRange::from(94031, 1),
+    CodePointRange::from(94079, 9),
+    CodePointRange::from(94178, 2),
+    CodePointRange::from(100338, 6),
+    CodePointRange::from(110928, 3),
+    CodePointRange::from(110948, 4),
+    CodePointRange::from(123136, 45),
+    CodePointRange::from(123184, 14),
+    CodePointRange::from(123200, 10),
+    CodePointRange::from(123214, 2),
+    CodePointRange::from(123584, 58),
+    CodePointRange::from(123647, 1),
+    CodePointRange::from(125259, 1),
+    CodePointRange::from(126209, 61),
+    CodePointRange::from(127340, 1),
+    CodePointRange::from(128725, 1),
+    CodePointRange::from(128762, 1),
+    CodePointRange::from(128992, 12),
+    CodePointRange::from(129293, 3),
+    CodePointRange::from(129343, 1),
+    CodePointRange::from(129393, 1),
+    CodePointRange::from(129403, 1),
+    CodePointRange::from(129445, 6),
+    CodePointRange::from(129454, 2),
+    CodePointRange::from(129466, 6),
+    CodePointRange::from(129475, 8),
+    CodePointRange::from(129485, 3),
+    CodePointRange::from(129536, 84),
+    CodePointRange::from(129648, 4),
+    CodePointRange::from(129656, 3),
+    CodePointRange::from(129664, 3),
+    CodePointRange::from(129680, 6),
+];
+
+/// Return whether c was assigned in Unicode version 12.0.
+pub(crate) fn is_age_v12_0(c: char) -> bool {
+    AGE_V12_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V12_1: [CodePointRange; 1] = [CodePointRange::from(13055, 1)];
+
+/// Return whether c was assigned in Unicode version 12.1.
+pub(crate) fn is_age_v12_1(c: char) -> bool {
+    AGE_V12_1
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) const AGE_V13_0: [CodePointRange; 60] = [
+    CodePointRange::from(2238, 10),
+    CodePointRange::from(2901, 1),
+    CodePointRange::from(3332, 1),
+    CodePointRange::from(3457, 1),
+    CodePointRange::from(6847, 2),
+    CodePointRange::from(11159, 1),
+    CodePointRange::from(11856, 3),
+    CodePointRange::from(12731, 5),
+    CodePointRange::from(19894, 10),
+    CodePointRange::from(40944, 13),
+    CodePointRange::from(42951, 4),
+    CodePointRange::from(42997, 2),
+    CodePointRange::from(43052, 1),
+    CodePointRange::from(43880, 4),
+    CodePointRange::from(65948, 1),
+    CodePointRange::from(69248, 42),
+    CodePointRange::from(69291, 3),
+    CodePointRange::from(69296, 2),
+    CodePointRange::from(69552, 28),
+    CodePointRange::from(69959, 1),
+    CodePointRange::from(70094, 2),
+    CodePointRange::from(70746, 1),
+    CodePointRange::from(70752, 2),
+    CodePointRange::from(71936, 7),
+    CodePointRange::from(71945, 1),
+    CodePointRange::from(71948, 8),
+    CodePointRange::from(71957, 2),
+    CodePointRange::from(71960, 30),
+    CodePointRange::from(71991, 2),
+    CodePointRange::from(71995, 12),
+    CodePointRange::from(72016, 10),
+    CodePointRange::from(73648, 1),
+    CodePointRange::from(94180, 1),
+    CodePointRange::from(94192, 2),
+    CodePointRange::from(101107, 483),
+    CodePointRange::from(101632, 9),
+    CodePointRange::from(127245, 3),
+    CodePointRange::from(127341, 3),
+    CodePointRange::from(127405, 1),
+    CodePointRange::from(128726, 2),
+    CodePointRange::from(128763, 2),
+    CodePointRange::from(129200, 2),
+    CodePointRange::from(129292, 1),
+    CodePointRange::from(129394, 1),
+    CodePointRange::from(129399, 2),
+    CodePointRange::from(129443, 2),
+    CodePointRange::from(129451, 3),
+    CodePointRange::from(129483, 1),
+    CodePointRange::from(129652, 1),
+    CodePointRange::from(129667, 4),
+    CodePointRange::from(129686, 19),
+    CodePointRange::from(129712, 7),
+    CodePointRange::from(129728, 3),
+    CodePointRange::from(129744, 7),
+    CodePointRange::from(129792, 147),
+    CodePointRange::from(129940, 55),
+    CodePointRange::from(130032, 10),
+    CodePointRange::from(173783, 7),
+    CodePointRange::from(196608, 4096),
+    CodePointRange::from(200704, 843),
+];
+
+/// Return whether c was assigned in Unicode version 13.0.
+pub(crate) fn is_age_v13_0(c: char) -> bool {
+    AGE_V13_0
+        .binary_search_by(|&cpr| cpr.compare(c as u32))
+        .is_ok()
+}
+
+pub(crate) fn is_property_value_age(c: char, value: &UnicodePropertyValueAge) -> bool {
+    use UnicodePropertyValueAge::*;
+    match value {
+        V1_1 => is_age_v1_1(c),
+        V2_0 => is_age_v2_0(c),
+        V2_1 => is_age_v2_1(c),
+        V3_0 => is_age_v3_0(c),
+        V3_1 => is_age_v3_1(c),
+        V3_2 => is_age_v3_2(c),
+        V4_0 => is_age_v4_0(c),
+        V4_1 => is_age_v4_1(c),
+        V5_0 => is_age_v5_0(c),
+        V5_1 => is_age_v5_1(c),
+        V5_2 => is_age_v5_2(c),
+        V6_0 => is_age_v6_0(c),
+        V6_1 => is_age_v6_1(c),
+        V6_2 => is_age_v6_2(c),
+        V6_3 => is_age_v6_3(c),
+        V7_0 => is_age_v7_0(c),
+        V8_0 => is_age_v8_0(c),
+        V9_0 => is_age_v9_0(c),
+        V10_0 => is_age_v10_0(c),
+        V11_0 => is_age_v11_0(c),
+        V12_0 => is_age_v12_0(c),
+        V12_1 => is_age_v12_1(c),
+        V13_0 => is_age_v13_0(c),
+    }
+}
+
+pub(crate) fn property_value_age_table(value: &UnicodePropertyValueAge) -> CodePointTable {
+    use UnicodePropertyValueAge::*;
+    match value {
+        V1_1 => CodePointTable::Packed(&AGE_V1_1),
+        V2_0 => CodePointTable::Unpacked(&AGE_V2_0),
+        V2_1 => CodePointTable::Packed(&AGE_V2_1),
+        V3_0 => CodePointTable::Packed(&AGE_V3_0),
+        V3_1 => CodePointTable::Packed(&AGE_V3_1),
+        V3_2 => CodePointTable::Packed(&AGE_V3_2),
+        V4_0 => CodePointTable::Packed(&AGE_V4_0),
+        V4_1 => CodePointTable::Packed(&AGE_V4_1),
+        V5_0 => CodePointTable::Packed(&AGE_V5_0),
+        V5_1 => CodePointTable::Packed(&AGE_V5_1),
+        V5_2 => CodePointTable::Packed(&AGE_V5_2),
+        V6_0 => CodePointTable::Packed(&AGE_V6_0),
+        V6_1 => CodePointTable::Packed(&AGE_V6_1),
+        V6_2 => CodePointTable::Packed(&AGE_V6_2),
+        V6_3 => CodePointTable::Packed(&AGE_V6_3),
+        V7_0 => CodePointTable::Packed(&AGE_V7_0),
+        V8_0 => CodePointTable::Packed(&AGE_V8_0),
+        V9_0 => CodePointTable::Packed(&AGE_V9_0),
+        V10_0 => CodePointTable::Packed(&AGE_V10_0),
+        V11_0 => CodePointTable::Packed(&AGE_V11_0),
+        V12_0 => CodePointTable::Packed(&AGE_V12_0),
+        V12_1 => CodePointTable::Packed(&AGE_V12_1),
+        V13_0 => CodePointTable::Packed(&AGE_V13_0),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum UnicodePropertyValueAge {
+    V1_1,
+    V2_0,
+    V2_1,
+    V3_0,
+    V3_1,
+    V3_2,
+    V4_0,
+    V4_1,
+    V5_0,
+    V5_1,
+    V5_2,
+    V6_0,
+    V6_1,
+    V6_2,
+    V6_3,
+    V7_0,
+    V8_0,
+    V9_0,
+    V10_0,
+    V11_0,
+    V12_0,
+    V12_1,
+    V13_0,
+}
+
+pub fn unicode_property_value_age_from_str(s: &str) -> Option<UnicodePropertyValueAge> {
+    use UnicodePropertyValueAge::*;
+    match s {
+        "1.1" | "V1_1" => Some(V1_1),
+        "2.0" | "V2_0" => Some(V2_0),
+        "2.1" | "V2_1" => Some(V2_1),
+        "3.0" | "V3_0" => Some(V3_0),
+        "3.1" | "V3_1" => Some(V3_1),
+        "3.2" | "V3_2" => Some(V3_2),
+        "4.0" | "V4_0" => Some(V4_0),
+        "4.1" | "V4_1" => Some(V4_1),
+        "5.0" | "V5_0" => Some(V5_0),
+        "5.1" | "V5_1" => Some(V5_1),
+        "5.2" | "V5_2" => Some(V5_2),
+        "6.0" | "V6_0" => Some(V6_0),
+        "6.1" | "V6_1" => Some(V6_1),
+        "6.2" | "V6_2" => Some(V6_2),
+        "6.3" | "V6_3" => Some(V6_3),
+        "7.0" | "V7_0" => Some(V7_0),
+        "8.0" | "V8_0" => Some(V8_0),
+        "9.0" | "V9_0" => Some(V9_0),
+        "10.0" | "V10_0" => Some(V10_0),
+        "11.0" | "V11_0" => Some(V11_0),
+        "12.0" | "V12_0" => Some(V12_0),
+        "12.1" | "V12_1" => Some(V12_1),
+        "13.0" | "V13_0" => Some(V13_0),
+        _ => None,
+    }
+}
+
+/// Every UnicodePropertyValueAge, in declaration order.
+pub(crate) const UNICODE_PROPERTY_VALUE_AGE_VARIANTS: [UnicodePropertyValueAge; 23] = [
+    UnicodePropertyValueAge::V1_1,
+    UnicodePropertyValueAge::V2_0,
+    UnicodePropertyValueAge::V2_1,
+    UnicodePropertyValueAge::V3_0,
+    UnicodePropertyValueAge::V3_1,
+    UnicodePropertyValueAge::V3_2,
+    UnicodePropertyValueAge::V4_0,
+    UnicodePropertyValueAge::V4_1,
+    UnicodePropertyValueAge::V5_0,
+    UnicodePropertyValueAge::V5_1,
+    UnicodePropertyValueAge::V5_2,
+    UnicodePropertyValueAge::V6_0,
+    UnicodePropertyValueAge::V6_1,
+    UnicodePropertyValueAge::V6_2,
+    UnicodePropertyValueAge::V6_3,
+    UnicodePropertyValueAge::V7_0,
+    UnicodePropertyValueAge::V8_0,
+    UnicodePropertyValueAge::V9_0,
+    UnicodePropertyValueAge::V10_0,
+    UnicodePropertyValueAge::V11_0,
+    UnicodePropertyValueAge::V12_0,
+    UnicodePropertyValueAge::V12_1,
+    UnicodePropertyValueAge::V13_0,
+];
+
+pub(crate) static BASIC_EMOJI: [&[u32]; 1329] = [
+    &[0xA9, 0xFE0F],
+    &[0xAE, 0xFE0F],
+    &[0x203C, 0xFE0F],
+    &[0x2049, 0xFE0F],
+    &[0x2122, 0xFE0F],
+    &[0x2139, 0xFE0F],
+    &[0x2194, 0xFE0F],
+    &[0x2195, 0xFE0F],
+    &[0x2196, 0xFE0F],
+    &[0x2197, 0xFE0F],
+    &[0x2198, 0xFE0F],
+    &[0x2199, 0xFE0F],
+    &[0x21A9, 0xFE0F],
+    &[0x21AA, 0xFE0F],
+    &[0x231A],
+    &[0x231B],
+    &[0x2328, 0xFE0F],
+    &[0x23CF, 0xFE0F],
+    &[0x23E9],
+    &[0x23EA],
+    &[0x23EB],
+    &[0x23EC],
+    &[0x23ED, 0xFE0F],
+    &[0x23EE, 0xFE0F],
+    &[0x23EF, 0xFE0F],
+    &[0x23F0],
+    &[0x23F1, 0xFE0F],
+    &[0x23F2, 0xFE0F],
+    &[0x23F3],
+    &[0x23F8, 0xFE0F],
+    &[0x23F9, 0xFE0F],
+    &[0x23FA, 0xFE0F],
+    &[0x24C2, 0xFE0F],
+    &[0x25AA, 0xFE0F],
+    &[0x25AB, 0xFE0F],
+    &[0x25B6, 0xFE0F],
+    &[0x25C0, 0xFE0F],
+    &[0x25FB, 0xFE0F],
+    &[0x25FC, 0xFE0F],
+    &[0x25FD],
+    &[0x25FE],
+    &[0x2600, 0xFE0F],
+    &[0x2601, 0xFE0F],
+    &[0x2602, 0xFE0F],
+    &[0x2603, 0xFE0F],
+    &[0x2604, 0xFE0F],
+    &[0x260E, 0xFE0F],
+    &[0x2611, 0xFE0F],
+    &[0x2614],
+    &[0x2615],
+    &[0x2618, 0xFE0F],
+    &[0x261D, 0xFE0F],
+    &[0x2620, 0xFE0F],
+    &[0x2622, 0xFE0F],
+    &[0x2623, 0xFE0F],
+    &[0x2626, 0xFE0F],
+    &[0x262A, 0xFE0F],
+    &[0x262E, 0xFE0F],
+    &[0x262F, 0xFE0F],
+    &[0x2638, 0xFE0F],
+    &[0x2639, 0xFE0F],
+    &[0x263A, 0xFE0F],
+    &[0x2640, 0xFE0F],
+    &[0x2642, 0xFE0F],
+    &[0x2648],
+    &[0x2649],
+    &[0x264A],
+    &[0x264B],
+    &[0x264C],
+    &[0x264D],
+    &[0x264E],
+    &[0x264F],
+    &[0x2650],
+    &[0x2651],
+    &[0x2652],
+    &[0x2653],
+    &[0x265F, 0xFE0F],
+    &[0x2660, 0xFE0F],
+    &[0x2663, 0xFE0F],
+    &[0x2665, 0xFE0F],
+    &[0x2666, 0xFE0F],
+    &[0x2668, 0xFE0F],
+    &[0x267B, 0xFE0F],
+    &[0x267E, 0xFE0F],
+    &[0x267F],
+    &[0x2692, 0xFE0F],
+    &[0x2693],
+    &[0x2694, 0xFE0F],
+    &[0x2695, 0xFE0F],
+    &[0x2696, 0xFE0F],
+    &[0x2697, 0xFE0F],
+    &[0x2699, 0xFE0F],
+    &[0x269B, 0xFE0F],
+    &[0x269C, 0xFE0F],
+    &[0x26A0, 0xFE0F],
+    &[0x26A1],
+    &[0x26A7, 0xFE0F],
+    &[0x26AA],
+    &[0x26AB],
+    &[0x26B0, 0xFE0F],
+    &[0x26B1, 0xFE0F],
+    &[0x26BD],
+    &[0x26BE],
+    &[0x26C4],
+    &[0x26C5],
+    &[0x26C8, 0xFE0F],
+    &[0x26CE],
+    &[0x26CF, 0xFE0F],
+    &[0x26D1, 0xFE0F],
+    &[0x26D3, 0xFE0F],
+    &[0x26D4],
+    &[0x26E9, 0xFE0F],
+    &[0x26EA],
+    &[0x26F0, 0xFE0F],
+    &[0x26F1, 0xFE0F],
+    &[0x26F2],
+    &[0x26F3],
+    &[0x26F4, 0xFE0F],
+    &[0x26F5],
+    &[0x26F7, 0xFE0F],
+    &[0x26F8, 0xFE0F],
+    &[0x26F9, 0xFE0F],
+    &[0x26FA],
+    &[0x26FD],
+    &[0x2702, 0xFE0F],
+    &[0x2705],
+    &[0x2708, 0xFE0F],
+    &[0x2709, 0xFE0F],
+    &[0x270A],
+    &[0x270B],
+    &[0x270C, 0xFE0F],
+    &[0x270D, 0xFE0F],
+    &[0x270F, 0xFE0F],
+    &[0x2712, 0xFE0F],
+    &[0x2714, 0xFE0F],
+    &[0x2716, 0xFE0F],
+    &[0x271D, 0xFE0F],
+    &[0x2721, 0xFE0F],
+    &[0x2728],
+    &[0x2733, 0xFE0F],
+    &[0x2734, 0xFE0F],
+    &[0x2744, 0xFE0F],
+    &[0x2747, 0xFE0F],
+    &[0x274C],
+    &[0x274E],
+    &[0x2753],
+    &[0x2754],
+    &[0x2755],
+    &[0x2757],
+    &[0x2763, 0xFE0F],
+    &[0x2764, 0xFE0F],
+    &[0x2795],
+    &[0x2796],
+    &[0x2797],
+    &[0x27A1, 0xFE0F],
+    &[0x27B0],
+    &[0x27BF],
+    &[0x2934, 0xFE0F],
+    &[0x2935, 0xFE0F],
+    &[0x2B05, 0xFE0F],
+    &[0x2B06, 0xFE0F],
+    &[0x2B07, 0xFE0F],
+    &[0x2B1B],
+    &[0x2B1C],
+    &[0x2B50],
+    &[0x2B55],
+    &[0x3030, 0xFE0F],
+    &[0x303D, 0xFE0F],
+    &[0x3297, 0xFE0F],
+    &[0x3299, 0xFE0F],
+    &[0x1F004],
+    &[0x1F0CF],
+    &[0x1F170, 0xFE0F],
+    &[0x1F171, 0xFE0F],
+    &[0x1F17E, 0xFE0F],
+    &[0x1F17F, 0xFE0F],
+    &[0x1F18E],
+    &[0x1F191],
+    &[0x1F192],
+    &[0x1F193],
+    &[0x1F194],
+    &[0x1F195],
+    &[0x1F196],
+    &[0x1F197],
+    &[0x1F198],
+    &[0x1F199],
+    &[0x1F19A],
+    &[0x1F201],
+    &[0x1F202, 0xFE0F],
+    &[0x1F21A],
+    &[0x1F22F],
+    &[0x1F232],
+    &[0x1F233],
+    &[0x1F234],
+    &[0x1F235],
+    &[0x1F236],
+    &[0x1F237, 0xFE0F],
+    &[0x1F238],
+    &[0x1F239],
+    &[0x1F23A],
+    &[0x1F250],
+    &[0x1F251],
+    &[0x1F300],
+    &[0x1F301],
+    &[0x1F302],
+    &[0x1F303],
+    &[0x1F304],
+    &[0x1F305],
+    &[0x1F306],
+    &[0x1F307],
+    &[0x1F308],
+    &[0x1F309],
+    &[0x1F30A],
+    &[0x1F30B],
+    &[0x1F30C],
+    &[0x1F30D],
+    &[0x1F30E],
+    &[0x1F30F],
+    &[0x1F310],
+    &[0x1F311],
+    &[0x1F312],
+    &[0x1F313],
+    &[0x1F314],
+    &[0x1F315],
+    &[0x1F316],
+    &[0x1F317],
+    &[0x1F318],
+    &[0x1F319],
+    &[0x1F31A],
+    &[0x1F31B],
+    &[0x1F31C],
+    &[0x1F31D],
+    &[0x1F31E],
+    &[0x1F31F],
+    &[0x1F320],
+    &[0x1F321, 0xFE0F],
+    &[0x1F324, 0xFE0F],
+    &[0x1F325, 0xFE0F],
+    &[0x1F326, 0xFE0F],
+    &[0x1F327, 0xFE0F],
+    &[0x1F328, 0xFE0F],
+    &[0x1F329, 0xFE0F],
+    &[0x1F32A, 0xFE0F],
+    &[0x1F32B, 0xFE0F],
+    &[0x1F32C, 0xFE0F],
+    &[0x1F32D],
+    &[0x1F32E],
+    &[0x1F32F],
+    &[0x1F330],
+    &[0x1F331],
+    &[0x1F332],
+    &[0x1F333],
+    &[0x1F334],
+    &[0x1F335],
+    &[0x1F336, 0xFE0F],
+    &[0x1F337],
+    &[0x1F338],
+    &[0x1F339],
+    &[0x1F33A],
+    &[0x1F33B],
+    &[0x1F33C],
+    &[0x1F33D],
+    &[0x1F33E],
+    &[0x1F33F],
+    &[0x1F340],
+    &[0x1F341],
+    &[0x1F342],
+    &[0x1F343],
+    &[0x1F344],
+    &[0x1F345],
+    &[0x1F346],
+    &[0x1F347],
+    &[0x1F348],
+    &[0x1F349],
+    &[0x1F34A],
+    &[0x1F34B],
+    &[0x1F34C],
+    &[0x1F34D],
+    &[0x1F34E],
+    &[0x1F34F],
+    &[0x1F350],
+    &[0x1F351],
+    &[0x1F352],
+    &[0x1F353],
+    &[0x1F354],
+    &[0x1F355],
+    &[0x1F356],
+    &[0x1F357],
+    &[0x1F358],
+    &[0x1F359],
+    &[0x1F35A],
+    &[0x1F35B],
+    &[0x1F35C],
+    &[0x1F35D],
+    &[0x1F35E],
+    &[0x1F35F],
+    &[0x1F360],
+    &[0x1F361],
+    &[0x1F362],
+    &[0x1F363],
+    &[0x1F364],
+    &[0x1F365],
+    &[0x1F366],
+    &[0x1F367],
+    &[0x1F368],
+    &[0x1F369],
+    &[0x1F36A],
+    &[0x1F36B],
+    &[0x1F36C],
+    &[0x1F36D],
+    &[0x1F36E],
+    &[0x1F36F],
+    &[0x1F370],
+    &[0x1F371],
+    &[0x1F372],
+    &[0x1F373],
+    &[0x1F374],
+    &[0x1F375],
+    &[0x1F376],
+    &[0x1F377],
+    &[0x1F378],
+    &[0x1F379],
+    &[0x1F37A],
+    &[0x1F37B],
+    &[0x1F37C],
+    &[0x1F37D, 0xFE0F],
+    &[0x1F37E],
+    &[0x1F37F],
+    &[0x1F380],
+    &[0x1F381],
+    &[0x1F382],
+    &[0x1F383],
+    &[0x1F384],
+    &[0x1F385],
+    &[0x1F386],
+    &[0x1F387],
+    &[0x1F388],
+    &[0x1F389],
+    &[0x1F38A],
+    &[0x1F38B],
+    &[0x1F38C],
+    &[0x1F38D],
+    &[0x1F38E],
+    &[0x1F38F],
+    &[0x1F390],
+    &[0x1F391],
+    &[0x1F392],
+    &[0x1F393],
+    &[0x1F396, 0xFE0F],
+    &[0x1F397, 0xFE0F],
+    &[0x1F399, 0xFE0F],
+    &[0x1F39A, 0xFE0F],
+    &[0x1F39B, 0xFE0F],
+    &[0x1F39E, 0xFE0F],
+    &[0x1F39F, 0xFE0F],
+    &[0x1F3A0],
+    &[0x1F3A1],
+    &[0x1F3A2],
+    &[0x1F3A3],
+    &[0x1F3A4],
+    &[0x1F3A5],
+    &[0x1F3A6],
+    &[0x1F3A7],
+    &[0x1F3A8],
+    &[0x1F3A9],
+    &[0x1F3AA],
+    &[0x1F3AB],
+    &[0x1F3AC],
+    &[0x1F3AD],
+    &[0x1F3AE],
+    &[0x1F3AF],
+    &[0x1F3B0],
+    &[0x1F3B1],
+    &[0x1F3B2],
+    &[0x1F3B3],
+    &[0x1F3B4],
+    &[0x1F3B5],
+    &[0x1F3B6],
+    &[0x1F3B7],
+    &[0x1F3B8],
+    &[0x1F3B9],
+    &[0x1F3BA],
+    &[0x1F3BB],
+    &[0x1F3BC],
+    &[0x1F3BD],
+    &[0x1F3BE],
+    &[0x1F3BF],
+    &[0x1F3C0],
+    &[0x1F3C1],
+    &[0x1F3C2],
+    &[0x1F3C3],
+    &[0x1F3C4],
+    &[0x1F3C5],
+    &[0x1F3C6],
+    &[0x1F3C7],
+    &[0x1F3C8],
+    &[0x1F3C9],
+    &[0x1F3CA],
+    &[0x1F3CB, 0xFE0F],
+    &[0x1F3CC, 0xFE0F],
+    &[0x1F3CD, 0xFE0F],
+    &[0x1F3CE, 0xFE0F],
+    &[0x1F3CF],
+    &[0x1F3D0],
+    &[0x1F3D1],
+    &[0x1F3D2],
+    &[0x1F3D3],
+    &[0x1F3D4, 0xFE0F],
+    &[0x1F3D5, 0xFE0F],
+    &[0x1F3D6, 0xFE0F],
+    &[0x1F3D7, 0xFE0F],
+    &[0x1F3D8, 0xFE0F],
+    &[0x1F3D9, 0xFE0F],
+    &[0x1F3DA, 0xFE0F],
+    &[0x1F3DB, 0xFE0F],
+    &[0x1F3DC, 0xFE0F],
+    &[0x1F3DD, 0xFE0F],
+    &[0x1F3DE, 0xFE0F],
+    &[0x1F3DF, 0xFE0F],
+    &[0x1F3E0],
+    &[0x1F3E1],
+    &[0x1F3E2],
+    &[0x1F3E3],
+    &[0x1F3E4],
+    &[0x1F3E5],
+    &[0x1F3E6],
+    &[0x1F3E7],
+    &[0x1F3E8],
+    &[0x1F3E9],
+    &[0x1F3EA],
+    &[0x1F3EB],
+    &[0x1F3EC],
+    &[0x1F3ED],
+    &[0x1F3EE],
+    &[0x1F3EF],
+    &[0x1F3F0],
+    &[0x1F3F3, 0xFE0F],
+    &[0x1F3F4],
+    &[0x1F3F5, 0xFE0F],
+    &[0x1F3F7, 0xFE0F],
+    &[0x1F3F8],
+    &[0x1F3F9],
+    &[0x1F3FA],
+    &[0x1F3FB],
+    &[0x1F3FC],
+    &[0x1F3FD],
+    &[0x1F3FE],
+    &[0x1F3FF],
+    &[0x1F400],
+    &[0x1F401],
+    &[0x1F402],
+    &[0x1F403],
+    &[0x1F404],
+    &[0x1F405],
+    &[0x1F406],
+    &[0x1F407],
+    &[0x1F408],
+    &[0x1F409],
+    &[0x1F40A],
+    &[0x1F40B],
+    &[0x1F40C],
+    &[0x1F40D],
+    &[0x1F40E],
+    &[0x1F40F],
+    &[0x1F410],
+    &[0x1F411],
+    &[0x1F412],
+    &[0x1F413],
+    &[0x1F414],
+    &[0x1F415],
+    &[0x1F416],
+    &[0x1F417],
+    &[0x1F418],
+    &[0x1F419],
+    &[0x1F41A],
+    &[0x1F41B],
+    &[0x1F41C],
+    &[0x1F41D],
+    &[0x1F41E],
+    &[0x1F41F],
+    &[0x1F420],
+    &[0x1F421],
+    &[0x1F422],
+    &[0x1F423],
+    &[0x1F424],
+    &[0x1F425],
+    &[0x1F426],
+    &[0x1F427],
+    &[0x1F428],
+    &[0x1F429],
+    &[0x1F42A],
+    &[0x1F42B],
+    &[0x1F42C],
+    &[0x1F42D],
+    &[0x1F42E],
+    &[0x1F42F],
+    &[0x1F430],
+    &[0x1F431],
+    &[0x1F432],
+    &[0x1F433],
+    &[0x1F434],
+    &[0x1F435],
+    &[0x1F436],
+    &[0x1F437],
+    &[0x1F438],
+    &[0x1F439],
+    &[0x1F43A],
+    &[0x1F43B],
+    &[0x1F43C],
+    &[0x1F43D],
+    &[0x1F43E],
+    &[0x1F43F, 0xFE0F],
+    &[0x1F440],
+    &[0x1F441, 0xFE0F],
+    &[0x1F442],
+    &[0x1F443],
+    &[0x1F444],
+    &[0x1F445],
+    &[0x1F446],
+    &[0x1F447],
+    &[0x1F448],
+    &[0x1F449],
+    &[0x1F44A],
+    &[0x1F44B],
+    &[0x1F44C],
+    &[0x1F44D],
+    &[0x1F44E],
+    &[0x1F44F],
+    &[0x1F450],
+    &[0x1F451],
+    &[0x1F452],
+    &[0x1F453],
+    &[0x1F454],
+    &[0x1F455],
+    &[0x1F456],
+    &[0x1F457],
+    &[0x1F458],
+    &[0x1F459],
+    &[0x1F45A],
+    &[0x1F45B],
+    &[0x1F45C],
+    &[0x1F45D],
+    &[0x1F45E],
+    &[0x1F45F],
+    &[0x1F460],
+    &[0x1F461],
+    &[0x1F462],
+    &[0x1F463],
+    &[0x1F464],
+    &[0x1F465],
+    &[0x1F466],
+    &[0x1F467],
+    &[0x1F468],
+    &[0x1F469],
+    &[0x1F46A],
+    &[0x1F46B],
+    &[0x1F46C],
+    &[0x1F46D],
+    &[0x1F46E],
+    &[0x1F46F],
+    &[0x1F470],
+    &[0x1F471],
+    &[0x1F472],
+    &[0x1F473],
+    &[0x1F474],
+    &[0x1F475],
+    &[0x1F476],
+    &[0x1F477],
+    &[0x1F478],
+    &[0x1F479],
+    &[0x1F47A],
+    &[0x1F47B],
+    &[0x1F47C],
+    &[0x1F47D],
+    &[0x1F47E],
+    &[0x1F47F],
+    &[0x1F480],
+    &[0x1F481],
+    &[0x1F482],
+    &[0x1F483],
+    &[0x1F484],
+    &[0x1F485],
+    &[0x1F486],
+    &[0x1F487],
+    &[0x1F488],
+    &[0x1F489],
+    &[0x1F48A],
+    &[0x1F48B],
+    &[0x1F48C],
+    &[0x1F48D],
+    &[0x1F48E],
+    &[0x1F48F],
+    &[0x1F490],
+    &[0x1F491],
+    &[0x1F492],
+    &[0x1F493],
+    &[0x1F494],
+    &[0x1F495],
+    &[0x1F496],
+    &[0x1F497],
+    &[0x1F498],
+    &[0x1F499],
+    &[0x1F49A],
+    &[0x1F49B],
+    &[0x1F49C],
+    &[0x1F49D],
+    &[0x1F49E],
+    &[0x1F49F],
+    &[0x1F4A0],
+    &[0x1F4A1],
+    &[0x1F4A2],
+    &[0x1F4A3],
+    &[0x1F4A4],
+    &[0x1F4A5],
+    &[0x1F4A6],
+    &[0x1F4A7],
+    &[0x1F4A8],
+    &[0x1F4A9],
+    &[0x1F4AA],
+    &[0x1F4AB],
+    &[0x1F4AC],
+    &[0x1F4AD],
+    &[0x1F4AE],
+    &[0x1F4AF],
+    &[0x1F4B0],
+    &[0x1F4B1],
+    &[0x1F4B2],
+    &[0x1F4B3],
+    &[0x1F4B4],
+    &[0x1F4B5],
+    &[0x1F4B6],
+    &[0x1F4B7],
+    &[0x1F4B8],
+    &[0x1F4B9],
+    &[0x1F4BA],
+    &[0x1F4BB],
+    &[0x1F4BC],
+    &[0x1F4BD],
+    &[0x1F4BE],
+    &[0x1F4BF],
+    &[0x1F4C0],
+    &[0x1F4C1],
+    &[0x1F4C2],
+    &[0x1F4C3],
+    &[0x1F4C4],
+    &[0x1F4C5],
+    &[0x1F4C6],
+    &[0x1F4C7],
+    &[0x1F4C8],
+    &[0x1F4C9],
+    &[0x1F4CA],
+    &[0x1F4CB],
+    &[0x1F4CC],
+    &[0x1F4CD],
+    &[0x1F4CE],
+    &[0x1F4CF],
+    &[0x1F4D0],
+    &[0x1F4D1],
+    &[0x1F4D2],
+    &[0x1F4D3],
+    &[0x1F4D4],
+    &[0x1F4D5],
+    &[0x1F4D6],
+    &[0x1F4D7],
+    &[0x1F4D8],
+    &[0x1F4D9],
+    &[0x1F4DA],
+    &[0x1F4DB],
+    &[0x1F4DC],
+    &[0x1F4DD],
+    &[0x1F4DE],
+    &[0x1F4DF],
+    &[0x1F4E0],
+    &[0x1F4E1],
+    &[0x1F4E2],
+    &[0x1F4E3],
+    &[0x1F4E4],
+    &[0x1F4E5],
+    &[0x1F4E6],
+    &[0x1F4E7],
+    &[0x1F4E8],
+    &[0x1F4E9],
+    &[0x1F4EA],
+    &[0x1F4EB],
+    &[0x1F4EC],
+    &[0x1F4ED],
+    &[0x1F4EE],
+    &[0x1F4EF],
+    &[0x1F4F0],
+    &[0x1F4F1],
+    &[0x1F4F2],
+    &[0x1F4F3],
+    &[0x1F4F4],
+    &[0x1F4F5],
+    &[0x1F4F6],
+    &[0x1F4F7],
+    &[0x1F4F8],
+    &[0x1F4F9],
+    &[0x1F4FA],
+    &[0x1F4FB],
+    &[0x1F4FC],
+    &[0x1F4FD, 0xFE0F],
+    &[0x1F4FF],
+    &[0x1F500],
+    &[0x1F501],
+    &[0x1F502],
+    &[0x1F503],
+    &[0x1F504],
+    &[0x1F505],
+    &[0x1F506],
+    &[0x1F507],
+    &[0x1F508],
+    &[0x1F509],
+    &[0x1F50A],
+    &[0x1F50B],
+    &[0x1F50C],
+    &[0x1F50D],
+    &[0x1F50E],
+    &[0x1F50F],
+    &[0x1F510],
+    &[0x1F511],
+    &[0x1F512],
+    &[0x1F513],
+    &[0x1F514],
+    &[0x1F515],
+    &[0x1F516],
+    &[0x1F517],
+    &[0x1F518],
+    &[0x1F519],
+    &[0x1F51A],
+    &[0x1F51B],
+    &[0x1F51C],
+    &[0x1F51D],
+    &[0x1F51E],
+    &[0x1F51F],
+    &[0x1F520],
+    &[0x1F521],
+    &[0x1F522],
+    &[0x1F523],
+    &[0x1F524],
+    &[0x1F525],
+    &[0x1F526],
+    &[0x1F527],
+    &[0x1F528],
+    &[0x1F529],
+    &[0x1F52A],
+    &[0x1F52B],
+    &[0x1F52C],
+    &[0x1F52D],
+    &[0x1F52E],
+    &[0x1F52F],
+    &[0x1F530],
+    &[0x1F531],
+    &[0x1F532],
+    &[0x1F533],
+    &[0x1F534],
+    &[0x1F535],
+    &[0x1F536],
+    &[0x1F537],
+    &[0x1F538],
+    &[0x1F539],
+    &[0x1F53A],
+    &[0x1F53B],
+    &[0x1F53C],
+    &[0x1F53D],
+    &[0x1F549, 0xFE0F],
+    &[0x1F54A, 0xFE0F],
+    &[0x1F54B],
+    &[0x1F54C],
+    &[0x1F54D],
+    &[0x1F54E],
+    &[0x1F550],
+    &[0x1F551],
+    &[0x1F552],
+    &[0x1F553],
+    &[0x1F554],
+    &[0x1F555],
+    &[0x1F556],
+    &[0x1F557],
+    &[0x1F558],
+    &[0x1F559],
+    &[0x1F55A],
+    &[0x1F55B],
+    &[0x1F55C],
+    &[0x1F55D],
+    &[0x1F55E],
+    &[0x1F55F],
+    &[0x1F560],
+    &[0x1F561],
+    &[0x1F562],
+    &[0x1F563],
+    &[0x1F564],
+    &[0x1F565],
+    &[0x1F566],
+    &[0x1F567],
+    &[0x1F56F, 0xFE0F],
+    &[0x1F570, 0xFE0F],
+    &[0x1F573, 0xFE0F],
+    &[0x1F574, 0xFE0F],
+    &[0x1F575, 0xFE0F],
+    &[0x1F576, 0xFE0F],
+    &[0x1F577, 0xFE0F],
+    &[0x1F578, 0xFE0F],
+    &[0x1F579, 0xFE0F],
+    &[0x1F57A],
+    &[0x1F587, 0xFE0F],
+    &[0x1F58A, 0xFE0F],
+    &[0x1F58B, 0xFE0F],
+    &[0x1F58C, 0xFE0F],
+    &[0x1F58D, 0xFE0F],
+    &[0x1F590, 0xFE0F],
+    &[0x1F595],
+    &[0x1F596],
+    &[0x1F5A4],
+    &[0x1F5A5, 0xFE0F],
+    &[0x1F5A8, 0xFE0F],
+    &[0x1F5B1, 0xFE0F],
+    &[0x1F5B2, 0xFE0F],
+    &[0x1F5BC, 0xFE0F],
+    &[0x1F5C2, 0xFE0F],
+    &[0x1F5C3, 0xFE0F],
+    &[0x1F5C4, 0xFE0F],
+    &[0x1F5D1, 0xFE0F],
+    &[0x1F5D2, 0xFE0F],
+    &[0x1F5D3, 0xFE0F],
+    &[0x1F5DC, 0xFE0F],
+    &[0x1F5DD, 0xFE0F],
+    &[0x1F5DE, 0xFE0F],
+    &[0x1F5E1, 0xFE0F],
+    &[0x1F5E3, 0xFE0F],
+    &[0x1F5E8, 0xFE0F],
+    &[0x1F5EF, 0xFE0F],
+    &[0x1F5F3, 0xFE0F],
+    &[0x1F5FA, 0xFE0F],
+    &[0x1F5FB],
+    &[0x1F5FC],
+    &[0x1F5FD],
+    &[0x1F5FE],
+    &[0x1F5FF],
+    &[0x1F600],
+    &[0x1F601],
+    &[0x1F602],
+    &[0x1F603],
+    &[0x1F604],
+    &[0x1F605],
+    &[0x1F606],
+    &[0x1F607],
+    &[0x1F608],
+    &[0x1F609],
+    &[0x1F60A],
+    &[0x1F60B],
+    &[0x1F60C],
+    &[0x1F60D],
+    &[0x1F60E],
+    &[0x1F60F],
+    &[0x1F610],
+    &[0x1F611],
+    &[0x1F612],
+    &[0x1F613],
+    &[0x1F614],
+    &[0x1F615],
+    &[0x1F616],
+    &[0x1F617],
+    &[0x1F618],
+    &[0x1F619],
+    &[0x1F61A],
+    &[0x1F61B],
+    &[0x1F61C],
+    &[0x1F61D],
+    &[0x1F61E],
+    &[0x1F61F],
+    &[0x1F620],
+    &[0x1F621],
+    &[0x1F622],
+    &[0x1F623],
+    &[0x1F624],
+    &[0x1F625],
+    &[0x1F626],
+    &[0x1F627],
+    &[0x1F628],
+    &[0x1F629],
+    &[0x1F62A],
+    &[0x1F62B],
+    &[0x1F62C],
+    &[0x1F62D],
+    &[0x1F62E],
+    &[0x1F62F],
+    &[0x1F630],
+    &[0x1F631],
+    &[0x1F632],
+    &[0x1F633],
+    &[0x1F634],
+    &[0x1F635],
+    &[0x1F636],
+    &[0x1F637],
+    &[0x1F638],
+    &[0x1F639],
+    &[0x1F63A],
+    &[0x1F63B],
+    &[0x1F63C],
+    &[0x1F63D],
+    &[0x1F63E],
+    &[0x1F63F],
+    &[0x1F640],
+    &[0x1F641],
+    &[0x1F642],
+    &[0x1F643],
+    &[0x1F644],
+    &[0x1F645],
+    &[0x1F646],
+    &[0x1F647],
+    &[0x1F648],
+    &[0x1F649],
+    &[0x1F64A],
+    &[0x1F64B],
+    &[0x1F64C],
+    &[0x1F64D],
+    &[0x1F64E],
+    &[0x1F64F],
+    &[0x1F680],
+    &[0x1F681],
+    &[0x1F682],
+    &[0x1F683],
+    &[0x1F684],
+    &[0x1F685],
+    &[0x1F686],
+    &[0x1F687],
+    &[0x1F688],
+    &[0x1F689],
+    &[0x1F68A],
+    &[0x1F68B],
+    &[0x1F68C],
+    &[0x1F68D],
+    &[0x1F68E],
+    &[0x1F68F],
+    &[0x1F690],
+    &[0x1F691],
+    &[0x1F692],
+    &[0x1F693],
+    &[0x1F694],
+    &[0x1F695],
+    &[0x1F696],
+    &[0x1F697],
+    &[0x1F698],
+    &[0x1F699],
+    &[0x1F69A],
+    &[0x1F69B],
+    &[0x1F69C],
+    &[0x1F69D],
+    &[0x1F69E],
+    &[0x1F69F],
+    &[0x1F6A0],
+    &[0x1F6A1],
+    &[0x1F6A2],
+    &[0x1F6A3],
+    &[0x1F6A4],
+    &[0x1F6A5],
+    &[0x1F6A6],
+    &[0x1F6A7],
+    &[0x1F6A8],
+    &[0x1F6A9],
+    &[0x1F6AA],
+    &[0x1F6AB],
+    &[0x1F6AC],
+    &[0x1F6AD],
+    &[0x1F6AE],
+    &[0x1F6AF],
+    &[0x1F6B0],
+    &[0x1F6B1],
+    &[0x1F6B2],
+    &[0x1F6B3],
+    &[0x1F6B4],
+    &[0x1F6B5],
+    &[0x1F6B6],
+    &[0x1F6B7],
+    &[0x1F6B8],
+    &[0x1F6B9],
+    &[0x1F6BA],
+    &[0x1F6BB],
+    &[0x1F6BC],
+    &[0x1F6BD],
+    &[0x1F6BE],
+    &[0x1F6BF],
+    &[0x1F6C0],
+    &[0x1F6C1],
+    &[0x1F6C2],
+    &[0x1F6C3],
+    &[0x1F6C4],
+    &[0x1F6C5],
+    &[0x1F6CB, 0xFE0F],
+    &[0x1F6CC],
+    &[0x1F6CD, 0xFE0F],
+    &[0x1F6CE, 0xFE0F],
+    &[0x1F6CF, 0xFE0F],
+    &[0x1F6D0],
+    &[0x1F6D1],
+    &[0x1F6D2],
+    &[0x1F6D5],
+    &[0x1F6D6],
+    &[0x1F6D7],
+    &[0x1F6E0, 0xFE0F],
+    &[0x1F6E1, 0xFE0F],
+    &[0x1F6E2, 0xFE0F],
+    &[0x1F6E3, 0xFE0F],
+    &[0x1F6E4, 0xFE0F],
+    &[0x1F6E5, 0xFE0F],
+    &[0x1F6E9, 0xFE0F],
+    &[0x1F6EB],
+    &[0x1F6EC],
+    &[0x1F6F0, 0xFE0F],
+    &[0x1F6F3, 0xFE0F],
+    &[0x1F6F4],
+    &[0x1F6F5],
+    &[0x1F6F6],
+    &[0x1F6F7],
+    &[0x1F6F8],
+    &[0x1F6F9],
+    &[0x1F6FA],
+    &[0x1F6FB],
+    &[0x1F6FC],
+    &[0x1F7E0],
+    &[0x1F7E1],
+    &[0x1F7E2],
+    &[0x1F7E3],
+    &[0x1F7E4],
+    &[0x1F7E5],
+    &[0x1F7E6],
+    &[0x1F7E7],
+    &[0x1F7E8],
+    &[0x1F7E9],
+    &[0x1F7EA],
+    &[0x1F7EB],
+    &[0x1F90C],
+    &[0x1F90D],
+    &[0x1F90E],
+    &[0x1F90F],
+    &[0x1F910],
+    &[0x1F911],
+    &[0x1F912],
+    &[0x1F913],
+    &[0x1F914],
+    &[0x1F915],
+    &[0x1F916],
+    &[0x1F917],
+    &[0x1F918],
+    &[0x1F919],
+    &[0x1F91A],
+    &[0x1F91B],
+    &[0x1F91C],
+    &[0x1F91D],
+    &[0x1F91E],
+    &[0x1F91F],
+    &[0x1F920],
+    &[0x1F921],
+    &[0x1F922],
+    &[0x1F923],
+    &[0x1F924],
+    &[0x1F925],
+    &[0x1F926],
+    &[0x1F927],
+    &[0x1F928],
+    &[0x1F929],
+    &[0x1F92A],
+    &[0x1F92B],
+    &[0x1F92C],
+    &[0x1F92D],
+    &[0x1F92E],
+    &[0x1F92F],
+    &[0x1F930],
+    &[0x1F931],
+    &[0x1F932],
+    &[0x1F933],
+    &[0x1F934],
+    &[0x1F935],
+    &[0x1F936],
+    &[0x1F937],
+    &[0x1F938],
+    &[0x1F939],
+    &[0x1F93A],
+    &[0x1F93C],
+    &[0x1F93D],
+    &[0x1F93E],
+    &[0x1F93F],
+    &[0x1F940],
+    &[0x1F941],
+    &[0x1F942],
+    &[0x1F943],
+    &[0x1F944],
+    &[0x1F945],
+    &[0x1F947],
+    &[0x1F948],
+    &[0x1F949],
+    &[0x1F94A],
+    &[0x1F94B],
+    &[0x1F94C],
+    &[0x1F94D],
+    &[0x1F94E],
+    &[0x1F94F],
+    &[0x1F950],
+    &[0x1F951],
+    &[0x1F952],
+    &[0x1F953],
+    &[0x1F954],
+    &[0x1F955],
+    &[0x1F956],
+    &[0x1F957],
+    &[0x1F958],
+    &[0x1F959],
+    &[0x1F95A],
+    &[0x1F95B],
+    &[0x1F95C],
+    &[0x1F95D],
+    &[0x1F95E],
+    &[0x1F95F],
+    &[0x1F960],
+    &[0x1F961],
+    &[0x1F962],
+    &[0x1F963],
+    &[0x1F964],
+    &[0x1F965],
+    &[0x1F966],
+    &[0x1F967],
+    &[0x1F968],
+    &[0x1F969],
+    &[0x1F96A],
+    &[0x1F96B],
+    &[0x1F96C],
+    &[0x1F96D],
+    &[0x1F96E],
+    &[0x1F96F],
+    &[0x1F970],
+    &[0x1F971],
+    &[0x1F972],
+    &[0x1F973],
+    &[0x1F974],
+    &[0x1F975],
+    &[0x1F976],
+    &[0x1F977],
+    &[0x1F978],
+    &[0x1F97A],
+    &[0x1F97B],
+    &[0x1F97C],
+    &[0x1F97D],
+    &[0x1F97E],
+    &[0x1F97F],
+    &[0x1F980],
+    &[0x1F981],
+    &[0x1F982],
+    &[0x1F983],
+    &[0x1F984],
+    &[0x1F985],
+    &[0x1F986],
+    &[0x1F987],
+    &[0x1F988],
+    &[0x1F989],
+    &[0x1F98A],
+    &[0x1F98B],
+    &[0x1F98C],
+    &[0x1F98D],
+    &[0x1F98E],
+    &[0x1F98F],
+    &[0x1F990],
+    &[0x1F991],
+    &[0x1F992],
+    &[0x1F993],
+    &[0x1F994],
+    &[0x1F995],
+    &[0x1F996],
+    &[0x1F997],
+    &[0x1F998],
+    &[0x1F999],
+    &[0x1F99A],
+    &[0x1F99B],
+    &[0x1F99C],
+    &[0x1F99D],
+    &[0x1F99E],
+    &[0x1F99F],
+    &[0x1F9A0],
+    &[0x1F9A1],
+    &[0x1F9A2],
+    &[0x1F9A3],
+    &[0x1F9A4],
+    &[0x1F9A5],
+    &[0x1F9A6],
+    &[0x1F9A7],
+    &[0x1F9A8],
+    &[0x1F9A9],
+    &[0x1F9AA],
+    &[0x1F9AB],
+    &[0x1F9AC],
+    &[0x1F9AD],
+    &[0x1F9AE],
+    &[0x1F9AF],
+    &[0x1F9B0],
+    &[0x1F9B1],
+    &[0x1F9B2],
+    &[0x1F9B3],
+    &[0x1F9B4],
+    &[0x1F9B5],
+    &[0x1F9B6],
+    &[0x1F9B7],
+    &[0x1F9B8],
+    &[0x1F9B9],
+    &[0x1F9BA],
+    &[0x1F9BB],
+    &[0x1F9BC],
+    &[0x1F9BD],
+    &[0x1F9BE],
+    &[0x1F9BF],
+    &[0x1F9C0],
+    &[0x1F9C1],
+    &[0x1F9C2],
+    &[0x1F9C3],
+    &[0x1F9C4],
+    &[0x1F9C5],
+    &[0x1F9C6],
+    &[0x1F9C7],
+    &[0x1F9C8],
+    &[0x1F9C9],
+    &[0x1F9CA],
+    &[0x1F9CB],
+    &[0x1F9CD],
+    &[0x1F9CE],
+    &[0x1F9CF],
+    &[0x1F9D0],
+    &[0x1F9D1],
+    &[0x1F9D2],
+    &[0x1F9D3],
+    &[0x1F9D4],
+    &[0x1F9D5],
+    &[0x1F9D6],
+    &[0x1F9D7],
+    &[0x1F9D8],
+    &[0x1F9D9],
+    &[0x1F9DA],
+    &[0x1F9DB],
+    &[0x1F9DC],
+    &[0x1F9DD],
+    &[0x1F9DE],
+    &[0x1F9DF],
+    &[0x1F9E0],
+    &[0x1F9E1],
+    &[0x1F9E2],
+    &[0x1F9E3],
+    &[0x1F9E4],
+    &[0x1F9E5],
+    &[0x1F9E6],
+    &[0x1F9E7],
+    &[0x1F9E8],
+    &[0x1F9E9],
+    &[0x1F9EA],
+    &[0x1F9EB],
+    &[0x1F9EC],
+    &[0x1F9ED],
+    &[0x1F9EE],
+    &[0x1F9EF],
+    &[0x1F9F0],
+    &[0x1F9F1],
+    &[0x1F9F2],
+    &[0x1F9F3],
+    &[0x1F9F4],
+    &[0x1F9F5],
+    &[0x1F9F6],
+    &[0x1F9F7],
+    &[0x1F9F8],
+    &[0x1F9F9],
+    &[0x1F9FA],
+    &[0x1F9FB],
+    &[0x1F9FC],
+    &[0x1F9FD],
+    &[0x1F9FE],
+    &[0x1F9FF],
+    &[0x1FA70],
+    &[0x1FA71],
+    &[0x1FA72],
+    &[0x1FA73],
+    &[0x1FA74],
+    &[0x1FA78],
+    &[0x1FA79],
+    &[0x1FA7A],
+    &[0x1FA80],
+    &[0x1FA81],
+    &[0x1FA82],
+    &[0x1FA83],
+    &[0x1FA84],
+    &[0x1FA85],
+    &[0x1FA86],
+    &[0x1FA90],
+    &[0x1FA91],
+    &[0x1FA92],
+    &[0x1FA93],
+    &[0x1FA94],
+    &[0x1FA95],
+    &[0x1FA96],
+    &[0x1FA97],
+    &[0x1FA98],
+    &[0x1FA99],
+    &[0x1FA9A],
+    &[0x1FA9B],
+    &[0x1FA9C],
+    &[0x1FA9D],
+    &[0x1FA9E],
+    &[0x1FA9F],
+    &[0x1FAA0],
+    &[0x1FAA1],
+    &[0x1FAA2],
+    &[0x1FAA3],
+    &[0x1FAA4],
+    &[0x1FAA5],
+    &[0x1FAA6],
+    &[0x1FAA7],
+    &[0x1FAA8],
+    &[0x1FAB0],
+    &[0x1FAB1],
+    &[0x1FAB2],
+    &[0x1FAB3],
+    &[0x1FAB4],
+    &[0x1FAB5],
+    &[0x1FAB6],
+    &[0x1FAC0],
+    &[0x1FAC1],
+    &[0x1FAC2],
+    &[0x1FAD0],
+    &[0x1FAD1],
+    &[0x1FAD2],
+    &[0x1FAD3],
+    &[0x1FAD4],
+    &[0x1FAD5],
+    &[0x1FAD6],
+];
+
+pub(crate) static EMOJI_KEYCAP_SEQUENCE: [&[u32]; 12] = [
+    &[0x23, 0xFE0F, 0x20E3],
+    &[0x2A, 0xFE0F, 0x20E3],
+    &[0x30, 0xFE0F, 0x20E3],
+    &[0x31, 0xFE0F, 0x20E3],
+    &[0x32, 0xFE0F, 0x20E3],
+    &[0x33, 0xFE0F, 0x20E3],
+    &[0x34, 0xFE0F, 0x20E3],
+    &[0x35, 0xFE0F, 0x20E3],
+    &[0x36, 0xFE0F, 0x20E3],
+    &[0x37, 0xFE0F, 0x20E3],
+    &[0x38, 0xFE0F, 0x20E3],
+    &[0x39, 0xFE0F, 0x20E3],
+];
+
+pub(crate) static RGI_EMOJI_FLAG_SEQUENCE: [&[u32]; 258] = [
+    &[0x1F1E6, 0x1F1E8],
+    &[0x1F1E6, 0x1F1E9],
+    &[0x1F1E6, 0x1F1EA],
+    &[0x1F1E6, 0x1F1EB],
+    &[0x1F1E6, 0x1F1EC],
+    &[0x1F1E6, 0x1F1EE],
+    &[0x1F1E6, 0x1F1F1],
+    &[0x1F1E6, 0x1F1F2],
+    &[0x1F1E6, 0x1F1F4],
+    &[0x1F1E6, 0x1F1F6],
+    &[0x1F1E6, 0x1F1F7],
+    &[0x1F1E6, 0x1F1F8],
+    &[0x1F1E6, 0x1F1F9],
+    &[0x1F1E6, 0x1F1FA],
+    &[0x1F1E6, 0x1F1FC],
+    &[0x1F1E6, 0x1F1FD],
+    &[0x1F1E6, 0x1F1FF],
+    &[0x1F1E7, 0x1F1E6],
+    &[0x1F1E7, 0x1F1E7],
+    &[0x1F1E7, 0x1F1E9],
+    &[0x1F1E7, 0x1F1EA],
+    &[0x1F1E7, 0x1F1EB],
+    &[0x1F1E7, 0x1F1EC],
+    &[0x1F1E7, 0x1F1ED],
+    &[0x1F1E7, 0x1F1EE],
+    &[0x1F1E7, 0x1F1EF],
+    &[0x1F1E7, 0x1F1F1],
+    &[0x1F1E7, 0x1F1F2],
+    &[0x1F1E7, 0x1F1F3],
+    &[0x1F1E7, 0x1F1F4],
+    &[0x1F1E7, 0x1F1F6],
+    &[0x1F1E7, 0x1F1F7],
+    &[0x1F1E7, 0x1F1F8],
+    &[0x1F1E7, 0x1F1F9],
+    &[0x1F1E7, 0x1F1FB],
+    &[0x1F1E7, 0x1F1FC],
+    &[0x1F1E7, 0x1F1FE],
+    &[0x1F1E7, 0x1F1FF],
+    &[0x1F1E8, 0x1F1E6],
+    &[0x1F1E8, 0x1F1E8],
+    &[0x1F1E8, 0x1F1E9],
+    &[0x1F1E8, 0x1F1EB],
+    &[0x1F1E8, 0x1F1EC],
+    &[0x1F1E8, 0x1F1ED],
+    &[0x1F1E8, 0x1F1EE],
+    &[0x1F1E8, 0x1F1F0],
+    &[0x1F1E8, 0x1F1F1],
+    &[0x1F1E8, 0x1F1F2],
+    &[0x1F1E8, 0x1F1F3],
+    &[0x1F1E8, 0x1F1F4],
+    &[0x1F1E8, 0x1F1F5],
+    &[0x1F1E8, 0x1F1F7],
+    &[0x1F1E8, 0x1F1FA],
+    &[0x1F1E8, 0x1F1FB],
+    &[0x1F1E8, 0x1F1FC],
+    &[0x1F1E8, 0x1F1FD],
+    &[0x1F1E8, 0x1F1FE],
+    &[0x1F1E8, 0x1F1FF],
+    &[0x1F1E9, 0x1F1EA],
+    &[0x1F1E9, 0x1F1EC],
+    &[0x1F1E9, 0x1F1EF],
+    &[0x1F1E9, 0x1F1F0],
+    &[0x1F1E9, 0x1F1F2],
+    &[0x1F1E9, 0x1F1F4],
+    &[0x1F1E9, 0x1F1FF],
+    &[0x1F1EA, 0x1F1E6],
+    &[0x1F1EA, 0x1F1E8],
+    &[0x1F1EA, 0x1F1EA],
+    &[0x1F1EA, 0x1F1EC],
+    &[0x1F1EA, 0x1F1ED],
+    &[0x1F1EA, 0x1F1F7],
+    &[0x1F1EA, 0x1F1F8],
+    &[0x1F1EA, 0x1F1F9],
+    &[0x1F1EA, 0x1F1FA],
+    &[0x1F1EB, 0x1F1EE],
+    &[0x1F1EB, 0x1F1EF],
+    &[0x1F1EB, 0x1F1F0],
+    &[0x1F1EB, 0x1F1F2],
+    &[0x1F1EB, 0x1F1F4],
+    &[0x1F1EB, 0x1F1F7],
+    &[0x1F1EC, 0x1F1E6],
+    &[0x1F1EC, 0x1F1E7],
+    &[0x1F1EC, 0x1F1E9],
+    &[0x1F1EC, 0x1F1EA],
+    &[0x1F1EC, 0x1F1EB],
+    &[0x1F1EC, 0x1F1EC],
+    &[0x1F1EC, 0x1F1ED],
+    &[0x1F1EC, 0x1F1EE],
+    &[0x1F1EC, 0x1F1F1],
+    &[0x1F1EC, 0x1F1F2],
+    &[0x1F1EC, 0x1F1F3],
+    &[0x1F1EC, 0x1F1F5],
+    &[0x1F1EC, 0x1F1F6],
+    &[0x1F1EC, 0x1F1F7],
+    &[0x1F1EC, 0x1F1F8],
+    &[0x1F1EC, 0x1F1F9],
+    &[0x1F1EC, 0x1F1FA],
+    &[0x1F1EC, 0x1F1FC],
+    &[0x1F1EC, 0x1F1FE],
+    &[0x1F1ED, 0x1F1F0],
+    &[0x1F1ED, 0x1F1F2],
+    &[0x1F1ED, 0x1F1F3],
+    &[0x1F1ED, 0x1F1F7],
+    &[0x1F1ED, 0x1F1F9],
+    &[0x1F1ED, 0x1F1FA],
+    &[0x1F1EE, 0x1F1E8],
+    &[0x1F1EE, 0x1F1E9],
+    &[0x1F1EE, 0x1F1EA],
+    &[0x1F1EE, 0x1F1F1],
+    &[0x1F1EE, 0x1F1F2],
+    &[0x1F1EE, 0x1F1F3],
+    &[0x1F1EE, 0x1F1F4],
+    &[0x1F1EE, 0x1F1F6],
+    &[0x1F1EE, 0x1F1F7],
+    &[0x1F1EE, 0x1F1F8],
+    &[0x1F1EE, 0x1F1F9],
+    &[0x1F1EF, 0x1F1EA],
+    &[0x1F1EF, 0x1F1F2],
+    &[0x1F1EF, 0x1F1F4],
+    &[0x1F1EF, 0x1F1F5],
+    &[0x1F1F0, 0x1F1EA],
+    &[0x1F1F0, 0x1F1EC],
+    &[0x1F1F0, 0x1F1ED],
+    &[0x1F1F0, 0x1F1EE],
+    &[0x1F1F0, 0x1F1F2],
+    &[0x1F1F0, 0x1F1F3],
+    &[0x1F1F0, 0x1F1F5],
+    &[0x1F1F0, 0x1F1F7],
+    &[0x1F1F0, 0x1F1FC],
+    &[0x1F1F0, 0x1F1FE],
+    &[0x1F1F0, 0x1F1FF],
+    &[0x1F1F1, 0x1F1E6],
+    &[0x1F1F1, 0x1F1E7],
+    &[0x1F1F1, 0x1F1E8],
+    &[0x1F1F1, 0x1F1EE],
+    &[0x1F1F1, 0x1F1F0],
+    &[0x1F1F1, 0x1F1F7],
+    &[0x1F1F1, 0x1F1F8],
+    &[0x1F1F1, 0x1F1F9],
+    &[0x1F1F1, 0x1F1FA],
+    &[0x1F1F1, 0x1F1FB],
+    &[0x1F1F1, 0x1F1FE],
+    &[0x1F1F2, 0x1F1E6],
+    &[0x1F1F2, 0x1F1E8],
+    &[0x1F1F2, 0x1F1E9],
+    &[0x1F1F2, 0x1F1EA],
+    &[0x1F1F2, 0x1F1EB],
+    &[0x1F1F2, 0x1F1EC],
+    &[0x1F1F2, 0x1F1ED],
+    &[0x1F1F2, 0x1F1F0],
+    &[0x1F1F2, 0x1F1F1],
+    &[0x1F1F2, 0x1F1F2],
+    &[0x1F1F2, 0x1F1F3],
+    &[0x1F1F2, 0x1F1F4],
+    &[0x1F1F2, 0x1F1F5],
+    &[0x1F1F2, 0x1F1F6],
+    &[0x1F1F2, 0x1F1F7],
+    &[0x1F1F2, 0x1F1F8],
+    &[0x1F1F2, 0x1F1F9],
+    &[0x1F1F2, 0x1F1FA],
+    &[0x1F1F2, 0x1F1FB],
+    &[0x1F1F2, 0x1F1FC],
+    &[0x1F1F2, 0x1F1FD],
+    &[0x1F1F2, 0x1F1FE],
+    &[0x1F1F2, 0x1F1FF],
+    &[0x1F1F3, 0x1F1E6],
+    &[0x1F1F3, 0x1F1E8],
+    &[0x1F1F3, 0x1F1EA],
+    &[0x1F1F3, 0x1F1EB],
+    &[0x1F1F3, 0x1F1EC],
+    &[0x1F1F3, 0x1F1EE],
+    &[0x1F1F3, 0x1F1F1],
+    &[0x1F1F3, 0x1F1F4],
+    &[0x1F1F3, 0x1F1F5],
+    &[0x1F1F3, 0x1F1F7],
+    &[0x1F1F3, 0x1F1FA],
+    &[0x1F1F3, 0x1F1FF],
+    &[0x1F1F4, 0x1F1F2],
+    &[0x1F1F5, 0x1F1E6],
+    &[0x1F1F5, 0x1F1EA],
+    &[0x1F1F5, 0x1F1EB],
+    &[0x1F1F5, 0x1F1EC],
+    &[0x1F1F5, 0x1F1ED],
+    &[0x1F1F5, 0x1F1F0],
+    &[0x1F1F5, 0x1F1F1],
+    &[0x1F1F5, 0x1F1F2],
+    &[0x1F1F5, 0x1F1F3],
+    &[0x1F1F5, 0x1F1F7],
+    &[0x1F1F5, 0x1F1F8],
+    &[0x1F1F5, 0x1F1F9],
+    &[0x1F1F5, 0x1F1FC],
+    &[0x1F1F5, 0x1F1FE],
+    &[0x1F1F6, 0x1F1E6],
+    &[0x1F1F7, 0x1F1EA],
+    &[0x1F1F7, 0x1F1F4],
+    &[0x1F1F7, 0x1F1F8],
+    &[0x1F1F7, 0x1F1FA],
+    &[0x1F1F7, 0x1F1FC],
+    &[0x1F1F8, 0x1F1E6],
+    &[0x1F1F8, 0x1F1E7],
+    &[0x1F1F8, 0x1F1E8],
+    &[0x1F1F8, 0x1F1E9],
+    &[0x1F1F8, 0x1F1EA],
+    &[0x1F1F8, 0x1F1EC],
+    &[0x1F1F8, 0x1F1ED],
+    &[0x1F1F8, 0x1F1EE],
+    &[0x1F1F8, 0x1F1EF],
+    &[0x1F1F8, 0x1F1F0],
+    &[0x1F1F8, 0x1F1F1],
+    &[0x1F1F8, 0x1F1F2],
+    &[0x1F1F8, 0x1F1F3],
+    &[0x1F1F8, 0x1F1F4],
+    &[0x1F1F8, 0x1F1F7],
+    &[0x1F1F8, 0x1F1F8],
+    &[0x1F1F8, 0x1F1F9],
+    &[0x1F1F8, 0x1F1FB],
+    &[0x1F1F8, 0x1F1FD],
+    &[0x1F1F8, 0x1F1FE],
+    &[0x1F1F8, 0x1F1FF],
+    &[0x1F1F9, 0x1F1E6],
+    &[0x1F1F9, 0x1F1E8],
+    &[0x1F1F9, 0x1F1E9],
+    &[0x1F1F9, 0x1F1EB],
+    &[0x1F1F9, 0x1F1EC],
+    &[0x1F1F9, 0x1F1ED],
+    &[0x1F1F9, 0x1F1EF],
+    &[0x1F1F9, 0x1F1F0],
+    &[0x1F1F9, 0x1F1F1],
+    &[0x1F1F9, 0x1F1F2],
+    &[0x1F1F9, 0x1F1F3],
+    &[0x1F1F9, 0x1F1F4],
+    &[0x1F1F9, 0x1F1F7],
+    &[0x1F1F9, 0x1F1F9],
+    &[0x1F1F9, 0x1F1FB],
+    &[0x1F1F9, 0x1F1FC],
+    &[0x1F1F9, 0x1F1FF],
+    &[0x1F1FA, 0x1F1E6],
+    &[0x1F1FA, 0x1F1EC],
+    &[0x1F1FA, 0x1F1F2],
+    &[0x1F1FA, 0x1F1F3],
+    &[0x1F1FA, 0x1F1F8],
+    &[0x1F1FA, 0x1F1FE],
+    &[0x1F1FA, 0x1F1FF],
+    &[0x1F1FB, 0x1F1E6],
+    &[0x1F1FB, 0x1F1E8],
+    &[0x1F1FB, 0x1F1EA],
+    &[0x1F1FB, 0x1F1EC],
+    &[0x1F1FB, 0x1F1EE],
+    &[0x1F1FB, 0x1F1F3],
+    &[0x1F1FB, 0x1F1FA],
+    &[0x1F1FC, 0x1F1EB],
+    &[0x1F1FC, 0x1F1F8],
+    &[0x1F1FD, 0x1F1F0],
+    &[0x1F1FE, 0x1F1EA],
+    &[0x1F1FE, 0x1F1F9],
+    &[0x1F1FF, 0x1F1E6],
+    &[0x1F1FF, 0x1F1F2],
+    &[0x1F1FF, 0x1F1FC],
+];
+
+pub(crate) static RGI_EMOJI_MODIFIER_SEQUENCE: [&[u32]; 580] = [
+    &[0x261D, 0x1F3FB],
+    &[0x261D, 0x1F3FC],
+    &[0x261D, 0x1F3FD],
+    &[0x261D, 0x1F3FE],
+    &[0x261D, 0x1F3FF],
+    &[0x26F9, 0x1F3FB],
+    &[0x26F9, 0x1F3FC],
+    &[0x26F9, 0x1F3FD],
+    &[0x26F9, 0x1F3FE],
+    &[0x26F9, 0x1F3FF],
+    &[0x270A, 0x1F3FB],
+    &[0x270A, 0x1F3FC],
+    &[0x270A, 0x1F3FD],
+    &[0x270A, 0x1F3FE],
+    &[0x270A, 0x1F3FF],
+    &[0x270B, 0x1F3FB],
+    &[0x270B, 0x1F3FC],
+    &[0x270B, 0x1F3FD],
+    &[0x270B, 0x1F3FE],
+    &[0x270B, 0x1F3FF],
+    &[0x270C, 0x1F3FB],
+    &[0x270C, 0x1F3FC],
+    &[0x270C, 0x1F3FD],
+    &[0x270C, 0x1F3FE],
+    &[0x270C, 0x1F3FF],
+    &[0x270D, 0x1F3FB],
+    &[0x270D, 0x1F3FC],
+    &[0x270D, 0x1F3FD],
+    &[0x270D, 0x1F3FE],
+    &[0x270D, 0x1F3FF],
+    &[0x1F385, 0x1F3FB],
+    &[0x1F385, 0x1F3FC],
+    &[0x1F385, 0x1F3FD],
+    &[0x1F385, 0x1F3FE],
+    &[0x1F385, 0x1F3FF],
+    &[0x1F3C2, 0x1F3FB],
+    &[0x1F3C2, 0x1F3FC],
+    &[0x1F3C2, 0x1F3FD],
+    &[0x1F3C2, 0x1F3FE],
+    &[0x1F3C2, 0x1F3FF],
+    &[0x1F3C3, 0x1F3FB],
+    &[0x1F3C3, 0x1F3FC],
+    &[0x1F3C3, 0x1F3FD],
+    &[0x1F3C3, 0x1F3FE],
+    &[0x1F3C3, 0x1F3FF],
+    &[0x1F3C4, 0x1F3FB],
+    &[0x1F3C4, 0x1F3FC],
+    &[0x1F3C4, 0x1F3FD],
+    &[0x1F3C4, 0x1F3FE],
+    &[0x1F3C4, 0x1F3FF],
+    &[0x1F3C7, 0x1F3FB],
+    &[0x1F3C7, 0x1F3FC],
+    &[0x1F3C7, 0x1F3FD],
+    &[0x1F3C7, 0x1F3FE],
+    &[0x1F3C7, 0x1F3FF],
+    &[0x1F3CA, 0x1F3FB],
+    &[0x1F3CA, 0x1F3FC],
+    &[0x1F3CA, 0x1F3FD],
+    &[0x1F3CA, 0x1F3FE],
+    &[0x1F3CA, 0x1F3FF],
+    &[0x1F3CB, 0x1F3FB],
+    &[0x1F3CB, 0x1F3FC],
+    &[0x1F3CB, 0x1F3FD],
+    &[0x1F3CB, 0x1F3FE],
+    &[0x1F3CB, 0x1F3FF],
+    &[0x1F3CC, 0x1F3FB],
+    &[0x1F3CC, 0x1F3FC],
+    &[0x1F3CC, 0x1F3FD],
+    &[0x1F3CC, 0x1F3FE],
+    &[0x1F3CC, 0x1F3FF],
+    &[0x1F442, 0x1F3FB],
+    &[0x1F442, 0x1F3FC],
+    &[0x1F442, 0x1F3FD],
+    &[0x1F442, 0x1F3FE],
+    &[0x1F442, 0x1F3FF],
+    &[0x1F443, 0x1F3FB],
+    &[0x1F443, 0x1F3FC],
+    &[0x1F443, 0x1F3FD],
+    &[0x1F443, 0x1F3FE],
+    &[0x1F443, 0x1F3FF],
+    &[0x1F446, 0x1F3FB],
+    &[0x1F446, 0x1F3FC],
+    &[0x1F446, 0x1F3FD],
+    &[0x1F446, 0x1F3FE],
+    &[0x1F446, 0x1F3FF],
+    &[0x1F447, 0x1F3FB],
+    &[0x1F447, 0x1F3FC],
+    &[0x1F447, 0x1F3FD],
+    &[0x1F447, 0x1F3FE],
+    &[0x1F447, 0x1F3FF],
+    &[0x1F448, 0x1F3FB],
+    &[0x1F448, 0x1F3FC],
+    &[0x1F448, 0x1F3FD],
+    &[0x1F448, 0x1F3FE],
+    &[0x1F448, 0x1F3FF],
+    &[0x1F449, 0x1F3FB],
+    &[0x1F449, 0x1F3FC],
+    &[0x1F449, 0x1F3FD],
+    &[0x1F449, 0x1F3FE],
+    &[0x1F449, 0x1F3FF],
+    &[0x1F44A, 0x1F3FB],
+    &[0x1F44A, 0x1F3FC],
+    &[0x1F44A, 0x1F3FD],
+    &[0x1F44A, 0x1F3FE],
+    &[0x1F44A, 0x1F3FF],
+    &[0x1F44B, 0x1F3FB],
+    &[0x1F44B, 0x1F3FC],
+    &[0x1F44B, 0x1F3FD],
+    &[0x1F44B, 0x1F3FE],
+    &[0x1F44B, 0x1F3FF],
+    &[0x1F44C, 0x1F3FB],
+    &[0x1F44C, 0x1F3FC],
+    &[0x1F44C, 0x1F3FD],
+    &[0x1F44C, 0x1F3FE],
+    &[0x1F44C, 0x1F3FF],
+    &[0x1F44D, 0x1F3FB],
+    &[0x1F44D, 0x1F3FC],
+    &[0x1F44D, 0x1F3FD],
+    &[0x1F44D, 0x1F3FE],
+    &[0x1F44D, 0x1F3FF],
+    &[0x1F44E, 0x1F3FB],
+    &[0x1F44E, 0x1F3FC],
+    &[0x1F44E, 0x1F3FD],
+    &[0x1F44E, 0x1F3FE],
+    &[0x1F44E, 0x1F3FF],
+    &[0x1F44F, 0x1F3FB],
+    &[0x1F44F, 0x1F3FC],
+    &[0x1F44F, 0x1F3FD],
+    &[0x1F44F, 0x1F3FE],
+    &[0x1F44F, 0x1F3FF],
+    &[0x1F450, 0x1F3FB],
+    &[0x1F450, 0x1F3FC],
+    &[0x1F450, 0x1F3FD],
+    &[0x1F450, 0x1F3FE],
+    &[0x1F450, 0x1F3FF],
+    &[0x1F466, 0x1F3FB],
+    &[0x1F466, 0x1F3FC],
+    &[0x1F466, 0x1F3FD],
+    &[0x1F466, 0x1F3FE],
+    &[0x1F466, 0x1F3FF],
+    &[0x1F467, 0x1F3FB],
+    &[0x1F467, 0x1F3FC],
+    &[0x1F467, 0x1F3FD],
+    &[0x1F467, 0x1F3FE],
+    &[0x1F467, 0x1F3FF],
+    &[0x1F468, 0x1F3FB],
+    &[0x1F468, 0x1F3FC],
+    &[0x1F468, 0x1F3FD],
+    &[0x1F468, 0x1F3FE],
+    &[0x1F468, 0x1F3FF],
+    &[0x1F469, 0x1F3FB],
+    &[0x1F469, 0x1F3FC],
+    &[0x1F469, 0x1F3FD],
+    &[0x1F469, 0x1F3FE],
+    &[0x1F469, 0x1F3FF],
+    &[0x1F46B, 0x1F3FB],
+    &[0x1F46B, 0x1F3FC],
+    &[0x1F46B, 0x1F3FD],
+    &[0x1F46B, 0x1F3FE],
+    &[0x1F46B, 0x1F3FF],
+    &[0x1F46C, 0x1F3FB],
+    &[0x1F46C, 0x1F3FC],
+    &[0x1F46C, 0x1F3FD],
+    &[0x1F46C, 0x1F3FE],
+    &[0x1F46C, 0x1F3FF],
+    &[0x1F46D, 0x1F3FB],
+    &[0x1F46D, 0x1F3FC],
+    &[0x1F46D, 0x1F3FD],
+    &[0x1F46D, 0x1F3FE],
+    &[0x1F46D, 0x1F3FF],
+    &[0x1F46E, 0x1F3FB],
+    &[0x1F46E, 0x1F3FC],
+    &[0x1F46E, 0x1F3FD],
+    &[0x1F46E, 0x1F3FE],
+    &[0x1F46E, 0x1F3FF],
+    &[0x1F470, 0x1F3FB],
+    &[0x1F470, 0x1F3FC],
+    &[0x1F470, 0x1F3FD],
+    &[0x1F470, 0x1F3FE],
+    &[0x1F470, 0x1F3FF],
+    &[0x1F471, 0x1F3FB],
+    &[0x1F471, 0x1F3FC],
+    &[0x1F471, 0x1F3FD],
+    &[0x1F471, 0x1F3FE],
+    &[0x1F471, 0x1F3FF],
+    &[0x1F472, 0x1F3FB],
+    &[0x1F472, 0x1F3FC],
+    &[0x1F472, 0x1F3FD],
+    &[0x1F472, 0x1F3FE],
+    &[0x1F472, 0x1F3FF],
+    &[0x1F473, 0x1F3FB],
+    &[0x1F473, 0x1F3FC],
+    &[0x1F473, 0x1F3FD],
+    &[0x1F473, 0x1F3FE],
+    &[0x1F473, 0x1F3FF],
+    &[0x1F474, 0x1F3FB],
+    &[0x1F474, 0x1F3FC],
+    &[0x1F474, 0x1F3FD],
+    &[0x1F474, 0x1F3FE],
+    &[0x1F474, 0x1F3FF],
+    &[0x1F475, 0x1F3FB],
+    &[0x1F475, 0x1F3FC],
+    &[0x1F475, 0x1F3FD],
+    &[0x1F475, 0x1F3FE],
+    &[0x1F475, 0x1F3FF],
+    &[0x1F476, 0x1F3FB],
+    &[0x1F476, 0x1F3FC],
+    &[0x1F476, 0x1F3FD],
+    &[0x1F476, 0x1F3FE],
+    &[0x1F476, 0x1F3FF],
+    &[0x1F477, 0x1F3FB],
+    &[0x1F477, 0x1F3FC],
+    &[0x1F477, 0x1F3FD],
+    &[0x1F477, 0x1F3FE],
+    &[0x1F477, 0x1F3FF],
+    &[0x1F478, 0x1F3FB],
+    &[0x1F478, 0x1F3FC],
+    &[0x1F478, 0x1F3FD],
+    &[0x1F478, 0x1F3FE],
+    &[0x1F478, 0x1F3FF],
+    &[0x1F47C, 0x1F3FB],
+    &[0x1F47C, 0x1F3FC],
+    &[0x1F47C, 0x1F3FD],
+    &[0x1F47C, 0x1F3FE],
+    &[0x1F47C, 0x1F3FF],
+    &[0x1F481, 0x1F3FB],
+    &[0x1F481, 0x1F3FC],
+    &[0x1F481, 0x1F3FD],
+    &[0x1F481, 0x1F3FE],
+    &[0x1F481, 0x1F3FF],
+    &[0x1F482, 0x1F3FB],
+    &[0x1F482, 0x1F3FC],
+    &[0x1F482, 0x1F3FD],
+    &[0x1F482, 0x1F3FE],
+    &[0x1F482, 0x1F3FF],
+    &[0x1F483, 0x1F3FB],
+    &[0x1F483, 0x1F3FC],
+    &[0x1F483, 0x1F3FD],
+    &[0x1F483, 0x1F3FE],
+    &[0x1F483, 0x1F3FF],
+    &[0x1F485, 0x1F3FB],
+    &[0x1F485, 0x1F3FC],
+    &[0x1F485, 0x1F3FD],
+    &[0x1F485, 0x1F3FE],
+    &[0x1F485, 0x1F3FF],
+    &[0x1F486, 0x1F3FB],
+    &[0x1F486, 0x1F3FC],
+    &[0x1F486, 0x1F3FD],
+    &[0x1F486, 0x1F3FE],
+    &[0x1F486, 0x1F3FF],
+    &[0x1F487, 0x1F3FB],
+    &[0x1F487, 0x1F3FC],
+    &[0x1F487, 0x1F3FD],
+    &[0x1F487, 0x1F3FE],
+    &[0x1F487, 0x1F3FF],
+    &[0x1F4AA, 0x1F3FB],
+    &[0x1F4AA, 0x1F3FC],
+    &[0x1F4AA, 0x1F3FD],
+    &[0x1F4AA, 0x1F3FE],
+    &[0x1F4AA, 0x1F3FF],
+    &[0x1F574, 0x1F3FB],
+    &[0x1F574, 0x1F3FC],
+    &[0x1F574, 0x1F3FD],
+    &[0x1F574, 0x1F3FE],
+    &[0x1F574, 0x1F3FF],
+    &[0x1F575, 0x1F3FB],
+    &[0x1F575, 0x1F3FC],
+    &[0x1F575, 0x1F3FD],
+    &[0x1F575, 0x1F3FE],
+    &[0x1F575, 0x1F3FF],
+    &[0x1F57A, 0x1F3FB],
+    &[0x1F57A, 0x1F3FC],
+    &[0x1F57A, 0x1F3FD],
+    &[0x1F57A, 0x1F3FE],
+    &[0x1F57A, 0x1F3FF],
+    &[0x1F590, 0x1F3FB],
+    &[0x1F590, 0x1F3FC],
+    &[0x1F590, 0x1F3FD],
+    &[0x1F590, 0x1F3FE],
+    &[0x1F590, 0x1F3FF],
+    &[0x1F595, 0x1F3FB],
+    &[0x1F595, 0x1F3FC],
+    &[0x1F595, 0x1F3FD],
+    &[0x1F595, 0x1F3FE],
+    &[0x1F595, 0x1F3FF],
+    &[0x1F596, 0x1F3FB],
+    &[0x1F596, 0x1F3FC],
+    &[0x1F596, 0x1F3FD],
+    &[0x1F596, 0x1F3FE],
+    &[0x1F596, 0x1F3FF],
+    &[0x1F645, 0x1F3FB],
+    &[0x1F645, 0x1F3FC],
+    &[0x1F645, 0x1F3FD],
+    &[0x1F645, 0x1F3FE],
+    &[0x1F645, 0x1F3FF],
+    &[0x1F646, 0x1F3FB],
+    &[0x1F646, 0x1F3FC],
+    &[0x1F646, 0x1F3FD],
+    &[0x1F646, 0x1F3FE],
+    &[0x1F646, 0x1F3FF],
+    &[0x1F647, 0x1F3FB],
+    &[0x1F647, 0x1F3FC],
+    &[0x1F647, 0x1F3FD],
+    &[0x1F647, 0x1F3FE],
+    &[0x1F647, 0x1F3FF],
+    &[0x1F64B, 0x1F3FB],
+    &[0x1F64B, 0x1F3FC],
+    &[0x1F64B, 0x1F3FD],
+    &[0x1F64B, 0x1F3FE],
+    &[0x1F64B, 0x1F3FF],
+    &[0x1F64C, 0x1F3FB],
+    &[0x1F64C, 0x1F3FC],
+    &[0x1F64C, 0x1F3FD],
+    &[0x1F64C, 0x1F3FE],
+    &[0x1F64C, 0x1F3FF],
+    &[0x1F64D, 0x1F3FB],
+    &[0x1F64D, 0x1F3FC],
+    &[0x1F64D, 0x1F3FD],
+    &[0x1F64D, 0x1F3FE],
+    &[0x1F64D, 0x1F3FF],
+    &[0x1F64E, 0x1F3FB],
+    &[0x1F64E, 0x1F3FC],
+    &[0x1F64E, 0x1F3FD],
+    &[0x1F64E, 0x1F3FE],
+    &[0x1F64E, 0x1F3FF],
+    &[0x1F64F, 0x1F3FB],
+    &[0x1F64F, 0x1F3FC],
+    &[0x1F64F, 0x1F3FD],
+    &[0x1F64F, 0x1F3FE],
+    &[0x1F64F, 0x1F3FF],
+    &[0x1F6A3, 0x1F3FB],
+    &[0x1F6A3, 0x1F3FC],
+    &[0x1F6A3, 0x1F3FD],
+    &[0x1F6A3, 0x1F3FE],
+    &[0x1F6A3, 0x1F3FF],
+    &[0x1F6B4, 0x1F3FB],
+    &[0x1F6B4, 0x1F3FC],
+    &[0x1F6B4, 0x1F3FD],
+    &[0x1F6B4, 0x1F3FE],
+    &[0x1F6B4, 0x1F3FF],
+    &[0x1F6B5, 0x1F3FB],
+    &[0x1F6B5, 0x1F3FC],
+    &[0x1F6B5, 0x1F3FD],
+    &[0x1F6B5, 0x1F3FE],
+    &[0x1F6B5, 0x1F3FF],
+    &[0x1F6B6, 0x1F3FB],
+    &[0x1F6B6, 0x1F3FC],
+    &[0x1F6B6, 0x1F3FD],
+    &[0x1F6B6, 0x1F3FE],
+    &[0x1F6B6, 0x1F3FF],
+    &[0x1F6C0, 0x1F3FB],
+    &[0x1F6C0, 0x1F3FC],
+    &[0x1F6C0, 0x1F3FD],
+    &[0x1F6C0, 0x1F3FE],
+    &[0x1F6C0, 0x1F3FF],
+    &[0x1F6CC, 0x1F3FB],
+    &[0x1F6CC, 0x1F3FC],
+    &[0x1F6CC, 0x1F3FD],
+    &[0x1F6CC, 0x1F3FE],
+    &[0x1F6CC, 0x1F3FF],
+    &[0x1F90C, 0x1F3FB],
+    &[0x1F90C, 0x1F3FC],
+    &[0x1F90C, 0x1F3FD],
+    &[0x1F90C, 0x1F3FE],
+    &[0x1F90C, 0x1F3FF],
+    &[0x1F90F, 0x1F3FB],
+    &[0x1F90F, 0x1F3FC],
+    &[0x1F90F, 0x1F3FD],
+    &[0x1F90F, 0x1F3FE],
+    &[0x1F90F, 0x1F3FF],
+    &[0x1F918, 0x1F3FB],
+    &[0x1F918, 0x1F3FC],
+    &[0x1F918, 0x1F3FD],
+    &[0x1F918, 0x1F3FE],
+    &[0x1F918, 0x1F3FF],
+    &[0x1F919, 0x1F3FB],
+    &[0x1F919, 0x1F3FC],
+    &[0x1F919, 0x1F3FD],
+    &[0x1F919, 0x1F3FE],
+    &[0x1F919, 0x1F3FF],
+    &[0x1F91A, 0x1F3FB],
+    &[0x1F91A, 0x1F3FC],
+    &[0x1F91A, 0x1F3FD],
+    &[0x1F91A, 0x1F3FE],
+    &[0x1F91A, 0x1F3FF],
+    &[0x1F91B, 0x1F3FB],
+    &[0x1F91B, 0x1F3FC],
+    &[0x1F91B, 0x1F3FD],
+    &[0x1F91B, 0x1F3FE],
+    &[0x1F91B, 0x1F3FF],
+    &[0x1F91C, 0x1F3FB],
+    &[0x1F91C, 0x1F3FC],
+    &[0x1F91C, 0x1F3FD],
+    &[0x1F91C, 0x1F3FE],
+    &[0x1F91C, 0x1F3FF],
+    &[0x1F91E, 0x1F3FB],
+    &[0x1F91E, 0x1F3FC],
+    &[0x1F91E, 0x1F3FD],
+    &[0x1F91E, 0x1F3FE],
+    &[0x1F91E, 0x1F3FF],
+    &[0x1F91F, 0x1F3FB],
+    &[0x1F91F, 0x1F3FC],
+    &[0x1F91F, 0x1F3FD],
+    &[0x1F91F, 0x1F3FE],
+    &[0x1F91F, 0x1F3FF],
+    &[0x1F926, 0x1F3FB],
+    &[0x1F926, 0x1F3FC],
+    &[0x1F926, 0x1F3FD],
+    &[0x1F926, 0x1F3FE],
+    &[0x1F926, 0x1F3FF],
+    &[0x1F930, 0x1F3FB],
+    &[0x1F930, 0x1F3FC],
+    &[0x1F930, 0x1F3FD],
+    &[0x1F930, 0x1F3FE],
+    &[0x1F930, 0x1F3FF],
+    &[0x1F931, 0x1F3FB],
+    &[0x1F931, 0x1F3FC],
+    &[0x1F931, 0x1F3FD],
+    &[0x1F931, 0x1F3FE],
+    &[0x1F931, 0x1F3FF],
+    &[0x1F932, 0x1F3FB],
+    &[0x1F932, 0x1F3FC],
+    &[0x1F932, 0x1F3FD],
+    &[0x1F932, 0x1F3FE],
+    &[0x1F932, 0x1F3FF],
+    &[0x1F933, 0x1F3FB],
+    &[0x1F933, 0x1F3FC],
+    &[0x1F933, 0x1F3FD],
+    &[0x1F933, 0x1F3FE],
+    &[0x1F933, 0x1F3FF],
+    &[0x1F934, 0x1F3FB],
+    &[0x1F934, 0x1F3FC],
+    &[0x1F934, 0x1F3FD],
+    &[0x1F934, 0x1F3FE],
+    &[0x1F934, 0x1F3FF],
+    &[0x1F935, 0x1F3FB],
+    &[0x1F935, 0x1F3FC],
+    &[0x1F935, 0x1F3FD],
+    &[0x1F935, 0x1F3FE],
+    &[0x1F935, 0x1F3FF],
+    &[0x1F936, 0x1F3FB],
+    &[0x1F936, 0x1F3FC],
+    &[0x1F936, 0x1F3FD],
+    &[0x1F936, 0x1F3FE],
+    &[0x1F936, 0x1F3FF],
+    &[0x1F937, 0x1F3FB],
+    &[0x1F937, 0x1F3FC],
+    &[0x1F937, 0x1F3FD],
+    &[0x1F937, 0x1F3FE],
+    &[0x1F937, 0x1F3FF],
+    &[0x1F938, 0x1F3FB],
+    &[0x1F938, 0x1F3FC],
+    &[0x1F938, 0x1F3FD],
+    &[0x1F938, 0x1F3FE],
+    &[0x1F938, 0x1F3FF],
+    &[0x1F939, 0x1F3FB],
+    &[0x1F939, 0x1F3FC],
+    &[0x1F939, 0x1F3FD],
+    &[0x1F939, 0x1F3FE],
+    &[0x1F939, 0x1F3FF],
+    &[0x1F93D, 0x1F3FB],
+    &[0x1F93D, 0x1F3FC],
+    &[0x1F93D, 0x1F3FD],
+    &[0x1F93D, 0x1F3FE],
+    &[0x1F93D, 0x1F3FF],
+    &[0x1F93E, 0x1F3FB],
+    &[0x1F93E, 0x1F3FC],
+    &[0x1F93E, 0x1F3FD],
+    &[0x1F93E, 0x1F3FE],
+    &[0x1F93E, 0x1F3FF],
+    &[0x1F977, 0x1F3FB],
+    &[0x1F977, 0x1F3FC],
+    &[0x1F977, 0x1F3FD],
+    &[0x1F977, 0x1F3FE],
+    &[0x1F977, 0x1F3FF],
+    &[0x1F9B5, 0x1F3FB],
+    &[0x1F9B5, 0x1F3FC],
+    &[0x1F9B5, 0x1F3FD],
+    &[0x1F9B5, 0x1F3FE],
+    &[0x1F9B5, 0x1F3FF],
+    &[0x1F9B6, 0x1F3FB],
+    &[0x1F9B6, 0x1F3FC],
+    &[0x1F9B6, 0x1F3FD],
+    &[0x1F9B6, 0x1F3FE],
+    &[0x1F9B6, 0x1F3FF],
+    &[0x1F9B8, 0x1F3FB],
+    &[0x1F9B8, 0x1F3FC],
+    &[0x1F9B8, 0x1F3FD],
+    &[0x1F9B8, 0x1F3FE],
+    &[0x1F9B8, 0x1F3FF],
+    &[0x1F9B9, 0x1F3FB],
+    &[0x1F9B9, 0x1F3FC],
+    &[0x1F9B9, 0x1F3FD],
+    &[0x1F9B9, 0x1F3FE],
+    &[0x1F9B9, 0x1F3FF],
+    &[0x1F9BB, 0x1F3FB],
+    &[0x1F9BB, 0x1F3FC],
+    &[0x1F9BB, 0x1F3FD],
+    &[0x1F9BB, 0x1F3FE],
+    &[0x1F9BB, 0x1F3FF],
+    &[0x1F9CD, 0x1F3FB],
+    &[0x1F9CD, 0x1F3FC],
+    &[0x1F9CD, 0x1F3FD],
+    &[0x1F9CD, 0x1F3FE],
+    &[0x1F9CD, 0x1F3FF],
+    &[0x1F9CE, 0x1F3FB],
+    &[0x1F9CE, 0x1F3FC],
+    &[0x1F9CE, 0x1F3FD],
+    &[0x1F9CE, 0x1F3FE],
+    &[0x1F9CE, 0x1F3FF],
+    &[0x1F9CF, 0x1F3FB],
+    &[0x1F9CF, 0x1F3FC],
+    &[0x1F9CF, 0x1F3FD],
+    &[0x1F9CF, 0x1F3FE],
+    &[0x1F9CF, 0x1F3FF],
+    &[0x1F9D1, 0x1F3FB],
+    &[0x1F9D1, 0x1F3FC],
+    &[0x1F9D1, 0x1F3FD],
+    &[0x1F9D1, 0x1F3FE],
+    &[0x1F9D1, 0x1F3FF],
+    &[0x1F9D2, 0x1F3FB],
+    &[0x1F9D2, 0x1F3FC],
+    &[0x1F9D2, 0x1F3FD],
+    &[0x1F9D2, 0x1F3FE],
+    &[0x1F9D2, 0x1F3FF],
+    &[0x1F9D3, 0x1F3FB],
+    &[0x1F9D3, 0x1F3FC],
+    &[0x1F9D3, 0x1F3FD],
+    &[0x1F9D3, 0x1F3FE],
+    &[0x1F9D3, 0x1F3FF],
+    &[0x1F9D4, 0x1F3FB],
+    &[0x1F9D4, 0x1F3FC],
+    &[0x1F9D4, 0x1F3FD],
+    &[0x1F9D4, 0x1F3FE],
+    &[0x1F9D4, 0x1F3FF],
+    &[0x1F9D5, 0x1F3FB],
+    &[0x1F9D5, 0x1F3FC],
+    &[0x1F9D5, 0x1F3FD],
+    &[0x1F9D5, 0x1F3FE],
+    &[0x1F9D5, 0x1F3FF],
+    &[0x1F9D6, 0x1F3FB],
+    &[0x1F9D6, 0x1F3FC],
+    &[0x1F9D6, 0x1F3FD],
+    &[0x1F9D6, 0x1F3FE],
+    &[0x1F9D6, 0x1F3FF],
+    &[0x1F9D7, 0x1F3FB],
+    &[0x1F9D7, 0x1F3FC],
+    &[0x1F9D7, 0x1F3FD],
+    &[0x1F9D7, 0x1F3FE],
+    &[0x1F9D7, 0x1F3FF],
+    &[0x1F9D8, 0x1F3FB],
+    &[0x1F9D8, 0x1F3FC],
+    &[0x1F9D8, 0x1F3FD],
+    &[0x1F9D8, 0x1F3FE],
+    &[0x1F9D8, 0x1F3FF],
+    &[0x1F9D9, 0x1F3FB],
+    &[0x1F9D9, 0x1F3FC],
+    &[0x1F9D9, 0x1F3FD],
+    &[0x1F9D9, 0x1F3FE],
+    &[0x1F9D9, 0x1F3FF],
+    &[0x1F9DA, 0x1F3FB],
+    &[0x1F9DA, 0x1F3FC],
+    &[0x1F9DA, 0x1F3FD],
+    &[0x1F9DA, 0x1F3FE],
+    &[0x1F9DA, 0x1F3FF],
+    &[0x1F9DB, 0x1F3FB],
+    &[0x1F9DB, 0x1F3FC],
+    &[0x1F9DB, 0x1F3FD],
+    &[0x1F9DB, 0x1F3FE],
+    &[0x1F9DB, 0x1F3FF],
+    &[0x1F9DC, 0x1F3FB],
+    &[0x1F9DC, 0x1F3FC],
+    &[0x1F9DC, 0x1F3FD],
+    &[0x1F9DC, 0x1F3FE],
+    &[0x1F9DC, 0x1F3FF],
+    &[0x1F9DD, 0x1F3FB],
+    &[0x1F9DD, 0x1F3FC],
+    &[0x1F9DD, 0x1F3FD],
+    &[0x1F9DD, 0x1F3FE],
+    &[0x1F9DD, 0x1F3FF],
+];
+
+pub(crate) static RGI_EMOJI_TAG_SEQUENCE: [&[u32]; 3] = [
+    &[
+        0x1F3F4, 0xE0067, 0xE0062, 0xE0065, 0xE006E, 0xE0067, 0xE007F,
+    ],
+    &[
+        0x1F3F4, 0xE0067, 0xE0062, 0xE0073, 0xE0063, 0xE0074, 0xE007F,
+    ],
+    &[
+        0x1F3F4, 0xE0067, 0xE0062, 0xE0077, 0xE006C, 0xE0073, 0xE007F,
+    ],
+];
+
+pub(crate) static RGI_EMOJI_ZWJ_SEQUENCE: [&[u32]; 1122] = [
+    &[0x26F9, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+    &[0x26F9, 0xFE0F, 0x200D, 0x2642, 0xFE0F],
+    &[0x26F9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x26F9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x26F9, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x26F9, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x26F9, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x26F9, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x26F9, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x26F9, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x26F9, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x26F9, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C3, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C3, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C3, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C3, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C3, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C3, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C3, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C3, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C3, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C3, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C3, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C3, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C4, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C4, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C4, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C4, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C4, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C4, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C4, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C4, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C4, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C4, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C4, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C4, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CA, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CA, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CA, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CA, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CA, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CA, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CA, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CA, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CA, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CA, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CA, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CA, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CB, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CB, 0xFE0F, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CB, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CB, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CB, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CB, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CB, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CB, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CB, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CB, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CB, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CB, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CC, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CC, 0xFE0F, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CC, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CC, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CC, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CC, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CC, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CC, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CC, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CC, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CC, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CC, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3F3, 0xFE0F, 0x200D, 0x26A7, 0xFE0F],
+    &[0x1F3F3, 0xFE0F, 0x200D, 0x1F308],
+    &[0x1F3F4, 0x200D, 0x2620, 0xFE0F],
+    &[0x1F408, 0x200D, 0x2B1B],
+    &[0x1F415, 0x200D, 0x1F9BA],
+    &[0x1F43B, 0x200D, 0x2744, 0xFE0F],
+    &[0x1F441, 0xFE0F, 0x200D, 0x1F5E8, 0xFE0F],
+    &[0x1F468, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F468, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F468, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F468, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468],
+    &[
+        0x1F468, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468,
+    ],
+    &[0x1F468, 0x200D, 0x1F33E],
+    &[0x1F468, 0x200D, 0x1F373],
+    &[0x1F468, 0x200D, 0x1F37C],
+    &[0x1F468, 0x200D, 0x1F393],
+    &[0x1F468, 0x200D, 0x1F3A4],
+    &[0x1F468, 0x200D, 0x1F3A8],
+    &[0x1F468, 0x200D, 0x1F3EB],
+    &[0x1F468, 0x200D, 0x1F3ED],
+    &[0x1F468, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F4BB],
+    &[0x1F468, 0x200D, 0x1F4BC],
+    &[0x1F468, 0x200D, 0x1F527],
+    &[0x1F468, 0x200D, 0x1F52C],
+    &[0x1F468, 0x200D, 0x1F680],
+    &[0x1F468, 0x200D, 0x1F692],
+    &[0x1F468, 0x200D, 0x1F9AF],
+    &[0x1F468, 0x200D, 0x1F9B0],
+    &[0x1F468, 0x200D, 0x1F9B1],
+    &[0x1F468, 0x200D, 0x1F9B2],
+    &[0x1F468, 0x200D, 0x1F9B3],
+    &[0x1F468, 0x200D, 0x1F9BC],
+    &[0x1F468, 0x200D, 0x1F9BD],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F33E],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F373],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F37C],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F393],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F3A4],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F3A8],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F3EB],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F3ED],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F4BB],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F4BC],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F527],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F52C],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F680],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F692],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9AF],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B0],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B1],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B2],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B3],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BC],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BD],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F33E],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F373],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F37C],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F393],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F3A4],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F3A8],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F3EB],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F3ED],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F4BB],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F4BC],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F527],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F52C],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F680],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F692],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F9AF],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F9B0],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F9B1],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F9B2],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F9B3],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F9BC],
+    &[0x1F468, 0x1F3FC, 0x200D, 0x1F9BD],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F33E],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F373],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F37C],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F393],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F3A4],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F3A8],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F3EB],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F3ED],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F4BB],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F4BC],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F527],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F52C],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F680],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F692],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F9AF],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F9B0],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F9B1],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F9B2],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F9B3],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F9BC],
+    &[0x1F468, 0x1F3FD, 0x200D, 0x1F9BD],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F33E],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F373],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F37C],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F393],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F3A4],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F3A8],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F3EB],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F3ED],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F4BB],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F4BC],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F527],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F52C],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F680],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F692],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F9AF],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F9B0],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F9B1],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F9B2],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F9B3],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F9BC],
+    &[0x1F468, 0x1F3FE, 0x200D, 0x1F9BD],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F33E],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F373],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F37C],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F393],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F3A4],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F3A8],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F3EB],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F3ED],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F4BB],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F4BC],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F527],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F52C],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F680],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F692],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F9AF],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F9B0],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F9B1],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F9B2],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F9B3],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F9BC],
+    &[0x1F468, 0x1F3FF, 0x200D, 0x1F9BD],
+    &[0x1F469, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F469, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F469, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468],
+    &[0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469],
+    &[
+        0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468,
+    ],
+    &[
+        0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469,
+    ],
+    &[0x1F469, 0x200D, 0x1F33E],
+    &[0x1F469, 0x200D, 0x1F373],
+    &[0x1F469, 0x200D, 0x1F37C],
+    &[0x1F469, 0x200D, 0x1F393],
+    &[0x1F469, 0x200D, 0x1F3A4],
+    &[0x1F469, 0x200D, 0x1F3A8],
+    &[0x1F469, 0x200D, 0x1F3EB],
+    &[0x1F469, 0x200D, 0x1F3ED],
+    &[0x1F469, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F467],
+    &[0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F469, 0x200D, 0x1F4BB],
+    &[0x1F469, 0x200D, 0x1F4BC],
+    &[0x1F469, 0x200D, 0x1F527],
+    &[0x1F469, 0x200D, 0x1F52C],
+    &[0x1F469, 0x200D, 0x1F680],
+    &[0x1F469, 0x200D, 0x1F692],
+    &[0x1F469, 0x200D, 0x1F9AF],
+    &[0x1F469, 0x200D, 0x1F9B0],
+    &[0x1F469, 0x200D, 0x1F9B1],
+    &[0x1F469, 0x200D, 0x1F9B2],
+    &[0x1F469, 0x200D, 0x1F9B3],
+    &[0x1F469, 0x200D, 0x1F9BC],
+    &[0x1F469, 0x200D, 0x1F9BD],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F33E],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F373],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F37C],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F393],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F3A4],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F3A8],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F3EB],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F3ED],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F4BB],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F4BC],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F527],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F52C],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F680],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F692],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FC],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FD],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FE],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FF],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9AF],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B0],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B1],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B2],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B3],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BC],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BD],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F33E],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F373],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F37C],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F393],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F3A4],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F3A8],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F3EB],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F3ED],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F4BB],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F4BC],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F527],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F52C],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F680],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F692],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FD],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FE],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FF],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F9AF],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F9B0],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F9B1],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F9B2],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F9B3],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F9BC],
+    &[0x1F469, 0x1F3FC, 0x200D, 0x1F9BD],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F33E],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F373],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F37C],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F393],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F3A4],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F3A8],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F3EB],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F3ED],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F4BB],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F4BC],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F527],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F52C],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F680],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F692],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FC],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FE],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FF],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F9AF],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F9B0],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F9B1],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F9B2],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F9B3],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F9BC],
+    &[0x1F469, 0x1F3FD, 0x200D, 0x1F9BD],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F33E],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F373],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F37C],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F393],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F3A4],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F3A8],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F3EB],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F3ED],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F4BB],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F4BC],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F527],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F52C],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F680],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F692],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FC],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FD],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FF],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F9AF],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F9B0],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F9B1],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F9B2],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F9B3],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F9BC],
+    &[0x1F469, 0x1F3FE, 0x200D, 0x1F9BD],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F33E],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F373],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F37C],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F393],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F3A4],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F3A8],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F3EB],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F3ED],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F4BB],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F4BC],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F527],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F52C],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F680],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F692],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FC],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FD],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FE],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F9AF],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F9B0],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F9B1],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F9B2],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F9B3],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F9BC],
+    &[0x1F469, 0x1F3FF, 0x200D, 0x1F9BD],
+    &[0x1F46E, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F46E, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F46E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46E, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F46E, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46E, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F46E, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46E, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F46E, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46E, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F46E, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F46F, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F470, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F470, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F470, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F470, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F470, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F470, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F470, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F470, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F470, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F470, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F470, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F470, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F471, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F471, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F471, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F471, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F471, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F471, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F471, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F471, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F471, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F471, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F471, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F471, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F473, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F473, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F473, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F473, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F473, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F473, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F473, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F473, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F473, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F473, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F473, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F473, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F477, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F477, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F477, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F477, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F477, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F477, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F477, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F477, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F477, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F477, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F477, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F477, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F481, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F481, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F481, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F481, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F481, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F481, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F481, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F481, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F481, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F481, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F481, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F481, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F482, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F482, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F482, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F482, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F482, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F482, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F482, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F482, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F482, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F482, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F482, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F482, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F486, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F486, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F486, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F486, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F486, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F486, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F486, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F486, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F486, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F486, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F486, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F486, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F487, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F487, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F487, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F487, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F487, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F487, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F487, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F487, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F487, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F487, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F487, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F487, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F575, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F575, 0xFE0F, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F575, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F575, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F575, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F575, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F575, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F575, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F575, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F575, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F575, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F575, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F645, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F645, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F645, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F645, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F645, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F645, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F645, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F645, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F645, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F645, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F645, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F645, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F646, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F646, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F646, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F646, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F646, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F646, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F646, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F646, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F646, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F646, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F646, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F646, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F647, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F647, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F647, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F647, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F647, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F647, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F647, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F647, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F647, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F647, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F647, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F647, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64B, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64B, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64B, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64B, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64B, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64B, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64B, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64B, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64B, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64B, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64B, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64B, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64D, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64D, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64D, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64D, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64D, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64D, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64E, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64E, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64E, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64E, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64E, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64E, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64E, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64E, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64E, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64E, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6A3, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6A3, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6A3, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6A3, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6A3, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6A3, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6A3, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6A3, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6A3, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6A3, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6A3, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6A3, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B4, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B4, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B4, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B4, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B4, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B4, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B4, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B4, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B4, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B4, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B4, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B4, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B5, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B5, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B5, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B5, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B5, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B5, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B5, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B5, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B5, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B5, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B5, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B5, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B6, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B6, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B6, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B6, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B6, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B6, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F926, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F926, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F926, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F926, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F926, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F926, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F926, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F926, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F926, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F926, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F926, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F926, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F935, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F935, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F935, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F935, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F935, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F935, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F935, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F935, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F935, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F935, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F935, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F935, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F937, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F937, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F937, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F937, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F937, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F937, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F937, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F937, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F937, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F937, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F937, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F937, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F938, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F938, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F938, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F938, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F938, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F938, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F938, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F938, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F938, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F938, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F938, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F938, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F939, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F939, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F939, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F939, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F939, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F939, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F939, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F939, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F939, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F939, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F939, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F939, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93C, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93C, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93D, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93D, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93D, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93D, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93D, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93D, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93D, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93D, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93D, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93D, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93D, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93D, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93E, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93E, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93E, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93E, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93E, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93E, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93E, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93E, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93E, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93E, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B8, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B8, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B8, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B8, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B8, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B8, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B8, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B8, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B8, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B8, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B8, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B8, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B9, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B9, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B9, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B9, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B9, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B9, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B9, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B9, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B9, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B9, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CD, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CD, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CD, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CD, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CD, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CD, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CD, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CD, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CD, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CD, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CE, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CE, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CE, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CE, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CE, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CF, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CF, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CF, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CF, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CF, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CF, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CF, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CF, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CF, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CF, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1F33E],
+    &[0x1F9D1, 0x200D, 0x1F373],
+    &[0x1F9D1, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x200D, 0x1F384],
+    &[0x1F9D1, 0x200D, 0x1F393],
+    &[0x1F9D1, 0x200D, 0x1F3A4],
+    &[0x1F9D1, 0x200D, 0x1F3A8],
+    &[0x1F9D1, 0x200D, 0x1F3EB],
+    &[0x1F9D1, 0x200D, 0x1F3ED],
+    &[0x1F9D1, 0x200D, 0x1F4BB],
+    &[0x1F9D1, 0x200D, 0x1F4BC],
+    &[0x1F9D1, 0x200D, 0x1F527],
+    &[0x1F9D1, 0x200D, 0x1F52C],
+    &[0x1F9D1, 0x200D, 0x1F680],
+    &[0x1F9D1, 0x200D, 0x1F692],
+    &[0x1F9D1, 0x200D, 0x1F91D, 0x200D, 0x1F9D1],
+    &[0x1F9D1, 0x200D, 0x1F9AF],
+    &[0x1F9D1, 0x200D, 0x1F9B0],
+    &[0x1F9D1, 0x200D, 0x1F9B1],
+    &[0x1F9D1, 0x200D, 0x1F9B2],
+    &[0x1F9D1, 0x200D, 0x1F9B3],
+    &[0x1F9D1, 0x200D, 0x1F9BC],
+    &[0x1F9D1, 0x200D, 0x1F9BD],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F33E],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F373],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F384],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F393],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3A4],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3A8],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3EB],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3ED],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F4BB],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F4BC],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F527],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F52C],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F680],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F692],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9AF],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B0],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B1],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B2],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B3],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BC],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BD],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F33E],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F373],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F384],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F393],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F3A4],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F3A8],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F3EB],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F3ED],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F4BB],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F4BC],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F527],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F52C],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F680],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F692],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9AF],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9B0],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9B1],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9B2],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9B3],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9BC],
+    &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9BD],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F33E],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F373],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F384],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F393],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F3A4],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F3A8],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F3EB],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F3ED],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F4BB],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F4BC],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F527],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F52C],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F680],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F692],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9AF],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9B0],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9B1],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9B2],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9B3],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9BC],
+    &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9BD],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F33E],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F373],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F384],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F393],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F3A4],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F3A8],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F3EB],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F3ED],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F4BB],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F4BC],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F527],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F52C],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F680],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F692],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9AF],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9B0],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9B1],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9B2],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9B3],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9BC],
+    &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9BD],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F33E],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F373],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F384],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F393],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F3A4],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F3A8],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F3EB],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F3ED],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F4BB],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F4BC],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F527],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F52C],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F680],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F692],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9AF],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9B0],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9B1],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9B2],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9B3],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9BC],
+    &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9BD],
+    &[0x1F9D6, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D6, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D6, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D6, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D6, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D6, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D6, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D6, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D6, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D6, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D6, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D6, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D7, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D7, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D7, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D7, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D7, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D7, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D7, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D7, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D7, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D7, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D7, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D7, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D8, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D8, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D8, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D8, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D8, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D8, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D8, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D8, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D8, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D8, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D8, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D8, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D9, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D9, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D9, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D9, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D9, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D9, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D9, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D9, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D9, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D9, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DA, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DA, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DA, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DA, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DA, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DA, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DA, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DA, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DA, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DA, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DA, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DA, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DB, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DB, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DB, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DB, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DB, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DB, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DB, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DB, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DB, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DB, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DC, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DC, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DC, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DC, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DC, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DC, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DC, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DC, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DC, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DC, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DD, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DD, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DD, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DD, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DD, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DD, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DD, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DD, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DD, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DD, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DF, 0x200D, 0x2642, 0xFE0F],
+];
+
+#[derive(Debug, Clone, Copy)]
+pub enum UnicodePropertyOfStrings {
+    BasicEmoji,
+    EmojiKeycapSequence,
+    RgiEmojiFlagSequence,
+    RgiEmojiModifierSequence,
+    RgiEmojiTagSequence,
+    RgiEmojiZwjSequence,
+    RgiEmoji,
+}
+
+pub fn unicode_property_of_strings_from_str(s: &str) -> Option<UnicodePropertyOfStrings> {
+    use UnicodePropertyOfStrings::*;
+    match s {
+        "Basic_Emoji" => Some(BasicEmoji),
+        "Emoji_Keycap_Sequence" => Some(EmojiKeycapSequence),
+        "RGI_Emoji_Flag_Sequence" => Some(RgiEmojiFlagSequence),
+        "RGI_Emoji_Modifier_Sequence" => Some(RgiEmojiModifierSequence),
+        "RGI_Emoji_Tag_Sequence" => Some(RgiEmojiTagSequence),
+        "RGI_Emoji_ZWJ_Sequence" => Some(RgiEmojiZwjSequence),
+        "RGI_Emoji" => Some(RgiEmoji),
+        _ => None,
+    }
+}
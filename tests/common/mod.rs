@@ -153,6 +153,47 @@ impl TestCompiledRegex {
             .collect::<Vec<String>>()
             .join(",")
     }
+
+    /// Match against \p input only at offset \p start, returning the matched
+    /// string, or None if none.
+    pub fn find_sticky<'b>(&self, input: &'b str, start: usize) -> Option<&'b str> {
+        self.re.find_sticky(input, start).map(|m| &input[m.range()])
+    }
+
+    /// Split \p input by matches of self, returning the pieces.
+    pub fn split<'b>(&self, input: &'b str) -> Vec<&'b str> {
+        self.re.split(input).collect()
+    }
+
+    /// Split \p input by matches of self, returning at most \p limit pieces.
+    pub fn splitn<'b>(&self, input: &'b str, limit: usize) -> Vec<&'b str> {
+        self.re.splitn(input, limit).collect()
+    }
+
+    /// Replace the first match in \p input with \p replacement.
+    pub fn replace(&self, input: &str, replacement: &str) -> String {
+        self.re.replace(input, replacement).into_owned()
+    }
+
+    /// Replace all matches in \p input with \p replacement.
+    pub fn replace_all(&self, input: &str, replacement: &str) -> String {
+        self.re.replace_all(input, replacement).into_owned()
+    }
+
+    /// Replace up to \p count matches in \p input with \p replacement,
+    /// returning the result and the number replaced.
+    pub fn replacen(&self, input: &str, count: usize, replacement: &str) -> (String, usize) {
+        let (result, replaced) = self.re.replacen(input, count, replacement);
+        (result.into_owned(), replaced)
+    }
+
+    /// Replace all matches in \p input with the result of \p f.
+    pub fn replace_all_with<F>(&self, input: &str, f: F) -> String
+    where
+        F: FnMut(&regress::Match, &str) -> String,
+    {
+        self.re.replace_all_with(input, f)
+    }
 }
 
 /// Our backend types.
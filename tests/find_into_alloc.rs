@@ -0,0 +1,72 @@
+//! Checks that Regex::find_into reuses the storage of its Captures. This is
+//! its own test binary with a single test, as it counts the allocations of
+//! the whole process.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// \return the number of allocations made by \p f.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+#[test]
+#[cfg_attr(feature = "prohibit-unsafe", ignore)]
+fn test_find_into_does_not_allocate() {
+    // The storage kept for the search does not tie Captures to a thread.
+    fn check_send_sync<T: Send + Sync>() {}
+    check_send_sync::<regress::Captures>();
+
+    let text = "k1=v1; key2 = (v2); k3=v3 k4 ; ab=cd";
+    for pattern in [
+        r"(?<key>\w+)\s*=\s*(\w+)",
+        r"(\w+)=(?=v)(\w+)",
+        r"(?<=;\s)(\w+)(?>\s*)=",
+        r"(a|b|(c))+d",
+    ] {
+        let re = regress::Regex::new(pattern).unwrap();
+        let mut caps = regress::Captures::new();
+        let search = |caps: &mut regress::Captures| {
+            let mut start = 0;
+            let mut count = 0;
+            while re.find_into(text, start, caps) {
+                start = caps.range().end.max(start + 1);
+                count += 1;
+            }
+            count
+        };
+        // The first search grows the buffers.
+        let expected = search(&mut caps);
+        assert!(expected > 0, "{}", pattern);
+        for _ in 0..3 {
+            let mut count = 0;
+            assert_eq!(allocations(|| count = search(&mut caps)), 0, "{}", pattern);
+            assert_eq!(count, expected);
+        }
+    }
+}
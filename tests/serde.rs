@@ -0,0 +1,63 @@
+#![cfg(feature = "serde")]
+
+#[test]
+fn test_serde_round_trip() {
+    use regress::Regex;
+    let text = "Caf\u{E9} CAFE caf\u{E9}\ncafe";
+    for (pattern, flags) in [
+        (r"caf(?<e>\u{E9}|e)", "iu"),
+        (r"^caf.$", "m"),
+        (r"\w+", ""),
+        (r"[\p{L}--[a-z]]+", "v"),
+    ] {
+        let re = Regex::with_flags(pattern, flags).unwrap();
+        let json = serde_json::to_string(&re).unwrap();
+        let back: Regex = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.as_str(), pattern);
+        assert_eq!(format!("{:#}", back), format!("{:#}", re));
+        let ranges = |re: &Regex| -> Vec<_> {
+            re.find_iter(text)
+                .map(|m| m.groups().collect::<Vec<_>>())
+                .collect()
+        };
+        assert_eq!(ranges(&back), ranges(&re), "{}", json);
+    }
+
+    let json = serde_json::to_string(&Regex::with_flags("a+", "iy").unwrap()).unwrap();
+    assert_eq!(json, r#"{"source":"a+","flags":"iy"}"#);
+
+    // A pattern which does not compile is an error, not a panic.
+    let err = serde_json::from_str::<Regex>(r#"{"source":"(a","flags":""}"#).unwrap_err();
+    assert!(err.to_string().contains("Unbalanced"), "{}", err);
+
+    // Unknown and repeated flags are rejected rather than ignored.
+    let err = serde_json::from_str::<Regex>(r#"{"source":"a","flags":"iq"}"#).unwrap_err();
+    assert!(err.to_string().contains("Unknown flag 'q'"), "{}", err);
+    assert!(serde_json::from_str::<Regex>(r#"{"source":"a","flags":"ii"}"#).is_err());
+}
+
+#[test]
+fn test_serde_drops_builder_options() {
+    use regress::{OffsetUnit, Regex, RegexBuilder};
+    let re = RegexBuilder::new(r"(a|a)*b")
+        .case_insensitive(true)
+        .step_budget(100)
+        .offset_unit(OffsetUnit::Utf16)
+        .build()
+        .unwrap();
+    let json = serde_json::to_string(&re).unwrap();
+    assert_eq!(json, r#"{"source":"(a|a)*b","flags":"i"}"#);
+
+    // The flags survive the round trip, but the step budget and offset unit
+    // are the defaults of Regex::with_flags.
+    let back: Regex = serde_json::from_str(&json).unwrap();
+    assert_eq!(format!("{:#}", back), "/(a|a)*b/i");
+    assert_eq!(back.step_budget(), None);
+    assert_eq!(back.offset_unit(), OffsetUnit::Bytes);
+    let text = "aaaaaaaaaaaa";
+    assert!(re.try_find(text).is_err());
+    assert!(back.try_find(text).unwrap().is_none());
+    let text = "\u{1F600}AB";
+    assert_eq!(re.find(text).unwrap().unit_range(), 2..4);
+    assert_eq!(back.find(text).unwrap().unit_range(), 4..6);
+}
@@ -56,4 +56,367 @@ fn test_syntax_errors() {
     test_1_error("(?!abc){3,}", "Quantifier not allowed here");
 
     test_1_error(r"\2(a)", "Invalid character escape");
+
+    test_1_error(r"(?ii:a)", "Repeated flag in modifier group");
+    test_1_error(r"(?i-i:a)", "Repeated flag in modifier group");
+    test_1_error(r"(?-:a)", "Invalid modifier group");
+    test_1_error(r"(?i:a", "Unbalanced parenthesis");
+    test_1_error(r"(?i)*", "Nothing to repeat");
+
+    test_1_error(r"\p{Script=Lu}", "Invalid property value");
+    test_1_error(r"\p{gc=Greek}", "Invalid property value");
+    test_1_error(r"\p{scx=Alphabetic}", "Invalid property value");
+    test_1_error(r"\p{Block=Greek}", "Invalid property name");
+    test_1_error(r"\p{Age=13.1}", "Invalid property value '13.1'");
+    test_1_error(r"\p{Age=9}", "Invalid property value '9'");
+    test_1_error(r"\p{9.0}", "Invalid property name");
+    test_1_error(r"\p{Age!9.0}", "Invalid property name");
+    test_1_error(r"\p{Age!=}", "Invalid property value");
+}
+
+#[track_caller]
+fn test_1_error_v(pattern: &str, expected_err: &str) {
+    let res = regress::Regex::with_flags(pattern, "v");
+    assert!(res.is_err(), "Pattern should not have parsed: {}", pattern);
+
+    let err = res.err().unwrap().text;
+    assert!(
+        err.contains(expected_err),
+        "Error text '{}' did not contain '{}' for pattern '{}'",
+        err,
+        expected_err,
+        pattern
+    );
+}
+
+#[test]
+fn test_unicode_sets_syntax_errors() {
+    // Operators may not be mixed without nesting.
+    test_1_error_v(r"[a&&b--c]", "Invalid set operation");
+    test_1_error_v(r"[a--b&&c]", "Invalid set operation");
+    test_1_error_v(r"[ab&&c]", "Invalid set operation");
+    test_1_error_v(r"[a&&bc]", "Invalid set operation");
+    test_1_error_v(r"[a&&&b]", "Invalid set operation");
+    test_1_error_v(r"[a!!b]", "Invalid set operation");
+
+    test_1_error_v(r"[(]", "must be escaped");
+    test_1_error_v(r"[a-]", "must be escaped");
+    test_1_error_v(r"[[a]", "Unbalanced bracket");
+    test_1_error_v(r"[z-a]", "Range values reversed");
+    test_1_error_v(r"[\d-z]", "Invalid character range");
+    test_1_error_v(r"[\q{abc", "Unterminated class string disjunction");
+    test_1_error_v(r"[\q{abc]", "must be escaped");
+    test_1_error_v(r"[\qabc]", "Invalid escape");
+    test_1_error_v(r"[\q{a(}]", "must be escaped");
+    test_1_error_v(r"[\q{ab}-z]", "Invalid character range");
+    test_1_error_v(r"[^\q{ab}]", "Negated character class may contain strings");
+    test_1_error_v(r"[^\q{}]", "Negated character class may contain strings");
+    test_1_error_v(
+        r"[^[\q{ab}--a]]",
+        "Negated character class may contain strings",
+    );
+    test_1_error_v(
+        r"[^[\q{ab}\q{c}]]",
+        "Negated character class may contain strings",
+    );
+    test_1_error_v(
+        r"[^[\q{ab}&&\q{ab}]]",
+        "Negated character class may contain strings",
+    );
+
+    // Properties of strings may not be negated, and need the v flag.
+    test_1_error_v(r"\P{RGI_Emoji}", "Negated property of strings");
+    test_1_error_v(r"[\P{Basic_Emoji}]", "Negated property of strings");
+    test_1_error_v(
+        r"[^\p{RGI_Emoji_Flag_Sequence}]",
+        "Negated character class may contain strings",
+    );
+    test_1_error(r"\p{RGI_Emoji}", "Property of strings requires the v flag");
+    test_1_error(
+        r"[\p{RGI_Emoji}]",
+        "Property of strings requires the v flag",
+    );
+    // The errors cite what may contain strings.
+    test_1_error_v(r"[^\q{a|bc}]", r#"the string "bc" of \q{...}"#);
+    test_1_error_v(r"[^a\q{}]", r"the empty string of \q{...}");
+    test_1_error_v(
+        r"[^[\p{RGI_Emoji}--a]]",
+        r"the property of strings \p{RGI_Emoji}",
+    );
+    test_1_error_v(r"[^[\q{a|b}\p{Basic_Emoji}]]", r"\p{Basic_Emoji}");
+    test_1_error_v(
+        r"\P{RGI_Emoji_Flag_Sequence}",
+        r"\P{RGI_Emoji_Flag_Sequence}",
+    );
+    assert!(regress::Regex::with_flags(r"[^\q{a|b}]", "v").is_ok());
+    assert!(regress::Regex::with_flags(r"[^[a--\q{ab}]]", "v").is_ok());
+    assert!(regress::Regex::with_flags(r"[^[\q{ab}&&a]]", "v").is_ok());
+
+    let res = regress::Regex::with_flags("a", "uv");
+    assert!(res.unwrap_err().text.contains("may not be combined"));
+}
+
+#[test]
+fn test_named_backreference_syntax_errors() {
+    test_1_error(
+        r"(?<a>x)\k<missing>",
+        "Backreference to invalid named capture group: missing",
+    );
+    test_1_error(r"(?<a>x)\k<a", "Unexpected end of named backreference");
+    test_1_error(r"(?<a>x)\k", "Unexpected end of named backreference");
+
+    // Without named groups, `\k` is only an error in unicode mode.
+    for flags in ["u", "v"] {
+        let err = |pattern| regress::Regex::with_flags(pattern, flags).unwrap_err().text;
+        assert_eq!(err(r"\k"), "Unexpected end of named backreference");
+        assert_eq!(err(r"\k<a"), "Unexpected end of named backreference");
+        assert_eq!(
+            err(r"\k<a>"),
+            "Backreference to invalid named capture group: a"
+        );
+    }
+    for pattern in [r"\k", r"\k<a", r"\k<a>", r"(a)\k", r"[\k]"] {
+        assert!(regress::Regex::new(pattern).is_ok(), "{}", pattern);
+    }
+
+    // With named groups, `\k` is not an identity escape in a class either,
+    // wherever the group is.
+    for pattern in [r"(?<a>x)[\k]", r"[\k](?<a>x)", r"(?<a>x)[a-\k]"] {
+        for flags in ["", "u"] {
+            let err = regress::Regex::with_flags(pattern, flags).unwrap_err();
+            assert_eq!(err.text, "Invalid character escape", "{}", pattern);
+        }
+    }
+    test_1_error_v(r"(?<a>x)[\k]", "Invalid character escape");
+}
+
+#[test]
+fn test_quantified_assertion_syntax_errors() {
+    // Lookaheads may only be quantified outside of unicode mode, and
+    // lookbehinds and anchors never.
+    for flags in ["u", "v"] {
+        for pattern in [r"(?=a)*", r"(?!a)+", r"(?=a)?", r"(?!a){2}"] {
+            let err = regress::Regex::with_flags(pattern, flags).unwrap_err();
+            assert_eq!(err.text, "Quantifier not allowed here", "{}", pattern);
+        }
+    }
+    for pattern in [r"(?=a)*", r"(?!a)+", r"(?=a)?", r"(?!a){2}", r"(?=a){2,}?"] {
+        assert!(regress::Regex::new(pattern).is_ok(), "{}", pattern);
+    }
+    for pattern in [r"(?<=a)*", r"(?<!a)+", r"^*", r"${3}"] {
+        let err = regress::Regex::new(pattern).unwrap_err();
+        assert_eq!(err.text, "Quantifier not allowed here", "{}", pattern);
+    }
+}
+
+#[track_caller]
+fn test_1_error_offset(pattern: &str, expected_offset: Option<usize>) {
+    let err = regress::Regex::with_flags(pattern, "u").unwrap_err();
+    assert_eq!(
+        err.offset(),
+        expected_offset,
+        "Wrong offset for pattern '{}' with error '{}'",
+        pattern,
+        err
+    );
+}
+
+#[test]
+fn test_unicode_escape_errors() {
+    test_1_error(r"\u{110000}", "Invalid unicode escape");
+    test_1_error(r"[\u{110000}]", "Invalid unicode escape");
+    test_1_error(r"\u{FFFFFFFFF}", "Invalid unicode escape");
+    test_1_error(r"\u{}", "Invalid unicode escape");
+    test_1_error(r"\u{+41}", "Invalid unicode escape");
+    test_1_error(r"\u{41", "Invalid unicode escape");
+    test_1_error(r"\u", "Invalid unicode escape");
+    test_1_error(r"\u12", "Invalid unicode escape");
+    test_1_error(r"\u+041", "Invalid unicode escape");
+    test_1_error(r"[\u]", "Invalid unicode escape");
+    test_1_error(r"\x", "Invalid character escape");
+    test_1_error(r"\x4", "Invalid character escape");
+    test_1_error(r"[\xg0]", "Invalid character escape");
+    test_1_error(r"\1", "Invalid character escape");
+    test_1_error(r"\01", "Invalid character escape");
+    test_1_error(r"\8", "Invalid character escape");
+    test_1_error(r"(a)\2", "Invalid character escape");
+    test_1_error(r"[\1]", "Invalid character escape");
+    test_1_error_v(r"[\01]", "Invalid character escape");
+    test_1_error(r"\c", "Invalid character escape");
+    test_1_error(r"\c1", "Invalid character escape");
+    test_1_error(r"[\c_]", "Invalid character escape");
+    test_1_error(r"[\c]]", "Invalid character escape");
+    test_1_error_v(r"[\c1]", "Invalid character escape");
+    test_1_error_v(r"\u{110000}", "Invalid unicode escape");
+    test_1_error_v(r"[\u12]", "Invalid unicode escape");
+    assert!(regress::Regex::with_flags(r"\u{10FFFF}", "u").is_ok());
+}
+
+#[test]
+fn test_syntax_error_offsets() {
+    test_1_error_offset(r"abc)", Some(3));
+    test_1_error_offset(r"a(b", Some(3));
+    test_1_error_offset(r"ab[c", Some(4));
+    test_1_error_offset(r"a**", Some(2));
+    test_1_error_offset(r"a(*b)", Some(2));
+    test_1_error_offset(r"x{5,3}y", Some(6));
+    test_1_error_offset(r"a\", Some(2));
+    test_1_error_offset(r"(?<n>a)(?<n>b)", Some(12));
+
+    // Offsets are in bytes.
+    test_1_error_offset("é€)", Some(5));
+
+    // Property names are reported.
+    test_1_error(r"\p{Foo}", "Invalid property name 'Foo'");
+    test_1_error(r"\p{Script=Foo}", "Invalid property value 'Foo'");
+    test_1_error(r"\p{Foo=Bar}", "Invalid property name 'Foo'");
+    test_1_error(
+        r"\p{White_Space=Yes}",
+        "Binary property 'White_Space' does not take a value",
+    );
+    test_1_error_offset(r"x\p{Foo}", Some(8));
+
+    // Errors not tied to a position have no offset.
+    let err = regress::Regex::with_flags("a", "uv").unwrap_err();
+    assert_eq!(err.offset(), None);
+
+    // Display is just the message.
+    let err = regress::Regex::new("(").unwrap_err();
+    assert_eq!(err.to_string(), "Unbalanced parenthesis");
+
+    // RegexSet retains the offset within the failing pattern.
+    let err = regress::RegexSet::new(["a", "b)"]).unwrap_err();
+    assert_eq!(err.offset(), Some(1));
+}
+
+#[test]
+fn test_syntax_check() {
+    let patterns = [
+        r"abc",
+        r"(a|b)+\1",
+        r"(?<year>\d{4})-\k<year>",
+        r"\p{Script=Greek}+",
+        r"[\q{abc}]",
+        r"(?<!x)y",
+        r"abc)",
+        r"x{5,3}",
+        r"\p{Foo}",
+        r"(?<a>x)\k<b>",
+        r"[a&&b]",
+    ];
+    for flags in ["", "u", "v", "iv"] {
+        for pattern in patterns {
+            assert_eq!(
+                regress::Regex::syntax_check(pattern, flags),
+                regress::Regex::with_flags(pattern, flags).map(|_| ()),
+                "syntax_check disagrees for pattern '{}' with flags '{}'",
+                pattern,
+                flags
+            );
+        }
+    }
+}
+
+#[test]
+fn test_nesting_depth_limit() {
+    let nested = |open: &str, close: &str, depth: usize| {
+        format!("{}a{}", open.repeat(depth), close.repeat(depth))
+    };
+    let limit = regress::DEFAULT_MAX_NESTING_DEPTH;
+
+    // Every kind of group counts, and so do nested classes with the v flag.
+    for open in ["(", "(?:", "(?=", "(?<!", "(?>", "(?i:"] {
+        assert!(regress::Regex::new(&nested(open, ")", limit)).is_ok());
+        test_1_error(
+            &nested(open, ")", limit + 1),
+            "Nesting depth limit exceeded",
+        );
+    }
+    assert!(regress::Regex::with_flags(&nested("[", "]", limit), "v").is_ok());
+    test_1_error_v(&nested("[", "]", limit + 1), "Nesting depth limit exceeded");
+
+    // Groups which have closed do not count, nor do inline modifiers once
+    // they are parsed.
+    let siblings = nested("(", ")", limit).repeat(2);
+    assert!(regress::Regex::new(&siblings).is_ok());
+    let modifiers = format!("{}(?i)a{}", "(".repeat(limit - 1), ")".repeat(limit - 1));
+    assert!(regress::Regex::new(&modifiers.repeat(2)).is_ok());
+
+    // Nor do alternatives, which do not nest deeply however many there are.
+    let alts = "a|".repeat(50000);
+    let re = regress::Regex::new(&alts).unwrap();
+    assert_eq!(re.find("ba").unwrap().range(), 0..0);
+    let words = (0..50000).map(|i| format!("w{}", i)).collect::<Vec<_>>();
+    let re = regress::Regex::new(&format!("^(?:{})$", words.join("|"))).unwrap();
+    assert!(re.find("w0").is_some() && re.find("w49999").is_some());
+    assert!(re.find("w50000").is_none());
+    let re = regress::Regex::new(&nested("(?:b|", ")", limit)).unwrap();
+    assert_eq!(re.find("cab").unwrap().range(), 1..2);
+    assert_eq!(re.find("cba").unwrap().range(), 1..2);
+
+    // The error is reported at the group which is too deep.
+    let err = regress::Regex::new(&nested("(", ")", limit + 1)).unwrap_err();
+    assert_eq!(err.offset(), Some(limit));
+
+    // The builder may lower or raise the limit.
+    let re = regress::RegexBuilder::new(&nested("(", ")", 3))
+        .max_nesting_depth(3)
+        .build()
+        .unwrap();
+    assert_eq!(re.find("a").unwrap().group(3), Some(0..1));
+    let err = regress::RegexBuilder::new(&nested("(", ")", 4))
+        .max_nesting_depth(3)
+        .build()
+        .unwrap_err();
+    assert!(err.text.contains("Nesting depth limit exceeded"));
+    let deep = nested("(?:", ")", limit + 1);
+    let re = regress::RegexBuilder::new(&deep)
+        .max_nesting_depth(limit + 1)
+        .build()
+        .unwrap();
+    assert!(re.find("a").is_some());
+    assert!(re.is_linear_safe());
+}
+
+#[test]
+fn test_quantifier_bounds() {
+    // Bounds out of order are an error, with or without the u flag.
+    test_1_error(r"a{5,2}", "Invalid quantifier");
+    test_1_error(r"a{5,2}?", "Invalid quantifier");
+    test_1_error(r"(?:ab){1,0}", "Invalid quantifier");
+    assert!(regress::Regex::new(r"a{5,2}").is_err());
+    assert!(regress::Regex::new(r"a{2,2}").is_ok());
+
+    // By default any count is accepted.
+    assert!(regress::Regex::new(r"a{1000000000}").is_ok());
+    assert!(regress::Regex::new(r"a{99999999999999999999999}").is_ok());
+
+    // The builder may cap the counts. Unbounded quantifiers only have their
+    // lower count checked.
+    let build = |pattern: &str| {
+        regress::RegexBuilder::new(pattern)
+            .max_repetition(1000)
+            .build()
+            .map(|_| ())
+            .map_err(|err| err.text)
+    };
+    let exceeded = Err("Repetition count limit exceeded".to_string());
+    assert_eq!(build(r"a{1000}b{0,1000}c{1000,}d*"), Ok(()));
+    assert_eq!(build(r"a{1001}"), exceeded);
+    assert_eq!(build(r"a{0,1001}"), exceeded);
+    assert_eq!(build(r"a{1001,}"), exceeded);
+    assert_eq!(build(r"(?:x|y{5000})"), exceeded);
+    assert_eq!(build(r"a{99999999999999999999999}"), exceeded);
+    let err = regress::RegexBuilder::new(r"ab{2000}")
+        .max_repetition(1000)
+        .build()
+        .unwrap_err();
+    assert_eq!(err.offset(), Some(8));
+
+    // Nested counted loops compile without being unrolled exponentially.
+    let nested = format!("{}a{}", "(?:".repeat(40), "){5}".repeat(40));
+    assert!(regress::Regex::new(&nested).is_ok());
+    let re = regress::Regex::new(r"^(?:(?:(?:a{2}){2}){2}){2}$").unwrap();
+    assert!(re.find(&"a".repeat(16)).is_some());
+    assert!(re.find(&"a".repeat(15)).is_none());
 }
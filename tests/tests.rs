@@ -93,6 +93,56 @@ fn test_dotall() {
     test_with_configs(test_dotall_tc)
 }
 
+fn test_line_terminators_tc(tc: TestConfig) {
+    for lt in ["\n", "\r", "\u{2028}", "\u{2029}"].iter() {
+        let text = format!("ab{}cd", lt);
+        for flags in ["", "u"].iter() {
+            tc.compilef(r"b.c", flags).test_fails(&text);
+            tc.compilef(r"b$", flags).test_fails(&text);
+            tc.compilef(r"^c", flags).test_fails(&text);
+            tc.compilef(r".+", flags).match1f(&text).test_eq("ab");
+            let m_flags = format!("m{}", flags);
+            tc.compilef(r"b$", &m_flags).match1f(&text).test_eq("b");
+            tc.compilef(r"^c", &m_flags).match1f(&text).test_eq("c");
+            tc.compilef(r"^\w+$", &m_flags)
+                .match_all(&text)
+                .test_eq(vec!["ab", "cd"]);
+            let s_flags = format!("s{}", flags);
+            tc.compilef(r"b.c", &s_flags)
+                .match1f(&text)
+                .test_eq(&format!("b{}c", lt));
+        }
+    }
+    // Other vertical whitespace is not a line terminator.
+    for c in ["\u{B}", "\u{C}", "\u{85}"].iter() {
+        let text = format!("a{}b", c);
+        tc.compile(r"a.b").match1f(&text).test_eq(&text);
+        tc.compilef(r"a$", "m").test_fails(&text);
+    }
+}
+
+#[test]
+fn test_line_terminators() {
+    test_with_configs(test_line_terminators_tc)
+}
+
+#[test]
+fn test_line_terminators_is_match() {
+    for lt in ["\n", "\r", "\u{2028}", "\u{2029}"].iter() {
+        let text = format!("ab{}cd", lt);
+        let is_match = |pattern: &str, flags: &str| {
+            regress::Regex::with_flags(pattern, flags)
+                .unwrap()
+                .is_match(&text)
+        };
+        assert!(!is_match(r"b.c", ""));
+        assert!(is_match(r"b.c", "s"));
+        assert!(!is_match(r"b$", "u"));
+        assert!(is_match(r"b$", "mu"));
+        assert!(is_match(r"^c", "m"));
+    }
+}
+
 fn test_lookbehinds_tc(tc: TestConfig) {
     tc.compilef(r"(?<=efg)..", "")
         .match1f("abcdefghijk123456")
@@ -150,6 +200,59 @@ fn test_lookbehinds() {
     test_parse_fails(".(?<=.)?");
 }
 
+#[test]
+fn test_variable_length_lookbehinds() {
+    test_with_configs(test_variable_length_lookbehinds_tc)
+}
+
+fn test_variable_length_lookbehinds_tc(tc: TestConfig) {
+    // Unbounded loops.
+    tc.compile(r"(?<=\w+\s)\w+")
+        .match_all("one two three")
+        .test_eq(vec!["two", "three"]);
+    tc.compile(r"(?<=\$\d+\.)\d\d")
+        .match1f("cost: $1234.56")
+        .test_eq("56");
+    tc.compile(r"(?<!\w+\s)\b\w+")
+        .match_all("one two three")
+        .test_eq(vec!["one"]);
+
+    // Alternations of differing lengths.
+    tc.compile(r"(?<=a|bcd|efghi)x")
+        .match_all("ax bcdx efghix fghix cdx")
+        .test_eq(vec!["x", "x", "x"]);
+    tc.compile(r"(?<!a|bcd|efghi)x")
+        .match_all("ax bcdx efghix fghix cdx")
+        .test_eq(vec!["x", "x"]);
+    tc.compile(r"(?<=(a|bcd|efghi))x")
+        .match1f("--efghix")
+        .test_eq("x,efghi");
+    tc.compile(r"(?<=(?:ab|b)(c|dc))x")
+        .match1f("bdcx")
+        .test_eq("x,dc");
+
+    // Lookbehind contents match from right to left, so the rightmost loop is
+    // the first to choose its length.
+    tc.compile(r"(?<=(\d+)(\d+))$")
+        .match1f("1053")
+        .test_eq(",1,053");
+    tc.compile(r"(?<=(\d+)(\d+?))$")
+        .match1f("1053")
+        .test_eq(",105,3");
+
+    // Nested lookarounds of both directions.
+    tc.compile(r"(?<=(?<!b)a+)c")
+        .match_all("aac bac")
+        .test_eq(vec!["c"]);
+    tc.compile(r"(?<=(?<!b)a?)c")
+        .match_all("aac bac")
+        .test_eq(vec!["c", "c"]);
+    tc.compile(r"(?<=(?=ab)\w+)c").match1f("xabbc").test_eq("c");
+    tc.compile(r"(?<=\w+(?<=(\d)))x")
+        .match1f("ab9x")
+        .test_eq("x,9");
+}
+
 #[test]
 fn test_lookbehinds_mjsunit() {
     test_with_configs(test_lookbehinds_mjsunit_tc)
@@ -1167,35 +1270,51 @@ fn run_regexp_named_capture_groups_tc(tc: TestConfig) {
     tc.compilef(r#"(?<a>a)(?<b>b)\k<a>"#, "").match1_named_group("aba", "a").test_eq("a");
     tc.compilef(r#"(?<a>a)(?<b>b)\k<a>"#, "").match1_named_group("aba", "b").test_eq("b");
 
+    // Annex B: without named groups and outside of unicode mode, `\k` is an
+    // identity escape, so what follows it is literal.
+    tc.compilef(r#"\k"#, "").match1f("ok").test_eq("k");
+    tc.compilef(r#"\k<a>"#, "").match1f("\\k<a>").test_eq("k<a>");
+    tc.compilef(r#"\k<a"#, "").match1f("k<a").test_eq("k<a");
+    tc.compilef(r#"(a)\k\1"#, "").match1f("aka").test_eq("aka,a");
+    tc.compilef(r#"[\k]+"#, "").match1f("kk").test_eq("kk");
+    tc.compilef(r#"\k"#, "i").match1f("K").test_eq("K");
+    // From 262 test/annexB/built-ins/RegExp/named-groups/non-unicode-malformed.js
+    tc.compilef(r#"\k<a>(?<=>)a"#, "").match1f("k<a>a").test_eq("k<a>a");
+    tc.compilef(r#"(?<!a>)\k<a>"#, "").match1f("k<a>").test_eq("k<a>");
+    tc.compilef(r#"\k<4>"#, "").match1f("k<4>").test_eq("k<4>");
+    // A named group anywhere in the pattern makes `\k` a backreference.
+    tc.compilef(r#"\k<a>(?<a>b)"#, "").match1f("b").test_eq("b,b");
+
     // Make sure that escapes are parsed correctly in the fast capture group parser.
     // This pattern should fail in unicode mode, because there is a backreference without a capture group.
     // If the `\]` is not handled correctly in the parser, the following `(.)` may be parsed as a capture group.
-    test_parse_fails(r#"/[\](.)]\1/"#);
+    // Without the unicode flag, the \1 is instead a legacy octal escape.
+    test_parse_fails_flags(r#"/[\](.)]\1/"#, "u");
 }
 
 #[test]
 #[rustfmt::skip]
 fn run_regexp_named_groups_unicode_malformed_tc() {
-    // From 262 test/annexB/built-ins/RegExp/named-groups/non-unicode-malformed-lookbehind.js
-    test_parse_fails(r#"\k<a>(?<=>)a"#);
-    test_parse_fails(r#"(?<=>)\k<a>"#);
-    test_parse_fails(r#"\k<a>(?<!a)a"#);
-    test_parse_fails(r#"(?<!a>)\k<a>"#);
+    // From 262 test/built-ins/RegExp/named-groups/unicode-malformed-lookbehind.js
+    test_parse_fails_flags(r#"\k<a>(?<=>)a"#, "u");
+    test_parse_fails_flags(r#"(?<=>)\k<a>"#, "u");
+    test_parse_fails_flags(r#"\k<a>(?<!a)a"#, "u");
+    test_parse_fails_flags(r#"(?<!a>)\k<a>"#, "u");
 
-    // From 262 test/annexB/built-ins/RegExp/named-groups/non-unicode-malformed.js
-    test_parse_fails(r#"\k<a>"#);
-    test_parse_fails(r#"\k<4>"#);
-    test_parse_fails(r#"\k<a"#);
-    test_parse_fails(r#"\k"#);
+    // From 262 test/built-ins/RegExp/named-groups/unicode-malformed.js
+    test_parse_fails_flags(r#"\k<a>"#, "u");
+    test_parse_fails_flags(r#"\k<4>"#, "u");
+    test_parse_fails_flags(r#"\k<a"#, "u");
+    test_parse_fails_flags(r#"\k"#, "u");
 
     // TODO: This test fails, because we accept alphabetic ascii characters in otherwise invalid escapes, due to PCRE tests.
     //test_parse_fails(r#"(?<a>\a)"#);
 
-    test_parse_fails(r#"\k<a>"#);
-    test_parse_fails(r#"\k<a"#);
-    test_parse_fails(r#"\k<a>(<a>x)"#);
-    test_parse_fails(r#"\k<a>\1"#);
-    test_parse_fails(r#"\1(b)\k<a>"#);
+    test_parse_fails_flags(r#"\k<a>"#, "u");
+    test_parse_fails_flags(r#"\k<a"#, "u");
+    test_parse_fails_flags(r#"\k<a>(<a>x)"#, "u");
+    test_parse_fails_flags(r#"\k<a>\1"#, "u");
+    test_parse_fails_flags(r#"\1(b)\k<a>"#, "u");
 
     // From 262 test/language/literals/regexp/named-groups/invalid-duplicate-groupspecifier.js
     test_parse_fails(r#"(?<a>a)(?<a>a)"#);
@@ -1219,6 +1338,24 @@ fn run_regexp_unicode_escape_tc(tc: TestConfig) {
     tc.compilef(r#"\u{000000003f}"#, "").test_succeeds("?");
     tc.compilef(r#"\u{3F}"#, "").test_succeeds("?");
     tc.compilef(r#"\u{10ffff}"#, "").test_succeeds("\u{10ffff}");
+    tc.compilef(r#"\u{10FFFF}"#, "u").test_succeeds("\u{10ffff}");
+    tc.compilef(r#"[\u{10fffe}-\u{10FFFF}]"#, "u").test_succeeds("\u{10ffff}");
+    tc.compilef(r#"\u{1F600}"#, "u").test_succeeds("\u{1F600}");
+    tc.compilef(r#"\u{1F600}"#, "v").test_succeeds("\u{1F600}");
+
+    // Surrogate pairs combine into the astral code point.
+    tc.compilef(r#"^\uD83D\uDE00$"#, "u").test_succeeds("\u{1F600}");
+    tc.compilef(r#"^[\uD83D\uDE00]$"#, "u").test_succeeds("\u{1F600}");
+    tc.compilef(r#"\uD83D\u{1F600}"#, "u").test_fails("\u{1F600}\u{1F600}");
+
+    // Annex B: outside of unicode mode, malformed \x and \u escapes are
+    // identity escapes.
+    tc.compilef(r#"^\u$"#, "").test_succeeds("u");
+    tc.compilef(r#"^\u12$"#, "").test_succeeds("u12");
+    tc.compilef(r#"^\x1$"#, "").test_succeeds("x1");
+    tc.compilef(r#"^[\x]$"#, "").test_succeeds("x");
+    tc.compilef(r#"^\u{2}$"#, "").test_fails("uu");
+    tc.compilef(r#"^\u{+41}$"#, "").test_fails("A");
 }
 
 #[test]
@@ -1232,6 +1369,60 @@ fn run_regexp_unicode_property_classes_tc(tc: TestConfig) {
     tc.compilef(r#"\p{Script=Buhid}"#, "").test_succeeds("ᝀᝁᝂᝃᝄᝅᝆᝇᝈᝉᝊᝋᝌᝍᝎᝏᝐᝑ\u{1752}\u{1753}ᝀᝁᝂᝃᝄᝅᝆᝇᝈᝉᝊᝋᝌᝍᝎᝏᝐᝑ\u{1752}\u{1753}");
 }
 
+#[test]
+fn unicode_property_age() {
+    test_with_configs(unicode_property_age_tc)
+}
+
+fn unicode_property_age_tc(tc: TestConfig) {
+    // U+0041 is from 1.1, U+1F6F6 (canoe) from 9.0, U+20BF (bitcoin sign)
+    // from 10.0, U+32FF (square era name reiwa) from 12.1, and U+1FAD0
+    // (blueberries) from 13.0.
+    tc.compilef(r"^\p{Age=1.1}$", "u").test_succeeds("A");
+    tc.compilef(r"^\p{Age=V1_1}$", "u").test_succeeds("A");
+    tc.compilef(r"^\p{Age=9.0}$", "u")
+        .test_succeeds("\u{1F6F6}");
+    tc.compilef(r"^\p{age=9.0}$", "u").test_fails("A");
+    tc.compilef(r"^\p{Age=10.0}$", "u")
+        .test_succeeds("\u{20BF}");
+    tc.compilef(r"^\p{Age=12.1}$", "u")
+        .test_succeeds("\u{32FF}");
+    tc.compilef(r"^\p{Age=13.0}$", "u")
+        .test_succeeds("\u{1FAD0}");
+    tc.compilef(r"^\p{Age=2.0}$", "u")
+        .test_succeeds("\u{10FFFD}");
+
+    // Ages are not cumulative, and unassigned code points have no age.
+    tc.compilef(r"^\p{Age=9.0}$", "u").test_fails("\u{20BF}");
+    for age in ["1.1", "6.0", "13.0"] {
+        let re = tc.compilef(&format!(r"^\p{{Age={}}}$", age), "u");
+        re.test_fails("\u{0378}");
+        re.test_fails("\u{1FAE0}");
+    }
+
+    // Negation with \P and with !=.
+    tc.compilef(r"^\P{Age=9.0}$", "u").test_succeeds("A");
+    tc.compilef(r"^\P{Age=9.0}$", "u").test_fails("\u{1F6F6}");
+    tc.compilef(r"^\p{Age!=9.0}$", "u").test_succeeds("A");
+    tc.compilef(r"^\p{Age!=9.0}$", "u").test_fails("\u{1F6F6}");
+    tc.compilef(r"^\P{Age!=9.0}$", "u")
+        .test_succeeds("\u{1F6F6}");
+    tc.compilef(r"^\p{gc!=Lu}$", "u").test_succeeds("a");
+    tc.compilef(r"^\p{gc!=Lu}$", "u").test_fails("A");
+
+    // In brackets, including v-mode set operations.
+    tc.compilef(r"^[\p{Age=10.0}x]+$", "u")
+        .test_succeeds("x\u{20BF}");
+    tc.compilef(r"^[^\p{Age!=10.0}]$", "u")
+        .test_succeeds("\u{20BF}");
+    tc.compilef(r"^[\p{Age=9.0}&&\p{So}]$", "v")
+        .test_succeeds("\u{1F6F6}");
+    tc.compilef(r"^[\p{So}--\p{Age=9.0}]$", "v")
+        .test_fails("\u{1F6F6}");
+    tc.compilef(r"^[\P{Age!=13.0}]$", "v")
+        .test_succeeds("\u{1FAD0}");
+}
+
 #[test]
 fn property_escapes_invalid() {
     // From 262 test/built-ins/RegExp/property-escapes/
@@ -1447,6 +1638,56 @@ fn unicode_escape_property_binary_assigned_tc(tc: TestConfig) {
     }
 }
 
+#[test]
+fn unicode_escape_property_binary_spot_checks() {
+    test_with_configs(unicode_escape_property_binary_spot_checks_tc)
+}
+
+fn unicode_escape_property_binary_spot_checks_tc(tc: TestConfig) {
+    // Each property, with code points which have it and which do not.
+    let cases: [(&str, &[&str], &[&str]); 5] = [
+        (
+            "White_Space",
+            &[" ", "\t", "\u{A0}", "\u{3000}"],
+            &["a", "_", "\u{200B}"],
+        ),
+        (
+            "Alphabetic",
+            &["a", "Z", "\u{E9}", "\u{4E00}"],
+            &["1", "-", "\u{300}"],
+        ),
+        (
+            "Uppercase",
+            &["A", "\u{C9}", "\u{2160}"],
+            &["a", "1", "\u{4E00}"],
+        ),
+        (
+            "Case_Ignorable",
+            &["'", ".", "\u{300}", "\u{AD}"],
+            &["a", "A", " "],
+        ),
+        (
+            "Default_Ignorable_Code_Point",
+            &["\u{AD}", "\u{200B}", "\u{FE0F}"],
+            &["a", " ", "\u{300}"],
+        ),
+    ];
+    for (name, have, have_not) in cases {
+        for flags in ["u", "v"] {
+            let positive = tc.compilef(&format!("^\\p{{{}}}$", name), flags);
+            let negative = tc.compilef(&format!("^\\P{{{}}}$", name), flags);
+            for c in have {
+                positive.test_succeeds(c);
+                negative.test_fails(c);
+            }
+            for c in have_not {
+                positive.test_fails(c);
+                negative.test_succeeds(c);
+            }
+        }
+    }
+}
+
 #[test]
 fn unicode_escape_id_start() {
     test_with_configs(unicode_escape_id_start_tc)
@@ -1487,6 +1728,37 @@ fn unicode_escape_id_continue_tc(tc: TestConfig) {
     }
 }
 
+#[test]
+fn test_quantified_lookaheads_in_annex_b() {
+    test_with_configs(test_quantified_lookaheads_in_annex_b_tc)
+}
+
+fn test_quantified_lookaheads_in_annex_b_tc(tc: TestConfig) {
+    // Annex B: outside of unicode mode, a lookahead may be quantified. An
+    // iteration which matches empty ends a loop once its minimum is reached,
+    // so an optional lookahead is skipped and a required one is tested once.
+    tc.compile(r"(?=a)*b").match1f("b").test_eq("b");
+    tc.compile(r"(?=a)*a").match1f("a").test_eq("a");
+    tc.compile(r"(?=a)+a").test_fails("b");
+    tc.compile(r"(?!a)+b").match1f("ab").test_eq("b");
+    tc.compile(r"x(?=a){3,}").match1f("xa").test_eq("x");
+    tc.compile(r"x(?=a){3,}?").test_fails("xb");
+    tc.compile(r"x(?!a){0}a").match1f("xa").test_eq("xa");
+    // Groups in an optional lookahead do not participate.
+    tc.compile(r"(?=(a))?").match1f("a").test_eq(",");
+    tc.compile(r"(?=(a))*").match1f("a").test_eq(",");
+    tc.compile(r"(?=(a))+").match1f("a").test_eq(",a");
+    tc.compile(r"(?=(a)){2}").match1f("a").test_eq(",a");
+    tc.compile(r"(?=(a))+?").match1f("a").test_eq(",a");
+    tc.compile(r"(?!(a))*b").match1f("b").test_eq("b,");
+    // Searching does not loop forever on the empty iterations.
+    assert_eq!(
+        tc.compile(r"(?=\w)*").match_all("ab c"),
+        ["", "", "", "", ""]
+    );
+    assert_eq!(tc.compile(r"(?=\w)+\w").match_all("ab c"), ["a", "b", "c"]);
+}
+
 #[test]
 fn test_valid_character_sets_in_annex_b() {
     test_with_configs(test_valid_character_sets_in_annex_b_tc)
@@ -1510,3 +1782,4754 @@ fn test_valid_character_sets_in_annex_b_tc(tc: TestConfig) {
     tc.test_match_fails(regexp, "", "f");
     tc.test_match_fails(regexp, "", " ");
 }
+
+#[test]
+fn test_replace() {
+    test_with_configs(test_replace_tc)
+}
+
+fn test_replace_tc(tc: TestConfig) {
+    let re = tc.compile(r"o");
+    re.replace("foo boo", "0").test_eq("f0o boo");
+    re.replace_all("foo boo", "0").test_eq("f00 b00");
+    re.replace("xyz", "0").test_eq("xyz");
+    re.replace_all("xyz", "0").test_eq("xyz");
+
+    // Empty matches.
+    let re = tc.compile(r"a*");
+    re.replace_all("baac", "-").test_eq("-b--c-");
+    re.replace_all("", "-").test_eq("-");
+    tc.compile(r"")
+        .replace_all("a\u{1F600}b", "-")
+        .test_eq("-a-\u{1F600}-b-");
+
+    // Special patterns.
+    let re = tc.compile(r"(\w+)\s(\w+)");
+    re.replace("John Smith", "$2, $1").test_eq("Smith, John");
+    re.replace("John Smith", "$$1").test_eq("$1");
+    re.replace("John Smith", "[$&]").test_eq("[John Smith]");
+    re.replace("- John Smith +", "[$`]").test_eq("- [- ] +");
+    re.replace("- John Smith +", "[$']").test_eq("- [ +] +");
+    re.replace("John Smith", "$0 $3 $ $").test_eq("$0 $3 $ $");
+    re.replace("John Smith", "$01$02").test_eq("JohnSmith");
+    re.replace("John Smith", "$<name>").test_eq("$<name>");
+
+    // Two digit references fall back to one digit if out of range.
+    let re = tc.compile(r"(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)");
+    re.replace("abcdefghijk", "$11").test_eq("k");
+    re.replace("abcdefghijk", "$12").test_eq("a2");
+    re.replace("abcdefghijk", "$10").test_eq("j");
+
+    // Unmatched groups expand to nothing.
+    let re = tc.compile(r"(a)|(b)");
+    re.replace_all("ab", "[$1$2]").test_eq("[a][b]");
+
+    // Named groups.
+    let re = tc.compile(r"(?<first>\w+)\s(?<last>\w+)");
+    re.replace("John Smith", "$<last>, $<first>")
+        .test_eq("Smith, John");
+    re.replace("John Smith", "$<missing>!").test_eq("!");
+    re.replace("John Smith", "$<first").test_eq("$<first");
+}
+
+#[test]
+fn test_replacen() {
+    test_with_configs(test_replacen_tc)
+}
+
+fn test_replacen_tc(tc: TestConfig) {
+    let re = tc.compile(r"o");
+    let (s, n) = re.replacen("foo boo", 3, "0");
+    s.test_eq("f00 b0o");
+    assert_eq!(n, 3);
+    let (s, n) = re.replacen("foo boo", 10, "0");
+    s.test_eq("f00 b00");
+    assert_eq!(n, 4);
+    let (s, n) = re.replacen("foo boo", 0, "0");
+    s.test_eq("f00 b00");
+    assert_eq!(n, 4);
+    let (s, n) = re.replacen("xyz", 0, "0");
+    s.test_eq("xyz");
+    assert_eq!(n, 0);
+
+    // Empty matches count once each.
+    let re = tc.compile(r"a*");
+    let (s, n) = re.replacen("baac", 2, "-");
+    s.test_eq("-b-c");
+    assert_eq!(n, 2);
+    let (s, n) = re.replacen("baac", 0, "-");
+    s.test_eq("-b--c-");
+    assert_eq!(n, 4);
+    let (s, n) = tc.compile(r"").replacen("a\u{1F600}b", 3, "-");
+    s.test_eq("-a-\u{1F600}-b");
+    assert_eq!(n, 3);
+
+    // Substitutions.
+    let (s, n) = tc.compile(r"(\w)(\d)").replacen("a1 b2 c3", 2, "$2$1");
+    s.test_eq("1a 2b c3");
+    assert_eq!(n, 2);
+}
+
+#[test]
+fn test_replace_all_with() {
+    test_with_configs(test_replace_all_with_tc)
+}
+
+fn test_replace_all_with_tc(tc: TestConfig) {
+    let re = tc.compile(r"(?<key>\w+)=(?<val>\w*)");
+    re.replace_all_with("a=1, bb=22, c=", |m, input| {
+        let key = &input[m.named_group("key").unwrap()];
+        let value = &input[m.named_group("val").unwrap()];
+        format!("{}:{}", value, key)
+    })
+    .test_eq("1:a, 22:bb, :c");
+
+    let mut count = 0;
+    tc.compile(r"\d")
+        .replace_all_with("x1y2z3", |m, input| {
+            count += 1;
+            format!("<{}>", &input[m.range()])
+        })
+        .test_eq("x<1>y<2>z<3>");
+    assert_eq!(count, 3);
+
+    // No matches leaves input unchanged.
+    tc.compile(r"q")
+        .replace_all_with("abc", |_, _| unreachable!())
+        .test_eq("abc");
+
+    // Zero-width matches advance by a code point.
+    tc.compilef(r"", "u")
+        .replace_all_with("\u{1F600}\u{E9}", |m, _| format!("[{}]", m.start()))
+        .test_eq("[0]\u{1F600}[4]\u{E9}[6]");
+}
+
+#[test]
+fn test_split() {
+    test_with_configs(test_split_tc)
+}
+
+fn test_split_tc(tc: TestConfig) {
+    let re = tc.compile(r",");
+    re.split("a,b,c").test_eq(vec!["a", "b", "c"]);
+    re.split(",a,,b,").test_eq(vec!["", "a", "", "b", ""]);
+    re.split("abc").test_eq(vec!["abc"]);
+    re.split("").test_eq(vec![""]);
+    re.splitn("a,b,c", 2).test_eq(vec!["a", "b"]);
+    re.splitn("a,b,c", 0).test_eq(vec![]);
+
+    // Captures are spliced into the output.
+    let re = tc.compile(r"\s*(,)\s*");
+    re.split("a , b,c").test_eq(vec!["a", ",", "b", ",", "c"]);
+    re.splitn("a , b,c", 3).test_eq(vec!["a", ",", "b"]);
+    tc.compile(r"(-)|(\+)")
+        .split("1-2+3")
+        .test_eq(vec!["1", "-", "", "2", "", "+", "3"]);
+
+    // Zero-width separators split at each character.
+    let re = tc.compile(r"");
+    re.split("abc").test_eq(vec!["a", "b", "c"]);
+    re.split("").test_eq(vec![]);
+    tc.compilef(r"", "u")
+        .split("\u{1F600}x\u{E9}")
+        .test_eq(vec!["\u{1F600}", "x", "\u{E9}"]);
+    tc.compile(r"x*").split("axxb").test_eq(vec!["a", "b"]);
+    tc.compile(r"(?:)").split("ab").test_eq(vec!["a", "b"]);
+    tc.compile(r"\b")
+        .split("ab cd")
+        .test_eq(vec!["ab", " ", "cd"]);
+}
+
+#[test]
+fn test_sticky() {
+    test_with_configs(test_sticky_tc)
+}
+
+fn test_sticky_tc(tc: TestConfig) {
+    let re = tc.compilef(r"\d", "y");
+    re.match_all("123a45").test_eq(vec!["1", "2", "3"]);
+    re.match_all("a12").test_eq(vec![]);
+    assert_eq!(re.match_all_from("a12b3", 1), vec![1..2, 2..3]);
+    assert_eq!(re.match_all_from("a12b3", 3), vec![]);
+
+    // Empty matches advance by a character.
+    tc.compilef(r"a*", "y")
+        .match_all("aab")
+        .test_eq(vec!["aa", "", ""]);
+
+    // The start predicate must not skip ahead.
+    tc.compilef(r"abc", "y").test_fails("xabc");
+    tc.compilef(r"[xy]z", "y").test_fails("_xz");
+
+    // ^ is still only satisfied at the start (or after a line terminator).
+    let re = tc.compilef(r"^a", "y");
+    assert_eq!(re.match_all_from("aa", 1), vec![]);
+    let re = tc.compilef(r"^a", "my");
+    assert_eq!(re.match_all_from("a\na", 2), vec![2..3]);
+
+    // Splitting ignores the sticky flag.
+    tc.compilef(r",", "y")
+        .split("a,b,c")
+        .test_eq(vec!["a", "b", "c"]);
+
+    // Replacement stops at the first failure.
+    tc.compilef(r"a", "y")
+        .replace_all("aaba", "x")
+        .test_eq("xxba");
+
+    // find_sticky works without the flag.
+    let re = tc.compile(r"\w+");
+    assert_eq!(re.find_sticky("ab cd", 0), Some("ab"));
+    assert_eq!(re.find_sticky("ab cd", 2), None);
+    assert_eq!(re.find_sticky("ab cd", 3), Some("cd"));
+    assert_eq!(re.find_sticky("ab cd", 10), None);
+}
+
+#[test]
+fn test_find_anchored() {
+    // Drive a simple tokenizer.
+    let re = regress::Regex::new(r"\s*([a-z]+|\d+|[()+*])").unwrap();
+    let text = "(ab + 12) * c";
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while let Some(m) = re.find_anchored(text, pos) {
+        tokens.push(&text[m.group(1).unwrap()]);
+        pos = m.end();
+    }
+    assert_eq!(tokens, vec!["(", "ab", "+", "12", ")", "*", "c"]);
+    assert_eq!(pos, text.len());
+
+    // Later offsets are never tried.
+    let re = regress::Regex::new(r"\d+").unwrap();
+    assert!(re.find_anchored("ab12", 1).is_none());
+    assert_eq!(re.find_anchored("ab12", 2).unwrap().range(), 2..4);
+    assert!(re.find_anchored("ab12", 5).is_none());
+
+    // Anchoring does not depend on the multiline flag, and ^ keeps its
+    // meaning.
+    let re = regress::Regex::new(r"b").unwrap();
+    assert_eq!(re.find_anchored("a\nb", 2).unwrap().range(), 2..3);
+    let re = regress::Regex::new(r"^b").unwrap();
+    assert!(re.find_anchored("a\nb", 2).is_none());
+    let re = regress::Regex::with_flags(r"^b", "m").unwrap();
+    assert!(re.find_anchored("a\nb", 2).is_some());
+
+    // Lookbehinds may see text before the start.
+    let re = regress::Regex::new(r"(?<=a)b").unwrap();
+    assert!(re.find_anchored("ab", 1).is_some());
+}
+
+#[test]
+fn test_find_into() {
+    let re = regress::Regex::new(r"(?<key>[a-z]+)=(\d+)?").unwrap();
+    let text = "a=1 bc= d=42";
+    let mut caps = regress::Captures::new();
+    let mut found = Vec::new();
+    let mut start = 0;
+    while re.find_into(text, start, &mut caps) {
+        assert_eq!(caps.get(0), Some(caps.range()));
+        found.push((
+            &text[caps.name("key").unwrap()],
+            caps.get(2).map(|r| &text[r]),
+        ));
+        start = caps.range().end;
+    }
+    assert_eq!(
+        found,
+        vec![("a", Some("1")), ("bc", None), ("d", Some("42"))]
+    );
+
+    // A failed search leaves the captures empty.
+    assert_eq!(caps.iter().count(), 0);
+    assert_eq!(caps.name("key"), None);
+
+    // Results agree with captures(), and the buffer may be shared between
+    // regexes with different group counts.
+    let re2 = regress::Regex::new(r"(x)(y)(z)").unwrap();
+    assert!(re2.find_into("wxyz", 0, &mut caps));
+    assert_eq!(caps, re2.captures("wxyz").unwrap());
+    assert_eq!(caps.name("key"), None);
+    assert!(re.find_into(text, 0, &mut caps));
+    assert_eq!(caps, re.captures(text).unwrap());
+
+    // The sticky flag is honored.
+    let re = regress::Regex::with_flags(r"\d", "y").unwrap();
+    assert!(!re.find_into("a1", 0, &mut caps));
+    assert!(re.find_into("a1", 1, &mut caps));
+    assert_eq!(caps.range(), 1..2);
+}
+
+#[test]
+fn test_empty_matches_advance_by_code_point() {
+    test_with_configs(test_empty_matches_advance_by_code_point_tc)
+}
+
+fn test_empty_matches_advance_by_code_point_tc(tc: TestConfig) {
+    // After an empty match the search resumes at the next code point, so no
+    // offset lands inside U+1F4A9, which is four bytes in UTF-8. It is also
+    // one code point under the non-unicode semantics; a str cannot be split
+    // between the two UTF-16 code units of a surrogate pair.
+    let text = "a\u{1F4A9}b";
+    for flags in ["", "u", "v"] {
+        let re = tc.compilef(r"a*", flags);
+        assert_eq!(re.match_all_from(text, 0), vec![0..1, 1..1, 5..5, 6..6]);
+        let re = tc.compilef(r"(?:)", flags);
+        assert_eq!(re.match_all_from(text, 0), vec![0..0, 1..1, 5..5, 6..6]);
+        let re = tc.compilef(r"\u{1F4A9}*", flags);
+        assert_eq!(re.match_all_from(text, 0), vec![0..0, 1..5, 5..5, 6..6]);
+        tc.compilef(r"x*", flags)
+            .replace_all(text, "-")
+            .test_eq("-a-\u{1F4A9}-b-");
+        for r in re.match_all_from(text, 0) {
+            assert!(text.is_char_boundary(r.start) && text.is_char_boundary(r.end));
+        }
+    }
+}
+
+#[test]
+fn test_find_utf16() {
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+    let find = |pattern: &str, flags: &str, text: &[u16]| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .find_utf16(text)
+            .map(|m| m.range())
+    };
+
+    // Offsets are in code units.
+    let text = utf16("a\u{1F600}bc\u{E9}d");
+    assert_eq!(find(r"b", "", &text), Some(3..4));
+    assert_eq!(find(r"\u{1F600}b", "u", &text), Some(1..4));
+    assert_eq!(find(r"\uD83D\uDE00", "u", &text), Some(1..3));
+    assert_eq!(find(r"c.d", "", &text), Some(4..7));
+    assert_eq!(find(r"\u{C9}", "i", &text), Some(5..6));
+    assert_eq!(find(r"\uD83D", "u", &text), None);
+
+    // Without the 'u' flag, each code unit of a surrogate pair is a char, and
+    // astral chars in the pattern are pairs of code units.
+    let pair = utf16("\u{1F600}");
+    for (pattern, flags, expected) in [
+        (r"^.$", "", None),
+        (r"^..$", "", Some(0..2)),
+        (r"\uD83D", "", Some(0..1)),
+        (r"\uDE00", "", Some(1..2)),
+        (r"\uD83D\uDE00", "", Some(0..2)),
+        (r"^[^a]$", "", None),
+        ("[\u{1F600}]", "", Some(0..1)),
+        ("^\u{1F600}$", "", Some(0..2)),
+        (r"^.$", "u", Some(0..2)),
+        (r"^..$", "u", None),
+        (r"\uD83D", "u", None),
+        (r"\uDE00", "u", None),
+        (r"\uD83D\uDE00", "u", Some(0..2)),
+        (r"^[^a]$", "u", Some(0..2)),
+        ("[\u{1F600}]", "u", Some(0..2)),
+        (r"^.$", "v", Some(0..2)),
+    ] {
+        assert_eq!(
+            find(pattern, flags, &pair),
+            expected,
+            "/{}/{}",
+            pattern,
+            flags
+        );
+    }
+    // A lone surrogate is a char either way.
+    for flags in ["", "u"] {
+        assert_eq!(find(r"^.$", flags, &[0xD83D]), Some(0..1));
+        assert_eq!(find(r"^.$", flags, &[0xDE00]), Some(0..1));
+        assert_eq!(find(r"^..$", flags, &[0xDE00, 0xD83D]), Some(0..2));
+    }
+    assert_eq!(find(r"\uD83D", "", &[0xD83D, 0x61]), Some(0..1));
+    assert_eq!(find(r"\uD83D", "u", &[0xD83D, 0x61]), Some(0..1));
+    let re = regress::Regex::new("(\u{1F600})\\1").unwrap();
+    let m = re.find_utf16(&utf16("x\u{1F600}\u{1F600}")).unwrap();
+    assert_eq!((m.range(), m.group(1)), (1..5, Some(1..3)));
+
+    // The step budget is honored.
+    let re = regress::RegexBuilder::new(r"(a+)+$")
+        .step_budget(1_000)
+        .build()
+        .unwrap();
+    let text = utf16("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa!");
+    assert_eq!(
+        re.try_find_utf16(&text).unwrap_err(),
+        regress::BudgetExceeded
+    );
+    assert_eq!(
+        re.try_find_utf16(&text[..5]).unwrap().unwrap().range(),
+        0..5
+    );
+
+    // Lone surrogates are matched as individual code points.
+    let lone = [0x61, 0xD800, 0x62, 0xDFFF];
+    assert_eq!(find(r"a.b", "", &lone), Some(0..3));
+    assert_eq!(find(r"a.b", "u", &lone), Some(0..3));
+    assert_eq!(find(r"[\uD800-\uDFFF]", "", &lone), Some(1..2));
+    assert_eq!(find(r"\uDFFF$", "", &lone), Some(3..4));
+    assert_eq!(find(r"[^a-z]b", "", &lone), Some(1..3));
+    assert_eq!(find(r"(?<=\uD800)b", "", &lone), Some(2..3));
+    assert_eq!(find(r"(.)b\1", "", &[0xD800, 0x62, 0xD800]), Some(0..3));
+    assert_eq!(find(r"(.)\1", "i", &[0xD800, 0xD800]), Some(0..2));
+    assert_eq!(find(r"^.$", "u", &[0xDE00]), Some(0..1));
+    assert_eq!(
+        find(r"\uD800\u{1F600}", "u", &[0xD800, 0xD83D, 0xDE00]),
+        Some(0..3)
+    );
+    // Property escapes which hold the surrogates match lone ones.
+    for (pattern, flags, expected) in [
+        (r"\p{Any}", "u", Some(0..1)),
+        (r"\p{Cs}", "u", Some(0..1)),
+        (r"\p{gc=Surrogate}", "u", Some(0..1)),
+        (r"[\p{Cs}]", "u", Some(0..1)),
+        (r"\p{Cs}", "ui", Some(0..1)),
+        (r"[\p{Any}--\p{L}]", "v", Some(0..1)),
+        (r"\P{L}", "u", Some(0..1)),
+        (r"\P{Cs}", "u", None),
+        (r"[^\p{Cs}]", "u", None),
+        (r"\p{L}", "u", None),
+    ] {
+        assert_eq!(
+            find(pattern, flags, &[0xD800]),
+            expected,
+            "/{}/{}",
+            pattern,
+            flags
+        );
+    }
+    assert_eq!(find(r"\P{Cs}", "u", &[0xD800, 0x61]), Some(1..2));
+
+    // Starting offsets and captures.
+    let re = regress::Regex::new(r"x(\d)?").unwrap();
+    let text = utf16("\u{1F600}x1x");
+    let m = re.find_utf16_from(&text, 0).unwrap();
+    assert_eq!((m.range(), m.group(1)), (2..4, Some(3..4)));
+    let m = re.find_utf16_from(&text, 3).unwrap();
+    assert_eq!((m.range(), m.group(1)), (4..5, None));
+    assert_eq!(re.find_utf16_from(&text, 1).unwrap().range(), 2..4);
+    assert!(re.find_utf16_from(&text, 6).is_none());
+    // With the 'u' flag, a start between the halves of a pair is invalid.
+    let re = regress::Regex::with_flags(r"x(\d)?", "u").unwrap();
+    assert!(re.find_utf16_from(&text, 1).is_none());
+    assert_eq!(re.find_utf16_from(&text, 2).unwrap().range(), 2..4);
+    assert_eq!(
+        regress::Regex::new(r"$")
+            .unwrap()
+            .find_utf16_from(&text, 5)
+            .map(|m| m.range()),
+        Some(5..5)
+    );
+
+    // The sticky flag is honored.
+    let re = regress::Regex::with_flags(r"x", "y").unwrap();
+    assert!(re.find_utf16_from(&text, 0).is_none());
+    assert_eq!(re.find_utf16_from(&text, 2).unwrap().range(), 2..3);
+}
+
+#[test]
+fn test_find_iter_utf16() {
+    // The matches agree with those in the str, counted in code units.
+    let text = "a\u{1F600}bb \u{E9}x\u{1F600} ab";
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let to_units = |r: std::ops::Range<usize>| {
+        let start = text[..r.start].encode_utf16().count();
+        start..start + text[r].encode_utf16().count()
+    };
+    for (pattern, flags) in [
+        (r"b+", ""),
+        (r"(\w)(\S)?", "u"),
+        (r"x?", "u"),
+        (r"(?<=\s)\S", "u"),
+        (r"b|\s", "y"),
+    ] {
+        let re = regress::Regex::with_flags(pattern, flags).unwrap();
+        let prepared = re.prepare_utf16(&units);
+        assert_eq!(prepared.units(), &units[..]);
+        let expected: Vec<_> = re
+            .find_iter(text)
+            .map(|m| {
+                (
+                    to_units(m.range()),
+                    m.captures
+                        .into_iter()
+                        .map(|g| g.map(to_units))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        let actual: Vec<_> = re
+            .find_iter_utf16(&prepared)
+            .map(|m| (m.range(), m.captures))
+            .collect();
+        assert_eq!(actual, expected, "/{}/{}", pattern, flags);
+    }
+
+    // Text prepared for a regex without the 'u' flag may be searched by one
+    // with it, and the reverse.
+    let plain = regress::Regex::new(r"^.").unwrap();
+    let unicode = regress::Regex::with_flags(r"^.", "u").unwrap();
+    let pair: Vec<u16> = "\u{1F600}".encode_utf16().collect();
+    fn ranges(
+        re: &regress::Regex,
+        prepared: &regress::PreparedUtf16,
+    ) -> Vec<std::ops::Range<usize>> {
+        re.find_iter_utf16(prepared).map(|m| m.range()).collect()
+    }
+    assert_eq!(ranges(&unicode, &plain.prepare_utf16(&pair)), vec![0..2]);
+    assert_eq!(ranges(&plain, &unicode.prepare_utf16(&pair)), vec![0..1]);
+
+    // Empty matches step over whole code points, which without the 'u' flag
+    // are code units.
+    let re = regress::Regex::new(r"").unwrap();
+    assert_eq!(
+        ranges(&re, &re.prepare_utf16(&pair)),
+        vec![0..0, 1..1, 2..2]
+    );
+    let re = regress::Regex::with_flags(r"", "u").unwrap();
+    let units = [0xD83D, 0xDE00, 0xD800, 0x61];
+    assert_eq!(
+        ranges(&re, &re.prepare_utf16(&units)),
+        vec![0..0, 2..2, 3..3, 4..4]
+    );
+}
+
+#[test]
+fn test_is_match() {
+    let patterns = [
+        (r"abc", ""),
+        (r"a.c", ""),
+        (r"a.c", "s"),
+        (r"^abc$", ""),
+        (r"^b", "m"),
+        (r"c$", "m"),
+        (r"^$", "m"),
+        (r"\bfoo\b", ""),
+        (r"\Boo\B", ""),
+        (r"\b\u{E9}t\u{E9}\b", "iu"),
+        (r"(a|bc)*d", ""),
+        (r"(?:ab|a)(?:bc|c)$", ""),
+        (r"x{2,3}y", ""),
+        (r"(?:xy){2}z", ""),
+        (r"(a*)*b", ""),
+        (r"(?:a?){3}a{3}", ""),
+        (r"(?:){5}q", ""),
+        (r"[^a-z]{2,}", ""),
+        (r"[\u{1F600}-\u{1F64F}]+x", "u"),
+        (r"\u{1F600}", "u"),
+        (r"caf\u{E9}", ""),
+        (r"\p{Greek}+", "u"),
+        (r"\P{L}\d", "u"),
+        (r"[\p{L}--[a-z]]", "v"),
+        (r"HELLO", "i"),
+        (r"\u{212A}", "iu"),
+        (r"[ab][cd]", ""),
+        (r"[+-]?\d+(?:\.\d*)?(?:e[+-]?\d+)?", "i"),
+        (r"a", "y"),
+        (r"(?:a|b)+", "y"),
+    ];
+    let texts = [
+        "",
+        "abc",
+        "xabcx",
+        "a\nc",
+        "a\nb\nc",
+        "foo",
+        " food",
+        "boo",
+        "\u{C9}T\u{C9} ",
+        "bcbcad",
+        "abc",
+        "xxxy",
+        "xyxyz",
+        "aaab",
+        "aaaaaa",
+        "q",
+        "AB12",
+        "\u{1F600}\u{1F610}x",
+        "caf\u{E9}",
+        "\u{3B1}\u{3B2}",
+        "!1",
+        "Hello",
+        "\u{3A9}",
+        "k",
+        "bd",
+        "-12.5E+3",
+        "ba",
+    ];
+    for (pattern, flags) in patterns.iter() {
+        let re = regress::Regex::with_flags(pattern, *flags).unwrap();
+        // Short texts are backtracked, and longer ones run the DFA, whose
+        // states are kept between the calls.
+        for text in texts.iter() {
+            for padded in [
+                text.to_string(),
+                format!("{}{}", "-=".repeat(20), text),
+                format!(
+                    "{}
+{}{}",
+                    text,
+                    "bc".repeat(20),
+                    text
+                ),
+            ] {
+                assert_eq!(
+                    re.is_match(&padded),
+                    re.find(&padded).is_some(),
+                    "pattern /{}/{} on {:?}",
+                    pattern,
+                    flags,
+                    padded
+                );
+            }
+        }
+    }
+
+    // Non-regular patterns fall back to the backtracker.
+    assert!(regress::Regex::new(r"(a)\1").unwrap().is_match("xaa"));
+    assert!(!regress::Regex::new(r"a(?!b)").unwrap().is_match("ab"));
+    assert!(regress::Regex::new(r"(?<=a)b").unwrap().is_match("ab"));
+
+    // Patterns which would backtrack exponentially are fast.
+    let re = regress::Regex::new(r"^(a|a?)+$").unwrap();
+    assert!(!re.is_match(&format!("{}b", "a".repeat(10_000))));
+
+    // Patterns with many states exceed the cache, which is flushed.
+    let re = regress::Regex::new(r"[ab]*a[ab]{14}c").unwrap();
+    let text: String = (0..20_000u32)
+        .map(|i| if i.count_ones() % 2 == 0 { 'a' } else { 'b' })
+        .collect();
+    assert!(!re.is_match(&text));
+    assert!(re.is_match(&format!("{}c", text)));
+}
+
+#[test]
+fn test_is_match_at() {
+    let patterns = [
+        (r"^a", ""),
+        (r"^a", "m"),
+        (r"a$", "m"),
+        (r"\ba", ""),
+        (r"(?<=b)a", ""),
+        (r"(?<!b)a", ""),
+        (r"a(?=\n)", ""),
+        (r"(?<=^|\n)a(?!a)", ""),
+        (r"(a)b\1", ""),
+        (r"(?<x>a)\k<x>", "i"),
+        (r"a", "y"),
+        (r"(?<=a)a", "y"),
+    ];
+    let text = "aba\naab aAa";
+    for (pattern, flags) in patterns.iter() {
+        let re = regress::Regex::with_flags(pattern, *flags).unwrap();
+        for start in 0..=text.len() + 1 {
+            assert_eq!(
+                re.is_match_at(text, start),
+                re.find_from(text, start).next().is_some(),
+                "pattern /{}/{} at {}",
+                pattern,
+                flags,
+                start
+            );
+        }
+    }
+    let re = regress::Regex::new(r"\u{E9}").unwrap();
+    assert!(re.is_match_at("\u{E9}", 0));
+    assert!(!re.is_match_at("\u{E9}", 2));
+}
+
+#[test]
+fn test_find_iter_from() {
+    let ranges = |re: &regress::Regex, text: &str, start: usize| {
+        re.find_iter_from(text, start)
+            .map(|m| m.range())
+            .collect::<Vec<_>>()
+    };
+    let re = regress::Regex::new(r"\w+").unwrap();
+    let text = "ab cd ef";
+    assert_eq!(ranges(&re, text, 0), vec![0..2, 3..5, 6..8]);
+    assert_eq!(ranges(&re, text, 4), vec![4..5, 6..8]);
+    assert_eq!(ranges(&re, text, 8), vec![]);
+    assert_eq!(ranges(&re, text, 100), vec![]);
+
+    // Starts inside a multibyte char are rounded up to the next char.
+    let re = regress::Regex::new(r".").unwrap();
+    let text = "\u{E9}\u{1F600}x";
+    assert_eq!(ranges(&re, text, 1), vec![2..6, 6..7]);
+    assert_eq!(ranges(&re, text, 3), vec![6..7]);
+    assert_eq!(ranges(&re, text, 5), vec![6..7]);
+    assert_eq!(re.find_from(text, 1).next().unwrap().range(), 2..6);
+    assert!(re.is_match_at(text, 5));
+    let sticky = regress::Regex::with_flags(r".", "y").unwrap();
+    assert_eq!(ranges(&sticky, text, 1), vec![2..6, 6..7]);
+
+    // The text before the start is still visible.
+    let re = regress::Regex::new(r"(?<=a)b|^c|\bd").unwrap();
+    assert_eq!(ranges(&re, "abcxd d", 1), vec![1..2, 6..7]);
+}
+
+#[cfg(feature = "ir-visitor")]
+#[test]
+fn test_walk_ir() {
+    use regress::{IrAssertion, IrNode, IrVisitor};
+
+    // Records the walk as a string, with children in parentheses.
+    #[derive(Default)]
+    struct Recorder(String);
+    impl IrVisitor for Recorder {
+        fn enter(&mut self, node: &IrNode<'_>) {
+            let desc = match node {
+                IrNode::Empty => "empty".to_string(),
+                &IrNode::Literal { c, icase } => {
+                    let c = char::from_u32(c).unwrap();
+                    if icase {
+                        format!("{}/i", c)
+                    } else {
+                        c.to_string()
+                    }
+                }
+                IrNode::Dot { dot_all } => format!("dot{}", if *dot_all { "/s" } else { "" }),
+                IrNode::Class { negated } => format!("class{}", if *negated { "^" } else { "" }),
+                IrNode::Concatenation => "cat".to_string(),
+                IrNode::Alternation => "alt".to_string(),
+                IrNode::Group { index, name } => format!("group{}{:?}", index, name),
+                IrNode::Quantifier { min, max, greedy } => {
+                    format!("quant{{{},{:?},{}}}", min, max, greedy)
+                }
+                IrNode::Atomic => "atomic".to_string(),
+                IrNode::Assertion(IrAssertion::Start { multiline }) => format!("^{}", multiline),
+                IrNode::Assertion(IrAssertion::End { multiline }) => format!("${}", multiline),
+                IrNode::Assertion(IrAssertion::WordBoundary { negated }) => {
+                    format!("wb{}", negated)
+                }
+                IrNode::Assertion(IrAssertion::WordEdge { end, half }) => {
+                    format!("we{}{}", end, half)
+                }
+                IrNode::BackRef { group, icase } => format!("backref{}{}", group, icase),
+                IrNode::Lookaround { negated, behind } => format!("look{}{}", negated, behind),
+                _ => panic!("Unexpected node {:?}", node),
+            };
+            self.0.push_str(&desc);
+            self.0.push('(');
+        }
+        fn exit(&mut self, _node: &IrNode<'_>) {
+            self.0.push(')');
+        }
+    }
+    let walk = |pattern: &str, flags: &str| {
+        let mut rec = Recorder::default();
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .walk_ir(&mut rec)
+            .unwrap();
+        rec.0
+    };
+
+    assert_eq!(walk("a", ""), "a()");
+    assert_eq!(walk("", ""), "empty()");
+    assert_eq!(walk("ab", "i"), "cat(a/i()b/i())");
+    assert_eq!(walk("a|b|", ""), "alt(a()b()empty())");
+    assert_eq!(
+        walk(r"(a)(?<n>b)\1\k<n>", ""),
+        "cat(group1None(a())group2Some(\"n\")(b())backref1false()backref2false())"
+    );
+    assert_eq!(
+        walk(r"a*b{2,5}?c{3,}d++", ""),
+        "cat(quant{0,None,true}(a())quant{2,Some(5),false}(b())quant{3,None,true}(c())atomic(quant{1,None,true}(d())))"
+    );
+    assert_eq!(walk(r"^.$", "m"), "cat(^true()dot()$true())");
+    assert_eq!(walk(r".\b\B", "s"), "cat(dot/s()wbfalse()wbtrue())");
+    assert_eq!(
+        walk(r"[a-z][^a]\d\D\p{L}\P{L}", "u"),
+        "cat(class()class^()class()class()class()class^())"
+    );
+    assert_eq!(walk(r"(?>ab)", ""), "atomic(cat(a()b()))");
+
+    let mut rec = Recorder::default();
+    regress::RegexBuilder::new(r"\<\b{end-half}")
+        .word_edge_escapes(true)
+        .build()
+        .unwrap()
+        .walk_ir(&mut rec)
+        .unwrap();
+    assert_eq!(rec.0, "cat(wefalsefalse()wetruetrue())");
+
+    // Lookbehinds are reported in pattern order.
+    assert_eq!(
+        walk(r"(?<=ab)(?<!c)(?=d)(?!e)", ""),
+        "cat(lookfalsetrue(cat(a()b()))looktruetrue(c())lookfalsefalse(d())looktruefalse(e()))"
+    );
+
+    // Modifiers are reflected in the literals.
+    assert_eq!(walk(r"a(?i:b)", ""), "cat(a()b/i())");
+
+    // A deserialized regex carries no IR to walk, whatever its source.
+    let re = regress::Regex::new("[a]").unwrap();
+    let re = regress::Regex::from_serialized(&re.serialize_program(), "[a]").unwrap();
+    assert!(re.walk_ir(&mut Recorder::default()).is_err());
+}
+
+#[cfg(feature = "ir-visitor")]
+#[test]
+fn test_from_ir() {
+    use regress::{CodePointSet, Interval, Ir, IrAssertion, Regex};
+
+    let lit = |c: char| Ir::Literal {
+        c: c as u32,
+        icase: false,
+    };
+    let cat = |s: &str| Ir::Concatenation(s.chars().map(lit).collect());
+    let group = |index: usize, name: Option<&str>, contents: Ir| Ir::Group {
+        index,
+        name: name.map(String::from),
+        contents: Box::new(contents),
+    };
+    let ranges =
+        |re: &Regex, text: &str| -> Vec<_> { re.find_iter(text).map(|m| m.range()).collect() };
+    let class = |first: char, last: char| {
+        let mut set = CodePointSet::new();
+        set.add(Interval::new(first as u32, last as u32));
+        set
+    };
+
+    // An IR matches as the pattern it was written for.
+    let digits = Ir::Class {
+        set: class('0', '9'),
+        negated: false,
+        icase: false,
+    };
+    let ir = Ir::Concatenation(vec![
+        Ir::Assertion(IrAssertion::WordBoundary { negated: false }),
+        group(
+            1,
+            Some("num"),
+            Ir::Quantifier {
+                min: 1,
+                max: Some(3),
+                greedy: true,
+                contents: Box::new(digits),
+            },
+        ),
+        Ir::Alternation(vec![cat("px"), cat("em")]),
+    ]);
+    let re = Regex::from_ir(ir, "").unwrap();
+    let parsed = Regex::new(r"\b(?<num>\d{1,3})(?:px|em)").unwrap();
+    let text = "12px 1234em 7em x9px";
+    assert_eq!(ranges(&re, text), ranges(&parsed, text));
+    let m = re.find(text).unwrap();
+    assert_eq!(m.named_group("num"), Some(0..2));
+    assert_eq!(re.as_str(), "");
+
+    // Lookbehind contents are given in pattern order.
+    let ir = Ir::Concatenation(vec![
+        Ir::Lookaround {
+            negated: false,
+            behind: true,
+            contents: Box::new(Ir::Concatenation(vec![group(1, None, cat("ab")), lit('c')])),
+        },
+        lit('d'),
+    ]);
+    let re = Regex::from_ir(ir, "").unwrap();
+    let m = re.find("bcd abcd").unwrap();
+    assert_eq!(m.range(), 7..8);
+    assert_eq!(m.group(1), Some(4..6));
+
+    // Case-insensitive nodes, and the empty alternation, which never matches.
+    let ir = Ir::Concatenation(vec![
+        Ir::Literal {
+            c: 'K' as u32,
+            icase: true,
+        },
+        Ir::Class {
+            set: class('a', 'c'),
+            negated: false,
+            icase: true,
+        },
+    ]);
+    let re = Regex::from_ir(ir, "").unwrap();
+    assert_eq!(ranges(&re, "kB Kc kd"), vec![0..2, 3..5]);
+    let re = Regex::from_ir(Ir::Alternation(vec![]), "").unwrap();
+    assert!(re.find("abc").is_none());
+
+    // Introspection inspects the IR.
+    let ir = Ir::Quantifier {
+        min: 0,
+        max: None,
+        greedy: true,
+        contents: Box::new(Ir::BackRef {
+            group: 1,
+            icase: false,
+        }),
+    };
+    let ir = Ir::Concatenation(vec![group(1, None, lit('a')), ir]);
+    assert!(!Regex::from_ir(ir, "").unwrap().is_linear_safe());
+    assert!(Regex::from_ir(cat("ab"), "").unwrap().is_linear_safe());
+
+    // Invariants which the parser would uphold are checked.
+    let err = |ir: Ir, flags: &str| Regex::from_ir(ir, flags).unwrap_err().to_string();
+    assert_eq!(
+        err(group(2, None, Ir::Empty), ""),
+        "Capture group 2 is out of order, expected 1"
+    );
+    assert_eq!(
+        err(
+            Ir::Concatenation(vec![group(1, None, group(1, None, Ir::Empty))]),
+            ""
+        ),
+        "Capture group 1 is out of order, expected 2"
+    );
+    assert_eq!(
+        err(
+            Ir::Concatenation(vec![
+                group(1, Some("x"), Ir::Empty),
+                group(2, Some("x"), Ir::Empty)
+            ]),
+            ""
+        ),
+        "Duplicate capture group name"
+    );
+    assert_eq!(
+        err(
+            Ir::Concatenation(vec![
+                Ir::BackRef {
+                    group: 2,
+                    icase: false
+                },
+                group(1, None, Ir::Empty)
+            ]),
+            ""
+        ),
+        "Backreference to nonexistent group 2"
+    );
+    assert_eq!(
+        err(
+            Ir::Quantifier {
+                min: 3,
+                max: Some(2),
+                greedy: true,
+                contents: Box::new(lit('a'))
+            },
+            ""
+        ),
+        "Invalid quantifier"
+    );
+    assert_eq!(
+        err(
+            Ir::Literal {
+                c: 0x110000,
+                icase: false
+            },
+            ""
+        ),
+        "Invalid code point 0x110000"
+    );
+    for c in [0xD800, 0xDBFF, 0xDC00, 0xDFFF] {
+        assert_eq!(
+            err(Ir::Literal { c, icase: false }, "u"),
+            format!("Invalid code point {:#X}", c)
+        );
+    }
+    for name in ["1 2", "", "1a", "a-b", "a b", "a>"] {
+        assert_eq!(
+            err(group(1, Some(name), Ir::Empty), ""),
+            format!("Invalid capture group name '{}'", name)
+        );
+    }
+    for name in ["a1", "$", "_x", "π", "a\u{200C}b"] {
+        assert!(Regex::from_ir(group(1, Some(name), Ir::Empty), "").is_ok());
+    }
+    assert_eq!(
+        err(lit('a'), "uv"),
+        "The 'u' and 'v' flags may not be combined"
+    );
+    let mut deep = Ir::Empty;
+    for _ in 0..=regress::DEFAULT_MAX_NESTING_DEPTH {
+        deep = Ir::Atomic(Box::new(deep));
+    }
+    assert_eq!(err(deep, ""), "Nesting depth limit exceeded");
+
+    // A forward reference to a later group is allowed.
+    let ir = Ir::Concatenation(vec![
+        Ir::BackRef {
+            group: 1,
+            icase: false,
+        },
+        group(1, None, lit('a')),
+    ]);
+    assert_eq!(
+        Regex::from_ir(ir, "").unwrap().find("a").unwrap().range(),
+        0..1
+    );
+}
+
+#[test]
+fn test_explain_failure() {
+    use regress::{OffsetUnit, Regex, RegexBuilder};
+    let explain = |pattern: &str, flags: &str, text: &str| {
+        Regex::with_flags(pattern, flags)
+            .unwrap()
+            .explain_failure(text)
+            .map(|report| report.to_string())
+    };
+    assert_eq!(explain(r"abc", "", "abc"), None);
+    assert_eq!(explain(r"", "", ""), None);
+
+    // Failures are located within literals and among alternatives.
+    assert_eq!(
+        explain(r"abc", "", "abd").as_deref(),
+        Some("expected 'c' at offset 2, found 'd'")
+    );
+    assert_eq!(
+        explain(r"(?:cat|car)s", "", "cab").as_deref(),
+        Some("expected [rt] at offset 2, found 'b'")
+    );
+    assert_eq!(
+        explain(r"abc", "i", "abX").as_deref(),
+        Some("expected [Cc] at offset 2, found 'X'")
+    );
+    assert_eq!(
+        explain(r"\d+px", "", "12").as_deref(),
+        Some("expected [0-9p] at offset 2, found end of input")
+    );
+    assert_eq!(
+        explain(r"[^a]", "", "").as_deref(),
+        Some("expected [^a] at offset 0, found end of input")
+    );
+    assert_eq!(
+        explain(r"(?=abc)a.", "", "abd").as_deref(),
+        Some("expected 'c' at offset 2, found 'd'")
+    );
+
+    // The whole text must match, whatever the multiline flag.
+    assert_eq!(
+        explain(r"a+", "", "aab").as_deref(),
+        Some("expected 'a' or end of input at offset 2, found 'b'")
+    );
+    assert_eq!(
+        explain(r"a$", "m", "a\nb").as_deref(),
+        Some("expected end of input at offset 1, found '\\n'")
+    );
+
+    // Nothing in particular is expected where a backreference fails.
+    assert_eq!(
+        explain(r"(a)\1", "", "ab").as_deref(),
+        Some("no match at offset 1, found 'b'")
+    );
+
+    // The report's accessors, and the offset in the regex's unit.
+    let re = RegexBuilder::new(r"[^-]+")
+        .flags("u")
+        .offset_unit(OffsetUnit::Utf16)
+        .build()
+        .unwrap();
+    let report = re.explain_failure("é😀x-").unwrap();
+    assert_eq!((report.offset(), report.unit_offset()), (7, 4));
+    assert_eq!(report.found(), Some('-'));
+    assert!(report.expected().contains('w' as u32));
+    assert!(report.expects_end());
+}
+
+/// Check that the shortest match of the pattern on each text agrees with the
+/// shortest prefix of the remaining text, beginning at the leftmost match
+/// found by `find`, which matches the pattern exactly.
+fn check_shortest_match(pattern: &str, flags: &str, texts: &[&str]) {
+    let re = regress::Regex::with_flags(pattern, flags).unwrap();
+    let exact = regress::Regex::with_flags(&format!("^(?:{})$", pattern), flags).unwrap();
+    for text in texts.iter() {
+        let expected = re.find(text).map(|m| {
+            (m.start()..=text.len())
+                .find(|&end| exact.find(&text[m.start()..end]).is_some())
+                .unwrap()
+        });
+        assert_eq!(
+            re.shortest_match(text),
+            expected,
+            "pattern /{}/{} on {:?}",
+            pattern,
+            flags,
+            text
+        );
+    }
+}
+
+#[test]
+fn test_shortest_match() {
+    let patterns = [
+        r"a+",
+        r"a*?",
+        r"ab|a|abc",
+        r"(a|ab)(c|bcd)",
+        r"[a-c]{2,}d?",
+        r"(a)b\1+",
+        r"(?>a+)b",
+        r"a++b|ab",
+        r"(\w)\1|x{2,3}",
+        r"b*",
+        r"(?:.?)+?",
+        r"(x?|.?)+?",
+        r"(?:(?:b?){1,2})*",
+    ];
+    let texts = [
+        "", "a", "aaa", "xabcd", "abcbcd", "ccaab", "abaa", "xxxx", "zz", "b", "bb",
+    ];
+    for pattern in patterns.iter() {
+        check_shortest_match(pattern, "", &texts);
+    }
+    check_shortest_match(r"(?:(?:\w{0,3}){1,2}\d*?c*?)*a*", "su", &["b", "bb"]);
+
+    // Assertions are respected.
+    let re = regress::Regex::new(r"\w+\b").unwrap();
+    assert_eq!(re.shortest_match("  hello world"), Some(7));
+    let re = regress::Regex::new(r"a+$").unwrap();
+    assert_eq!(re.shortest_match("baaa"), Some(4));
+    let re = regress::Regex::new(r"a+(?!a)").unwrap();
+    assert_eq!(re.shortest_match("baaab"), Some(4));
+    let re = regress::Regex::new(r"(?<=b)a+").unwrap();
+    assert_eq!(re.shortest_match("aabaa"), Some(4));
+    let re = regress::Regex::new(r"(?=(a+))a").unwrap();
+    assert_eq!(re.shortest_match("aaa"), Some(1));
+
+    // Sticky matches must begin at the start.
+    let re = regress::Regex::with_flags(r"a+", "y").unwrap();
+    assert_eq!(re.shortest_match("aaa"), Some(1));
+    assert_eq!(re.shortest_match("baaa"), None);
+
+    // Programs with many paths remain usable.
+    let re = regress::Regex::new(r"(a|aa)+b").unwrap();
+    assert_eq!(re.shortest_match(&format!("{}b", "a".repeat(20))), Some(21));
+
+    // Lazy loops whose body may match empty do not repeat empty iterations,
+    // even after backtracking into an iteration past the first match.
+    let re = regress::Regex::new(r"(?:.?)+?").unwrap();
+    assert_eq!(re.shortest_match("a"), Some(0));
+    let re = regress::Regex::new(r"(x?|.?)+?").unwrap();
+    assert_eq!(re.shortest_match("a"), Some(0));
+    let re = regress::Regex::new(r"(?:.?)*?x").unwrap();
+    assert_eq!(re.shortest_match("ab"), None);
+    assert!(re.find("ab").is_none());
+    assert_eq!(re.find("abx").unwrap().range(), 0..3);
+
+    // Loops nested in loops restore their iteration count when backtracked
+    // into, after the outer loop has entered them again.
+    let re = regress::Regex::new(r"(?:(?:b?){1,2})*").unwrap();
+    assert_eq!(re.shortest_match("b"), Some(0));
+    assert_eq!(re.find("b").unwrap().range(), 0..1);
+    assert_eq!(re.find("bb").unwrap().range(), 0..2);
+}
+
+#[test]
+fn test_find_iter_chars() {
+    let text = "\u{E9}\u{1F600}ab \u{1F600}\u{E9}cd";
+    let re = regress::Regex::new(r"(?<=(\S))(\w+)").unwrap();
+    let byte_ms: Vec<_> = re.find_iter(text).collect();
+    let char_ms: Vec<_> = re.find_iter_chars(text).collect();
+    assert_eq!(byte_ms.len(), char_ms.len());
+    // Each range agrees with counting the code points before it.
+    let to_chars = |r: std::ops::Range<usize>| {
+        let start = text[..r.start].chars().count();
+        start..start + text[r].chars().count()
+    };
+    for (bm, cm) in byte_ms.iter().zip(char_ms.iter()) {
+        for idx in 0..=bm.captures.len() {
+            assert_eq!(bm.group(idx).map(to_chars), cm.unit_group(idx));
+            assert_eq!(bm.group(idx), cm.group(idx));
+        }
+    }
+    assert_eq!(char_ms[0].unit_range(), 2..4);
+    // The lookbehind capture precedes the match.
+    assert_eq!(char_ms[1].unit_group(1), Some(6..7));
+    assert_eq!(char_ms[1].unit_range(), 7..9);
+
+    let re = regress::Regex::new(r"x?").unwrap();
+    let ranges: Vec<_> = re
+        .find_iter_chars("\u{1F600}x")
+        .map(|m| m.unit_range())
+        .collect();
+    assert_eq!(ranges, vec![0..0, 1..2, 2..2]);
+
+    // The byte ranges still slice the text.
+    let m = regress::Regex::new("b")
+        .unwrap()
+        .find_iter_chars("\u{E9}\u{E9}b")
+        .next()
+        .unwrap();
+    assert_eq!(m.as_str("\u{E9}\u{E9}b"), "b");
+    assert_eq!((m.range(), m.unit_range()), (4..5, 2..3));
+}
+
+#[test]
+fn test_offset_unit() {
+    use regress::{Captures, OffsetUnit, Regex, RegexBuilder};
+    use std::ops::Range;
+    // BMP chars of one to three UTF-8 bytes, and astral chars of four bytes
+    // and two UTF-16 units.
+    let text = "x\u{E9}\u{1F600}ab \u{20AC}\u{1F600}cd\n\u{10348}e\u{FFFD}f";
+    let pattern = r"(?<=(\S))(\w+)|(\u{1F600})";
+    let len = |unit: OffsetUnit, s: &str| match unit {
+        OffsetUnit::Bytes => s.len(),
+        OffsetUnit::Utf16 => s.encode_utf16().count(),
+        OffsetUnit::CodePoints => s.chars().count(),
+    };
+    let convert = |unit: OffsetUnit, r: Range<usize>| {
+        let start = len(unit, &text[..r.start]);
+        start..start + len(unit, &text[r])
+    };
+    let bytes_re = Regex::with_flags(pattern, "u").unwrap();
+    assert_eq!(bytes_re.offset_unit(), OffsetUnit::Bytes);
+    let expected: Vec<_> = bytes_re.find_iter(text).collect();
+    assert_eq!(expected.len(), 7);
+
+    for unit in [OffsetUnit::Bytes, OffsetUnit::Utf16, OffsetUnit::CodePoints] {
+        let re = RegexBuilder::new(pattern)
+            .unicode(true)
+            .offset_unit(unit)
+            .build()
+            .unwrap();
+        assert_eq!(re.offset_unit(), unit);
+        // The byte ranges are kept, and the ranges in the unit are separate.
+        let check = |m: &regress::Match, bm: &regress::Match| {
+            assert_eq!(m.offset_unit(), unit);
+            assert!(m.groups().eq(bm.groups()), "{:?}", unit);
+            for idx in 0..=m.group_count() {
+                assert_eq!(
+                    m.unit_group(idx),
+                    bm.group(idx).map(|r| convert(unit, r)),
+                    "{:?}",
+                    unit
+                );
+            }
+            assert_eq!(m.unit_range(), convert(unit, bm.range()));
+        };
+        let found: Vec<_> = re.find_iter(text).collect();
+        assert_eq!(found.len(), expected.len());
+        for (m, bm) in found.iter().zip(&expected) {
+            check(m, bm);
+        }
+
+        let caps: Vec<Captures> = re.captures_iter(text).collect();
+        assert_eq!(caps.len(), expected.len());
+        for (c, bm) in caps.iter().zip(&expected) {
+            assert_eq!(c.offset_unit(), unit);
+            for idx in 0..=bm.group_count() {
+                assert_eq!(c.get(idx), bm.group(idx));
+                assert_eq!(c.unit_get(idx), bm.group(idx).map(|r| convert(unit, r)));
+            }
+        }
+        assert_eq!(re.captures(text).as_ref(), caps.first());
+
+        // Single matches, which convert from the start of the text, and
+        // offsets passed in, which are bytes.
+        let last = expected.last().unwrap();
+        let last_start = last.start();
+        check(&re.rfind(text).unwrap(), last);
+        check(&re.find_from(text, last_start).next().unwrap(), last);
+        check(&re.find_iter_from(text, last_start).next().unwrap(), last);
+        check(&re.find_anchored(text, last_start).unwrap(), last);
+        check(&re.find_sticky(text, last_start).unwrap(), last);
+        assert!(re.is_match_at(text, last_start));
+        assert!(!re.is_match_at(text, last.end()));
+        check(
+            &re.find_with_budget(text, 1000).unwrap().unwrap(),
+            &expected[0],
+        );
+        let mut caps = Captures::new();
+        assert!(re.find_into(text, last_start, &mut caps));
+        assert_eq!(caps.range(), last.range());
+        assert_eq!(caps.unit_get(0), Some(convert(unit, last.range())));
+        assert_eq!(re.shortest_match(text), Some(expected[0].end()));
+        let lines: Vec<_> = re.find_line_iter(text).collect();
+        let expected_lines: Vec<_> = bytes_re.find_line_iter(text).collect();
+        assert_eq!(lines.len(), expected_lines.len());
+        for ((line, m), (expected_line, bm)) in lines.iter().zip(&expected_lines) {
+            assert_eq!(line, expected_line);
+            check(m, bm);
+        }
+        let overlapping: Vec<_> = re.find_overlapping_iter(text).collect();
+        let expected_overlapping: Vec<_> = bytes_re.find_overlapping_iter(text).collect();
+        assert_eq!(overlapping.len(), expected_overlapping.len());
+        for (m, bm) in overlapping.iter().zip(&expected_overlapping) {
+            check(m, bm);
+        }
+
+        // Methods which slice the text work in every unit.
+        for (m, bm) in found.iter().zip(&expected) {
+            assert_eq!(m.as_str(text), bm.as_str(text));
+            for idx in 0..=m.group_count() {
+                assert_eq!(m.group_str(text, idx), bm.group_str(text, idx));
+            }
+            assert_eq!(m.into_owned(text), bm.into_owned(text));
+            let (mut dst, mut expected_dst) = (String::new(), String::new());
+            m.expand(text, "[$1|$2|$3]", &mut dst);
+            bm.expand(text, "[$1|$2|$3]", &mut expected_dst);
+            assert_eq!(dst, expected_dst);
+        }
+        assert_eq!(
+            re.replace_all(text, "[$2$3]"),
+            bytes_re.replace_all(text, "[$2$3]")
+        );
+        assert!(re.split(text).eq(bytes_re.split(text)));
+        let chars: Vec<_> = re.find_iter_chars(text).map(|m| m.unit_range()).collect();
+        let expected_chars: Vec<_> = expected
+            .iter()
+            .map(|m| convert(OffsetUnit::CodePoints, m.range()))
+            .collect();
+        assert_eq!(chars, expected_chars);
+
+        // A match after an astral char, whose units differ from its bytes.
+        let re = RegexBuilder::new(r"(?<ch>.)")
+            .offset_unit(unit)
+            .unicode(true)
+            .build()
+            .unwrap();
+        let m = re.find_from("\u{1F600}a", 4).next().unwrap();
+        assert_eq!((m.as_str("\u{1F600}a"), m.range()), ("a", 4..5));
+        assert_eq!(m.named_group_str("\u{1F600}a", "ch"), Some("a"));
+        let start = len(unit, "\u{1F600}");
+        assert_eq!(m.unit_named_group("ch"), Some(start..start + 1));
+        let re = RegexBuilder::new(r"\u{1F600}a")
+            .offset_unit(unit)
+            .unicode(true)
+            .build()
+            .unwrap();
+        let m = re.find("\u{1F600}a").unwrap();
+        assert_eq!(m.as_str("\u{1F600}a"), "\u{1F600}a");
+        assert_eq!(m.unit_range(), 0..len(unit, "\u{1F600}a"));
+
+        // The incremental matcher reports byte offsets in every unit.
+        let re = RegexBuilder::new(r"\w+").offset_unit(unit).build().unwrap();
+        let mut inc = re.incremental().unwrap();
+        let mut ranges = inc.feed("\u{1F600}ab ".as_bytes());
+        ranges.extend(inc.feed("\u{E9}c".as_bytes()));
+        assert_eq!(inc.offset(), 10);
+        assert_eq!(inc.pending_start(), Some(9));
+        ranges.extend(inc.finish());
+        assert_eq!(ranges, vec![4..6, 9..10]);
+        let mut inc = re.incremental().unwrap();
+        let mut ranges = inc.feed(b"\xF0\x9F");
+        ranges.extend(inc.feed(b"x- ab"));
+        ranges.extend(inc.finish());
+        assert_eq!(ranges, vec![2..3, 5..7]);
+    }
+
+    let re = RegexBuilder::new(r"\u{1F600}(.)")
+        .unicode(true)
+        .offset_unit(OffsetUnit::Utf16)
+        .build()
+        .unwrap();
+    let m = re.find("\u{10348}\u{1F600}\u{10348}").unwrap();
+    assert_eq!((m.unit_range(), m.unit_group(1)), (2..6, Some(4..6)));
+    assert_eq!((m.range(), m.group(1)), (4..12, Some(8..12)));
+    let ranges: Vec<_> = re
+        .find_iter("\u{1F600}a\u{1F600}\u{1F600}b")
+        .map(|m| m.unit_range())
+        .collect();
+    assert_eq!(ranges, vec![0..3, 3..7]);
+    // A start within a char is rounded up.
+    let m = re.find_from("\u{1F600}\u{1F600}a", 1).next().unwrap();
+    assert_eq!(m.unit_range(), 2..5);
+}
+
+#[test]
+fn test_offset_unit_start_is_match_end() {
+    use regress::{OffsetUnit, RegexBuilder};
+    // The end of a match may be passed back as a start, whatever the unit.
+    let text = "\u{1F4A9}ab";
+    for unit in [OffsetUnit::Bytes, OffsetUnit::Utf16, OffsetUnit::CodePoints] {
+        let re = RegexBuilder::new("[ab]").offset_unit(unit).build().unwrap();
+        let m = re.find(text).unwrap();
+        assert_eq!(m.range(), 4..5, "{:?}", unit);
+        let next = re.find_anchored(text, m.end()).unwrap();
+        assert_eq!(next.as_str(text), "b", "{:?}", unit);
+        assert!(re.is_match_at(text, m.end()), "{:?}", unit);
+        let rest: Vec<_> = re
+            .find_iter_from(text, m.end())
+            .map(|m| m.range())
+            .collect();
+        assert_eq!(rest, vec![5..6], "{:?}", unit);
+        assert!(!re.is_match_at(text, next.end()), "{:?}", unit);
+    }
+}
+
+#[cfg(feature = "trace")]
+#[test]
+fn test_find_traced() {
+    use regress::TraceEvent;
+    let re = regress::Regex::new(r"(a|ab)c").unwrap();
+    let mut attempts = Vec::new();
+    let mut backtracks = Vec::new();
+    let mut captures = Vec::new();
+    let mut matched = None;
+    let m = re.find_traced("xabc", &mut |event| match *event {
+        TraceEvent::Attempt { pos } => attempts.push(pos),
+        TraceEvent::Backtrack { pos, .. } => backtracks.push(pos),
+        TraceEvent::Capture { group, start, end } => captures.push((group, start, end)),
+        TraceEvent::Matched { start, end } => matched = Some(start..end),
+        TraceEvent::Step { insn, .. } => assert!(!insn.to_string().is_empty()),
+        _ => {}
+    });
+    assert_eq!(m.unwrap().range(), 1..4);
+    // The search skips ahead to the 'a', so there is one attempt.
+    assert_eq!(attempts, vec![1]);
+    assert_eq!(matched, Some(1..4));
+    // The first alternative fails at 'b' and the second is taken by
+    // backtracking, which restores the group.
+    assert!(backtracks.contains(&1));
+    assert_eq!(captures.last(), Some(&(1, Some(1), Some(3))));
+
+    // Tracing does not change the result.
+    let re = regress::Regex::new(r"(?<=(\w))b+?").unwrap();
+    assert_eq!(
+        re.find_traced("abbb", &mut |_| {}).map(|m| m.captures),
+        re.find("abbb").map(|m| m.captures)
+    );
+}
+
+#[test]
+fn test_match_all_groups() {
+    let re = regress::Regex::new(r"(a)(?:x)(b)?(?<c>c)").unwrap();
+    let m = re.find("zaxc").unwrap();
+    assert_eq!(m.group_count(), re.group_count());
+    assert_eq!(m.group_count(), 3);
+    assert_eq!(m.group(0), Some(1..4));
+    assert_eq!(m.group(1), Some(1..2));
+    assert_eq!(m.group(2), None);
+    assert_eq!(m.group(3), Some(3..4));
+    // Indexes past the last group have no group, rather than panicking.
+    assert_eq!(m.group(4), None);
+    assert_eq!(m.group(usize::MAX), None);
+
+    let all: Vec<_> = m.all_groups().collect();
+    assert_eq!(all, vec![Some(1..4), Some(1..2), None, Some(3..4)]);
+    assert_eq!(m.all_groups().len(), 4);
+    assert_eq!(m.groups().collect::<Vec<_>>(), all);
+    let mut iter = m.all_groups();
+    iter.next();
+    assert_eq!(iter.len(), 3);
+
+    let m = regress::Regex::new("").unwrap().find("").unwrap();
+    assert_eq!(m.group_count(), 0);
+    assert_eq!(m.all_groups().collect::<Vec<_>>(), vec![Some(0..0)]);
+}
+
+#[test]
+fn test_control_escapes() {
+    test_with_configs(test_control_escapes_tc)
+}
+
+fn test_control_escapes_tc(tc: TestConfig) {
+    for flags in ["", "u", "v"] {
+        tc.compilef(r"^\cJ$", flags).test_succeeds("\n");
+        tc.compilef(r"^\cj$", flags).test_succeeds("\n");
+        tc.compilef(r"^\cA\cZ$", flags).test_succeeds("\u{1}\u{1A}");
+        tc.compilef(r"^[\cJ]$", flags).test_succeeds("\n");
+        tc.compilef(r"^\cJ$", flags).test_fails("J");
+    }
+    assert_eq!(
+        regress::Regex::new(r"\cJ")
+            .unwrap()
+            .find("a\nb")
+            .unwrap()
+            .range(),
+        regress::Regex::new(r"\n")
+            .unwrap()
+            .find("a\nb")
+            .unwrap()
+            .range()
+    );
+
+    // Annex B: without the unicode flag, \c not followed by a letter is a
+    // literal backslash followed by c.
+    tc.compilef(r"^\c%$", "").test_succeeds("\\c%");
+    tc.compilef(r"^\c9$", "").test_succeeds("\\c9");
+    tc.compilef(r"^\c$", "").test_succeeds("\\c");
+    tc.compilef(r"^\c*$", "").test_succeeds("\\ccc");
+    tc.compilef(r"^\c*$", "").test_succeeds("\\");
+    tc.compilef(r"^\c9$", "").test_fails("\u{19}");
+
+    // In brackets, \c may also be followed by a digit or _. Otherwise the
+    // backslash and c are members of the bracket.
+    tc.compilef(r"^[\c1]$", "").test_succeeds("\u{11}");
+    tc.compilef(r"^[\c_]$", "").test_succeeds("\u{1F}");
+    tc.compilef(r"^[\c0]$", "").test_fails("0");
+    let re = tc.compilef(r"^[\c]]$", "");
+    re.test_succeeds("c]");
+    re.test_succeeds("\\]");
+    re.test_fails("]");
+    let re = tc.compilef(r"^[\c%]*$", "");
+    re.test_succeeds("\\c%");
+    re.test_fails("\u{5}");
+}
+
+#[test]
+fn test_full_match() {
+    let full = |pattern: &str, flags: &str, text: &str| {
+        let re = regress::Regex::with_flags(pattern, flags).unwrap();
+        let expected = regress::Regex::with_flags(&format!("^(?:{})$", pattern), flags)
+            .unwrap()
+            .is_match(text);
+        let actual = re.full_match(text);
+        if !flags.contains('m') {
+            assert_eq!(actual, expected, "{} on {:?}", pattern, text);
+        }
+        actual
+    };
+    assert!(full(r"a|ab", "", "ab"));
+    assert!(full(r"(a+)+b", "", "aaab"));
+    assert!(!full(r"a+", "", "aab"));
+    assert!(!full(r"a+", "", "baa"));
+    assert!(full(r"", "", ""));
+    assert!(!full(r"", "", "x"));
+    assert!(full(r"x*", "", ""));
+    assert!(full(r"(\w)\1+", "", "aaaa"));
+    assert!(!full(r"(\w)\1+", "", "aaab"));
+    assert!(full(r"\d+(?=$)", "", "123"));
+    assert!(full(r"(?<=^)\u{1F600}+", "u", "\u{1F600}\u{1F600}"));
+    assert!(!full(r"a*?", "", "aab"));
+    assert!(full(r"a*?b", "", "aab"));
+    // Multiline anchors do not stand in for the ends of the input.
+    assert!(!full(r"\d+", "m", "12\n34"));
+    assert!(full(r"\d+$\n^\d+", "m", "12\n34"));
+    // The sticky flag makes no difference.
+    assert!(full(r"a|ab", "y", "ab"));
+}
+
+#[test]
+fn test_match_length_bounds() {
+    let bounds = |pattern: &str, flags: &str| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .match_length_bounds()
+    };
+    assert_eq!(bounds(r"abc", ""), (3, Some(3)));
+    assert_eq!(bounds(r"", ""), (0, Some(0)));
+    assert_eq!(bounds(r"^\d{3}-\d{4}$", ""), (8, Some(8)));
+    assert_eq!(bounds(r"a|bcd", ""), (1, Some(3)));
+    assert_eq!(bounds(r"(?:ab){2,5}", ""), (4, Some(10)));
+    assert_eq!(bounds(r"a{2,}", ""), (2, None));
+    assert_eq!(bounds(r"a{2,}", "O"), (2, None));
+    assert_eq!(bounds(r"x(?=yy)(?<!z)\b", ""), (1, Some(1)));
+    assert_eq!(bounds(r"(a)\1", ""), (1, None));
+    assert_eq!(bounds(r"(?:)*", ""), (0, Some(0)));
+
+    // Lengths are in bytes of UTF-8.
+    assert_eq!(bounds(r"é€\u{1F600}", "u"), (9, Some(9)));
+    assert_eq!(bounds(r".", "su"), (1, Some(4)));
+    assert_eq!(bounds(r"[a-zé]", ""), (1, Some(2)));
+    assert_eq!(bounds(r"[^\0-\u{FFFF}]", "u"), (4, Some(4)));
+    assert_eq!(bounds(r"\p{Lu}", "u"), (1, Some(4)));
+    assert_eq!(bounds(r"[\q{abc|d}x]", "v"), (1, Some(3)));
+
+    // Case-insensitive chars may match other case forms, like the Kelvin sign.
+    assert_eq!(bounds(r"k", "i"), (1, Some(3)));
+    assert_eq!(bounds(r"[k]", "i"), (1, Some(3)));
+
+    // The bounds survive serialization.
+    let re = regress::Regex::new(r"a{2,4}").unwrap();
+    let re = regress::Regex::from_serialized(&re.serialize_program(), "").unwrap();
+    assert_eq!(re.match_length_bounds(), (2, Some(4)));
+
+    // full_match rejects texts outside the bounds, and otherwise matches.
+    let re = regress::Regex::with_flags(r"k{2,3}", "i").unwrap();
+    assert!(!re.full_match("k"));
+    assert!(re.full_match("Kk"));
+    assert!(re.full_match("\u{212A}\u{212A}\u{212A}"));
+    assert!(!re.full_match("kkkk"));
+}
+
+#[test]
+fn test_rfind() {
+    let patterns = [
+        r"aa",
+        r"a|ab",
+        r"ab|a",
+        r"\d{2}",
+        r"a*",
+        r"a?",
+        r"(?:)",
+        r"b+",
+        r"(a)\1",
+        r"\ba\w?",
+        r"(?<=a)b|a",
+        r"a(?=b)|ba",
+        r"^a|a$",
+        r"[é€]b?",
+        r"x{2,3}",
+    ];
+    let texts = [
+        "", "a", "aa", "aaa", "aaaa", "abab", "abba", "baab", "xxxxx", "a12 345", "éb€", "b a",
+    ];
+    for flags in ["", "i", "m", "u", "y"] {
+        for pattern in patterns {
+            let re = regress::Regex::with_flags(pattern, flags).unwrap();
+            for text in texts {
+                assert_eq!(
+                    re.rfind(text).map(|m| m.range()),
+                    re.find_iter(text).last().map(|m| m.range()),
+                    "'{}' with flags '{}' on {:?}",
+                    pattern,
+                    flags,
+                    text
+                );
+            }
+        }
+    }
+
+    // The captures are those of the match.
+    let re = regress::Regex::new(r"(\w)(\d)?").unwrap();
+    let m = re.rfind("a1 b2 c").unwrap();
+    assert_eq!(m.range(), 6..7);
+    assert_eq!(m.group(1), Some(6..7));
+    assert_eq!(m.group(2), None);
+}
+
+#[test]
+fn test_find_overlapping_iter() {
+    let ranges = |pattern: &str, flags: &str, text: &str| {
+        let re = regress::Regex::with_flags(pattern, flags).unwrap();
+        re.find_overlapping_iter(text)
+            .map(|m| m.range())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ranges(r"aa", "", "aaaa"), vec![0..2, 1..3, 2..4]);
+    assert_eq!(ranges(r"\w+", "", "ab c"), vec![0..2, 1..2, 3..4]);
+    // Empty matches are found at every position, including the end.
+    assert_eq!(ranges(r"", "", "\u{E9}x"), vec![0..0, 2..2, 3..3]);
+    assert_eq!(ranges(r"x*", "", "xx"), vec![0..2, 1..2, 2..2]);
+    assert_eq!(ranges(r"$", "", "ab"), vec![2..2]);
+    // Each match is the leftmost at or after its start position.
+    assert_eq!(ranges(r"b", "", "abcb"), vec![1..2, 3..4]);
+    assert_eq!(ranges(r".", "", "\u{1F600}\u{E9}"), vec![0..4, 4..6]);
+    assert_eq!(ranges(r"a", "y", "aaba"), vec![0..1, 1..2]);
+
+    let re = regress::Regex::new(r"(\w)(\w)?").unwrap();
+    let captures: Vec<_> = re.find_overlapping_iter("ab").map(|m| m.captures).collect();
+    assert_eq!(
+        captures,
+        vec![vec![Some(0..1), Some(1..2)], vec![Some(1..2), None]]
+    );
+}
+
+#[test]
+fn test_replace_all_to() {
+    let cases = [
+        (r"a*", "baac", "-"),
+        (r"(\d+)", "1 22 333", "<$1$$>"),
+        (r"(?<k>\w)=(?<v>\w)", "a=b c=d", "$<v>=$<k>"),
+        (r"x", "abc", "y"),
+        (r"", "", "e"),
+        (r"\u{1F600}", "a\u{1F600}b", "$`$'"),
+    ];
+    for (pattern, text, replacement) in cases {
+        let re = regress::Regex::with_flags(pattern, "u").unwrap();
+        let mut out = String::new();
+        re.replace_all_to(text, replacement, &mut out).unwrap();
+        assert_eq!(out, re.replace_all(text, replacement));
+    }
+
+    // A failing sink stops the replacement, leaving a prefix written.
+    struct Limited(String, usize);
+    impl std::fmt::Write for Limited {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            if self.0.len() + s.len() > self.1 {
+                return Err(std::fmt::Error);
+            }
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+    let re = regress::Regex::new(r"b").unwrap();
+    let mut out = Limited(String::new(), 6);
+    assert!(re.replace_all_to("abababab", "XY", &mut out).is_err());
+    assert_eq!(out.0, "aXYaXY");
+}
+
+#[test]
+fn test_match_expand() {
+    let re = regress::Regex::new(r"(?<key>\w+)=(\w*)").unwrap();
+    let text = "a=1, bb=, c=3";
+    let mut dst = String::new();
+    for m in re.find_iter(text) {
+        m.expand(text, "<$2:$<key>$<none>$$$&>", &mut dst);
+    }
+    assert_eq!(dst, "<1:a$a=1><:bb$bb=><3:c$c=3>");
+
+    // Expansion appends, and agrees with replace.
+    let m = re.find(text).unwrap();
+    let mut dst = String::from("x");
+    m.expand(text, "$'|$`|$1$9", &mut dst);
+    assert_eq!(dst, "x, bb=, c=3||a$9");
+    assert_eq!(
+        &dst[1..],
+        re.replace(text, "$'|$`|$1$9")
+            .strip_suffix(&text[m.end()..])
+            .unwrap()
+    );
+
+    // Without named groups, $< is literal.
+    let re = regress::Regex::new(r"(b)").unwrap();
+    let m = re.find("abc").unwrap();
+    let mut dst = String::new();
+    m.expand("abc", "$<x>$1", &mut dst);
+    assert_eq!(dst, "$<x>b");
+}
+
+#[test]
+fn test_legacy_octal_escapes() {
+    test_with_configs(test_legacy_octal_escapes_tc)
+}
+
+fn test_legacy_octal_escapes_tc(tc: TestConfig) {
+    // \0 is NUL, with or without the unicode flag.
+    for flags in ["", "u"] {
+        tc.compilef(r"^\0$", flags).test_succeeds("\u{0}");
+        tc.compilef(r"^[\0]$", flags).test_succeeds("\u{0}");
+        tc.compilef(r"^\0$", flags).test_fails("0");
+    }
+
+    // Without the unicode flag, numbers which are not groups are octal.
+    tc.compilef(r"^\1$", "").test_succeeds("\u{1}");
+    tc.compilef(r"^\01$", "").test_succeeds("\u{1}");
+    tc.compilef(r"^\123$", "").test_succeeds("S");
+    tc.compilef(r"^\377$", "").test_succeeds("\u{FF}");
+    tc.compilef(r"^\400$", "").test_succeeds("\u{20}0");
+    tc.compilef(r"^\08$", "").test_succeeds("\u{0}8");
+    tc.compilef(r"^\18$", "").test_succeeds("\u{1}8");
+    tc.compilef(r"^[\1-\3]+$", "")
+        .test_succeeds("\u{1}\u{2}\u{3}");
+    tc.compilef(r"^[\101]$", "").test_succeeds("A");
+    // \8 and \9 are identity escapes.
+    tc.compilef(r"^\8\9$", "").test_succeeds("89");
+    tc.compilef(r"^[\8]$", "").test_succeeds("8");
+
+    // With a group 1, \1 is a backreference instead.
+    let re = tc.compilef(r"^(a)\1$", "");
+    re.test_succeeds("aa");
+    re.test_fails("a\u{1}");
+    tc.compilef(r"^\1(a)$", "").test_succeeds("a");
+    tc.compilef(r"^(a)\12$", "").test_succeeds("a\u{A}");
+    tc.compilef(r"^(a)\2$", "").test_succeeds("a\u{2}");
+    // In brackets, numbers are never backreferences.
+    tc.compilef(r"^(a)[\1]$", "").test_succeeds("a\u{1}");
+}
+
+#[test]
+fn test_is_linear_safe() {
+    let safe = |pattern: &str| regress::Regex::new(pattern).unwrap().is_linear_safe();
+    assert!(safe(r""));
+    assert!(safe(r"abc"));
+    assert!(safe(r"^\d{4}-\d{2}-\d{2}$"));
+    assert!(safe(r"(a|b)*c+"));
+    assert!(safe(r"(?:a{2,5})*"));
+    assert!(safe(r"(a+)?b+"));
+    assert!(safe(r"\b\w+\b$"));
+    assert!(!safe(r"(a+)+"));
+    assert!(!safe(r"(?:a*b?)*"));
+    assert!(!safe(r"(x(?:ab{2,})+)*"));
+    assert!(!safe(r"(a)\1"));
+    assert!(!safe(r"(?<n>a)\k<n>"));
+    assert!(!safe(r"a(?!b)"));
+    assert!(!safe(r"(?<=a)b"));
+    assert!(!safe(r"(?>a+)b"));
+    assert!(!safe(r"a++"));
+}
+
+#[test]
+fn test_inspect_deserialized() {
+    // The source given with a program is not taken to be its pattern, so a
+    // mismatched one does not change what is reported.
+    let re = regress::Regex::new(r"(a)\1").unwrap();
+    let re = regress::Regex::from_serialized(&re.serialize_program(), "hello").unwrap();
+    assert!(re.find("aa").is_some());
+    assert!(!re.is_linear_safe());
+    assert!(!re.is_anchored_start() && !re.is_anchored_end());
+    assert_eq!(re.required_prefix(), None);
+    assert!(re.first_codepoint_set().contains('x' as u32));
+    assert!(re.last_codepoint_set().is_none());
+    assert!(re.try_simplify().is_none());
+    assert!(!re.canonical_key().contains("hello"));
+    assert_eq!(re.explain_failure("ab").unwrap().offset(), 1);
+}
+
+#[test]
+fn test_inspect_reparsed() {
+    // The IR is parsed again from the pattern, which may be nested deeper
+    // than the default limit allows, or hold code points which are not chars.
+    let deep = format!("{}a{}", "(?:".repeat(300), ")".repeat(300));
+    let re = regress::RegexBuilder::new(&deep)
+        .max_nesting_depth(400)
+        .build()
+        .unwrap();
+    assert!(re.is_linear_safe());
+    assert_eq!(re.required_prefix().as_deref(), Some("a"));
+
+    let re = regress::Regex::from_unicode([0xD800, 0x61].iter().copied(), "").unwrap();
+    assert!(re.first_codepoint_set().contains(0xD800));
+    assert!(!re.first_codepoint_set().contains(0xFFFD));
+    assert_eq!(re.clone(), re);
+}
+
+#[test]
+fn test_is_anchored() {
+    let re = |pattern: &str, flags: &str| regress::Regex::with_flags(pattern, flags).unwrap();
+    let start = |pattern: &str| re(pattern, "").is_anchored_start();
+    let end = |pattern: &str| re(pattern, "").is_anchored_end();
+
+    assert!(start(r"^abc") && !end(r"^abc"));
+    assert!(end(r"abc$") && !start(r"abc$"));
+    assert!(start(r"^$") && end(r"^$"));
+    assert!(!start(r"") && !end(r""));
+    assert!(!start(r"a^") && !end(r"$a"));
+
+    // Every alternative must be anchored.
+    assert!(start(r"^a|^b|^c"));
+    assert!(!start(r"^a|b|^c"));
+    assert!(end(r"(?:a$|b$)"));
+    assert!(!end(r"(?:a$|b)"));
+    assert!(start(r"(?:^a|^b)c") && !start(r"c(?:^a|^b)"));
+
+    // Groups, loops which must run, and zero-width nodes are looked through.
+    assert!(start(r"((?<n>^a))b") && end(r"(?>a$)"));
+    assert!(start(r"(^a){2,}") && !start(r"(^a)*"));
+    assert!(end(r"(a$)+") && !end(r"(a$)?"));
+    assert!(start(r"\b(?=a)^\w") && end(r"\w$(?<=a)\B"));
+    assert!(start(r"(?=^)a") && end(r"a(?=$)") && end(r"a(?=\b$)"));
+    assert!(!end(r"(?=a$)a") && !start(r"(?!^)a") && !end(r"a(?!$)"));
+
+    // Multiline anchors match at line terminators.
+    assert!(!re(r"^a", "m").is_anchored_start());
+    assert!(!re(r"a$", "m").is_anchored_end());
+    assert!(re(r"(?-m:^a)", "m").is_anchored_start());
+    assert!(!start(r"(?m:^a)"));
+
+    // Other flags do not matter.
+    assert!(re(r"^a|^\u{1F600}", "iu").is_anchored_start());
+    assert!(re(r"^a", "y").is_anchored_start());
+}
+
+#[test]
+fn test_name_to_index() {
+    let re = regress::Regex::new(r"(a)(?<x>b)(?:c)(?<y>d)(e)").unwrap();
+    for (idx, name) in re.capture_names().enumerate() {
+        let idx = idx + 1;
+        assert_eq!(re.index_to_name(idx), name);
+        if let Some(name) = name {
+            assert_eq!(re.name_to_index(name), Some(idx));
+        }
+    }
+    assert_eq!(re.name_to_index("x"), Some(2));
+    assert_eq!(re.name_to_index("y"), Some(3));
+    assert_eq!(re.name_to_index("z"), None);
+    assert_eq!(re.index_to_name(0), None);
+    assert_eq!(re.index_to_name(1), None);
+    assert_eq!(re.index_to_name(5), None);
+    assert_eq!(re.index_to_name(usize::MAX), None);
+
+    // The index agrees with the match.
+    let m = re.find("abcde").unwrap();
+    assert_eq!(m.group(re.name_to_index("y").unwrap()), m.named_group("y"));
+
+    let err = regress::Regex::new(r"(?<x>a)|(?<x>b)").unwrap_err();
+    assert!(err.text.contains("Duplicate capture group name"));
+}
+
+#[test]
+fn test_find_iter_utf8_runs() {
+    let ranges = |pattern: &str, bytes: &[u8]| {
+        let re = regress::Regex::new(pattern).unwrap();
+        re.find_iter_utf8_runs(bytes)
+            .map(|m| m.range())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ranges(r"\w+", b"ab\xFFcd"), vec![0..2, 3..5]);
+    assert_eq!(ranges(r"\w+", b"abcd"), vec![0..4]);
+    assert_eq!(ranges(r"\w+", b""), vec![]);
+    assert_eq!(ranges(r"\w+", b"\xFF\xFE"), vec![]);
+    // Matches cannot cross invalid bytes, and anchors see the run edges.
+    assert_eq!(ranges(r"a.b", b"a\xFFb"), vec![]);
+    assert_eq!(ranges(r"b$", b"ab\xC0\x80"), vec![1..2]);
+    assert_eq!(ranges(r"(?<=a)b", b"a\xFFb"), vec![]);
+    assert_eq!(ranges(r"\bb", b"a\xFFb"), vec![2..3]);
+    // A truncated sequence at the end, and multibyte characters.
+    assert_eq!(
+        ranges(
+            r".",
+            "\u{E9}"
+                .as_bytes()
+                .iter()
+                .chain(b"\xF0\x9F")
+                .copied()
+                .collect::<Vec<_>>()
+                .as_slice()
+        ),
+        vec![0..2]
+    );
+    assert_eq!(ranges(r"\u{1F600}", "x\u{1F600}".as_bytes()), vec![1..5]);
+
+    let re = regress::Regex::new(r"(\d)(\d)").unwrap();
+    let m = re.find_iter_utf8_runs(b"1\xFF23").next().unwrap();
+    assert_eq!(m.range(), 2..4);
+    assert_eq!(m.captures, vec![Some(2..3), Some(3..4)]);
+
+    // The offset unit of the regex is honored, counting each invalid
+    // sequence as one U+FFFD.
+    let mut bytes = "\u{1F600}".as_bytes().to_vec();
+    bytes.extend_from_slice(b"\xFF");
+    bytes.extend_from_slice("\u{E9}b".as_bytes());
+    bytes.extend_from_slice(b"\xF0\x9Fc");
+    let lossy = String::from_utf8_lossy(&bytes);
+    for (unit, expected) in [
+        (regress::OffsetUnit::Utf16, [3..5, 6..7]),
+        (regress::OffsetUnit::CodePoints, [2..4, 5..6]),
+        (regress::OffsetUnit::Bytes, [5..8, 10..11]),
+    ] {
+        let re = regress::RegexBuilder::new(r"(\w)\w*")
+            .unicode(true)
+            .offset_unit(unit)
+            .build()
+            .unwrap();
+        let ms: Vec<_> = re.find_iter_utf8_runs(&bytes).collect();
+        let units: Vec<_> = ms.iter().map(|m| m.unit_range()).collect();
+        assert_eq!(units, expected, "{:?}", unit);
+        let lossy_units: Vec<_> = re.find_iter(&lossy).map(|m| m.unit_range()).collect();
+        if unit != regress::OffsetUnit::Bytes {
+            assert_eq!(units, lossy_units, "{:?}", unit);
+        }
+        assert_eq!(ms[1].unit_group(1), Some(expected[1].clone()));
+        assert_eq!(ms[0].range(), 5..8);
+    }
+}
+
+#[cfg(all(unix, feature = "std"))]
+#[test]
+fn test_find_iter_os_str() {
+    use std::os::unix::ffi::OsStrExt;
+    let name = std::ffi::OsStr::from_bytes(b"caf\xE9.txt");
+    let re = regress::Regex::new(r"\w+").unwrap();
+    let ranges: Vec<_> = re.find_iter_os_str(name).map(|m| m.range()).collect();
+    assert_eq!(ranges, vec![0..3, 5..8]);
+}
+
+#[test]
+fn test_find_memoized() {
+    // Memoization does not change the matches or their captures.
+    let cases = [
+        (r"(a|ab)(c|bcd)(d*)", "abcd abcdd"),
+        (r"(a*)*b", "aaab ab b"),
+        (r"(?:x(a|b)*y)+", "xaby xy xbbay"),
+        (r"(a+?)(a*?)b", "aaab"),
+        (r"(?:(a)|b){2,4}c", "abbac bac"),
+        (r"(?<=(\w){2})(\w)+", "hello world"),
+        (r"(?=(a+))a*b\1?", "aaab"),
+        (r"((a)|b)*", "abab"),
+        (r"(?>a+|b)+c", "aabac"),
+        (r"(?:a|){3,5}b", "aab b"),
+        (r"(?:(?:a*)*){2,}x", "aax"),
+        (r"\b(\w+)\s\1", "the the cat"),
+    ];
+    for (pattern, text) in cases {
+        let re = regress::Regex::new(pattern).unwrap();
+        let expected: Vec<_> = re
+            .find_iter(text)
+            .map(|m| (m.range(), m.captures))
+            .collect();
+        let actual: Vec<_> = re
+            .find_iter_memoized(text)
+            .map(|m| (m.range(), m.captures))
+            .collect();
+        assert_eq!(actual, expected, "pattern {}", pattern);
+    }
+
+    // Exponential patterns finish quickly.
+    let text = "a".repeat(500);
+    for pattern in [r"^(a|a)*b", r"(a*)*b", r"^(a|aa)+$\n", r"(?:a+)+(?:a+)+c"] {
+        let re = regress::Regex::new(pattern).unwrap();
+        assert!(re.find_memoized(&text).is_none(), "pattern {}", pattern);
+    }
+    let re = regress::Regex::new(r"^(a|a)*$").unwrap();
+    let m = re.find_memoized(&text).unwrap();
+    assert_eq!(m.range(), 0..500);
+    assert_eq!(m.group(1), Some(499..500));
+}
+
+#[test]
+fn test_find_line_iter() {
+    let lines = |pattern: &str, flags: &str, text: &str| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .find_line_iter(text)
+            .map(|(line, m)| (line, m.range()))
+            .collect::<Vec<_>>()
+    };
+
+    // At most one match per line, the leftmost.
+    assert_eq!(
+        lines(r"\d+", "", "a1 22\nno\n333"),
+        vec![(0..5, 1..2), (9..12, 9..12)]
+    );
+
+    // Every line terminator separates lines, and \r\n is one.
+    assert_eq!(
+        lines(r"^.*$", "", "a\rb\r\nc\u{2028}d\u{2029}\ne"),
+        vec![
+            (0..1, 0..1),
+            (2..3, 2..3),
+            (5..6, 5..6),
+            (9..10, 9..10),
+            (13..13, 13..13),
+            (14..15, 14..15),
+        ]
+    );
+
+    // No empty line after a final terminator, and none in empty text.
+    assert_eq!(lines(r"", "", "a\n"), vec![(0..1, 0..0)]);
+    assert_eq!(lines(r"", "", "\n\n"), vec![(0..0, 0..0), (1..1, 1..1)]);
+    assert_eq!(lines(r"", "", ""), vec![]);
+
+    // Anchors and lookarounds see only the line, with or without m.
+    assert_eq!(lines(r"b$", "", "ab\nb"), vec![(0..2, 1..2), (3..4, 3..4)]);
+    assert_eq!(lines(r"b$", "m", "ab\nb"), vec![(0..2, 1..2), (3..4, 3..4)]);
+    assert_eq!(lines(r"(?<!a\s)b", "", "a\nb"), vec![(2..3, 2..3)]);
+    assert_eq!(lines(r"a\nb", "", "a\nb"), vec![]);
+
+    // Captures are offsets into the text.
+    let re = regress::Regex::new(r"(\w)=(\w)?").unwrap();
+    let ms: Vec<_> = re.find_line_iter("x\na=\nb=c").map(|(_, m)| m).collect();
+    assert_eq!(ms[0].group(1), Some(2..3));
+    assert_eq!(ms[0].group(2), None);
+    assert_eq!(ms[1].group(2), Some(7..8));
+}
+
+#[test]
+fn test_required_prefix() {
+    let prefix = |pattern: &str, flags: &str| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .required_prefix()
+    };
+    let some = |s: &str| Some(s.to_string());
+
+    assert_eq!(prefix(r"abc", ""), some("abc"));
+    assert_eq!(prefix(r"(?:abc)(d)e", ""), some("abcde"));
+    assert_eq!(prefix(r"x{3}y", ""), some("xxxy"));
+    assert_eq!(prefix(r"(?:ab)+c", ""), some("ab"));
+    assert_eq!(prefix(r"ab(?=c)cd", ""), some("ab"));
+    assert_eq!(prefix(r"a.b", "s"), some("a"));
+    assert_eq!(prefix(r"(a)\1", ""), some("a"));
+    assert_eq!(prefix(r"é€\u{1F600}x", "u"), some("é€😀x"));
+    assert_eq!(prefix(r"abc", "O"), some("abc"));
+
+    // Only chars without other case forms are exact.
+    assert_eq!(prefix(r"12ab", "i"), some("12"));
+    assert_eq!(prefix(r"a(?i)bc", ""), some("a"));
+    assert_eq!(prefix(r"ab", "i"), None);
+
+    // Alternations, classes and assertions have no prefix.
+    assert_eq!(prefix(r"", ""), None);
+    assert_eq!(prefix(r"ab|ac", ""), None);
+    assert_eq!(prefix(r"[a]bc", "i"), None);
+    assert_eq!(prefix(r"^abc", ""), None);
+    assert_eq!(prefix(r"\bab", ""), None);
+    assert_eq!(prefix(r"a*bc", ""), None);
+}
+
+#[test]
+fn test_first_codepoint_set() {
+    let first = |pattern: &str, flags: &str| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .first_codepoint_set()
+            .intervals()
+            .iter()
+            .map(|iv| (iv.first, iv.last))
+            .collect::<Vec<_>>()
+    };
+    let c = |c: char| c as u32;
+    let all = vec![(0, regress::CODE_POINT_MAX)];
+
+    assert_eq!(first(r"abc", ""), vec![(c('a'), c('a'))]);
+    assert_eq!(
+        first(r"x|[0-9]y", ""),
+        vec![(c('0'), c('9')), (c('x'), c('x'))]
+    );
+    assert_eq!(first(r"a?b*c", ""), vec![(c('a'), c('c'))]);
+    assert_eq!(first(r"(?:)(a)", ""), vec![(c('a'), c('a'))]);
+    assert_eq!(first(r"^(?=b)\bc", ""), vec![(c('c'), c('c'))]);
+    assert_eq!(
+        first(r"k", "i"),
+        vec![(c('K'), c('K')), (c('k'), c('k')), (0x212A, 0x212A)]
+    );
+    assert_eq!(
+        first(r"[^\n]", ""),
+        vec![(0, 9), (11, regress::CODE_POINT_MAX)]
+    );
+    assert_eq!(first(r"\p{Nd}", "u")[0], (c('0'), c('9')));
+    assert_eq!(first(r"(?<!x)\u{1F600}", "u"), vec![(0x1F600, 0x1F600)]);
+    assert_eq!(first(r"(?>a|b)", ""), vec![(c('a'), c('b'))]);
+
+    // A pattern which can match anywhere can begin with anything.
+    assert_eq!(first(r"x*", ""), all);
+    assert_eq!(first(r"(?:a|)", ""), all);
+    assert_eq!(first(r"(a)\1", ""), vec![(c('a'), c('a'))]);
+    assert_eq!(first(r"(a)?\1b", ""), all);
+    assert_eq!(first(r".", "s"), all);
+
+    // The set is a usable CodePointSet.
+    let set = regress::Regex::new(r"[a-z]").unwrap().first_codepoint_set();
+    let mut vowels = regress::CodePointSet::new();
+    "aeiou".chars().for_each(|ch| vowels.add_one(c(ch)));
+    assert_eq!(set.intersection(&vowels), vowels);
+    assert!(set.difference(&vowels).contains(c('b')));
+    assert!(set.inverted().contains(c('A')));
+}
+
+#[test]
+fn test_last_codepoint_set() {
+    let last = |pattern: &str, flags: &str| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .last_codepoint_set()
+            .map(|set| {
+                set.intervals()
+                    .iter()
+                    .map(|iv| (iv.first, iv.last))
+                    .collect::<Vec<_>>()
+            })
+    };
+    let c = |c: char| c as u32;
+
+    assert_eq!(last(r"abc", ""), Some(vec![(c('c'), c('c'))]));
+    assert_eq!(
+        last(r"x|y[0-9]", ""),
+        Some(vec![(c('0'), c('9')), (c('x'), c('x'))])
+    );
+    assert_eq!(last(r"a?b*c?", ""), None);
+    assert_eq!(last(r"ab*c?", ""), Some(vec![(c('a'), c('c'))]));
+    assert_eq!(last(r"(a)(?:)", ""), Some(vec![(c('a'), c('a'))]));
+    assert_eq!(last(r"c\b(?<=c)$", ""), Some(vec![(c('c'), c('c'))]));
+    assert_eq!(
+        last(r"k", "i"),
+        Some(vec![(c('K'), c('K')), (c('k'), c('k')), (0x212A, 0x212A)])
+    );
+    assert_eq!(last(r"a\u{1F600}", "u"), Some(vec![(0x1F600, 0x1F600)]));
+    assert_eq!(last(r"(?>a|b)+", ""), Some(vec![(c('a'), c('b'))]));
+    assert_eq!(
+        last(r"a.", ""),
+        Some(vec![
+            (0, 9),
+            (11, 12),
+            (14, 0x2027),
+            (0x202A, regress::CODE_POINT_MAX)
+        ])
+    );
+
+    // A backreference before the end of a match is no obstacle.
+    assert_eq!(last(r"(a)\1b", ""), Some(vec![(c('b'), c('b'))]));
+
+    // A match which may be empty, or end with a backreference, is unknown.
+    assert_eq!(last(r"x*", ""), None);
+    assert_eq!(last(r"(?:a|)", ""), None);
+    assert_eq!(last(r"(a)\1", ""), None);
+    assert_eq!(last(r"(a)b\1?", ""), None);
+
+    // The set is a usable CodePointSet.
+    let set = regress::Regex::new(r"hello|(?:wor)ld")
+        .unwrap()
+        .last_codepoint_set()
+        .unwrap();
+    assert!(set.contains(c('o')) && set.contains(c('d')) && !set.contains(c('h')));
+}
+
+#[test]
+fn test_code_point_set_samples() {
+    use regress::{CodePointSet, Interval};
+    let samples = |set: &CodePointSet| set.sample_code_points().collect::<Vec<_>>();
+
+    // Intervals of one, two and more code points.
+    let mut set = CodePointSet::new();
+    set.add_one(5);
+    set.add(Interval::new(10, 11));
+    set.add(Interval::new(20, 25));
+    assert_eq!(samples(&set), vec![5, 10, 11, 20, 22, 25]);
+    assert_eq!(
+        set.iter_intervals().collect::<Vec<_>>(),
+        set.intervals().to_vec()
+    );
+    assert_eq!(set.iter_intervals().len(), 3);
+    assert_eq!(
+        set.iter_intervals().next_back(),
+        Some(Interval::new(20, 25))
+    );
+    assert!(samples(&CodePointSet::new()).is_empty());
+    assert_eq!(
+        samples(&CodePointSet::new().inverted()),
+        vec![0, 0x6BFF, 0xD7FF, 0xE000, 0x8EFFF, regress::CODE_POINT_MAX]
+    );
+
+    // Surrogates are skipped.
+    let mut set = CodePointSet::new();
+    set.add(Interval::new(0xD7FF, 0xE000));
+    assert_eq!(samples(&set), vec![0xD7FF, 0xE000]);
+    let mut set = CodePointSet::new();
+    set.add(Interval::new(0xD800, 0xDFFF));
+    assert!(samples(&set).is_empty());
+    let mut set = CodePointSet::new();
+    set.add(Interval::new(0xDC00, 0xE002));
+    assert_eq!(samples(&set), vec![0xE000, 0xE001, 0xE002]);
+    assert_eq!(Interval::new(7, 7).samples().collect::<Vec<_>>(), vec![7]);
+    assert_eq!(Interval::new(0, 9).midpoint(), 4);
+
+    // The samples of a regex's first code points begin matches, and those of
+    // their complement do not.
+    let re = regress::Regex::new(r"^(?:[a-f]|\d|x+)").unwrap();
+    let set = re.first_codepoint_set();
+    for cp in set.sample_code_points() {
+        let c = char::from_u32(cp).unwrap();
+        assert!(re.find(&c.to_string()).is_some(), "{:?}", c);
+    }
+    for cp in set.inverted().sample_code_points() {
+        let c = char::from_u32(cp).unwrap();
+        assert!(re.find(&c.to_string()).is_none(), "{:?}", c);
+    }
+}
+
+#[test]
+fn test_with_flag_set() {
+    use regress::{Flag, Flags, Regex};
+
+    let re = Regex::with_flag_set(r"^a.b$", [Flag::DotAll, Flag::Multiline]).unwrap();
+    assert_eq!(format!("{:#}", re), "/^a.b$/ms");
+    assert!(re.find("x\na\nb").is_some());
+    let re = Regex::with_flag_set(r"\p{L}", []).unwrap();
+    assert_eq!(format!("{:#}", re), r"/\p{L}/");
+
+    // Every flag agrees with its letter.
+    let all = [
+        Flag::CaseInsensitive,
+        Flag::Multiline,
+        Flag::DotAll,
+        Flag::Extended,
+        Flag::Unicode,
+        Flag::Sticky,
+        Flag::HasIndices,
+    ];
+    for flag in all.iter().copied().chain([Flag::UnicodeSets]) {
+        assert_eq!(Flag::from_char(flag.as_char()), Some(flag));
+        let letter = flag.as_char().to_string();
+        assert_eq!(
+            Flags::from_flag_set([flag]).unwrap().to_string(),
+            Flags::from(letter.as_str()).to_string()
+        );
+    }
+    assert_eq!(Flag::from_char('g'), None);
+    assert_eq!(Flags::from_flag_set(all).unwrap().to_string(), "dmisxuy");
+
+    // Repeated and conflicting flags are errors.
+    let err = Regex::with_flag_set("a", [Flag::Sticky, Flag::Sticky]).unwrap_err();
+    assert_eq!(err.text, "Duplicate flag 'y'");
+    let err = Regex::with_flag_set("a", [Flag::UnicodeSets, Flag::Unicode]).unwrap_err();
+    assert_eq!(err.text, "The 'u' and 'v' flags may not be combined");
+
+    // Parsing a string shares the validation, and rejects unknown flags.
+    assert_eq!("iu".parse::<Flags>().unwrap().to_string(), "iu");
+    assert_eq!(
+        "ii".parse::<Flags>().unwrap_err().text,
+        "Duplicate flag 'i'"
+    );
+    assert_eq!("gi".parse::<Flags>().unwrap_err().text, "Unknown flag 'g'");
+    assert!("uv".parse::<Flags>().is_err());
+    // Flags::new remains tolerant.
+    assert_eq!(Flags::from("gii").to_string(), "i");
+}
+
+#[test]
+fn test_group_count_and_capture_names() {
+    let names = |pattern: &str| {
+        regress::Regex::new(pattern)
+            .unwrap()
+            .capture_names()
+            .map(|n| n.map(str::to_string))
+            .collect::<Vec<_>>()
+    };
+    let count = |pattern: &str| regress::Regex::new(pattern).unwrap().group_count();
+    assert_eq!(count("abc"), 0);
+    assert!(names("abc").is_empty());
+    assert_eq!(count(r"(a)|(?:b)|(c(d))"), 3);
+    assert_eq!(names(r"(a)|(?:b)|(c(d))"), vec![None, None, None]);
+    assert_eq!(count(r"(?<x>a)(?=(?<y>b))(?<!(c))"), 3);
+    assert_eq!(
+        names(r"(?<x>a)(?=(?<y>b))(?<!(c))"),
+        vec![Some("x".to_string()), Some("y".to_string()), None]
+    );
+
+    // The names agree with the groups of a match.
+    let re = regress::Regex::new(r"(?<a>.)(.)(?<c>.)").unwrap();
+    let m = re.find("xyz").unwrap();
+    assert_eq!(m.captures.len(), re.group_count());
+    for (idx, name) in re.capture_names().enumerate() {
+        if let Some(name) = name {
+            assert_eq!(m.named_group(name), m.group(idx + 1));
+        }
+    }
+}
+
+#[test]
+fn test_regex_builder() {
+    use regress::RegexBuilder;
+    let flags = |b: &RegexBuilder| format!("{:#}", b.build().unwrap());
+    assert_eq!(flags(&RegexBuilder::new("a")), "/a/");
+    assert_eq!(
+        flags(
+            RegexBuilder::new("a")
+                .case_insensitive(true)
+                .multiline(true)
+                .dot_all(true)
+                .extended(true)
+                .unicode(true)
+                .sticky(true)
+                .has_indices(true)
+        ),
+        "/a/dmisxuy"
+    );
+    assert_eq!(
+        flags(RegexBuilder::new("a").unicode_sets(true).no_opt(true)),
+        "/a/v"
+    );
+    assert_eq!(
+        flags(RegexBuilder::new("a").flags("im").multiline(false)),
+        "/a/i"
+    );
+
+    // The flags take effect.
+    let re = RegexBuilder::new(r"a.b # comment")
+        .extended(true)
+        .dot_all(true)
+        .build()
+        .unwrap();
+    assert_eq!(re.find("xa\nb").unwrap().range(), 1..4);
+    let re = RegexBuilder::new(r"\u{1F600}")
+        .unicode(true)
+        .build()
+        .unwrap();
+    assert!(re.is_match("\u{1F600}"));
+
+    // Errors are reported as for Regex::with_flags.
+    let err = RegexBuilder::new("a")
+        .unicode(true)
+        .unicode_sets(true)
+        .build();
+    assert_eq!(
+        err.unwrap_err().to_string(),
+        regress::Regex::with_flags("a", "uv")
+            .unwrap_err()
+            .to_string()
+    );
+    assert!(RegexBuilder::new(r"\u{").unicode(true).build().is_err());
+
+    // The step budget is carried by the regex.
+    let re = RegexBuilder::new(r"(a+)+$")
+        .step_budget(1000)
+        .build()
+        .unwrap();
+    assert_eq!(re.step_budget(), Some(1000));
+    assert!(re.try_find(&"a".repeat(30)).unwrap().is_some());
+    assert!(re.try_find(&format!("{}!", "a".repeat(30))).is_err());
+    let re = regress::Regex::new(r"(a+)+$").unwrap();
+    assert_eq!(re.step_budget(), None);
+    assert!(re.try_find("aa!").unwrap().is_none());
+}
+
+#[test]
+fn test_serialize_program() {
+    let cases = [
+        (r"abc", ""),
+        (r"a.c", "s"),
+        (r"^(a|b)*?c$", "m"),
+        (r"(?<first>\w+)\s(?<last>\w+)", ""),
+        (r"(a+)\1", "i"),
+        (r"[^\d\sx-z]+", ""),
+        (r"[\u{1F600}-\u{1F64F}]", "u"),
+        (r"\p{Script=Greek}\P{Lu}\p{Emoji}\p{Age!=9.0}", "u"),
+        (r"[\p{L}--[a-z]]", "v"),
+        (r"(?<=\$)\d+(?!\.)", ""),
+        (r"\bfoo\B", "i"),
+        (r"x{2,5}y{3}z+?", ""),
+        (r"(?:ab|cd){2,}", ""),
+        (r"[abc][xy]", ""),
+        (r"sticky", "y"),
+        (r"a # comment", "x"),
+        (r"(?>a+)b", ""),
+        ("abcdefghijklmno\u{E9}xyz", ""),
+    ];
+    let text = "abc a\nc bbc Hello World aA xyz 0123 $42. \u{1F600} \u{3B1}b foo cdcd xxxyyyz \
+                sticky ab abcdefghijklmno\u{E9}xyz";
+    for (pattern, flags) in cases.iter() {
+        let re = regress::Regex::with_flags(pattern, *flags).unwrap();
+        let bytes = re.serialize_program();
+        let re2 = regress::Regex::from_serialized(&bytes, pattern).unwrap();
+        assert_eq!(re2.as_str(), *pattern);
+        assert_eq!(re2.to_string(), re.to_string());
+        assert_eq!(re2.serialize_program(), bytes);
+        let ranges = |re: &regress::Regex| {
+            re.find_iter(text)
+                .map(|m| (m.range(), m.captures))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranges(&re2), ranges(&re), "pattern {}", pattern);
+    }
+
+    let re = regress::Regex::new(r"(?<x>a)|b").unwrap();
+    let re2 = regress::Regex::from_serialized(&re.serialize_program(), "").unwrap();
+    assert_eq!(re2.find("a").unwrap().named_group("x"), Some(0..1));
+    assert_eq!(re2.find("b").unwrap().named_group("x"), None);
+
+    // Incompatible or malformed programs are rejected.
+    let bytes = re.serialize_program();
+    let err = |bytes: &[u8]| {
+        regress::Regex::from_serialized(bytes, "")
+            .unwrap_err()
+            .to_string()
+    };
+    assert_eq!(err(b""), "Not a serialized program");
+    assert_eq!(err(&bytes[1..]), "Not a serialized program");
+    let mut future = bytes.clone();
+    future[4] += 1;
+    assert_eq!(
+        err(&future),
+        "Unsupported serialized program version 7 (expected 6)"
+    );
+    let mut other_unicode = bytes.clone();
+    assert_eq!(&other_unicode[12..18], regress::UNICODE_VERSION.as_bytes());
+    other_unicode[13] += 1;
+    assert_eq!(
+        err(&other_unicode),
+        "Serialized program uses a different Unicode version"
+    );
+    for len in 5..bytes.len() {
+        assert!(regress::Regex::from_serialized(&bytes[..len], "").is_err());
+    }
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert_eq!(err(&extended), "Invalid serialized program");
+
+    // A program must end in a Goal instruction.
+    let mut no_goal = bytes.clone();
+    *no_goal.last_mut().unwrap() = 28;
+    assert_eq!(err(&no_goal), "Invalid serialized program");
+
+    // Loading corrupt programs must not panic.
+    for pattern in [r"(a|b)*c", r"(?<=x)(\w)\1+", r"[^a-f]{2,}?"].iter() {
+        let bytes = regress::Regex::new(pattern).unwrap().serialize_program();
+        for idx in 0..bytes.len() {
+            for &bit in [0x01, 0x10, 0x80].iter() {
+                let mut corrupt = bytes.clone();
+                corrupt[idx] ^= bit;
+                let _ = regress::Regex::from_serialized(&corrupt, "");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_match_corrupt_programs() {
+    // A corrupt program is either rejected, or matches without crashing and
+    // reports ranges which may be sliced.
+    let texts = ["\u{1F600}b\u{E9}", "xaab", "ABC-z\u{E9}\u{E9}b", ""];
+    for &(pattern, flags) in [
+        ("[a-z]", "i"),
+        (r"(a|b)*c", ""),
+        (r"(?<=x)(\w)\1+", ""),
+        (r"\u{E9}{2}b|\u{1F600}", "u"),
+        (r"(?<n>[^a-f]{2,}?)(?=b)", ""),
+    ]
+    .iter()
+    {
+        let bytes = regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .serialize_program();
+        for idx in 0..bytes.len() {
+            let original = bytes[idx];
+            let values = (0..8).map(|bit| original ^ (1 << bit)).chain([0x00, 0xFF]);
+            for value in values {
+                let mut corrupt = bytes.clone();
+                corrupt[idx] = value;
+                let re = match regress::Regex::from_serialized(&corrupt, pattern) {
+                    Ok(re) => re,
+                    Err(_) => continue,
+                };
+                for text in texts.iter() {
+                    for m in re.find_iter(text) {
+                        assert!(text.get(m.range()).is_some());
+                        for group in m.groups().flatten() {
+                            assert!(text.get(group).is_some());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sticky_flag_display() {
+    assert_eq!(regress::Flags::from("yu").to_string(), "uy");
+    assert!(regress::Flags::from("y").sticky);
+}
+
+#[test]
+fn test_group_indices() {
+    test_with_configs(test_group_indices_tc)
+}
+
+fn test_group_indices_tc(tc: TestConfig) {
+    let re = tc.compilef(r"(?<word>\w+)(?: (\d+))?(x)?", "d");
+    let m = re.find("  hello 42").unwrap();
+    assert_eq!(m.group_indices(0), Some(2..10));
+    assert_eq!(m.group_indices(1), Some(2..7));
+    assert_eq!(m.group_indices(2), Some(8..10));
+    assert_eq!(m.group_indices(3), None);
+    assert_eq!(m.group_indices(4), None);
+    assert_eq!(m.named_group_indices("word"), Some(2..7));
+    assert_eq!(m.named_group_indices("nope"), None);
+
+    // Offsets are bytes.
+    let m = re.find("\u{E9}ab 7").unwrap();
+    assert_eq!(m.group_indices(1), Some(2..4));
+    assert_eq!(m.group_indices(2), Some(5..6));
+
+    // Groups in a loop are reset on each iteration.
+    let m = tc.compilef(r"(?:(a)|b)+", "d").find("ab").unwrap();
+    assert_eq!(m.group_indices(0), Some(0..2));
+    assert_eq!(m.group_indices(1), None);
+}
+
+#[test]
+fn test_has_indices_flag() {
+    let flags = regress::Flags::from("dgi");
+    assert!(flags.has_indices);
+    assert_eq!(flags.to_string(), "di");
+}
+
+#[test]
+fn test_modifier_groups() {
+    test_with_configs(test_modifier_groups_tc)
+}
+
+fn test_modifier_groups_tc(tc: TestConfig) {
+    // Case-insensitivity is scoped to the group.
+    let re = tc.compile(r"(?i:A)A");
+    re.test_succeeds("aA");
+    re.test_succeeds("AA");
+    re.test_fails("aa");
+    re.test_fails("Aa");
+
+    // Turning flags off.
+    let re = tc.compilef(r"a(?-i:b)c", "i");
+    re.test_succeeds("AbC");
+    re.test_fails("ABC");
+
+    // Nesting.
+    let re = tc.compile(r"(?i:a(?-i:b(?i:c))d)e");
+    re.test_succeeds("AbCDe");
+    re.test_fails("ABCDe");
+    re.test_fails("AbCDE");
+    tc.compile(r"(?i:(?i:a))").test_succeeds("A");
+
+    // Brackets, escapes and backreferences honor the scoped flag.
+    tc.compile(r"(?i:[a-c]\x64)")
+        .match_all("ABCD")
+        .test_eq(vec!["CD"]);
+    let re = tc.compile(r"(a)(?i:\1)\1");
+    re.test_succeeds("aAa");
+    re.test_fails("aAA");
+
+    // Toggles apply until the end of the enclosing group, including later
+    // alternatives.
+    let re = tc.compile(r"a(?i)b|c");
+    re.test_succeeds("aB");
+    re.test_succeeds("C");
+    re.test_fails("Ab");
+    let re = tc.compile(r"(?:(?i)a)a");
+    re.test_succeeds("Aa");
+    re.test_fails("AA");
+
+    // Multiline and dotAll.
+    let re = tc.compile(r"(?m:^b)");
+    re.test_succeeds("a\nb");
+    tc.compile(r"^b").test_fails("a\nb");
+    tc.compile(r"(?m)a$").test_succeeds("a\nb");
+    tc.compilef(r"(?-m:a$)", "m").test_fails("a\nb");
+    tc.compile(r"a(?s:.)b").test_succeeds("a\nb");
+    tc.compile(r"a(?s:.).b").test_fails("a\n\nb");
+    tc.compile(r"a(?s)..b").test_succeeds("a\n\nb");
+
+    // Modifier groups do not capture.
+    assert_eq!(
+        tc.compile(r"(?i:(a))(b)").match1_vec("Ab"),
+        vec![Some("Ab"), Some("A"), Some("b")]
+    );
+    tc.compile(r"(?i:ab)+")
+        .match_all("abAB xAb")
+        .test_eq(vec!["abAB", "Ab"]);
+}
+
+#[test]
+fn test_extended_mode() {
+    test_with_configs(test_extended_mode_tc)
+}
+
+fn test_extended_mode_tc(tc: TestConfig) {
+    let re = tc.compilef(
+        r"
+        (\d{4})   # year
+        -
+        (\d{2})   # month
+        ",
+        "x",
+    );
+    assert_eq!(
+        re.match1_vec("on 2020-05"),
+        vec![Some("2020-05"), Some("2020"), Some("05")]
+    );
+
+    // Whitespace between an atom and its quantifier is ignored.
+    tc.compilef(r"a +b", "x")
+        .match_all("aab ab")
+        .test_eq(vec!["aab", "ab"]);
+
+    // Escaped whitespace and hashes are literal.
+    tc.compilef(r"a\ b\#c", "x").test_succeeds("a b#c");
+    tc.compilef(r"a b", "x").test_fails("a b");
+
+    // Whitespace in brackets is significant.
+    tc.compilef(r"a[ #]b", "x")
+        .match_all("a b a#b")
+        .test_eq(vec!["a b", "a#b"]);
+
+    // Parens in comments are ignored.
+    tc.compilef("(a) # (b)\n \\1", "x").test_succeeds("aa");
+
+    // Inline toggles.
+    tc.compile(r"(?x) a b c").test_succeeds("abc");
+    tc.compile(r"(?x: a b )c d").test_succeeds("abc d");
+    tc.compilef(r"(?-x:a b)", "x").test_succeeds("a b");
+}
+
+#[test]
+fn test_unicode_sets() {
+    test_with_configs(test_unicode_sets_tc)
+}
+
+fn test_unicode_sets_tc(tc: TestConfig) {
+    // Intersection.
+    let re = tc.compilef(r"[[a-z]&&[^aeiou]]+", "v");
+    re.match_all("hello world")
+        .test_eq(vec!["h", "ll", "w", "rld"]);
+    tc.compilef(r"[\w&&\d&&[0-4]]+", "v")
+        .match_all("a0123456")
+        .test_eq(vec!["01234"]);
+
+    // Subtraction.
+    let re = tc.compilef(r"[\p{Letter}--\p{Lowercase}]+", "v");
+    re.match_all("abCDe\u{C9}f").test_eq(vec!["CD", "\u{C9}"]);
+    tc.compilef(r"[[a-z]--[aeiou]--y]+", "v")
+        .match_all("keyboard")
+        .test_eq(vec!["k", "b", "rd"]);
+
+    // Nested classes and negation.
+    let re = tc.compilef(r"[[a-c][x-z]]+", "v");
+    re.match_all("abdxyz").test_eq(vec!["ab", "xyz"]);
+    let re = tc.compilef(r"[^[a-c]--b]+", "v");
+    re.match_all("abcdb").test_eq(vec!["b", "db"]);
+    tc.compilef(r"[a[]]", "v").test_succeeds("a");
+    tc.compilef(r"[\q{a}]", "").test_succeeds("q");
+
+    // String literals, which are matched longest first.
+    let re = tc.compilef(r"[\q{abc|d|}x]", "v");
+    re.match_all("abcdxab")
+        .test_eq(vec!["abc", "d", "x", "", "", ""]);
+    tc.compilef(r"^[\q{a|ab|abc}]$", "v").test_succeeds("abc");
+    tc.compilef(r"^[\q{a|ab|abc}]$", "v").test_succeeds("ab");
+    tc.compilef(r"^[\q{a|ab|abc}]$", "v").test_fails("abcd");
+    tc.compilef(r"[\q{\u{1F600}\||\}}]+", "v")
+        .match_all("\u{1F600}|}!")
+        .test_eq(vec!["\u{1F600}|}"]);
+    tc.compilef(r"[\q{abc}--\q{abc}]", "v").test_fails("abc");
+    tc.compilef(r"[\q{abc|de}&&\q{de|f}]+", "v")
+        .match_all("abcdef")
+        .test_eq(vec!["de"]);
+    tc.compilef(r"[[a-z\q{ab}]--[a-c]]", "v")
+        .match_all("abcdab")
+        .test_eq(vec!["ab", "d", "ab"]);
+    tc.compilef(r"[\q{AB}]", "vi").test_succeeds("ab");
+    tc.compilef(r"[\q{AB}&&\q{ab}]", "vi").test_succeeds("aB");
+    tc.compilef(r"(?<=[\q{ab}])c", "v")
+        .match1f("abc")
+        .test_eq("c");
+
+    // Escaped punctuators.
+    tc.compilef(r"[\&\-\!]+", "v")
+        .match_all("a&-!b")
+        .test_eq(vec!["&-!"]);
+
+    // Case-insensitive.
+    tc.compilef(r"[[a-z]--[b]]", "vi").test_fails("B");
+    tc.compilef(r"[[a-z]--[b]]", "vi").test_succeeds("C");
+
+    // With 'v' and 'i', sets are case folded before they are complemented,
+    // so a complement excludes both cases of its letters.
+    for pattern in [r"\P{Ll}", r"[\P{Ll}]", r"[^\p{Ll}]", r"[^[a-z]]"] {
+        tc.compilef(pattern, "vi").test_fails("a");
+        tc.compilef(pattern, "vi").test_fails("A");
+        tc.compilef(pattern, "vi").test_succeeds("1");
+    }
+    tc.compilef(r"[^\P{Ll}]+", "vi")
+        .match_all("aB1c")
+        .test_eq(vec!["aB", "c"]);
+    tc.compilef(r"\p{Lu}+", "vi")
+        .match_all("aB1c")
+        .test_eq(vec!["aB", "c"]);
+    tc.compilef(r"[\W\D]", "vi").test_succeeds("a");
+    tc.compilef(r"[\w--k]+", "vi")
+        .match_all("sKk\u{212A}\u{17F}")
+        .test_eq(vec!["s", "\u{17F}"]);
+    tc.compilef(r"[\p{L}--[^\p{Lu}]]+", "vi")
+        .match_all("aB1")
+        .test_eq(vec!["aB"]);
+    // With 'u', the complement is taken first, so it has both cases.
+    tc.compilef(r"\P{Ll}", "u").test_succeeds("A");
+    tc.compilef(r"[^\P{Ll}]", "ui").test_fails("a");
+
+    // Property escapes in brackets in Unicode mode.
+    tc.compilef(r"[\p{Lu}\d]+", "u")
+        .match_all("ab1C\u{C9}d")
+        .test_eq(vec!["1C\u{C9}"]);
+    tc.compilef(r"[^\P{Lu}]+", "u")
+        .match_all("abCD")
+        .test_eq(vec!["CD"]);
+}
+
+#[test]
+fn test_properties_of_strings() {
+    test_with_configs(test_properties_of_strings_tc)
+}
+
+fn test_properties_of_strings_tc(tc: TestConfig) {
+    // Sequences are matched whole, longest first.
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let flag = "\u{1F1EF}\u{1F1F5}";
+    let keycap = "#\u{FE0F}\u{20E3}";
+    let thumbs = "\u{1F44D}\u{1F3FD}";
+    let text = format!("a{}b{}c{}d{}e\u{1F600}", family, flag, keycap, thumbs);
+    let re = tc.compilef(r"\p{RGI_Emoji}", "v");
+    re.match_all(&text)
+        .test_eq(vec![family, flag, keycap, thumbs, "\u{1F600}"]);
+    tc.compilef(r"^\p{RGI_Emoji}$", "v")
+        .test_fails("\u{1F468}\u{200D}");
+
+    // The sub-properties.
+    tc.compilef(r"\p{Basic_Emoji}", "v")
+        .match_all(&text)
+        .test_eq(vec![
+            "\u{1F468}",
+            "\u{1F469}",
+            "\u{1F467}",
+            "\u{1F44D}",
+            "\u{1F3FD}",
+            "\u{1F600}",
+        ]);
+    tc.compilef(r"\p{Emoji_Keycap_Sequence}", "v")
+        .match_all(&text)
+        .test_eq(vec![keycap]);
+    tc.compilef(r"\p{RGI_Emoji_Flag_Sequence}", "v")
+        .match_all(&text)
+        .test_eq(vec![flag]);
+    tc.compilef(r"\p{RGI_Emoji_Modifier_Sequence}", "v")
+        .match_all(&text)
+        .test_eq(vec![thumbs]);
+    tc.compilef(r"\p{RGI_Emoji_ZWJ_Sequence}", "v")
+        .match_all(&text)
+        .test_eq(vec![family]);
+    tc.compilef(r"\p{RGI_Emoji_Tag_Sequence}", "v")
+        .test_succeeds("\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}");
+
+    // In brackets, with set operations.
+    tc.compilef(r"[\p{RGI_Emoji}--\q{\u{1F1EF}\u{1F1F5}}]+", "v")
+        .match_all(&text)
+        .test_eq(vec![family, keycap, thumbs, "\u{1F600}"]);
+    tc.compilef(r"[\p{RGI_Emoji}&&\p{RGI_Emoji_Flag_Sequence}]", "v")
+        .match_all(&text)
+        .test_eq(vec![flag]);
+    tc.compilef(r"[\p{RGI_Emoji_Flag_Sequence}x]+", "v")
+        .match_all("x\u{1F1EF}\u{1F1F5}x")
+        .test_eq(vec!["x\u{1F1EF}\u{1F1F5}x"]);
+}
+
+#[test]
+fn test_script_property_escapes() {
+    test_with_configs(test_script_property_escapes_tc)
+}
+
+fn test_script_property_escapes_tc(tc: TestConfig) {
+    let re = tc.compilef(r"\p{Script=Greek}+", "u");
+    re.match_all("abc\u{3B1}\u{3B2}d\u{3A9}")
+        .test_eq(vec!["\u{3B1}\u{3B2}", "\u{3A9}"]);
+    tc.compilef(r"\p{sc=Grek}", "u").test_succeeds("\u{3B1}");
+    tc.compilef(r"\p{scx=Han}", "u").test_succeeds("\u{4E00}");
+    tc.compilef(r"\p{Script_Extensions=Latin}", "u")
+        .test_fails("\u{4E00}");
+
+    // The ideographic comma is Common, but used with Han among others.
+    tc.compilef(r"\p{scx=Han}", "u").test_succeeds("\u{3001}");
+    tc.compilef(r"\p{scx=Hira}", "u").test_succeeds("\u{3001}");
+    tc.compilef(r"\p{sc=Han}", "u").test_fails("\u{3001}");
+    tc.compilef(r"\p{sc=Common}", "u").test_succeeds("\u{3001}");
+    tc.compilef(r"\p{scx=Common}", "u").test_fails("\u{3001}");
+    tc.compilef(r"\P{scx=Han}", "u").test_fails("\u{3001}");
+    tc.compilef(r"[\p{scx=Han}--\p{sc=Han}]", "v")
+        .test_succeeds("\u{3001}");
+    // The Arabic tatweel is used with several joining scripts.
+    tc.compilef(r"\p{scx=Syriac}", "u").test_succeeds("\u{640}");
+    tc.compilef(r"\p{sc=Syriac}", "u").test_fails("\u{640}");
+    // Inherited marks may extend a single script.
+    tc.compilef(r"\p{scx=Greek}", "u").test_succeeds("\u{342}");
+    tc.compilef(r"\p{scx=Inherited}", "u").test_fails("\u{342}");
+    tc.compilef(r"\p{sc=Inherited}", "u")
+        .test_succeeds("\u{342}");
+
+    // Negation is the complement over all code points.
+    let re = tc.compilef(r"\P{Script=Greek}+", "u");
+    re.match_all("\u{3B1}a\u{1F600}\u{3B2}")
+        .test_eq(vec!["a\u{1F600}"]);
+    let re = tc.compilef(r"[\P{Script=Greek}]+", "u");
+    re.match_all("\u{3B1}a\u{1F600}\u{3B2}")
+        .test_eq(vec!["a\u{1F600}"]);
+
+    // General categories may be given by name.
+    tc.compilef(r"\p{General_Category=Lu}", "u")
+        .test_succeeds("A");
+    tc.compilef(r"\p{gc=Uppercase_Letter}", "u").test_fails("a");
+}
+
+#[test]
+fn test_unicode_version() {
+    assert_eq!(regress::UNICODE_VERSION, "13.0.0");
+}
+
+#[test]
+fn test_find_with_budget() {
+    for pattern in &[r"(a+)+$", r"(a|aa)+$", r"(?=(a+)+$)a"] {
+        let re = regress::Regex::new(pattern).unwrap();
+        let text = "a".repeat(40) + "!";
+        assert_eq!(
+            re.find_with_budget(&text, 100_000).unwrap_err(),
+            regress::BudgetExceeded
+        );
+    }
+
+    // Matches within the budget are reported as usual.
+    let re = regress::Regex::new(r"(a+)+b").unwrap();
+    let m = re.find_with_budget("xaab", 1_000).unwrap().unwrap();
+    assert_eq!(m.range(), 1..4);
+    assert_eq!(m.group(1), Some(1..3));
+    assert!(re.find_with_budget("xyz", 1_000).unwrap().is_none());
+
+    // A literal found directly by the prefix search takes no steps.
+    let re = regress::Regex::new(r"abc").unwrap();
+    assert!(re.find_with_budget("xxabc", 0).unwrap().is_some());
+}
+
+#[test]
+fn test_literal_prefix_search() {
+    test_with_configs(test_literal_prefix_search_tc)
+}
+
+fn test_literal_prefix_search_tc(tc: TestConfig) {
+    let haystack = "x".repeat(100) + "foobar12 foo fooba foobar345";
+    tc.compile(r"foobar\d+")
+        .match_all(&haystack)
+        .test_eq(vec!["foobar12", "foobar345"]);
+    tc.compile(r"(foo)?bar")
+        .match_all("bar foobar")
+        .test_eq(vec!["bar", "foobar"]);
+    tc.compilef(r"kb", "i")
+        .match_all("xxKB kb Kb")
+        .test_eq(vec!["KB", "kb", "Kb"]);
+    tc.compile(r"(?:ab|cd|ef)x")
+        .match_all("abcdx efx abx cdy")
+        .test_eq(vec!["cdx", "efx", "abx"]);
+    tc.compile(r"été")
+        .match_all("\u{E9}\u{E9}t\u{E9}, ete, \u{E9}t\u{E9}")
+        .test_eq(vec!["\u{E9}t\u{E9}", "\u{E9}t\u{E9}"]);
+    tc.compilef(r"\u{1F600}+", "u")
+        .match_all("a\u{1F600}\u{1F600}b\u{1F600}")
+        .test_eq(vec!["\u{1F600}\u{1F600}", "\u{1F600}"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_par_find_all() {
+    let re = regress::Regex::new(r"(\d+)-(?<word>[a-z]+)").unwrap();
+    let haystacks: Vec<String> = (0..1000)
+        .map(|i| {
+            if i % 3 == 0 {
+                format!("x {}-abc", i)
+            } else {
+                format!("{} none", i)
+            }
+        })
+        .collect();
+    let haystacks: Vec<&str> = haystacks.iter().map(String::as_str).collect();
+    let found = re.par_find_all(&haystacks);
+    assert_eq!(found.len(), haystacks.len());
+    for ((text, m), i) in haystacks.iter().zip(&found).zip(0..) {
+        assert_eq!(
+            m.as_ref().map(|m| m.range()),
+            re.find(text).map(|m| m.range())
+        );
+        if let Some(m) = m {
+            assert_eq!(&text[m.group(1).unwrap()], i.to_string());
+            assert_eq!(&text[m.named_group("word").unwrap()], "abc");
+        }
+    }
+    assert_eq!(found.iter().filter(|m| m.is_some()).count(), 334);
+
+    assert!(re.par_find_all(&[]).is_empty());
+    assert!(re.par_find_all(&["1-a"])[0].is_some());
+}
+
+#[test]
+fn test_regex_shared_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<regress::Regex>();
+
+    let re = regress::Regex::new(r"(\d+)-(?<word>[a-z]+)").unwrap();
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let re = re.clone();
+            std::thread::spawn(move || {
+                let text = format!("{}-abc x {}-def", i, i * 10);
+                for _ in 0..100 {
+                    let ms: Vec<_> = re.find_iter(&text).collect();
+                    assert_eq!(ms.len(), 2);
+                    assert_eq!(&text[ms[0].group(1).unwrap()], i.to_string());
+                    assert_eq!(&text[ms[1].named_group("word").unwrap()], "def");
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(re.find("7-q").unwrap().range(), 0..3);
+}
+
+#[test]
+fn test_regex_source() {
+    let re = regress::Regex::new(r"(\d+)-[a-z]").unwrap();
+    assert_eq!(re.as_str(), r"(\d+)-[a-z]");
+    assert_eq!(re.to_string(), r"(\d+)-[a-z]");
+    assert_eq!(format!("{:#}", re), r"/(\d+)-[a-z]/");
+    assert_eq!(re.clone().as_str(), re.as_str());
+
+    let re = regress::Regex::with_flags("x$", "miu").unwrap();
+    assert_eq!(format!("{:#}", re), "/x$/miu");
+
+    let re = regress::Regex::from_unicode("a\u{1F600}".chars().map(u32::from), "").unwrap();
+    assert_eq!(re.as_str(), "a\u{1F600}");
+    let re = regress::Regex::from_unicode([0x61, 0xD800].iter().copied(), "").unwrap();
+    assert_eq!(re.as_str(), "a\u{FFFD}");
+}
+
+#[test]
+fn test_regex_eq_hash() {
+    use regress::Regex;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let hash = |re: &Regex| {
+        let mut hasher = DefaultHasher::new();
+        re.hash(&mut hasher);
+        hasher.finish()
+    };
+    let re = |pattern: &str, flags: &str| Regex::with_flags(pattern, flags).unwrap();
+
+    // Equal sources and flags make equal regexes, however they were built.
+    assert_eq!(re(r"a+\d", "i"), re(r"a+\d", "i"));
+    assert_eq!(hash(&re(r"a+\d", "i")), hash(&re(r"a+\d", "i")));
+    assert_eq!(re(r"a+", "mi"), re(r"a+", "im"));
+    let built = regress::RegexBuilder::new(r"a+").build().unwrap();
+    assert_eq!(built, re(r"a+", ""));
+
+    // The settings of the builder are compared.
+    let build = |unit, budget: Option<usize>| {
+        let mut builder = regress::RegexBuilder::new(r"a+");
+        builder.offset_unit(unit);
+        if let Some(budget) = budget {
+            builder.step_budget(budget);
+        }
+        builder.build().unwrap()
+    };
+    use regress::OffsetUnit::{Bytes, Utf16};
+    assert_ne!(build(Bytes, Some(10)), re(r"a+", ""));
+    assert_ne!(build(Bytes, Some(10)), build(Bytes, Some(11)));
+    assert_eq!(build(Utf16, Some(10)), build(Utf16, Some(10)));
+    assert_eq!(hash(&build(Utf16, None)), hash(&build(Utf16, None)));
+    assert_ne!(build(Utf16, None), re(r"a+", ""));
+
+    // The programs of regexes whose pattern is unknown are compared, so these
+    // are not equal merely for having the same source and flags.
+    let program = re(r"a+", "").serialize_program();
+    let deserialized = Regex::from_serialized(&program, r"a+").unwrap();
+    let again = Regex::from_serialized(&program, r"a+").unwrap();
+    assert_eq!(deserialized, again);
+    assert_eq!(hash(&deserialized), hash(&again));
+    assert_ne!(deserialized, re(r"a+", ""));
+    let other = Regex::from_serialized(&re(r"b+", "").serialize_program(), r"a+").unwrap();
+    assert_ne!(deserialized, other);
+    assert_ne!(hash(&deserialized), hash(&other));
+    let other = Regex::from_serialized(&program, r"b+").unwrap();
+    assert_ne!(deserialized, other);
+
+    // Flags are compared, including those which only affect compilation.
+    assert_ne!(re(r"a", ""), re(r"a", "i"));
+    assert_ne!(re(r"a", "u"), re(r"a", "v"));
+    let mut no_opt = regress::Flags::default();
+    no_opt.no_opt = true;
+    assert_ne!(re(r"a", ""), Regex::with_flags(r"a", no_opt).unwrap());
+
+    // Equality is syntactic.
+    assert_ne!(re(r"abc", ""), re(r"(?:abc)", ""));
+    assert_ne!(re(r"a b", "x"), re(r"ab", "x"));
+    assert_eq!(
+        re(r"abc", "").canonical_key(),
+        re(r"(?:abc)", "").canonical_key()
+    );
+}
+
+#[test]
+fn test_unparticipated_backreferences() {
+    test_with_configs(test_unparticipated_backreferences_tc)
+}
+
+fn test_unparticipated_backreferences_tc(tc: TestConfig) {
+    // A backreference to a group which did not participate matches the
+    // empty string.
+    tc.compile(r"(?:(a)|b)\1").match1f("b").test_eq("b,");
+    tc.compile(r"(?:(a)|b)\1c").match1f("bc").test_eq("bc,");
+    tc.compile(r"^(?:(a)|b)\1$").test_fails("ba");
+    tc.compile(r"(a)?x\1y").match1f("xy").test_eq("xy,");
+    tc.compile(r"(?:(a)|(b))\1\2")
+        .match1f("aa")
+        .test_eq("aa,a,");
+    tc.compilef(r"(?:(a)|b)\1", "i").match1f("b").test_eq("b,");
+
+    // Forward references, and references from within the group, match the
+    // empty string.
+    tc.compile(r"\1(a)").match1f("a").test_eq("a,a");
+    tc.compile(r"(a\1)").match1f("aa").test_eq("a,a");
+    tc.compile(r"\2(a)(b)").match1f("ab").test_eq("ab,a,b");
+
+    // Captures are reset on each iteration of a quantified group, so a
+    // backreference sees only the group from its own iteration.
+    tc.compile(r"(?:(a)|b\1)+").match1f("aba").test_eq("aba,a");
+    tc.compile(r"^(?:(a)|(b))*\1\2$").match1f("").test_eq(",,");
+    tc.compile(r"^(?:(a)|(b))+\2$")
+        .match1f("abb")
+        .test_eq("abb,,b");
+    tc.compile(r"^(?:(a)|(b))+\1$")
+        .match1f("ab")
+        .test_eq("ab,,b");
+}
+
+#[test]
+fn test_lookaround_captures() {
+    test_with_configs(test_lookaround_captures_tc)
+}
+
+fn test_lookaround_captures_tc(tc: TestConfig) {
+    // Negative lookarounds never export their captures.
+    assert_eq!(tc.compile(r"(?!(x))y").match1_vec("y"), &[Some("y"), None]);
+    assert_eq!(
+        tc.compile(r"(?!(a)b)(a)c").match1_vec("ac"),
+        &[Some("ac"), None, Some("a")]
+    );
+    assert_eq!(
+        tc.compile(r"(?<!(a)c)ab").match1_vec("ab"),
+        &[Some("ab"), None]
+    );
+    assert_eq!(
+        tc.compile(r"(?:(?!(a))\w)+").match1_vec("ba"),
+        &[Some("b"), None]
+    );
+
+    // Positive lookarounds export their captures when they succeed, but not
+    // once a later failure backtracks past them.
+    assert_eq!(
+        tc.compile(r"(?=(a)+)a(?!(b))").match1_vec("aab"),
+        &[Some("a"), Some("a"), None]
+    );
+    assert_eq!(
+        tc.compile(r"(?=(a)(?!(b)))a").match1_vec("ac"),
+        &[Some("a"), Some("a"), None]
+    );
+    assert_eq!(
+        tc.compile(r"(?<=(a))c|ab").match1_vec("ab"),
+        &[Some("ab"), None]
+    );
+    assert_eq!(
+        tc.compile(r"(?=(a))ab|ac").match1_vec("ac"),
+        &[Some("ac"), None]
+    );
+    assert_eq!(
+        tc.compile(r"(?=(a)b)|a").match1_vec("a"),
+        &[Some("a"), None]
+    );
+    assert_eq!(
+        tc.compile(r"(?:(?=(a))a|b)+").match1_vec("ab"),
+        &[Some("ab"), None]
+    );
+}
+
+#[test]
+fn test_named_backreferences() {
+    test_with_configs(test_named_backreferences_tc)
+}
+
+fn test_named_backreferences_tc(tc: TestConfig) {
+    tc.compile(r#"(?<q>['"]).*?\k<q>"#)
+        .match_all(r#"say "it's" and 'x"y'"#)
+        .test_eq(vec![r#""it's""#, r#"'x"y'"#]);
+
+    // Names may contain 'u' and may be written with \u escapes.
+    tc.compile(r"(?<value>\d)\k<value>")
+        .match1f("a11")
+        .test_eq("11,1");
+    tc.compile(r"(?<ab>\d)\k<ab>")
+        .match1f("a22")
+        .test_eq("22,2");
+    tc.compilef(r"(?<\u{1D49C}>.)\k<\u{1D49C}>", "u")
+        .match1f("xx")
+        .test_eq("xx,x");
+
+    // Forward references: the group is defined after its use.
+    tc.compile(r"\k<later>(?<later>a)")
+        .match1f("a")
+        .test_eq("a,a");
+    // Captures are reset on each iteration, so the last one leaves it unset.
+    tc.compile(r"(?:\k<later>b|(?<later>a))+")
+        .match1f("aab")
+        .test_eq("aab,");
+    tc.compile(r"(?:x)(?<n>a)\k<n>")
+        .match1f("xaa")
+        .test_eq("xaa,a");
+
+    // Comparison is by code point and respects case-insensitivity.
+    tc.compilef(r"(?<c>.)\k<c>", "u")
+        .match1f("\u{1F600}\u{1F600}")
+        .test_eq("\u{1F600}\u{1F600},\u{1F600}");
+    tc.compilef(r"(?<w>\w+) \k<w>", "i")
+        .match1f("Hello hELLO")
+        .test_eq("Hello hELLO,Hello");
+    tc.compilef(r"(?<w>.)\k<w>", "iu")
+        .match1f("\u{C9}\u{E9}")
+        .test_eq("\u{C9}\u{E9},\u{C9}");
+}
+
+#[test]
+fn test_captures() {
+    let re = regress::Regex::new(r"(?<key>[a-z]+)=(\d+)?").unwrap();
+    let text = String::from("a=1 bc= d=23");
+    let caps: Vec<regress::Captures> = re.captures_iter(&text).collect();
+    assert_eq!(caps.len(), 3);
+    assert_eq!(caps[0].range(), 0..3);
+    assert_eq!(caps[1].get(0), Some(4..7));
+    assert_eq!(caps[1].name("key"), Some(4..6));
+    assert_eq!(caps[1].get(2), None);
+    assert_eq!(caps[2].get(2), Some(10..12));
+    assert_eq!(caps[2].get(3), None);
+    assert_eq!(caps[2].name("nope"), None);
+    assert_eq!(
+        caps[2].iter().collect::<Vec<_>>(),
+        vec![Some(8..12), Some(8..9), Some(10..12)]
+    );
+
+    // Captures do not borrow the input.
+    drop(text);
+    assert_eq!(caps[0].name("key"), Some(0..1));
+
+    let m = re.find("zz=9").unwrap();
+    let from_match = regress::Captures::from(m.clone());
+    assert_eq!(from_match, re.captures("zz=9").unwrap());
+    assert_eq!(from_match.get(1), m.group(1));
+    assert!(re.captures("==").is_none());
+}
+
+#[test]
+fn test_named_groups_order() {
+    // Declaration order is retained regardless of the names.
+    let re = regress::Regex::new(r"(?<z>a)(?<y>b)(c)(?<x>d)?(?<w>e)").unwrap();
+    let m = re.find("abce").unwrap();
+    let groups: Vec<_> = m.named_groups().collect();
+    assert_eq!(
+        groups,
+        vec![
+            ("z", Some(0..1)),
+            ("y", Some(1..2)),
+            ("x", None),
+            ("w", Some(3..4))
+        ]
+    );
+    assert_eq!(m.named_groups().len(), 4);
+
+    // Nested groups are ordered by their opening parens.
+    let text = "ab";
+    let re = regress::Regex::new(r"(?<outer>(?<first>a)(?<second>b))").unwrap();
+    let m = re.find(text).unwrap();
+    let groups: Vec<_> = m.named_group_strs(text).collect();
+    assert_eq!(
+        groups,
+        vec![
+            ("outer", Some("ab")),
+            ("first", Some("a")),
+            ("second", Some("b"))
+        ]
+    );
+
+    // No named groups.
+    let m = regress::Regex::new(r"(a)").unwrap().find("a").unwrap();
+    assert_eq!(m.named_groups().next(), None);
+}
+
+#[test]
+fn test_named_groups_stable_order() {
+    // Enough names that a hashed order would vary between compilations.
+    let names: Vec<String> = (0..40).map(|i| format!("n{}", (i * 7) % 40)).collect();
+    let pattern: String = names.iter().map(|n| format!("(?<{}>.)", n)).collect();
+    let text = "x".repeat(names.len());
+    let first = regress::Regex::new(&pattern).unwrap();
+    let program = first.serialize_program();
+    for _ in 0..20 {
+        let re = regress::Regex::new(&pattern).unwrap();
+        let m = re.find(&text).unwrap();
+        let found: Vec<_> = m.named_groups().map(|(name, _)| name).collect();
+        assert_eq!(found, names);
+        let declared: Vec<_> = re.capture_names().map(Option::unwrap).collect();
+        assert_eq!(declared, names);
+        assert_eq!(re.serialize_program(), program);
+        assert_eq!(re.captures(&text), first.captures(&text));
+    }
+
+    // A deserialized program keeps the order.
+    let re = regress::Regex::from_serialized(&program, &pattern).unwrap();
+    let m = re.find(&text).unwrap();
+    assert!(m.named_groups().map(|(name, _)| name).eq(names.iter()));
+    assert_eq!(re.index_to_name(2), Some("n7"));
+    assert_eq!(re.name_to_index("n7"), Some(2));
+}
+
+#[test]
+fn test_match_strs() {
+    let text = String::from("añb€c 12");
+    let re = regress::Regex::new(r"(?<first>\S)(\S+?)(?<last>€?)c|(\d)").unwrap();
+
+    // The slices borrow from the text, and outlive the match.
+    let (whole, groups, named) = {
+        let m = re.find(&text).unwrap();
+        let groups: Vec<_> = (0..=5).map(|idx| m.group_str(&text, idx)).collect();
+        let named = [
+            m.named_group_str(&text, "first"),
+            m.named_group_str(&text, "last"),
+            m.named_group_str(&text, "missing"),
+        ];
+        (m.as_str(&text), groups, named)
+    };
+    assert_eq!(whole, "añb€c");
+    assert_eq!(
+        groups,
+        vec![Some("añb€c"), Some("a"), Some("ñb"), Some("€"), None, None]
+    );
+    assert_eq!(named, [Some("a"), Some("€"), None]);
+
+    let m = re.find_from(&text, 8).next().unwrap();
+    assert_eq!(m.as_str(&text), "1");
+    assert_eq!(m.group_str(&text, 4), Some("1"));
+    assert_eq!(m.named_group_str(&text, "first"), None);
+
+    // Empty matches are empty slices.
+    let m = regress::Regex::new(r"x*").unwrap().find(&text).unwrap();
+    assert_eq!(m.as_str(&text), "");
+    assert_eq!(m.group_str(&text, 0), Some(""));
+}
+
+#[test]
+fn test_owned_match() {
+    let re = regress::Regex::new(r"(?<first>\S)(\S+?)(?<last>€?)c|(\d)").unwrap();
+    let owned: Vec<regress::OwnedMatch> = {
+        let text = String::from("añb€c 12");
+        re.find_iter(&text).map(|m| m.into_owned(&text)).collect()
+    };
+    assert_eq!(owned.len(), 3);
+    let m = &owned[0];
+    assert_eq!(m.as_str(), "añb€c");
+    assert_eq!(m.range(), 0..8);
+    assert_eq!(m.group_count(), 4);
+    assert_eq!(
+        m.iter().collect::<Vec<_>>(),
+        vec![Some("añb€c"), Some("a"), Some("ñb"), Some("€"), None]
+    );
+    assert_eq!(m.group(5), None);
+    assert_eq!(m.named_group("last"), Some("€"));
+    assert_eq!(m.named_group("missing"), None);
+    assert_eq!(
+        m.named_groups().collect::<Vec<_>>(),
+        vec![("first", Some("a")), ("last", Some("€"))]
+    );
+    assert_eq!(owned[1].as_str(), "1");
+    assert_eq!(owned[1].range(), 9..10);
+    assert_eq!(owned[1].named_group("first"), None);
+    assert_eq!(owned[1].group(4), Some("1"));
+
+    // Empty matches own empty strings.
+    let text = "ab";
+    let m = regress::Regex::new(r"x*").unwrap().find(text).unwrap();
+    assert_eq!(m.into_owned(text).as_str(), "");
+    assert_eq!(m.into_owned(text), m.into_owned(text));
+
+    // An owned match may be sent to another thread.
+    let m = owned[2].clone();
+    let handle = std::thread::spawn(move || m.group(4).map(String::from));
+    assert_eq!(handle.join().unwrap().as_deref(), Some("2"));
+}
+
+#[test]
+fn test_canonical_key() {
+    let key = |pattern: &str, flags: &str| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .canonical_key()
+    };
+    let same = [
+        (r"abc", "", r"(?:abc)", ""),
+        (r"abc", "", r"(?:a(?:b)c)", ""),
+        (r"[a-c]", "", r"[abc]", ""),
+        (r"[a-cx]", "", r"[xcba]", ""),
+        (r"a{2,}", "", r"aaa*", ""),
+        (r"a+", "", r"a{1,}", ""),
+        (r"a{3}", "", r"aaa", ""),
+        (r"\d", "", r"[0-9]", ""),
+        (r"a b # c", "x", r"ab", ""),
+        (r"abc", "O", r"abc", ""),
+        (r"(?<n>a)\k<n>", "", r"(?<n>a)\1", ""),
+        (r"12", "i", r"12", "i"),
+    ];
+    for (p1, f1, p2, f2) in same {
+        assert_eq!(key(p1, f1), key(p2, f2), "{} /{} vs {} /{}", p1, f1, p2, f2);
+    }
+
+    let different = [
+        (r"abc", "", r"abd", ""),
+        (r"abc", "", r"abc", "i"),
+        (r"abc", "", r"(abc)", ""),
+        (r"(?<n>a)", "", r"(?<m>a)", ""),
+        (r"a*", "", r"a*?", ""),
+        (r"^a", "", r"^a", "m"),
+        (r"a", "", r"a", "y"),
+        (r"a|b", "", r"b|a", ""),
+        (r"(?=a)", "", r"(?!a)", ""),
+    ];
+    for (p1, f1, p2, f2) in different {
+        assert_ne!(key(p1, f1), key(p2, f2), "{} /{} vs {} /{}", p1, f1, p2, f2);
+    }
+
+    // The settings of the builder which change matching are part of the key.
+    use regress::{OffsetUnit, RegexBuilder, WordBoundary};
+    let default = RegexBuilder::new(r"\b").unicode(true).build().unwrap();
+    let ascii = RegexBuilder::new(r"\b")
+        .unicode(true)
+        .word_boundary(WordBoundary::Ascii)
+        .build()
+        .unwrap();
+    assert!(default.find("é").is_some());
+    assert!(ascii.find("é").is_none());
+    assert_ne!(default.canonical_key(), ascii.canonical_key());
+    let unicode = RegexBuilder::new(r"\b")
+        .unicode(true)
+        .word_boundary(WordBoundary::Unicode)
+        .build()
+        .unwrap();
+    assert_eq!(default.canonical_key(), unicode.canonical_key());
+    let budget = RegexBuilder::new(r"\b")
+        .unicode(true)
+        .step_budget(10)
+        .build()
+        .unwrap();
+    assert_ne!(default.canonical_key(), budget.canonical_key());
+    let utf16 = RegexBuilder::new(r"\b")
+        .unicode(true)
+        .offset_unit(OffsetUnit::Utf16)
+        .build()
+        .unwrap();
+    assert_ne!(default.canonical_key(), utf16.canonical_key());
+}
+
+#[test]
+fn test_simplify_redundant_constructs() {
+    use regress::{Flags, Regex};
+    let key = |pattern: &str| Regex::new(pattern).unwrap().canonical_key();
+
+    // Each pattern has the same optimized IR as its simplified form.
+    let simplified = [
+        (r"(?:(?:a))", r"a"),
+        (r"(?:a(?:))b", r"ab"),
+        (r"a{1}b{1,1}?", r"ab"),
+        (r"(a){1}", r"(a)"),
+        (r"(?:(a)|b){1}\1", r"(?:(a)|b)\1"),
+        (r"[a]b[c]", r"abc"),
+        (r"[\u{1F600}]", r"\u{1F600}"),
+        (r"a(?:||)", r"a"),
+        (r"a||", r"a|"),
+        (r"|a|", r"|a"),
+        (r"b(?:|a||c|)", r"b(?:|a|c)"),
+    ];
+    for (before, after) in simplified {
+        assert_eq!(key(before), key(after), "{} vs {}", before, after);
+    }
+
+    // An empty alternative before the first may be needed, as may a class
+    // which is not a single char.
+    let kept = [
+        (r"a||b", r"a|b"),
+        (r"a|", r"a"),
+        (r"(?:a|)b", r"ab"),
+        (r"[^a]", r"a"),
+        (r"[ab]", r"a"),
+        (r"a{1,2}", r"a"),
+    ];
+    for (before, after) in kept {
+        assert_ne!(key(before), key(after), "{} vs {}", before, after);
+    }
+
+    // The simplified program of an unoptimized regex numbers and reports its
+    // groups as before.
+    let mut no_opt = Flags::default();
+    no_opt.no_opt = true;
+    let cases = [
+        (r"(?:(a)|b){1}(c)[d]\1", "xacdad"),
+        (r"(?<x>[a]){1,1}(?:)\k<x>", "aa"),
+        (r"((?:z||y)){1}(w)?", "zw"),
+        (r"(?<=(a){1}[b])c", "abc"),
+    ];
+    for (pattern, text) in cases {
+        let re = Regex::with_flags(pattern, no_opt).unwrap();
+        let simplified = re.try_simplify().unwrap();
+        assert_ne!(
+            re.debug_program(),
+            simplified.debug_program(),
+            "{}",
+            pattern
+        );
+        assert_eq!(simplified.as_str(), re.as_str());
+        assert_eq!(simplified.group_count(), re.group_count());
+        let (m1, m2) = (re.find(text).unwrap(), simplified.find(text).unwrap());
+        assert_eq!(m1.range(), m2.range(), "{}", pattern);
+        assert_eq!(m1.captures, m2.captures, "{}", pattern);
+        assert_eq!(m1.named_group("x"), m2.named_group("x"), "{}", pattern);
+    }
+
+    // Optimized regexes are already simplified.
+    let re = Regex::new(r"(?:(a)){1}[b]").unwrap();
+    let simplified = re.try_simplify().unwrap();
+    assert_eq!(re.debug_program(), simplified.debug_program());
+
+    // A deserialized regex has no known pattern, even with its own source,
+    // so it is not simplified.
+    let bytes = re.serialize_program();
+    assert!(Regex::from_serialized(&bytes, "ab")
+        .unwrap()
+        .try_simplify()
+        .is_none());
+    assert!(Regex::from_serialized(&bytes, r"(?:(a)){1}[b]")
+        .unwrap()
+        .try_simplify()
+        .is_none());
+}
+
+#[test]
+fn test_dot_matches_all() {
+    let build = |pattern: &str, flags: &str, dot_matches_all: bool| {
+        regress::RegexBuilder::new(pattern)
+            .flags(flags)
+            .dot_matches_all(dot_matches_all)
+            .build()
+            .unwrap()
+    };
+    let find = |pattern: &str, flags: &str, dot_matches_all: bool, text: &str| {
+        build(pattern, flags, dot_matches_all)
+            .find(text)
+            .map(|m| m.range())
+    };
+    for flags in ["", "u", "v", "i"] {
+        // By default `.` excludes the four line terminators.
+        for text in ["\n", "\r", "\u{2028}", "\u{2029}"] {
+            assert_eq!(find(r".", flags, false, text), None);
+            assert_eq!(find(r".", flags, true, text), Some(0..text.len()));
+            assert_eq!(
+                find(r".", &format!("{}s", flags), false, text),
+                Some(0..text.len())
+            );
+        }
+        assert_eq!(find(r"a.+b", flags, true, "a\r\n\u{2028}b"), Some(0..7));
+
+        // Both agree when set together, and disabling 's' in a group does
+        // not change the option.
+        assert_eq!(find(r".", &format!("{}s", flags), true, "\n"), Some(0..1));
+        assert_eq!(
+            find(r"(?-s:.)", &format!("{}s", flags), true, "\n"),
+            Some(0..1)
+        );
+        assert_eq!(find(r"(?-s:.)", &format!("{}s", flags), false, "\n"), None);
+
+        // Classes and \N are unaffected.
+        assert_eq!(find(r"[^a]", flags, true, "\n"), Some(0..1));
+        assert_eq!(find(r"[.]", flags, true, "\n"), None);
+    }
+    let re = regress::RegexBuilder::new(r"\N")
+        .line_break_escapes(true)
+        .dot_matches_all(true)
+        .build()
+        .unwrap();
+    assert!(re.find("\n").is_none());
+
+    // The option survives serialization, though not the flag letters.
+    let re = build(r"a.b", "", true);
+    let re2 = regress::Regex::from_serialized(&re.serialize_program(), re.as_str()).unwrap();
+    assert!(re2.is_match("a\nb"));
+    assert_eq!(re.to_string(), "a.b");
+    assert_eq!(format!("{:#}", re), "/a.b/");
+    assert_ne!(re, build(r"a.b", "", false));
+}
+
+#[test]
+fn test_word_boundary_strategy() {
+    use regress::{CodePointSet, Interval, Regex, RegexBuilder, WordBoundary};
+    let build = |pattern: &str, flags: &str, wb: Option<WordBoundary>| {
+        let mut builder = RegexBuilder::new(pattern);
+        builder.flags(flags).word_edge_escapes(true);
+        if let Some(wb) = wb {
+            builder.word_boundary(wb);
+        }
+        builder.build().unwrap()
+    };
+    let starts = |re: &Regex, text: &str| -> Vec<usize> {
+        let found: Vec<usize> = re.find_iter(text).map(|m| m.start()).collect();
+        // The backtracker agrees with the per-position check, which may use
+        // the lazy DFA.
+        let mask = re.matches_at_each_position(text);
+        let expected: Vec<usize> = text
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain(core::iter::once(text.len()))
+            .zip(mask)
+            .filter_map(|(idx, m)| m.then_some(idx))
+            .collect();
+        assert_eq!(found, expected, "{} on {:?}", re, text);
+        found
+    };
+
+    // By default the flags choose the word chars.
+    assert_eq!(starts(&build(r"\b", "", None), "café"), [0, 3]);
+    assert_eq!(starts(&build(r"\b", "u", None), "café"), [0, 5]);
+    assert_eq!(
+        starts(&build(r"\b", "", Some(WordBoundary::Unicode)), "café"),
+        [0, 5]
+    );
+    assert_eq!(
+        starts(&build(r"\b", "u", Some(WordBoundary::Ascii)), "café"),
+        [0, 3]
+    );
+    assert_eq!(
+        starts(&build(r"\B", "v", Some(WordBoundary::Ascii)), "café"),
+        [1, 2, 5]
+    );
+    assert_eq!(
+        starts(&build(r"\>", "u", Some(WordBoundary::Ascii)), "café"),
+        [3]
+    );
+
+    // A custom set: Unicode word chars without Hebrew, so that a boundary
+    // falls between Latin and Hebrew runs.
+    let mut hebrew = CodePointSet::new();
+    hebrew.add(Interval::new(0x0590, 0x05FF));
+    let custom = WordBoundary::Custom(WordBoundary::Unicode.word_chars().difference(&hebrew));
+    let text = "abc\u{5E9}\u{5DC}\u{5D5}\u{5DD} déf";
+    for flags in ["", "u", "i"] {
+        let re = build(r"\b", flags, Some(custom.clone()));
+        assert_eq!(starts(&re, text), [0, 3, 12, 16]);
+        let re = build(r"\<", flags, Some(custom.clone()));
+        assert_eq!(starts(&re, text), [0, 12]);
+        let re = build(r"\B", flags, Some(custom.clone()));
+        assert_eq!(starts(&re, text), [1, 2, 5, 7, 9, 11, 13, 15]);
+    }
+    let re = build(r"\b[a-z]+\b", "", Some(custom.clone()));
+    assert_eq!(re.find(text).unwrap().range(), 0..3);
+
+    // Case-insensitive matching and captures are unaffected.
+    let re = build(r"(\w+)\b", "i", Some(custom.clone()));
+    assert_eq!(re.find(text).unwrap().group(1), Some(0..3));
+    let ascii_only = WordBoundary::Custom(WordBoundary::Ascii.word_chars());
+    let re = build(r"\bé", "u", Some(ascii_only));
+    assert_eq!(starts(&re, "café"), [3]);
+
+    // The setting is kept when serializing, and regexes which differ in it
+    // are not equal.
+    let re = build(r"\b", "", Some(custom.clone()));
+    let re2 = Regex::from_serialized(&re.serialize_program(), re.as_str()).unwrap();
+    assert_eq!(starts(&re2, text), [0, 3, 12, 16]);
+    assert_eq!(
+        re2,
+        Regex::from_serialized(&re.serialize_program(), re.as_str()).unwrap()
+    );
+    let program = build(r"\b", "", None).serialize_program();
+    assert_ne!(re2, Regex::from_serialized(&program, re.as_str()).unwrap());
+    assert_ne!(re, build(r"\b", "", None));
+    assert!(re.incremental().is_err());
+    assert!(build(r"\b", "", Some(WordBoundary::Ascii))
+        .incremental()
+        .is_ok());
+
+    // The word chars of each choice.
+    let ascii = WordBoundary::Ascii.word_chars();
+    assert!(ascii.contains('_' as u32) && !ascii.contains('é' as u32));
+    assert!(WordBoundary::Unicode.word_chars().contains('é' as u32));
+}
+
+#[test]
+fn test_strip_bom() {
+    use regress::strip_bom;
+    assert_eq!(strip_bom("\u{FEFF}abc"), ("abc", 3));
+    assert_eq!(strip_bom("abc"), ("abc", 0));
+    assert_eq!(strip_bom(""), ("", 0));
+    assert_eq!(strip_bom("\u{FEFF}"), ("", 3));
+    // Only one leading BOM is removed.
+    assert_eq!(strip_bom("\u{FEFF}\u{FEFF}a"), ("\u{FEFF}a", 3));
+    assert_eq!(strip_bom("a\u{FEFF}"), ("a\u{FEFF}", 0));
+
+    let re = regress::Regex::with_flags(r"^\d+$", "m").unwrap();
+    let text = "\u{FEFF}12\n34";
+    let starts: Vec<_> = re.find_iter(text).map(|m| m.start()).collect();
+    assert_eq!(starts, [6]);
+    let (rest, skipped) = strip_bom(text);
+    let starts: Vec<_> = re.find_iter(rest).map(|m| m.start() + skipped).collect();
+    assert_eq!(starts, [3, 6]);
+}
+
+#[test]
+fn test_single_char_loops_over_ascii_runs() {
+    test_with_configs(test_single_char_loops_over_ascii_runs_tc)
+}
+
+fn test_single_char_loops_over_ascii_runs_tc(tc: TestConfig) {
+    // Runs of ASCII longer than a SIMD register, ending at non-ASCII text, at
+    // line terminators, and within the bounds of the loops.
+    let long = "x".repeat(40);
+    let text = format!("{long}\u{e9}{long}\n{long}\r\u{1F600}");
+    let line = format!("{long}\u{e9}{long}");
+    assert_eq!(
+        tc.compile(r".*").match_all(&text),
+        [line.as_str(), "", &long, "", "\u{1F600}", ""]
+    );
+    assert_eq!(tc.compile(r"[^]*").match_all(&text), [text.as_str(), ""]);
+    assert_eq!(
+        tc.compile(r".{3,41}").match_all(&text),
+        [format!("{long}\u{e9}").as_str(), &long, &long]
+    );
+    tc.compile(r".{45}")
+        .match1f(&text)
+        .test_eq(&format!("{long}\u{e9}xxxx"));
+    tc.compile(r".*x\u{e9}")
+        .match1f(&text)
+        .test_eq(&format!("{long}\u{e9}"));
+    tc.compilef(r".*\n", "s")
+        .match1f(&text)
+        .test_eq(&format!("{line}\n"));
+    tc.compilef(r".{81,}", "s").test_fails(&long);
+    assert_eq!(
+        tc.compilef(r"x.{0,38}", "s").match_all(&line),
+        [&long[1..], &format!("x\u{e9}{}", &long[3..]), "xxx"]
+    );
+}
+
+#[test]
+fn test_start_predicate_description() {
+    let describe = |pattern: &str, flags: &str| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .start_predicate_description()
+    };
+    let none = "none: the engine runs at every position";
+    assert_eq!(describe(r"abcdef", ""), r#"literal prefix "abcd""#);
+    assert_eq!(
+        describe(r"\u{e9}t\u{e9}", "u"),
+        "literal prefix [c3, a9, 74, c3]"
+    );
+    assert_eq!(
+        describe(r"cat|dog|cow", ""),
+        "first byte any of \'c\' \'d\'"
+    );
+    assert_eq!(describe(r"cat|car", ""), r#"literal prefix "ca""#);
+    assert_eq!(
+        describe(r"[0-9a-f]+", ""),
+        "first byte any of [\'0\'-\'9\' \'a\'-\'f\']"
+    );
+    assert_eq!(describe(r"abc", "i"), "first byte any of \'A\' \'a\'");
+    assert_eq!(describe(r"^abc", ""), "anchored at the start of the text");
+    assert_eq!(describe(r"^abc", "m"), none);
+    assert_eq!(
+        describe(r"(?:^a)|(?:^b)", ""),
+        "anchored at the start of the text"
+    );
+    assert_eq!(describe(r"a?b", ""), none);
+    assert_eq!(describe(r"(?<=a)b", ""), r#"literal prefix "b""#);
+    assert_eq!(describe(r".", ""), none);
+
+    // A range spanning ASCII and multibyte chars admits no continuation byte,
+    // so that no match is tried inside a character.
+    assert_eq!(
+        describe(r"[{-\u07FF]", "u"),
+        r"first byte any of ['{'-'\u{7f}' 0xc0-0xdf]"
+    );
+    let re = regress::Regex::with_flags(r"[{-\u07FF]", "u").unwrap();
+    let ranges: Vec<_> = re.find_iter("\u{20AC}a\u{E9}").map(|m| m.range()).collect();
+    assert_eq!(ranges, vec![4..6]);
+}
+
+#[test]
+fn test_line_break_escapes() {
+    let build = |pattern: &str, flags: &str| {
+        regress::RegexBuilder::new(pattern)
+            .flags(flags)
+            .line_break_escapes(true)
+            .build()
+            .unwrap()
+    };
+    for flags in ["", "u", "v", "i", "s"] {
+        let re = build(r"^\R$", flags);
+        for text in ["\r\n", "\r", "\n", "\u{2028}", "\u{2029}"] {
+            assert!(re.find(text).is_some(), "{:?} with flags {:?}", text, flags);
+        }
+        for text in ["", "\n\r", "\r\n\n", "x", "\u{85}"] {
+            assert!(re.find(text).is_none(), "{:?} with flags {:?}", text, flags);
+        }
+
+        // \R is atomic, so its CRLF is never split.
+        assert!(build(r"^\R{2}$", flags).find("\r\n").is_none());
+        assert!(build(r"^\R{2}$", flags).find("\r\n\r").is_some());
+        assert!(build(r"^\R\n$", flags).find("\r\n").is_none());
+        let m = build(r"\R+", flags).find("a\r\n\n\rb").unwrap();
+        assert_eq!(m.range(), 1..5);
+
+        // \N excludes line terminators, even with the 's' flag.
+        let re = build(r"\N+", flags);
+        assert_eq!(re.find("\nab\rc").unwrap().range(), 1..3);
+        assert!(re.find("\r\n\u{2028}").is_none());
+    }
+
+    // Without the flag, these are identity escapes outside unicode mode.
+    let re = regress::Regex::new(r"\R\N").unwrap();
+    assert!(re.find("RN").is_some());
+    assert!(re.find("\r\n").is_none());
+    assert!(regress::Regex::with_flags(r"\R", "u")
+        .unwrap()
+        .find("\n")
+        .is_none());
+
+    // The flag survives serialization, so the source is parsed the same way.
+    let re = build(r"a\Rb", "");
+    let re2 = regress::Regex::from_serialized(&re.serialize_program(), re.as_str()).unwrap();
+    assert!(re2.find("a\r\nb").is_some());
+    let re3 = regress::Regex::from_serialized(&re.serialize_program(), "").unwrap();
+    assert_eq!(re2.canonical_key(), re3.canonical_key());
+}
+
+#[test]
+fn test_word_edge_escapes() {
+    let build = |pattern: &str, flags: &str| {
+        regress::RegexBuilder::new(pattern)
+            .flags(flags)
+            .word_edge_escapes(true)
+            .build()
+            .unwrap()
+    };
+    let starts = |pattern: &str, flags: &str, text: &str| -> Vec<usize> {
+        let re = build(pattern, flags);
+        let found: Vec<usize> = re.find_iter(text).map(|m| m.start()).collect();
+        // The lazy DFA and the backtracker must agree.
+        let mask = re.matches_at_each_position(text);
+        let expected: Vec<usize> = text
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain(core::iter::once(text.len()))
+            .zip(mask)
+            .filter_map(|(idx, m)| m.then_some(idx))
+            .collect();
+        assert_eq!(found, expected, "/{}/{} on {:?}", pattern, flags, text);
+        found
+    };
+    for flags in ["", "u", "v", "i"] {
+        // At the start and end of the string, and around punctuation.
+        assert_eq!(starts(r"\<", flags, "ab"), [0]);
+        assert_eq!(starts(r"\>", flags, "ab"), [2]);
+        assert_eq!(starts(r"\<", flags, "(ab), c!"), [1, 6]);
+        assert_eq!(starts(r"\>", flags, "(ab), c!"), [3, 7]);
+        assert_eq!(starts(r"\b", flags, "(ab), c!"), [1, 3, 6, 7]);
+        assert_eq!(starts(r"\<", flags, ""), []);
+        assert_eq!(starts(r"\>", flags, " - "), []);
+        assert_eq!(starts(r"\<\w+\>", flags, "a_1 b-c"), [0, 4, 6]);
+        assert_eq!(starts(r"\b{start}\w", flags, "x.y"), [0, 2]);
+        assert_eq!(starts(r"\w\b{end}", flags, "x.y"), [0, 2]);
+
+        // The half edges check only the side outside the word.
+        assert_eq!(
+            starts(r"\b{start-half}", flags, "(ab), c!"),
+            [0, 1, 4, 5, 6, 8]
+        );
+        assert_eq!(
+            starts(r"\b{end-half}", flags, "(ab), c!"),
+            [0, 3, 4, 5, 7, 8]
+        );
+        assert_eq!(starts(r"\b{start-half}", flags, ""), [0]);
+        assert_eq!(starts(r"\b{end-half}", flags, ""), [0]);
+        assert_eq!(
+            starts(r"\b{start-half}\w+\b{end-half}", flags, "a_1 b-c"),
+            [0, 4, 6]
+        );
+        assert_eq!(starts(r"\b{start-half}-", flags, "a- -"), [3]);
+    }
+
+    // Other variants are errors, but a quantifier is still one.
+    let build_err = |pattern: &str| {
+        regress::RegexBuilder::new(pattern)
+            .word_edge_escapes(true)
+            .build()
+            .unwrap_err()
+    };
+    for pattern in [r"\b{wb}", r"\b{start-Half}", r"\b{gcb}x"] {
+        let err = build_err(pattern);
+        assert_eq!(err.text, "Invalid word boundary variant", "{}", pattern);
+        assert_eq!(err.offset(), Some(2));
+    }
+    assert_eq!(starts(r"\b{2}", "", "a b"), starts(r"\b", "", "a b"));
+
+    // Word characters follow \w, which is Unicode-aware with 'u' or 'v'.
+    assert_eq!(starts(r"\<", "", "é1"), [2]);
+    assert_eq!(starts(r"\<", "", "ſ1"), [2]);
+    assert_eq!(starts(r"\<", "ui", "ſ1"), [0]);
+
+    // Within lookbehinds, these look at the same characters.
+    assert_eq!(starts(r"(?<=a\>)\W", "", "a b ba c"), [1, 6]);
+
+    // Without the flag, these are identity escapes outside unicode mode, and
+    // the braces after \b are literal.
+    let re = regress::Regex::new(r"\<\>").unwrap();
+    assert!(re.find("<>").is_some());
+    assert!(regress::Regex::with_flags(r"\<", "u").is_err());
+    let re = regress::Regex::new(r"a\b{end}").unwrap();
+    assert_eq!(re.find("a a{end}").unwrap().range(), 2..8);
+
+    // The flag survives serialization.
+    let re = build(r"\<a", "");
+    let re2 = regress::Regex::from_serialized(&re.serialize_program(), re.as_str()).unwrap();
+    assert_eq!(re2.find("ba a").unwrap().range(), 3..4);
+    let re3 = regress::Regex::from_serialized(&re.serialize_program(), "").unwrap();
+    assert_eq!(re2.canonical_key(), re3.canonical_key());
+}
+
+#[test]
+fn test_incremental() {
+    // Feed the text in every split into three chunks, which may split code
+    // points, and compare to find_iter_utf8_runs, which agrees with
+    // find_iter on valid UTF-8.
+    let check_bytes = |pattern: &str, flags: &str, text: &[u8]| {
+        let re = regress::Regex::with_flags(pattern, flags).unwrap();
+        let expected: Vec<_> = re.find_iter_utf8_runs(text).map(|m| m.range()).collect();
+        for i in 0..=text.len() {
+            for j in i..=text.len() {
+                let mut m = re.incremental().unwrap();
+                let mut got = m.feed(&text[..i]);
+                got.extend(m.feed(&text[i..j]));
+                got.extend(m.feed(&text[j..]));
+                if core::str::from_utf8(text).is_ok() {
+                    assert_eq!(m.offset(), text.len());
+                }
+                got.extend(m.finish());
+                assert_eq!(
+                    got,
+                    expected,
+                    "/{}/{} on {:?} split at {}, {}",
+                    pattern,
+                    flags,
+                    String::from_utf8_lossy(text),
+                    i,
+                    j
+                );
+            }
+        }
+    };
+    let check = |pattern: &str, flags: &str, text: &str| {
+        let re = regress::Regex::with_flags(pattern, flags).unwrap();
+        let expected: Vec<_> = re.find_iter(text).map(|m| m.range()).collect();
+        let runs: Vec<_> = re
+            .find_iter_utf8_runs(text.as_bytes())
+            .map(|m| m.range())
+            .collect();
+        assert_eq!(runs, expected);
+        check_bytes(pattern, flags, text.as_bytes());
+    };
+    check(r"\d+", "", "a12 345 6");
+    check(r"ab|a", "", "aab ac a");
+    check(r"a(?:bcd|b)", "", "abc abcd ab");
+    check(r"a.*b|a", "", "a--a--b--a");
+    check(r"a*?", "", "aaa");
+    check(r"a*", "", "baab");
+    check(r"(?:|a)*", "", "aab");
+    check(r"(?:a|b)*?c", "", "abacbc");
+    check(r"^\w+$", "m", "ab\ncd e\nf");
+    check(r"\b\w", "", "one two");
+    check(r"\Bé|ü+", "iu", "ÉéÜüx");
+    check(r"a{2,3}", "y", "aaaaaaa b");
+    check(r"(x)|y", "y", "xyyzx");
+    check(r"[^a]{2}", "", "bécd");
+    check(r"\w+", "u", "\u{1F600}é€x");
+
+    // Invalid UTF-8 sequences are hard boundaries, including a code point
+    // left incomplete at the end.
+    check_bytes(r"\w+", "", b"ab\xFFcd");
+    check_bytes(r"^\w|\w$", "", b"ab\xFF\xFEcd\xE2\x82");
+    check_bytes(r"a*", "", b"\xFFaa\xC3");
+    check_bytes(r"\b", "u", b"a\xE2\x82-\xF0\x9F\x98b");
+    check_bytes(r"a{2}", "y", b"aa\x80aaa");
+    check_bytes(r".+", "u", b"\xC3\xA9\xED\xA0\x80\xC3\xA9");
+
+    // pending_start tracks a match which more text may extend.
+    let re = regress::Regex::new(r"<[a-z]*>").unwrap();
+    let mut m = re.incremental().unwrap();
+    assert_eq!(m.feed(b"text <ta"), vec![]);
+    assert!(m.is_pending());
+    assert_eq!(m.pending_start(), Some(5));
+    assert_eq!(m.feed(b"g> <"), vec![5..10]);
+    assert_eq!(m.pending_start(), Some(11));
+    assert_eq!(m.feed(b"1"), vec![]);
+    assert_eq!(m.pending_start(), None);
+    assert_eq!(m.finish(), vec![]);
+
+    // Matches may only be found once the following text shows they are final.
+    let re = regress::Regex::new(r"ab|abcd").unwrap();
+    let mut m = re.incremental().unwrap();
+    assert_eq!(m.feed(b"ab"), vec![]);
+    assert_eq!(m.feed(b"c"), vec![0..2]);
+    let re = regress::Regex::new(r"a(?:bcd|b)").unwrap();
+    let mut m = re.incremental().unwrap();
+    assert_eq!(m.feed(b"abc"), vec![]);
+    assert_eq!(m.pending_start(), Some(0));
+    assert_eq!(m.feed(b"d"), vec![]);
+    assert_eq!(m.finish(), vec![0..4]);
+
+    // Patterns which are not regular are rejected.
+    for pattern in [r"(a)\1", r"a(?=b)", r"(?<!a)b", r"(?>a+)"] {
+        assert!(regress::Regex::new(pattern).unwrap().incremental().is_err());
+    }
+}
+
+#[test]
+fn test_find_with_case_insensitive() {
+    use regress::{MatchOptions, Regex};
+    let icase = MatchOptions {
+        case_insensitive: true,
+        ..Default::default()
+    };
+    let find = |pattern: &str, text: &str| {
+        Regex::new(pattern)
+            .unwrap()
+            .find_with(text, icase)
+            .map(|m| m.range())
+    };
+
+    // Matches agree with the 'i' flag on ASCII text, with offsets into the
+    // original text.
+    for (pattern, text) in [
+        (r"hello", "say HeLLo"),
+        (r"abcdefghijklmnopqrstuvwxyz", "ABCdefGHIjklMNOpqrSTUvwxYZ!"),
+        (r"[a-c]+[^x]", "CaBX"),
+        (r"[^a]", "Ab"),
+        (r"x\w*y", "--XabCY"),
+        (r"(ab)\1", "abAB"),
+        (r"(?<=b)c", "Bc bC"),
+        (r"^T.*E$", "tRuE"),
+        (r"q|[K-M]|z", "??l"),
+        // Literal prefixes and sets are searched for in either case.
+        (r"HELLO world", "oh, hello WORLD"),
+        (r"[xy]z", "wXZ"),
+        (r"k[0-9]", "xK1"),
+        (r"(?<=AB)c", "abC"),
+        (r"(a)\1b", "xAaB"),
+    ] {
+        let expected = Regex::with_flags(pattern, "i")
+            .unwrap()
+            .find(text)
+            .map(|m| m.range());
+        assert!(expected.is_some(), "{:?}", pattern);
+        assert_eq!(find(pattern, text), expected, "{:?} on {:?}", pattern, text);
+    }
+    assert_eq!(find(r"\bCAT\b", "a cat"), Some(2..5));
+    assert_eq!(find(r"\p{Lu}", "1a"), Some(1..2));
+
+    // Captures are reported too.
+    let re = Regex::new(r"(?<k>[a-z]+)=(\d)").unwrap();
+    let m = re.find_with("; KEY=5", icase).unwrap();
+    assert_eq!(m.named_group("k"), Some(2..5));
+    assert_eq!(m.group(2), Some(6..7));
+
+    // Only ASCII letters are folded.
+    assert_eq!(find(r"é", "É"), None);
+    assert_eq!(find(r"K", "\u{212A}"), None);
+    assert_eq!(find(r"xé", "Xé"), Some(0..3));
+
+    // The option applies throughout the pattern.
+    assert_eq!(find(r"(?-i:a)b", "AB"), Some(0..2));
+
+    // The folded program is kept between searches, and the regex keeps
+    // matching case-sensitively otherwise.
+    let re = Regex::new(r"zz(y)").unwrap();
+    for text in ["ZZY", "zzy", "aZzY"] {
+        let m = re.find_with(text, icase).unwrap();
+        assert_eq!(m.group(1), Some(text.len() - 1..text.len()));
+    }
+    assert!(re.find("ZZY").is_none());
+
+    // Without the option, the search is as with find.
+    let re = Regex::new(r"Ab").unwrap();
+    assert!(re.find_with("ab", MatchOptions::default()).is_none());
+    assert_eq!(
+        re.find_with("xAb", MatchOptions::default())
+            .unwrap()
+            .range(),
+        1..3
+    );
+}
+
+#[test]
+fn test_find_with_grapheme_align() {
+    use regress::{MatchOptions, Regex};
+    let aligned = MatchOptions {
+        grapheme_align: true,
+        ..Default::default()
+    };
+    let find = |pattern: &str, text: &str| {
+        Regex::with_flags(pattern, "u")
+            .unwrap()
+            .find_with(text, aligned)
+            .map(|m| m.range())
+    };
+
+    // Matches on cluster boundaries are unchanged.
+    assert_eq!(find(r"b", "abc"), Some(1..2));
+    assert_eq!(find(r"x", "abc"), None);
+    assert_eq!(find(r"e\u{301}", "cafe\u{301}"), Some(3..6));
+
+    // Combining marks and spacing marks extend the cluster before them.
+    assert_eq!(find(r"e", "cafe\u{301}!"), Some(3..6));
+    assert_eq!(find(r"\u{301}", "e\u{301}\u{302}x"), Some(0..5));
+    assert_eq!(find(r"\u{915}", "\u{915}\u{93E}"), Some(0..6));
+    assert_eq!(find(r"(?<=e)", "e\u{301}"), Some(0..3));
+
+    // A mark after a control, or at the start of text, is its own cluster.
+    assert_eq!(find(r"\u{301}", "\u{7}\u{301}"), Some(1..3));
+    assert_eq!(find(r"\u{301}", "\u{301}a"), Some(0..2));
+
+    // CR LF is a single cluster.
+    assert_eq!(find(r"\n", "a\r\nb"), Some(1..3));
+    assert_eq!(find(r"\n", "a\n\rb"), Some(1..2));
+
+    // Prepended characters attach to what follows.
+    assert_eq!(find(r"1", "\u{600}1"), Some(0..3));
+
+    // Hangul syllables.
+    assert_eq!(find(r"\u{1161}", "\u{1100}\u{1161}\u{11A8}"), Some(0..9));
+    assert_eq!(find(r"\u{11A8}", "\u{AC00}\u{11A8}"), Some(0..6));
+    assert_eq!(find(r"\u{11A8}", "\u{AC01}a\u{11A8}"), Some(4..7));
+
+    // Emoji ZWJ sequences and modifiers.
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    assert_eq!(find(r"\u{1F469}", family), Some(0..18));
+    assert_eq!(find(r"\u{1F44B}", "\u{1F44B}\u{1F3FD}"), Some(0..8));
+    assert_eq!(find(r"b", "a\u{200D}b"), Some(4..5));
+
+    // Regional indicators pair up into flags.
+    let flags = "\u{1F1FA}\u{1F1F8}\u{1F1EB}\u{1F1F7}";
+    assert_eq!(find(r"\u{1F1F8}\u{1F1EB}", flags), Some(0..16));
+    assert_eq!(find(r"\u{1F1EB}", flags), Some(8..16));
+
+    // Only the whole match is widened; groups are reported as found.
+    let m = Regex::new(r"(e)")
+        .unwrap()
+        .find_with("e\u{301}", aligned)
+        .unwrap();
+    assert_eq!(m.range(), 0..3);
+    assert_eq!(m.group(1), Some(0..1));
+
+    // The options combine.
+    let both = MatchOptions {
+        case_insensitive: true,
+        grapheme_align: true,
+        wanted_groups: None,
+    };
+    let m = Regex::new(r"e").unwrap().find_with("CAFE\u{301}", both);
+    assert_eq!(m.map(|m| m.range()), Some(3..6));
+}
+
+#[test]
+fn test_find_with_wanted_groups() {
+    use regress::{MatchOptions, Regex};
+    let wanting = |groups: &'static [usize]| MatchOptions {
+        wanted_groups: Some(groups),
+        ..Default::default()
+    };
+    let groups = |pattern: &str, text: &str, options: MatchOptions| {
+        let m = Regex::new(pattern)
+            .unwrap()
+            .find_with(text, options)
+            .unwrap();
+        (0..=m.group_count())
+            .map(|idx| m.group(idx))
+            .collect::<Vec<_>>()
+    };
+
+    let re = r"(a)(b)?(c)";
+    assert_eq!(
+        groups(re, "xabc", MatchOptions::default()),
+        [Some(1..4), Some(1..2), Some(2..3), Some(3..4)]
+    );
+    assert_eq!(
+        groups(re, "xabc", wanting(&[1, 3])),
+        [Some(1..4), Some(1..2), None, Some(3..4)]
+    );
+    assert_eq!(
+        groups(re, "xabc", wanting(&[])),
+        [Some(1..4), None, None, None]
+    );
+
+    // Indexes with no group, including the whole match, are ignored.
+    assert_eq!(
+        groups(re, "xabc", wanting(&[0, 2, 7])),
+        [Some(1..4), None, Some(2..3), None]
+    );
+
+    // Groups which backreferences refer to are recorded while matching, but
+    // are only reported if wanted.
+    let re = r"(\w)(\w)\1";
+    assert_eq!(
+        groups(re, "abcb", wanting(&[2])),
+        [Some(1..4), None, Some(2..3)]
+    );
+    assert_eq!(groups(re, "abcb", wanting(&[])), [Some(1..4), None, None]);
+    assert_eq!(
+        groups(r#"(?<q>['"])(\w+)\k<q>"#, "say 'hi\" 'yo'", wanting(&[2])),
+        [Some(9..13), None, Some(10..12)]
+    );
+    let regex = Regex::new(r"(?<q>a)\k<q>").unwrap();
+    let m = regex.find_with("aa", wanting(&[])).unwrap();
+    assert_eq!(m.named_group("q"), None);
+
+    // Lookarounds and case insensitive matching record only wanted groups.
+    assert_eq!(
+        groups(r"(?=(a))(a)(?<=(a))", "ba", wanting(&[3])),
+        [Some(1..2), None, None, Some(1..2)]
+    );
+    let options = MatchOptions {
+        case_insensitive: true,
+        wanted_groups: Some(&[1]),
+        ..Default::default()
+    };
+    assert_eq!(
+        groups(r"(a)(b)\2", "xABb", options),
+        [Some(1..4), Some(1..2), None]
+    );
+
+    // The sticky flag is respected.
+    let sticky = Regex::with_flags(r"(a)(b)", "y").unwrap();
+    assert!(sticky.find_with("xab", wanting(&[1])).is_none());
+    let m = sticky.find_with("abab", wanting(&[1])).unwrap();
+    assert_eq!((m.group(1), m.group(2)), (Some(0..1), None));
+}
+
+#[test]
+fn test_count_matches_up_to() {
+    // The count agrees with find_iter up to the limit, for empty matches,
+    // non-ASCII text, backreferences and the sticky flag.
+    let cases = [
+        (r"\d+", "", "1 22 333 4444"),
+        (r"x*", "", "axxbx"),
+        (r"", "", "aé😀"),
+        (r"\b", "", "ab cd"),
+        (r"(?=a)|a", "", "aaa"),
+        (r"(\w)\1", "", "aabbcdd"),
+        (r"a", "y", "aaba"),
+        (r"", "y", "ab"),
+        (r"(?<=é)\w", "u", "éaébc"),
+        (r"z", "", "abc"),
+    ];
+    for (pattern, flags, text) in cases {
+        let re = regress::Regex::with_flags(pattern, flags).unwrap();
+        let total = re.find_iter(text).count();
+        for limit in 0..=total + 1 {
+            assert_eq!(
+                re.count_matches_up_to(text, limit),
+                total.min(limit),
+                "/{}/{} on {:?} up to {}",
+                pattern,
+                flags,
+                text,
+                limit
+            );
+        }
+    }
+
+    // The search stops at the limit, so a long text is not scanned.
+    let re = regress::Regex::new(r"(a|a)*b").unwrap();
+    let text = format!("b b {}", "a".repeat(40));
+    assert_eq!(re.count_matches_up_to(&text, 2), 2);
+}
+
+#[test]
+fn test_matches_at_each_position() {
+    // Compare against an anchored attempt at each position, for patterns
+    // run by the lazy DFA and by the backtracker.
+    let cases = [
+        (r"ab|b", "", "abxb"),
+        (r"\bwo", "", "wo two wo"),
+        (r"a*", "", "baab"),
+        (r"^\w", "m", "ab\ncd"),
+        (r"[a-z]+\d", "", "ab1 c2 3"),
+        (r"\w(?=\d)", "", "a1b2c"),
+        (r"(?<=é)x|x$", "", "éxxéx"),
+        (r"(\w)\1", "i", "aAbbc"),
+        (r"(?>a+)b", "", "aaab"),
+        (r"\d", "y", "1a2"),
+    ];
+    for (pattern, flags, text) in cases {
+        let re = regress::Regex::with_flags(pattern, flags).unwrap();
+        let mut expected: Vec<bool> = text
+            .char_indices()
+            .map(|(idx, _)| re.find_anchored(text, idx).is_some())
+            .collect();
+        expected.push(re.find_anchored(text, text.len()).is_some());
+        assert_eq!(
+            re.matches_at_each_position(text),
+            expected,
+            "/{}/{} on {:?}",
+            pattern,
+            flags,
+            text
+        );
+    }
+
+    // Entries are per code point, with one for the end of the text.
+    let re = regress::Regex::new(r"é|$").unwrap();
+    assert_eq!(
+        re.matches_at_each_position("aéé"),
+        [false, true, true, true]
+    );
+    assert_eq!(re.matches_at_each_position(""), [true]);
+
+    // Runs which reach the same state are merged, so a long text in which
+    // every attempt runs to the end is still fast.
+    let re = regress::Regex::new(r"a*b").unwrap();
+    let mask = re.matches_at_each_position(&"a".repeat(50_000));
+    assert!(mask.iter().all(|&m| !m));
+    let mask = re.matches_at_each_position(&("a".repeat(50_000) + "b"));
+    assert!(mask[..50_001].iter().all(|&m| m) && !mask[50_001]);
+}
+
+#[test]
+fn test_debug_program() {
+    let listing = regress::Regex::new(r"(a|[xy])\1").unwrap().debug_program();
+    let lines: Vec<&str> = listing.lines().collect();
+    assert_eq!(lines[0], "flags: // groups: 1 loops: 0");
+    assert_eq!(lines[1], "start predicate: any of 'a' 'x' 'y'");
+    assert_eq!(
+        lines[2..],
+        [
+            "0: BeginCaptureGroup 0",
+            "1: Alt else -> 4",
+            "2: ByteSeq1 \"a\"",
+            "3: Jump -> 5",
+            "4: ByteSet2 'x' 'y'",
+            "5: EndCaptureGroup 0",
+            "6: BackRef 0 icase: false",
+            "7: Goal",
+        ]
+    );
+
+    // Indexes are aligned, loops and lookarounds show their targets, and
+    // brackets are listed after the instructions.
+    let re = regress::Regex::with_flags(r"(?:ab|c){2,}?(?<!\p{Lu})[^é-ê]", "u").unwrap();
+    let listing = re.debug_program();
+    assert!(listing.contains(" 0: "), "{}", listing);
+    assert!(listing.contains("EnterLoop #0 {0, inf} non-greedy exit -> "));
+    assert!(listing.contains("LoopAgain -> "));
+    assert!(listing.contains("Lookbehind negative groups 0..0 continue -> "));
+    assert!(listing.contains("UnicodePropertyEscape"));
+    assert!(listing.contains("Bracket #0"));
+    assert!(listing.ends_with("bracket #0: [^'é'-'ê']\n"), "{}", listing);
+
+    // Large brackets are abbreviated.
+    let listing = regress::Regex::with_flags(r"[\p{L}\d]", "u")
+        .unwrap()
+        .debug_program();
+    assert!(listing.contains(" more]"), "{}", listing);
+    assert!(listing.len() < 1000);
+}
+
+#[test]
+fn test_regex_set() {
+    let set = regress::RegexSet::new([r"error", r"^\d+$", r"warn(ing)?", r"(?<=x)y"]).unwrap();
+    assert_eq!(set.len(), 4);
+    assert!(!set.is_empty());
+    assert_eq!(
+        set.matches("warning: error").collect::<Vec<_>>(),
+        vec![0, 2]
+    );
+    assert_eq!(set.matches("12345").collect::<Vec<_>>(), vec![1]);
+    assert_eq!(set.matches("xy").collect::<Vec<_>>(), vec![3]);
+    assert_eq!(set.matches("nothing").count(), 0);
+    assert!(set.is_match_any("a warn"));
+    assert!(!set.is_match_any("ok"));
+    assert_eq!(set.regexes()[2].as_str(), r"warn(ing)?");
+
+    let set = regress::RegexSet::with_flags(vec!["ERROR", "Warn"], "i").unwrap();
+    assert_eq!(set.matches("error warn").collect::<Vec<_>>(), vec![0, 1]);
+
+    let empty = regress::RegexSet::new(Vec::<&str>::new()).unwrap();
+    assert!(empty.is_empty());
+    assert!(!empty.is_match_any("abc"));
+
+    let err = regress::RegexSet::new(["a", "(b"]).unwrap_err();
+    assert!(err.text.contains("in pattern 1"), "{}", err);
+
+    // The patterns matched together agree with searching for each alone,
+    // including those which only match at the end of the text, and those
+    // which the DFA cannot match.
+    let patterns = [
+        r"a",
+        r"b+c",
+        r"^ab",
+        r"c$",
+        r"\bcab\b",
+        r"\Bb",
+        r"x*",
+        r"(?:ab|ba){2}",
+        r"[^abc]",
+        r"^$",
+        r"(a)\1",
+        r"a(?=b)",
+        r"d{2,3}",
+        r"a.c",
+        r"\d",
+        r"é+",
+    ];
+    for flags in ["", "i", "m", "u", "y", "s"] {
+        let set = regress::RegexSet::with_flags(patterns, flags).unwrap();
+        for text in [
+            "", "a", "abc", "cab", "abab", "ba\nc", "ddd", "xab", "AB", "a\u{e9}c", "bbbc", "9",
+        ] {
+            let expected: Vec<usize> = set
+                .regexes()
+                .iter()
+                .enumerate()
+                .filter(|(_, re)| re.find(text).is_some())
+                .map(|(idx, _)| idx)
+                .collect();
+            assert_eq!(
+                set.matches(text).collect::<Vec<_>>(),
+                expected,
+                "/{} on {:?}",
+                flags,
+                text
+            );
+            assert_eq!(set.is_match_any(text), !expected.is_empty());
+        }
+    }
+
+    // Many patterns are matched in one pass, with their states kept between
+    // searches.
+    let patterns: Vec<String> = (0..300).map(|i| format!(r"\bw{}\b", i)).collect();
+    let set = regress::RegexSet::new(&patterns).unwrap();
+    for _ in 0..2 {
+        assert_eq!(
+            set.matches("w7 w299 w30x w30").collect::<Vec<_>>(),
+            vec![7, 30, 299]
+        );
+    }
+    assert!(!set.is_match_any("w300 w-"));
+}
+
+#[test]
+fn test_find_bytes() {
+    let find = |pattern: &str, flags: &str, bytes: &[u8]| {
+        regress::Regex::with_flags(pattern, flags)
+            .unwrap()
+            .find_bytes(bytes)
+            .map(|m| m.range())
+    };
+
+    // Valid UTF-8 behaves as with find().
+    assert_eq!(
+        find(r"\u{E9}+", "u", "x\u{E9}\u{E9}y".as_bytes()),
+        Some(1..5)
+    );
+    assert_eq!(
+        find(r"(?<=\u{1F600})b", "u", "a\u{1F600}b".as_bytes()),
+        Some(5..6)
+    );
+    assert_eq!(find(r"(.)\1", "iu", "x\u{C9}\u{E9}".as_bytes()), Some(1..5));
+    assert_eq!(find(r"^$", "", b""), Some(0..0));
+
+    // Invalid sequences never match a char, and searching continues after them.
+    assert_eq!(find(r".+", "", b"\xFFab\x80c"), Some(1..3));
+    assert_eq!(find(r"a.c", "s", b"a\xFFc abc"), Some(4..7));
+    assert_eq!(find(r"[^a]", "", b"\xC3"), None);
+    // Truncated, overlong, and surrogate encodings.
+    assert_eq!(find(r"b.", "", b"b\xE2\x82"), None);
+    assert_eq!(find(r"b.", "", b"b\xC0\xAF"), None);
+    assert_eq!(find(r"b.", "", b"b\xED\xA0\x80"), None);
+    assert_eq!(find(r"b.", "", b"b\xF4\x90\x80\x80"), None);
+    // Lookbehind over an invalid sequence fails.
+    assert_eq!(find(r"(?<=.)z", "", b"\xA9z"), None);
+    assert_eq!(find(r"(?<=\u{A9})z", "u", b"\xC2\xA9z"), Some(2..3));
+    assert_eq!(find(r"\d+", "", b"\xFF\xFE 42"), Some(3..5));
+}
+
+/// An Input whose text is split into small chunks, to exercise non-contiguous
+/// access.
+struct ChunkedInput {
+    chunks: Vec<Vec<u8>>,
+}
+
+impl ChunkedInput {
+    fn new(text: &str, chunk_len: usize) -> Self {
+        ChunkedInput {
+            chunks: text
+                .as_bytes()
+                .chunks(chunk_len)
+                .map(|c| c.to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl regress::Input for ChunkedInput {
+    fn len(&self) -> usize {
+        self.chunks.iter().map(|c| c.len()).sum()
+    }
+
+    fn byte_at(&self, mut offset: usize) -> u8 {
+        for chunk in &self.chunks {
+            if offset < chunk.len() {
+                return chunk[offset];
+            }
+            offset -= chunk.len();
+        }
+        panic!("Offset out of bounds")
+    }
+}
+
+#[test]
+fn test_find_input() {
+    let cases = [
+        (r"foobar\d+", "", "x foobar foobar42"),
+        (r"(?<=\u{E9})[a-z]+", "u", "a\u{E9}bc\u{1F600}"),
+        (r"[\u{1F600}-\u{1F64F}]+", "u", "ab\u{1F600}\u{1F601}c"),
+        (r"(\w+)\s+\1", "i", "say Hello HELLO there"),
+        (r"\bis\b", "", "this is it"),
+        (r"^b", "m", "a\nb"),
+        (r"[0-9a-f]{2,}", "", "xyz 7 beef"),
+        (r"", "", "abc"),
+    ];
+    for (pattern, flags, text) in cases.iter() {
+        let re = regress::Regex::with_flags(pattern, *flags).unwrap();
+        let expected = re.find(text).map(|m| m.groups().collect::<Vec<_>>());
+        for chunk_len in 1..4 {
+            let input = ChunkedInput::new(text, chunk_len);
+            let found = re
+                .find_input(&input)
+                .map(|m| m.groups().collect::<Vec<_>>());
+            assert_eq!(
+                found, expected,
+                "pattern {} chunk length {}",
+                pattern, chunk_len
+            );
+        }
+        let found = re.find_input(*text).map(|m| m.groups().collect::<Vec<_>>());
+        assert_eq!(found, expected, "pattern {} as str", pattern);
+        let found = re
+            .find_input(text.as_bytes())
+            .map(|m| m.groups().collect::<Vec<_>>());
+        assert_eq!(found, expected, "pattern {} as bytes", pattern);
+    }
+
+    // Invalid UTF-8 in a byte slice fails to match a char.
+    let re = regress::Regex::new(r"a.").unwrap();
+    assert!(re.find_input(&b"a\xFF"[..]).is_none());
+    let input = b"a\xFFab";
+    assert_eq!(re.find_input(&input[..]).unwrap().range(), 2..4);
+}
+
+#[test]
+fn test_atomic_groups() {
+    test_with_configs(test_atomic_groups_tc)
+}
+
+fn test_atomic_groups_tc(tc: TestConfig) {
+    // Possessive quantifiers never give back what they matched.
+    tc.compile(r"a++a").test_fails("aaa");
+    tc.compile(r"a*+a").test_fails("aaa");
+    tc.compile(r"a?+a").test_fails("a");
+    tc.compile(r"a?+a").match1f("aa").test_eq("aa");
+    tc.compile(r"a{2,}+a").test_fails("aaaa");
+    tc.compile(r"a{2}+a").match1f("aaa").test_eq("aaa");
+    tc.compile(r"a*+b").match1f("aaab").test_eq("aaab");
+    tc.compile(r"x[ab]++").match1f("xabba").test_eq("xabba");
+
+    // A trailing + after a non-greedy quantifier is still an error.
+    assert!(regress::Regex::new(r"a+?+").is_err());
+
+    // Atomic groups commit to the first alternative that matches.
+    tc.compile(r"(?>a|ab)c").test_fails("abc");
+    tc.compile(r"(?>ab|a)c").match1f("abc").test_eq("abc");
+    tc.compile(r"(?>a+)b").match1f("xaab").test_eq("aab");
+    tc.compile(r"(?>a+)a").test_fails("aaa");
+
+    // Captures inside atomic groups are retained, and restored when
+    // backtracking out of the group.
+    tc.compile(r"(?>(a+))(b)")
+        .match1f("aab")
+        .test_eq("aab,aa,b");
+    tc.compile(r"(?:(?>(a))b|ac)").match1f("ac").test_eq("ac,");
+    tc.compile(r"(?>(a)|b)+").match1f("ab").test_eq("ab,");
+    tc.compile(r"((?>a*+))b\1")
+        .match1f("aabaa")
+        .test_eq("aabaa,aa");
+    tc.compile(r"((?>a*+))\1").match1f("aa").test_eq(",");
+
+    // Atomic groups inside lookbehinds match backwards.
+    tc.compile(r"(?<=(?>a+))b").match1f("aab").test_eq("b");
+    tc.compile(r"(?<=(?>a|ba)?)c").match1f("bac").test_eq("c");
+
+    // Nested quantified atomic groups do not explode.
+    let text = "a".repeat(40);
+    tc.compile(r"(?>a+)+b").test_fails(&text);
+    tc.compile(r"(?>a+)+$").match1f(&text).test_eq(&text);
+    tc.compile(r"(a++)+b").test_fails(&text);
+}
+
+#[test]
+fn test_atomic_groups_bound_backtracking() {
+    // Without the atomic group this needs exponentially many steps.
+    let text = "a".repeat(40);
+    for pattern in &[r"(?>a+)+b", r"(?:a++)+b"] {
+        let re = regress::Regex::new(pattern).unwrap();
+        assert!(re.find_with_budget(&text, 100_000).unwrap().is_none());
+    }
+}
+
+#[test]
+fn test_simple_case_folding() {
+    test_with_configs(test_simple_case_folding_tc)
+}
+
+fn test_simple_case_folding_tc(tc: TestConfig) {
+    // Pairs of code points which fold together through C or S entries of
+    // CaseFolding.txt, including many-to-one folds.
+    let equivalents = [
+        // 03A3; C; 03C3 and 03C2; C; 03C3 (sigma and final sigma).
+        "\u{3A3}\u{3C3}\u{3C2}",
+        // 212A; C; 006B (Kelvin sign).
+        "Kk\u{212A}",
+        // 212B; C; 00E5 (Angstrom sign).
+        "\u{C5}\u{E5}\u{212B}",
+        // 2126; C; 03C9 (Ohm sign).
+        "\u{3A9}\u{3C9}\u{2126}",
+        // 017F; C; 0073 (long s).
+        "Ss\u{17F}",
+        // 1E9E; S; 00DF (capital sharp s).
+        "\u{DF}\u{1E9E}",
+        // 01C4; C; 01C6 and 01C5; C; 01C6 (titlecase digraphs).
+        "\u{1C4}\u{1C5}\u{1C6}",
+        // 03F4; C; 03B8 and 03D1; C; 03B8 (theta symbols).
+        "\u{398}\u{3B8}\u{3D1}\u{3F4}",
+        // 1E9B; C; 1E61 (long s with dot above).
+        "\u{1E60}\u{1E61}\u{1E9B}",
+        // 10400; C; 10428 (Deseret, outside the BMP).
+        "\u{10400}\u{10428}",
+        // 0100; C; 0101 and 0102; C; 0103 (alternating case pairs).
+        "\u{100}\u{101}",
+        "\u{102}\u{103}",
+        "\u{1E60}\u{1E61}",
+        // 01B8; C; 01B9 and 01BC; C; 01BD (pairs with a stride of 4).
+        "\u{1B8}\u{1B9}",
+        "\u{1BC}\u{1BD}",
+    ];
+    // Neighbouring pairs do not fold together.
+    for (pattern, text) in [
+        ("\u{101}", "\u{102}"),
+        ("\u{100}", "\u{103}"),
+        ("\u{1E61}", "\u{1E62}"),
+        ("\u{1B9}", "\u{1BC}"),
+    ] {
+        tc.compilef(pattern, "iu").test_fails(text);
+        tc.compilef(text, "iu").test_fails(pattern);
+    }
+    for chars in equivalents {
+        for pc in chars.chars() {
+            for tc_char in chars.chars() {
+                let pattern = pc.to_string();
+                let text = tc_char.to_string();
+                tc.compilef(&pattern, "iu").test_succeeds(&text);
+                tc.compilef(&format!("[{}]", pattern), "iu")
+                    .test_succeeds(&text);
+                tc.compilef(&format!("[^{}]", pattern), "iu")
+                    .test_fails(&text);
+            }
+        }
+    }
+
+    // Dotted and dotless i only fold through F and T entries, which simple
+    // case folding excludes.
+    for (pattern, text) in [
+        ("i", "\u{130}"),
+        ("i", "\u{131}"),
+        ("I", "\u{131}"),
+        ("\u{130}", "i"),
+        ("\u{131}", "I"),
+        ("\u{130}", "\u{131}"),
+    ] {
+        tc.compilef(pattern, "iu").test_fails(text);
+    }
+    tc.compilef(r"\u{130}", "iu").test_succeeds("\u{130}");
+
+    // Full folds to multiple code points are also excluded.
+    tc.compilef("\u{FB00}", "iu").test_fails("ff");
+    tc.compilef("\u{DF}", "iu").test_fails("ss");
+
+    // Folds apply across ranges, loops and backreferences.
+    tc.compilef(r"[a-z]+", "iu")
+        .match1f("\u{212A}\u{17F}K")
+        .test_eq("\u{212A}\u{17F}K");
+    tc.compilef("\u{3C3}+", "iu")
+        .match1f("\u{3A3}\u{3C2}\u{3C3}")
+        .test_eq("\u{3A3}\u{3C2}\u{3C3}");
+    tc.compilef(r"(.)\1", "iu")
+        .match1f("\u{3C2}\u{3A3}")
+        .test_eq("\u{3C2}\u{3A3},\u{3C2}");
+}
+
+#[test]
+fn test_mixed_ascii_brackets() {
+    test_with_configs(test_mixed_ascii_brackets_tc)
+}
+
+fn test_mixed_ascii_brackets_tc(tc: TestConfig) {
+    // Brackets with both ASCII and non-ASCII members test the ASCII ones
+    // with a bitmap; check the edges of each part.
+    let re = tc.compilef(r"^[a-c_\x7F\x80\u{E9}\u{1F600}-\u{1F602}]+$", "u");
+    re.test_succeeds("abc_\u{7F}\u{80}\u{E9}\u{1F600}\u{1F602}");
+    for s in [
+        "d",
+        "`",
+        "\u{7E}",
+        "\u{81}",
+        "\u{E8}",
+        "\u{1F5FF}",
+        "\u{1F603}",
+    ] {
+        re.test_fails(s);
+    }
+    let re = tc.compilef(r"^[^a-c_\x7F\x80\u{E9}\u{1F600}-\u{1F602}]+$", "u");
+    re.test_succeeds("d`\u{7E}\u{81}\u{E8}\u{1F5FF}\u{1F603}\0\u{10FFFF}");
+    for s in [
+        "a",
+        "c",
+        "_",
+        "\u{7F}",
+        "\u{80}",
+        "\u{E9}",
+        "\u{1F600}",
+        "\u{1F602}",
+    ] {
+        re.test_fails(s);
+    }
+
+    // Inverted ASCII-only brackets match non-ASCII chars.
+    let re = tc.compilef(r"^[^a-z]+$", "");
+    re.test_succeeds("\u{E9}A\u{7F}\u{80}\u{1F600}");
+    re.test_fails("q");
+
+    // Large sets like Unicode \w, with case folding.
+    tc.compilef(r"^\w+$", "u")
+        .test_succeeds("caf\u{E9}_\u{65E5}9");
+    tc.compilef(r"^\W+$", "u").test_succeeds(" -\u{1F600}");
+    let re = tc.compilef(r"^[\u{E0}-\u{FE}k]+$", "iu");
+    re.test_succeeds("\u{C9}K\u{212A}\u{E9}");
+    re.test_fails("\u{D7}");
+}
+
+#[test]
+fn test_unicode_word_chars() {
+    test_with_configs(test_unicode_word_chars_tc)
+}
+
+fn test_unicode_word_chars_tc(tc: TestConfig) {
+    // Without the 'u' flag, word characters are ASCII only.
+    tc.compile(r"\w+").match1f("café").test_eq("caf");
+    tc.compile(r"\bé").test_succeeds("café");
+    tc.compile(r"f\b").match1f("café").test_eq("f");
+    tc.compile(r"[\w]+").match1f("naïve").test_eq("na");
+
+    // With it, letters, marks, decimal numbers and connector punctuation are
+    // all word characters.
+    tc.compilef(r"\w+", "u").match1f("café").test_eq("café");
+    tc.compilef(r"\w+", "u")
+        .match_all("Ωμέγα ٣٤ a‿b")
+        .test_eq(vec!["Ωμέγα", "٣٤", "a‿b"]);
+    tc.compilef(r"[\w]+", "u").match1f("naïve").test_eq("naïve");
+    tc.compilef(r"[\w]+", "v").match1f("naïve").test_eq("naïve");
+    tc.compilef(r"\W+", "u").match1f("café, ok").test_eq(", ");
+    tc.compilef(r"[^\W]+", "u")
+        .match1f("— señor")
+        .test_eq("señor");
+    tc.compilef(r"\w", "u").test_fails("—…!");
+    tc.compilef(r"\w", "u")
+        .match1f("\u{1D7D8}")
+        .test_eq("\u{1D7D8}");
+
+    // Word boundaries do not fall before accented letters.
+    tc.compilef(r"f\b", "u").test_fails("café");
+    tc.compilef(r"\bcafé\b", "u")
+        .match1f("un café noir")
+        .test_eq("café");
+    tc.compilef(r"\b\w+\b", "u")
+        .match_all("über straße")
+        .test_eq(vec!["über", "straße"]);
+    tc.compilef(r"é\B", "u").test_fails("café!");
+
+    // Combining marks are word characters, so a boundary does not separate
+    // them from their base letter.
+    tc.compilef("e\\b", "u").test_fails("cafe\u{301}");
+    tc.compilef("\\b\u{301}", "u").test_fails("cafe\u{301}");
+    tc.compilef("e\u{301}\\b", "u")
+        .test_succeeds("cafe\u{301} noir");
+    tc.compilef("\\w+", "u")
+        .match1f("cafe\u{301} noir")
+        .test_eq("cafe\u{301}");
+    tc.compilef("\\b\u{301}", "").test_succeeds("cafe\u{301}");
+}
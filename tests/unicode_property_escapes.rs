@@ -21083,6 +21083,1519 @@ fn unicode_escape_property_script_zanabazarsquare_tc(tc: TestConfig) {
     }
 }
 
+#[test]
+fn unicode_escape_property_script_extensions_adlam() {
+    test_with_configs(unicode_escape_property_script_extensions_adlam_tc)
+}
+
+fn unicode_escape_property_script_extensions_adlam_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 2] = ["\u{61f}", "\u{640}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Adlam}+$",
+        "^\\p{scx=Adlam}+$",
+        "^\\p{Script_Extensions=Adlm}+$",
+        "^\\p{scx=Adlm}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_arabic() {
+    test_with_configs(unicode_escape_property_script_extensions_arabic_tc)
+}
+
+fn unicode_escape_property_script_extensions_arabic_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 13] = [
+        "\u{60c}",
+        "\u{61b}",
+        "\u{61c}",
+        "\u{61f}",
+        "\u{640}",
+        "\u{64b}",
+        "\u{660}",
+        "\u{670}",
+        "\u{6d4}",
+        "\u{fd3e}",
+        "\u{fdf2}",
+        "\u{fdfd}",
+        "\u{102e0}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Arabic}+$",
+        "^\\p{scx=Arabic}+$",
+        "^\\p{Script_Extensions=Arab}+$",
+        "^\\p{scx=Arab}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_bengali() {
+    test_with_configs(unicode_escape_property_script_extensions_bengali_tc)
+}
+
+fn unicode_escape_property_script_extensions_bengali_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 16] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{9e6}", "\u{1cd0}", "\u{1cd2}", "\u{1cd5}",
+        "\u{1cd8}", "\u{1ce1}", "\u{1cea}", "\u{1ced}", "\u{1cf2}", "\u{1cf5}", "\u{1cf7}",
+        "\u{a8f1}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Bengali}+$",
+        "^\\p{scx=Bengali}+$",
+        "^\\p{Script_Extensions=Beng}+$",
+        "^\\p{scx=Beng}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_bopomofo() {
+    test_with_configs(unicode_escape_property_script_extensions_bopomofo_tc)
+}
+
+fn unicode_escape_property_script_extensions_bopomofo_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 12] = [
+        "\u{3001}", "\u{3003}", "\u{3008}", "\u{3013}", "\u{3014}", "\u{301c}", "\u{302a}",
+        "\u{3030}", "\u{3037}", "\u{30fb}", "\u{fe45}", "\u{ff61}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Bopomofo}+$",
+        "^\\p{scx=Bopomofo}+$",
+        "^\\p{Script_Extensions=Bopo}+$",
+        "^\\p{scx=Bopo}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_buginese() {
+    test_with_configs(unicode_escape_property_script_extensions_buginese_tc)
+}
+
+fn unicode_escape_property_script_extensions_buginese_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{a9cf}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Buginese}+$",
+        "^\\p{scx=Buginese}+$",
+        "^\\p{Script_Extensions=Bugi}+$",
+        "^\\p{scx=Bugi}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_buhid() {
+    test_with_configs(unicode_escape_property_script_extensions_buhid_tc)
+}
+
+fn unicode_escape_property_script_extensions_buhid_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{1735}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Buhid}+$",
+        "^\\p{scx=Buhid}+$",
+        "^\\p{Script_Extensions=Buhd}+$",
+        "^\\p{scx=Buhd}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_chakma() {
+    test_with_configs(unicode_escape_property_script_extensions_chakma_tc)
+}
+
+fn unicode_escape_property_script_extensions_chakma_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 2] = ["\u{9e6}", "\u{1040}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Chakma}+$",
+        "^\\p{scx=Chakma}+$",
+        "^\\p{Script_Extensions=Cakm}+$",
+        "^\\p{scx=Cakm}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_coptic() {
+    test_with_configs(unicode_escape_property_script_extensions_coptic_tc)
+}
+
+fn unicode_escape_property_script_extensions_coptic_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{102e0}"];
+    const REGEXES: [&str; 6] = [
+        "^\\p{Script_Extensions=Coptic}+$",
+        "^\\p{scx=Coptic}+$",
+        "^\\p{Script_Extensions=Copt}+$",
+        "^\\p{scx=Copt}+$",
+        "^\\p{Script_Extensions=Qaac}+$",
+        "^\\p{scx=Qaac}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_cypriot() {
+    test_with_configs(unicode_escape_property_script_extensions_cypriot_tc)
+}
+
+fn unicode_escape_property_script_extensions_cypriot_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 3] = ["\u{10100}", "\u{10107}", "\u{10137}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Cypriot}+$",
+        "^\\p{scx=Cypriot}+$",
+        "^\\p{Script_Extensions=Cprt}+$",
+        "^\\p{scx=Cprt}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_cyrillic() {
+    test_with_configs(unicode_escape_property_script_extensions_cyrillic_tc)
+}
+
+fn unicode_escape_property_script_extensions_cyrillic_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 7] = [
+        "\u{483}", "\u{484}", "\u{485}", "\u{487}", "\u{1df8}", "\u{2e43}", "\u{a66f}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Cyrillic}+$",
+        "^\\p{scx=Cyrillic}+$",
+        "^\\p{Script_Extensions=Cyrl}+$",
+        "^\\p{scx=Cyrl}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_devanagari() {
+    test_with_configs(unicode_escape_property_script_extensions_devanagari_tc)
+}
+
+fn unicode_escape_property_script_extensions_devanagari_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 37] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{966}", "\u{1cd0}", "\u{1cd1}", "\u{1cd2}",
+        "\u{1cd3}", "\u{1cd4}", "\u{1cd5}", "\u{1cd7}", "\u{1cd8}", "\u{1cd9}", "\u{1cda}",
+        "\u{1cdb}", "\u{1cdc}", "\u{1cde}", "\u{1ce0}", "\u{1ce1}", "\u{1ce2}", "\u{1ce9}",
+        "\u{1cea}", "\u{1ceb}", "\u{1ced}", "\u{1cee}", "\u{1cf2}", "\u{1cf3}", "\u{1cf4}",
+        "\u{1cf5}", "\u{1cf8}", "\u{20f0}", "\u{a830}", "\u{a833}", "\u{a836}", "\u{a8f1}",
+        "\u{a8f3}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Devanagari}+$",
+        "^\\p{scx=Devanagari}+$",
+        "^\\p{Script_Extensions=Deva}+$",
+        "^\\p{scx=Deva}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_dogra() {
+    test_with_configs(unicode_escape_property_script_extensions_dogra_tc)
+}
+
+fn unicode_escape_property_script_extensions_dogra_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 6] = [
+        "\u{964}", "\u{965}", "\u{966}", "\u{a830}", "\u{a833}", "\u{a836}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Dogra}+$",
+        "^\\p{scx=Dogra}+$",
+        "^\\p{Script_Extensions=Dogr}+$",
+        "^\\p{scx=Dogr}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_duployan() {
+    test_with_configs(unicode_escape_property_script_extensions_duployan_tc)
+}
+
+fn unicode_escape_property_script_extensions_duployan_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{1bca0}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Duployan}+$",
+        "^\\p{scx=Duployan}+$",
+        "^\\p{Script_Extensions=Dupl}+$",
+        "^\\p{scx=Dupl}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_georgian() {
+    test_with_configs(unicode_escape_property_script_extensions_georgian_tc)
+}
+
+fn unicode_escape_property_script_extensions_georgian_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{10fb}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Georgian}+$",
+        "^\\p{scx=Georgian}+$",
+        "^\\p{Script_Extensions=Geor}+$",
+        "^\\p{scx=Geor}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_glagolitic() {
+    test_with_configs(unicode_escape_property_script_extensions_glagolitic_tc)
+}
+
+fn unicode_escape_property_script_extensions_glagolitic_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 4] = ["\u{484}", "\u{487}", "\u{2e43}", "\u{a66f}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Glagolitic}+$",
+        "^\\p{scx=Glagolitic}+$",
+        "^\\p{Script_Extensions=Glag}+$",
+        "^\\p{scx=Glag}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_grantha() {
+    test_with_configs(unicode_escape_property_script_extensions_grantha_tc)
+}
+
+fn unicode_escape_property_script_extensions_grantha_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 18] = [
+        "\u{951}",
+        "\u{952}",
+        "\u{964}",
+        "\u{965}",
+        "\u{be6}",
+        "\u{1cd0}",
+        "\u{1cd2}",
+        "\u{1cd3}",
+        "\u{1cf2}",
+        "\u{1cf3}",
+        "\u{1cf4}",
+        "\u{1cf8}",
+        "\u{20f0}",
+        "\u{11301}",
+        "\u{11303}",
+        "\u{1133b}",
+        "\u{11fd0}",
+        "\u{11fd3}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Grantha}+$",
+        "^\\p{scx=Grantha}+$",
+        "^\\p{Script_Extensions=Gran}+$",
+        "^\\p{scx=Gran}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_greek() {
+    test_with_configs(unicode_escape_property_script_extensions_greek_tc)
+}
+
+fn unicode_escape_property_script_extensions_greek_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 3] = ["\u{342}", "\u{345}", "\u{1dc0}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Greek}+$",
+        "^\\p{scx=Greek}+$",
+        "^\\p{Script_Extensions=Grek}+$",
+        "^\\p{scx=Grek}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_gujarati() {
+    test_with_configs(unicode_escape_property_script_extensions_gujarati_tc)
+}
+
+fn unicode_escape_property_script_extensions_gujarati_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 8] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{ae6}", "\u{a830}", "\u{a833}", "\u{a836}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Gujarati}+$",
+        "^\\p{scx=Gujarati}+$",
+        "^\\p{Script_Extensions=Gujr}+$",
+        "^\\p{scx=Gujr}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_gunjalagondi() {
+    test_with_configs(unicode_escape_property_script_extensions_gunjalagondi_tc)
+}
+
+fn unicode_escape_property_script_extensions_gunjalagondi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 2] = ["\u{964}", "\u{965}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Gunjala_Gondi}+$",
+        "^\\p{scx=Gunjala_Gondi}+$",
+        "^\\p{Script_Extensions=Gong}+$",
+        "^\\p{scx=Gong}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_gurmukhi() {
+    test_with_configs(unicode_escape_property_script_extensions_gurmukhi_tc)
+}
+
+fn unicode_escape_property_script_extensions_gurmukhi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 8] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{a66}", "\u{a830}", "\u{a833}", "\u{a836}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Gurmukhi}+$",
+        "^\\p{scx=Gurmukhi}+$",
+        "^\\p{Script_Extensions=Guru}+$",
+        "^\\p{scx=Guru}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_han() {
+    test_with_configs(unicode_escape_property_script_extensions_han_tc)
+}
+
+fn unicode_escape_property_script_extensions_han_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 27] = [
+        "\u{3001}",
+        "\u{3003}",
+        "\u{3006}",
+        "\u{3008}",
+        "\u{3013}",
+        "\u{3014}",
+        "\u{301c}",
+        "\u{302a}",
+        "\u{3030}",
+        "\u{3037}",
+        "\u{303c}",
+        "\u{303e}",
+        "\u{30fb}",
+        "\u{3190}",
+        "\u{31c0}",
+        "\u{3220}",
+        "\u{3280}",
+        "\u{32c0}",
+        "\u{32ff}",
+        "\u{3358}",
+        "\u{337b}",
+        "\u{33e0}",
+        "\u{a700}",
+        "\u{fe45}",
+        "\u{ff61}",
+        "\u{1d360}",
+        "\u{1f250}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Han}+$",
+        "^\\p{scx=Han}+$",
+        "^\\p{Script_Extensions=Hani}+$",
+        "^\\p{scx=Hani}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_hangul() {
+    test_with_configs(unicode_escape_property_script_extensions_hangul_tc)
+}
+
+fn unicode_escape_property_script_extensions_hangul_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 11] = [
+        "\u{3001}", "\u{3003}", "\u{3008}", "\u{3013}", "\u{3014}", "\u{301c}", "\u{3030}",
+        "\u{3037}", "\u{30fb}", "\u{fe45}", "\u{ff61}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Hangul}+$",
+        "^\\p{scx=Hangul}+$",
+        "^\\p{Script_Extensions=Hang}+$",
+        "^\\p{scx=Hang}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_hanifirohingya() {
+    test_with_configs(unicode_escape_property_script_extensions_hanifirohingya_tc)
+}
+
+fn unicode_escape_property_script_extensions_hanifirohingya_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 5] = ["\u{60c}", "\u{61b}", "\u{61f}", "\u{640}", "\u{6d4}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Hanifi_Rohingya}+$",
+        "^\\p{scx=Hanifi_Rohingya}+$",
+        "^\\p{Script_Extensions=Rohg}+$",
+        "^\\p{scx=Rohg}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_hanunoo() {
+    test_with_configs(unicode_escape_property_script_extensions_hanunoo_tc)
+}
+
+fn unicode_escape_property_script_extensions_hanunoo_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{1735}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Hanunoo}+$",
+        "^\\p{scx=Hanunoo}+$",
+        "^\\p{Script_Extensions=Hano}+$",
+        "^\\p{scx=Hano}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_hiragana() {
+    test_with_configs(unicode_escape_property_script_extensions_hiragana_tc)
+}
+
+fn unicode_escape_property_script_extensions_hiragana_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 18] = [
+        "\u{3001}", "\u{3003}", "\u{3008}", "\u{3013}", "\u{3014}", "\u{301c}", "\u{3030}",
+        "\u{3031}", "\u{3037}", "\u{303c}", "\u{3099}", "\u{30a0}", "\u{30fb}", "\u{30fc}",
+        "\u{fe45}", "\u{ff61}", "\u{ff70}", "\u{ff9e}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Hiragana}+$",
+        "^\\p{scx=Hiragana}+$",
+        "^\\p{Script_Extensions=Hira}+$",
+        "^\\p{scx=Hira}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_javanese() {
+    test_with_configs(unicode_escape_property_script_extensions_javanese_tc)
+}
+
+fn unicode_escape_property_script_extensions_javanese_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{a9cf}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Javanese}+$",
+        "^\\p{scx=Javanese}+$",
+        "^\\p{Script_Extensions=Java}+$",
+        "^\\p{scx=Java}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_kaithi() {
+    test_with_configs(unicode_escape_property_script_extensions_kaithi_tc)
+}
+
+fn unicode_escape_property_script_extensions_kaithi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 4] = ["\u{966}", "\u{a830}", "\u{a833}", "\u{a836}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Kaithi}+$",
+        "^\\p{scx=Kaithi}+$",
+        "^\\p{Script_Extensions=Kthi}+$",
+        "^\\p{scx=Kthi}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_kannada() {
+    test_with_configs(unicode_escape_property_script_extensions_kannada_tc)
+}
+
+fn unicode_escape_property_script_extensions_kannada_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 12] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{ce6}", "\u{1cd0}", "\u{1cd2}", "\u{1cda}",
+        "\u{1cf2}", "\u{1cf4}", "\u{a830}", "\u{a833}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Kannada}+$",
+        "^\\p{scx=Kannada}+$",
+        "^\\p{Script_Extensions=Knda}+$",
+        "^\\p{scx=Knda}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_katakana() {
+    test_with_configs(unicode_escape_property_script_extensions_katakana_tc)
+}
+
+fn unicode_escape_property_script_extensions_katakana_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 18] = [
+        "\u{3001}", "\u{3003}", "\u{3008}", "\u{3013}", "\u{3014}", "\u{301c}", "\u{3030}",
+        "\u{3031}", "\u{3037}", "\u{303c}", "\u{3099}", "\u{30a0}", "\u{30fb}", "\u{30fc}",
+        "\u{fe45}", "\u{ff61}", "\u{ff70}", "\u{ff9e}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Katakana}+$",
+        "^\\p{scx=Katakana}+$",
+        "^\\p{Script_Extensions=Kana}+$",
+        "^\\p{scx=Kana}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_kayahli() {
+    test_with_configs(unicode_escape_property_script_extensions_kayahli_tc)
+}
+
+fn unicode_escape_property_script_extensions_kayahli_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{a92e}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Kayah_Li}+$",
+        "^\\p{scx=Kayah_Li}+$",
+        "^\\p{Script_Extensions=Kali}+$",
+        "^\\p{scx=Kali}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_khojki() {
+    test_with_configs(unicode_escape_property_script_extensions_khojki_tc)
+}
+
+fn unicode_escape_property_script_extensions_khojki_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 4] = ["\u{ae6}", "\u{a830}", "\u{a833}", "\u{a836}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Khojki}+$",
+        "^\\p{scx=Khojki}+$",
+        "^\\p{Script_Extensions=Khoj}+$",
+        "^\\p{scx=Khoj}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_khudawadi() {
+    test_with_configs(unicode_escape_property_script_extensions_khudawadi_tc)
+}
+
+fn unicode_escape_property_script_extensions_khudawadi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 5] = ["\u{964}", "\u{965}", "\u{a830}", "\u{a833}", "\u{a836}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Khudawadi}+$",
+        "^\\p{scx=Khudawadi}+$",
+        "^\\p{Script_Extensions=Sind}+$",
+        "^\\p{scx=Sind}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_latin() {
+    test_with_configs(unicode_escape_property_script_extensions_latin_tc)
+}
+
+fn unicode_escape_property_script_extensions_latin_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 9] = [
+        "\u{363}", "\u{485}", "\u{951}", "\u{952}", "\u{10fb}", "\u{202f}", "\u{20f0}", "\u{a700}",
+        "\u{a92e}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Latin}+$",
+        "^\\p{scx=Latin}+$",
+        "^\\p{Script_Extensions=Latn}+$",
+        "^\\p{scx=Latn}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_limbu() {
+    test_with_configs(unicode_escape_property_script_extensions_limbu_tc)
+}
+
+fn unicode_escape_property_script_extensions_limbu_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{965}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Limbu}+$",
+        "^\\p{scx=Limbu}+$",
+        "^\\p{Script_Extensions=Limb}+$",
+        "^\\p{scx=Limb}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_lineara() {
+    test_with_configs(unicode_escape_property_script_extensions_lineara_tc)
+}
+
+fn unicode_escape_property_script_extensions_lineara_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{10107}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Linear_A}+$",
+        "^\\p{scx=Linear_A}+$",
+        "^\\p{Script_Extensions=Lina}+$",
+        "^\\p{scx=Lina}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_linearb() {
+    test_with_configs(unicode_escape_property_script_extensions_linearb_tc)
+}
+
+fn unicode_escape_property_script_extensions_linearb_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 3] = ["\u{10100}", "\u{10107}", "\u{10137}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Linear_B}+$",
+        "^\\p{scx=Linear_B}+$",
+        "^\\p{Script_Extensions=Linb}+$",
+        "^\\p{scx=Linb}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_mahajani() {
+    test_with_configs(unicode_escape_property_script_extensions_mahajani_tc)
+}
+
+fn unicode_escape_property_script_extensions_mahajani_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 6] = [
+        "\u{964}", "\u{965}", "\u{966}", "\u{a830}", "\u{a833}", "\u{a836}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Mahajani}+$",
+        "^\\p{scx=Mahajani}+$",
+        "^\\p{Script_Extensions=Mahj}+$",
+        "^\\p{scx=Mahj}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_malayalam() {
+    test_with_configs(unicode_escape_property_script_extensions_malayalam_tc)
+}
+
+fn unicode_escape_property_script_extensions_malayalam_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 6] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{1cda}", "\u{a830}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Malayalam}+$",
+        "^\\p{scx=Malayalam}+$",
+        "^\\p{Script_Extensions=Mlym}+$",
+        "^\\p{scx=Mlym}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_mandaic() {
+    test_with_configs(unicode_escape_property_script_extensions_mandaic_tc)
+}
+
+fn unicode_escape_property_script_extensions_mandaic_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{640}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Mandaic}+$",
+        "^\\p{scx=Mandaic}+$",
+        "^\\p{Script_Extensions=Mand}+$",
+        "^\\p{scx=Mand}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_manichaean() {
+    test_with_configs(unicode_escape_property_script_extensions_manichaean_tc)
+}
+
+fn unicode_escape_property_script_extensions_manichaean_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{640}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Manichaean}+$",
+        "^\\p{scx=Manichaean}+$",
+        "^\\p{Script_Extensions=Mani}+$",
+        "^\\p{scx=Mani}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_masaramgondi() {
+    test_with_configs(unicode_escape_property_script_extensions_masaramgondi_tc)
+}
+
+fn unicode_escape_property_script_extensions_masaramgondi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 2] = ["\u{964}", "\u{965}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Masaram_Gondi}+$",
+        "^\\p{scx=Masaram_Gondi}+$",
+        "^\\p{Script_Extensions=Gonm}+$",
+        "^\\p{scx=Gonm}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_modi() {
+    test_with_configs(unicode_escape_property_script_extensions_modi_tc)
+}
+
+fn unicode_escape_property_script_extensions_modi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 3] = ["\u{a830}", "\u{a833}", "\u{a836}"];
+    const REGEXES: [&str; 2] = ["^\\p{Script_Extensions=Modi}+$", "^\\p{scx=Modi}+$"];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_mongolian() {
+    test_with_configs(unicode_escape_property_script_extensions_mongolian_tc)
+}
+
+fn unicode_escape_property_script_extensions_mongolian_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 3] = ["\u{1802}", "\u{1805}", "\u{202f}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Mongolian}+$",
+        "^\\p{scx=Mongolian}+$",
+        "^\\p{Script_Extensions=Mong}+$",
+        "^\\p{scx=Mong}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_multani() {
+    test_with_configs(unicode_escape_property_script_extensions_multani_tc)
+}
+
+fn unicode_escape_property_script_extensions_multani_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{a66}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Multani}+$",
+        "^\\p{scx=Multani}+$",
+        "^\\p{Script_Extensions=Mult}+$",
+        "^\\p{scx=Mult}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_myanmar() {
+    test_with_configs(unicode_escape_property_script_extensions_myanmar_tc)
+}
+
+fn unicode_escape_property_script_extensions_myanmar_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 2] = ["\u{1040}", "\u{a92e}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Myanmar}+$",
+        "^\\p{scx=Myanmar}+$",
+        "^\\p{Script_Extensions=Mymr}+$",
+        "^\\p{scx=Mymr}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_nandinagari() {
+    test_with_configs(unicode_escape_property_script_extensions_nandinagari_tc)
+}
+
+fn unicode_escape_property_script_extensions_nandinagari_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 8] = [
+        "\u{964}", "\u{965}", "\u{ce6}", "\u{1ce9}", "\u{1cf2}", "\u{1cfa}", "\u{a830}", "\u{a833}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Nandinagari}+$",
+        "^\\p{scx=Nandinagari}+$",
+        "^\\p{Script_Extensions=Nand}+$",
+        "^\\p{scx=Nand}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_nko() {
+    test_with_configs(unicode_escape_property_script_extensions_nko_tc)
+}
+
+fn unicode_escape_property_script_extensions_nko_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 4] = ["\u{60c}", "\u{61b}", "\u{61f}", "\u{fd3e}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Nko}+$",
+        "^\\p{scx=Nko}+$",
+        "^\\p{Script_Extensions=Nkoo}+$",
+        "^\\p{scx=Nkoo}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_oldpermic() {
+    test_with_configs(unicode_escape_property_script_extensions_oldpermic_tc)
+}
+
+fn unicode_escape_property_script_extensions_oldpermic_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{483}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Old_Permic}+$",
+        "^\\p{scx=Old_Permic}+$",
+        "^\\p{Script_Extensions=Perm}+$",
+        "^\\p{scx=Perm}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_oriya() {
+    test_with_configs(unicode_escape_property_script_extensions_oriya_tc)
+}
+
+fn unicode_escape_property_script_extensions_oriya_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 6] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{1cda}", "\u{1cf2}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Oriya}+$",
+        "^\\p{scx=Oriya}+$",
+        "^\\p{Script_Extensions=Orya}+$",
+        "^\\p{scx=Orya}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_phagspa() {
+    test_with_configs(unicode_escape_property_script_extensions_phagspa_tc)
+}
+
+fn unicode_escape_property_script_extensions_phagspa_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 2] = ["\u{1802}", "\u{1805}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Phags_Pa}+$",
+        "^\\p{scx=Phags_Pa}+$",
+        "^\\p{Script_Extensions=Phag}+$",
+        "^\\p{scx=Phag}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_psalterpahlavi() {
+    test_with_configs(unicode_escape_property_script_extensions_psalterpahlavi_tc)
+}
+
+fn unicode_escape_property_script_extensions_psalterpahlavi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{640}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Psalter_Pahlavi}+$",
+        "^\\p{scx=Psalter_Pahlavi}+$",
+        "^\\p{Script_Extensions=Phlp}+$",
+        "^\\p{scx=Phlp}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_sharada() {
+    test_with_configs(unicode_escape_property_script_extensions_sharada_tc)
+}
+
+fn unicode_escape_property_script_extensions_sharada_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 5] = ["\u{951}", "\u{1cd7}", "\u{1cd9}", "\u{1cdc}", "\u{1ce0}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Sharada}+$",
+        "^\\p{scx=Sharada}+$",
+        "^\\p{Script_Extensions=Shrd}+$",
+        "^\\p{scx=Shrd}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_sinhala() {
+    test_with_configs(unicode_escape_property_script_extensions_sinhala_tc)
+}
+
+fn unicode_escape_property_script_extensions_sinhala_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 2] = ["\u{964}", "\u{965}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Sinhala}+$",
+        "^\\p{scx=Sinhala}+$",
+        "^\\p{Script_Extensions=Sinh}+$",
+        "^\\p{scx=Sinh}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_sogdian() {
+    test_with_configs(unicode_escape_property_script_extensions_sogdian_tc)
+}
+
+fn unicode_escape_property_script_extensions_sogdian_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{640}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Sogdian}+$",
+        "^\\p{scx=Sogdian}+$",
+        "^\\p{Script_Extensions=Sogd}+$",
+        "^\\p{scx=Sogd}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_sylotinagri() {
+    test_with_configs(unicode_escape_property_script_extensions_sylotinagri_tc)
+}
+
+fn unicode_escape_property_script_extensions_sylotinagri_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 3] = ["\u{964}", "\u{965}", "\u{9e6}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Syloti_Nagri}+$",
+        "^\\p{scx=Syloti_Nagri}+$",
+        "^\\p{Script_Extensions=Sylo}+$",
+        "^\\p{scx=Sylo}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_syriac() {
+    test_with_configs(unicode_escape_property_script_extensions_syriac_tc)
+}
+
+fn unicode_escape_property_script_extensions_syriac_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 9] = [
+        "\u{60c}", "\u{61b}", "\u{61c}", "\u{61f}", "\u{640}", "\u{64b}", "\u{670}", "\u{1df8}",
+        "\u{1dfa}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Syriac}+$",
+        "^\\p{scx=Syriac}+$",
+        "^\\p{Script_Extensions=Syrc}+$",
+        "^\\p{scx=Syrc}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_tagalog() {
+    test_with_configs(unicode_escape_property_script_extensions_tagalog_tc)
+}
+
+fn unicode_escape_property_script_extensions_tagalog_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{1735}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Tagalog}+$",
+        "^\\p{scx=Tagalog}+$",
+        "^\\p{Script_Extensions=Tglg}+$",
+        "^\\p{scx=Tglg}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_tagbanwa() {
+    test_with_configs(unicode_escape_property_script_extensions_tagbanwa_tc)
+}
+
+fn unicode_escape_property_script_extensions_tagbanwa_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{1735}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Tagbanwa}+$",
+        "^\\p{scx=Tagbanwa}+$",
+        "^\\p{Script_Extensions=Tagb}+$",
+        "^\\p{scx=Tagb}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_taile() {
+    test_with_configs(unicode_escape_property_script_extensions_taile_tc)
+}
+
+fn unicode_escape_property_script_extensions_taile_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 1] = ["\u{1040}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Tai_Le}+$",
+        "^\\p{scx=Tai_Le}+$",
+        "^\\p{Script_Extensions=Tale}+$",
+        "^\\p{scx=Tale}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_takri() {
+    test_with_configs(unicode_escape_property_script_extensions_takri_tc)
+}
+
+fn unicode_escape_property_script_extensions_takri_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 5] = ["\u{964}", "\u{965}", "\u{a830}", "\u{a833}", "\u{a836}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Takri}+$",
+        "^\\p{scx=Takri}+$",
+        "^\\p{Script_Extensions=Takr}+$",
+        "^\\p{scx=Takr}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_tamil() {
+    test_with_configs(unicode_escape_property_script_extensions_tamil_tc)
+}
+
+fn unicode_escape_property_script_extensions_tamil_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 12] = [
+        "\u{951}",
+        "\u{952}",
+        "\u{964}",
+        "\u{965}",
+        "\u{be6}",
+        "\u{1cda}",
+        "\u{a8f3}",
+        "\u{11301}",
+        "\u{11303}",
+        "\u{1133b}",
+        "\u{11fd0}",
+        "\u{11fd3}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Tamil}+$",
+        "^\\p{scx=Tamil}+$",
+        "^\\p{Script_Extensions=Taml}+$",
+        "^\\p{scx=Taml}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_telugu() {
+    test_with_configs(unicode_escape_property_script_extensions_telugu_tc)
+}
+
+fn unicode_escape_property_script_extensions_telugu_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 6] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{1cda}", "\u{1cf2}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Telugu}+$",
+        "^\\p{scx=Telugu}+$",
+        "^\\p{Script_Extensions=Telu}+$",
+        "^\\p{scx=Telu}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_thaana() {
+    test_with_configs(unicode_escape_property_script_extensions_thaana_tc)
+}
+
+fn unicode_escape_property_script_extensions_thaana_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 7] = [
+        "\u{60c}", "\u{61b}", "\u{61c}", "\u{61f}", "\u{660}", "\u{fdf2}", "\u{fdfd}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Thaana}+$",
+        "^\\p{scx=Thaana}+$",
+        "^\\p{Script_Extensions=Thaa}+$",
+        "^\\p{scx=Thaa}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_tirhuta() {
+    test_with_configs(unicode_escape_property_script_extensions_tirhuta_tc)
+}
+
+fn unicode_escape_property_script_extensions_tirhuta_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 8] = [
+        "\u{951}", "\u{952}", "\u{964}", "\u{965}", "\u{1cf2}", "\u{a830}", "\u{a833}", "\u{a836}",
+    ];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Tirhuta}+$",
+        "^\\p{scx=Tirhuta}+$",
+        "^\\p{Script_Extensions=Tirh}+$",
+        "^\\p{scx=Tirh}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_yezidi() {
+    test_with_configs(unicode_escape_property_script_extensions_yezidi_tc)
+}
+
+fn unicode_escape_property_script_extensions_yezidi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 4] = ["\u{60c}", "\u{61b}", "\u{61f}", "\u{660}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Yezidi}+$",
+        "^\\p{scx=Yezidi}+$",
+        "^\\p{Script_Extensions=Yezi}+$",
+        "^\\p{scx=Yezi}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
+#[test]
+fn unicode_escape_property_script_extensions_yi() {
+    test_with_configs(unicode_escape_property_script_extensions_yi_tc)
+}
+
+fn unicode_escape_property_script_extensions_yi_tc(tc: TestConfig) {
+    const CODE_POINTS: [&str; 5] = ["\u{3001}", "\u{3008}", "\u{3014}", "\u{30fb}", "\u{ff61}"];
+    const REGEXES: [&str; 4] = [
+        "^\\p{Script_Extensions=Yi}+$",
+        "^\\p{scx=Yi}+$",
+        "^\\p{Script_Extensions=Yiii}+$",
+        "^\\p{scx=Yiii}+$",
+    ];
+    for regex in REGEXES {
+        let regex = tc.compile(regex);
+        for code_point in CODE_POINTS {
+            regex.test_succeeds(code_point);
+        }
+    }
+}
+
 #[test]
 fn unicode_escape_property_gc_closepunctuation() {
     test_with_configs(unicode_escape_property_gc_closepunctuation_tc)